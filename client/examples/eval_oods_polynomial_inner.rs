@@ -10,16 +10,18 @@ use solana_sdk::{
 };
 use solana_system_interface::instruction::create_account;
 use stark::stark_proof::stark_commit::eval_oods_polynomial_inner::EvalOodsPolynomialInner;
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
 use stark::swiftness::stark::types::cast_struct_to_slice;
-use std::{mem::size_of, path::Path};
+use std::{
+    mem::{offset_of, size_of},
+    path::Path,
+};
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
-use utils::OODS_VALUES_SIZE;
-use utils::{AccountCast, Executable};
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
-// Add these imports for the new types
-use felt::Felt;
-use stark::swiftness::air::recursive_with_poseidon::GlobalValues;
-use utils::global_values::EcPoint;
+use utils::{AccountCast, Executable, TaskError};
+use verifier::{
+    instruction::{initialize, seal, VerifierInstruction},
+    state::BidirectionalStackAccount,
+};
 
 pub const CHUNK_SIZE: usize = 1000;
 
@@ -53,7 +55,10 @@ async fn main() -> client::Result<()> {
     );
 
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -71,184 +76,11 @@ async fn main() -> client::Result<()> {
     let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
     let proof = StarkProofParser::try_from(proof_json).unwrap();
 
-    // Extract values from proof
-    let oods_values = proof.unsent_commitment.oods_values.clone();
-
-    // Hardcoded column_values
-    let column_values = vec![
-        Felt::from_hex("0x4259480c2e22c881ee19f1a33a3ba4741be9d482d60a1e101caa1880a60da4a")
-            .unwrap(),
-        Felt::from_hex("0x30e9bd02ed5c2b63c21aea250864b92954874321b8e435b4094c4dc0eee66ed")
-            .unwrap(),
-        Felt::from_hex("0x10d0a289021b4237e35246efcadec0bc8ba9639d3a4fe8ee0fabcb9b1debd87")
-            .unwrap(),
-        Felt::from_hex("0x1a3f12b77f078a954ab0679e396b9bb4f983926be28f8e51aa3ed96071a200d")
-            .unwrap(),
-        Felt::from_hex("0x495d4e5eabd1299ab4e710d293df100d2cb92c3e660d16c1a5c2f9ce8b0ec6a")
-            .unwrap(),
-        Felt::from_hex("0x7c790c2b3715747359636cc557168400f6dfa53f69d39f6566cf849333034f2")
-            .unwrap(),
-        Felt::from_hex("0x5396ee71fe13db8760e752eae2c345b478bb742d474e06a026d19020355a514")
-            .unwrap(),
-        Felt::from_hex("0x2e6bfcb0a0dc9bc65f65a09947db8c653d138a0fefe55275c9c1d42b4be1b97")
-            .unwrap(),
-        Felt::from_hex("0x1a0cebd05a8079567be06d342813a5726b0094dfe5294ebb221bdfc5ff5006a")
-            .unwrap(),
-        Felt::from_hex("0x6c6059de8def374197a382cb19880e30f1aa80a009bf4c3db6dfa5656f308").unwrap(),
-    ];
-
-    let mut oods_slice = &oods_values.as_slice()[0..OODS_VALUES_SIZE];
-
     let mut proof_verifier = proof.transform_to();
 
-    // Calculate offsets based on struct layout
-    // front_index: usize (8 bytes)
-    // back_index: usize (8 bytes)
-    // proof: StarkProof (variable size)
-    // buffer: [u8; CAPACITY] (65536 bytes)
-    // autogenerated_pows: [Felt; POWS_SIZE] (134 * 32 = 4288 bytes)
-    // oods_values: [Felt; OODS_VALUES_SIZE] (194 * 32 = 6208 bytes)
-    // domains: [Felt; DOMAINS_SIZE] (31 * 32 = 992 bytes)
-    // global_values: GlobalValues (variable size)
-    // constraint_coefficients: [Felt; N_CONSTRAINTS] (194 * 32 = 6208 bytes)
-    // column_values: [Felt; COLUMN_VALUES_SIZE] (10 * 32 = 320 bytes)
-
-    // Start with stack initialization
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
-
-    // Calculate proof size
     let proof_bytes = cast_struct_to_slice(&mut proof_verifier).to_vec();
-    let proof_size = proof_bytes.len();
-
-    // Calculate GlobalValues size (approximately 35 * 32 = 1120 bytes)
-    let global_values = GlobalValues {
-        trace_length: Felt::from_hex("0x10000000").unwrap(),
-        initial_pc: Felt::from_hex("0x1").unwrap(),
-        final_pc: Felt::from_hex("0x5").unwrap(),
-        initial_ap: Felt::from_hex("0x1c6").unwrap(),
-        final_ap: Felt::from_hex("0x1c43b3").unwrap(),
-        initial_pedersen_addr: Felt::from_hex("0x1c43b8").unwrap(),
-        initial_range_check_addr: Felt::from_hex("0x1f43b8").unwrap(),
-        initial_bitwise_addr: Felt::from_hex("0x2f43b8").unwrap(),
-        initial_poseidon_addr: Felt::from_hex("0x7f43b8").unwrap(),
-        range_check_min: Felt::from_hex("0x0").unwrap(),
-        range_check_max: Felt::from_hex("0xffff").unwrap(),
-        offset_size: Felt::from_hex("0x10000").unwrap(),
-        half_offset_size: Felt::from_hex("0x8000").unwrap(),
-        pedersen_shift_point: EcPoint {
-            x: Felt::from_hex("0x49ee3eba8c1600700ee1b87eb599f16716b0b1022947733551fde4050ca6804")
-                .unwrap(),
-            y: Felt::from_hex("0x3ca0cfe4b3bc6ddf346d49d06ea0ed34e621062c0e056c1d0405d266e10268a")
-                .unwrap(),
-        },
-        pedersen_points_x: Felt::from_hex(
-            "0x598904d65b0434a87c175e65222359d01fff2522cade3bb409c28885b7671e",
-        )
-        .unwrap(),
-        pedersen_points_y: Felt::from_hex(
-            "0x4fe4068e06eefa17eefab622b3c9d9433bc11552fd96bf324893028770e40f4",
-        )
-        .unwrap(),
-        poseidon_poseidon_full_round_key0: Felt::from_hex(
-            "0x4f7c465fb34210b739758542eb985867c6ba4ec77b078ccb61b8e4288cbbae8",
-        )
-        .unwrap(),
-        poseidon_poseidon_full_round_key1: Felt::from_hex(
-            "0x2f96e26e8a7034b6317c2483e935e6bd1d5ea8efa42dc84ebba571760a1527d",
-        )
-        .unwrap(),
-        poseidon_poseidon_full_round_key2: Felt::from_hex(
-            "0x79e52af7b64407d08c6b7b54d92ea2477b7120da296f986f0d52705a850043d",
-        )
-        .unwrap(),
-        poseidon_poseidon_partial_round_key0: Felt::from_hex(
-            "0x17d8c8dc5aaa6ac1879e160be09a2012f52e1d6df8e3528255e00fa01f13020",
-        )
-        .unwrap(),
-        poseidon_poseidon_partial_round_key1: Felt::from_hex(
-            "0x786dda7880b1250660bec5c62a9c1a255f95c69b9d050d5bc4a89b4accdd89d",
-        )
-        .unwrap(),
-        memory_multi_column_perm_perm_interaction_elm: Felt::from_hex(
-            "0x63be95eef090c5ed842139ace99b3dc2e8222f4946d656d2b8ecf9f3a4eaa64",
-        )
-        .unwrap(),
-        memory_multi_column_perm_hash_interaction_elm0: Felt::from_hex(
-            "0x522df1ce46453857bc93d7b48c77fd4968ae6be4de52c9a9ebf3b053fe3f288",
-        )
-        .unwrap(),
-        range_check16_perm_interaction_elm: Felt::from_hex(
-            "0x47256c1d9e69a2c23e0a5b2666fd2e2037ef2987d19b53da2b089c7a79e217c",
-        )
-        .unwrap(),
-        diluted_check_permutation_interaction_elm: Felt::from_hex(
-            "0x1f44508505278264aabe386ad5df3bee4b8147b3d0e20518bfaec709cbc1322",
-        )
-        .unwrap(),
-        diluted_check_interaction_z: Felt::from_hex(
-            "0x7f01d79f2cdf6aa851c9b2e0fa2e92f64ecd655289f827b14d5e7b483f52b48",
-        )
-        .unwrap(),
-        diluted_check_interaction_alpha: Felt::from_hex(
-            "0x734820597aa2142c285a8ab4990f17ba4241a78de519e3661dafd9453a8e822",
-        )
-        .unwrap(),
-        memory_multi_column_perm_perm_public_memory_prod: Felt::from_hex(
-            "0x5593c3e7c28433d4bed879adb1cb8081b0a46decda462e76da45b0d7244cbf0",
-        )
-        .unwrap(),
-        range_check16_perm_public_memory_prod: Felt::from_hex("0x1").unwrap(),
-        diluted_check_first_elm: Felt::from_hex("0x0").unwrap(),
-        diluted_check_permutation_public_memory_prod: Felt::from_hex("0x1").unwrap(),
-        diluted_check_final_cum_val: Felt::from_hex(
-            "0x5f16ce646fe7bef242b9158006cb52930937bf075c6e8bc638bba2b8244dfa",
-        )
-        .unwrap(),
-    };
-
-    let mut global_values_mut = global_values;
-    let global_values_bytes = cast_struct_to_slice(&mut global_values_mut).to_vec();
-    let global_values_size = global_values_bytes.len();
-
-    // Calculate offsets
-    let stack_offset = 0;
-    let proof_offset = 16; // front_index + back_index
-    let buffer_offset = proof_offset + proof_size;
-    let autogenerated_pows_offset = buffer_offset + 65536; // CAPACITY
-    let oods_values_offset = autogenerated_pows_offset + 4288; // POWS_SIZE * 32
-    let domains_offset = oods_values_offset + 6208; // OODS_VALUES_SIZE * 32
-    let global_values_offset = domains_offset + 992; // DOMAINS_SIZE * 32
-    let constraint_coefficients_offset = global_values_offset + global_values_size;
-    let column_values_offset = constraint_coefficients_offset + 6208; // N_CONSTRAINTS * 32
-
-    println!("Offsets:");
-    println!("  Stack: {}", stack_offset);
-    println!("  Proof: {}", proof_offset);
-    println!("  Buffer: {}", buffer_offset);
-    println!("  Autogenerated pows: {}", autogenerated_pows_offset);
-    println!("  OODS values: {}", oods_values_offset);
-    println!("  Domains: {}", domains_offset);
-    println!("  Global values: {}", global_values_offset);
-    println!(
-        "  Constraint coefficients: {}",
-        constraint_coefficients_offset
-    );
-    println!("  Column values: {}", column_values_offset);
-
-    // Create instructions for each section
-    let mut instructions = Vec::new();
-
-    // 1. Set stack initialization
-    let stack_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::SetAccountData(stack_offset, stack_init_bytes.to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-    instructions.push(stack_ix);
-
-    // 2. Set proof data
-    let proof_chunks: Vec<_> = proof_bytes
+    let proof_offset = offset_of!(BidirectionalStackAccount, proof);
+    let instructions: Vec<_> = proof_bytes
         .chunks(CHUNK_SIZE)
         .enumerate()
         .map(|(i, chunk)| {
@@ -258,85 +90,13 @@ async fn main() -> client::Result<()> {
                     proof_offset + (i * CHUNK_SIZE),
                     chunk.to_vec(),
                 ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(proof_chunks);
-
-    // 3. Set OODS values
-    let oods_values_bytes = cast_struct_to_slice(&mut oods_slice).to_vec();
-    let oods_chunks: Vec<_> = oods_values_bytes
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    oods_values_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(oods_chunks);
-
-    // 4. Set column values
-    let mut column_values_mut = column_values;
-    let column_values_bytes = cast_struct_to_slice(&mut column_values_mut.as_mut_slice()).to_vec();
-    let column_chunks: Vec<_> = column_values_bytes
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    column_values_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(column_chunks);
-
-    // 5. Set constraint coefficients
-    let mut constraint_coefficients_slice = get();
-    let constraint_coefficients_bytes =
-        cast_struct_to_slice(&mut constraint_coefficients_slice).to_vec();
-    let constraint_chunks: Vec<_> = constraint_coefficients_bytes
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    constraint_coefficients_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
+                vec![
+                    AccountMeta::new(stack_account.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                ],
             )
         })
         .collect();
-    instructions.extend(constraint_chunks);
-
-    // 6. Set global values
-    let global_chunks: Vec<_> = global_values_bytes
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    global_values_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(global_chunks);
 
     println!("Total instructions: {}", instructions.len());
 
@@ -354,73 +114,20 @@ async fn main() -> client::Result<()> {
     send_and_confirm_transactions(&client, &transactions).await?;
     println!("All data set successfully");
 
-    // Push the EvalOodsPolynomialInner task to the stack
-    let validate_task = EvalOodsPolynomialInner::new();
-
-    println!(
-        "Using EvalOodsPolynomialInner with TYPE_TAG: {}",
-        EvalOodsPolynomialInner::TYPE_TAG
-    );
-
-    let push_task_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushTask(validate_task.to_vec_with_type_tag()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let signature = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[push_task_ix],
-    )
-    .await?;
-    println!("EvalOodsPolynomialInner task pushed: {signature}");
-
-    let mut trace_generator =
-        Felt::from_hex("0x57a797181c06d8427145cb66056f032751615d8617c5468258e96d2bb6422f9")
-            .unwrap();
-    let trace_generator_bytes = cast_struct_to_slice(&mut trace_generator).to_vec();
-
-    let mut oods_point =
-        Felt::from_hex("0x49185430497be4bd990699e70b3b91b25c0dd22d5cd436dbf23f364136368bc")
-            .unwrap();
-    let oods_point_bytes = cast_struct_to_slice(&mut oods_point).to_vec();
-
-    let mut point =
-        Felt::from_hex("0x7f90255cc310f54635400a0fc3ad5d4dcd9afb685485297d828f04cb9c29fcb")
-            .unwrap();
-    let point_bytes = cast_struct_to_slice(&mut point).to_vec();
-
-    let push_trace_generator_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushData(trace_generator_bytes),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let push_oods_point_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushData(oods_point_bytes),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    // Push parameters to stack
-    let push_point_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushData(point_bytes),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
     let signature = interact_with_program_instructions(
         &client,
         &payer,
         &program_id,
         &stack_account,
-        &[push_point_ix, push_oods_point_ix, push_trace_generator_ix],
+        &[seal(
+            &program_id,
+            &stack_account.pubkey(),
+            &payer.pubkey(),
+            DEFAULT_SECURITY_BITS,
+        )],
     )
     .await?;
-    println!("Parameters pushed to stack: {signature}");
+    println!("Proof sealed: {signature}");
 
     let mut account_data = client
         .get_account_data(&stack_account.pubkey())
@@ -428,7 +135,8 @@ async fn main() -> client::Result<()> {
         .map_err(ClientError::SolanaClientError)?;
 
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let simulation_steps = stack.simulate();
+    // The sealed Verify task runs until EvalOodsPolynomialInner finished
+    let simulation_steps = stack.simulate_until(EvalOodsPolynomialInner::TYPE_TAG)?;
     println!("Steps in simulation: {simulation_steps}");
 
     let limit_instructions = ComputeBudgetInstruction::set_compute_unit_limit(800_000);
@@ -470,213 +178,19 @@ async fn main() -> client::Result<()> {
     }
 
     println!("All execution steps completed");
+
+    let account_data = client
+        .get_account_data(&stack_account.pubkey())
+        .await
+        .map_err(ClientError::SolanaClientError)?;
+    let stack = BidirectionalStackAccount::cast(&account_data);
+    println!("Verification state: {:?}", stack.header.state);
+    if stack.status.is_failed() {
+        return Err(ClientError::ProofRejected(
+            TaskError::describe(stack.status.error_code).to_string(),
+        ));
+    }
     println!("\nEvalOodsPolynomialInner successfully executed on Solana!");
 
     Ok(())
 }
-
-use stark::funvec::FunVec;
-
-pub fn get() -> FunVec<Felt, 194> {
-    FunVec::from_vec(
-        vec![
-            "0x1",
-            "0x1b63b11b6dbde42a3c1889ed7a8a7fa8b341c3e478db9d2364cd7b0f98ba234",
-            "0x1540bf531b68f071978f5f2bfafe5a316ad3f3f2d4a1d0301118c3462a9a85f",
-            "0x4c590f823dd204e1c2576968a1e5abce136f69bf52de6cef4e486c895f8ef15",
-            "0x15a60926c43c071bfaec4eba798d842000a3ad4faf7fbe4942d8f5c4c8c823c",
-            "0x1cce7414b3015704bb129075520177f1d91391d64b27687702054162ff0ebb9",
-            "0x636718275ac6fb788c3fdecb5bc12cf7c0460b75a4f837b91cfa5c1aba28085",
-            "0x7d6e1959906ed0e60c8c716445b29126736f5f441790bf677e206f064fd6347",
-            "0x1ec592e5a96ac46830f2a73a24872514f5ae2e0605295feda5c29bdcbab0f2b",
-            "0x79f182e12eeaa6f787d449582cf498bf35b7fe635a91ea4ede86d7d1dc2b787",
-            "0x58a9309fbad4f54610b70d2bab7c18ea40a03792211189ba1ed5e07b96c6632",
-            "0xc09732081f5afec7ae0113c68cce87f11309cc2b8e6aabe9a497f245a8217f",
-            "0x361bbfd1225b5b90e7a48f09f04a7a9c566511015885314942b7b25610e4be7",
-            "0x75bc16f7c3b462fb42b8b5adaf75d8ee40d9ce4810e6894db9771d897abf9af",
-            "0x3215de0659269e0e31202ec4478a5701826bb570e99c768107d88dc829d4603",
-            "0x7ea169d9072974f778b6860cfa53ad577a81754e4f0e32dd7be690913cb9e6c",
-            "0x5709a5b9d67e5b2fc30f065e9ac4315e2d4558e1c848bc154d0db6b1817e2e5",
-            "0x1872e58c6886e9dd7852b808a14405d7c6b9b4173366eb5d11459fada72992b",
-            "0x690bfdd6d6b4731bbdbe83238ab879069d1e2dd79f1964c447bb975182a409b",
-            "0x1af437997a6b8194353c516bd26f3b8d615026eefc39261e08e73dcd0e8f300",
-            "0x658b1909fcfb2a55ab5ed9346a3f37f790774679f5658c98c30f83d5cd8d3aa",
-            "0x729fc4caebc09d35b1083df4cb8e98f8392e39f15b44e08b3664391d1bc9b85",
-            "0x3cf804e2c2f93805d35a16509db119e9306f00bace0a1d84195cdfaf933270",
-            "0x2e7a7656df4024d3b43e827b9fb907968fe5e79f6b8fce9d564b93793ad88b3",
-            "0x7c1979f53cd24a3aaaec2950be392c43f213d27038f55ba172d964ea7429625",
-            "0x7138f9e39f62c453db7adc56e506d5c5fbcc8c677a1e5a7dd702ad347abf0e5",
-            "0x441e9c67345f7d947282025d3b17f135aeadb2fffa110c741578d06d906f2a0",
-            "0x3f2a7c265985dcf9ae78b903191e11102b6539e9855eeeb3da2081005eb5b1",
-            "0x731186586f2addfb4cd991475f4eebea3e470779dd7d645aa79988eb9c5c1cf",
-            "0x4b53b0ec48ed4443cea2155e48b292f00aebec6b07f37f9c2bd13d155ced9a4",
-            "0x2351b3b2ca2d1ff1e9262340efa77b1efa9566a0a2fa0e2f240df34dd8693ec",
-            "0x6a16c246ae4da2e2189bd80ee3f3b711ac72c7292f6b97429e602bcd8e3557c",
-            "0xe2dc8df7c16230ab4be29a8a9960ee72956142af2a9b595143bcec37271897",
-            "0x437c423e9482eba774eb4a55b9b706fb020e1f4aa399f4fb9d549782da5dcc9",
-            "0x6d31a762149082022873cfb2b22c835d4538c675806b47a32508ab6d9b98440",
-            "0x131433950a91d0a0351ec7ed43cd6f3ca92e6aae40929d77257b4a339129185",
-            "0x7b6070e8bae8440e595dd53b96c6e9a8b5a2bccd25cc75b4856ed0b7be0fb04",
-            "0x41290faa3d56a317295479bee01172d071ce27a07f37219ee11ede6a651f41",
-            "0x57d4313be0d04063669db86f0bbab67d4ae07bf8ff742d293eb9492c3b5c744",
-            "0x16e8fc9bf9fdee3cfcc13913eda1c796bc1692da2e844898b4e43c8512519b3",
-            "0x7519dacc964a4420f19aa25e92379ebb01079e95f3c486e7228000e4cf8d60a",
-            "0x7166679002a7b681b2ecd62c36decdfd4755265c9d681d2b36bcc6f707063a4",
-            "0x4b640b14f292471d7154ef618c65fe5e39f466dd6483c50fc455e5974debca",
-            "0x4da6026e85025aeaf42e38d1e3a1aea98abb2d9a1115f46f810e4085e0b50ee",
-            "0x4ecc80349de6b7804da60b27205801aefc6ea4cf0d864e47356c8ce784a5a80",
-            "0x4f373d9b427a84f5d08150b68b110d249fde101943cad49942824f0cbd630c1",
-            "0x1e8638340e9f61321a7190be952aa833d3bdcbd2f23e945fcd770f5dadf81ce",
-            "0x7cdae99167d70f6193e357c2ffdcd2957bdfb0bb4f7a4bf2cbad35e09006f2f",
-            "0x4699ca7563be7b7ca3a86f578bd8ee2f58b056576ff99e8df20ca9ba1bccc52",
-            "0x305bb255adf3abd121321bfdae16bf36689ba68ad34ba26d1aefbac188897a5",
-            "0x4a96dc8540435486a36f75d704abdb5083d4a5842899837ba72acf7abe85ec0",
-            "0x333a82914de82858baeaf3525f103d50684c6dffd6dfa9ebedc36b1d8e8917f",
-            "0x153eb906e68eccf32b38223c55208ca8099d2d374faddb2a8db88692dd967d",
-            "0x5d53b00ca863cf2b89a4a603ead71fa1495d91193f163702962dc310bf92951",
-            "0xb4bc07860b5cfaecc6b41e135ff9b2649cf55b02e90eb0e5fe299e3dd31e04",
-            "0x3c91b7308a1f5958601782a343bee956bdd728d1d7b5df8a2198e6392daa2bb",
-            "0x2df9eaab6e0156593c6d44c2ebee2df038ccd1715ca2470d302e29b07260176",
-            "0x2595852af47f1bac984e14f0b2e704b0e9a9807a13bdeed850051e138a90b7d",
-            "0x4b53e06d5e3aa7afe9d47d1292e97636329d19bae457f87a9c454ae540337a6",
-            "0x235056aac3afb2341a5cab57eaa67eb8e1e78c2f7bee7a3655bf2e06f95156f",
-            "0x11ec46329e45a959956bf0d8cfeb6519cdec453e711b571f7c428574bb2f6e0",
-            "0x122efcb50c70b3dcbe72c68280ed4ecfb6de3f8bf6ab622c1a75870c0fdd599",
-            "0x11091ab6ce693928f731d2995661b55148268cebceb21ab152264349e03982b",
-            "0x53eba1db2c7502b5c52c1e1a57102540c45ad2faf46fe5023a5fc42ceffc1c0",
-            "0x16d2b1849a29cb565cf743c834834ac4d5888c6eece38c2637889311b1885d6",
-            "0x1a963195c459de1b5b6fb1242cb18f49460f84c877de10ee0733482323b703e",
-            "0x3b6d28895d980125d30401a4001f3969d269c834af6bd4575d678454d43fe77",
-            "0x4faddedccc48ccf22b430f21a65011b5758b65e4324ea2268bc0a8718c03659",
-            "0x7ef6c96e1dd2a296d320cb0246786834c17a4a35a704441371183b55b49b5db",
-            "0x37afeaf2ddad2737a666736d9529ac1ec655e00782b861a6e3691a9bc218b61",
-            "0x7b567b0d38db69169f11de0693807d38a4d7fe706bdcbee56ff34864f3509d",
-            "0x29d52301316e47ff6e2869a67aa98863f09beeea0f3586184ddc4c7bfc84cc1",
-            "0x3fb9d5a5e68f16ebefdb710db92f55025fbd0e7d34bc9df7796afac6b6c5793",
-            "0x3a73c6c1d0159841808ad5bc3b5229ee8bba13c85c6b71a55785202a08ab341",
-            "0xb0629e1d5986766e17eea7de625085625e7d3b031a161713612952887ae906",
-            "0x3360b868a7225363f41e2240573b2b6475152b5f0d24b8584e6d7b21dba052e",
-            "0x18a0bb8a790afbe63f5b7c32fdd34732c401d27f2ad5ac2d88459bbc4b775e1",
-            "0x3ac87f6ee65d9075129b49a7ef96fa2be06c522df2457d711ca26cf3098bd95",
-            "0x1af79fae42067105aa20e71b0259039e2b356ec132d7b4f817872f8d2358605",
-            "0x18600c3923a67466d77a035336aa4a3361332665ecaa1d5d803ee02162c950b",
-            "0x4ac5e3493995fe70fc1081af1e54ea4bb47b32a607a2851b36e17eed9d4e7a1",
-            "0x3e8050fd86f211fac87000970ea67f1e5077a3450bb7cd602d704da8124995e",
-            "0x4679767a59aa8bfbc865a2aafe5a27ca249b5667d942703b16ee13b45ad1592",
-            "0xda528262d3cc3f341c9e49662fb23adfbcb0070d217c6b5d93dfb710fafc0c",
-            "0x34e759efb080862196b82054de3bdba7f787ff644d033797ce561fdcf962ca3",
-            "0x7abb8faa7cfba157dae9b97a72c1411180b5ad0168a33b981779a4be0d1f179",
-            "0x5cec019c5b800f7326c4ce6b7381c7c686b817cf528a7283a6a3d01a1d29a41",
-            "0x2e22ad1ff142156ac8be3d985388e5a100f252572c3046347e908a9fd2201dd",
-            "0x68049a51ef5977bf0324dd763de6590e468064240bdd05ef4ca7ef89382926c",
-            "0x5419df57acda5de881158d2a82d767b7fe2764591c662b6571d82fb759c3e25",
-            "0x18fe3382fdb36f7498f067ee91003a4e06e9cf1acdc0f600d50c0351a1ea019",
-            "0x7d14b02bd67662ebd47fdfab77a09c5592874f1706a37dba45f6e846cb949c0",
-            "0x7eb30ed00230133494e5f5813f966b20fde40cce9a8fb7caa72bfcd4eb33233",
-            "0x5b69e4bf5b31b0ba9c4df82ccfb9d82e0ba16d07512db91e5b855455163f1db",
-            "0x5ad78452a2db2fdb29505046075ee1fad94ce9dac56c89e6bcbd609b02cbe98",
-            "0x2047594d9f85165bfe2c11a6c7cec744b1dfa85b07b9464ad6d91c3bdf2d8dd",
-            "0x6144467207b6bd12b3439e43e81a09d9ce2054372f4766d6860022da6f5a9ba",
-            "0x40965c9fe4a7e9bc7e1d63be68db157759bc2bf402f312aae31b1b6abaa25d5",
-            "0x6dfd773d3d03319fb9cb9a72a7b2e6d701515c38c999a03a91370837cb92987",
-            "0x2eb44bd16bc008432add7d7d7f1fb2afe1d8f471adc02da558bc4bbcd45b39f",
-            "0xfb1511de0b7e718f4c737b6e70d093fa55d3e6cd182d620249fe99a4f49707",
-            "0x34aef5d75bea4bab33c48493638cf8fc1b52732d0cf53278e4f319f98cb6a5",
-            "0x52cc74c26995dc90683f0f97e767177b2ceb30ccb5bc0fd36cb06f889342719",
-            "0xcc0ce9ff5f1f2583ab22ebdb3a970cd4a62dcae45429d31d8d342d532d60c1",
-            "0x114cd1615857484fc5403c586c050b3be3a54260a20a70935749be81a78ccc3",
-            "0x182c9fa7eac92f96e262043e37ddccd62641455b0f72be53c8b92c3a09a7742",
-            "0x45d197019141bbd3cdb2e534b3929116c77f9963482932d3a44ddfcfc990c64",
-            "0x28fdecf482722907fb995e3f626e8ce1053f3c480c3fec50be959063cb88dbf",
-            "0x221d1b34f7f32cbae5abfd26701b408d73617e25b438d8aa5aec241323911d2",
-            "0x59bee037ca5613934d9b51cc9296407a639960dbb6507e40e1575dd6b148e0",
-            "0x3ebf2a6663191b8eacc33c302e43dfb47db9ffa82741aec4b795ba17aa714d",
-            "0x790f8ad0d61fdbfbb5abae53e45d6f1167acef054b4636198a2a5714e356f64",
-            "0x334380a9b387c79a1bb673bcfb503f11a310c54804d93f52527b1ca6bb3261a",
-            "0x7b2f94ca34696bfffd71a9cd5adf47ecce22af7025738eb1512391df8948a91",
-            "0x657af76e2a5d2fc4c08829b88a8d2ddceba6b8107e7cc8f2c9012494671fa90",
-            "0x470507cf36fa5416e7a46430958be0ea15d654d821292f8932228e177edd0cb",
-            "0x116c38e8787004837660dff1fefd9e9feb8cae822348bec11b84a5dbaf67c0c",
-            "0x1626ab0daad9d2693b4786d3f4e4048e6f33641b0de2ea41048ac2fd0edb7c7",
-            "0x3d9f9c7ad535de55d579094ade4669d3eec8ae469b2e0ee8d20f204161f0bed",
-            "0x60d711f4986fe6c6575770ca8282a4848350b9d7d86bb27750c02415b64ffc2",
-            "0x1a157d254b0957f79547f8886deafdf7c0d4d3b93a81a7e3444d9c1a53411c1",
-            "0x634eb9709e787a1989515eb6bb881de37df0517237223ecd39f3cf30bf1d3b2",
-            "0x4a9a238c388534e1079f3b0a4d72043505cb9f916d3a371835e49424440738e",
-            "0x4369143d15c9336b35fbf8bc83ebc2eadfc157b1f762402c4218edee7eac942",
-            "0x6778b966da6bc8ece431e6eea74b3b582e312af52726193d536da9f3aae8fa5",
-            "0x441596b6c3e462b121ebb816933470367abae8768e2eeb243baad9915410e98",
-            "0x47668c8cc4202cb7ad26f83e58c14415d5ea0c28082616f95f630f8928eede0",
-            "0x623b07af4d2460035e87395b2183f175a85b8fb81557383b904461d9a7dbe2a",
-            "0x43dacf2240b2547f73c1f8161b7549305bc4a1c0891530f6e61158b520e7754",
-            "0x50b45743c33745031d53a68543541ef03a31a49bf2a44ab8ad97f94e00db2dd",
-            "0x1d5c5c152ff6d864cc8508742c41b80e6750032b1b4042f94bb093d8b6dc8d9",
-            "0x787bd280a7f9ea9d1ad7847e14abb148757d757706162d6cc19417aace16780",
-            "0x7ff36d1c713012e55c44662faf6aea7dbcc4ed412bc9575bf700821e905b401",
-            "0x38a2015bc24b2d49fa34ea71694331b2bb9b6816c51e74b952745615ec0fdf1",
-            "0x6017d279eef512afffa38341d7ddf37ca7b67e6afbc988be86d38cd28153783",
-            "0x46680d2b0edced686278772ae149aef5df53c015b3c2605c1f2abd88658fea5",
-            "0x5c4f2b1d6d547892cbcf746d92644a77809ec418edffe473466f3e9254b582",
-            "0x7be091f07e6af6130285635ef1794d496034db9d7413820cd241bc9d684c1a9",
-            "0x4e764c122050bb2fda8e3a41c85a24eae031a8a6c1a860b2ca158de0a62956d",
-            "0xaf462e85b46e8d582e4cdc5c616f98f597427239da7684638ddcfadcf2231f",
-            "0x47c9ac4416d13da3826ac274753cd216bda93c76548b557b341d1c14c11490d",
-            "0x253b4111611580963cf488d4e7867ca46be58139306f41212357a9128f425c2",
-            "0x341c6bc6bf683f626f1eaa877fdb39728de0bea7bf20663baa871e1a26e2af2",
-            "0x703c1c5f8233ee0e51461e0a8f5e1c7aa1192fcc23eb622dc54d40ab6090ff7",
-            "0x4e16ca0f93a7f2d72b84f9cc288a52ec9888cd4ec28b845f5b24bbc676f692c",
-            "0x2101da64ef6a076d24f72be291055eaaa7d680547f80dfbbedbcd22ad905a6",
-            "0x6eaf2f49b9d892ff68cbc38adcd777cd923ed58fcc42c91558639a8f63ad899",
-            "0x6d2703bffa2a841a6fa6119fefeaddb627b4b8a5f2a2a05cf1d553eb506f128",
-            "0x3dccd46b84d6655bbea7cb5bf556e863c84358894c88a0036c97a92a2937b84",
-            "0x2d40d918e72ecae39c8496fe17e7784c394f303ca0d3f7b708426ddcbbbde39",
-            "0x5c582cc5e546c5c4bf92b5d7715a618c6c43226cecd1c3a9e9b9e1d01753dd4",
-            "0x425f1209a76ec6e9595a1a30c3cd99b7ca432e7e67493e730ddceaecab8aedf",
-            "0x46c6cd668e4ac058cc45d48d1c70c4516ded3f025d0276e8185a14a9080244f",
-            "0x544834e94ec103ba3850de1cfbe4e9b56e07b13e599ed328619c21b0f7a16bc",
-            "0x7d5e2ec3780d71281fedcca6d08f1d0ed85264c5f1a2ec887fcac9a9d007de",
-            "0x2277fa2169ec97ec808f6bba2c92271ac0fb73b20205ae0676addef2d4c88a7",
-            "0x61d74d052b50379f3112cab0fc83991263ad5c89e326baddf4b228562e41953",
-            "0x316e8bcdc0d8dbf1db4df8d2e63f96bb8ab900597030b7551f0332ff9d2a09c",
-            "0x3b2c6d515af8b542f4c1273da8a3b78001e282c5fac30fafe8389c964bb8531",
-            "0x3e81bb1da8ad64ec5cd3d7f4ce25ec8e963fa162ad25a685f20c9d2a783c472",
-            "0x20f852942e1bf3bccc8e59852582e59bb79b56db056239e17ba29f1e48eac2",
-            "0x4fd06fb2dc9f15df8c5d276942d0f178b826bd113d93e581544a68001e8d250",
-            "0x34a12266c02676d97dbf4bf84447ddbefbacf28625ef94e5c045084fad4fd49",
-            "0x5f8df30e9ffb54518dd8fc9b2f405e4f360c4a1b96346e5ef3819a04519729e",
-            "0x21767ea9a9d982092c4b8c4dde56426ec50cf3e07f9ba5e29b2fa3e5754e102",
-            "0x2d03896bb6ac6252768c4977b7590881a3b4c6d052fb8a8d263df2d25e7625",
-            "0x356b0f96b11f5795990d625d8ea9c6de7d869a2044b7cd50dfe951ac29a6e0e",
-            "0x6ae56f7f8741c8f43ef2f6966133dc0b34d5e8610b8c7dea3da7c89d7c2d2b9",
-            "0x5ffa0f47cc7768c83d204643a7a447580a00fbfad7a12a769bab53febce2895",
-            "0x2051d63f5ce3795e77efa76826f43872619ec46ec6afa7c23f9d6f355b443e0",
-            "0x7e0f8123feae0a258bcda08a38e65e7bb28cdc04f84523b1640c9f54b68ce4b",
-            "0x6c717817eef0504e32ce7a0b4fb18659be9ae9c2aa56ba210468ab2fcaf79cf",
-            "0x5337b52058f81bc3dcdd03993850a58e5911af43cb3536ff4228539070fbdbc",
-            "0x691cb37604424cfb585a6a35a42748479f1748ea0e65b902dd11651c2e85886",
-            "0x59df9036736f235dc89f2261133f598664955a36c8ca22171542d7c2454c3eb",
-            "0x31b7677eba3df8f0a5fc108056cc706cd4a68d233fa1bf9c8c65a05528a4c8c",
-            "0x1f2e1702db19c2fae9e42f329ebd14c0964d9227e20362397f1f47138599561",
-            "0x238b32490ee3ab940f6ca41f921b60d1aa44cb05f225287380703c76ed7b892",
-            "0x4ab9b7217d715a3fe55eceb2a5dace1623a87a6e7118b209c13326824c30935",
-            "0x47ca19eafd3a13ab82ba5557d7b9a2432d5fec617bd659862aed8f7a9141695",
-            "0x10abd80ae93ca1193c6d22d9783565e6297ae5758f5c2a9fed9c979ffd8cb40",
-            "0x386bba816d036566ed7c37de103c04055c7923c6aa36a95c3a04303645e21fa",
-            "0x7a47cef3e67468513e6962ef223e732a1f5191041e837eec773a9fd2f0875c0",
-            "0xbf2262e2b94bb0e73800663b0dc9039cdc1c808cc7ae62ca2e914f598a6958",
-            "0x18413a00414d92091c18b2d984eeaa6020e3d2fc9ff17124a9e82702f294fb8",
-            "0x531e2b4e28c591fa1e8df17ffe646db148b691b89f0bde12e8c465eb7c14c26",
-            "0x5d9bde719bcc9d7f1d2dcd8c80b993c55e55064bebd17ec1484cdbffd9c910f",
-            "0x70716e2187959123507a5a08a3d12a52c26632a74a4814a34346612cbfb626d",
-            "0x31267b2b6dcb35cc23f7f0573a26722a5494f84c5c71d119772b80a0d692175",
-            "0x5dea5c7c620d5bb173a5288c59e4b92f2944c502e73d10c01f933b844a9644b",
-            "0x2cd3c1354b4772d3a5b6020902e388a290475dd6bc35ec3d215dc930f8dc56d",
-            "0x5a233ad19f77821f871ea6d9d716501c9d1db9c4a525b2fc2b36d9aead841a6",
-            "0x4d72e9a46fc082fd230296bd215d4e312bfccc9eb2301bdb486fc7c7d3d99f2",
-            "0x71a9fa23e7973944d33b4556eb3d5c940bc111be9967c06ecddbafcf10de274",
-        ]
-        .iter()
-        .map(|f| Felt::from_hex_unchecked(f))
-        .collect(),
-    )
-}
@@ -9,11 +9,18 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use solana_system_interface::instruction::create_account;
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
 use stark::swiftness::stark::types::cast_struct_to_slice;
-use std::{mem::size_of, path::Path};
+use std::{
+    mem::{offset_of, size_of},
+    path::Path,
+};
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
-use utils::{AccountCast, Executable};
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
+use utils::{AccountCast, Executable, TaskError};
+use verifier::{
+    instruction::{initialize, seal, VerifierInstruction},
+    state::BidirectionalStackAccount,
+};
 
 use stark::stark_proof::stark_commit::FriCommit;
 
@@ -49,7 +56,10 @@ async fn main() -> client::Result<()> {
     );
 
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -60,25 +70,6 @@ async fn main() -> client::Result<()> {
         .await?;
     println!("Account created successfully: {signature}");
 
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
-
-    let init_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::SetAccountData(0, stack_init_bytes.to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let signature = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[init_ix],
-    )
-    .await?;
-    println!("Account initialized: {signature}");
-
     println!("\nFriCommit Task on Solana");
     println!("==================================");
 
@@ -88,21 +79,21 @@ async fn main() -> client::Result<()> {
 
     let mut proof_verifier = proof.transform_to();
 
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
     let proof_bytes = cast_struct_to_slice(&mut proof_verifier).to_vec();
-    let mut init_calldata = stack_init_bytes.to_vec();
-    init_calldata.extend(proof_bytes.clone());
+    let proof_offset = offset_of!(BidirectionalStackAccount, proof);
 
-    println!("Proof bytes in kb: {:?}", init_calldata.len() / 1024);
-    let instructions = init_calldata
+    println!("Proof bytes in kb: {:?}", proof_bytes.len() / 1024);
+    let instructions = proof_bytes
         .chunks(CHUNK_SIZE)
         .enumerate()
         .map(|(i, chunk)| {
             Instruction::new_with_borsh(
                 program_id,
-                &VerifierInstruction::SetAccountData(i * CHUNK_SIZE, chunk.to_vec()),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
+                &VerifierInstruction::SetAccountData(proof_offset + i * CHUNK_SIZE, chunk.to_vec()),
+                vec![
+                    AccountMeta::new(stack_account.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                ],
             )
         })
         .collect::<Vec<_>>();
@@ -121,26 +112,20 @@ async fn main() -> client::Result<()> {
     send_and_confirm_transactions(&client, &transactions).await?;
     println!("Proof data set successfully");
 
-    // Push the FriCommit task to the stack
-    let validate_task = FriCommit::new();
-
-    println!("Using FriCommit with TYPE_TAG: {}", FriCommit::TYPE_TAG);
-
-    let push_task_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushTask(validate_task.to_vec_with_type_tag()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
     let signature = interact_with_program_instructions(
         &client,
         &payer,
         &program_id,
         &stack_account,
-        &[push_task_ix],
+        &[seal(
+            &program_id,
+            &stack_account.pubkey(),
+            &payer.pubkey(),
+            DEFAULT_SECURITY_BITS,
+        )],
     )
     .await?;
-    println!("FriCommit task pushed: {signature}");
+    println!("Proof sealed: {signature}");
 
     let mut account_data = client
         .get_account_data(&stack_account.pubkey())
@@ -148,7 +133,8 @@ async fn main() -> client::Result<()> {
         .map_err(ClientError::SolanaClientError)?;
 
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let simulation_steps = stack.simulate();
+    // The sealed Verify task runs until FriCommit finished
+    let simulation_steps = stack.simulate_until(FriCommit::TYPE_TAG)?;
     println!("Steps in simulation: {simulation_steps}");
 
     let limit_instructions = ComputeBudgetInstruction::set_compute_unit_limit(800_000);
@@ -190,6 +176,18 @@ async fn main() -> client::Result<()> {
     }
 
     println!("All execution steps completed");
+
+    let account_data = client
+        .get_account_data(&stack_account.pubkey())
+        .await
+        .map_err(ClientError::SolanaClientError)?;
+    let stack = BidirectionalStackAccount::cast(&account_data);
+    println!("Verification state: {:?}", stack.header.state);
+    if stack.status.is_failed() {
+        return Err(ClientError::ProofRejected(
+            TaskError::describe(stack.status.error_code).to_string(),
+        ));
+    }
     println!("\nFriCommit successfully executed on Solana!");
 
     Ok(())
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{mem::offset_of, path::Path};
 
 use client::{
     initialize_client, interact_with_program_instructions, send_and_confirm_transactions,
@@ -13,15 +13,15 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use solana_system_interface::instruction::create_account;
-use stark::{
-    stark_proof::VerifyPublicInput,
-    swiftness::stark::types::{cast_struct_to_slice, StarkProof},
-};
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
+use stark::swiftness::stark::types::{cast_struct_to_slice, StarkProof};
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
 use utils::AccountCast;
-use utils::BidirectionalStack;
-use utils::Executable;
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
+use utils::TaskError;
+use verifier::{
+    instruction::{initialize, seal, VerifierInstruction},
+    state::BidirectionalStackAccount,
+};
 
 pub const CHUNK_SIZE: usize = 1000;
 
@@ -60,7 +60,10 @@ async fn main() -> client::Result<()> {
     );
 
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -73,40 +76,31 @@ async fn main() -> client::Result<()> {
     println!("\nSet Proof on Solana");
     println!("====================");
 
-    let mut input: [u64; 2] = [0, 65536];
-    let proof_bytes = cast_struct_to_slice(&mut input);
-    let new_offset = proof_bytes.len();
-    let stack_set_instructions = proof_bytes
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(i * CHUNK_SIZE, chunk.to_vec()),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect::<Vec<_>>();
-
     let input = include_str!("../../example_proof/saya.json");
     let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
     let proof = StarkProofParser::try_from(proof_json).unwrap();
     let mut proof_verifier = proof.transform_to();
 
     let proof_bytes = cast_struct_to_slice(&mut proof_verifier);
+    let proof_offset = offset_of!(BidirectionalStackAccount, proof);
     println!("Proof bytes in kb: {:?}", proof_bytes.len() / 1024);
-    let mut instructions = proof_bytes
+    let instructions = proof_bytes
         .chunks(CHUNK_SIZE)
         .enumerate()
         .map(|(i, chunk)| {
             Instruction::new_with_borsh(
                 program_id,
-                &VerifierInstruction::SetAccountData(new_offset + (i * CHUNK_SIZE), chunk.to_vec()),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
+                &VerifierInstruction::SetAccountData(
+                    proof_offset + (i * CHUNK_SIZE),
+                    chunk.to_vec(),
+                ),
+                vec![
+                    AccountMeta::new(stack_account.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                ],
             )
         })
         .collect::<Vec<_>>();
-    instructions.extend(stack_set_instructions);
 
     println!("Instructions count: {:?}", instructions.len());
     let mut transactions = Vec::new();
@@ -121,23 +115,20 @@ async fn main() -> client::Result<()> {
     }
     send_and_confirm_transactions(&client, &transactions).await?;
 
-    let task = VerifyPublicInput::new();
-
-    let verify_public_input_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushTask(task.to_vec_with_type_tag()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
     let signature = interact_with_program_instructions(
         &client,
         &payer,
         &program_id,
         &stack_account,
-        &[verify_public_input_ix],
+        &[seal(
+            &program_id,
+            &stack_account.pubkey(),
+            &payer.pubkey(),
+            DEFAULT_SECURITY_BITS,
+        )],
     )
     .await?;
-    println!("Verify public input: {signature}");
+    println!("Proof sealed: {signature}");
 
     let limit_instructions = ComputeBudgetInstruction::set_compute_unit_limit(800_000);
 
@@ -146,7 +137,7 @@ async fn main() -> client::Result<()> {
         .await
         .map_err(ClientError::SolanaClientError)?;
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let simulation_steps = stack.simulate();
+    let simulation_steps = stack.simulate()?;
 
     println!("Simulation steps: {simulation_steps}");
 
@@ -170,34 +161,30 @@ async fn main() -> client::Result<()> {
     send_and_confirm_transactions(&client, &transactions).await?;
 
     // Read and display the result
-    let mut account_data = client
+    let account_data = client
         .get_account_data(&stack_account.pubkey())
         .await
         .map_err(ClientError::SolanaClientError)?;
-    let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let result_program_hash = Felt::from_bytes_be_slice(stack.borrow_front());
-    stack.pop_front();
-    let result_output_hash = Felt::from_bytes_be_slice(stack.borrow_front());
-    stack.pop_front();
+    let stack = BidirectionalStackAccount::cast(&account_data);
+    println!("Verification state: {:?}", stack.header.state);
+    let hashes = stack.verified_hashes().ok_or_else(|| {
+        ClientError::ProofRejected(TaskError::describe(stack.status.error_code).to_string())
+    })?;
 
-    println!("\nProgram Hash: {result_program_hash:?}");
+    println!("\nProgram Hash: {:?}", hashes.program_hash);
     assert_eq!(
-        result_program_hash,
+        hashes.program_hash,
         Felt::from_hex_unchecked(
             "0x5ab580b04e3532b6b18f81cfa654a05e29dd8e2352d88df1e765a84072db07"
         )
     );
-    println!("Output Hash: {result_output_hash:?}");
+    println!("Output Hash: {:?}", hashes.output_hash);
     assert_eq!(
-        result_output_hash,
+        hashes.output_hash,
         Felt::from_hex_unchecked(
             "0x3233b5615a8de5563f7d3ba086b8f260189ac47753a1c131d063ed3f6c24400"
         )
     );
-    println!("Stack front index: {}", stack.front_index);
-    assert_eq!(stack.front_index, 0);
-    println!("Stack back index: {}", stack.back_index);
-    assert_eq!(stack.back_index, 65536);
-    println!("\nHash Public Inputs successfully executed on Solana!");
+    println!("\nProof successfully verified on Solana!");
     Ok(())
 }
@@ -12,10 +12,16 @@ use solana_sdk::{
 use solana_system_interface::instruction::create_account;
 use stark::swiftness::stark::types::cast_struct_to_slice;
 use starknet_crypto::{pedersen_hash, poseidon_hash_many, Felt as StarkFelt};
-use std::{mem::size_of, path::Path};
+use std::{
+    mem::{offset_of, size_of},
+    path::Path,
+};
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
 use utils::{AccountCast, BidirectionalStack, Executable};
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
+use verifier::{
+    instruction::{initialize, VerifierInstruction},
+    state::BidirectionalStackAccount,
+};
 
 use stark::stark_proof::get_hash::GetHash;
 
@@ -51,7 +57,10 @@ async fn main() -> client::Result<()> {
     );
 
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -62,25 +71,6 @@ async fn main() -> client::Result<()> {
         .await?;
     println!("Account created successfully: {signature}");
 
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
-
-    let init_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::SetAccountData(0, stack_init_bytes.to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let signature = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[init_ix],
-    )
-    .await?;
-    println!("Account initialized: {signature}");
-
     println!("\nGetHash Task on Solana");
     println!("=====================");
 
@@ -90,21 +80,21 @@ async fn main() -> client::Result<()> {
 
     let mut proof_verifier = proof.transform_to();
 
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
     let proof_bytes = cast_struct_to_slice(&mut proof_verifier).to_vec();
-    let mut init_calldata = stack_init_bytes.to_vec();
-    init_calldata.extend(proof_bytes.clone());
+    let proof_offset = offset_of!(BidirectionalStackAccount, proof);
 
-    println!("Proof bytes in kb: {:?}", init_calldata.len() / 1024);
-    let instructions = init_calldata
+    println!("Proof bytes in kb: {:?}", proof_bytes.len() / 1024);
+    let instructions = proof_bytes
         .chunks(CHUNK_SIZE)
         .enumerate()
         .map(|(i, chunk)| {
             Instruction::new_with_borsh(
                 program_id,
-                &VerifierInstruction::SetAccountData(i * CHUNK_SIZE, chunk.to_vec()),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
+                &VerifierInstruction::SetAccountData(proof_offset + i * CHUNK_SIZE, chunk.to_vec()),
+                vec![
+                    AccountMeta::new(stack_account.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                ],
             )
         })
         .collect::<Vec<_>>();
@@ -136,7 +126,10 @@ async fn main() -> client::Result<()> {
     let push_task_ix = Instruction::new_with_borsh(
         program_id,
         &VerifierInstruction::PushTask(get_hash_task.to_vec_with_type_tag()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
+        vec![
+            AccountMeta::new(stack_account.pubkey(), false),
+            AccountMeta::new_readonly(payer.pubkey(), true),
+        ],
     );
 
     let signature = interact_with_program_instructions(
@@ -155,7 +148,7 @@ async fn main() -> client::Result<()> {
         .map_err(ClientError::SolanaClientError)?;
 
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let simulation_steps = stack.simulate();
+    let simulation_steps = stack.simulate()?;
     println!("Steps in simulation: {simulation_steps}");
 
     let limit_instructions = ComputeBudgetInstruction::set_compute_unit_limit(800_000);
@@ -257,7 +250,7 @@ fn calculate_expected_get_hash(
         StarkFelt::from_bytes_be(&public_input.layout.to_bytes_be()),
     ];
 
-    if let Some(dynamic_params) = &public_input.dynamic_params {
+    if let Some(dynamic_params) = public_input.dynamic_params().unwrap() {
         let dynamic_params_vec: Vec<u32> = (*dynamic_params).into();
         hash_data.extend(dynamic_params_vec.into_iter().map(|x| StarkFelt::from(x)));
     }
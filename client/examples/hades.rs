@@ -10,10 +10,12 @@ use solana_sdk::{
 };
 use solana_system_interface::instruction::create_account;
 use stark::poseidon::hades::HadesPermutation;
-use stark::swiftness::stark::types::cast_struct_to_slice;
 use std::{mem::size_of, path::Path};
 use utils::{AccountCast, BidirectionalStack, Executable};
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
+use verifier::{
+    instruction::{initialize, VerifierInstruction},
+    state::BidirectionalStackAccount,
+};
 
 /// Main entry point for the Solana program client
 #[tokio::main]
@@ -55,7 +57,10 @@ async fn main() -> client::Result<()> {
 
     // Create and send the transaction
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -67,24 +72,6 @@ async fn main() -> client::Result<()> {
     println!("Account created successfully: {signature}");
 
     // Initialize the account
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
-    // Initialize the account
-    let init_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::SetAccountData(0, stack_init_bytes.to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let signature = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[init_ix],
-    )
-    .await?;
-    println!("Account initialized: {signature}");
 
     // Cast to stack account to see if initialized correctly
     let account_data_after_init = client
@@ -115,7 +102,10 @@ async fn main() -> client::Result<()> {
     let push_task_ix = Instruction::new_with_borsh(
         program_id,
         &VerifierInstruction::PushTask(HadesPermutation::new(state).to_vec_with_type_tag()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
+        vec![
+            AccountMeta::new(stack_account.pubkey(), false),
+            AccountMeta::new_readonly(payer.pubkey(), true),
+        ],
     );
 
     let signature = interact_with_program_instructions(
@@ -133,7 +123,7 @@ async fn main() -> client::Result<()> {
         .await
         .map_err(ClientError::SolanaClientError)?;
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let simulation_steps = stack.simulate();
+    let simulation_steps = stack.simulate()?;
     println!("Simulation steps: {simulation_steps}");
     let mut transactions = Vec::new();
     for i in 0..simulation_steps {
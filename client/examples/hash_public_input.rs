@@ -12,10 +12,13 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use solana_system_interface::instruction::create_account;
-use stark::{stark_proof::HashPublicInputs, swiftness::stark::types::cast_struct_to_slice};
+use stark::stark_proof::HashPublicInputs;
 use utils::BidirectionalStack;
 use utils::{AccountCast, Executable};
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
+use verifier::{
+    instruction::{initialize, VerifierInstruction},
+    state::BidirectionalStackAccount,
+};
 
 #[tokio::main]
 #[allow(clippy::result_large_err)]
@@ -47,7 +50,10 @@ async fn main() -> client::Result<()> {
     );
 
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -58,25 +64,6 @@ async fn main() -> client::Result<()> {
         .await?;
     println!("Account created successfully: {signature}");
 
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
-    // Initialize the account
-    let init_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::SetAccountData(0, stack_init_bytes.to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let signature = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[init_ix],
-    )
-    .await?;
-    println!("Account initialized: {signature}");
-
     let account_data_after_init = client
         .get_account_data(&stack_account.pubkey())
         .await
@@ -160,7 +147,10 @@ async fn main() -> client::Result<()> {
             let push_data_ix = Instruction::new_with_borsh(
                 program_id,
                 &VerifierInstruction::PushData(input.to_bytes_be().to_vec()),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
+                vec![
+                    AccountMeta::new(stack_account.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                ],
             );
             let push_data_tx = Transaction::new_signed_with_payer(
                 &[push_data_ix],
@@ -175,7 +165,10 @@ async fn main() -> client::Result<()> {
             let push_data_ix = Instruction::new_with_borsh(
                 program_id,
                 &VerifierInstruction::PushData(Felt::ZERO.to_bytes_be().to_vec()),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
+                vec![
+                    AccountMeta::new(stack_account.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                ],
             );
             let push_data_tx = Transaction::new_signed_with_payer(
                 &[push_data_ix],
@@ -193,7 +186,10 @@ async fn main() -> client::Result<()> {
     let push_task_ix = Instruction::new_with_borsh(
         program_id,
         &VerifierInstruction::PushTask(task.to_vec_with_type_tag()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
+        vec![
+            AccountMeta::new(stack_account.pubkey(), false),
+            AccountMeta::new_readonly(payer.pubkey(), true),
+        ],
     );
 
     let signature = interact_with_program_instructions(
@@ -211,7 +207,7 @@ async fn main() -> client::Result<()> {
         .await
         .map_err(ClientError::SolanaClientError)?;
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let simulation_steps = stack.simulate();
+    let simulation_steps = stack.simulate()?;
     let mut transactions = Vec::new();
     for i in 0..simulation_steps {
         // Execute the task
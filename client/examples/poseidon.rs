@@ -10,10 +10,12 @@ use solana_sdk::{
 };
 use solana_system_interface::instruction::create_account;
 use stark::poseidon::PoseidonHashMany;
-use stark::swiftness::stark::types::cast_struct_to_slice;
 use std::{mem::size_of, path::Path};
 use utils::{AccountCast, BidirectionalStack, Executable};
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
+use verifier::{
+    instruction::{initialize, VerifierInstruction},
+    state::BidirectionalStackAccount,
+};
 
 /// Main entry point for the Solana program client
 #[tokio::main]
@@ -55,7 +57,10 @@ async fn main() -> client::Result<()> {
 
     // Create and send the transaction
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -67,25 +72,6 @@ async fn main() -> client::Result<()> {
     println!("Account created successfully: {signature}");
     // Cast to stack account to see if initialized correctly
 
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
-    // Initialize the account
-    let init_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::SetAccountData(0, stack_init_bytes.to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let signature = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[init_ix],
-    )
-    .await?;
-    println!("Account initialized: {signature}");
-
     let account_data_after_init = client
         .get_account_data(&stack_account.pubkey())
         .await
@@ -129,7 +115,10 @@ async fn main() -> client::Result<()> {
         let push_data_ix = Instruction::new_with_borsh(
             program_id,
             &VerifierInstruction::PushData(input.to_bytes_be().to_vec()),
-            vec![AccountMeta::new(stack_account.pubkey(), false)],
+            vec![
+                AccountMeta::new(stack_account.pubkey(), false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+            ],
         );
 
         let push_data_tx = Transaction::new_signed_with_payer(
@@ -148,7 +137,10 @@ async fn main() -> client::Result<()> {
         let push_data_ix = Instruction::new_with_borsh(
             program_id,
             &VerifierInstruction::PushData(Felt::ZERO.to_bytes_be().to_vec()),
-            vec![AccountMeta::new(stack_account.pubkey(), false)],
+            vec![
+                AccountMeta::new(stack_account.pubkey(), false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+            ],
         );
 
         let push_data_tx = Transaction::new_signed_with_payer(
@@ -168,7 +160,10 @@ async fn main() -> client::Result<()> {
     let push_task_ix = Instruction::new_with_borsh(
         program_id,
         &VerifierInstruction::PushTask(poseidon_task.to_vec_with_type_tag()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
+        vec![
+            AccountMeta::new(stack_account.pubkey(), false),
+            AccountMeta::new_readonly(payer.pubkey(), true),
+        ],
     );
 
     let signature = interact_with_program_instructions(
@@ -187,7 +182,7 @@ async fn main() -> client::Result<()> {
         .map_err(ClientError::SolanaClientError)?;
 
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let simulation_steps = stack.simulate();
+    let simulation_steps = stack.simulate()?;
     println!("Steps in simulation: {simulation_steps}");
     // Execute until task is complete
     let mut transactions = Vec::new();
@@ -10,11 +10,18 @@ use solana_sdk::{
 };
 use solana_system_interface::instruction::create_account;
 use stark::stark_proof::stark_commit::proof_of_work::ProofOfWork;
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
 use stark::swiftness::stark::types::cast_struct_to_slice;
-use std::{mem::size_of, path::Path};
+use std::{
+    mem::{offset_of, size_of},
+    path::Path,
+};
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
-use utils::{AccountCast, Executable};
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
+use utils::{AccountCast, Executable, TaskError};
+use verifier::{
+    instruction::{initialize, seal, VerifierInstruction},
+    state::BidirectionalStackAccount,
+};
 
 pub const CHUNK_SIZE: usize = 1000;
 
@@ -48,7 +55,10 @@ async fn main() -> client::Result<()> {
     );
 
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -68,42 +78,9 @@ async fn main() -> client::Result<()> {
 
     let mut proof_verifier = proof.transform_to();
 
-    // Calculate offsets based on struct layout
-    // front_index: usize (8 bytes)
-    // back_index: usize (8 bytes)
-    // proof: StarkProof (variable size)
-    // buffer: [u8; CAPACITY] (65536 bytes)
-    // autogenerated_pows: [Felt; POWS_SIZE] (134 * 32 = 4288 bytes)
-    // oods_values: [Felt; OODS_VALUES_SIZE] (194 * 32 = 6208 bytes)
-    // domains: [Felt; DOMAINS_SIZE] (31 * 32 = 992 bytes)
-    // global_values: GlobalValues (variable size)
-    // constraint_coefficients: [Felt; N_CONSTRAINTS] (194 * 32 = 6208 bytes)
-    // column_values: [Felt; COLUMN_VALUES_SIZE] (10 * 32 = 320 bytes)
-
-    // Start with stack initialization
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
-
-    // Calculate proof size
     let proof_bytes = cast_struct_to_slice(&mut proof_verifier).to_vec();
-
-    // Calculate offsets
-    let stack_offset = 0;
-    let proof_offset = 16; // front_index + back_index
-
-    // Create instructions for each section
-    let mut instructions = Vec::new();
-
-    // 1. Set stack initialization
-    let stack_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::SetAccountData(stack_offset, stack_init_bytes.to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-    instructions.push(stack_ix);
-
-    // 2. Set proof data
-    let proof_chunks: Vec<_> = proof_bytes
+    let proof_offset = offset_of!(BidirectionalStackAccount, proof);
+    let instructions: Vec<_> = proof_bytes
         .chunks(CHUNK_SIZE)
         .enumerate()
         .map(|(i, chunk)| {
@@ -113,11 +90,13 @@ async fn main() -> client::Result<()> {
                     proof_offset + (i * CHUNK_SIZE),
                     chunk.to_vec(),
                 ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
+                vec![
+                    AccountMeta::new(stack_account.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                ],
             )
         })
         .collect();
-    instructions.extend(proof_chunks);
 
     println!("Total instructions: {}", instructions.len());
 
@@ -135,48 +114,20 @@ async fn main() -> client::Result<()> {
     send_and_confirm_transactions(&client, &transactions).await?;
     println!("All data set successfully");
 
-    // Push the ProofOfWork task to the stack
-    let proof_of_work_task = ProofOfWork::new();
-
-    println!("Using ProofOfWork with TYPE_TAG: {}", ProofOfWork::TYPE_TAG);
-
-    let push_task_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushTask(proof_of_work_task.to_vec_with_type_tag()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let signature = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[push_task_ix],
-    )
-    .await?;
-    println!("ProofOfWork task pushed: {signature}");
-
-    // Push digest to the stack
-    let digest: [u8; 32] = [
-        4, 95, 162, 177, 183, 248, 159, 156, 78, 134, 182, 48, 118, 191, 221, 127, 64, 53, 5, 81,
-        127, 23, 239, 148, 25, 227, 176, 237, 25, 158, 163, 230,
-    ];
-
-    let push_digest_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushData(digest.to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
     let signature = interact_with_program_instructions(
         &client,
         &payer,
         &program_id,
         &stack_account,
-        &[push_digest_ix],
+        &[seal(
+            &program_id,
+            &stack_account.pubkey(),
+            &payer.pubkey(),
+            DEFAULT_SECURITY_BITS,
+        )],
     )
     .await?;
-    println!("Digest pushed to stack: {signature}");
+    println!("Proof sealed: {signature}");
 
     let mut account_data = client
         .get_account_data(&stack_account.pubkey())
@@ -184,7 +135,8 @@ async fn main() -> client::Result<()> {
         .map_err(ClientError::SolanaClientError)?;
 
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let simulation_steps = stack.simulate();
+    // The sealed Verify task runs until ProofOfWork finished
+    let simulation_steps = stack.simulate_until(ProofOfWork::TYPE_TAG)?;
     println!("Steps in simulation: {simulation_steps}");
 
     let limit_instructions = ComputeBudgetInstruction::set_compute_unit_limit(800_000);
@@ -226,6 +178,18 @@ async fn main() -> client::Result<()> {
     }
 
     println!("All execution steps completed");
+
+    let account_data = client
+        .get_account_data(&stack_account.pubkey())
+        .await
+        .map_err(ClientError::SolanaClientError)?;
+    let stack = BidirectionalStackAccount::cast(&account_data);
+    println!("Verification state: {:?}", stack.header.state);
+    if stack.status.is_failed() {
+        return Err(ClientError::ProofRejected(
+            TaskError::describe(stack.status.error_code).to_string(),
+        ));
+    }
     println!("\nProofOfWork successfully executed on Solana!");
 
     Ok(())
@@ -10,11 +10,14 @@ use solana_sdk::{
 };
 use solana_system_interface::instruction::create_account;
 use stark::swiftness::stark::types::{cast_struct_to_slice, StarkProof};
-use std::path::Path;
+use std::{mem::offset_of, path::Path};
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
 use utils::AccountCast;
 use utils::BidirectionalStack;
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
+use verifier::{
+    instruction::{initialize, VerifierInstruction},
+    state::BidirectionalStackAccount,
+};
 
 pub const CHUNK_SIZE: usize = 1000;
 #[repr(C)]
@@ -54,7 +57,10 @@ async fn main() -> client::Result<()> {
     );
 
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -71,21 +77,21 @@ async fn main() -> client::Result<()> {
     let proof = StarkProofParser::try_from(proof_json).unwrap();
 
     let mut proof_verifier = proof.transform_to();
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
     let proof_bytes = cast_struct_to_slice(&mut proof_verifier).to_vec();
-    let mut init_calldata = stack_init_bytes.to_vec();
-    init_calldata.extend(proof_bytes.clone());
+    let proof_offset = offset_of!(BidirectionalStackAccount, proof);
 
-    println!("Proof bytes in kb: {:?}", init_calldata.len() / 1024);
-    let instructions = init_calldata
+    println!("Proof bytes in kb: {:?}", proof_bytes.len() / 1024);
+    let instructions = proof_bytes
         .chunks(CHUNK_SIZE)
         .enumerate()
         .map(|(i, chunk)| {
             Instruction::new_with_borsh(
                 program_id,
-                &VerifierInstruction::SetAccountData(i * CHUNK_SIZE, chunk.to_vec()),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
+                &VerifierInstruction::SetAccountData(proof_offset + i * CHUNK_SIZE, chunk.to_vec()),
+                vec![
+                    AccountMeta::new(stack_account.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                ],
             )
         })
         .collect::<Vec<_>>();
@@ -10,15 +10,20 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use solana_system_interface::instruction::create_account;
+use stark::stark_proof::stark_commit::StarkCommit;
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
+use stark::stark_proof::verify::Verify;
 use stark::swiftness::stark::types::cast_struct_to_slice;
-use stark::{stark_proof::stark_commit::StarkCommit, swiftness::stark::types::StarkCommitment};
-use std::{mem::size_of, path::Path};
+use std::{
+    mem::{offset_of, size_of},
+    path::Path,
+};
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
-use utils::{
-    global_values::{GlobalValues, InteractionElements},
-    AccountCast, Executable,
+use utils::{AccountCast, Executable, TaskError};
+use verifier::{
+    instruction::{initialize, seal, VerifierInstruction},
+    state::BidirectionalStackAccount,
 };
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
 
 pub const CHUNK_SIZE: usize = 1000;
 
@@ -52,7 +57,10 @@ async fn main() -> client::Result<()> {
     );
 
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -72,189 +80,9 @@ async fn main() -> client::Result<()> {
 
     let mut proof_verifier = proof.transform_to();
 
-    // Calculate memory layout and offsets for BidirectionalStackAccount
-    // The account structure is:
-    // [front_index: usize (8 bytes), back_index: usize (8 bytes)]
-    // [proof: StarkProof (variable size)]
-    // [buffer: [u8; CAPACITY] (65536 bytes)]
-    // [autogenerated_pows: [Felt; POWS_SIZE] (134 * 32 = 4288 bytes)]
-    // [oods_values: [Felt; OODS_VALUES_SIZE] (194 * 32 = 6208 bytes)]
-    // [domains: [Felt; DOMAINS_SIZE] (31 * 32 = 992 bytes)]
-    // [global_values: GlobalValues (variable size)]
-    // [constraint_coefficients: [Felt; N_CONSTRAINTS] (194 * 32 = 6208 bytes)]
-    // [column_values: [Felt; COLUMN_VALUES_SIZE] (10 * 32 = 320 bytes)]
-    // [stark_commitment: StarkCommitment<InteractionElements> (variable size)]
-
-    let proof_bytes = cast_struct_to_slice(&mut proof_verifier).to_vec();
-    let proof_size = proof_bytes.len();
-    let global_values_size = std::mem::size_of::<GlobalValues>();
-    let stark_commitment_size = std::mem::size_of::<StarkCommitment<InteractionElements>>();
-
-    println!("Memory layout calculation:");
-    println!("  Proof size: {} bytes", proof_size);
-    println!("  Global values size: {} bytes", global_values_size);
-    println!("  Stark commitment size: {} bytes", stark_commitment_size);
-
-    // Calculate offsets for each section in the account
-    let stack_offset = 0; // Start of account
-    let proof_offset = 16; // After front_index (8) + back_index (8)
-    let buffer_offset = proof_offset + proof_size; // After proof data
-    let autogenerated_pows_offset = buffer_offset + 65536; // After buffer (CAPACITY = 65536)
-    let oods_values_offset = autogenerated_pows_offset + 4288; // After autogenerated_pows (POWS_SIZE * 32 = 134 * 32)
-    let domains_offset = oods_values_offset + 6208; // After oods_values (OODS_VALUES_SIZE * 32 = 194 * 32)
-    let global_values_offset = domains_offset + 992; // After domains (DOMAINS_SIZE * 32 = 31 * 32)
-    let constraint_coefficients_offset = global_values_offset + global_values_size; // After global_values
-    let column_values_offset = constraint_coefficients_offset + 6208; // After constraint_coefficients (N_CONSTRAINTS * 32 = 194 * 32)
-    let stark_commitment_offset = column_values_offset + 320; // After column_values (COLUMN_VALUES_SIZE * 32 = 10 * 32)
-
-    println!(
-        "  Total account size: {} bytes",
-        stark_commitment_offset + stark_commitment_size
-    );
-    println!("  Preallocated space breakdown:");
-    println!("    Stack indices: {} bytes", proof_offset - stack_offset);
-    println!("    Proof data: {} bytes", proof_size);
-    println!("    Buffer: {} bytes", 65536);
-    println!("    Autogenerated powers: {} bytes", 4288);
-    println!("    OODS values: {} bytes", 6208);
-    println!("    Domains: {} bytes", 992);
-    println!("    Global values: {} bytes", global_values_size);
-    println!("    Constraint coefficients: {} bytes", 6208);
-    println!("    Column values: {} bytes", 320);
-    println!("    Stark commitment: {} bytes", stark_commitment_size);
-
-    // Start with stack initialization
-    let mut stack_init_input: [u64; 2] = [0, 65536];
-    let stack_init_bytes = cast_struct_to_slice(&mut stack_init_input);
-
-    // Create instructions for each section
-    let mut instructions = Vec::new();
-
-    // 1. Initialize stack indices (front_index = 0, back_index = 65536)
-    let stack_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::SetAccountData(stack_offset, stack_init_bytes.to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-    instructions.push(stack_ix);
-
-    // 2. Initialize buffer with zeros (65536 bytes) - will be filled during push/pop operations
-    let buffer_size = 65536; // CAPACITY - preallocated space for stack operations
-    let empty_buffer = vec![0u8; buffer_size];
-    let buffer_chunks: Vec<_> = empty_buffer
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    buffer_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(buffer_chunks);
-
-    // 3. Set proof data (moved to after OODS values setup)
-
-    // 4. Initialize global values with zeros - will be computed and set during task execution
-    let empty_global_values = vec![0u8; global_values_size];
-    let global_chunks: Vec<_> = empty_global_values
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    global_values_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(global_chunks);
-
-    // 5. Initialize constraint coefficients with zeros (194 * 32 = 6208 bytes) - will be computed during execution
-    let constraint_coefficients_size = 6208; // N_CONSTRAINTS * 32 = 194 * 32 bytes
-    let empty_constraint_coefficients = vec![0u8; constraint_coefficients_size];
-    let constraint_coefficients_chunks: Vec<_> = empty_constraint_coefficients
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    constraint_coefficients_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(constraint_coefficients_chunks);
-
-    // 6. Initialize column values with zeros (10 * 32 = 320 bytes) - will be computed during execution
-    let column_values_size = 320; // COLUMN_VALUES_SIZE * 32 = 10 * 32 bytes
-    let empty_column_values = vec![0u8; column_values_size];
-    let column_values_chunks: Vec<_> = empty_column_values
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    column_values_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(column_values_chunks);
-
-    // 7. Initialize stark commitment with zeros - will be filled with computed commitments during execution
-    let empty_stark_commitment = vec![0u8; stark_commitment_size];
-    let stark_commitment_chunks: Vec<_> = empty_stark_commitment
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    stark_commitment_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(stark_commitment_chunks);
-
-    // 8. Set OODS values from proof data (194 * 32 = 6208 bytes) - these are precomputed values needed for verification
-    let oods_values = proof_verifier.unsent_commitment.oods_values.clone();
-    let oods_values_bytes = cast_struct_to_slice(&mut oods_values.clone()).to_vec();
-    let oods_values_chunks: Vec<_> = oods_values_bytes
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    oods_values_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(oods_values_chunks);
-
-    // 9. Set proof data - the actual STARK proof that will be verified
     let proof_bytes = cast_struct_to_slice(&mut proof_verifier).to_vec();
-    let proof_chunks: Vec<_> = proof_bytes
+    let proof_offset = offset_of!(BidirectionalStackAccount, proof);
+    let instructions: Vec<_> = proof_bytes
         .chunks(CHUNK_SIZE)
         .enumerate()
         .map(|(i, chunk)| {
@@ -264,70 +92,15 @@ async fn main() -> client::Result<()> {
                     proof_offset + (i * CHUNK_SIZE),
                     chunk.to_vec(),
                 ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(proof_chunks);
-
-    // 10. Initialize autogenerated_pows with zeros (134 * 32 = 4288 bytes) - will be computed during task execution
-    let autogenerated_pows_size = 4288; // POWS_SIZE * 32 = 134 * 32 bytes
-    let empty_autogenerated_pows = vec![0u8; autogenerated_pows_size];
-    let autogenerated_pows_chunks: Vec<_> = empty_autogenerated_pows
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    autogenerated_pows_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect();
-    instructions.extend(autogenerated_pows_chunks);
-
-    // 11. Initialize domains with zeros (31 * 32 = 992 bytes) - will be computed during task execution
-    let domains_size = 992; // DOMAINS_SIZE * 32 = 31 * 32 bytes
-    let empty_domains = vec![0u8; domains_size];
-    let domains_chunks: Vec<_> = empty_domains
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(
-                    domains_offset + (i * CHUNK_SIZE),
-                    chunk.to_vec(),
-                ),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
+                vec![
+                    AccountMeta::new(stack_account.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                ],
             )
         })
         .collect();
-    instructions.extend(domains_chunks);
 
     println!("Total instructions: {}", instructions.len());
-    println!("\nAccount initialization summary:");
-    println!("  ✓ Stack indices initialized (front_index=0, back_index=65536)");
-    println!("  ✓ Buffer preallocated (65536 bytes) for stack operations");
-    println!("  ✓ All computation arrays initialized with zeros:");
-    println!("    - autogenerated_pows (4288 bytes) - will be computed during execution");
-    println!("    - domains (992 bytes) - will be computed during execution");
-    println!(
-        "    - global_values ({} bytes) - will be computed during execution",
-        global_values_size
-    );
-    println!("    - constraint_coefficients (6208 bytes) - will be computed during execution");
-    println!("    - column_values (320 bytes) - will be computed during execution");
-    println!(
-        "    - stark_commitment ({} bytes) - will be computed during execution",
-        stark_commitment_size
-    );
-    println!("  ✓ Proof data set from JSON file");
-    println!("  ✓ OODS values set from proof data");
-    println!("  ✓ Account ready for StarkCommit task execution");
 
     // Send transactions
     let mut transactions = Vec::new();
@@ -343,93 +116,20 @@ async fn main() -> client::Result<()> {
     send_and_confirm_transactions(&client, &transactions).await?;
     println!("All data set successfully");
 
-    // Push the StarkCommit task to the stack
-    let stark_commit_task = StarkCommit::new();
-
-    println!("Using StarkCommit with TYPE_TAG: {}", StarkCommit::TYPE_TAG);
-
-    let push_task_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushTask(stark_commit_task.to_vec_with_type_tag()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let _ = interact_with_program_instructions(
+    let signature = interact_with_program_instructions(
         &client,
         &payer,
         &program_id,
         &stack_account,
-        &[push_task_ix],
-    )
-    .await?;
-
-    let trace_generator =
-        Felt::from_hex("0x57a797181c06d8427145cb66056f032751615d8617c5468258e96d2bb6422f9")
-            .unwrap();
-    let push_trace_generator_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushData(trace_generator.to_bytes_be().to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let _ = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[push_trace_generator_ix],
-    )
-    .await?;
-
-    let trace_domain_size = Felt::from_hex("0x10000000").unwrap();
-    let push_trace_domain_size_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushData(trace_domain_size.to_bytes_be().to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let _ = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[push_trace_domain_size_ix],
-    )
-    .await?;
-
-    let digest =
-        Felt::from_hex("0x59496b8e649ff03c8e9f739e141bd82653fccb2fb1b1a51a71760ea3813ea35")
-            .unwrap();
-    let push_digest_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushData(digest.to_bytes_be().to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let _ = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[push_digest_ix],
-    )
-    .await?;
-
-    let counter = Felt::from_hex("0x0").unwrap();
-    let push_counter_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushData(counter.to_bytes_be().to_vec()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
-
-    let _ = interact_with_program_instructions(
-        &client,
-        &payer,
-        &program_id,
-        &stack_account,
-        &[push_counter_ix],
+        &[seal(
+            &program_id,
+            &stack_account.pubkey(),
+            &payer.pubkey(),
+            DEFAULT_SECURITY_BITS,
+        )],
     )
     .await?;
+    println!("Proof sealed: {signature}");
 
     let mut account_data = client
         .get_account_data(&stack_account.pubkey())
@@ -437,7 +137,8 @@ async fn main() -> client::Result<()> {
         .map_err(ClientError::SolanaClientError)?;
 
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let simulation_steps = stack.simulate();
+    // The sealed Verify task runs until StarkCommit finished
+    let simulation_steps = stack.simulate_until(StarkCommit::TYPE_TAG)?;
     println!("Steps in simulation: {simulation_steps}");
 
     let limit_instructions = ComputeBudgetInstruction::set_compute_unit_limit(800_000);
@@ -488,6 +189,12 @@ async fn main() -> client::Result<()> {
         .map_err(ClientError::SolanaClientError)?;
 
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
+    println!("Verification state: {:?}", stack.header.state);
+    if stack.status.is_failed() {
+        return Err(ClientError::ProofRejected(
+            TaskError::describe(stack.status.error_code).to_string(),
+        ));
+    }
 
     // Get the computed stark_commitment from the account
     let computed_stark_commitment = &stack.stark_commitment;
@@ -620,21 +327,29 @@ async fn main() -> client::Result<()> {
 
     // Verify OODS values match the input
     assert_eq!(
-        computed_stark_commitment.oods_values, stack.oods_values,
+        computed_stark_commitment.oods_values.as_slice(),
+        &stack.oods_values[..computed_stark_commitment.oods_values.len()],
         "OODS values mismatch"
     );
 
     assert_eq!(
-        computed_stark_commitment.fri.last_layer_coefficients, expected_last_layer_coeffs,
+        computed_stark_commitment
+            .fri
+            .last_layer_coefficients
+            .as_slice(),
+        expected_last_layer_coeffs.as_slice(),
         "FRI last layer coefficients mismatch"
     );
 
-    // Check that stack is empty (task completed successfully)
-    assert_eq!(stack.front_index, 0, "Stack should be empty");
-    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+    // Only the sealed Verify task is left, to go on with StarkVerify
+    assert_eq!(
+        stack.next_type_tag(),
+        Some(Verify::TYPE_TAG),
+        "Verify should be next"
+    );
 
     println!("✓ All verifications passed! Results match expected values from stark_commitment.rs");
-    println!("✓ Stack is empty - task completed successfully");
+    println!("✓ Verify task is left on the stack - StarkCommit completed successfully");
     println!("✓ StarkCommit test completed successfully on Solana!");
 
     Ok(())
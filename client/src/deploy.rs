@@ -5,7 +5,7 @@ use solana_sdk::{
     signer::Signer,
     transaction::Transaction,
 };
-use verifier::state::BidirectionalStackAccount;
+use verifier::{instruction::initialize, state::BidirectionalStackAccount};
 
 use crate::{initialize_client, setup_payer, setup_program, Config, Result};
 use log::info;
@@ -43,7 +43,10 @@ pub async fn deploy(config: &Config) -> Result<()> {
     );
 
     let create_account_tx = Transaction::new_signed_with_payer(
-        &[create_account_ix],
+        &[
+            create_account_ix,
+            initialize(&program_id, &stack_account.pubkey(), &payer.pubkey()),
+        ],
         Some(&payer.pubkey()),
         &[&payer, &stack_account],
         client.get_latest_blockhash().await?,
@@ -33,6 +33,12 @@ pub enum ClientError {
 
     #[error("Serialization error: {0}")]
     SerializationError(String),
+
+    #[error("Proof rejected: {0}")]
+    ProofRejected(String),
+
+    #[error("Task error: {0}")]
+    TaskError(#[from] utils::TaskError),
 }
 
 pub type Result<T> = std::result::Result<T, ClientError>;
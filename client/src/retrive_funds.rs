@@ -1,12 +1,10 @@
 use solana_sdk::{
-    instruction::{AccountMeta, Instruction},
     native_token::LAMPORTS_PER_SOL,
     signature::Keypair,
     signer::{EncodableKey, Signer},
     transaction::Transaction,
 };
-use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
-use verifier::instruction::VerifierInstruction;
+use verifier::instruction::close;
 
 use crate::{initialize_client, setup_payer, Config, Result};
 use log::info;
@@ -32,20 +30,13 @@ pub async fn retrive_funds(config: &Config) -> Result<()> {
     let balance_sol = balance as f64 / LAMPORTS_PER_SOL as f64;
     info!(balance_sol:% = balance_sol; "Balance");
 
-    let close_account_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::Close,
-        vec![
-            AccountMeta::new(stack_account.pubkey(), true),
-            AccountMeta::new(payer.pubkey(), false),
-            AccountMeta::new(SYSTEM_PROGRAM_ID, false),
-        ],
-    );
+    // The payer is the authority of the account, set when it was deployed
+    let close_account_ix = close(&program_id, &stack_account.pubkey(), &payer.pubkey());
 
     let close_account_tx = Transaction::new_signed_with_payer(
         &[close_account_ix],
         Some(&payer.pubkey()),
-        &[&stack_account, &payer],
+        &[&payer],
         client.get_latest_blockhash().await?,
     );
     let close_account_signature = client
@@ -3,19 +3,26 @@ use crate::{
     setup_payer, ClientError,
 };
 use crate::{read_keypair_file, Config, Result};
-use felt::Felt;
 use log::info;
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     signature::Keypair,
     signer::Signer,
 };
-use stark::{stark_proof::VerifyPublicInput, swiftness::stark::types::cast_struct_to_slice};
+use stark::{
+    stark_proof::bootloader::{decode_bootloader_output, output_values, BootloaderOutputError},
+    stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS,
+    swiftness::{air::public_memory::PublicInput, stark::types::cast_struct_to_slice},
+};
+use std::mem::offset_of;
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
 use utils::AccountCast;
-use utils::BidirectionalStack;
-use utils::Executable;
-use verifier::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
+use utils::TaskError;
+use verifier::{
+    fact::{compute_fact, find_fact_address},
+    instruction::{register_fact, reset, seal, VerifierInstruction},
+    state::{BidirectionalStackAccount, VerificationState},
+};
 
 pub const CHUNK_SIZE: usize = 900;
 
@@ -35,70 +42,76 @@ pub async fn verify(config: &Config) -> Result<()> {
     let stack_account = read_keypair_file("keypairs/stack-account-keypair.json").unwrap();
     info!(public_key:% = stack_account.pubkey(); "Using stack account");
 
-    let time = std::time::Instant::now();
-    let input: [u64; 2] = [0, 65536];
-    let proof_bytes = cast_struct_to_slice(&input);
-    let new_offset = proof_bytes.len();
-    let stack_set_instructions = proof_bytes
-        .chunks(CHUNK_SIZE)
-        .enumerate()
-        .map(|(i, chunk)| {
-            Instruction::new_with_borsh(
-                program_id,
-                &VerifierInstruction::SetAccountData(i * CHUNK_SIZE, chunk.to_vec()),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
-            )
-        })
-        .collect::<Vec<_>>();
+    // Start from an empty account, it may hold a previous verification
+    let account_data = client
+        .get_account_data(&stack_account.pubkey())
+        .await
+        .map_err(ClientError::SolanaClientError)?;
+    if BidirectionalStackAccount::cast(&account_data).header.state != VerificationState::Uploading {
+        let signature = interact_with_program_instructions(
+            &client,
+            &payer,
+            &program_id,
+            &stack_account,
+            &[reset(&program_id, &stack_account.pubkey(), &payer.pubkey())],
+        )
+        .await?;
+        info!(signature:% = signature; "Reset");
+    }
 
+    let time = std::time::Instant::now();
     let input = include_str!("../../example_proof/saya.json");
     let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
     let proof = StarkProofParser::try_from(proof_json).unwrap();
 
     let proof_verifier = proof.transform_to();
     let proof_bytes = cast_struct_to_slice(&proof_verifier);
+    let proof_offset = offset_of!(BidirectionalStackAccount, proof);
 
     info!(size_in_bytes:% = proof_bytes.len() / 1024; "Proof bytes in kb");
-    let mut proof_set_instructions = proof_bytes
+    let proof_set_instructions = proof_bytes
         .chunks(CHUNK_SIZE)
         .enumerate()
         .map(|(i, chunk)| {
             Instruction::new_with_borsh(
                 program_id,
-                &VerifierInstruction::SetAccountData(new_offset + (i * CHUNK_SIZE), chunk.to_vec()),
-                vec![AccountMeta::new(stack_account.pubkey(), false)],
+                &VerifierInstruction::SetAccountData(
+                    proof_offset + (i * CHUNK_SIZE),
+                    chunk.to_vec(),
+                ),
+                vec![
+                    AccountMeta::new(stack_account.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                ],
             )
         })
         .collect::<Vec<_>>();
-    proof_set_instructions.extend(stack_set_instructions);
     info!(instructions_number:% = proof_set_instructions.len(); "Instructions number");
     send_and_confirm_with_limit(&client, &proof_set_instructions, &payer, 1_000).await?;
     info!(time_in_seconds:% = time.elapsed().as_secs(); "Time taken to set proof");
     let time2 = std::time::Instant::now();
-    let task = VerifyPublicInput::new();
-
-    let verify_public_input_ix = Instruction::new_with_borsh(
-        program_id,
-        &VerifierInstruction::PushTask(task.to_vec_with_type_tag()),
-        vec![AccountMeta::new(stack_account.pubkey(), false)],
-    );
 
     let signature = interact_with_program_instructions(
         &client,
         &payer,
         &program_id,
         &stack_account,
-        &[verify_public_input_ix],
+        &[seal(
+            &program_id,
+            &stack_account.pubkey(),
+            &payer.pubkey(),
+            DEFAULT_SECURITY_BITS,
+        )],
     )
     .await?;
-    info!(signature:% = signature; "Verify public input");
+    info!(signature:% = signature; "Proof sealed");
 
     let mut account_data = client
         .get_account_data(&stack_account.pubkey())
         .await
         .map_err(ClientError::SolanaClientError)?;
     let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let simulation_steps = stack.simulate();
+    let simulation_steps = stack.simulate()?;
 
     info!(simulation_steps:% = simulation_steps; "Simulation steps");
 
@@ -117,20 +130,66 @@ pub async fn verify(config: &Config) -> Result<()> {
 
     info!(time_in_seconds:% = time2.elapsed().as_secs(); "Time taken to execute");
     // Read and display the result
-    let mut account_data = client
+    let account_data = client
         .get_account_data(&stack_account.pubkey())
         .await
         .map_err(ClientError::SolanaClientError)?;
-    let stack = BidirectionalStackAccount::cast_mut(&mut account_data);
-    let result_program_hash = Felt::from_bytes_be_slice(stack.borrow_front());
-    stack.pop_front();
-    let result_output_hash = Felt::from_bytes_be_slice(stack.borrow_front());
-    stack.pop_front();
-    info!(result_program_hash:% = result_program_hash; "Program Hash");
-    info!(result_output_hash:% = result_output_hash; "Output Hash");
+    let stack = BidirectionalStackAccount::cast(&account_data);
+    let status = stack.status;
+    if status.is_failed() {
+        let reason = TaskError::describe(status.error_code);
+        info!(step:% = status.steps, type_tag:% = status.type_tag, reason:% = reason; "Proof rejected");
+        return Err(ClientError::ProofRejected(reason.to_string()));
+    }
+    let Some(hashes) = stack.verified_hashes() else {
+        return Err(ClientError::TransactionError(format!(
+            "Verification stopped in state {:?}",
+            stack.header.state
+        )));
+    };
+    info!(result_program_hash:% = hashes.program_hash; "Program Hash");
+    info!(result_output_hash:% = hashes.output_hash; "Output Hash");
+    info!(result_fact_hash:% = hashes.fact_hash; "Integrity Fact Hash");
+    info!(result_verification_hash:% = hashes.verification_hash; "Integrity Verification Hash");
+    if let Err(e) = log_bootloader_output(&stack.proof.public_input) {
+        info!(reason:% = e; "Output is not a simple_bootloader output");
+    }
     info!(front_index:% = stack.front_index; "Stack front index");
     info!(back_index:% = stack.back_index; "Stack back index");
 
-    info!("Hash Public Inputs successfully executed on Solana!");
+    // Record the verified fact on-chain
+    let program_hash = hashes.program_hash.to_bytes_be();
+    let output_hash = hashes.output_hash.to_bytes_be();
+    let (fact_address, _) =
+        find_fact_address(&program_id, &compute_fact(&program_hash, &output_hash));
+    let register_fact_ix = register_fact(
+        &program_id,
+        &stack_account.pubkey(),
+        &payer.pubkey(),
+        &program_hash,
+        &output_hash,
+    );
+    send_and_confirm_with_limit(&client, &[register_fact_ix], &payer, 200_000).await?;
+    info!(fact_account:% = fact_address; "Fact registered");
+
+    info!("Proof successfully verified on Solana!");
+    Ok(())
+}
+
+// Logs the program hash and the output of each task of a simple_bootloader run.
+fn log_bootloader_output(
+    public_input: &PublicInput,
+) -> std::result::Result<(), BootloaderOutputError> {
+    let output = output_values(public_input)?;
+    let bootloader_output = decode_bootloader_output(&output)?;
+    info!(n_tasks:% = bootloader_output.tasks.len(); "Bootloader tasks");
+    for (task, task_output) in bootloader_output.tasks.iter().enumerate() {
+        info!(
+            task:% = task,
+            program_hash:% = task_output.program_hash,
+            output:? = task_output.output;
+            "Bootloader task"
+        );
+    }
     Ok(())
 }
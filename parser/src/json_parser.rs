@@ -6,6 +6,7 @@ use crate::{
 };
 use num_bigint::BigUint;
 use serde::Deserialize;
+use stark::swiftness::air::dynamic::DYNAMIC_PARAMS_NAMES;
 use starknet_crypto::{pedersen_hash, Felt};
 use std::{
     collections::{BTreeMap, HashMap},
@@ -17,6 +18,8 @@ pub struct StarkProof {
     proof_parameters: ProofParameters,
     annotations: Vec<String>,
     public_input: PublicInput,
+    #[serde(default)]
+    verifier_config: VerifierConfigInput,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
@@ -24,6 +27,20 @@ pub struct ProofParameters {
     pub stark: Stark,
     #[serde(default)]
     pub n_verifier_friendly_commitment_layers: u32,
+    #[serde(default)]
+    pub commitment_hash: Option<String>,
+    #[serde(default)]
+    pub pow_hash: Option<String>,
+}
+
+// Settings of the verifier that the prover does not record in the proof: the Stone version and
+// the hash function of the program and output hashes, which depends on the program.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct VerifierConfigInput {
+    #[serde(default)]
+    pub stone_version: StoneVersion,
+    #[serde(default)]
+    pub program_hash_function: HashFunction,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
@@ -171,6 +188,38 @@ impl StarkProof {
         }
         Ok(layer_log_sizes)
     }
+    fn verifier_config(&self) -> anyhow::Result<VerifierConfig> {
+        let parameters = &self.proof_parameters;
+        let commitment_hasher = parameters
+            .commitment_hash
+            .as_deref()
+            .map(|name| match name {
+                "keccak256_masked160_lsb" => Ok(Hasher::Keccak256),
+                "blake256_masked160_lsb" => Ok(Hasher::Blake2s256),
+                _ => Err(anyhow::anyhow!("Unsupported commitment hash {}", name)),
+            })
+            .transpose()?;
+        let pow_hasher = parameters
+            .pow_hash
+            .as_deref()
+            .map(|name| match name {
+                "keccak256" => Ok(Hasher::Keccak256),
+                "blake256" => Ok(Hasher::Blake2s256),
+                _ => Err(anyhow::anyhow!("Unsupported proof of work hash {}", name)),
+            })
+            .transpose()?;
+        let hasher = match (commitment_hasher, pow_hasher) {
+            (Some(commitment), Some(pow)) if commitment != pow => {
+                anyhow::bail!("Commitment and proof of work hashes differ")
+            }
+            (commitment, pow) => commitment.or(pow).unwrap_or_default(),
+        };
+        Ok(VerifierConfig {
+            stone_version: self.verifier_config.stone_version,
+            program_hash_function: self.verifier_config.program_hash_function,
+            hasher,
+        })
+    }
     fn public_input(
         public_input: PublicInput,
         z: BigUint,
@@ -178,6 +227,7 @@ impl StarkProof {
     ) -> anyhow::Result<stark_proof::PublicInput> {
         let continuous_page_headers =
             Self::continuous_page_headers(&public_input.public_memory, z, alpha);
+        let continuous_page_values = Self::continuous_page_values(&public_input.public_memory)?;
         let main_page = Self::main_page(&public_input.public_memory)?;
         let dynamic_params = public_input.dynamic_params.unwrap_or_default();
         let memory_segments = Builtin::sort_segments(public_input.memory_segments)
@@ -220,8 +270,46 @@ impl StarkProof {
                     ]
                 })
                 .collect::<Vec<BigUint>>(),
+            continuous_page_values,
         })
     }
+    // The dynamic layout needs every parameter of DynamicParams, and no other layout takes any.
+    pub fn validate_dynamic_params(
+        layout: &Layout,
+        dynamic_params: &Option<BTreeMap<String, u32>>,
+    ) -> anyhow::Result<()> {
+        let Some(dynamic_params) = dynamic_params else {
+            if *layout == Layout::Dynamic {
+                anyhow::bail!("Missing dynamic params for the dynamic layout");
+            }
+            return Ok(());
+        };
+        if *layout != Layout::Dynamic {
+            anyhow::bail!("Dynamic params are only allowed for the dynamic layout");
+        }
+        if let Some(name) = DYNAMIC_PARAMS_NAMES
+            .iter()
+            .find(|name| !dynamic_params.contains_key(**name))
+        {
+            anyhow::bail!("Missing dynamic param {}", name);
+        }
+        if let Some(name) = dynamic_params
+            .keys()
+            .find(|name| !DYNAMIC_PARAMS_NAMES.contains(&name.as_str()))
+        {
+            anyhow::bail!("Unknown dynamic param {}", name);
+        }
+        for name in [
+            "cpu_component_step",
+            "num_columns_first",
+            "num_columns_second",
+        ] {
+            if dynamic_params[name] == 0 {
+                anyhow::bail!("Invalid dynamic param {}", name);
+            }
+        }
+        Ok(())
+    }
     fn main_page(public_memory: &[PublicMemoryElement]) -> anyhow::Result<Vec<PubilcMemoryCell>> {
         public_memory
             .iter()
@@ -235,6 +323,18 @@ impl StarkProof {
             })
             .collect::<anyhow::Result<Vec<_>>>()
     }
+    // Values of the continuous pages, page after page, in the order of their headers.
+    fn continuous_page_values(
+        public_memory: &[PublicMemoryElement],
+    ) -> anyhow::Result<Vec<BigUint>> {
+        let mut pages: Vec<&PublicMemoryElement> =
+            public_memory.iter().filter(|m| m.page != 0).collect();
+        pages.sort_by_key(|m| m.page);
+        pages
+            .into_iter()
+            .map(|m| BigUint::from_str_hex(&m.value).ok_or(anyhow::anyhow!("Invalid memory value")))
+            .collect()
+    }
     pub fn continuous_page_headers(
         public_memory: &[PublicMemoryElement],
         z: BigUint,
@@ -399,6 +499,10 @@ impl StarkProof {
 impl TryFrom<StarkProof> for stark_proof::StarkProof {
     type Error = anyhow::Error;
     fn try_from(value: StarkProof) -> anyhow::Result<Self> {
+        StarkProof::validate_dynamic_params(
+            &value.public_input.layout,
+            &value.public_input.dynamic_params,
+        )?;
         let config = value.stark_config()?;
 
         let annotations = Annotations::new(
@@ -418,6 +522,7 @@ impl TryFrom<StarkProof> for stark_proof::StarkProof {
         let witness = value.stark_witness(&annotations);
 
         Ok(stark_proof::StarkProof {
+            verifier_config: value.verifier_config()?,
             config,
             public_input,
             unsent_commitment,
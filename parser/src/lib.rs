@@ -14,6 +14,8 @@ pub fn parse<I: AsRef<str>>(input: I) -> anyhow::Result<stark_proof::StarkProof>
 #[cfg(test)]
 mod tests {
     use crate::transform::TransformTo;
+    use stark::swiftness::air::dynamic::DYNAMIC_PARAMS_NAMES;
+    use stark::swiftness::stark::config as verifier_config;
 
     use super::*;
 
@@ -25,4 +27,152 @@ mod tests {
 
         let _ = proof.transform_to();
     }
+
+    fn parse_with_public_input(
+        layout: &str,
+        dynamic_params: Option<serde_json::Value>,
+    ) -> anyhow::Result<StarkProof> {
+        let input = include_str!("../../example_proof/saya.json");
+        let mut value: serde_json::Value = serde_json::from_str(input).unwrap();
+        value["public_input"]["layout"] = layout.into();
+        if let Some(dynamic_params) = dynamic_params {
+            value["public_input"]["dynamic_params"] = dynamic_params;
+        }
+        let proof_json = serde_json::from_value::<json_parser::StarkProof>(value).unwrap();
+        StarkProof::try_from(proof_json)
+    }
+
+    fn all_dynamic_params() -> serde_json::Map<String, serde_json::Value> {
+        DYNAMIC_PARAMS_NAMES
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.to_string(), (i as u32 + 1).into()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_dynamic_params_field_order() {
+        let mut params = all_dynamic_params();
+        params.insert("cpu_component_step".to_string(), 1.into());
+        let proof = parse_with_public_input("dynamic", Some(params.clone().into())).unwrap();
+        let public_input = proof.transform_to().public_input;
+        let dynamic_params = public_input.dynamic_params().unwrap().unwrap();
+
+        // Each field holds the value of the param of the same name, wherever the name sorts
+        macro_rules! assert_params {
+            ($($name:ident),*) => {
+                $(
+                    assert_eq!(
+                        serde_json::Value::from(dynamic_params.$name),
+                        params[stringify!($name)],
+                        stringify!($name)
+                    );
+                )*
+            };
+        }
+        assert_params!(
+            add_mod_a0_suboffset,
+            bitwise_row_ratio,
+            cpu_component_step,
+            cpu_decode_mem_inst_suboffset,
+            cpu_decode_opcode_range_check_column_offset,
+            diluted_units_row_ratio,
+            num_columns_first,
+            num_columns_second,
+            pedersen_builtin_row_ratio,
+            poseidon_row_ratio,
+            range_check_builtin_row_ratio,
+            uses_pedersen_builtin,
+            uses_range_check_builtin
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_dynamic_params() {
+        let error = parse_with_public_input("dynamic", None).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Missing dynamic params for the dynamic layout"
+        );
+
+        let mut params = all_dynamic_params();
+        params.remove("num_columns_first");
+        let error = parse_with_public_input("dynamic", Some(params.into())).unwrap_err();
+        assert_eq!(error.to_string(), "Missing dynamic param num_columns_first");
+
+        let mut params = all_dynamic_params();
+        params.insert("unknown_suboffset".to_string(), 1.into());
+        let error = parse_with_public_input("dynamic", Some(params.into())).unwrap_err();
+        assert_eq!(error.to_string(), "Unknown dynamic param unknown_suboffset");
+
+        let error =
+            parse_with_public_input("recursive_with_poseidon", Some(all_dynamic_params().into()))
+                .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Dynamic params are only allowed for the dynamic layout"
+        );
+    }
+
+    fn parse_with_verifier_config(
+        proof_parameters: serde_json::Value,
+        verifier_config: Option<serde_json::Value>,
+    ) -> anyhow::Result<StarkProof> {
+        let input = include_str!("../../example_proof/saya.json");
+        let mut value: serde_json::Value = serde_json::from_str(input).unwrap();
+        for (key, parameter) in proof_parameters.as_object().unwrap() {
+            value["proof_parameters"][key] = parameter.clone();
+        }
+        if let Some(verifier_config) = verifier_config {
+            value["verifier_config"] = verifier_config;
+        }
+        let proof_json = serde_json::from_value::<json_parser::StarkProof>(value).unwrap();
+        StarkProof::try_from(proof_json)
+    }
+
+    #[test]
+    fn test_parse_verifier_config() {
+        let proof = parse_with_verifier_config(serde_json::json!({}), None).unwrap();
+        assert_eq!(
+            proof.transform_to().verifier_config,
+            verifier_config::VerifierConfig::default()
+        );
+
+        let proof = parse_with_verifier_config(
+            serde_json::json!({
+                "commitment_hash": "blake256_masked160_lsb",
+                "pow_hash": "blake256",
+            }),
+            Some(serde_json::json!({
+                "stone_version": "stone5",
+                "program_hash_function": "pedersen",
+            })),
+        )
+        .unwrap();
+        assert_eq!(
+            proof.transform_to().verifier_config,
+            verifier_config::VerifierConfig::new(
+                verifier_config::StoneVersion::Stone5,
+                verifier_config::HashFunction::Pedersen,
+                verifier_config::Hasher::Blake2s256,
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_verifier_config() {
+        let error = parse_with_verifier_config(
+            serde_json::json!({ "commitment_hash": "blake256_masked160_lsb" }),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Commitment and proof of work hashes differ"
+        );
+
+        let error = parse_with_verifier_config(serde_json::json!({ "pow_hash": "sha256" }), None)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Unsupported proof of work hash sha256");
+    }
 }
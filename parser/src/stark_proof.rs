@@ -4,12 +4,44 @@ use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StarkProof {
+    pub verifier_config: VerifierConfig,
     pub config: StarkConfig,
     pub public_input: PublicInput,
     pub unsent_commitment: StarkUnsentCommitment,
     pub witness: StarkWitness,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StoneVersion {
+    Stone5,
+    #[default]
+    Stone6,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HashFunction {
+    Pedersen,
+    #[default]
+    Poseidon,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hasher {
+    #[default]
+    Keccak256,
+    Blake2s256,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct VerifierConfig {
+    pub stone_version: StoneVersion,
+    pub program_hash_function: HashFunction,
+    pub hasher: Hasher,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StarkConfig {
     pub traces: TracesConfig,
@@ -151,6 +183,7 @@ pub struct PublicInput {
     pub main_page: Vec<PubilcMemoryCell>,
     pub n_continuous_pages: usize,
     pub continuous_page_headers: Vec<BigUint>,
+    pub continuous_page_values: Vec<BigUint>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
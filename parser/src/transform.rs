@@ -1,13 +1,14 @@
 use crate::stark_proof;
 use felt::Felt;
 use stark::funvec::FunVec;
-use stark::swiftness::air::dynamic::DynamicParams;
+use stark::swiftness::air::dynamic::{DynamicParams, DYNAMIC_PARAMS_NAMES};
 use stark::swiftness::air::public_memory::PublicInput as PublicInputVerifier;
 use stark::swiftness::air::trace::config::Config as TraceConfigVerifier;
 use stark::swiftness::air::trace::Decommitment as TraceDecommitmentVerifier;
 use stark::swiftness::air::trace::UnsentCommitment as TraceUnsentCommitmentVerifier;
 use stark::swiftness::air::trace::Witness as TraceWitnessVerifier;
 use stark::swiftness::air::types::AddrValue;
+use stark::swiftness::air::types::ContinuousPageHeader;
 use stark::swiftness::air::types::Page;
 use stark::swiftness::air::types::SegmentInfo as SegmentInfoVerifier;
 use stark::swiftness::commitment::table::config::Config as TableConfigVerifier;
@@ -21,7 +22,11 @@ use stark::swiftness::fri::types::UnsentCommitment as FriUnsentCommitmentVerifie
 use stark::swiftness::fri::types::Witness as FriWitnessVerifier;
 use stark::swiftness::pow::config::Config as PowConfigVerifier;
 use stark::swiftness::pow::pow::UnsentCommitment as PowUnsentCommitmentVerifier;
+use stark::swiftness::stark::config::HashFunction as HashFunctionVerifier;
+use stark::swiftness::stark::config::Hasher as HasherVerifier;
 use stark::swiftness::stark::config::StarkConfig as StarkConfigVerifier;
+use stark::swiftness::stark::config::StoneVersion as StoneVersionVerifier;
+use stark::swiftness::stark::config::VerifierConfig as VerifierConfigVerifier;
 use stark::swiftness::stark::types::StarkProof as StarkProofVerifier;
 use stark::swiftness::stark::types::StarkUnsentCommitment as StarkUnsentCommitmentVerifier;
 use stark::swiftness::stark::types::StarkWitness as StarkWitnessVerifier;
@@ -36,6 +41,7 @@ pub trait TransformTo<T> {
 impl TransformTo<StarkProofVerifier> for stark_proof::StarkProof {
     fn transform_to(self) -> StarkProofVerifier {
         StarkProofVerifier {
+            verifier_config: self.verifier_config.transform_to(),
             config: self.config.transform_to(),
             public_input: self.public_input.transform_to(),
             unsent_commitment: self.unsent_commitment.transform_to(),
@@ -44,6 +50,25 @@ impl TransformTo<StarkProofVerifier> for stark_proof::StarkProof {
     }
 }
 
+impl TransformTo<VerifierConfigVerifier> for stark_proof::VerifierConfig {
+    fn transform_to(self) -> VerifierConfigVerifier {
+        VerifierConfigVerifier::new(
+            match self.stone_version {
+                stark_proof::StoneVersion::Stone5 => StoneVersionVerifier::Stone5,
+                stark_proof::StoneVersion::Stone6 => StoneVersionVerifier::Stone6,
+            },
+            match self.program_hash_function {
+                stark_proof::HashFunction::Pedersen => HashFunctionVerifier::Pedersen,
+                stark_proof::HashFunction::Poseidon => HashFunctionVerifier::Poseidon,
+            },
+            match self.hasher {
+                stark_proof::Hasher::Keccak256 => HasherVerifier::Keccak256,
+                stark_proof::Hasher::Blake2s256 => HasherVerifier::Blake2s256,
+            },
+        )
+    }
+}
+
 impl TransformTo<StarkConfigVerifier> for stark_proof::StarkConfig {
     fn transform_to(self) -> StarkConfigVerifier {
         StarkConfigVerifier {
@@ -119,11 +144,15 @@ impl TransformTo<VectorConfigVerifier> for stark_proof::VectorCommitmentConfig {
 
 impl TransformTo<PublicInputVerifier> for stark_proof::PublicInput {
     fn transform_to(self) -> PublicInputVerifier {
-        let dynamic_params = match self.dynamic_params.is_empty() {
-            true => None,
+        let (has_dynamic_params, dynamic_params) = match self.dynamic_params.is_empty() {
+            true => (0, DynamicParams::default()),
             false => {
-                let params: Vec<u32> = self.dynamic_params.values().cloned().collect();
-                Some(DynamicParams::from(params))
+                // The map is sorted by name, which is not the field order of DynamicParams.
+                let params: Vec<u32> = DYNAMIC_PARAMS_NAMES
+                    .iter()
+                    .map(|name| self.dynamic_params[*name])
+                    .collect();
+                (1, DynamicParams::from(params))
             }
         };
 
@@ -132,6 +161,7 @@ impl TransformTo<PublicInputVerifier> for stark_proof::PublicInput {
             range_check_min: self.range_check_min.into(),
             range_check_max: self.range_check_max.into(),
             layout: self.layout.into(),
+            has_dynamic_params,
             dynamic_params,
             segments: FunVec::from_vec(
                 self.segments
@@ -147,7 +177,24 @@ impl TransformTo<PublicInputVerifier> for stark_proof::PublicInput {
                     .map(|x| x.transform_to())
                     .collect(),
             )),
-            continuous_page_headers: FunVec::from_vec(vec![]),
+            // Headers are flattened as (start_address, size, hash, prod).
+            continuous_page_headers: FunVec::from_vec(
+                self.continuous_page_headers
+                    .chunks(4)
+                    .map(|header| ContinuousPageHeader {
+                        start_address: header[0].clone().into(),
+                        size: header[1].clone().into(),
+                        hash: header[2].clone().into(),
+                        prod: header[3].clone().into(),
+                    })
+                    .collect(),
+            ),
+            continuous_page_values: FunVec::from_vec(
+                self.continuous_page_values
+                    .into_iter()
+                    .map(|x| x.into())
+                    .collect(),
+            ),
         }
     }
 }
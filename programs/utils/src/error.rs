@@ -0,0 +1,108 @@
+use felt::Felt;
+use thiserror::Error;
+
+/// Errors of the tasks run by the scheduler
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskError {
+    /// The task on the back of the stack is not a known Executable type
+    #[error("Unknown type tag: {0}")]
+    UnknownTypeTag(u32),
+
+    /// The stack holds less data than the task reads
+    #[error("Stack underflow")]
+    StackUnderflow,
+
+    /// The stack has no room for the data a task pushes
+    #[error("Stack overflow")]
+    StackOverflow,
+
+    /// The public input of the proof is invalid
+    #[error("Invalid public input: {0}")]
+    InvalidPublicInput(&'static str),
+
+    /// The STARK config of the proof is invalid
+    #[error("Invalid config: {0}")]
+    InvalidConfig(&'static str),
+
+    /// The proof of work nonce does not reach the required number of bits
+    #[error("Proof of work verification failed")]
+    PowInsufficient,
+
+    /// The composition polynomial does not match the trace at the OODS point
+    #[error("OODS evaluation invalid")]
+    OodsMismatch { claimed: Felt, computed: Felt },
+
+    /// The proof does not hold the expected number of values, decommitments or witnesses
+    #[error("Invalid witness: {0}")]
+    InvalidWitness(&'static str),
+
+    /// A decommitment does not open to its commitment
+    #[error("Commitment mismatch")]
+    CommitmentMismatch { expected: Felt, actual: Felt },
+
+    /// The FRI last layer polynomial does not match the query at the given index
+    #[error("Invalid last layer value")]
+    LastLayerMismatch { index: Felt, value: Felt },
+}
+
+impl TaskError {
+    /// Code of the error, returned on-chain as `ProgramError::Custom`
+    pub fn code(&self) -> u32 {
+        match self {
+            TaskError::UnknownTypeTag(_) => 0,
+            TaskError::StackUnderflow => 1,
+            TaskError::InvalidPublicInput(_) => 2,
+            TaskError::InvalidConfig(_) => 3,
+            TaskError::PowInsufficient => 4,
+            TaskError::OodsMismatch { .. } => 5,
+            TaskError::InvalidWitness(_) => 6,
+            TaskError::CommitmentMismatch { .. } => 7,
+            TaskError::LastLayerMismatch { .. } => 8,
+            TaskError::StackOverflow => 9,
+        }
+    }
+
+    /// Whether the error rejects the proof, rather than the tasks pushed to the stack
+    pub fn is_proof_rejection(&self) -> bool {
+        !matches!(
+            self,
+            TaskError::UnknownTypeTag(_) | TaskError::StackUnderflow | TaskError::StackOverflow
+        )
+    }
+
+    /// Values of the failed check, zero for the errors that carry none
+    pub fn felts(&self) -> [Felt; 2] {
+        match *self {
+            TaskError::OodsMismatch { claimed, computed } => [claimed, computed],
+            TaskError::CommitmentMismatch { expected, actual } => [expected, actual],
+            TaskError::LastLayerMismatch { index, value } => [index, value],
+            _ => [Felt::ZERO; 2],
+        }
+    }
+
+    /// Description of an error code, for the failures recorded on-chain
+    pub fn describe(code: u32) -> &'static str {
+        match code {
+            0 => "unknown task",
+            1 => "stack underflow",
+            2 => "invalid public input",
+            3 => "invalid config",
+            4 => "insufficient proof of work",
+            5 => "OODS mismatch",
+            6 => "invalid witness",
+            7 => "commitment mismatch",
+            8 => "invalid last layer value",
+            9 => "stack overflow",
+            _ => "unknown error",
+        }
+    }
+}
+
+/// Fails with `error` unless `condition` holds
+pub fn ensure(condition: bool, error: TaskError) -> Result<(), TaskError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(error)
+    }
+}
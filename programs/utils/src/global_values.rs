@@ -1,3 +1,4 @@
+use super::layout::StoredGlobalValues;
 use super::transcript::Transcript;
 use felt::Felt;
 
@@ -7,6 +8,19 @@ pub struct EcPoint {
     pub y: Felt,
 }
 
+#[derive(Debug, PartialEq)]
+pub struct CurveConfig {
+    pub alpha: Felt,
+    pub beta: Felt,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct EcdsaSigConfig {
+    pub alpha: Felt,
+    pub beta: Felt,
+    pub shift_point: EcPoint,
+}
+
 // Accumulation of member expressions for auto generated composition polynomial code.
 #[derive(Debug, PartialEq)]
 pub struct GlobalValues {
@@ -91,6 +105,8 @@ impl Default for GlobalValues {
     }
 }
 
+unsafe impl StoredGlobalValues for GlobalValues {}
+
 // Elements that are sent from the prover after the commitment on the original trace.
 // Used for components after the first interaction, e.g., memory and range check.
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -0,0 +1,40 @@
+/// Sizes and types of the layout dependent buffers of the verifier account.
+///
+/// Every layout describes its own buffers. The account holds the buffers of the largest
+/// supported layout, since the layout of a proof is only known from its public input.
+pub trait LayoutStorage {
+    /// Accumulation of member expressions for the autogenerated composition polynomial code.
+    type GlobalValues: Default + StoredGlobalValues;
+
+    const CONSTRAINT_DEGREE: usize;
+    const N_CONSTRAINTS: usize;
+    const MASK_SIZE: usize;
+    const NUM_COLUMNS_FIRST: u32;
+    const NUM_COLUMNS_SECOND: u32;
+    /// Number of autogenerated powers of the evaluation point and trace generator.
+    const POWS_SIZE: usize;
+    /// Number of autogenerated domain evaluations.
+    const DOMAINS_SIZE: usize;
+
+    /// Mask values followed by the composition columns evaluated at the OODS point.
+    const OODS_VALUES_SIZE: usize = Self::MASK_SIZE + Self::CONSTRAINT_DEGREE;
+    /// Holds the constraint coefficients, then the OODS coefficients.
+    const COEFFICIENTS_SIZE: usize = if Self::N_CONSTRAINTS > Self::OODS_VALUES_SIZE {
+        Self::N_CONSTRAINTS
+    } else {
+        Self::OODS_VALUES_SIZE
+    };
+    /// Trace and composition column values of a single query.
+    const COLUMN_VALUES_SIZE: usize =
+        (Self::NUM_COLUMNS_FIRST + Self::NUM_COLUMNS_SECOND) as usize + Self::CONSTRAINT_DEGREE;
+}
+
+/// Global values of a layout, kept in the global values buffer of the verifier account.
+///
+/// The account accessors only read and write implementors of this trait, so passing any other
+/// type fails to compile.
+///
+/// # Safety
+///
+/// Implementors must only hold field elements, so the zeroed buffer is a valid value.
+pub unsafe trait StoredGlobalValues: Sized {}
@@ -1,24 +1,16 @@
 use felt::Felt;
 use std::fmt::Debug;
 
+pub mod error;
 pub mod global_values;
-use crate::global_values::GlobalValues;
+pub mod layout;
+use crate::layout::{LayoutStorage, StoredGlobalValues};
 pub mod transcript;
 
+pub use error::{ensure, TaskError};
+
 pub const CAPACITY: usize = 65536;
 pub const LENGTH_SIZE: usize = 2;
-// pub const MASK_VALUES_ROWS_SIZE: usize = 4107;
-// pub const MASK_VALUES_COLUMNS_SIZE: usize = 8;
-pub const MASK_VALUES_SIZE: usize = 192;
-pub const OODS_VALUES_SIZE: usize = 194;
-pub const COLUMN_VALUES_SIZE: usize = 10;
-pub const DOMAINS_SIZE: usize = 31;
-pub const POWS_SIZE: usize = 134;
-//todo: make it dependent on Layout
-pub const N_CONSTRAINTS: usize = 194;
-pub const CONSTRAINT_DEGREE: usize = 2;
-pub const NUM_COLUMNS_FIRST: u32 = 6;
-pub const NUM_COLUMNS_SECOND: u32 = 2;
 
 /// Trait for safely casting between account data and Rust types
 pub trait AccountCast: Sized {
@@ -46,6 +38,9 @@ pub trait BidirectionalStack {
     fn borrow_back(&self) -> &[u8];
     fn borrow_mut_front(&mut self) -> &mut [u8];
     fn borrow_mut_back(&mut self) -> &mut [u8];
+    /// Borrow the frames on the front of the stack, topmost first, until they cover `length`
+    /// bytes. The last frame is cut to the bytes still needed.
+    fn borrow_front_frames(&self, length: usize) -> Vec<&[u8]>;
     fn is_empty_front(&self) -> bool;
     fn is_empty_back(&self) -> bool;
 }
@@ -72,6 +67,9 @@ pub trait StarkCommitmentTrait {
 }
 
 pub trait ProofData {
+    /// Layout sizing the autogenerated buffers and the global values
+    type Layout: LayoutStorage;
+
     /// Get a reference to the proof data as any type T
     fn get_proof_reference<T: Sized>(&self) -> &T {
         let bytes = self.get_proof_bytes();
@@ -87,35 +85,45 @@ pub trait ProofData {
     /// Get raw proof bytes - to be implemented by concrete types
     fn get_proof_bytes(&self) -> &[u8];
     fn get_proof_bytes_mut(&mut self) -> &mut [u8];
-    fn get_autogenerated_pows(&self) -> &[Felt; POWS_SIZE];
-    fn get_autogenerated_pows_mut(&mut self) -> &mut [Felt; POWS_SIZE];
+    fn get_autogenerated_pows(&self) -> &[Felt];
+    fn get_autogenerated_pows_mut(&mut self) -> &mut [Felt];
     /// Get both autogenerated_pows and domains references to avoid borrowing conflicts
-    fn get_pows_and_domains_mut(&mut self) -> (&[Felt; POWS_SIZE], &mut [Felt; DOMAINS_SIZE]);
-    /// Get both domains and mask_column_row references to avoid borrowing conflicts
+    fn get_pows_and_domains_mut(&mut self) -> (&[Felt], &mut [Felt]);
+    /// Get the proof, the layout buffers and the global values of a layout at once to avoid
+    /// borrowing conflicts
     #[allow(clippy::type_complexity)]
-    fn get_proof_data_references<T: Sized>(
+    fn get_proof_data_references<T: Sized, G: StoredGlobalValues>(
         &mut self,
     ) -> (
         &T,
-        &[Felt; POWS_SIZE],
-        &[Felt; DOMAINS_SIZE],
-        &mut [Felt; OODS_VALUES_SIZE],
-        &mut GlobalValues,
-        &mut [Felt; N_CONSTRAINTS],
-        &mut [Felt; COLUMN_VALUES_SIZE],
+        &[Felt],
+        &[Felt],
+        &mut [Felt],
+        &mut G,
+        &mut [Felt],
+        &mut [Felt],
     );
 
-    /// Get global values - to be implemented by concrete types that have access to GlobalValues
-    fn get_global_values(&self) -> &GlobalValues;
+    /// Get the global values of a layout, which must fit the global values of Self::Layout
+    fn get_global_values<G: StoredGlobalValues>(&self) -> &G;
 
-    /// Set global values - to be implemented by concrete types that have access to GlobalValues
-    fn set_global_values(&mut self, global_values: GlobalValues);
+    /// Set the global values of a layout, which must fit the global values of Self::Layout
+    fn set_global_values<G: StoredGlobalValues>(&mut self, global_values: G);
 
     fn get_stark_commitment_and_proof_mut<T: Sized, P: Sized>(&mut self) -> (&mut T, &mut P);
-    fn get_constraint_coefficients(&self) -> &[Felt; N_CONSTRAINTS];
-    fn get_constraint_coefficients_mut(&mut self) -> &mut [Felt; N_CONSTRAINTS];
+    fn get_constraint_coefficients(&self) -> &[Felt];
+    fn get_constraint_coefficients_mut(&mut self) -> &mut [Felt];
+
+    /// Get the query phase working memory as any type T
+    fn get_queries<T: Sized>(&self) -> &T;
+    fn get_queries_mut<T: Sized>(&mut self) -> &mut T;
+    /// Get the query phase working memory together with the proof to avoid borrowing conflicts
+    fn get_queries_and_proof_mut<T: Sized, P: Sized>(&mut self) -> (&mut T, &P);
 }
 
+/// Global values of the layout of a ProofData implementation
+pub type LayoutGlobalValues<T> = <<T as ProofData>::Layout as LayoutStorage>::GlobalValues;
+
 /// Trait for providing automatic type identification with cryptographic hashing
 pub trait TypeIdentifiable {
     /// Returns a unique type ID based on the type name using a cryptographic hash
@@ -161,7 +169,7 @@ pub trait Executable: Sized + TypeIdentifiable {
     fn execute<T: BidirectionalStack + ProofData + StarkCommitmentTrait>(
         &mut self,
         stack: &mut T,
-    ) -> Vec<Vec<u8>>;
+    ) -> Result<Vec<Vec<u8>>, TaskError>;
     fn is_finished(&mut self) -> bool {
         false
     }
@@ -0,0 +1,94 @@
+#![allow(deprecated)]
+
+use solana_program::{
+    account_info::AccountInfo, keccak::hashv, program_error::ProgramError, pubkey::Pubkey,
+};
+
+/// Seed prefix of the fact account addresses
+pub const FACT_SEED: &[u8] = b"fact";
+
+/// Record of a verified Cairo execution, stored in a PDA seeded by its fact
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FactAccount {
+    /// keccak(program_hash || output_hash)
+    pub fact: [u8; 32],
+    pub program_hash: [u8; 32],
+    pub output_hash: [u8; 32],
+    /// Verifier account the proof was verified in
+    pub verifier_account: Pubkey,
+    /// Slot at which the fact was registered
+    pub slot: u64,
+    /// Fact hash of the proof in Herodotus Integrity's FactRegistry, poseidon(program_hash,
+    /// output_hash), or zero for proofs with continuous pages, which Integrity does not verify
+    pub integrity_fact_hash: [u8; 32],
+    /// Verification hash of the proof in Herodotus Integrity's FactRegistry, poseidon(fact_hash,
+    /// verifier_config_hash, security_bits), or zero for proofs with continuous pages
+    pub verification_hash: [u8; 32],
+    /// How the public input and the commitments of the proof were hashed
+    pub verifier_config: FactVerifierConfig,
+}
+
+/// Verifier config of a verified proof, as the discriminants of the `StoneVersion`,
+/// `HashFunction` and `Hasher` enums of the verifier
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FactVerifierConfig {
+    pub stone_version: u8,
+    pub program_hash_function: u8,
+    pub hasher: u8,
+}
+
+impl FactAccount {
+    pub const SIZE: usize = std::mem::size_of::<Self>();
+
+    /// Cast account data to a fact record
+    pub fn cast(data: &[u8]) -> &Self {
+        assert_eq!(data.len(), Self::SIZE);
+        unsafe { &*(data.as_ptr() as *const Self) }
+    }
+
+    /// Cast mutable account data to a fact record
+    pub fn cast_mut(data: &mut [u8]) -> &mut Self {
+        assert_eq!(data.len(), Self::SIZE);
+        unsafe { &mut *(data.as_mut_ptr() as *mut Self) }
+    }
+
+    /// Reads the fact account of the given hashes, checking it was registered by the verifier
+    pub fn load<'a>(
+        program_id: &Pubkey,
+        account: &'a AccountInfo,
+        program_hash: &[u8; 32],
+        output_hash: &[u8; 32],
+    ) -> Result<std::cell::Ref<'a, Self>, ProgramError> {
+        if account.owner != program_id || account.data_len() != Self::SIZE {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        let fact = compute_fact(program_hash, output_hash);
+        let (fact_address, _) = find_fact_address(program_id, &fact);
+        if *account.key != fact_address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let data = account.try_borrow_data()?;
+        let record = std::cell::Ref::map(data, |data| Self::cast(data));
+        if record.fact != fact
+            || record.program_hash != *program_hash
+            || record.output_hash != *output_hash
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(record)
+    }
+}
+
+/// Fact of a verified execution, keccak(program_hash || output_hash)
+pub fn compute_fact(program_hash: &[u8; 32], output_hash: &[u8; 32]) -> [u8; 32] {
+    hashv(&[program_hash, output_hash]).to_bytes()
+}
+
+/// Address and bump of the fact account of the given fact
+pub fn find_fact_address(program_id: &Pubkey, fact: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FACT_SEED, fact], program_id)
+}
@@ -0,0 +1,280 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+use crate::fact::{compute_fact, find_fact_address};
+
+/// Instructions supported by the verifier program
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum VerifierInstruction {
+    /// Writes part of the proof: the data at the given offset from the start of the verifier
+    /// account, which must fall within its proof region. Sealing requires every byte of the
+    /// proof region to have been written
+    ///
+    /// Valid while `Uploading`
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` The verifier account
+    /// 1. `[signer]` The authority of the verifier account
+    SetAccountData(usize, Vec<u8>),
+
+    /// Pushes a task to the verifier account's bidirectional stack
+    ///
+    /// Valid while `Uploading`
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` The verifier account
+    /// 1. `[signer]` The authority of the verifier account
+    PushTask(Vec<u8>),
+
+    /// Pushes data to the verifier account's bidirectional stack
+    ///
+    /// Valid while `Uploading`
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` The verifier account
+    /// 1. `[signer]` The authority of the verifier account
+    PushData(Vec<u8>),
+
+    /// Executes the next task in the verifier account's bidirectional stack, by anyone
+    ///
+    /// Valid while `Uploading`, for the tasks pushed by the uploader, and while `Sealed` or
+    /// `Verifying`, for the `Verify` task. Moves to `Verified` once `Verify` finishes and to
+    /// `Failed` once one of its checks rejects the proof
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` The verifier account
+    Execute(u32),
+
+    /// Closes the verifier account
+    ///
+    /// Valid once initialized
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` The verifier account
+    /// 1. `[writable, signer]` The authority of the verifier account, receiving its lamports
+    /// 2. `[]` The system program
+    Close,
+
+    /// Records the program hash and the output hash left by a finished `Verify` task in a fact
+    /// account, the PDA seeded by `[FACT_SEED, keccak(program_hash || output_hash)]`
+    ///
+    /// Valid once `Verified`
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The verifier account
+    /// 1. `[writable]` The fact account
+    /// 2. `[writable, signer]` The payer of the fact account rent
+    /// 3. `[]` The system program
+    RegisterFact,
+
+    /// Succeeds only if the fact of the given program hash and output hash was registered, for
+    /// programs acting on a verified execution through CPI
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The fact account
+    AssertFactVerified {
+        program_hash: [u8; 32],
+        output_hash: [u8; 32],
+    },
+
+    /// Logs the tasks of a verified simple_bootloader run: the program hash and the output of
+    /// each task, decoded from the output segment of the proof
+    ///
+    /// Valid once `Verified`
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The verifier account
+    LogBootloaderOutput,
+
+    /// Initializes a new verifier account, moving it from `Uninitialized` to `Uploading`. The
+    /// signer becomes the authority of the account. The verifier account signs too, so only the
+    /// holder of its keypair can initialize it
+    ///
+    /// Accounts expected:
+    /// 0. `[writable, signer]` The verifier account
+    /// 1. `[signer]` The authority of the verifier account
+    Initialize,
+
+    /// Makes the uploaded proof immutable and replaces the stack with the `Verify` task, moving
+    /// the verifier account from `Uploading` to `Sealed`. `Verify` rejects proofs with less than
+    /// `min_security_bits` bits of security, which can only raise the default of 80 bits. Fails if
+    /// part of the proof was not uploaded
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` The verifier account
+    /// 1. `[signer]` The authority of the verifier account
+    Seal { min_security_bits: u64 },
+
+    /// Clears the stack and the verification status, moving an initialized verifier account back
+    /// to `Uploading`. The proof has to be uploaded again before sealing
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` The verifier account
+    /// 1. `[signer]` The authority of the verifier account
+    Reset,
+}
+
+/// Creates a `RegisterFact` instruction for the hashes left by `Verify` in the verifier account
+pub fn register_fact(
+    program_id: &Pubkey,
+    verifier_account: &Pubkey,
+    payer: &Pubkey,
+    program_hash: &[u8; 32],
+    output_hash: &[u8; 32],
+) -> Instruction {
+    let (fact_address, _) = find_fact_address(program_id, &compute_fact(program_hash, output_hash));
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::RegisterFact,
+        vec![
+            AccountMeta::new_readonly(*verifier_account, false),
+            AccountMeta::new(fact_address, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+/// Creates an `AssertFactVerified` instruction for the given hashes
+pub fn assert_fact_verified(
+    program_id: &Pubkey,
+    program_hash: &[u8; 32],
+    output_hash: &[u8; 32],
+) -> Instruction {
+    let (fact_address, _) = find_fact_address(program_id, &compute_fact(program_hash, output_hash));
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::AssertFactVerified {
+            program_hash: *program_hash,
+            output_hash: *output_hash,
+        },
+        vec![AccountMeta::new_readonly(fact_address, false)],
+    )
+}
+
+/// Creates a `LogBootloaderOutput` instruction for the proof verified in the verifier account
+pub fn log_bootloader_output(program_id: &Pubkey, verifier_account: &Pubkey) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::LogBootloaderOutput,
+        vec![AccountMeta::new_readonly(*verifier_account, false)],
+    )
+}
+
+/// Accounts of the instructions signed by the authority of the verifier account
+fn authority_accounts(verifier_account: &Pubkey, authority: &Pubkey) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(*verifier_account, false),
+        AccountMeta::new_readonly(*authority, true),
+    ]
+}
+
+/// Creates an `Initialize` instruction making `authority` the authority of the verifier account
+pub fn initialize(
+    program_id: &Pubkey,
+    verifier_account: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::Initialize,
+        vec![
+            AccountMeta::new(*verifier_account, true),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+    )
+}
+
+/// Creates a `SetAccountData` instruction writing `data` at `offset` from the start of the
+/// verifier account
+pub fn set_account_data(
+    program_id: &Pubkey,
+    verifier_account: &Pubkey,
+    authority: &Pubkey,
+    offset: usize,
+    data: Vec<u8>,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::SetAccountData(offset, data),
+        authority_accounts(verifier_account, authority),
+    )
+}
+
+/// Creates a `PushTask` instruction for a task serialized with its type tag
+pub fn push_task(
+    program_id: &Pubkey,
+    verifier_account: &Pubkey,
+    authority: &Pubkey,
+    task: Vec<u8>,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::PushTask(task),
+        authority_accounts(verifier_account, authority),
+    )
+}
+
+/// Creates a `PushData` instruction
+pub fn push_data(
+    program_id: &Pubkey,
+    verifier_account: &Pubkey,
+    authority: &Pubkey,
+    data: Vec<u8>,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::PushData(data),
+        authority_accounts(verifier_account, authority),
+    )
+}
+
+/// Creates an `Execute` instruction, the nonce keeping the transactions of consecutive steps
+/// distinct
+pub fn execute(program_id: &Pubkey, verifier_account: &Pubkey, nonce: u32) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::Execute(nonce),
+        vec![AccountMeta::new(*verifier_account, false)],
+    )
+}
+
+/// Creates a `Seal` instruction for the proof uploaded to the verifier account
+pub fn seal(
+    program_id: &Pubkey,
+    verifier_account: &Pubkey,
+    authority: &Pubkey,
+    min_security_bits: u64,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::Seal { min_security_bits },
+        authority_accounts(verifier_account, authority),
+    )
+}
+
+/// Creates a `Reset` instruction for the verifier account
+pub fn reset(program_id: &Pubkey, verifier_account: &Pubkey, authority: &Pubkey) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::Reset,
+        authority_accounts(verifier_account, authority),
+    )
+}
+
+/// Creates a `Close` instruction sending the lamports of the verifier account to its authority
+pub fn close(program_id: &Pubkey, verifier_account: &Pubkey, authority: &Pubkey) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &VerifierInstruction::Close,
+        vec![
+            AccountMeta::new(*verifier_account, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
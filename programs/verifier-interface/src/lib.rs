@@ -0,0 +1,5 @@
+//! Instructions, account types and PDA derivation of the verifier program, for clients and for
+//! programs calling it through CPI.
+
+pub mod fact;
+pub mod instruction;
@@ -52,12 +52,16 @@ fn main() {
     dispatch_code.push_str("// Do not edit this file directly\n\n");
 
     // Generate the execute function
-    dispatch_code
-        .push_str("pub fn execute(stack: &mut crate::state::BidirectionalStackAccount) -> (Vec<Vec<u8>>, bool) {\n");
+    dispatch_code.push_str(
+        "pub fn execute(stack: &mut crate::state::BidirectionalStackAccount) -> Result<(Vec<Vec<u8>>, bool), TaskError> {\n",
+    );
     dispatch_code.push_str("    // Create a raw pointer to avoid multiple mutable borrow issues\n");
     dispatch_code
         .push_str("    let stack_ptr = stack as *mut crate::state::BidirectionalStackAccount;\n");
     dispatch_code.push_str("    \n");
+    dispatch_code.push_str("    if stack.is_empty_back() {\n");
+    dispatch_code.push_str("        return Err(TaskError::StackUnderflow);\n");
+    dispatch_code.push_str("    }\n");
     dispatch_code.push_str("    // Get the data from the back of the stack using unsafe\n");
     dispatch_code.push_str("    let data = unsafe { (*stack_ptr).borrow_mut_back() };\n");
     dispatch_code.push_str("    let mut tasks = Vec::new();\n");
@@ -65,7 +69,7 @@ fn main() {
 
     // We need to ensure we have enough data (at least 4 bytes for u32)
     dispatch_code.push_str("    if data.len() < 4 {\n");
-    dispatch_code.push_str("        panic!(\"Data too short to contain type tag\");\n");
+    dispatch_code.push_str("        return Err(TaskError::StackUnderflow);\n");
     dispatch_code.push_str("    }\n");
 
     // Read the 32-bit type tag from the first 4 bytes
@@ -81,21 +85,25 @@ fn main() {
             "        {crate_name}::{type_name}::TYPE_TAG => {{\n"
         ));
 
+        // The task data must hold the whole task
+        dispatch_code.push_str(&format!(
+            "            let size = std::mem::size_of::<{crate_name}::{type_name}>();\n            if data.len() < 4 + size {{\n                return Err(TaskError::StackUnderflow);\n            }}\n"
+        ));
         dispatch_code.push_str(
             "            // Execute the task using unsafe to get around borrow checker\n",
         );
         dispatch_code.push_str(&format!(
-                "            unsafe {{\n                let obj = {crate_name}::{type_name}::cast_mut(&mut data[4..(4 + std::mem::size_of::<{crate_name}::{type_name}>())]);\n                let returned_tasks = obj.execute(&mut *stack_ptr);\n                tasks.extend(returned_tasks);\n                is_finished = obj.is_finished();\n            }}\n"
+                "            unsafe {{\n                let obj = {crate_name}::{type_name}::cast_mut(&mut data[4..(4 + size)]);\n                let returned_tasks = obj.execute(&mut *stack_ptr)?;\n                tasks.extend(returned_tasks);\n                is_finished = obj.is_finished();\n            }}\n"
             ));
         dispatch_code.push_str("        },\n");
     }
 
     // Add default case
     dispatch_code.push_str("        _ => {\n");
-    dispatch_code.push_str("            panic!(\"Unknown type tag: {type_tag}\");\n");
+    dispatch_code.push_str("            return Err(TaskError::UnknownTypeTag(type_tag));\n");
     dispatch_code.push_str("        }\n");
     dispatch_code.push_str("    }\n");
-    dispatch_code.push_str("    (tasks, is_finished)\n");
+    dispatch_code.push_str("    Ok((tasks, is_finished))\n");
     dispatch_code.push_str("}\n");
 
     // Write the generated code to a file
@@ -1,6 +1,7 @@
 use std::io;
 use std::num::TryFromIntError;
 
+use solana_program::program_error::ProgramError;
 use thiserror::Error;
 
 /// Custom errors for the verifier program
@@ -55,3 +56,36 @@ pub enum VerifierError {
     #[error(transparent)]
     Io(#[from] io::Error),
 }
+
+/// Errors of the proof upload, returned as `ProgramError::Custom` with codes after the ones of
+/// `TaskError`
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadError {
+    /// The data does not fit in the proof region of the account
+    #[error("{len} bytes at offset {offset} outside the proof region")]
+    OutsideProofRegion { offset: usize, len: usize },
+
+    /// Part of the proof region was never written
+    #[error("Proof region not written at offset {missing}")]
+    IncompleteProof { missing: usize },
+
+    /// The minimum security requested when sealing is below the default one
+    #[error("Minimum security of {min_security_bits} bits below the default")]
+    InsufficientSecurityBits { min_security_bits: u64 },
+}
+
+impl UploadError {
+    pub fn code(&self) -> u32 {
+        match self {
+            UploadError::OutsideProofRegion { .. } => 100,
+            UploadError::IncompleteProof { .. } => 101,
+            UploadError::InsufficientSecurityBits { .. } => 102,
+        }
+    }
+}
+
+impl From<UploadError> for ProgramError {
+    fn from(e: UploadError) -> Self {
+        ProgramError::Custom(e.code())
+    }
+}
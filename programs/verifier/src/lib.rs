@@ -1,7 +1,8 @@
 // Export modules
 pub mod entrypoint;
 pub mod error;
-pub mod instruction;
 pub mod processor;
 pub mod scheduler;
 pub mod state;
+
+pub use verifier_interface::{fact, instruction};
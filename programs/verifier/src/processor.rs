@@ -1,51 +1,87 @@
 use borsh::BorshDeserialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
+    program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
     sysvar::Sysvar,
 };
-use utils::{AccountCast, BidirectionalStack};
-
-use crate::{instruction::VerifierInstruction, state::BidirectionalStackAccount};
+use solana_system_interface::{instruction as system_instruction, program as system_program};
+use stark::stark_proof::bootloader::{decode_bootloader_output, output_values};
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
+use utils::{AccountCast, BidirectionalStack, TaskError};
+
+use crate::{
+    error::UploadError,
+    fact::{compute_fact, find_fact_address, FactAccount, FactVerifierConfig, FACT_SEED},
+    instruction::VerifierInstruction,
+    state::{BidirectionalStackAccount, VerificationState},
+};
 
 /// Program state handler
 pub struct Processor;
 
 impl Processor {
     /// Process the initialize instruction
-    pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
+    pub fn process_initialize(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
         msg!("Processing Initialize instruction");
 
         // Get the account to initialize
         let accounts_iter = &mut accounts.iter();
         let account = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
+        check_verifier_account(program_id, account)?;
+        // Only the creator of the account holds its keypair, so nobody else can initialize it
+        // with another authority between its creation and its initialization
+        if !account.is_signer {
+            msg!("Verifier account did not sign the instruction");
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if !authority.is_signer {
+            msg!("Authority did not sign the instruction");
+            return Err(ProgramError::MissingRequiredSignature);
+        }
 
         // Initialize the bidirectional stack
         let mut data = account.try_borrow_mut_data()?;
         let stack_account = BidirectionalStackAccount::cast_mut(*data);
 
-        // Set to default values - front_index to 0, back_index to CAPACITY
-        *stack_account = BidirectionalStackAccount::default();
+        if stack_account.header.state != VerificationState::Uninitialized {
+            msg!("Account already initialized");
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // Clear in place, the account is too large for a default value on the stack
+        stack_account.reset();
+        stack_account.header.authority = *authority.key;
         msg!("Account initialized successfully");
 
         Ok(())
     }
 
     /// Process the push task instruction
-    pub fn process_push_task(accounts: &[AccountInfo], task_data: Vec<u8>) -> ProgramResult {
+    pub fn process_push_task(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        task_data: Vec<u8>,
+    ) -> ProgramResult {
         msg!("Processing PushTask instruction");
 
         // Get the account to push task to
         let accounts_iter: &mut std::slice::Iter<'_, AccountInfo<'_>> = &mut accounts.iter();
         let account = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
+        check_verifier_account(program_id, account)?;
 
         // Push the task to the bidirectional stack
         let mut data = account.try_borrow_mut_data()?;
         let stack_account = BidirectionalStackAccount::cast_mut(*data);
+        check_state(stack_account, &[VerificationState::Uploading])?;
+        check_authority(stack_account, authority)?;
 
         // Push the task data to the back of the stack
         stack_account.push_back(&task_data).map_err(|e| {
@@ -58,16 +94,24 @@ impl Processor {
     }
 
     /// Process the push data instruction
-    pub fn process_push_data(accounts: &[AccountInfo], data_payload: Vec<u8>) -> ProgramResult {
+    pub fn process_push_data(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        data_payload: Vec<u8>,
+    ) -> ProgramResult {
         msg!("Processing PushData instruction");
 
         // Get the account to push data to
         let accounts_iter = &mut accounts.iter();
         let account = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
+        check_verifier_account(program_id, account)?;
 
         // Push the data to the bidirectional stack
         let mut data = account.try_borrow_mut_data()?;
         let stack_account = BidirectionalStackAccount::cast_mut(*data);
+        check_state(stack_account, &[VerificationState::Uploading])?;
+        check_authority(stack_account, authority)?;
 
         // Push the data to the front of the stack
         stack_account.push_front(&data_payload).map_err(|e| {
@@ -80,55 +124,348 @@ impl Processor {
     }
 
     /// Process the execute instruction
-    pub fn process_execute(accounts: &[AccountInfo], nonce: u32) -> ProgramResult {
+    pub fn process_execute(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        nonce: u32,
+    ) -> ProgramResult {
         msg!("Processing Execute instruction, nonce: {}", nonce);
 
         // Get the account to execute task from
         let accounts_iter = &mut accounts.iter();
         let account = next_account_info(accounts_iter)?;
+        check_verifier_account(program_id, account)?;
 
         // Execute the next task in the stack
         let mut data = account.try_borrow_mut_data()?;
         let stack_account = BidirectionalStackAccount::cast_mut(*data);
 
-        // Execute the task
-        stack_account.execute();
+        let execute_error = |e: TaskError| {
+            msg!("Error executing task: {}", e);
+            ProgramError::Custom(e.code())
+        };
+        match stack_account.header.state {
+            // Tasks pushed by the uploader
+            VerificationState::Uploading => {
+                stack_account.execute().map_err(execute_error)?;
+                msg!("Task executed successfully");
+                return Ok(());
+            }
+            // A rejected proof stays rejected
+            VerificationState::Failed => {
+                let status = stack_account.status;
+                msg!(
+                    "Proof rejected: {} in task {}",
+                    TaskError::describe(status.error_code),
+                    status.type_tag
+                );
+                return Err(ProgramError::Custom(status.error_code));
+            }
+            _ => check_state(
+                stack_account,
+                &[VerificationState::Sealed, VerificationState::Verifying],
+            )?,
+        }
+
+        // Execute the task of the sealed proof
+        stack_account.execute_verification_step().map_err(|e| {
+            msg!("Error executing task: {}", e);
+            ProgramError::Custom(e.code())
+        })?;
+        let status = stack_account.status;
+        if status.is_failed() {
+            msg!(
+                "Proof rejected at step {}: {} in task {}, {:?}",
+                status.steps,
+                TaskError::describe(status.error_code),
+                status.type_tag,
+                status.felts
+            );
+            return Ok(());
+        }
+        if stack_account.header.state == VerificationState::Verified {
+            msg!("Proof verified");
+        }
         msg!("Task executed successfully");
 
         Ok(())
     }
 
     pub fn process_set_account_data(
+        program_id: &Pubkey,
         accounts: &[AccountInfo],
         offset: usize,
         data: Vec<u8>,
     ) -> ProgramResult {
-        msg!("Processing SetProof instruction");
+        msg!("Processing SetAccountData instruction");
         // Get the account to set proof to
         let accounts_iter = &mut accounts.iter();
         let account = next_account_info(accounts_iter)?;
-        let account_data = &mut account.try_borrow_mut_data()?;
-
-        account_data[offset..offset + data.len()].copy_from_slice(&data);
+        let authority = next_account_info(accounts_iter)?;
+        check_verifier_account(program_id, account)?;
+        let mut account_data = account.try_borrow_mut_data()?;
+        let stack_account = BidirectionalStackAccount::cast_mut(*account_data);
+        check_state(stack_account, &[VerificationState::Uploading])?;
+        check_authority(stack_account, authority)?;
+
+        stack_account.write_proof(offset, &data).map_err(|e| {
+            msg!("Error setting proof: {}", e);
+            ProgramError::from(e)
+        })?;
         msg!("Proof part set successfully");
         Ok(())
     }
 
+    /// Process the seal instruction
+    pub fn process_seal(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        min_security_bits: u64,
+    ) -> ProgramResult {
+        msg!("Processing Seal instruction");
+
+        let accounts_iter = &mut accounts.iter();
+        let account = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
+        check_verifier_account(program_id, account)?;
+
+        let mut data = account.try_borrow_mut_data()?;
+        let stack_account = BidirectionalStackAccount::cast_mut(*data);
+        check_state(stack_account, &[VerificationState::Uploading])?;
+        check_authority(stack_account, authority)?;
+
+        if let Some(missing) = stack_account.header.upload.first_missing() {
+            let e = UploadError::IncompleteProof { missing };
+            msg!("Error sealing proof: {}", e);
+            return Err(e.into());
+        }
+        // Facts are shared by every verifier account, so the default security is a floor
+        if min_security_bits < DEFAULT_SECURITY_BITS {
+            let e = UploadError::InsufficientSecurityBits { min_security_bits };
+            msg!("Error sealing proof: {}", e);
+            return Err(e.into());
+        }
+        stack_account.seal(min_security_bits);
+        msg!("Proof sealed");
+
+        Ok(())
+    }
+
+    /// Process the reset instruction
+    pub fn process_reset(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        msg!("Processing Reset instruction");
+
+        let accounts_iter = &mut accounts.iter();
+        let account = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
+        check_verifier_account(program_id, account)?;
+
+        let mut data = account.try_borrow_mut_data()?;
+        let stack_account = BidirectionalStackAccount::cast_mut(*data);
+        check_initialized(stack_account)?;
+        check_authority(stack_account, authority)?;
+
+        stack_account.reset();
+        msg!("Account reset");
+
+        Ok(())
+    }
+
+    /// Process the register fact instruction
+    pub fn process_register_fact(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        msg!("Processing RegisterFact instruction");
+
+        let accounts_iter = &mut accounts.iter();
+        let account = next_account_info(accounts_iter)?;
+        let fact_account = next_account_info(accounts_iter)?;
+        let payer = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        // The hashes are only trusted from a verifier account of this program
+        check_stack_account(program_id, account)?;
+
+        let (hashes, verifier_config) = {
+            let data = account.try_borrow_data()?;
+            let stack_account = BidirectionalStackAccount::cast(&data);
+            check_state(stack_account, &[VerificationState::Verified])?;
+            let hashes = stack_account.verified_hashes().ok_or_else(|| {
+                msg!("Verification has not finished");
+                ProgramError::InvalidAccountData
+            })?;
+            (hashes, stack_account.proof.verifier_config)
+        };
+
+        let program_hash = hashes.program_hash.to_bytes_be();
+        let output_hash = hashes.output_hash.to_bytes_be();
+        let fact = compute_fact(&program_hash, &output_hash);
+        let (fact_address, bump) = find_fact_address(program_id, &fact);
+        if *fact_account.key != fact_address {
+            msg!("Fact account does not match the fact PDA");
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if fact_account.owner == program_id {
+            msg!("Fact already registered");
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        let signer_seeds: &[&[u8]] = &[FACT_SEED, &fact, &[bump]];
+        let space = FactAccount::SIZE;
+        let lamports_required = (Rent::get()?).minimum_balance(space);
+
+        if fact_account.lamports() == 0 {
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer.key,
+                    fact_account.key,
+                    lamports_required,
+                    space as u64,
+                    program_id,
+                ),
+                &[payer.clone(), fact_account.clone(), system_program.clone()],
+                &[signer_seeds],
+            )?;
+        } else {
+            // Someone already sent lamports to the PDA, which would make create_account fail
+            let top_up = lamports_required.saturating_sub(fact_account.lamports());
+            if top_up > 0 {
+                invoke(
+                    &system_instruction::transfer(payer.key, fact_account.key, top_up),
+                    &[payer.clone(), fact_account.clone(), system_program.clone()],
+                )?;
+            }
+            invoke_signed(
+                &system_instruction::allocate(fact_account.key, space as u64),
+                &[fact_account.clone(), system_program.clone()],
+                &[signer_seeds],
+            )?;
+            invoke_signed(
+                &system_instruction::assign(fact_account.key, program_id),
+                &[fact_account.clone(), system_program.clone()],
+                &[signer_seeds],
+            )?;
+        }
+
+        let mut data = fact_account.try_borrow_mut_data()?;
+        *FactAccount::cast_mut(&mut data) = FactAccount {
+            fact,
+            program_hash,
+            output_hash,
+            verifier_account: *account.key,
+            slot: Clock::get()?.slot,
+            integrity_fact_hash: hashes.fact_hash.to_bytes_be(),
+            verification_hash: hashes.verification_hash.to_bytes_be(),
+            verifier_config: FactVerifierConfig {
+                stone_version: verifier_config.stone_version,
+                program_hash_function: verifier_config.program_hash_function,
+                hasher: verifier_config.hasher,
+            },
+        };
+        msg!("Fact registered in {}", fact_account.key);
+
+        Ok(())
+    }
+
+    /// Process the assert fact verified instruction
+    pub fn process_assert_fact_verified(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        program_hash: [u8; 32],
+        output_hash: [u8; 32],
+    ) -> ProgramResult {
+        msg!("Processing AssertFactVerified instruction");
+
+        let accounts_iter = &mut accounts.iter();
+        let fact_account = next_account_info(accounts_iter)?;
+
+        FactAccount::load(program_id, fact_account, &program_hash, &output_hash).map_err(|e| {
+            msg!("Fact not verified: {:?}", e);
+            e
+        })?;
+        msg!("Fact verified");
+
+        Ok(())
+    }
+
+    /// Process the log bootloader output instruction
+    pub fn process_log_bootloader_output(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        msg!("Processing LogBootloaderOutput instruction");
+
+        let accounts_iter = &mut accounts.iter();
+        let account = next_account_info(accounts_iter)?;
+
+        // Only the output of a proof verified by this program is logged
+        check_stack_account(program_id, account)?;
+
+        let data = account.try_borrow_data()?;
+        let stack_account = BidirectionalStackAccount::cast(&data);
+        check_state(stack_account, &[VerificationState::Verified])?;
+        if stack_account.verified_hashes().is_none() {
+            msg!("Verification has not finished");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let invalid_output = |e| {
+            msg!("Invalid bootloader output: {}", e);
+            ProgramError::InvalidAccountData
+        };
+        let output = output_values(&stack_account.proof.public_input).map_err(invalid_output)?;
+        let bootloader_output = decode_bootloader_output(&output).map_err(invalid_output)?;
+
+        msg!("Bootloader tasks: {}", bootloader_output.tasks.len());
+        for (i, task) in bootloader_output.tasks.iter().enumerate() {
+            msg!(
+                "Task {}: program hash {:?}, output {:?}",
+                i,
+                task.program_hash,
+                task.output
+            );
+        }
+
+        Ok(())
+    }
+
     // src: https://github.com/solana-developers/program-examples/blob/main/basics/close-account/native/program/src/instructions/close_user.rs
-    pub fn close(accounts: &[AccountInfo]) -> ProgramResult {
+    pub fn close(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        msg!("Processing Close instruction");
+
         let accounts_iter = &mut accounts.iter();
         let target_account = next_account_info(accounts_iter)?;
-        let payer = next_account_info(accounts_iter)?;
+        let authority = next_account_info(accounts_iter)?;
         let system_program = next_account_info(accounts_iter)?;
+        check_verifier_account(program_id, target_account)?;
+        {
+            let data = target_account.try_borrow_data()?;
+            let stack_account = BidirectionalStackAccount::cast(&data);
+            check_initialized(stack_account)?;
+            check_authority(stack_account, authority)?;
+        }
+        if !authority.is_writable {
+            msg!("Authority account not writable");
+            return Err(ProgramError::InvalidArgument);
+        }
+        if !system_program::check_id(system_program.key) {
+            msg!("Invalid system program");
+            return Err(ProgramError::IncorrectProgramId);
+        }
 
         let account_span = 0usize;
         let lamports_required = (Rent::get()?).minimum_balance(account_span);
 
-        let diff = target_account.lamports() - lamports_required;
+        let diff = target_account
+            .lamports()
+            .checked_sub(lamports_required)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        let authority_lamports = authority
+            .lamports()
+            .checked_add(diff)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
 
-        // Send the rent back to the payer
+        // Send the rent back to the authority
         **target_account.lamports.borrow_mut() -= diff;
-        **payer.lamports.borrow_mut() += diff;
+        **authority.lamports.borrow_mut() = authority_lamports;
 
         // Realloc the account to zero
         target_account.resize(account_span)?;
@@ -140,9 +477,70 @@ impl Processor {
     }
 }
 
+/// Fails unless the verifier account is an account of the program sized for the stack, the
+/// data is cast to the stack only then
+fn check_stack_account(program_id: &Pubkey, account: &AccountInfo) -> ProgramResult {
+    if account.owner != program_id {
+        msg!("Verifier account not owned by the program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if account.data_len() != std::mem::size_of::<BidirectionalStackAccount>() {
+        msg!("Verifier account has the wrong size");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+/// Fails unless the verifier account is a writable account of the program
+fn check_verifier_account(program_id: &Pubkey, account: &AccountInfo) -> ProgramResult {
+    check_stack_account(program_id, account)?;
+    if !account.is_writable {
+        msg!("Verifier account not writable");
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok(())
+}
+
+/// Fails unless the authority set at initialization signed the instruction
+fn check_authority(
+    stack_account: &BidirectionalStackAccount,
+    authority: &AccountInfo,
+) -> ProgramResult {
+    if !authority.is_signer || *authority.key != stack_account.header.authority {
+        msg!("Instruction not signed by the authority of the verifier account");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+/// Fails if the verifier account was not initialized
+fn check_initialized(stack_account: &BidirectionalStackAccount) -> ProgramResult {
+    if stack_account.header.state == VerificationState::Uninitialized {
+        msg!("Account not initialized");
+        return Err(ProgramError::UninitializedAccount);
+    }
+    Ok(())
+}
+
+/// Fails unless the verifier account is in one of the given states
+fn check_state(
+    stack_account: &BidirectionalStackAccount,
+    states: &[VerificationState],
+) -> ProgramResult {
+    let state = stack_account.header.state;
+    if states.contains(&state) {
+        return Ok(());
+    }
+    msg!("Instruction not valid in state {:?}", state);
+    Err(match state {
+        VerificationState::Uninitialized => ProgramError::UninitializedAccount,
+        _ => ProgramError::InvalidAccountData,
+    })
+}
+
 /// Instruction processor
 pub fn process_instruction(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
@@ -154,16 +552,34 @@ pub fn process_instruction(
     // Process the instruction
     match instruction {
         VerifierInstruction::SetAccountData(offset, data) => {
-            Processor::process_set_account_data(accounts, offset, data)
+            Processor::process_set_account_data(program_id, accounts, offset, data)
         }
         VerifierInstruction::PushTask(task_data) => {
-            Processor::process_push_task(accounts, task_data)
+            Processor::process_push_task(program_id, accounts, task_data)
         }
         VerifierInstruction::PushData(data_payload) => {
-            Processor::process_push_data(accounts, data_payload)
+            Processor::process_push_data(program_id, accounts, data_payload)
         }
-        VerifierInstruction::Execute(nonce) => Processor::process_execute(accounts, nonce),
+        VerifierInstruction::Execute(nonce) => {
+            Processor::process_execute(program_id, accounts, nonce)
+        }
+
+        VerifierInstruction::Close => Processor::close(program_id, accounts),
 
-        VerifierInstruction::Close => Processor::close(accounts),
+        VerifierInstruction::RegisterFact => Processor::process_register_fact(program_id, accounts),
+        VerifierInstruction::AssertFactVerified {
+            program_hash,
+            output_hash,
+        } => {
+            Processor::process_assert_fact_verified(program_id, accounts, program_hash, output_hash)
+        }
+        VerifierInstruction::LogBootloaderOutput => {
+            Processor::process_log_bootloader_output(program_id, accounts)
+        }
+        VerifierInstruction::Initialize => Processor::process_initialize(program_id, accounts),
+        VerifierInstruction::Seal { min_security_bits } => {
+            Processor::process_seal(program_id, accounts, min_security_bits)
+        }
+        VerifierInstruction::Reset => Processor::process_reset(program_id, accounts),
     }
 }
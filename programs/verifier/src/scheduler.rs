@@ -1,5 +1,5 @@
 use crate::state::BidirectionalStackAccount;
-use utils::{BidirectionalStack, Executable, Scheduler};
+use utils::{BidirectionalStack, Executable, Scheduler, TaskError};
 
 // Include the generated dispatch code
 include!(concat!(env!("OUT_DIR"), "/verifier_executable_dispatch.rs"));
@@ -7,15 +7,16 @@ include!(concat!(env!("OUT_DIR"), "/verifier_executable_dispatch.rs"));
 impl Scheduler for BidirectionalStackAccount {}
 
 impl BidirectionalStackAccount {
-    pub fn execute(&mut self) {
-        let (tasks, is_finished) = execute(self);
+    pub fn execute(&mut self) -> Result<(), TaskError> {
+        let (tasks, is_finished) = execute(self)?;
 
         if is_finished {
             self.pop_back();
         }
 
         for task in tasks.iter().rev() {
-            let _ = self.push_back(task);
+            self.push_back(task).map_err(|_| TaskError::StackOverflow)?;
         }
+        Ok(())
     }
 }
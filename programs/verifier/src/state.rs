@@ -1,14 +1,39 @@
-use crate::error::VerifierError;
+use crate::error::{UploadError, VerifierError};
+use core::ops::Range;
 use felt::Felt;
-use stark::swiftness::air::recursive_with_poseidon::GlobalValues;
+use solana_program::pubkey::Pubkey;
+use stark::stark_proof::verify::Verify;
+use stark::swiftness::air::layout::AnyLayout;
 use stark::swiftness::stark::types::cast_struct_to_slice_mut;
+use stark::swiftness::stark::types::QueryState;
 use stark::swiftness::stark::types::StarkCommitment;
 use stark::swiftness::stark::types::{cast_struct_to_slice, StarkProof};
 use utils::global_values::InteractionElements;
+use utils::layout::{LayoutStorage, StoredGlobalValues};
 use utils::ProofData;
 use utils::StarkCommitmentTrait;
-use utils::{AccountCast, BidirectionalStack, N_CONSTRAINTS};
-use utils::{CAPACITY, COLUMN_VALUES_SIZE, DOMAINS_SIZE, LENGTH_SIZE, OODS_VALUES_SIZE, POWS_SIZE};
+use utils::{AccountCast, BidirectionalStack, Scheduler, TaskError};
+use utils::{CAPACITY, LENGTH_SIZE};
+
+/// Layout sizing the autogenerated buffers of the account, large enough for every supported layout
+pub type AccountLayout = AnyLayout;
+
+pub const POWS_SIZE: usize = <AccountLayout as LayoutStorage>::POWS_SIZE;
+pub const OODS_VALUES_SIZE: usize = <AccountLayout as LayoutStorage>::OODS_VALUES_SIZE;
+pub const DOMAINS_SIZE: usize = <AccountLayout as LayoutStorage>::DOMAINS_SIZE;
+pub const COEFFICIENTS_SIZE: usize = <AccountLayout as LayoutStorage>::COEFFICIENTS_SIZE;
+pub const COLUMN_VALUES_SIZE: usize = <AccountLayout as LayoutStorage>::COLUMN_VALUES_SIZE;
+type GlobalValues = <AccountLayout as LayoutStorage>::GlobalValues;
+
+/// Rejects at compile time the global values that do not fit the global values of the account
+const fn assert_fits_global_values<G>() {
+    assert!(std::mem::size_of::<G>() <= std::mem::size_of::<GlobalValues>());
+    assert!(std::mem::align_of::<G>() <= std::mem::align_of::<GlobalValues>());
+}
+
+/// Size of the proof region of the account, the only one written by `SetAccountData`
+pub const PROOF_SIZE: usize = std::mem::size_of::<StarkProof>();
+const UPLOAD_WORDS: usize = PROOF_SIZE.div_ceil(64);
 
 /// Define the type of state stored in accounts
 #[repr(C)]
@@ -22,9 +47,13 @@ pub struct BidirectionalStackAccount {
     pub oods_values: [Felt; OODS_VALUES_SIZE],
     pub domains: [Felt; DOMAINS_SIZE],
     pub global_values: GlobalValues,
-    pub constraint_coefficients: [Felt; N_CONSTRAINTS],
+    pub constraint_coefficients: [Felt; COEFFICIENTS_SIZE],
     pub column_values: [Felt; COLUMN_VALUES_SIZE],
     pub stark_commitment: StarkCommitment<InteractionElements>,
+    pub queries: QueryState,
+    pub status: VerificationStatus,
+    /// Kept after the other regions so that the proof offset, which clients write to, is unchanged
+    pub header: AccountHeader,
 }
 impl Default for BidirectionalStackAccount {
     fn default() -> Self {
@@ -37,30 +66,308 @@ impl Default for BidirectionalStackAccount {
             oods_values: [Felt::ZERO; OODS_VALUES_SIZE],
             domains: [Felt::ZERO; DOMAINS_SIZE],
             global_values: GlobalValues::default(),
-            constraint_coefficients: [Felt::ZERO; N_CONSTRAINTS],
+            constraint_coefficients: [Felt::ZERO; COEFFICIENTS_SIZE],
             column_values: [Felt::ZERO; COLUMN_VALUES_SIZE],
             stark_commitment: StarkCommitment::default(),
+            queries: QueryState::default(),
+            status: VerificationStatus::default(),
+            header: AccountHeader::default(),
         }
     }
 }
 
 impl BidirectionalStackAccount {
-    pub fn simulate(&mut self) -> u128 {
+    /// Writes `data` in the proof region, at `offset` from the start of the account, and records
+    /// the bytes written
+    pub fn write_proof(&mut self, offset: usize, data: &[u8]) -> Result<(), UploadError> {
+        let outside = UploadError::OutsideProofRegion {
+            offset,
+            len: data.len(),
+        };
+        let start = offset
+            .checked_sub(std::mem::offset_of!(Self, proof))
+            .ok_or(outside)?;
+        let end = start
+            .checked_add(data.len())
+            .filter(|end| *end <= PROOF_SIZE)
+            .ok_or(outside)?;
+
+        cast_struct_to_slice_mut(&mut self.proof)[start..end].copy_from_slice(data);
+        self.header.upload.mark(start..end);
+        Ok(())
+    }
+
+    /// Clears the stack, the status and the uploaded ranges, leaving the proof to be uploaded
+    /// again
+    pub fn reset(&mut self) {
+        self.clear_stack();
+        self.header.upload.clear();
+        self.header.state = VerificationState::Uploading;
+    }
+
+    fn clear_stack(&mut self) {
+        self.front_index = 0;
+        self.back_index = CAPACITY;
+        self.status = VerificationStatus::default();
+    }
+
+    /// Makes the proof immutable and replaces the stack with the `Verify` task, rejecting proofs
+    /// with less than `min_security_bits` bits of security. The working memory of the tasks is
+    /// cleared, so that tasks run while uploading leave nothing for `Verify` to read
+    pub fn seal(&mut self, min_security_bits: u64) {
+        self.clear_stack();
+        self.clear_working_memory();
+        self.push_task(Verify::with_min_security_bits(min_security_bits));
+        self.header.min_security_bits = min_security_bits;
+        self.header.state = VerificationState::Sealed;
+    }
+
+    fn clear_working_memory(&mut self) {
+        self.buffer.fill(0);
+        self.autogenerated_pows.fill(Felt::ZERO);
+        self.oods_values.fill(Felt::ZERO);
+        self.domains.fill(Felt::ZERO);
+        self.constraint_coefficients.fill(Felt::ZERO);
+        self.column_values.fill(Felt::ZERO);
+        // Felts and FunVecs of felts, for which zero bytes are the default value. Zeroed in place,
+        // since the account bytes are not values that can be dropped
+        cast_struct_to_slice_mut(&mut self.global_values).fill(0);
+        cast_struct_to_slice_mut(&mut self.stark_commitment).fill(0);
+        cast_struct_to_slice_mut(&mut self.queries).fill(0);
+    }
+
+    /// Executes the next task of the sealed proof. A rejected proof is recorded in the status
+    /// instead of being returned, other errors are returned without changing the account
+    pub fn execute_verification_step(&mut self) -> Result<(), TaskError> {
+        let type_tag = self.next_type_tag();
+        match self.execute() {
+            Err(e) if e.is_proof_rejection() => {
+                self.status.record_failure(type_tag.unwrap_or_default(), e);
+                self.header.state = VerificationState::Failed;
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+            Ok(()) => self.status.steps += 1,
+        }
+
+        if !self.is_empty_back() {
+            self.header.state = VerificationState::Verifying;
+            return Ok(());
+        }
+
+        // The sealed Verify task finished
+        self.status.hashes = self.pop_verified_hashes()?;
+        self.header.state = VerificationState::Verified;
+        Ok(())
+    }
+
+    /// Type tag of the task on the back of the stack
+    pub fn next_type_tag(&self) -> Option<u32> {
+        if self.is_empty_back() {
+            return None;
+        }
+        let data = self.borrow_back();
+        Some(u32::from_be_bytes(data.get(..4)?.try_into().unwrap()))
+    }
+
+    pub fn simulate(&mut self) -> Result<u128, TaskError> {
         let mut simulation_steps = 0;
         while !self.is_empty_back() {
-            self.execute();
+            self.execute()?;
             simulation_steps += 1;
         }
-        simulation_steps
+        Ok(simulation_steps)
+    }
+
+    /// Executes tasks until the first task with the given type tag finished, returning the number
+    /// of steps, or until the stack is empty
+    pub fn simulate_until(&mut self, type_tag: u32) -> Result<u128, TaskError> {
+        let mut simulation_steps = 0;
+        // Back index of the task once it is on the back of the stack, its subtasks go below it
+        let mut task_index = None;
+        while !self.is_empty_back() {
+            if task_index.is_none() && self.next_type_tag() == Some(type_tag) {
+                task_index = Some(self.back_index);
+            }
+            self.execute()?;
+            simulation_steps += 1;
+            if task_index.is_some_and(|index| self.back_index > index) {
+                break;
+            }
+        }
+        Ok(simulation_steps)
+    }
+
+    /// Hashes of the proof, once `Verify` finished
+    pub fn verified_hashes(&self) -> Option<VerifiedHashes> {
+        (self.header.state == VerificationState::Verified).then_some(self.status.hashes)
+    }
+
+    /// Pops the hashes left by `Verify`, which must be the only data on the stack, the program
+    /// hash on top
+    fn pop_verified_hashes(&mut self) -> Result<VerifiedHashes, TaskError> {
+        let mut hashes = [Felt::ZERO; 4];
+        for hash in hashes.iter_mut() {
+            let frame = self.borrow_front();
+            if self.front_index < LENGTH_SIZE || frame.len() != 32 {
+                return Err(TaskError::StackUnderflow);
+            }
+            *hash = Felt::from_bytes_be_slice(frame);
+            self.pop_front();
+        }
+        if self.front_index != 0 {
+            return Err(TaskError::StackUnderflow);
+        }
+        let [program_hash, output_hash, fact_hash, verification_hash] = hashes;
+        Ok(VerifiedHashes {
+            program_hash,
+            output_hash,
+            fact_hash,
+            verification_hash,
+        })
+    }
+}
+
+/// Stage of the verification of the proof in the account:
+///   Uninitialized -> Uploading -> Sealed -> Verifying -> Verified | Failed
+/// Reset brings an initialized account back to Uploading.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerificationState {
+    /// Created and not initialized yet
+    #[default]
+    Uninitialized,
+    /// The proof is written, tasks pushed by the uploader can be executed
+    Uploading,
+    /// The proof is immutable and `Verify` is the only task on the stack
+    Sealed,
+    /// `Verify` is running
+    Verifying,
+    /// `Verify` finished, the hashes of the proof are in `VerificationStatus`
+    Verified,
+    /// A check of `Verify` rejected the proof, see `VerificationStatus`
+    Failed,
+}
+
+/// Header of the verifier account
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AccountHeader {
+    pub state: VerificationState,
+    /// Signer of the instructions uploading, sealing, resetting and closing the account, set at
+    /// initialization
+    pub authority: Pubkey,
+    /// Bytes of the proof written since initialization, sealing requires all of them
+    pub upload: ProofUpload,
+    /// Minimum security of the proof in bits, set when sealing
+    pub min_security_bits: u64,
+}
+
+/// Bytes of the proof region written by `SetAccountData`, one bit per byte
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofUpload {
+    written: [u64; UPLOAD_WORDS],
+}
+
+impl Default for ProofUpload {
+    fn default() -> Self {
+        Self {
+            written: [0; UPLOAD_WORDS],
+        }
     }
 }
 
+impl ProofUpload {
+    /// Records the bytes of the proof region in `range` as written
+    pub fn mark(&mut self, range: Range<usize>) {
+        for i in range {
+            self.written[i / 64] |= 1 << (i % 64);
+        }
+    }
+
+    /// Offset in the proof region of the first byte not written yet
+    pub fn first_missing(&self) -> Option<usize> {
+        let (i, word) = self
+            .written
+            .iter()
+            .enumerate()
+            .find(|(_, word)| **word != u64::MAX)?;
+        let missing = i * 64 + word.trailing_ones() as usize;
+        (missing < PROOF_SIZE).then_some(missing)
+    }
+
+    pub fn clear(&mut self) {
+        self.written.fill(0);
+    }
+}
+
+/// Outcome of the verification run in the account. Once a check fails the verification stops
+/// and the failure stays recorded.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VerificationStatus {
+    /// Execute steps run on the account, the failed one included
+    pub steps: u64,
+    /// Non-zero once a check failed
+    pub failed: u32,
+    /// Type tag of the task whose check failed
+    pub type_tag: u32,
+    /// `TaskError` code of the failed check
+    pub error_code: u32,
+    /// Values of the failed check, see `TaskError::felts`
+    pub felts: [Felt; 2],
+    /// Hashes of the proof, set once `Verify` finished
+    pub hashes: VerifiedHashes,
+}
+
+impl VerificationStatus {
+    pub fn is_failed(&self) -> bool {
+        self.failed != 0
+    }
+
+    fn record_failure(&mut self, type_tag: u32, error: TaskError) {
+        self.steps += 1;
+        self.failed = 1;
+        self.type_tag = type_tag;
+        self.error_code = error.code();
+        self.felts = error.felts();
+    }
+}
+
+/// Hashes of a verified proof
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VerifiedHashes {
+    pub program_hash: Felt,
+    pub output_hash: Felt,
+    /// Fact hash of the proof in Herodotus Integrity's FactRegistry, zero for proofs with
+    /// continuous pages, which Integrity does not verify
+    pub fact_hash: Felt,
+    /// Verification hash of the proof in Herodotus Integrity's FactRegistry, zero for proofs with
+    /// continuous pages
+    pub verification_hash: Felt,
+}
+
 impl AccountCast for BidirectionalStackAccount {}
 
+impl BidirectionalStackAccount {
+    // Checks that the data and its length prefix fit between the front and the back of the stack.
+    // The buffer holds CAPACITY bytes, so the length of data that fits also fits the prefix.
+    fn ensure_room(&self, data: &[u8]) -> Result<(), VerifierError> {
+        let free = self.back_index.saturating_sub(self.front_index);
+        if data.len() + LENGTH_SIZE > free {
+            return Err(VerifierError::StackCapacity);
+        }
+        Ok(())
+    }
+}
+
 impl BidirectionalStack for BidirectionalStackAccount {
     type Error = VerifierError;
 
     fn push_front(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.ensure_room(data)?;
         for byte in data {
             self.buffer[self.front_index] = *byte;
             self.front_index = self.front_index.saturating_add(1);
@@ -76,6 +383,7 @@ impl BidirectionalStack for BidirectionalStackAccount {
     }
 
     fn push_back(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.ensure_room(data)?;
         for byte in data.iter().rev() {
             self.back_index = self.back_index.saturating_sub(1);
             self.buffer[self.back_index] = *byte;
@@ -156,6 +464,30 @@ impl BidirectionalStack for BidirectionalStackAccount {
             ..self.back_index.saturating_add(LENGTH_SIZE + data_length)]
     }
 
+    fn borrow_front_frames(&self, length: usize) -> Vec<&[u8]> {
+        let mut frames = Vec::new();
+        let mut index = self.front_index;
+        let mut remaining = length;
+
+        while remaining > 0 && index > 0 {
+            let mut data_length = 0_usize;
+            for i in 1..=LENGTH_SIZE {
+                let x: usize = self.buffer[index.saturating_sub(i)].into();
+                data_length = (data_length << 8) | x;
+            }
+
+            let end = index.saturating_sub(LENGTH_SIZE);
+            let start = end.saturating_sub(data_length);
+            let to_read = remaining.min(data_length);
+            frames.push(&self.buffer[start..start + to_read]);
+
+            remaining -= to_read;
+            index = start;
+        }
+
+        frames
+    }
+
     fn is_empty_front(&self) -> bool {
         self.front_index == 0
     }
@@ -196,6 +528,8 @@ impl StarkCommitmentTrait for BidirectionalStackAccount {
 }
 
 impl ProofData for BidirectionalStackAccount {
+    type Layout = AccountLayout;
+
     fn get_proof_bytes(&self) -> &[u8] {
         cast_struct_to_slice(&self.proof)
     }
@@ -204,64 +538,56 @@ impl ProofData for BidirectionalStackAccount {
         cast_struct_to_slice_mut(&mut self.proof)
     }
 
-    fn get_autogenerated_pows(&self) -> &[Felt; POWS_SIZE] {
+    fn get_autogenerated_pows(&self) -> &[Felt] {
         &self.autogenerated_pows
     }
 
-    fn get_autogenerated_pows_mut(&mut self) -> &mut [Felt; POWS_SIZE] {
+    fn get_autogenerated_pows_mut(&mut self) -> &mut [Felt] {
         &mut self.autogenerated_pows
     }
 
-    fn get_pows_and_domains_mut(&mut self) -> (&[Felt; POWS_SIZE], &mut [Felt; DOMAINS_SIZE]) {
-        // Create a slice that spans both arrays
-        let pows_slice = &self.autogenerated_pows;
-        let domains_slice = &mut self.domains;
-
-        (pows_slice, domains_slice)
+    fn get_pows_and_domains_mut(&mut self) -> (&[Felt], &mut [Felt]) {
+        (&self.autogenerated_pows, &mut self.domains)
     }
-    fn get_proof_data_references<T: Sized>(
+
+    fn get_proof_data_references<T: Sized, G: StoredGlobalValues>(
         &mut self,
     ) -> (
         &T,
-        &[Felt; POWS_SIZE],
-        &[Felt; DOMAINS_SIZE],
-        &mut [Felt; OODS_VALUES_SIZE],
-        &mut GlobalValues,
-        &mut [Felt; N_CONSTRAINTS],
-        &mut [Felt; COLUMN_VALUES_SIZE],
+        &[Felt],
+        &[Felt],
+        &mut [Felt],
+        &mut G,
+        &mut [Felt],
+        &mut [Felt],
     ) {
+        const { assert_fits_global_values::<G>() };
         // Access proof bytes directly to avoid borrowing conflicts
-        let proof_bytes = self.get_proof_bytes();
+        let proof_bytes = cast_struct_to_slice(&self.proof);
+        assert_eq!(proof_bytes.len(), std::mem::size_of::<T>());
         let proof_ref = unsafe { &*(proof_bytes.as_ptr() as *const T) };
-        let pows_slice = &self.autogenerated_pows;
-
-        // Use raw pointers to avoid borrowing conflicts
-        let domains_slice = &self.domains;
-        let oods_values_slice =
-            unsafe { &mut *(&mut self.oods_values as *mut [Felt; OODS_VALUES_SIZE]) };
-        let global_values_ref = unsafe { &mut *(&mut self.global_values as *mut GlobalValues) };
-        let constraint_coefficients_slice =
-            unsafe { &mut *(&mut self.constraint_coefficients as *mut [Felt; N_CONSTRAINTS]) };
-        let column_values_slice =
-            unsafe { &mut *(&mut self.column_values as *mut [Felt; COLUMN_VALUES_SIZE]) };
+        let global_values_ref =
+            unsafe { &mut *(&mut self.global_values as *mut GlobalValues as *mut G) };
 
         (
             proof_ref,
-            pows_slice,
-            domains_slice,
-            oods_values_slice,
+            &self.autogenerated_pows,
+            &self.domains,
+            &mut self.oods_values,
             global_values_ref,
-            constraint_coefficients_slice,
-            column_values_slice,
+            &mut self.constraint_coefficients,
+            &mut self.column_values,
         )
     }
 
-    fn get_global_values(&self) -> &GlobalValues {
-        &self.global_values
+    fn get_global_values<G: StoredGlobalValues>(&self) -> &G {
+        const { assert_fits_global_values::<G>() };
+        unsafe { &*(&self.global_values as *const GlobalValues as *const G) }
     }
 
-    fn set_global_values(&mut self, global_values: GlobalValues) {
-        self.global_values = global_values;
+    fn set_global_values<G: StoredGlobalValues>(&mut self, global_values: G) {
+        const { assert_fits_global_values::<G>() };
+        unsafe { *(&mut self.global_values as *mut GlobalValues as *mut G) = global_values };
     }
 
     fn get_stark_commitment_and_proof_mut<T: Sized, P: Sized>(&mut self) -> (&mut T, &mut P) {
@@ -277,19 +603,49 @@ impl ProofData for BidirectionalStackAccount {
         (stark_commitment, proof)
     }
 
-    fn get_constraint_coefficients(&self) -> &[Felt; N_CONSTRAINTS] {
+    fn get_constraint_coefficients(&self) -> &[Felt] {
         &self.constraint_coefficients
     }
 
-    fn get_constraint_coefficients_mut(&mut self) -> &mut [Felt; N_CONSTRAINTS] {
+    fn get_constraint_coefficients_mut(&mut self) -> &mut [Felt] {
         &mut self.constraint_coefficients
     }
+
+    fn get_queries<T: Sized>(&self) -> &T {
+        let bytes = cast_struct_to_slice(&self.queries);
+        assert_eq!(bytes.len(), std::mem::size_of::<T>());
+        unsafe { &*(bytes.as_ptr() as *const T) }
+    }
+
+    fn get_queries_mut<T: Sized>(&mut self) -> &mut T {
+        let bytes = cast_struct_to_slice_mut(&mut self.queries);
+        assert_eq!(bytes.len(), std::mem::size_of::<T>());
+        unsafe { &mut *(bytes.as_mut_ptr() as *mut T) }
+    }
+
+    fn get_queries_and_proof_mut<T: Sized, P: Sized>(&mut self) -> (&mut T, &P) {
+        let queries_bytes = cast_struct_to_slice_mut(&mut self.queries);
+        let proof_bytes = cast_struct_to_slice(&self.proof);
+
+        assert_eq!(queries_bytes.len(), std::mem::size_of::<T>());
+        assert_eq!(proof_bytes.len(), std::mem::size_of::<P>());
+
+        let queries = unsafe { &mut *(queries_bytes.as_mut_ptr() as *mut T) };
+        let proof = unsafe { &*(proof_bytes.as_ptr() as *const P) };
+
+        (queries, proof)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::state::{BidirectionalStackAccount, CAPACITY};
-    use utils::BidirectionalStack;
+    use crate::state::{
+        AccountLayout, BidirectionalStackAccount, GlobalValues, CAPACITY, LENGTH_SIZE,
+    };
+    use stark::swiftness::air::layout::LayoutId;
+    use stark::swiftness::stark::types::StarkProof;
+    use utils::layout::LayoutStorage;
+    use utils::{BidirectionalStack, ProofData};
 
     #[test]
     fn test_default() {
@@ -299,6 +655,35 @@ mod tests {
         assert_eq!(stack.buffer, [0; CAPACITY]);
     }
 
+    #[test]
+    fn test_layout_buffers() {
+        let mut stack = BidirectionalStackAccount::default();
+        let (_, pows, domains, oods_values, _, coefficients, column_values) =
+            stack.get_proof_data_references::<StarkProof, GlobalValues>();
+
+        assert_eq!(pows.len(), AccountLayout::POWS_SIZE);
+        assert_eq!(domains.len(), AccountLayout::DOMAINS_SIZE);
+        assert_eq!(
+            oods_values.len(),
+            AccountLayout::MASK_SIZE + AccountLayout::CONSTRAINT_DEGREE
+        );
+        assert!(coefficients.len() >= AccountLayout::N_CONSTRAINTS);
+        assert!(coefficients.len() >= oods_values.len());
+        assert_eq!(
+            column_values.len(),
+            (AccountLayout::NUM_COLUMNS_FIRST + AccountLayout::NUM_COLUMNS_SECOND) as usize
+                + AccountLayout::CONSTRAINT_DEGREE
+        );
+
+        // Every supported layout fits in the buffers
+        for params in LayoutId::ALL.iter().filter_map(LayoutId::params) {
+            assert!(oods_values.len() >= params.oods_values_size());
+            assert!(coefficients.len() >= params.n_constraints);
+            assert!(coefficients.len() >= params.oods_values_size());
+            assert!(column_values.len() >= params.column_values_size());
+        }
+    }
+
     #[test]
     fn test_push_front_and_borrow_front() {
         let mut stack = BidirectionalStackAccount::default();
@@ -521,6 +906,28 @@ mod tests {
         assert_eq!(stack.borrow_back(), large_data.as_slice());
     }
 
+    #[test]
+    fn test_push_overflow() {
+        let mut stack = BidirectionalStackAccount::default();
+
+        // Fill the stack but for one push of 10 bytes
+        let data = vec![1; CAPACITY / 2 - LENGTH_SIZE];
+        stack.push_front(&data).unwrap();
+        stack
+            .push_back(&data[..data.len() - 10 - LENGTH_SIZE])
+            .unwrap();
+
+        assert!(stack.push_front(&[2; 11]).is_err());
+        assert!(stack.push_back(&[2; 11]).is_err());
+        assert_eq!(stack.front_index + 10 + LENGTH_SIZE, stack.back_index);
+
+        stack.push_back(&[2; 10]).unwrap();
+        assert_eq!(stack.front_index, stack.back_index);
+        assert!(stack.push_front(&[]).is_err());
+        assert_eq!(stack.borrow_front(), data.as_slice());
+        assert_eq!(stack.borrow_back(), &[2; 10]);
+    }
+
     #[test]
     fn test_alternating_operations() {
         let mut stack = BidirectionalStackAccount::default();
@@ -8,7 +8,7 @@ fn test_add_operation() {
     stack.push_task(add::Add::new(48, 52));
 
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
     }
 
     let result = u128::from_be_bytes(stack.borrow_front().try_into().unwrap());
@@ -22,7 +22,7 @@ fn test_multiply_operation() {
     stack.push_task(mul::Mul::new(5, 7));
 
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
     }
 
     let result = u128::from_be_bytes(stack.borrow_front().try_into().unwrap());
@@ -36,7 +36,7 @@ fn test_exponentiation_operation() {
     stack.push_task(exp::Exp::new(2, 10));
 
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
     }
 
     let result = u128::from_be_bytes(stack.borrow_front().try_into().unwrap());
@@ -50,7 +50,7 @@ fn test_fibonacci_operation() {
     stack.push_task(fib::Fibonacci::new(19));
 
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
     }
 
     let result = u128::from_be_bytes(stack.borrow_front().try_into().unwrap());
@@ -66,7 +66,7 @@ fn test_increment_operation() {
         stack.push_task(increment::Increment::new());
     }
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
     }
 
     let result = u128::from_be_bytes(stack.borrow_front().try_into().unwrap());
@@ -0,0 +1,177 @@
+use felt::Felt;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::signer::Signer;
+use verifier::fact::{compute_fact, find_fact_address, FactAccount};
+use verifier::instruction::{assert_fact_verified, register_fact as register_fact_instruction};
+use verifier::state::{VerificationState, VerifiedHashes};
+
+mod program_test;
+use program_test::{program_account, stack_account, TestBank};
+
+const VERIFIER_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+const CONSUMER_ID: Pubkey = Pubkey::new_from_array([9; 32]);
+
+// Dummy consumer program: marks its account as paid once the verifier asserts the fact of the
+// program hash and output hash passed in the instruction data.
+fn consumer_process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let consumer_account = next_account_info(accounts_iter)?;
+    let fact_account = next_account_info(accounts_iter)?;
+    let verifier_program = next_account_info(accounts_iter)?;
+
+    let program_hash: [u8; 32] = instruction_data[..32].try_into().unwrap();
+    let output_hash: [u8; 32] = instruction_data[32..].try_into().unwrap();
+    invoke(
+        &assert_fact_verified(verifier_program.key, &program_hash, &output_hash),
+        &[fact_account.clone(), verifier_program.clone()],
+    )?;
+
+    consumer_account.try_borrow_mut_data()?[0] = 1;
+    Ok(())
+}
+
+fn program_test() -> ProgramTest {
+    let mut program_test = program_test::program_test(VERIFIER_ID);
+    program_test.add_program(
+        "consumer",
+        CONSUMER_ID,
+        processor!(consumer_process_instruction),
+    );
+    program_test
+}
+
+#[tokio::test]
+async fn test_consumer_acts_on_verified_fact() {
+    let program_hash = Felt::from_hex_unchecked("0x1234").to_bytes_be();
+    let output_hash = Felt::from_hex_unchecked("0x5678").to_bytes_be();
+    let consumer_account = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_account(consumer_account, program_account(CONSUMER_ID, vec![0]));
+    let mut bank = TestBank::start(program_test).await;
+    register_fact(&mut bank, &program_hash, &output_hash).await;
+
+    call_consumer(&mut bank, &consumer_account, &program_hash, &output_hash)
+        .await
+        .unwrap();
+    assert_eq!(bank.data(&consumer_account).await, [1]);
+}
+
+#[tokio::test]
+async fn test_consumer_rejects_unregistered_fact() {
+    let program_hash = Felt::from_hex_unchecked("0x1234").to_bytes_be();
+    let output_hash = Felt::from_hex_unchecked("0x9abc").to_bytes_be();
+    let consumer_account = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_account(consumer_account, program_account(CONSUMER_ID, vec![0]));
+    let mut bank = TestBank::start(program_test).await;
+
+    assert_eq!(
+        call_consumer(&mut bank, &consumer_account, &program_hash, &output_hash).await,
+        Err(ProgramError::UninitializedAccount)
+    );
+    assert_eq!(bank.data(&consumer_account).await, [0]);
+}
+
+#[tokio::test]
+async fn test_assert_fact_verified_rejects_forged_record() {
+    let program_hash = Felt::from_hex_unchecked("0x1234").to_bytes_be();
+    let output_hash = Felt::from_hex_unchecked("0xdef0").to_bytes_be();
+    let mut bank = TestBank::start(program_test()).await;
+    let fact_address = register_fact(&mut bank, &program_hash, &output_hash).await;
+    let mut fact_account = bank.account(&fact_address).await.unwrap();
+    let instruction = assert_fact_verified(&VERIFIER_ID, &program_hash, &output_hash);
+
+    // A fact account of another program at the same address is not trusted
+    fact_account.owner = CONSUMER_ID;
+    bank.set_account(&fact_address, fact_account.clone());
+    assert_eq!(
+        bank.process(&[instruction.clone()], &[]).await,
+        Err(ProgramError::UninitializedAccount)
+    );
+
+    // Nor is the fact account of other hashes
+    fact_account.owner = VERIFIER_ID;
+    bank.set_account(&fact_address, fact_account.clone());
+    let other_output_hash = Felt::from_hex_unchecked("0xdef1").to_bytes_be();
+    let mut other_instruction =
+        assert_fact_verified(&VERIFIER_ID, &program_hash, &other_output_hash);
+    other_instruction.accounts[0].pubkey = fact_address;
+    assert_eq!(
+        bank.process(&[other_instruction], &[]).await,
+        Err(ProgramError::InvalidSeeds)
+    );
+
+    // Nor a record whose hashes differ from its address
+    FactAccount::cast_mut(&mut fact_account.data).output_hash[31] ^= 1;
+    bank.set_account(&fact_address, fact_account);
+    assert_eq!(
+        bank.process(&[instruction], &[]).await,
+        Err(ProgramError::InvalidAccountData)
+    );
+}
+
+// Registers the fact of the hashes left by Verify in a verifier account, returning the address
+// of the fact account
+async fn register_fact(
+    bank: &mut TestBank,
+    program_hash: &[u8; 32],
+    output_hash: &[u8; 32],
+) -> Pubkey {
+    let verifier_account = Pubkey::new_unique();
+    bank.set_account(
+        &verifier_account,
+        stack_account(VERIFIER_ID, |stack| {
+            stack.header.state = VerificationState::Verified;
+            stack.status.hashes = VerifiedHashes {
+                program_hash: Felt::from_bytes_be(program_hash),
+                output_hash: Felt::from_bytes_be(output_hash),
+                ..Default::default()
+            };
+        }),
+    );
+
+    let payer = bank.payer().pubkey();
+    let instruction = register_fact_instruction(
+        &VERIFIER_ID,
+        &verifier_account,
+        &payer,
+        program_hash,
+        output_hash,
+    );
+    bank.process(&[instruction], &[]).await.unwrap();
+
+    let (address, _) = find_fact_address(&VERIFIER_ID, &compute_fact(program_hash, output_hash));
+    address
+}
+
+async fn call_consumer(
+    bank: &mut TestBank,
+    consumer_account: &Pubkey,
+    program_hash: &[u8; 32],
+    output_hash: &[u8; 32],
+) -> Result<(), ProgramError> {
+    let (fact_address, _) =
+        find_fact_address(&VERIFIER_ID, &compute_fact(program_hash, output_hash));
+    let instruction = Instruction::new_with_bytes(
+        CONSUMER_ID,
+        &[program_hash.as_slice(), output_hash].concat(),
+        vec![
+            AccountMeta::new(*consumer_account, false),
+            AccountMeta::new_readonly(fact_address, false),
+            AccountMeta::new_readonly(VERIFIER_ID, false),
+        ],
+    );
+    bank.process(&[instruction], &[]).await
+}
@@ -0,0 +1,357 @@
+use arithmetic::add::Add;
+use solana_program::{
+    instruction::Instruction, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+};
+use solana_program_test::ProgramTest;
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
+use utils::{AccountCast, Executable};
+use verifier::fact::FactAccount;
+use verifier::instruction::{
+    close, execute, initialize, log_bootloader_output, push_data, push_task, register_fact, reset,
+    seal,
+};
+use verifier::state::{BidirectionalStackAccount, VerificationState, PROOF_SIZE};
+
+mod program_test;
+use program_test::{
+    new_stack_account, program_account, program_test, set_account_data_chunks, TestBank,
+};
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+const AUTHORITY_LAMPORTS: u64 = 1_000_000_000;
+
+// Verifier account in a running bank, with the keypairs of the account and of its authority
+struct Verifier {
+    bank: TestBank,
+    account: Keypair,
+    authority: Keypair,
+}
+
+impl Verifier {
+    async fn new() -> Self {
+        Self::with_account(new_stack_account(PROGRAM_ID)).await
+    }
+
+    async fn with_account(account: Account) -> Self {
+        Self::start(program_test(PROGRAM_ID), account).await
+    }
+
+    async fn start(mut program_test: ProgramTest, account: Account) -> Self {
+        let keypair = Keypair::new();
+        let authority = Keypair::new();
+        program_test.add_account(keypair.pubkey(), account);
+        program_test.add_account(
+            authority.pubkey(),
+            Account::new(AUTHORITY_LAMPORTS, 0, &SYSTEM_PROGRAM_ID),
+        );
+        Self {
+            bank: TestBank::start(program_test).await,
+            account: keypair,
+            authority,
+        }
+    }
+
+    // Verifier account initialized with the authority
+    async fn initialized() -> Self {
+        let mut verifier = Self::new().await;
+        verifier.initialize().await.unwrap();
+        verifier
+    }
+
+    fn key(&self) -> Pubkey {
+        self.account.pubkey()
+    }
+
+    async fn initialize(&mut self) -> Result<(), ProgramError> {
+        let instruction = initialize(&PROGRAM_ID, &self.key(), &self.authority.pubkey());
+        self.bank
+            .process(&[instruction], &[&self.account, &self.authority])
+            .await
+    }
+
+    async fn account(&mut self) -> Account {
+        let key = self.key();
+        self.bank.account(&key).await.unwrap()
+    }
+
+    async fn authority_lamports(&mut self) -> u64 {
+        let authority = self.authority.pubkey();
+        self.bank.account(&authority).await.unwrap().lamports
+    }
+}
+
+// Instructions of the authority, built for the given caller
+fn authority_instructions(account: &Pubkey, caller: &Pubkey) -> Vec<Instruction> {
+    let mut instructions = set_account_data_chunks(
+        &PROGRAM_ID,
+        account,
+        caller,
+        std::mem::offset_of!(BidirectionalStackAccount, proof),
+        &vec![0; PROOF_SIZE],
+    );
+    instructions.extend([
+        push_task(
+            &PROGRAM_ID,
+            account,
+            caller,
+            Add::new(1, 2).to_vec_with_type_tag(),
+        ),
+        push_data(&PROGRAM_ID, account, caller, vec![1; 8]),
+        seal(&PROGRAM_ID, account, caller, DEFAULT_SECURITY_BITS),
+        reset(&PROGRAM_ID, account, caller),
+    ]);
+    instructions
+}
+
+// The instruction with its authority, the second account, not signing
+fn unsigned(mut instruction: Instruction) -> Instruction {
+    instruction.accounts[1].is_signer = false;
+    instruction
+}
+
+#[tokio::test]
+async fn test_initialize_sets_authority() {
+    let mut verifier = Verifier::initialized().await;
+    let header = BidirectionalStackAccount::cast(&verifier.account().await.data).header;
+    assert_eq!(header.state, VerificationState::Uploading);
+    assert_eq!(header.authority, verifier.authority.pubkey());
+
+    // The authority signs the initialization
+    let mut verifier = Verifier::new().await;
+    let instruction = initialize(&PROGRAM_ID, &verifier.key(), &verifier.authority.pubkey());
+    assert_eq!(
+        verifier
+            .bank
+            .process(&[unsigned(instruction)], &[&verifier.account])
+            .await,
+        Err(ProgramError::MissingRequiredSignature)
+    );
+
+    // And so does the verifier account, so that no one else can initialize it before its creator
+    let other = Keypair::new();
+    let mut instruction = initialize(&PROGRAM_ID, &verifier.key(), &other.pubkey());
+    instruction.accounts[0].is_signer = false;
+    assert_eq!(
+        verifier.bank.process(&[instruction], &[&other]).await,
+        Err(ProgramError::MissingRequiredSignature)
+    );
+    assert_eq!(
+        BidirectionalStackAccount::cast(&verifier.account().await.data)
+            .header
+            .state,
+        VerificationState::Uninitialized
+    );
+    verifier.initialize().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_rejects_unauthorized_callers() {
+    let mut verifier = Verifier::initialized().await;
+    let snapshot = verifier.account().await.data;
+    let key = verifier.key();
+
+    // Another signer
+    let other = Keypair::new();
+    for instruction in authority_instructions(&key, &other.pubkey()) {
+        assert_eq!(
+            verifier.bank.process(&[instruction], &[&other]).await,
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+
+    // The authority without its signature
+    let authority = verifier.authority.pubkey();
+    for instruction in authority_instructions(&key, &authority) {
+        assert_eq!(
+            verifier.bank.process(&[unsigned(instruction)], &[]).await,
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+    assert!(verifier.account().await.data == snapshot);
+
+    // The authority
+    for instruction in authority_instructions(&key, &authority) {
+        verifier
+            .bank
+            .process(&[instruction], &[&verifier.authority])
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_close_requires_authority() {
+    let uninitialized_account = Pubkey::new_unique();
+    let mut program_test = program_test(PROGRAM_ID);
+    program_test.add_account(uninitialized_account, new_stack_account(PROGRAM_ID));
+    let mut verifier = Verifier::start(program_test, new_stack_account(PROGRAM_ID)).await;
+    verifier.initialize().await.unwrap();
+    let key = verifier.key();
+    let lamports = verifier.account().await.lamports;
+
+    // The lamports only go to the authority
+    let other = Keypair::new();
+    let instruction = close(&PROGRAM_ID, &key, &other.pubkey());
+    assert_eq!(
+        verifier.bank.process(&[instruction], &[&other]).await,
+        Err(ProgramError::MissingRequiredSignature)
+    );
+
+    let instruction = close(&PROGRAM_ID, &key, &verifier.authority.pubkey());
+    assert_eq!(
+        verifier
+            .bank
+            .process(&[unsigned(instruction.clone())], &[])
+            .await,
+        Err(ProgramError::MissingRequiredSignature)
+    );
+
+    let mut fake_system_program = instruction.clone();
+    fake_system_program.accounts[2].pubkey = Pubkey::new_unique();
+    assert_eq!(
+        verifier
+            .bank
+            .process(&[fake_system_program], &[&verifier.authority])
+            .await,
+        Err(ProgramError::IncorrectProgramId)
+    );
+    let account = verifier.account().await;
+    assert_eq!(account.lamports, lamports);
+    assert_eq!(account.owner, PROGRAM_ID);
+
+    // An uninitialized account has no authority
+    let instruction = close(
+        &PROGRAM_ID,
+        &uninitialized_account,
+        &verifier.authority.pubkey(),
+    );
+    assert_eq!(
+        verifier
+            .bank
+            .process(&[instruction], &[&verifier.authority])
+            .await,
+        Err(ProgramError::UninitializedAccount)
+    );
+
+    // The authority
+    let instruction = close(&PROGRAM_ID, &key, &verifier.authority.pubkey());
+    verifier
+        .bank
+        .process(&[instruction], &[&verifier.authority])
+        .await
+        .unwrap();
+    // The closed account keeps the rent of an empty account
+    let rent = Rent::default().minimum_balance(0);
+    let account = verifier.account().await;
+    assert_eq!(account.lamports, rent);
+    assert_eq!(account.owner, SYSTEM_PROGRAM_ID);
+    assert!(account.data.is_empty());
+    assert_eq!(
+        verifier.authority_lamports().await,
+        AUTHORITY_LAMPORTS + lamports - rent
+    );
+}
+
+#[tokio::test]
+async fn test_close_rejects_insufficient_funds() {
+    // The account holds less than the rent of an empty account
+    let mut verifier = Verifier::with_account(Account {
+        lamports: 1,
+        ..new_stack_account(PROGRAM_ID)
+    })
+    .await;
+    verifier.initialize().await.unwrap();
+
+    let instruction = close(&PROGRAM_ID, &verifier.key(), &verifier.authority.pubkey());
+    assert_eq!(
+        verifier
+            .bank
+            .process(&[instruction], &[&verifier.authority])
+            .await,
+        Err(ProgramError::InsufficientFunds)
+    );
+    let account = verifier.account().await;
+    assert_eq!(account.lamports, 1);
+    assert_eq!(account.owner, PROGRAM_ID);
+    assert_eq!(verifier.authority_lamports().await, AUTHORITY_LAMPORTS);
+}
+
+#[tokio::test]
+async fn test_rejects_foreign_verifier_account() {
+    let mut verifier = Verifier::initialized().await;
+    let key = verifier.key();
+    let mut account = verifier.account().await;
+    account.owner = Pubkey::new_unique();
+    verifier.bank.set_account(&key, account);
+
+    let authority = verifier.authority.pubkey();
+    for instruction in authority_instructions(&key, &authority) {
+        assert_eq!(
+            verifier
+                .bank
+                .process(&[instruction], &[&verifier.authority])
+                .await,
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+    assert_eq!(
+        verifier
+            .bank
+            .process(&[execute(&PROGRAM_ID, &key, 0)], &[])
+            .await,
+        Err(ProgramError::IncorrectProgramId)
+    );
+
+    let mut verifier = Verifier::new().await;
+    let mut instruction = initialize(&PROGRAM_ID, &verifier.key(), &verifier.authority.pubkey());
+    instruction.accounts[0].is_writable = false;
+    assert_eq!(
+        verifier
+            .bank
+            .process(&[instruction], &[&verifier.account, &verifier.authority])
+            .await,
+        Err(ProgramError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn test_rejects_verifier_account_of_other_size() {
+    // An account of the program holding something else than a stack, like a fact account
+    let mut verifier =
+        Verifier::with_account(program_account(PROGRAM_ID, vec![0; FactAccount::SIZE])).await;
+    let key = verifier.key();
+
+    assert_eq!(
+        verifier.initialize().await,
+        Err(ProgramError::InvalidAccountData)
+    );
+    let authority = verifier.authority.pubkey();
+    let mut instructions = authority_instructions(&key, &authority);
+    instructions.push(close(&PROGRAM_ID, &key, &authority));
+    for instruction in instructions {
+        assert_eq!(
+            verifier
+                .bank
+                .process(&[instruction], &[&verifier.authority])
+                .await,
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+    let payer = verifier.bank.payer().pubkey();
+    for instruction in [
+        execute(&PROGRAM_ID, &key, 0),
+        log_bootloader_output(&PROGRAM_ID, &key),
+        register_fact(&PROGRAM_ID, &key, &payer, &[1; 32], &[2; 32]),
+    ] {
+        assert_eq!(
+            verifier.bank.process(&[instruction], &[]).await,
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+    assert_eq!(
+        verifier.account().await.lamports,
+        Rent::default().minimum_balance(FactAccount::SIZE)
+    );
+}
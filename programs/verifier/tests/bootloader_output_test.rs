@@ -0,0 +1,179 @@
+use felt::Felt;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_sdk::account::Account;
+use stark::funvec::FunVec;
+use stark::stark_proof::bootloader::{
+    decode_bootloader_output, output_values, BootloaderOutputError, TaskOutput,
+};
+use stark::stark_proof::segments;
+use stark::swiftness::air::public_memory::PublicInput;
+use stark::swiftness::air::types::{AddrValue, ContinuousPageHeader, Page, SegmentInfo};
+use utils::AccountCast;
+use verifier::instruction::log_bootloader_output;
+use verifier::state::{BidirectionalStackAccount, VerificationState};
+
+mod program_test;
+use program_test::{program_test, stack_account, TestBank};
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+fn felts(values: &[u64]) -> Vec<Felt> {
+    values.iter().map(|value| Felt::from(*value)).collect()
+}
+
+#[test]
+fn test_decode_bootloader_output() {
+    // Two tasks, the second one without output
+    let output = felts(&[2, 5, 0xaaa, 1, 2, 3, 2, 0xbbb]);
+    let bootloader_output = decode_bootloader_output(&output).unwrap();
+    assert_eq!(
+        bootloader_output.tasks,
+        [
+            TaskOutput {
+                program_hash: Felt::from(0xaaa),
+                output: &felts(&[1, 2, 3]),
+            },
+            TaskOutput {
+                program_hash: Felt::from(0xbbb),
+                output: &[],
+            },
+        ]
+    );
+
+    let output = felts(&[0]);
+    assert!(decode_bootloader_output(&output).unwrap().tasks.is_empty());
+}
+
+#[test]
+fn test_decode_invalid_bootloader_output() {
+    assert_eq!(
+        decode_bootloader_output(&[]),
+        Err(BootloaderOutputError::InvalidTaskCount)
+    );
+    // More tasks than the output can hold
+    assert_eq!(
+        decode_bootloader_output(&felts(&[2, 2, 0xaaa])),
+        Err(BootloaderOutputError::InvalidTaskCount)
+    );
+    assert_eq!(
+        decode_bootloader_output(&[Felt::MAX, Felt::ZERO, Felt::ZERO]),
+        Err(BootloaderOutputError::InvalidTaskCount)
+    );
+    // The output size counts the header
+    assert_eq!(
+        decode_bootloader_output(&felts(&[1, 1, 0xaaa])),
+        Err(BootloaderOutputError::InvalidOutputSize { task: 0 })
+    );
+    assert_eq!(
+        decode_bootloader_output(&felts(&[2, 2, 0xaaa, 4, 0xbbb, 1])),
+        Err(BootloaderOutputError::InvalidOutputSize { task: 1 })
+    );
+    assert_eq!(
+        decode_bootloader_output(&felts(&[1, 3, 0xaaa, 1, 7, 8])),
+        Err(BootloaderOutputError::TrailingOutput { len: 2 })
+    );
+}
+
+#[test]
+fn test_output_values() {
+    let output = felts(&[1, 4, 0xaaa, 10, 11]);
+    let mut public_input = public_input(&output);
+    assert_eq!(output_values(&public_input), Ok(output));
+
+    public_input.continuous_page_headers = FunVec::from_vec(vec![ContinuousPageHeader {
+        start_address: Felt::from(104),
+        size: Felt::ONE,
+        hash: Felt::ZERO,
+        prod: Felt::ZERO,
+    }]);
+    assert_eq!(
+        output_values(&public_input),
+        Err(BootloaderOutputError::IncompleteOutput)
+    );
+}
+
+#[tokio::test]
+async fn test_log_bootloader_output() {
+    let account = Pubkey::new_unique();
+    let other_account = Pubkey::new_unique();
+    let mut program_test = program_test(PROGRAM_ID);
+    program_test.add_account(account, verified_account(&felts(&[1, 4, 0xaaa, 10, 11])));
+    program_test.add_account(
+        other_account,
+        verified_account(&felts(&[1, 5, 0xaaa, 10, 11])),
+    );
+    let mut bank = TestBank::start(program_test).await;
+
+    let instruction = log_bootloader_output(&PROGRAM_ID, &account);
+    assert_eq!(bank.process(&[instruction], &[]).await, Ok(()));
+
+    let instruction = log_bootloader_output(&PROGRAM_ID, &other_account);
+    assert_eq!(
+        bank.process(&[instruction], &[]).await,
+        Err(ProgramError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn test_log_bootloader_output_rejects_unverified_proof() {
+    let account = Pubkey::new_unique();
+    let mut verifying_account = verified_account(&felts(&[1, 4, 0xaaa, 10, 11]));
+    BidirectionalStackAccount::cast_mut(&mut verifying_account.data)
+        .header
+        .state = VerificationState::Verifying;
+    let mut program_test = program_test(PROGRAM_ID);
+    program_test.add_account(account, verifying_account);
+    let mut bank = TestBank::start(program_test).await;
+
+    let instruction = log_bootloader_output(&PROGRAM_ID, &account);
+    assert_eq!(
+        bank.process(&[instruction.clone()], &[]).await,
+        Err(ProgramError::InvalidAccountData)
+    );
+
+    let mut foreign_account = verified_account(&felts(&[1, 4, 0xaaa, 10, 11]));
+    foreign_account.owner = Pubkey::new_unique();
+    bank.set_account(&account, foreign_account);
+    assert_eq!(
+        bank.process(&[instruction], &[]).await,
+        Err(ProgramError::IncorrectProgramId)
+    );
+}
+
+// Public input of a run whose program is at [1, 4) and whose output is at [100, 100 + len), the
+// output written after the program on the main page
+fn public_input(output: &[Felt]) -> PublicInput {
+    let program = felts(&[0x10, 0x20, 0x30]);
+    let mut segment_info = vec![SegmentInfo::default(); segments::N_SEGMENTS];
+    segment_info[segments::PROGRAM] = SegmentInfo {
+        begin_addr: Felt::from(1),
+        stop_ptr: Felt::from(4),
+    };
+    segment_info[segments::OUTPUT] = SegmentInfo {
+        begin_addr: Felt::from(100),
+        stop_ptr: Felt::from(100 + output.len()),
+    };
+
+    let cells = program
+        .iter()
+        .zip(1u64..)
+        .chain(output.iter().zip(100u64..))
+        .map(|(value, address)| AddrValue {
+            address: Felt::from(address),
+            value: *value,
+        })
+        .collect();
+    PublicInput {
+        segments: FunVec::from_vec(segment_info),
+        main_page: Page(FunVec::from_vec(cells)),
+        ..Default::default()
+    }
+}
+
+// Verifier account in the state left by a finished Verify task, for a proof with the given output
+fn verified_account(output: &[Felt]) -> Account {
+    stack_account(PROGRAM_ID, |stack| {
+        stack.header.state = VerificationState::Verified;
+        stack.proof.public_input = public_input(output);
+    })
+}
@@ -1,9 +1,10 @@
 use felt::Felt;
 use stark::stark_proof::stark_commit::eval_composition_polynomial_inner::EvalCompositionPolynomialInner;
-use stark::swiftness::air::recursive_with_poseidon::GlobalValues;
+use stark::swiftness::air::layout::codes;
+use stark::swiftness::air::recursive_with_poseidon::{GlobalValues, PARAMS};
 use stark::swiftness::stark::types::StarkProof;
 use utils::global_values::EcPoint;
-use utils::{BidirectionalStack, Scheduler, OODS_VALUES_SIZE};
+use utils::{BidirectionalStack, ProofData, Scheduler};
 use verifier::state::BidirectionalStackAccount;
 mod fixtures;
 use fixtures::{fri_config, fri_unsent_commitment, oods_values, stark_config};
@@ -18,14 +19,14 @@ fn test_eval_composition_polynomial_inner() {
     proof.config.fri = fri_config::get();
     proof.unsent_commitment.fri = fri_unsent_commitment::get();
     proof.config = stark_config::get();
+    proof.public_input.layout = codes::RECURSIVE_WITH_POSEIDON;
     stack.proof = proof;
     let oods_values = oods_values::get();
-    let oods_slice = &oods_values.as_slice()[0..OODS_VALUES_SIZE];
-    stack.oods_values = oods_slice.try_into().unwrap();
-    stack.constraint_coefficients = constraint_coefficients::get()
-        .as_slice()
-        .try_into()
-        .unwrap();
+    let oods_slice = &oods_values.as_slice()[0..PARAMS.oods_values_size()];
+    stack.oods_values[..oods_slice.len()].copy_from_slice(oods_slice);
+    let constraint_coefficients = constraint_coefficients::get();
+    stack.constraint_coefficients[..constraint_coefficients.len()]
+        .copy_from_slice(constraint_coefficients.as_slice());
 
     let global_values = GlobalValues {
         trace_length: Felt::from_hex("0x10000000").unwrap(),
@@ -113,7 +114,7 @@ fn test_eval_composition_polynomial_inner() {
     };
 
     // Set global values in the stack account
-    stack.global_values = global_values;
+    stack.set_global_values(global_values);
 
     let point = Felt::from_hex("0x49185430497be4bd990699e70b3b91b25c0dd22d5cd436dbf23f364136368bc")
         .unwrap();
@@ -128,7 +129,7 @@ fn test_eval_composition_polynomial_inner() {
 
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
@@ -142,7 +143,7 @@ fn test_eval_composition_polynomial_inner() {
         &stack.constraint_coefficients,
         &point,
         &trace_generator,
-        &stack.global_values,
+        stack.get_global_values::<GlobalValues>(),
     );
 
     println!("Expected result: {:?}", expected_result);
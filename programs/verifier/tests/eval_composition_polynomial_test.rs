@@ -1,7 +1,13 @@
 use felt::Felt;
+use stark::funvec::FunVec;
 use stark::stark_proof::stark_commit::eval_composition_polynomial::EvalCompositionPolynomial;
+use stark::swiftness::air::layout::{codes, LayoutParams};
+use stark::swiftness::air::recursive_with_poseidon::PARAMS;
+use stark::swiftness::air::types::SegmentInfo;
+use stark::swiftness::air::{recursive, small, starknet};
 use stark::swiftness::stark::types::StarkProof;
-use utils::{BidirectionalStack, Scheduler, OODS_VALUES_SIZE};
+use utils::global_values::InteractionElements;
+use utils::{BidirectionalStack, Scheduler};
 use verifier::state::BidirectionalStackAccount;
 mod fixtures;
 use fixtures::{fri_config, fri_unsent_commitment, oods_values, public_input, stark_config};
@@ -21,12 +27,11 @@ fn test_eval_composition_polynomial() {
     stack.proof = proof;
 
     let oods_values = oods_values::get();
-    let oods_slice = &oods_values.as_slice()[0..OODS_VALUES_SIZE];
-    stack.oods_values = oods_slice.try_into().unwrap();
-    stack.constraint_coefficients = constraint_coefficients::get()
-        .as_slice()
-        .try_into()
-        .unwrap();
+    let oods_slice = &oods_values.as_slice()[0..PARAMS.oods_values_size()];
+    stack.oods_values[..oods_slice.len()].copy_from_slice(oods_slice);
+    let constraint_coefficients = constraint_coefficients::get();
+    stack.constraint_coefficients[..constraint_coefficients.len()]
+        .copy_from_slice(constraint_coefficients.as_slice());
 
     // Test parameters
     let point = Felt::from_hex("0x49185430497be4bd990699e70b3b91b25c0dd22d5cd436dbf23f364136368bc")
@@ -85,7 +90,7 @@ fn test_eval_composition_polynomial() {
     let mut steps = 0;
 
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
@@ -111,3 +116,102 @@ fn test_eval_composition_polynomial() {
     assert!(stack.is_empty_back(), "Stack should be empty");
     assert!(stack.is_empty_front(), "Stack should be empty");
 }
+
+// Evaluates the composition polynomial of the given layout on synthetic inputs. The tests below
+// only pin the result of each layout on these inputs to catch regressions: unlike the
+// recursive_with_poseidon layout above, they have no reference values from the Cairo verifier.
+// Their mask order is checked against the layout independent OODS polynomial in
+// eval_oods_polynomial_inner_test.rs.
+fn eval_layout(layout: Felt, params: &LayoutParams) -> Felt {
+    let mut stack = BidirectionalStackAccount::default();
+    let mut proof = StarkProof::default();
+
+    proof.public_input.log_n_steps = Felt::from_hex_unchecked("0x10");
+    proof.public_input.range_check_min = Felt::from_hex_unchecked("0x10");
+    proof.public_input.range_check_max = Felt::from_hex_unchecked("0xfff0");
+    proof.public_input.layout = layout;
+    proof.public_input.segments = FunVec::from_vec(
+        (1..=params.n_segments as u64)
+            .map(|i| SegmentInfo {
+                begin_addr: Felt::from(0x1000 * i),
+                stop_ptr: Felt::from(0x1000 * i + 0x10 * i),
+            })
+            .collect(),
+    );
+    proof.public_input.padding_addr = Felt::ONE;
+    proof.public_input.padding_value = Felt::TWO;
+    stack.proof = proof;
+
+    for i in 0..params.mask_size {
+        stack.oods_values[i] = Felt::from((i as u64 + 1) * 0x9e3779b9);
+    }
+    for i in 0..params.n_constraints {
+        stack.constraint_coefficients[i] = Felt::from(i as u64 * 7 + 1);
+    }
+
+    let elements: Vec<Felt> = (0..6u64)
+        .map(|j| {
+            if j < params.n_interaction_elements as u64 {
+                Felt::from(0x1000 + j)
+            } else {
+                Felt::ZERO
+            }
+        })
+        .collect();
+    stack.stark_commitment.traces.interaction_elements = InteractionElements {
+        memory_multi_column_perm_perm_interaction_elm: elements[0],
+        memory_multi_column_perm_hash_interaction_elm0: elements[1],
+        range_check16_perm_interaction_elm: elements[2],
+        diluted_check_permutation_interaction_elm: elements[3],
+        diluted_check_interaction_z: elements[4],
+        diluted_check_interaction_alpha: elements[5],
+    };
+
+    let point = Felt::from_hex("0x49185430497be4bd990699e70b3b91b25c0dd22d5cd436dbf23f364136368bc")
+        .unwrap();
+    let trace_generator =
+        Felt::from_hex("0x57a797181c06d8427145cb66056f032751615d8617c5468258e96d2bb6422f9")
+            .unwrap();
+    let trace_domain_size = Felt::from_hex("0x100000").unwrap(); // 2^20
+
+    stack.push_front(&trace_domain_size.to_bytes_be()).unwrap();
+    stack.push_front(&trace_generator.to_bytes_be()).unwrap();
+    stack.push_front(&point.to_bytes_be()).unwrap();
+    stack.push_task(EvalCompositionPolynomial::new());
+
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    let result = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    assert!(stack.is_empty_front(), "Stack should be empty");
+    result
+}
+
+#[test]
+fn test_eval_composition_polynomial_recursive() {
+    assert_eq!(
+        eval_layout(codes::RECURSIVE, &recursive::PARAMS),
+        Felt::from_hex("0x5e8069ebc1c86db1fd91132bc7e725315bc175d9b467a3a19722210b7468fc1")
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_eval_composition_polynomial_small() {
+    assert_eq!(
+        eval_layout(codes::SMALL, &small::PARAMS),
+        Felt::from_hex("0x2753f8b3b31f313aa594d945333d1b597580ee24c18965b13058595254576f1")
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_eval_composition_polynomial_starknet() {
+    assert_eq!(
+        eval_layout(codes::STARKNET, &starknet::PARAMS),
+        Felt::from_hex("0x27ceb85aec92ce6fb01e0d9048cd7cffd99cb1f37427886d8da4ab035c1204f")
+            .unwrap()
+    );
+}
@@ -0,0 +1,123 @@
+use felt::Felt;
+use stark::funvec::FunVec;
+use stark::stark_proof::stark_commit::eval_oods_polynomial_inner::EvalOodsPolynomialInner;
+use stark::stark_proof::stark_verify::EvalOodsBoundaryPolyAtPoints;
+use stark::swiftness::air::domains::{bit_reverse_u64, FIELD_GENERATOR};
+use stark::swiftness::air::layout::codes;
+use stark::swiftness::air::recursive_with_poseidon::PARAMS;
+use utils::{BidirectionalStack, Scheduler};
+use verifier::state::BidirectionalStackAccount;
+mod fixtures;
+use fixtures::{constraint_coefficients, oods_values, stark_domains};
+
+#[test]
+fn test_eval_oods_boundary_poly_at_points() {
+    let mut stack = BidirectionalStackAccount::default();
+    stack.proof.public_input.layout = codes::RECURSIVE_WITH_POSEIDON;
+
+    let domains = stark_domains::get();
+    let log_eval_domain_size: u32 = domains.log_eval_domain_size.try_into().unwrap();
+    let oods_point =
+        Felt::from_hex("0x49185430497be4bd990699e70b3b91b25c0dd22d5cd436dbf23f364136368bc")
+            .unwrap();
+    let oods_values = oods_values::get().as_slice()[..PARAMS.oods_values_size()].to_vec();
+    let oods_coefficients = constraint_coefficients::get().as_slice().to_vec();
+
+    let queries = [3u64, 0x1234, 0x8000_0001, 0xfedc_ba98];
+    let n_original = PARAMS.num_columns_first as usize;
+    let n_interaction = PARAMS.num_columns_second as usize;
+    let constraint_degree = PARAMS.constraint_degree;
+    let row = |query: usize, column: usize| Felt::from(query * 0x1000 + column * 0x31 + 1);
+    let original: Vec<Felt> = (0..queries.len())
+        .flat_map(|q| (0..n_original).map(move |c| row(q, c)))
+        .collect();
+    let interaction: Vec<Felt> = (0..queries.len())
+        .flat_map(|q| (0..n_interaction).map(move |c| row(q, n_original + c)))
+        .collect();
+    let composition: Vec<Felt> = (0..queries.len())
+        .flat_map(|q| (0..constraint_degree).map(move |c| row(q, n_original + n_interaction + c)))
+        .collect();
+
+    let witness = &mut stack.proof.witness;
+    witness.traces_decommitment.original.values = FunVec::from_vec(original.clone());
+    witness.traces_decommitment.interaction.values = FunVec::from_vec(interaction.clone());
+    witness.composition_decommitment.values = FunVec::from_vec(composition.clone());
+
+    stack.stark_commitment.interaction_after_composition = oods_point;
+    stack.stark_commitment.oods_values = FunVec::from_vec(oods_values.clone());
+    stack.stark_commitment.interaction_after_oods = FunVec::from_vec(oods_coefficients.clone());
+
+    stack.queries.queries.flush();
+    for query in queries {
+        stack.queries.queries.push(Felt::from(query));
+    }
+
+    stack.push_task(EvalOodsBoundaryPolyAtPoints::new(
+        log_eval_domain_size,
+        domains.eval_generator,
+        domains.trace_generator,
+    ));
+
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    assert_eq!(stack.queries.fri_queries.len(), queries.len());
+    for (q, &query) in queries.iter().enumerate() {
+        let point = FIELD_GENERATOR
+            * domains
+                .eval_generator
+                .pow(bit_reverse_u64(query, log_eval_domain_size));
+
+        let mut column_values = original[q * n_original..(q + 1) * n_original].to_vec();
+        column_values.extend_from_slice(&interaction[q * n_interaction..(q + 1) * n_interaction]);
+        column_values
+            .extend_from_slice(&composition[q * constraint_degree..(q + 1) * constraint_degree]);
+        let expected = eval_oods_reference(
+            &column_values,
+            &oods_values,
+            &oods_coefficients,
+            point,
+            oods_point,
+            domains.trace_generator,
+        );
+
+        let fri_query = stack.queries.fri_queries.at(q);
+        assert_eq!(fri_query.index, Felt::from(query));
+        assert_eq!(fri_query.y_value, expected);
+        assert_eq!(fri_query.x_inv_value * point, FIELD_GENERATOR);
+    }
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+// Evaluates the OODS polynomial at a single point on a fresh account, computing the trace
+// generator powers from scratch.
+fn eval_oods_reference(
+    column_values: &[Felt],
+    oods_values: &[Felt],
+    oods_coefficients: &[Felt],
+    point: Felt,
+    oods_point: Felt,
+    trace_generator: Felt,
+) -> Felt {
+    let mut stack = BidirectionalStackAccount::default();
+    stack.proof.public_input.layout = codes::RECURSIVE_WITH_POSEIDON;
+    stack.column_values[..column_values.len()].copy_from_slice(column_values);
+    stack.oods_values[..oods_values.len()].copy_from_slice(oods_values);
+    stack.constraint_coefficients[..oods_coefficients.len()].copy_from_slice(oods_coefficients);
+
+    stack.push_front(&trace_generator.to_bytes_be()).unwrap();
+    stack.push_front(&oods_point.to_bytes_be()).unwrap();
+    stack.push_front(&point.to_bytes_be()).unwrap();
+    stack.push_task(EvalOodsPolynomialInner::new());
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    let result = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    result
+}
@@ -1,9 +1,11 @@
 use felt::Felt;
 use stark::stark_proof::stark_commit::eval_oods_polynomial_inner::EvalOodsPolynomialInner;
-use stark::swiftness::air::recursive_with_poseidon::GlobalValues;
+use stark::swiftness::air::layout::{codes, LayoutParams};
+use stark::swiftness::air::recursive_with_poseidon::{GlobalValues, PARAMS};
+use stark::swiftness::air::{recursive, small, starknet};
 use stark::swiftness::stark::types::StarkProof;
 use utils::global_values::EcPoint;
-use utils::{BidirectionalStack, Scheduler, OODS_VALUES_SIZE};
+use utils::{BidirectionalStack, ProofData, Scheduler};
 use verifier::state::BidirectionalStackAccount;
 mod fixtures;
 use fixtures::{fri_config, fri_unsent_commitment, oods_values, stark_config};
@@ -19,6 +21,7 @@ fn test_eval_oods_polynomial_inner() {
     proof.config.fri = fri_config::get();
     proof.unsent_commitment.fri = fri_unsent_commitment::get();
     proof.config = stark_config::get();
+    proof.public_input.layout = codes::RECURSIVE_WITH_POSEIDON;
     stack.proof = proof;
 
     let column_values = vec![
@@ -44,14 +47,13 @@ fn test_eval_oods_polynomial_inner() {
     ];
 
     let oods_values = oods_values::get();
-    let oods_slice = &oods_values.as_slice()[0..OODS_VALUES_SIZE];
+    let oods_slice = &oods_values.as_slice()[0..PARAMS.oods_values_size()];
 
-    stack.column_values = column_values.try_into().unwrap();
-    stack.oods_values = oods_slice.try_into().unwrap();
-    stack.constraint_coefficients = constraint_coefficients::get()
-        .as_slice()
-        .try_into()
-        .unwrap();
+    stack.column_values[..column_values.len()].copy_from_slice(&column_values);
+    stack.oods_values[..oods_slice.len()].copy_from_slice(oods_slice);
+    let constraint_coefficients = constraint_coefficients::get();
+    stack.constraint_coefficients[..constraint_coefficients.len()]
+        .copy_from_slice(constraint_coefficients.as_slice());
 
     let global_values = GlobalValues {
         trace_length: Felt::from_hex("0x10000000").unwrap(),
@@ -138,7 +140,7 @@ fn test_eval_oods_polynomial_inner() {
         .unwrap(),
     };
 
-    stack.global_values = global_values;
+    stack.set_global_values(global_values);
 
     let point = Felt::from_hex("0x7f90255cc310f54635400a0fc3ad5d4dcd9afb685485297d828f04cb9c29fcb")
         .unwrap();
@@ -157,7 +159,7 @@ fn test_eval_oods_polynomial_inner() {
 
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
@@ -184,6 +186,174 @@ fn test_eval_oods_polynomial_inner() {
     assert_eq!(result, expected_result);
 }
 
+const POINT: Felt =
+    Felt::from_hex_unchecked("0x7f90255cc310f54635400a0fc3ad5d4dcd9afb685485297d828f04cb9c29fcb");
+const OODS_POINT: Felt =
+    Felt::from_hex_unchecked("0x49185430497be4bd990699e70b3b91b25c0dd22d5cd436dbf23f364136368bc");
+const TRACE_GENERATOR: Felt =
+    Felt::from_hex_unchecked("0x57a797181c06d8427145cb66056f032751615d8617c5468258e96d2bb6422f9");
+
+// Synthetic inputs of the OODS polynomial of a layout.
+fn column_values(params: &LayoutParams) -> Vec<Felt> {
+    (0..params.column_values_size() as u64)
+        .map(|i| Felt::from(i * 0x31 + 1))
+        .collect()
+}
+
+fn oods_values(params: &LayoutParams) -> Vec<Felt> {
+    (0..params.oods_values_size() as u64)
+        .map(|i| Felt::from(i * 0x17 + 2))
+        .collect()
+}
+
+fn coefficients(params: &LayoutParams) -> Vec<Felt> {
+    (0..params.oods_values_size() as u64)
+        .map(|i| Felt::from(i * 7 + 1))
+        .collect()
+}
+
+// Evaluates the OODS polynomial of the given layout on the synthetic inputs.
+fn eval_layout(layout: Felt, params: &LayoutParams) -> Felt {
+    let mut stack = BidirectionalStackAccount::default();
+    let mut proof = StarkProof::default();
+    proof.public_input.layout = layout;
+    stack.proof = proof;
+
+    for (i, value) in column_values(params).into_iter().enumerate() {
+        stack.column_values[i] = value;
+    }
+    for (i, value) in oods_values(params).into_iter().enumerate() {
+        stack.oods_values[i] = value;
+    }
+    for (i, value) in coefficients(params).into_iter().enumerate() {
+        stack.constraint_coefficients[i] = value;
+    }
+
+    stack.push_front(&TRACE_GENERATOR.to_bytes_be()).unwrap();
+    stack.push_front(&OODS_POINT.to_bytes_be()).unwrap();
+    stack.push_front(&POINT.to_bytes_be()).unwrap();
+    stack.push_task(EvalOodsPolynomialInner::new());
+
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    let result = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    assert!(stack.is_empty_front(), "Stack should be empty");
+    result
+}
+
+// Mask of a layout as (column, row offset) pairs, read from the column_row macro of its
+// autogenerated constraints, which also indexes the mask values of the composition polynomial.
+fn mask(autogenerated: &str) -> Vec<(usize, u64)> {
+    let mut mask = vec![];
+    let mut lines = autogenerated.lines();
+    while let Some(line) = lines.next() {
+        let Some(arm) = line.trim().strip_prefix("($mask_values:expr, ") else {
+            continue;
+        };
+        let (column, row) = arm
+            .strip_suffix(") => {")
+            .unwrap()
+            .split_once(", ")
+            .unwrap();
+        let value = lines.next().unwrap().split("//").next().unwrap().trim();
+        let index = value
+            .strip_prefix("$mask_values[")
+            .and_then(|value| value.strip_suffix(']'))
+            .unwrap();
+        assert_eq!(index.parse::<usize>().unwrap(), mask.len());
+        mask.push((column.parse().unwrap(), row.parse().unwrap()));
+    }
+    mask
+}
+
+// Layout independent definition of the OODS polynomial on the synthetic inputs: the sum of
+// (column(x) - column(g^row * z)) / (x - g^row * z) over the mask, then of
+// (composition(x) - composition(z^degree)) / (x - z^degree) over the composition columns,
+// each weighted by its coefficient.
+fn oods_polynomial_reference(autogenerated: &str, params: &LayoutParams) -> Felt {
+    let mask = mask(autogenerated);
+    assert_eq!(mask.len(), params.mask_size);
+
+    let column_values = column_values(params);
+    let oods_values = oods_values(params);
+    let coefficients = coefficients(params);
+    let n_columns = (params.num_columns_first + params.num_columns_second) as usize;
+
+    let mut total_sum = Felt::ZERO;
+    for (i, &(column, row)) in mask.iter().enumerate() {
+        assert!(column < n_columns);
+        let shifted_point = TRACE_GENERATOR.pow(row) * OODS_POINT;
+        let value = (column_values[column] - oods_values[i])
+            .field_div(&felt_nonzero!(POINT - shifted_point));
+        total_sum += coefficients[i] * value;
+    }
+    let oods_point_to_deg = OODS_POINT.pow(params.constraint_degree as u64);
+    for j in 0..params.constraint_degree {
+        let i = params.mask_size + j;
+        let value = (column_values[n_columns + j] - oods_values[i])
+            .field_div(&felt_nonzero!(POINT - oods_point_to_deg));
+        total_sum += coefficients[i] * value;
+    }
+    total_sum
+}
+
+#[test]
+fn test_oods_polynomial_reference() {
+    // Checks the reference against the autogenerated function of the Cairo verifier
+    assert_eq!(
+        oods_polynomial_reference(
+            include_str!(
+                "../../../tasks/stark/src/swiftness/air/recursive_with_poseidon/autogenerated.rs"
+            ),
+            &PARAMS
+        ),
+        eval_oods_polynomial_inner(
+            &column_values(&PARAMS),
+            &oods_values(&PARAMS),
+            &coefficients(&PARAMS),
+            &POINT,
+            &OODS_POINT,
+            &TRACE_GENERATOR,
+        )
+    );
+}
+
+#[test]
+fn test_eval_oods_polynomial_inner_recursive() {
+    assert_eq!(
+        eval_layout(codes::RECURSIVE, &recursive::PARAMS),
+        oods_polynomial_reference(
+            include_str!("../../../tasks/stark/src/swiftness/air/recursive/autogenerated.rs"),
+            &recursive::PARAMS
+        )
+    );
+}
+
+#[test]
+fn test_eval_oods_polynomial_inner_small() {
+    assert_eq!(
+        eval_layout(codes::SMALL, &small::PARAMS),
+        oods_polynomial_reference(
+            include_str!("../../../tasks/stark/src/swiftness/air/small/autogenerated.rs"),
+            &small::PARAMS
+        )
+    );
+}
+
+#[test]
+fn test_eval_oods_polynomial_inner_starknet() {
+    assert_eq!(
+        eval_layout(codes::STARKNET, &starknet::PARAMS),
+        oods_polynomial_reference(
+            include_str!("../../../tasks/stark/src/swiftness/air/starknet/autogenerated.rs"),
+            &starknet::PARAMS
+        )
+    );
+}
+
 use felt::felt_nonzero;
 use stark::swiftness::air::recursive_with_poseidon::consts::*;
 use stark::swiftness::air::recursive_with_poseidon::LayoutTrait;
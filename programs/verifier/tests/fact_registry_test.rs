@@ -0,0 +1,238 @@
+use felt::Felt;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, rent::Rent};
+use solana_program_test::ProgramTest;
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+use stark::swiftness::stark::config::Hasher;
+use utils::AccountCast;
+use verifier::fact::{compute_fact, find_fact_address, FactAccount, FactVerifierConfig};
+use verifier::instruction::register_fact;
+use verifier::state::{BidirectionalStackAccount, VerificationState, VerifiedHashes};
+
+mod program_test;
+use program_test::{program_test, stack_account, TestBank, SLOT};
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+const INTEGRITY_FACT_HASH: Felt = Felt::from_hex_unchecked("0xfac7");
+const VERIFICATION_HASH: Felt = Felt::from_hex_unchecked("0x7e51f1");
+const PAYER_LAMPORTS: u64 = 1_000_000_000;
+
+// Registry of the facts verified in one verifier account, paid by a payer other than the fee
+// payer
+struct Registry {
+    bank: TestBank,
+    verifier_account: Pubkey,
+    payer: Keypair,
+    program_hash: [u8; 32],
+    output_hash: [u8; 32],
+}
+
+impl Registry {
+    async fn start(program_test: ProgramTest, program_hash: Felt, output_hash: Felt) -> Self {
+        Self::start_with(program_test, program_hash, output_hash, |_| {}).await
+    }
+
+    // Starts the bank once `init` adjusted the verifier account
+    async fn start_with(
+        mut program_test: ProgramTest,
+        program_hash: Felt,
+        output_hash: Felt,
+        init: impl FnOnce(&mut Account),
+    ) -> Self {
+        let verifier_account = Pubkey::new_unique();
+        let mut account = verified_account(program_hash, output_hash);
+        init(&mut account);
+        program_test.add_account(verifier_account, account);
+        let payer = Keypair::new();
+        program_test.add_account(
+            payer.pubkey(),
+            Account::new(PAYER_LAMPORTS, 0, &SYSTEM_PROGRAM_ID),
+        );
+        Self {
+            bank: TestBank::start(program_test).await,
+            verifier_account,
+            payer,
+            program_hash: program_hash.to_bytes_be(),
+            output_hash: output_hash.to_bytes_be(),
+        }
+    }
+
+    fn fact(&self) -> [u8; 32] {
+        compute_fact(&self.program_hash, &self.output_hash)
+    }
+
+    fn fact_address(&self) -> Pubkey {
+        find_fact_address(&PROGRAM_ID, &self.fact()).0
+    }
+
+    // Registers the fact at the address of the given hashes
+    async fn register_fact_of(
+        &mut self,
+        program_hash: &[u8; 32],
+        output_hash: &[u8; 32],
+    ) -> Result<(), ProgramError> {
+        let instruction = register_fact(
+            &PROGRAM_ID,
+            &self.verifier_account,
+            &self.payer.pubkey(),
+            program_hash,
+            output_hash,
+        );
+        self.bank.process(&[instruction], &[&self.payer]).await
+    }
+
+    async fn register_fact(&mut self) -> Result<(), ProgramError> {
+        let (program_hash, output_hash) = (self.program_hash, self.output_hash);
+        self.register_fact_of(&program_hash, &output_hash).await
+    }
+
+    async fn payer_lamports(&mut self) -> u64 {
+        let payer = self.payer.pubkey();
+        self.bank.account(&payer).await.unwrap().lamports
+    }
+}
+
+#[tokio::test]
+async fn test_register_fact() {
+    let program_hash = Felt::from_hex_unchecked("0x1234");
+    let output_hash = Felt::from_hex_unchecked("0x5678");
+    let mut registry = Registry::start(program_test(PROGRAM_ID), program_hash, output_hash).await;
+
+    // The fact account is created by the program
+    registry.register_fact().await.unwrap();
+
+    let rent = Rent::default().minimum_balance(FactAccount::SIZE);
+    let fact_address = registry.fact_address();
+    let fact_account = registry.bank.account(&fact_address).await.unwrap();
+    assert_eq!(fact_account.owner, PROGRAM_ID);
+    assert_eq!(fact_account.lamports, rent);
+    assert_eq!(registry.payer_lamports().await, PAYER_LAMPORTS - rent);
+
+    let record = FactAccount::cast(&fact_account.data);
+    assert_eq!(record.fact, registry.fact());
+    assert_eq!(record.program_hash, program_hash.to_bytes_be());
+    assert_eq!(record.output_hash, output_hash.to_bytes_be());
+    assert_eq!(record.verifier_account, registry.verifier_account);
+    assert_eq!(record.slot, SLOT);
+    assert_eq!(
+        record.integrity_fact_hash,
+        INTEGRITY_FACT_HASH.to_bytes_be()
+    );
+    assert_eq!(record.verification_hash, VERIFICATION_HASH.to_bytes_be());
+    assert_eq!(
+        record.verifier_config,
+        FactVerifierConfig {
+            stone_version: 1,
+            program_hash_function: 1,
+            hasher: Hasher::Blake2s256 as u8,
+        }
+    );
+
+    // A fact is registered once
+    assert_eq!(
+        registry.register_fact().await,
+        Err(ProgramError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn test_register_fact_prefunded() {
+    let program_hash = Felt::from_hex_unchecked("0xabc");
+    let output_hash = Felt::from_hex_unchecked("0xdef");
+    let fact = compute_fact(&program_hash.to_bytes_be(), &output_hash.to_bytes_be());
+    let (fact_address, _) = find_fact_address(&PROGRAM_ID, &fact);
+
+    // Lamports sent to the fact address before the registration make create_account fail, so
+    // the program allocates and assigns the account instead
+    let mut program_test = program_test(PROGRAM_ID);
+    program_test.add_account(fact_address, Account::new(1000, 0, &SYSTEM_PROGRAM_ID));
+    let mut registry = Registry::start(program_test, program_hash, output_hash).await;
+
+    registry.register_fact().await.unwrap();
+
+    let rent = Rent::default().minimum_balance(FactAccount::SIZE);
+    let fact_account = registry.bank.account(&fact_address).await.unwrap();
+    assert_eq!(fact_account.owner, PROGRAM_ID);
+    assert_eq!(fact_account.lamports, rent);
+    assert_eq!(fact_account.data.len(), FactAccount::SIZE);
+    assert_eq!(FactAccount::cast(&fact_account.data).fact, fact);
+    assert_eq!(
+        registry.payer_lamports().await,
+        PAYER_LAMPORTS - (rent - 1000)
+    );
+}
+
+#[tokio::test]
+async fn test_register_fact_rejects_unfinished_verification() {
+    let program_hash = Felt::from_hex_unchecked("0x1");
+    let output_hash = Felt::from_hex_unchecked("0x2");
+
+    // The hashes only count once the account reached Verified
+    for state in [VerificationState::Verifying, VerificationState::Failed] {
+        let mut registry = Registry::start_with(
+            program_test(PROGRAM_ID),
+            program_hash,
+            output_hash,
+            |account| {
+                BidirectionalStackAccount::cast_mut(&mut account.data)
+                    .header
+                    .state = state;
+            },
+        )
+        .await;
+        assert_eq!(
+            registry.register_fact().await,
+            Err(ProgramError::InvalidAccountData)
+        );
+        let fact_address = registry.fact_address();
+        assert_eq!(registry.bank.account(&fact_address).await, None);
+    }
+}
+
+#[tokio::test]
+async fn test_register_fact_rejects_other_fact_account() {
+    let program_hash = Felt::from_hex_unchecked("0x1");
+    let output_hash = Felt::from_hex_unchecked("0x2");
+    let mut registry = Registry::start(program_test(PROGRAM_ID), program_hash, output_hash).await;
+
+    assert_eq!(
+        registry
+            .register_fact_of(&output_hash.to_bytes_be(), &program_hash.to_bytes_be())
+            .await,
+        Err(ProgramError::InvalidSeeds)
+    );
+}
+
+#[tokio::test]
+async fn test_register_fact_rejects_foreign_verifier_account() {
+    let program_hash = Felt::from_hex_unchecked("0x1");
+    let output_hash = Felt::from_hex_unchecked("0x2");
+    let mut registry = Registry::start_with(
+        program_test(PROGRAM_ID),
+        program_hash,
+        output_hash,
+        |account| {
+            account.owner = Pubkey::new_unique();
+        },
+    )
+    .await;
+
+    assert_eq!(
+        registry.register_fact().await,
+        Err(ProgramError::IncorrectProgramId)
+    );
+}
+
+// Verifier account in the state left by a finished Verify task
+fn verified_account(program_hash: Felt, output_hash: Felt) -> Account {
+    stack_account(PROGRAM_ID, |stack| {
+        stack.header.state = VerificationState::Verified;
+        stack.proof.verifier_config.hasher = Hasher::Blake2s256 as u8;
+        stack.status.hashes = VerifiedHashes {
+            program_hash,
+            output_hash,
+            fact_hash: INTEGRITY_FACT_HASH,
+            verification_hash: VERIFICATION_HASH,
+        };
+    })
+}
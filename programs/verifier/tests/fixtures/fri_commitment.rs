@@ -83,7 +83,7 @@ pub fn get() -> FriCommitment {
             ]),
             log_last_layer_degree_bound: Felt::from_hex_unchecked("0x6"),
         },
-        inner_layers: vec![
+        inner_layers: FunVec::from_vec(vec![
             TableCommitment {
                 config: TableCommitmentConfig {
                     n_columns: Felt::from_hex_unchecked("0x8"),
@@ -228,88 +228,92 @@ pub fn get() -> FriCommitment {
                     ),
                 },
             },
-        ],
-        eval_points: vec![
-            "0x496c968f1dac9c4e65716021ecbad75f43995e95232b76f9511f1c2bdb125e1",
-            "0x21405f4b938b8e96735126fe129009b6875fa3836b43caeae53f1c2868fe2ea",
-            "0x6beae26510027e130e3e2eaddf855ce622cbfee5f4fda948ac8d7994f82a5fe",
-            "0x5c0bf9884530fdbc71613efffae4e91c5c1d8d5ea611ef467e04ee209fe4319",
-            "0x5cef02aa4aab094f92cc250b7a1463cd43fa7c242657a205fb169a19c099805",
-            "0x468cb430f752e4abad5504b5ecaee3b5e6ddbcea288dea763c7bd24cf15b245",
-            "0x163dce928440e6d53639e3f5361d28fa1141da0939a32a3f4891655fc4658df",
-            "0x63bf61faefd29520a1c37bfa4fefbf615a8e54f48fdda77bfed480a5a5a6eff",
-        ]
-        .iter()
-        .map(|f| Felt::from_hex_unchecked(f))
-        .collect(),
-        last_layer_coefficients: vec![
-            "0x66c796d3d02b79f1651070cb45f0bf66555e52586bde97db07d3587acebcb1e",
-            "0x5a65f0a67b296d6fde75095e7bf9bb15147cdf46dac056a3515f3211c755a84",
-            "0x2a86628c832b25e8f7c66db9cd8e75acb17c032f73184e794b314d5b6768f16",
-            "0x18f8e25f5b8a67d4a815542cfc1af6798f1e6ceca9476fb1e116e88c3e44d90",
-            "0x78b507b81e1b5c348589e3d1df85c2dd43a522949fd5aef31e37365e230c234",
-            "0x24fa994cd55659e2d7f0112b1cee5c7321d0a47443422da51b4e607eba4b36c",
-            "0x4f8df7df167ee1dec27c283cb96c4c9ff85c62416f61e197fa517dfa78cde51",
-            "0x3a061e33f6af1045e247b390d5b71b8c3cd74d7936d85e1dab7ec1079b1f723",
-            "0x1fd53dff2886b9d6dd8020d63f8e07063fa051a1fead92975ac95887f62296f",
-            "0x6c2172d15248be94f9b5fb457a164ec6db6ed63fb84c0ab154f05a45ae1a6b0",
-            "0x3e18d61a269faa393ccd03bb2b64364065b7af2f529d83bbe13bad4d819fe3",
-            "0x4741100133289117afd8d7f75191b8cdb60674b5e46fe337c590c1fe9d589e1",
-            "0x4ce865332e2742b7294844916d0a5592adac0f2e574f7c726dd9ba0d16166",
-            "0x521b6743607208f1e573da338e0528176563d60b458262ebd3bea3493140ef7",
-            "0x24f45c1ebcfbf13c772f32b5aa48dbb0ca8abdaeb8338ac5e302b5674f4f7b3",
-            "0x19ff856bb023150b68993d3c59598f209084c2b05d1a49b9bf0ffdd58319612",
-            "0x5837d69ead25595f8729bd17a0723bd410878ecb7b0d16ac0a2f96019761a41",
-            "0x1ca31d4d9b77c07de0be1cab20657388978365f24ff58254928f319419be580",
-            "0x668e682b2116ff3e6ced4e554c0787781113a49a249876a00396bd06c356f88",
-            "0x617863095a0f5af592ea321e1e3d26739aebef5ad2fda50cc0e5fc3e3a399bb",
-            "0x2924f13b449fec4aa26b53e38ee1b422c17cfb1de4fe8f875f696d8e313e142",
-            "0x6a685173638b6009f043aee321a4d71c39a6b77793edc1c08e48b672254d8ad",
-            "0x6118f485eb29bbe3d5ae3e4834295f35a3cabbbee8e70ae3ff245f8ef9a5598",
-            "0xaef070e0b90f861871b1f3f499439b517718fe8d43bace6d55a90e69f154f7",
-            "0x57aadf081d14036422f6112b638b6a62218d75940542424624a3ed87b8c8d70",
-            "0x43817969bea69628097471048d3342b2ce2df90e83cd0705a4bbbdc4ad9a26f",
-            "0x19fadb1a38f3bf87a67758ce80ca095ed279bbf13627e173c395a52e4a0beec",
-            "0x26717f7a71785c0442416461ecf9b8692d04b2c762e2bbde8761606e5088231",
-            "0x652c1e32e09ba9fddbb602bf2c89d0a09aecb2aa6153b55b1ea73da7434d43e",
-            "0x7cfde088e2b31a36da73203d84afb491729d25075e10e4a43f1eea3cfddff95",
-            "0x2cd6701557574e1609c88e1a897851afd384cad0562d506422b89229fa2571b",
-            "0x1ef21bfa917b63aad98765bb9eab0b62d893c7da9e930a558ce2803c30a6965",
-            "0x737175bfaad9bec33e9b180d1e2f378d895b521e76dc0f2f2df9d274ad8278c",
-            "0x4fc88bea560a9c57e9dd0acd39f1454b38b768019f053d6e34a5c9c29f7154a",
-            "0x5a0a6e245e1bef2345106ca153caa41cc45569a2c2e763a3b714ca0e0fe948f",
-            "0x660d7d653c5f457ad83402f824d5ed801c9bf31ce19ea7c1fce003bacdbbbb9",
-            "0x46c77c3d74a2c3053f7cf212eebb5267414d9b79d13cbdc4bae750d1ef18855",
-            "0x716128901707f48b66a45f6486e820bf1d8197febe9e54d510c740786317da0",
-            "0x5813d095b76f9696142adce747f1092da9d7f4b4c78ec4f80f4677acbe0895f",
-            "0x23805037d788d418797cc464397b2fde00842c21eb5766fcaab215e212432f0",
-            "0x12525b04e5a921bee8888521e61a9560be03195655074e9022b09a25e543d1f",
-            "0x4c1c2994d16395a519f7477bde0052b8f2dcfa521fc8ba6de837a8f23711b47",
-            "0x2cf2d39ee47800e9d7f4fb662a8ec9ee4510dcb114a4ab6b5fcd9188f0bb0d9",
-            "0x25601c3d21d3768ed8e588f83428196799e75b6ebbb85ca8886549ad4258963",
-            "0x5d594bf05fe3020e30c58b949bfed2f14e946bb6564a6f7f7003f91fcbfe1c9",
-            "0x12736ff70283026b7a4e279f492ffa1f0b6433209e96b439d1728fed4429c26",
-            "0x9e4ef6f319e6d61c5ada1c0c01b85f705da3251e9c0038791995a1b4a9672b",
-            "0x34d0796fed079fe2eee157f30bda10630d34158bb45aa56ac88427fe70706b9",
-            "0x44e64282f5f87a93472b1ebf9d2a63e389708640ec1c2480c643c6aba386fd5",
-            "0x3f1fb9576bf9060f5c3c197018e4b4229a5b1427da821b1d23b509e16d28376",
-            "0x6fe2b5886bdfd06eb1a2a33e99dda8229d6d11d9df2815d7dcfe53230ea42aa",
-            "0x6de15d80bb2106afdff4e63f268e38cf0c75c7a188ea249987eac7da7cf9e75",
-            "0xce3f50c606621b881811f32242dd76e6f855601e7fa7a307d3bdae78fc7709",
-            "0x7a8ca41b50fce78b56de444fb90ad2a1c5b021a5e65f1535ad3e2bfd82ac35e",
-            "0x1812ea75c5b6bd574a0bf536f6bed6edaa6148e785b9615c6bfa9ce105c2996",
-            "0x17e9f49792461fb9185124566661cd1977d8ac8715b468840e09dd4aec18994",
-            "0x113229d548d7a1169f3a863d39f5f49b0d62268c57eb22de14c0fec227e22d9",
-            "0x3a7cbb5ffbdfda6ec423c778beb40e092a8158713a7fbc1349edd835d7205f9",
-            "0x37429f0c3c16caa393a37cf022d8026563e550682a70bd7cfb74f0eb0fbe641",
-            "0x779f6680f64e3a5d2ab847b788b28bc29da9dbc90d2fd9a779e8712b07cc153",
-            "0x2f6fc641bb2fda367785f91ce33398d61b804b5454aba1cf1d0a74121b84c15",
-            "0x3e7fcd7510327a6e70fc72020c1214cbe30a9331a44c7ddddded98cca785708",
-            "0x3157be835d92a4a5a0b4b46d6f11bf800c0fd1920454d58402612417bee11a8",
-            "0x2077c8e77e96c8db5212cf46c32546f1bd9a3e97c63aebccacc1438ffcc9aa7",
-        ]
-        .iter()
-        .map(|f| Felt::from_hex_unchecked(f))
-        .collect(),
+        ]),
+        eval_points: FunVec::from_vec(
+            [
+                "0x496c968f1dac9c4e65716021ecbad75f43995e95232b76f9511f1c2bdb125e1",
+                "0x21405f4b938b8e96735126fe129009b6875fa3836b43caeae53f1c2868fe2ea",
+                "0x6beae26510027e130e3e2eaddf855ce622cbfee5f4fda948ac8d7994f82a5fe",
+                "0x5c0bf9884530fdbc71613efffae4e91c5c1d8d5ea611ef467e04ee209fe4319",
+                "0x5cef02aa4aab094f92cc250b7a1463cd43fa7c242657a205fb169a19c099805",
+                "0x468cb430f752e4abad5504b5ecaee3b5e6ddbcea288dea763c7bd24cf15b245",
+                "0x163dce928440e6d53639e3f5361d28fa1141da0939a32a3f4891655fc4658df",
+                "0x63bf61faefd29520a1c37bfa4fefbf615a8e54f48fdda77bfed480a5a5a6eff",
+            ]
+            .iter()
+            .map(|f| Felt::from_hex_unchecked(f))
+            .collect(),
+        ),
+        last_layer_coefficients: FunVec::from_vec(
+            vec![
+                "0x66c796d3d02b79f1651070cb45f0bf66555e52586bde97db07d3587acebcb1e",
+                "0x5a65f0a67b296d6fde75095e7bf9bb15147cdf46dac056a3515f3211c755a84",
+                "0x2a86628c832b25e8f7c66db9cd8e75acb17c032f73184e794b314d5b6768f16",
+                "0x18f8e25f5b8a67d4a815542cfc1af6798f1e6ceca9476fb1e116e88c3e44d90",
+                "0x78b507b81e1b5c348589e3d1df85c2dd43a522949fd5aef31e37365e230c234",
+                "0x24fa994cd55659e2d7f0112b1cee5c7321d0a47443422da51b4e607eba4b36c",
+                "0x4f8df7df167ee1dec27c283cb96c4c9ff85c62416f61e197fa517dfa78cde51",
+                "0x3a061e33f6af1045e247b390d5b71b8c3cd74d7936d85e1dab7ec1079b1f723",
+                "0x1fd53dff2886b9d6dd8020d63f8e07063fa051a1fead92975ac95887f62296f",
+                "0x6c2172d15248be94f9b5fb457a164ec6db6ed63fb84c0ab154f05a45ae1a6b0",
+                "0x3e18d61a269faa393ccd03bb2b64364065b7af2f529d83bbe13bad4d819fe3",
+                "0x4741100133289117afd8d7f75191b8cdb60674b5e46fe337c590c1fe9d589e1",
+                "0x4ce865332e2742b7294844916d0a5592adac0f2e574f7c726dd9ba0d16166",
+                "0x521b6743607208f1e573da338e0528176563d60b458262ebd3bea3493140ef7",
+                "0x24f45c1ebcfbf13c772f32b5aa48dbb0ca8abdaeb8338ac5e302b5674f4f7b3",
+                "0x19ff856bb023150b68993d3c59598f209084c2b05d1a49b9bf0ffdd58319612",
+                "0x5837d69ead25595f8729bd17a0723bd410878ecb7b0d16ac0a2f96019761a41",
+                "0x1ca31d4d9b77c07de0be1cab20657388978365f24ff58254928f319419be580",
+                "0x668e682b2116ff3e6ced4e554c0787781113a49a249876a00396bd06c356f88",
+                "0x617863095a0f5af592ea321e1e3d26739aebef5ad2fda50cc0e5fc3e3a399bb",
+                "0x2924f13b449fec4aa26b53e38ee1b422c17cfb1de4fe8f875f696d8e313e142",
+                "0x6a685173638b6009f043aee321a4d71c39a6b77793edc1c08e48b672254d8ad",
+                "0x6118f485eb29bbe3d5ae3e4834295f35a3cabbbee8e70ae3ff245f8ef9a5598",
+                "0xaef070e0b90f861871b1f3f499439b517718fe8d43bace6d55a90e69f154f7",
+                "0x57aadf081d14036422f6112b638b6a62218d75940542424624a3ed87b8c8d70",
+                "0x43817969bea69628097471048d3342b2ce2df90e83cd0705a4bbbdc4ad9a26f",
+                "0x19fadb1a38f3bf87a67758ce80ca095ed279bbf13627e173c395a52e4a0beec",
+                "0x26717f7a71785c0442416461ecf9b8692d04b2c762e2bbde8761606e5088231",
+                "0x652c1e32e09ba9fddbb602bf2c89d0a09aecb2aa6153b55b1ea73da7434d43e",
+                "0x7cfde088e2b31a36da73203d84afb491729d25075e10e4a43f1eea3cfddff95",
+                "0x2cd6701557574e1609c88e1a897851afd384cad0562d506422b89229fa2571b",
+                "0x1ef21bfa917b63aad98765bb9eab0b62d893c7da9e930a558ce2803c30a6965",
+                "0x737175bfaad9bec33e9b180d1e2f378d895b521e76dc0f2f2df9d274ad8278c",
+                "0x4fc88bea560a9c57e9dd0acd39f1454b38b768019f053d6e34a5c9c29f7154a",
+                "0x5a0a6e245e1bef2345106ca153caa41cc45569a2c2e763a3b714ca0e0fe948f",
+                "0x660d7d653c5f457ad83402f824d5ed801c9bf31ce19ea7c1fce003bacdbbbb9",
+                "0x46c77c3d74a2c3053f7cf212eebb5267414d9b79d13cbdc4bae750d1ef18855",
+                "0x716128901707f48b66a45f6486e820bf1d8197febe9e54d510c740786317da0",
+                "0x5813d095b76f9696142adce747f1092da9d7f4b4c78ec4f80f4677acbe0895f",
+                "0x23805037d788d418797cc464397b2fde00842c21eb5766fcaab215e212432f0",
+                "0x12525b04e5a921bee8888521e61a9560be03195655074e9022b09a25e543d1f",
+                "0x4c1c2994d16395a519f7477bde0052b8f2dcfa521fc8ba6de837a8f23711b47",
+                "0x2cf2d39ee47800e9d7f4fb662a8ec9ee4510dcb114a4ab6b5fcd9188f0bb0d9",
+                "0x25601c3d21d3768ed8e588f83428196799e75b6ebbb85ca8886549ad4258963",
+                "0x5d594bf05fe3020e30c58b949bfed2f14e946bb6564a6f7f7003f91fcbfe1c9",
+                "0x12736ff70283026b7a4e279f492ffa1f0b6433209e96b439d1728fed4429c26",
+                "0x9e4ef6f319e6d61c5ada1c0c01b85f705da3251e9c0038791995a1b4a9672b",
+                "0x34d0796fed079fe2eee157f30bda10630d34158bb45aa56ac88427fe70706b9",
+                "0x44e64282f5f87a93472b1ebf9d2a63e389708640ec1c2480c643c6aba386fd5",
+                "0x3f1fb9576bf9060f5c3c197018e4b4229a5b1427da821b1d23b509e16d28376",
+                "0x6fe2b5886bdfd06eb1a2a33e99dda8229d6d11d9df2815d7dcfe53230ea42aa",
+                "0x6de15d80bb2106afdff4e63f268e38cf0c75c7a188ea249987eac7da7cf9e75",
+                "0xce3f50c606621b881811f32242dd76e6f855601e7fa7a307d3bdae78fc7709",
+                "0x7a8ca41b50fce78b56de444fb90ad2a1c5b021a5e65f1535ad3e2bfd82ac35e",
+                "0x1812ea75c5b6bd574a0bf536f6bed6edaa6148e785b9615c6bfa9ce105c2996",
+                "0x17e9f49792461fb9185124566661cd1977d8ac8715b468840e09dd4aec18994",
+                "0x113229d548d7a1169f3a863d39f5f49b0d62268c57eb22de14c0fec227e22d9",
+                "0x3a7cbb5ffbdfda6ec423c778beb40e092a8158713a7fbc1349edd835d7205f9",
+                "0x37429f0c3c16caa393a37cf022d8026563e550682a70bd7cfb74f0eb0fbe641",
+                "0x779f6680f64e3a5d2ab847b788b28bc29da9dbc90d2fd9a779e8712b07cc153",
+                "0x2f6fc641bb2fda367785f91ce33398d61b804b5454aba1cf1d0a74121b84c15",
+                "0x3e7fcd7510327a6e70fc72020c1214cbe30a9331a44c7ddddded98cca785708",
+                "0x3157be835d92a4a5a0b4b46d6f11bf800c0fd1920454d58402612417bee11a8",
+                "0x2077c8e77e96c8db5212cf46c32546f1bd9a3e97c63aebccacc1438ffcc9aa7",
+            ]
+            .iter()
+            .map(|f| Felt::from_hex_unchecked(f))
+            .collect(),
+        ),
     }
 }
@@ -5,7 +5,7 @@ use stark::swiftness::fri::types::UnsentCommitment;
 pub fn get() -> UnsentCommitment {
     UnsentCommitment {
         inner_layers: FunVec::from_vec(
-            vec![
+            [
                 "0x31bff9de415b246e26441df6d7ededb680bfedd63ab962377cd678d848c45ba",
                 "0x60a16437f82f2551257c0c6b8b4a0bc47195df671010b4c7a953a97e5ff057",
                 "0x792506d84d73eaa2cb70f6dcd2bdac7d7e369874396487a1438e2afc10bd1a2",
@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 pub mod commitment;
 pub mod config;
 pub mod constraint_coefficients;
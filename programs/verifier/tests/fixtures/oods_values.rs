@@ -1,7 +1,7 @@
 use felt::Felt;
-use stark::funvec::FunVec;
+use stark::funvec::{FunVec, FUNVEC_OODS};
 
-pub fn get() -> FunVec<Felt, 256> {
+pub fn get() -> FunVec<Felt, FUNVEC_OODS> {
     FunVec::from_vec(
         vec![
             "0x2e6d380bf135d48fe8c134a845c8f667a0cfd9bd8266c821900a37d66529efc",
@@ -9,7 +9,8 @@ pub fn get() -> PublicInput {
         range_check_min: Felt::from_hex_unchecked("0x0"),
         range_check_max: Felt::from_hex_unchecked("0xffff"),
         layout: Felt::from_hex_unchecked("0x7265637572736976655f776974685f706f736569646f6e"),
-        dynamic_params: None,
+        has_dynamic_params: 0,
+        dynamic_params: Default::default(),
         segments: FunVec::from_vec(vec![
             SegmentInfo {
                 begin_addr: Felt::from_hex_unchecked("0x1"),
@@ -44,6 +45,7 @@ pub fn get() -> PublicInput {
         padding_value: Felt::from_hex_unchecked("0x40780017fff7fff"),
         main_page: Page(FunVec::from_vec(get_public_memory())),
         continuous_page_headers: FunVec::from_vec(vec![]),
+        continuous_page_values: FunVec::from_vec(vec![]),
     }
 }
 
@@ -33,7 +33,7 @@ fn test_fri_commit_with_reference_values() {
 
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
     println!("steps: {:?}", steps);
@@ -51,6 +51,6 @@ fn test_fri_commit_with_reference_values() {
     );
 
     assert!(steps > 0, "Should have executed at least one step");
-    assert_eq!(stack.is_empty_back(), true, "Stack should be empty");
-    assert_eq!(stack.is_empty_front(), true, "Stack should be empty");
+    assert!(stack.is_empty_back(), "Stack should be empty");
+    assert!(stack.is_empty_front(), "Stack should be empty");
 }
@@ -0,0 +1,192 @@
+use felt::Felt;
+use stark::funvec::FunVec;
+use stark::stark_proof::stark_verify::ComputeNextLayer;
+use stark::swiftness::air::domains::bit_reverse_u64;
+use stark::swiftness::fri::formula::{batch_inverse, fri_formula};
+use stark::swiftness::fri::types::{FriLayerQuery, LayerWitness};
+use utils::{BidirectionalStack, Scheduler, TaskError};
+use verifier::state::BidirectionalStackAccount;
+mod fixtures;
+use fixtures::stark_domains;
+
+const LOG_LAYER_SIZE: u32 = 10;
+// Several queries in the first coset, one alone, and the last element of the layer
+const QUERIES: [u64; 6] = [0, 1, 5, 37, 38, (1 << LOG_LAYER_SIZE) - 1];
+
+#[test]
+fn test_compute_next_layer_step_size_1() {
+    run_compute_next_layer(1, &QUERIES);
+}
+
+#[test]
+fn test_compute_next_layer_step_size_2() {
+    run_compute_next_layer(2, &QUERIES);
+}
+
+#[test]
+fn test_compute_next_layer_step_size_3() {
+    run_compute_next_layer(3, &QUERIES);
+}
+
+#[test]
+fn test_compute_next_layer_step_size_4() {
+    run_compute_next_layer(4, &QUERIES);
+}
+
+#[test]
+fn test_compute_next_layer_many_cosets() {
+    // 40 cosets of 16 elements holding 6 queries each, more values than witness leaves
+    let queries: Vec<u64> = (0..40u64)
+        .flat_map(|coset| (0..6).map(move |offset| coset * 16 + offset * 2))
+        .collect();
+    run_compute_next_layer(4, &queries);
+}
+
+#[test]
+fn test_compute_next_layer_rejects_invalid_witness() {
+    let run = |step_size: Felt, n_leaves: usize| {
+        let mut stack = BidirectionalStackAccount::default();
+        stack.proof.config.fri.fri_step_sizes = FunVec::from_vec(vec![Felt::ZERO, step_size]);
+        stack.proof.witness.fri_witness.layers = FunVec::from_vec(vec![LayerWitness {
+            leaves: FunVec::from_vec(vec![Felt::ONE; n_leaves]),
+            ..Default::default()
+        }]);
+        stack.stark_commitment.fri.eval_points = FunVec::from_vec(vec![Felt::TWO]);
+        stack.queries.fri_queries.flush();
+        stack.queries.fri_queries.push(FriLayerQuery {
+            index: Felt::ZERO,
+            y_value: Felt::ONE,
+            x_inv_value: Felt::ONE,
+        });
+
+        stack.push_task(ComputeNextLayer::new(0));
+        stack.execute()
+    };
+
+    // A coset of 4 elements holding a single query needs 3 leaves
+    assert_eq!(
+        run(Felt::TWO, 2),
+        Err(TaskError::InvalidWitness("Missing FRI witness leaves"))
+    );
+    assert_eq!(
+        run(Felt::from(1u64 << 32), 3),
+        Err(TaskError::InvalidConfig("Invalid FRI step size"))
+    );
+    assert_eq!(run(Felt::TWO, 3), Ok(()));
+}
+
+#[test]
+fn test_batch_inverse() {
+    let values: Vec<Felt> = (1..20u64).map(|i| Felt::from(i * i + 0xabcdef)).collect();
+    let mut inverses = values.clone();
+    batch_inverse(&mut inverses).unwrap();
+    for (value, inverse) in values.iter().zip(inverses) {
+        assert_eq!(value.inverse().unwrap(), inverse);
+    }
+}
+
+#[test]
+fn test_batch_inverse_rejects_zero() {
+    let mut values = [Felt::ONE, Felt::ZERO, Felt::TWO];
+    assert_eq!(
+        batch_inverse(&mut values),
+        Err(TaskError::InvalidWitness("Cannot invert zero"))
+    );
+}
+
+#[test]
+fn test_fri_formula_rejects_invalid_coset_sizes() {
+    for coset_size in [0, 1, 3, 32] {
+        let values = vec![Felt::ONE; coset_size];
+        assert_eq!(
+            fri_formula(&values, Felt::TWO, Felt::THREE),
+            Err(TaskError::InvalidConfig("Invalid coset size"))
+        );
+    }
+}
+
+fn run_compute_next_layer(step_size: u32, queries: &[u64]) {
+    let mut stack = BidirectionalStackAccount::default();
+    let coset_size = 1u64 << step_size;
+
+    // Generator of the layer domain, of order 2^LOG_LAYER_SIZE
+    let eval_generator = stark_domains::get().eval_generator;
+    let generator = eval_generator.pow(1u128 << (32 - LOG_LAYER_SIZE));
+    let x = |index: u64| generator.pow(bit_reverse_u64(index, LOG_LAYER_SIZE));
+    let y = |index: u64| Felt::from(index * 0x9e3779b9 + 0x7f4a7c15);
+    let eval_point =
+        Felt::from_hex("0x2b1c8e9f7e2f8d2f4ea8a1b5c3d7e9f0a1b2c3d4e5f60718293a4b5c6d7e8f9")
+            .unwrap();
+
+    let mut expected_indices = vec![];
+    let mut expected_values = vec![];
+    let mut expected_queries = vec![];
+    let mut leaves = vec![];
+    for &query in queries {
+        let coset_index = query / coset_size;
+        if expected_indices.last() == Some(&Felt::from(coset_index)) {
+            continue;
+        }
+        let coset: Vec<u64> = (coset_index * coset_size..(coset_index + 1) * coset_size).collect();
+        for index in &coset {
+            if !queries.contains(index) {
+                leaves.push(y(*index));
+            }
+        }
+        let values: Vec<Felt> = coset.iter().map(|&index| y(index)).collect();
+        let points: Vec<Felt> = coset.iter().map(|&index| x(index)).collect();
+        expected_indices.push(Felt::from(coset_index));
+        expected_values.extend_from_slice(&values);
+        expected_queries.push(FriLayerQuery {
+            index: Felt::from(coset_index),
+            y_value: fold_reference(&values, &points, eval_point),
+            x_inv_value: points[0].pow(coset_size as u128).inverse().unwrap(),
+        });
+    }
+
+    stack.proof.config.fri.fri_step_sizes = FunVec::from_vec(vec![Felt::ZERO, step_size.into()]);
+    stack.proof.witness.fri_witness.layers = FunVec::from_vec(vec![LayerWitness {
+        leaves: FunVec::from_vec(leaves),
+        ..Default::default()
+    }]);
+    stack.stark_commitment.fri.eval_points = FunVec::from_vec(vec![eval_point]);
+
+    stack.queries.fri_queries.flush();
+    for &query in queries {
+        stack.queries.fri_queries.push(FriLayerQuery {
+            index: Felt::from(query),
+            y_value: y(query),
+            x_inv_value: x(query).inverse().unwrap(),
+        });
+    }
+
+    stack.push_task(ComputeNextLayer::new(0));
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    assert_eq!(stack.queries.layer_indices.as_slice(), expected_indices);
+    assert_eq!(stack.queries.layer_values.as_slice(), expected_values);
+    assert_eq!(stack.queries.fri_queries.as_slice(), expected_queries);
+}
+
+// Textbook FRI folding over explicit points, (x, -x) being adjacent in bit-reversed order:
+// f'(x^2) = f(x) + f(-x) + eval_point * (f(x) - f(-x)) / x
+fn fold_reference(values: &[Felt], points: &[Felt], eval_point: Felt) -> Felt {
+    let mut values = values.to_vec();
+    let mut points = points.to_vec();
+    let mut eval_point = eval_point;
+    while values.len() > 1 {
+        for pair in points.chunks(2) {
+            assert_eq!(pair[0], -pair[1]);
+        }
+        values = values
+            .chunks(2)
+            .zip(points.chunks(2))
+            .map(|(f, x)| f[0] + f[1] + eval_point * (f[0] - f[1]) * x[0].inverse().unwrap())
+            .collect();
+        points = points.iter().step_by(2).map(|x| x * x).collect();
+        eval_point = eval_point * eval_point;
+    }
+    values[0]
+}
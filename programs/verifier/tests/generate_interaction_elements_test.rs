@@ -24,7 +24,7 @@ fn test_generate_interaction_elements() {
     // Execute until completion
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
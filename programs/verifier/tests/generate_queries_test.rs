@@ -0,0 +1,68 @@
+use felt::Felt;
+use stark::stark_proof::stark_verify::GenerateQueries;
+use starknet_crypto::poseidon_hash;
+use starknet_crypto::Felt as StarknetFelt;
+use utils::{BidirectionalStack, Scheduler};
+use verifier::state::BidirectionalStackAccount;
+mod fixtures;
+
+#[test]
+fn test_generate_queries() {
+    let mut stack = BidirectionalStackAccount::default();
+    stack.proof.config = fixtures::stark_config::get();
+
+    let digest =
+        Felt::from_hex("0x3c28ef4f0d7c9d4d7bfd8ac54a9c1b0b79fd4eeb9a6e2a1fd04d08c4d8b7cd5")
+            .unwrap();
+    let counter = Felt::ZERO;
+
+    // Eval domain of size 2^(log_trace_domain_size + log_n_cosets) = 2^24
+    let n_queries = 18;
+    let eval_domain_size = 1u128 << 24;
+    let expected = generate_queries_reference(digest, counter, n_queries, eval_domain_size);
+
+    stack.push_front(&digest.to_bytes_be()).unwrap();
+    stack.push_front(&counter.to_bytes_be()).unwrap();
+    stack.push_task(GenerateQueries::new());
+
+    let mut steps = 0;
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+        steps += 1;
+    }
+
+    println!("GenerateQueries completed in {} steps", steps);
+
+    assert_eq!(stack.queries.queries.as_slice(), expected.as_slice());
+    assert!(stack
+        .queries
+        .queries
+        .as_slice()
+        .windows(2)
+        .all(|pair| pair[0] < pair[1]));
+
+    // The transcript state is consumed
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+// Reference implementation for verification
+fn generate_queries_reference(
+    digest: Felt,
+    counter: Felt,
+    n_queries: u64,
+    eval_domain_size: u128,
+) -> Vec<Felt> {
+    let digest_starknet = StarknetFelt::from_bytes_be(&digest.to_bytes_be());
+    let mut queries: Vec<u128> = (0..n_queries)
+        .map(|i| {
+            let counter_starknet =
+                StarknetFelt::from_bytes_be(&(counter + Felt::from(i)).to_bytes_be());
+            let random = poseidon_hash(digest_starknet, counter_starknet).to_bytes_be();
+            u128::from_be_bytes(random[16..].try_into().unwrap()) % eval_domain_size
+        })
+        .collect();
+    queries.sort();
+    queries.dedup();
+    queries.into_iter().map(Felt::from).collect()
+}
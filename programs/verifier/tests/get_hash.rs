@@ -1,9 +1,15 @@
 mod fixtures;
 use felt::Felt;
 use fixtures::public_input::get;
+use sha3::{Digest, Keccak256};
+use stark::funvec::FunVec;
 use stark::stark_proof::get_hash::GetHash;
+use stark::swiftness::air::public_memory::PublicInput;
+use stark::swiftness::air::types::{ContinuousPageHeader, Page};
+use stark::swiftness::stark::config::StoneVersion;
+use starknet_crypto::{pedersen_hash, poseidon_hash_many, Felt as StarknetFelt};
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
-use utils::{BidirectionalStack, Scheduler};
+use utils::{BidirectionalStack, Scheduler, TaskError};
 use verifier::state::BidirectionalStackAccount;
 
 #[test]
@@ -21,7 +27,7 @@ fn get_hash() {
 
     stack.push_task(GetHash::new(Felt::ZERO));
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
     }
 
     let expected = Felt::from_hex_unchecked(
@@ -33,3 +39,259 @@ fn get_hash() {
         Felt::from_bytes_be_slice(stack.borrow_front())
     );
 }
+
+// Public input whose output segment ends with two continuous pages
+fn continuous_pages_public_input() -> PublicInput {
+    let mut public_input = get();
+    public_input.main_page = Page(FunVec::from_vec(
+        public_input.main_page.0.as_slice()[..6].to_vec(),
+    ));
+    let first_page = [Felt::from(0x1b3c), Felt::from(0x5d7e)];
+    let second_page = [Felt::from(0x9f)];
+    public_input.continuous_page_headers = FunVec::from_vec(vec![
+        ContinuousPageHeader {
+            start_address: Felt::from_hex_unchecked("0x1c43b5"),
+            size: Felt::TWO,
+            hash: hash_on_elements_reference(&first_page),
+            prod: Felt::from_hex_unchecked("0x2468ace"),
+        },
+        ContinuousPageHeader {
+            start_address: Felt::from_hex_unchecked("0x1c43b7"),
+            size: Felt::ONE,
+            hash: hash_on_elements_reference(&second_page),
+            prod: Felt::from_hex_unchecked("0x13579bd"),
+        },
+    ]);
+    public_input.continuous_page_values =
+        FunVec::from_vec([first_page.as_slice(), &second_page].concat());
+    public_input
+}
+
+#[test]
+fn get_hash_continuous_pages() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    let public_input = continuous_pages_public_input();
+    let expected = public_input_hash_reference(&public_input, Some(Felt::from(0x17)));
+
+    stack.proof.public_input = public_input;
+    stack.push_task(GetHash::new(Felt::from(0x17)));
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    assert_eq!(Felt::from_bytes_be_slice(stack.borrow_front()), expected);
+    stack.pop_front();
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+#[test]
+fn get_hash_rejects_invalid_continuous_pages() {
+    let run = |tamper: &dyn Fn(&mut PublicInput)| {
+        let mut stack = BidirectionalStackAccount::default();
+        let mut public_input = continuous_pages_public_input();
+        tamper(&mut public_input);
+        stack.proof.public_input = public_input;
+        stack.push_task(GetHash::new(Felt::from(0x17)));
+        while !stack.is_empty_back() {
+            stack.execute()?;
+        }
+        Ok(())
+    };
+    fn header(public_input: &mut PublicInput, i: usize) -> &mut ContinuousPageHeader {
+        &mut public_input.continuous_page_headers.as_slice_mut()[i]
+    }
+
+    assert_eq!(run(&|_| {}), Ok(()));
+    assert_eq!(
+        run(&|public_input| public_input.continuous_page_values.as_slice_mut()[2] += Felt::ONE),
+        Err(TaskError::InvalidPublicInput(
+            "Continuous page hash mismatch"
+        ))
+    );
+    assert_eq!(
+        run(&|public_input| header(public_input, 0).hash += Felt::ONE),
+        Err(TaskError::InvalidPublicInput(
+            "Continuous page hash mismatch"
+        ))
+    );
+    assert_eq!(
+        run(&|public_input| public_input.continuous_page_values.push(Felt::ONE)),
+        Err(TaskError::InvalidPublicInput(
+            "Continuous page values do not match the headers"
+        ))
+    );
+    assert_eq!(
+        run(&|public_input| header(public_input, 1).start_address -= Felt::ONE),
+        Err(TaskError::InvalidPublicInput(
+            "Continuous pages are not sorted or overlap"
+        ))
+    );
+    assert_eq!(
+        run(&|public_input| header(public_input, 1).size = Felt::ZERO),
+        Err(TaskError::InvalidPublicInput("Empty continuous page"))
+    );
+    assert_eq!(
+        run(&|public_input| header(public_input, 0).start_address = Felt::from(u64::MAX)),
+        Err(TaskError::InvalidPublicInput("Invalid continuous page"))
+    );
+    assert_eq!(
+        run(&|public_input| header(public_input, 1).size = Felt::MAX),
+        Err(TaskError::InvalidPublicInput("Invalid continuous page"))
+    );
+}
+
+// Public input parsed from the example proof with an extra continuous page, so that the page
+// header is built by the parser
+fn parsed_public_input_with_page(values: &[u64]) -> PublicInput {
+    let input = include_str!("../../../example_proof/saya.json");
+    let mut json: serde_json::Value = serde_json::from_str(input).unwrap();
+    let public_memory = json["public_input"]["public_memory"]
+        .as_array_mut()
+        .unwrap();
+    for (i, value) in values.iter().enumerate() {
+        public_memory.push(serde_json::json!({
+            "address": 0x1c43b8 + i,
+            "page": 1,
+            "value": format!("{value:#x}"),
+        }));
+    }
+    let proof_json = serde_json::from_value::<json_parser::StarkProof>(json).unwrap();
+    let proof: stark::swiftness::stark::types::StarkProof = StarkProofParser::try_from(proof_json)
+        .unwrap()
+        .transform_to();
+    proof.public_input
+}
+
+fn run_get_hash(public_input: PublicInput) -> Result<Felt, TaskError> {
+    let mut stack = BidirectionalStackAccount::default();
+    stack.proof.public_input = public_input;
+    stack.push_task(GetHash::new(Felt::from(0x17)));
+    while !stack.is_empty_back() {
+        stack.execute()?;
+    }
+    Ok(Felt::from_bytes_be_slice(stack.borrow_front()))
+}
+
+#[test]
+fn get_hash_parsed_continuous_page() {
+    let public_input = parsed_public_input_with_page(&[0x1b3c, 0x5d7e, 0x9f]);
+    let values = [Felt::from(0x1b3c), Felt::from(0x5d7e), Felt::from(0x9f)];
+
+    // The parser hashes the page values with compute_hash_on_elements
+    let header = public_input.continuous_page_headers.as_slice()[0];
+    assert_eq!(public_input.continuous_page_headers.len(), 1);
+    assert_eq!(header.start_address, Felt::from(0x1c43b8));
+    assert_eq!(header.size, Felt::THREE);
+    assert_eq!(header.hash, hash_on_elements_reference(&values));
+    assert_eq!(public_input.continuous_page_values.as_slice(), values);
+
+    let expected = public_input_hash_reference(&public_input, Some(Felt::from(0x17)));
+    assert_eq!(run_get_hash(public_input.clone()), Ok(expected));
+
+    // A keccak page hash, as registered in the L1 memory page fact registry, is rejected
+    let mut public_input = public_input;
+    let keccak_input: Vec<u8> = values
+        .iter()
+        .flat_map(|value| value.to_bytes_be())
+        .collect();
+    public_input.continuous_page_headers.as_slice_mut()[0].hash =
+        Felt::from_bytes_be_slice(&Keccak256::digest(keccak_input));
+    assert_eq!(
+        run_get_hash(public_input),
+        Err(TaskError::InvalidPublicInput(
+            "Continuous page hash mismatch"
+        ))
+    );
+}
+
+#[test]
+fn get_hash_stone5() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    let mut public_input = get();
+    public_input.main_page = Page(FunVec::from_vec(
+        public_input.main_page.0.as_slice()[..4].to_vec(),
+    ));
+    let expected = public_input_hash_reference(&public_input, None);
+
+    stack.proof.public_input = public_input;
+    stack.proof.verifier_config.stone_version = StoneVersion::Stone5 as u8;
+
+    // Data below the task must be left untouched
+    let sentinel = Felt::from_hex_unchecked("0x5e7e1");
+    stack.push_front(&sentinel.to_bytes_be()).unwrap();
+
+    stack.push_task(GetHash::new(Felt::from(0x17)));
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    assert_eq!(Felt::from_bytes_be_slice(stack.borrow_front()), expected);
+    stack.pop_front();
+    assert_eq!(Felt::from_bytes_be_slice(stack.borrow_front()), sentinel);
+    stack.pop_front();
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+// Pedersen hash of the main page followed by a Poseidon hash of the public input fields
+fn public_input_hash_reference(
+    public_input: &PublicInput,
+    n_verifier_friendly_commitment_layers: Option<Felt>,
+) -> Felt {
+    let to_starknet = |value: Felt| StarknetFelt::from_bytes_be(&value.to_bytes_be());
+    let main_page = public_input.main_page.0.as_slice();
+    let headers = public_input.continuous_page_headers.as_slice();
+
+    let mut main_page_hash = StarknetFelt::ZERO;
+    for cell in main_page {
+        main_page_hash = pedersen_hash(&main_page_hash, &to_starknet(cell.address));
+        main_page_hash = pedersen_hash(&main_page_hash, &to_starknet(cell.value));
+    }
+    main_page_hash = pedersen_hash(
+        &main_page_hash,
+        &StarknetFelt::from(2 * main_page.len() as u64),
+    );
+
+    // Only Stone 6 hashes the number of verifier friendly layers
+    let mut elements: Vec<Felt> = n_verifier_friendly_commitment_layers.into_iter().collect();
+    elements.extend([
+        public_input.log_n_steps,
+        public_input.range_check_min,
+        public_input.range_check_max,
+        public_input.layout,
+    ]);
+    for segment in public_input.segments.as_slice() {
+        elements.push(segment.begin_addr);
+        elements.push(segment.stop_ptr);
+    }
+    elements.push(public_input.padding_addr);
+    elements.push(public_input.padding_value);
+    elements.push(Felt::from(headers.len() + 1));
+    elements.push(Felt::from(main_page.len()));
+    let mut elements: Vec<StarknetFelt> = elements.into_iter().map(to_starknet).collect();
+    elements.push(main_page_hash);
+    for header in headers {
+        elements.push(to_starknet(header.start_address));
+        elements.push(to_starknet(header.size));
+        elements.push(to_starknet(header.hash));
+    }
+
+    Felt::from_bytes_be(&poseidon_hash_many(&elements).to_bytes_be())
+}
+
+// Pedersen hash chain of the values followed by their count
+fn hash_on_elements_reference(values: &[Felt]) -> Felt {
+    let to_starknet = |value: Felt| StarknetFelt::from_bytes_be(&value.to_bytes_be());
+    let hash = values.iter().fold(StarknetFelt::ZERO, |hash, value| {
+        pedersen_hash(&hash, &to_starknet(*value))
+    });
+    let hash = pedersen_hash(&hash, &StarknetFelt::from(values.len() as u64));
+    Felt::from_bytes_be(&hash.to_bytes_be())
+}
@@ -19,7 +19,7 @@ fn test_hades_permutation() {
     // Execute until completion
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
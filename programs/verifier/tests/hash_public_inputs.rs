@@ -1,7 +1,12 @@
 use felt::Felt;
-use stark::stark_proof::VerifyPublicInput;
+use stark::funvec::FunVec;
+use stark::stark_proof::{segments, VerifyPublicInput};
+use stark::swiftness::air::public_memory::PublicInput;
+use stark::swiftness::air::types::{ContinuousPageHeader, Page};
+use stark::swiftness::stark::config::HashFunction;
+use starknet_crypto::{pedersen_hash, Felt as StarknetFelt};
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
-use utils::{BidirectionalStack, Scheduler};
+use utils::{BidirectionalStack, Scheduler, TaskError};
 use verifier::state::BidirectionalStackAccount;
 
 #[test]
@@ -18,7 +23,7 @@ fn hash_public_inputs_basic() {
 
     stack.push_task(VerifyPublicInput::new());
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
     }
     let result_program_hash = Felt::from_bytes_be_slice(stack.borrow_front());
     stack.pop_front();
@@ -36,3 +41,181 @@ fn hash_public_inputs_basic() {
             .unwrap()
     );
 }
+
+#[test]
+fn hash_public_inputs_pedersen() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    let input = include_str!("../../../example_proof/saya.json");
+    let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
+    let proof = StarkProofParser::try_from(proof_json).unwrap();
+
+    stack.proof = proof.transform_to();
+    stack.proof.verifier_config.program_hash_function = HashFunction::Pedersen as u8;
+
+    stack.push_task(VerifyPublicInput::new());
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+    let result_program_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    let result_output_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+
+    let public_input = &stack.proof.public_input;
+    let segments = public_input.segments.as_slice();
+    let address = |value: Felt| -> usize { value.try_into().unwrap() };
+    let program_len = address(segments[segments::EXECUTION].begin_addr)
+        - 2
+        - address(segments[segments::PROGRAM].begin_addr);
+    let output_len = address(segments[segments::OUTPUT].stop_ptr)
+        - address(segments[segments::OUTPUT].begin_addr);
+    let values: Vec<Felt> = public_input
+        .main_page
+        .0
+        .as_slice()
+        .iter()
+        .map(|cell| cell.value)
+        .collect();
+
+    assert_eq!(
+        result_program_hash,
+        hash_on_elements_reference(&values[..program_len])
+    );
+    assert_eq!(
+        result_output_hash,
+        hash_on_elements_reference(&values[values.len() - output_len..])
+    );
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+#[test]
+fn hash_public_inputs_continuous_pages() {
+    let input = include_str!("../../../example_proof/saya.json");
+    let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
+    let proof = StarkProofParser::try_from(proof_json)
+        .unwrap()
+        .transform_to();
+
+    // Moving the end of the output to continuous pages does not change the output hash
+    for hash_function in [HashFunction::Poseidon, HashFunction::Pedersen] {
+        let mut stack = BidirectionalStackAccount {
+            proof: proof.clone(),
+            ..Default::default()
+        };
+        stack.proof.verifier_config.program_hash_function = hash_function as u8;
+        let expected = verify_public_input(&mut stack).unwrap();
+
+        split_output(&mut stack.proof.public_input, &[2, 1]);
+        assert_eq!(verify_public_input(&mut stack), Ok(expected));
+    }
+}
+
+#[test]
+fn hash_public_inputs_rejects_invalid_continuous_pages() {
+    let input = include_str!("../../../example_proof/saya.json");
+    let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
+    let proof = StarkProofParser::try_from(proof_json)
+        .unwrap()
+        .transform_to();
+
+    let run = |tamper: &dyn Fn(&mut PublicInput)| {
+        let mut stack = BidirectionalStackAccount {
+            proof: proof.clone(),
+            ..Default::default()
+        };
+        split_output(&mut stack.proof.public_input, &[2, 1]);
+        tamper(&mut stack.proof.public_input);
+        verify_public_input(&mut stack).map(|_| ())
+    };
+    let not_covered = Err(TaskError::InvalidPublicInput(
+        "Continuous pages do not cover the end of the output segment",
+    ));
+
+    assert_eq!(run(&|_| {}), Ok(()));
+    // A gap between the pages
+    assert_eq!(
+        run(
+            &|public_input| public_input.continuous_page_headers.as_slice_mut()[1].start_address +=
+                Felt::ONE
+        ),
+        not_covered
+    );
+    // Pages running past the output segment
+    assert_eq!(
+        run(
+            &|public_input| public_input.continuous_page_headers.as_slice_mut()[1].size = Felt::TWO
+        ),
+        not_covered
+    );
+    assert_eq!(
+        run(
+            &|public_input| public_input.continuous_page_headers.as_slice_mut()[1].size = Felt::MAX
+        ),
+        not_covered
+    );
+    assert_eq!(
+        run(&|public_input| public_input.continuous_page_values.push(Felt::ONE)),
+        Err(TaskError::InvalidPublicInput(
+            "Continuous page values do not match the headers"
+        ))
+    );
+    assert_eq!(
+        run(
+            &|public_input| public_input.segments.as_slice_mut()[segments::OUTPUT].stop_ptr =
+                Felt::MAX
+        ),
+        Err(TaskError::InvalidPublicInput("Invalid output segment"))
+    );
+}
+
+// Runs VerifyPublicInput and returns the program and output hashes
+fn verify_public_input(stack: &mut BidirectionalStackAccount) -> Result<(Felt, Felt), TaskError> {
+    stack.push_task(VerifyPublicInput::new());
+    while !stack.is_empty_back() {
+        stack.execute()?;
+    }
+    let program_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    let output_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    Ok((program_hash, output_hash))
+}
+
+// Moves the end of the output segment from the main page to continuous pages of the given sizes
+fn split_output(public_input: &mut PublicInput, page_sizes: &[usize]) {
+    let pages_len: usize = page_sizes.iter().sum();
+    let mut main_page = public_input.main_page.0.to_vec();
+    let page_cells = main_page.split_off(main_page.len() - pages_len);
+    public_input.main_page = Page(FunVec::from_vec(main_page));
+
+    let mut headers = Vec::new();
+    let mut cells = page_cells.as_slice();
+    for size in page_sizes {
+        let (page, rest) = cells.split_at(*size);
+        let values: Vec<Felt> = page.iter().map(|cell| cell.value).collect();
+        headers.push(ContinuousPageHeader {
+            start_address: page[0].address,
+            size: Felt::from(*size),
+            hash: hash_on_elements_reference(&values),
+            prod: Felt::ZERO,
+        });
+        cells = rest;
+    }
+    public_input.continuous_page_headers = FunVec::from_vec(headers);
+    public_input.continuous_page_values =
+        FunVec::from_vec(page_cells.iter().map(|cell| cell.value).collect());
+}
+
+// Pedersen hash chain of the values followed by their count
+fn hash_on_elements_reference(values: &[Felt]) -> Felt {
+    let to_starknet = |value: Felt| StarknetFelt::from_bytes_be(&value.to_bytes_be());
+    let hash = values.iter().fold(StarknetFelt::ZERO, |hash, value| {
+        pedersen_hash(&hash, &to_starknet(*value))
+    });
+    let hash = pedersen_hash(&hash, &StarknetFelt::from(values.len() as u64));
+    Felt::from_bytes_be(&hash.to_bytes_be())
+}
@@ -18,6 +18,7 @@ mod tests {
         let witness = StarkWitness::default();
         println!("witness: {witness:?}");
         let _proof = StarkProof {
+            verifier_config: Default::default(),
             config,
             public_input,
             unsent_commitment,
@@ -0,0 +1,101 @@
+use felt::Felt;
+use stark::funvec::FunVec;
+use stark::stark_proof::integrity::{settings, IntegrityHashes};
+use stark::swiftness::air::types::ContinuousPageHeader;
+use stark::swiftness::stark::config::{Hasher, StoneVersion};
+use starknet_crypto::{poseidon_hash_many, Felt as StarknetFelt};
+use utils::{BidirectionalStack, Scheduler};
+use verifier::state::BidirectionalStackAccount;
+
+#[test]
+fn test_integrity_hashes() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    let layout = Felt::from_hex_unchecked("0x7265637572736976655f776974685f706f736569646f6e");
+    stack.proof.public_input.layout = layout;
+    stack.proof.verifier_config.stone_version = StoneVersion::Stone5 as u8;
+    stack.proof.verifier_config.hasher = Hasher::Blake2s256 as u8;
+    stack.proof.config.n_queries = Felt::from(16);
+    stack.proof.config.log_n_cosets = Felt::from(4);
+    stack.proof.config.proof_of_work.n_bits = 30;
+
+    let program_hash = Felt::from_hex_unchecked("0x1234");
+    let output_hash = Felt::from_hex_unchecked("0x5678");
+    stack.push_front(&output_hash.to_bytes_be()).unwrap();
+    stack.push_front(&program_hash.to_bytes_be()).unwrap();
+
+    stack.push_task(IntegrityHashes::new());
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    let mut results = Vec::new();
+    for _ in 0..4 {
+        results.push(Felt::from_bytes_be_slice(stack.borrow_front()));
+        stack.pop_front();
+    }
+
+    let fact_hash = poseidon_reference(&[program_hash, output_hash]);
+    let verifier_config_hash = poseidon_reference(&[
+        layout,
+        settings::BLAKE2S_160_LSB,
+        settings::STONE5,
+        settings::MEMORY_VERIFICATION_STRICT,
+    ]);
+    // 16 queries over 2^4 cosets and 30 bits of proof of work
+    let verification_hash = poseidon_reference(&[fact_hash, verifier_config_hash, Felt::from(94)]);
+    assert_eq!(
+        results,
+        [program_hash, output_hash, fact_hash, verification_hash]
+    );
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+#[test]
+fn test_integrity_hashes_absent_with_continuous_pages() {
+    let mut stack = BidirectionalStackAccount::default();
+    stack.proof.public_input.continuous_page_headers =
+        FunVec::from_vec(vec![ContinuousPageHeader::default()]);
+
+    let program_hash = Felt::from_hex_unchecked("0x1234");
+    let output_hash = Felt::from_hex_unchecked("0x5678");
+    stack.push_front(&output_hash.to_bytes_be()).unwrap();
+    stack.push_front(&program_hash.to_bytes_be()).unwrap();
+    stack.push_task(IntegrityHashes::new());
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    let mut results = Vec::new();
+    for _ in 0..4 {
+        results.push(Felt::from_bytes_be_slice(stack.borrow_front()));
+        stack.pop_front();
+    }
+    assert_eq!(results, [program_hash, output_hash, Felt::ZERO, Felt::ZERO]);
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+}
+
+#[test]
+fn test_integrity_setting_names() {
+    let name = |value: Felt| {
+        let bytes = value.to_bytes_be();
+        let start = bytes.iter().position(|byte| *byte != 0).unwrap();
+        String::from_utf8(bytes[start..].to_vec()).unwrap()
+    };
+    assert_eq!(name(settings::STONE5), "stone5");
+    assert_eq!(name(settings::STONE6), "stone6");
+    assert_eq!(name(settings::KECCAK_160_LSB), "keccak_160_lsb");
+    assert_eq!(name(settings::BLAKE2S_160_LSB), "blake2s_160_lsb");
+    assert_eq!(name(settings::MEMORY_VERIFICATION_STRICT), "strict");
+}
+
+fn poseidon_reference(values: &[Felt]) -> Felt {
+    let values: Vec<StarknetFelt> = values
+        .iter()
+        .map(|value| StarknetFelt::from_bytes_be(&value.to_bytes_be()))
+        .collect();
+    Felt::from_bytes_be(&poseidon_hash_many(&values).to_bytes_be())
+}
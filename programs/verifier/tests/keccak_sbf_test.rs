@@ -0,0 +1,68 @@
+// Runs the verifier program built for SBF, where keccak256 goes through the sol_keccak256
+// syscall instead of sha3. Built and run by `cargo test-sbf`.
+#![cfg(feature = "test-sbf")]
+
+use solana_program_test::ProgramTest;
+use solana_sdk::{
+    account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+use stark::stark_proof::stark_commit::ComputeHash;
+use utils::{AccountCast, BidirectionalStack, Executable};
+use verifier::instruction::{execute, initialize, push_data, push_task};
+use verifier::state::BidirectionalStackAccount;
+
+// Keccak-256("abc")
+const KECCAK_ABC: [u8; 32] = [
+    0x4e, 0x03, 0x65, 0x7a, 0xea, 0x45, 0xa9, 0x4f, 0xc7, 0xd4, 0x7b, 0xa8, 0x26, 0xc8, 0xd6, 0x67,
+    0xc0, 0xd1, 0xe6, 0xe3, 0x3a, 0x64, 0xa0, 0x36, 0xec, 0x44, 0xf5, 0x8f, 0xa1, 0x2d, 0x6c, 0x45,
+];
+
+#[tokio::test]
+async fn test_compute_hash_with_sol_keccak256() {
+    let program_id = Pubkey::new_unique();
+    let verifier_keypair = Keypair::new();
+    let verifier_account = verifier_keypair.pubkey();
+
+    let mut program_test = ProgramTest::new("verifier", program_id, None);
+    program_test.prefer_bpf(true);
+    let size = std::mem::size_of::<BidirectionalStackAccount>();
+    program_test.add_account(
+        verifier_account,
+        Account {
+            lamports: Rent::default().minimum_balance(size),
+            data: vec![0; size],
+            owner: program_id,
+            ..Account::default()
+        },
+    );
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let authority = payer.pubkey();
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            initialize(&program_id, &verifier_account, &authority),
+            push_data(&program_id, &verifier_account, &authority, b"abc".to_vec()),
+            push_task(
+                &program_id,
+                &verifier_account,
+                &authority,
+                ComputeHash::new(3).to_vec_with_type_tag(),
+            ),
+            execute(&program_id, &verifier_account, 0),
+        ],
+        Some(&authority),
+        &[&payer, &verifier_keypair],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let account = banks_client
+        .get_account(verifier_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let stack = BidirectionalStackAccount::cast(&account.data);
+    assert_eq!(stack.borrow_front(), KECCAK_ABC.as_slice());
+    assert!(stack.is_empty_back(), "The task should have finished");
+}
@@ -0,0 +1,75 @@
+#![allow(deprecated)]
+
+use sha3::{Digest, Keccak256};
+use solana_program::keccak::hashv;
+use stark::stark_proof::stark_commit::ComputeHash;
+use stark::swiftness::commitment::hash::keccak256;
+use utils::{BidirectionalStack, Scheduler};
+use verifier::state::BidirectionalStackAccount;
+
+// Keccak-256("abc")
+const KECCAK_ABC: [u8; 32] = [
+    0x4e, 0x03, 0x65, 0x7a, 0xea, 0x45, 0xa9, 0x4f, 0xc7, 0xd4, 0x7b, 0xa8, 0x26, 0xc8, 0xd6, 0x67,
+    0xc0, 0xd1, 0xe6, 0xe3, 0x3a, 0x64, 0xa0, 0x36, 0xec, 0x44, 0xf5, 0x8f, 0xa1, 0x2d, 0x6c, 0x45,
+];
+
+// The syscall path is only built for SBF, see keccak_sbf_test
+#[test]
+fn test_keccak256_known_digest() {
+    assert_eq!(keccak256(&[b"abc"]), KECCAK_ABC);
+    assert_eq!(keccak256(&[b"a", b"bc"]), KECCAK_ABC);
+}
+
+#[test]
+fn test_keccak256_matches_sol_keccak256() {
+    let long_input = [0xab; 200];
+    let cases: [&[&[u8]]; 5] = [
+        &[],
+        &[b""],
+        &[b"abc"],
+        &[b"ab", b"", b"c"],
+        &[
+            &0x0123456789abcdedu64.to_be_bytes(),
+            &[7; 32],
+            &[20],
+            &long_input,
+        ],
+    ];
+
+    for parts in cases {
+        // hashv takes the parts the way the sol_keccak256 syscall does
+        assert_eq!(keccak256(parts), hashv(parts).to_bytes());
+        assert_eq!(
+            keccak256(parts),
+            <[u8; 32]>::from(Keccak256::digest(parts.concat()))
+        );
+    }
+}
+
+#[test]
+fn test_compute_hash_across_frames() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    let magic = 0x0123456789abcdedu64.to_be_bytes();
+    let digest = [0x5a; 32];
+    let n_bits = [20u8];
+
+    // Frames are hashed from the front, the ones below the input stay on the stack
+    stack.push_front(&[1, 2, 3]).unwrap();
+    stack.push_front(&n_bits).unwrap();
+    stack.push_front(&digest).unwrap();
+    stack.push_front(&magic).unwrap();
+    stack.push_task(ComputeHash::new(41));
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    let expected = hashv(&[&magic, &digest, &n_bits]).to_bytes();
+    assert_eq!(stack.borrow_front(), expected.as_slice());
+    stack.pop_front();
+    assert_eq!(stack.borrow_front(), [1, 2, 3].as_slice());
+    stack.pop_front();
+
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
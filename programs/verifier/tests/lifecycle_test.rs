@@ -0,0 +1,264 @@
+use arithmetic::add::Add;
+use felt::Felt;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_sdk::{signature::Keypair, signer::Signer};
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
+use stark::swiftness::air::starknet;
+use stark::swiftness::stark::types::cast_struct_to_slice;
+use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
+use utils::{AccountCast, BidirectionalStack, Executable, ProofData, TaskError};
+use verifier::instruction::{execute, initialize, VerifierInstruction};
+use verifier::state::{BidirectionalStackAccount, VerificationState};
+
+mod program_test;
+use program_test::{new_stack_account, program_test, set_account_data_chunks, TestBank};
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+const STEPS_PER_TRANSACTION: usize = 32;
+
+// Verifier account in a running bank, with the keypairs of the account and of its authority
+struct Verifier {
+    bank: TestBank,
+    account: Keypair,
+    authority: Keypair,
+    nonce: u32,
+}
+
+impl Verifier {
+    async fn new() -> Self {
+        let account = Keypair::new();
+        let mut program_test = program_test(PROGRAM_ID);
+        program_test.add_account(account.pubkey(), new_stack_account(PROGRAM_ID));
+        Self {
+            bank: TestBank::start(program_test).await,
+            account,
+            authority: Keypair::new(),
+            nonce: 0,
+        }
+    }
+
+    async fn stack(&mut self) -> Vec<u8> {
+        self.bank.data(&self.account.pubkey()).await
+    }
+
+    async fn state(&mut self) -> VerificationState {
+        BidirectionalStackAccount::cast(&self.stack().await)
+            .header
+            .state
+    }
+
+    // Initializes the verifier account, signed by the account and the authority
+    async fn initialize(&mut self) -> Result<(), ProgramError> {
+        let instruction = initialize(
+            &PROGRAM_ID,
+            &self.account.pubkey(),
+            &self.authority.pubkey(),
+        );
+        self.bank
+            .process(&[instruction], &[&self.account, &self.authority])
+            .await
+    }
+
+    // Runs an instruction on the verifier account, signed by the authority
+    async fn run(&mut self, instruction: &VerifierInstruction) -> Result<(), ProgramError> {
+        let instruction = Instruction::new_with_borsh(
+            PROGRAM_ID,
+            instruction,
+            vec![
+                AccountMeta::new(self.account.pubkey(), false),
+                AccountMeta::new_readonly(self.authority.pubkey(), true),
+            ],
+        );
+        self.bank.process(&[instruction], &[&self.authority]).await
+    }
+
+    // Runs steps in one transaction, the nonces keeping the transactions distinct
+    async fn execute_steps(&mut self, steps: usize) -> Result<(), ProgramError> {
+        let instructions: Vec<_> = (0..steps)
+            .map(|_| {
+                self.nonce += 1;
+                execute(&PROGRAM_ID, &self.account.pubkey(), self.nonce)
+            })
+            .collect();
+        self.bank.process(&instructions, &[]).await
+    }
+
+    async fn execute(&mut self) -> Result<(), ProgramError> {
+        self.execute_steps(1).await
+    }
+
+    // Runs steps until one is refused, returning its error. The transaction reaching the end of
+    // the verification fails as a whole, so its steps run again one by one.
+    async fn execute_all(&mut self) -> ProgramError {
+        while self.execute_steps(STEPS_PER_TRANSACTION).await.is_ok() {}
+        loop {
+            if let Err(error) = self.execute().await {
+                return error;
+            }
+        }
+    }
+
+    async fn upload_proof(&mut self) -> Result<(), ProgramError> {
+        let input = include_str!("../../../example_proof/saya.json");
+        let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
+        let proof = StarkProofParser::try_from(proof_json).unwrap();
+        let proof: stark::swiftness::stark::types::StarkProof = proof.transform_to();
+        let instructions = set_account_data_chunks(
+            &PROGRAM_ID,
+            &self.account.pubkey(),
+            &self.authority.pubkey(),
+            std::mem::offset_of!(BidirectionalStackAccount, proof),
+            cast_struct_to_slice(&proof),
+        );
+        self.bank.process(&instructions, &[&self.authority]).await
+    }
+
+    async fn seal(&mut self) -> Result<(), ProgramError> {
+        self.run(&VerifierInstruction::Seal {
+            min_security_bits: DEFAULT_SECURITY_BITS,
+        })
+        .await
+    }
+}
+
+#[tokio::test]
+async fn test_lifecycle() {
+    let mut verifier = Verifier::new().await;
+    assert_eq!(verifier.state().await, VerificationState::Uninitialized);
+    assert_eq!(
+        verifier.upload_proof().await,
+        Err(ProgramError::UninitializedAccount)
+    );
+
+    verifier.initialize().await.unwrap();
+    assert_eq!(verifier.state().await, VerificationState::Uploading);
+    assert_eq!(
+        BidirectionalStackAccount::cast(&verifier.stack().await)
+            .header
+            .authority,
+        verifier.authority.pubkey()
+    );
+    assert_eq!(
+        verifier.initialize().await,
+        Err(ProgramError::AccountAlreadyInitialized)
+    );
+    assert_eq!(
+        verifier.execute().await,
+        Err(ProgramError::Custom(TaskError::StackUnderflow.code()))
+    );
+
+    verifier.upload_proof().await.unwrap();
+    verifier.seal().await.unwrap();
+    assert_eq!(verifier.state().await, VerificationState::Sealed);
+
+    // The proof and the stack are immutable once sealed
+    assert_eq!(
+        verifier.upload_proof().await,
+        Err(ProgramError::InvalidAccountData)
+    );
+    assert_eq!(
+        verifier
+            .run(&VerifierInstruction::PushTask(
+                Add::new(1, 2).to_vec_with_type_tag()
+            ))
+            .await,
+        Err(ProgramError::InvalidAccountData)
+    );
+    assert_eq!(verifier.seal().await, Err(ProgramError::InvalidAccountData));
+
+    verifier.execute().await.unwrap();
+    assert_eq!(verifier.state().await, VerificationState::Verifying);
+
+    // Steps run until the verification finished, then they are refused
+    assert_eq!(
+        verifier.execute_all().await,
+        ProgramError::InvalidAccountData
+    );
+    assert_eq!(verifier.state().await, VerificationState::Verified);
+    assert!(BidirectionalStackAccount::cast(&verifier.stack().await)
+        .verified_hashes()
+        .is_some());
+
+    // Reset starts another verification, of a proof uploaded again
+    verifier.run(&VerifierInstruction::Reset).await.unwrap();
+    let data = verifier.stack().await;
+    let stack = BidirectionalStackAccount::cast(&data);
+    assert_eq!(stack.header.state, VerificationState::Uploading);
+    assert!(stack.is_empty_back());
+    assert_eq!(stack.verified_hashes(), None);
+    assert_eq!(stack.header.upload.first_missing(), Some(0));
+    verifier.upload_proof().await.unwrap();
+    verifier.seal().await.unwrap();
+    assert_eq!(verifier.state().await, VerificationState::Sealed);
+
+    // A reset account cannot be initialized again
+    assert_eq!(
+        verifier.initialize().await,
+        Err(ProgramError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn test_execute_uploaded_tasks() {
+    let mut verifier = Verifier::new().await;
+    verifier.initialize().await.unwrap();
+
+    let task = Add::new(48, 52).to_vec_with_type_tag();
+    verifier
+        .run(&VerifierInstruction::PushTask(task))
+        .await
+        .unwrap();
+    verifier.execute().await.unwrap();
+
+    let data = verifier.stack().await;
+    let stack = BidirectionalStackAccount::cast(&data);
+    let result = u128::from_be_bytes(stack.borrow_front().try_into().unwrap());
+    assert_eq!(result, 100);
+    assert_eq!(stack.header.state, VerificationState::Uploading);
+}
+
+#[tokio::test]
+async fn test_seal_clears_working_memory() {
+    let mut verifier = Verifier::new().await;
+    verifier.initialize().await.unwrap();
+    verifier.upload_proof().await.unwrap();
+
+    // Values left by tasks run while uploading
+    let address = verifier.account.pubkey();
+    let mut account = verifier.bank.account(&address).await.unwrap();
+    let stack = BidirectionalStackAccount::cast_mut(&mut account.data);
+    stack.autogenerated_pows.fill(Felt::ONE);
+    stack.oods_values.fill(Felt::ONE);
+    stack.domains.fill(Felt::ONE);
+    stack.set_global_values(starknet::GlobalValues {
+        trace_length: Felt::ONE,
+        ..Default::default()
+    });
+    stack.constraint_coefficients.fill(Felt::ONE);
+    stack.column_values.fill(Felt::ONE);
+    stack.stark_commitment.interaction_after_composition = Felt::ONE;
+    stack.stark_commitment.oods_values.push(Felt::ONE);
+    stack.stark_commitment.fri.eval_points.push(Felt::ONE);
+    stack.queries.queries.push(Felt::ONE);
+    verifier.bank.set_account(&address, account);
+
+    verifier.seal().await.unwrap();
+    let data = verifier.stack().await;
+    let stack = BidirectionalStackAccount::cast(&data);
+    let default = BidirectionalStackAccount::default();
+    assert_eq!(stack.autogenerated_pows, default.autogenerated_pows);
+    assert_eq!(stack.oods_values, default.oods_values);
+    assert_eq!(stack.domains, default.domains);
+    assert_eq!(stack.global_values, default.global_values);
+    assert_eq!(
+        stack.constraint_coefficients,
+        default.constraint_coefficients
+    );
+    assert_eq!(stack.column_values, default.column_values);
+    assert_eq!(stack.stark_commitment, default.stark_commitment);
+    assert_eq!(stack.queries, default.queries);
+}
@@ -26,12 +26,13 @@ fn pedersen_hash() {
             "0208a0a10250e382e1e4bbe2880906c2791bf6275695e02fbbc6aeff9cd8b31a",
         ),
         &mut stack,
-    );
+    )
+    .unwrap();
     stack.push_task(PedersenHash::new());
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
     }
-    let result = Felt::from_bytes_be_slice(stack.borrow_front().try_into().unwrap());
+    let result = Felt::from_bytes_be_slice(stack.borrow_front());
     println!("result: {:?}", result);
 
     let expected = Felt::from_hex_unchecked(
@@ -55,13 +55,13 @@ fn test_hash_with_inputs(inputs: &[Felt], expected: Felt) {
 
     // Create the PoseidonHashMany task with the stack reference
     let hash_task = PoseidonHashMany::new(inputs.len());
-    PoseidonHashMany::push_input(inputs, &mut stack);
+    PoseidonHashMany::push_input(inputs, &mut stack).unwrap();
     stack.push_task(hash_task);
 
     // Execute until completion
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
@@ -22,13 +22,13 @@ fn test_hash_with_inputs(inputs: &[Felt], expected: StarkFelt) {
 
     // Create the PoseidonHashMany task with the stack reference
     let hash_task = PoseidonHash::new();
-    PoseidonHash::push_input(inputs[0], inputs[1], &mut stack);
+    PoseidonHash::push_input(inputs[0], inputs[1], &mut stack).unwrap();
     stack.push_task(hash_task);
 
     // Execute until completion
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
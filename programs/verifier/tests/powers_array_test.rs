@@ -22,7 +22,7 @@ fn test_powers_array() {
     // Execute until completion
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
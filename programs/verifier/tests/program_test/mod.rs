@@ -0,0 +1,173 @@
+#![allow(dead_code)]
+
+// Bank running the verifier program for the instruction tests. The verifier, and the programs
+// calling it through CPI, run as native processors.
+
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signature},
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use std::collections::HashSet;
+use utils::AccountCast;
+use verifier::instruction::set_account_data;
+use verifier::processor::process_instruction;
+use verifier::state::BidirectionalStackAccount;
+
+pub const SLOT: u64 = 42;
+
+/// Largest chunk written by one instruction, the instruction data length is a u16
+pub const CHUNK_SIZE: usize = 60_000;
+
+/// Program test with the verifier program at `program_id`
+pub fn program_test(program_id: Pubkey) -> ProgramTest {
+    let mut program_test =
+        ProgramTest::new("verifier", program_id, processor!(process_instruction));
+    // The programs of the tests only exist as native processors, also under `cargo test-sbf`
+    program_test.prefer_bpf(false);
+    program_test
+}
+
+/// Rent exempt account of `owner` holding `data`
+pub fn program_account(owner: Pubkey, data: Vec<u8>) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner,
+        ..Account::default()
+    }
+}
+
+/// Uninitialized verifier account of `program_id`
+pub fn new_stack_account(program_id: Pubkey) -> Account {
+    program_account(
+        program_id,
+        vec![0; std::mem::size_of::<BidirectionalStackAccount>()],
+    )
+}
+
+/// Verifier account of `program_id` holding the stack set up by `init`
+pub fn stack_account(
+    program_id: Pubkey,
+    init: impl FnOnce(&mut BidirectionalStackAccount),
+) -> Account {
+    let mut account = new_stack_account(program_id);
+    let stack = BidirectionalStackAccount::cast_mut(&mut account.data);
+    *stack = BidirectionalStackAccount::default();
+    init(stack);
+    account
+}
+
+/// `SetAccountData` instructions writing `data` at `offset`, in chunks fitting an instruction
+pub fn set_account_data_chunks(
+    program_id: &Pubkey,
+    verifier_account: &Pubkey,
+    authority: &Pubkey,
+    offset: usize,
+    data: &[u8],
+) -> Vec<Instruction> {
+    data.chunks(CHUNK_SIZE)
+        .enumerate()
+        .map(|(i, chunk)| {
+            set_account_data(
+                program_id,
+                verifier_account,
+                authority,
+                offset + i * CHUNK_SIZE,
+                chunk.to_vec(),
+            )
+        })
+        .collect()
+}
+
+/// Started program test, at slot SLOT
+pub struct TestBank {
+    context: ProgramTestContext,
+    // Signatures of the processed transactions, a transaction sent again needs a new blockhash
+    signatures: HashSet<Signature>,
+}
+
+impl TestBank {
+    pub async fn start(program_test: ProgramTest) -> Self {
+        let mut context = program_test.start_with_context().await;
+        context.warp_to_slot(SLOT).unwrap();
+        Self {
+            context,
+            signatures: HashSet::new(),
+        }
+    }
+
+    /// Payer of the transactions
+    pub fn payer(&self) -> &Keypair {
+        &self.context.payer
+    }
+
+    /// Processes the instructions in one transaction signed by the payer and the signers,
+    /// returning the error of the failed instruction
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), ProgramError> {
+        self.context.last_blockhash = self
+            .context
+            .banks_client
+            .get_latest_blockhash()
+            .await
+            .unwrap();
+        let mut transaction = self.transaction(instructions, signers);
+        if !self.signatures.insert(transaction.signatures[0]) {
+            self.context.last_blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+            transaction = self.transaction(instructions, signers);
+            self.signatures.insert(transaction.signatures[0]);
+        }
+
+        match self
+            .context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+        {
+            Ok(()) => Ok(()),
+            Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+                _,
+                error,
+            ))) => Err(ProgramError::try_from(error.clone())
+                .unwrap_or_else(|_| panic!("Instruction failed in the runtime: {error}"))),
+            Err(error) => panic!("Transaction failed: {error}"),
+        }
+    }
+
+    fn transaction(&self, instructions: &[Instruction], signers: &[&Keypair]) -> Transaction {
+        let payer = &self.context.payer;
+        Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[&[payer], signers].concat(),
+            self.context.last_blockhash,
+        )
+    }
+
+    pub async fn account(&mut self, address: &Pubkey) -> Option<Account> {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+    }
+
+    /// Data of an existing account
+    pub async fn data(&mut self, address: &Pubkey) -> Vec<u8> {
+        self.account(address).await.unwrap().data
+    }
+
+    pub fn set_account(&mut self, address: &Pubkey, account: Account) {
+        self.context.set_account(address, &account.into());
+    }
+}
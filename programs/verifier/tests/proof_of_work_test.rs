@@ -1,7 +1,11 @@
+use blake2::{Blake2s256, Digest};
 use felt::Felt;
-use stark::stark_proof::stark_commit::ProofOfWork;
+use stark::stark_proof::stark_commit::proof_of_work::MAGIC;
+use stark::stark_proof::stark_commit::{Blake2sHash, ProofOfWork};
+use stark::swiftness::stark::config::Hasher;
 use stark::swiftness::stark::types::StarkProof;
-use utils::{BidirectionalStack, Scheduler};
+use starknet_crypto::{poseidon_hash_many, Felt as StarknetFelt};
+use utils::{BidirectionalStack, Scheduler, TaskError};
 use verifier::state::BidirectionalStackAccount;
 
 mod fixtures;
@@ -27,7 +31,7 @@ fn test_proof_of_work_with_reference_values() {
 
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
@@ -48,3 +52,108 @@ fn test_proof_of_work_with_reference_values() {
     assert_eq!(stack.back_index, 65536, "Stack should be empty");
     println!("ProofOfWork test completed successfully in {} steps", steps);
 }
+
+#[test]
+fn test_blake2s_hash() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    stack.push_front(b"abc").unwrap();
+    stack.push_task(Blake2sHash::new(3));
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    // BLAKE2s-256("abc") from RFC 7693, Appendix B
+    let expected: [u8; 32] = [
+        0x50, 0x8c, 0x5e, 0x8c, 0x32, 0x7c, 0x14, 0xe2, 0xe1, 0xa7, 0x2b, 0xa3, 0x4e, 0xeb, 0x45,
+        0x2f, 0x37, 0x45, 0x8b, 0x20, 0x9e, 0xd6, 0x3a, 0x29, 0x4d, 0x99, 0x9b, 0x4c, 0x86, 0x67,
+        0x59, 0x82,
+    ];
+    assert_eq!(stack.borrow_front(), expected.as_slice());
+    stack.pop_front();
+
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+#[test]
+fn test_blake2s_hash_ending_inside_a_frame() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    let below = Felt::from_hex_unchecked("0x5e7e1");
+    stack.push_front(&below.to_bytes_be()).unwrap();
+    stack.push_front(b"cdef").unwrap();
+    stack.push_front(b"ab").unwrap();
+    stack.push_task(Blake2sHash::new(3));
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    // Only "abc" is hashed, the unread "def" stays on the stack
+    assert_eq!(stack.borrow_front(), Blake2s256::digest(b"abc").as_slice());
+    stack.pop_front();
+    assert_eq!(stack.borrow_front(), b"def");
+    stack.pop_front();
+    assert_eq!(Felt::from_bytes_be_slice(stack.borrow_front()), below);
+    stack.pop_front();
+
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+}
+
+#[test]
+fn test_blake2s_hash_rejects_short_input() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    stack.push_front(b"ab").unwrap();
+    stack.push_task(Blake2sHash::new(3));
+    assert_eq!(stack.execute(), Err(TaskError::StackUnderflow));
+}
+
+#[test]
+fn test_proof_of_work_blake2s() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    let digest = Felt::from_hex_unchecked("0x123456789abcdef");
+    let n_bits: u8 = 8;
+
+    // Smallest nonce satisfying the work with Blake2s
+    let mut init_input = MAGIC.to_be_bytes().to_vec();
+    init_input.extend_from_slice(&digest.to_bytes_be());
+    init_input.push(n_bits);
+    let init_hash = Blake2s256::digest(&init_input);
+    let nonce = (0u64..)
+        .find(|nonce| {
+            let final_hash =
+                Blake2s256::digest([init_hash.as_slice(), &nonce.to_be_bytes()].concat());
+            final_hash[0] == 0
+        })
+        .unwrap();
+
+    let mut proof = StarkProof::default();
+    proof.verifier_config.hasher = Hasher::Blake2s256 as u8;
+    proof.config.proof_of_work.n_bits = n_bits;
+    proof.unsent_commitment.proof_of_work.nonce = nonce;
+    stack.proof = proof;
+
+    stack.push_front(&digest.to_bytes_be()).unwrap();
+    stack.push_task(ProofOfWork::new());
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    let reseted_counter = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    let result_digest = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+
+    let to_starknet = |value: Felt| StarknetFelt::from_bytes_be(&value.to_bytes_be());
+    let expected_digest = poseidon_hash_many(&[
+        to_starknet(digest + Felt::ONE),
+        to_starknet(Felt::from(nonce)),
+    ]);
+    assert_eq!(result_digest.to_bytes_be(), expected_digest.to_bytes_be());
+    assert_eq!(reseted_counter, Felt::ZERO);
+
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
@@ -0,0 +1,54 @@
+use felt::Felt;
+use stark::stark_proof::stark_commit::StarkCommit;
+use stark::stark_proof::stark_verify::StarkVerify;
+use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
+use utils::{BidirectionalStack, Scheduler};
+use verifier::state::BidirectionalStackAccount;
+
+#[test]
+fn test_stark_verify() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    let input = include_str!("../../../example_proof/saya.json");
+    let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
+    let proof = StarkProofParser::try_from(proof_json).unwrap();
+    stack.proof = proof.transform_to();
+    let oods_values = stack.proof.unsent_commitment.oods_values;
+    stack.oods_values[..oods_values.len()].copy_from_slice(oods_values.as_slice());
+
+    let trace_generator =
+        Felt::from_hex("0x57a797181c06d8427145cb66056f032751615d8617c5468258e96d2bb6422f9")
+            .unwrap();
+    stack.push_front(&trace_generator.to_bytes_be()).unwrap();
+
+    let trace_domain_size = Felt::from_hex("0x10000000").unwrap();
+    stack.push_front(&trace_domain_size.to_bytes_be()).unwrap();
+
+    let digest =
+        Felt::from_hex("0x59496b8e649ff03c8e9f739e141bd82653fccb2fb1b1a51a71760ea3813ea35")
+            .unwrap();
+    stack.push_front(&digest.to_bytes_be()).unwrap();
+
+    let counter = Felt::from_hex("0x0").unwrap();
+    stack.push_front(&counter.to_bytes_be()).unwrap();
+
+    // The query phase continues from the transcript state left by StarkCommit
+    stack.push_task(StarkVerify::new());
+    stack.push_task(StarkCommit::new());
+
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    let n_queries: usize = stack.proof.config.n_queries.try_into().unwrap();
+    assert!(!stack.queries.queries.is_empty());
+    assert!(stack.queries.queries.len() <= n_queries);
+    assert_eq!(
+        stack.queries.fri_queries.len(),
+        stack.queries.layer_indices.len()
+    );
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
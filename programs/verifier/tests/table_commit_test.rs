@@ -37,7 +37,7 @@ fn test_table_commitment() {
 
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
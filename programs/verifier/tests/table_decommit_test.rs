@@ -0,0 +1,294 @@
+use blake2::Blake2s256;
+use felt::Felt;
+use sha3::{Digest, Keccak256};
+use stark::funvec::FunVec;
+use stark::stark_proof::stark_verify::{TableDecommit, TableDecommitTarget};
+use stark::swiftness::air::layout::LayoutId;
+use stark::swiftness::commitment::hash::{hash_row, MAX_ROW_LENGTH};
+use stark::swiftness::commitment::table::types::MONTGOMERY_R;
+use stark::swiftness::stark::config::Hasher;
+use starknet_crypto::Felt as StarknetFelt;
+use starknet_crypto::{poseidon_hash, poseidon_hash_many};
+use utils::{BidirectionalStack, Scheduler, TaskError};
+use verifier::state::BidirectionalStackAccount;
+
+const HEIGHT: usize = 5;
+const ROWS: [u64; 6] = [0, 3, 4, 13, 21, 31];
+
+#[test]
+fn test_table_decommit_single_column() {
+    run_table_decommit(TableDecommitTarget::TracesOriginal, 1, 2, Hasher::Keccak256);
+}
+
+#[test]
+fn test_table_decommit_poseidon_rows() {
+    // n_verifier_friendly_layers >= height + 1, rows are hashed with Poseidon
+    run_table_decommit(
+        TableDecommitTarget::TracesInteraction,
+        4,
+        HEIGHT + 1,
+        Hasher::Keccak256,
+    );
+}
+
+#[test]
+fn test_table_decommit_keccak_rows() {
+    run_table_decommit(TableDecommitTarget::Composition, 3, 2, Hasher::Keccak256);
+}
+
+#[test]
+fn test_table_decommit_blake2s_rows() {
+    run_table_decommit(TableDecommitTarget::Composition, 3, 2, Hasher::Blake2s256);
+}
+
+#[test]
+fn test_table_decommit_widest_rows() {
+    run_table_decommit(
+        TableDecommitTarget::Composition,
+        MAX_ROW_LENGTH,
+        2,
+        Hasher::Keccak256,
+    );
+}
+
+#[test]
+fn test_table_decommit_supported_layout_rows() {
+    for params in LayoutId::ALL.iter().filter_map(LayoutId::params) {
+        run_table_decommit(
+            TableDecommitTarget::TracesOriginal,
+            params.num_columns_first as usize,
+            2,
+            Hasher::Keccak256,
+        );
+        run_table_decommit(
+            TableDecommitTarget::TracesInteraction,
+            params.num_columns_second as usize,
+            2,
+            Hasher::Keccak256,
+        );
+    }
+}
+
+#[test]
+fn test_table_decommit_rejects_too_long_rows() {
+    let (mut stack, _) = table_decommit_stack(
+        TableDecommitTarget::Composition,
+        MAX_ROW_LENGTH + 1,
+        2,
+        Hasher::Keccak256,
+    );
+    stack.push_task(TableDecommit::new(TableDecommitTarget::Composition));
+    assert_eq!(
+        stack.execute(),
+        Err(TaskError::InvalidConfig("Table row too long"))
+    );
+
+    let values = [Felt::ONE; MAX_ROW_LENGTH + 1];
+    assert_eq!(
+        hash_row(Hasher::Keccak256, &values),
+        Err(TaskError::InvalidConfig("Table row too long"))
+    );
+}
+
+#[test]
+fn test_table_decommit_rejects_tampered_values() {
+    // The Montgomery values still open to the root, but the values used by the verifier don't
+    for n_columns in [1, 3] {
+        let (mut stack, _) = table_decommit_stack(
+            TableDecommitTarget::Composition,
+            n_columns,
+            2,
+            Hasher::Keccak256,
+        );
+        let values = stack
+            .proof
+            .witness
+            .composition_decommitment
+            .values
+            .as_slice_mut();
+        values[n_columns] += Felt::ONE;
+
+        stack.push_task(TableDecommit::new(TableDecommitTarget::Composition));
+        let error = loop {
+            if let Err(error) = stack.execute() {
+                break error;
+            }
+        };
+        assert!(matches!(error, TaskError::CommitmentMismatch { .. }));
+    }
+}
+
+fn run_table_decommit(
+    target: TableDecommitTarget,
+    n_columns: usize,
+    n_verifier_friendly_layers: usize,
+    hasher: Hasher,
+) {
+    let (mut stack, root) =
+        table_decommit_stack(target, n_columns, n_verifier_friendly_layers, hasher);
+    stack.push_task(TableDecommit::new(target));
+
+    let mut steps = 0;
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+        steps += 1;
+    }
+
+    println!("TableDecommit {:?} completed in {} steps", target, steps);
+
+    assert_eq!(stack.queries.nodes.len(), 1);
+    assert_eq!(stack.queries.nodes.at(0).value, root);
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+// Account holding the decommitment of ROWS of a full table, with its root
+fn table_decommit_stack(
+    target: TableDecommitTarget,
+    n_columns: usize,
+    n_verifier_friendly_layers: usize,
+    hasher: Hasher,
+) -> (BidirectionalStackAccount, Felt) {
+    let mut stack = BidirectionalStackAccount::default();
+
+    // Full table, in standard form
+    let table: Vec<Vec<Felt>> = (0..1u64 << HEIGHT)
+        .map(|row| {
+            (0..n_columns as u64)
+                .map(|column| Felt::from(row * 0x10001 + column * 0x3579 + 7))
+                .collect()
+        })
+        .collect();
+
+    // Full tree: tree[depth][i], the leaves at depth HEIGHT
+    let mut tree = vec![vec![]; HEIGHT + 1];
+    tree[HEIGHT] = table
+        .iter()
+        .map(|row| leaf_reference(row, n_verifier_friendly_layers, hasher))
+        .collect();
+    for depth in (1..=HEIGHT).rev() {
+        tree[depth - 1] = tree[depth]
+            .chunks(2)
+            .map(|pair| node_reference(pair[0], pair[1], depth, n_verifier_friendly_layers, hasher))
+            .collect();
+    }
+    let root = tree[0][0];
+
+    let values: Vec<Felt> = ROWS
+        .iter()
+        .flat_map(|&row| table[row as usize].clone())
+        .collect();
+    let montgomery_values = values.iter().map(|value| value * MONTGOMERY_R).collect();
+
+    let proof = &mut stack.proof;
+    proof.verifier_config.hasher = hasher as u8;
+    let (config, decommitment, witness) = match target {
+        TableDecommitTarget::TracesOriginal => (
+            &mut proof.config.traces.original,
+            &mut proof.witness.traces_decommitment.original,
+            &mut proof.witness.traces_witness.original,
+        ),
+        TableDecommitTarget::TracesInteraction => (
+            &mut proof.config.traces.interaction,
+            &mut proof.witness.traces_decommitment.interaction,
+            &mut proof.witness.traces_witness.interaction,
+        ),
+        TableDecommitTarget::Composition => (
+            &mut proof.config.composition,
+            &mut proof.witness.composition_decommitment,
+            &mut proof.witness.composition_witness,
+        ),
+        TableDecommitTarget::FriLayer(_) => unreachable!(),
+    };
+    config.n_columns = Felt::from(n_columns);
+    config.vector.height = Felt::from(HEIGHT);
+    config.vector.n_verifier_friendly_commitment_layers = Felt::from(n_verifier_friendly_layers);
+    decommitment.values = FunVec::from_vec(values);
+    decommitment.montgomery_values = FunVec::from_vec(montgomery_values);
+    witness.vector.authentications = FunVec::from_vec(authentications_reference(&tree));
+
+    let commitment = &mut stack.stark_commitment;
+    match target {
+        TableDecommitTarget::TracesOriginal => {
+            commitment.traces.original.vector_commitment.commitment_hash = root
+        }
+        TableDecommitTarget::TracesInteraction => {
+            commitment
+                .traces
+                .interaction
+                .vector_commitment
+                .commitment_hash = root
+        }
+        _ => commitment.composition.vector_commitment.commitment_hash = root,
+    }
+
+    stack.queries.queries.flush();
+    for row in ROWS {
+        stack.queries.queries.push(Felt::from(row));
+    }
+
+    (stack, root)
+}
+
+fn to_starknet(x: &Felt) -> StarknetFelt {
+    StarknetFelt::from_bytes_be(&x.to_bytes_be())
+}
+
+fn masked_reference(values: &[Felt], hasher: Hasher) -> Felt {
+    let bytes: Vec<u8> = values
+        .iter()
+        .flat_map(|value| value.to_bytes_be())
+        .collect();
+    let digest = match hasher {
+        Hasher::Keccak256 => Keccak256::digest(&bytes).to_vec(),
+        Hasher::Blake2s256 => Blake2s256::digest(&bytes).to_vec(),
+    };
+    Felt::from_bytes_be_slice(&digest[12..])
+}
+
+// Reference leaf of a table row, over the Montgomery form of its values
+fn leaf_reference(row: &[Felt], n_verifier_friendly_layers: usize, hasher: Hasher) -> Felt {
+    let montgomery: Vec<Felt> = row.iter().map(|value| value * MONTGOMERY_R).collect();
+    if montgomery.len() == 1 {
+        montgomery[0]
+    } else if n_verifier_friendly_layers > HEIGHT {
+        let inputs: Vec<StarknetFelt> = montgomery.iter().map(to_starknet).collect();
+        Felt::from_bytes_be(&poseidon_hash_many(&inputs).to_bytes_be())
+    } else {
+        masked_reference(&montgomery, hasher)
+    }
+}
+
+// Reference hash of two children at the given depth
+fn node_reference(
+    x: Felt,
+    y: Felt,
+    depth: usize,
+    n_verifier_friendly_layers: usize,
+    hasher: Hasher,
+) -> Felt {
+    if n_verifier_friendly_layers >= depth {
+        Felt::from_bytes_be(&poseidon_hash(to_starknet(&x), to_starknet(&y)).to_bytes_be())
+    } else {
+        masked_reference(&[x, y], hasher)
+    }
+}
+
+// Siblings of the queried nodes that are not themselves known, bottom layer up, left to right
+fn authentications_reference(tree: &[Vec<Felt>]) -> Vec<Felt> {
+    let mut authentications = vec![];
+    let mut known: Vec<u64> = ROWS.to_vec();
+    for depth in (1..=HEIGHT).rev() {
+        for &index in &known {
+            let sibling = index ^ 1;
+            if !known.contains(&sibling) {
+                authentications.push(tree[depth][sibling as usize]);
+            }
+        }
+        known = known.iter().map(|index| index / 2).collect();
+        known.dedup();
+    }
+    authentications
+}
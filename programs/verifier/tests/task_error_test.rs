@@ -0,0 +1,307 @@
+use felt::Felt;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use stark::poseidon::PoseidonHash;
+use stark::stark_proof::stark_verify::FriVerify;
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
+use stark::stark_proof::verify::Verify;
+use stark::stark_proof::{segments, VerifyPublicInput};
+use stark::swiftness::air::types::SegmentInfo;
+use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
+use utils::{AccountCast, BidirectionalStack, Scheduler, TaskError};
+use verifier::instruction::execute;
+use verifier::state::{BidirectionalStackAccount, VerificationState};
+
+mod program_test;
+use program_test::{program_test, stack_account, TestBank};
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+fn saya_stack() -> BidirectionalStackAccount {
+    let mut stack = BidirectionalStackAccount::default();
+    let input = include_str!("../../../example_proof/saya.json");
+    let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
+    let proof = StarkProofParser::try_from(proof_json).unwrap();
+    stack.proof = proof.transform_to();
+    stack
+}
+
+fn verify(stack: &mut BidirectionalStackAccount) -> Result<u128, TaskError> {
+    stack.push_task(Verify::new());
+    stack.simulate()
+}
+
+#[test]
+fn test_verify_rejects_invalid_public_input() {
+    let mut stack = saya_stack();
+    stack.proof.public_input.log_n_steps = Felt::from(0x50);
+    assert_eq!(
+        verify(&mut stack),
+        Err(TaskError::InvalidPublicInput("log_n_steps exceeds maximum"))
+    );
+}
+
+#[test]
+fn test_verify_rejects_invalid_dynamic_params_flag() {
+    for has_dynamic_params in [2, u32::MAX] {
+        let mut stack = saya_stack();
+        stack.proof.public_input.has_dynamic_params = has_dynamic_params;
+        assert_eq!(
+            verify(&mut stack),
+            Err(TaskError::InvalidPublicInput("Invalid dynamic params flag"))
+        );
+    }
+
+    // Dynamic params for a layout that is not dynamic
+    let mut stack = saya_stack();
+    stack.proof.public_input.has_dynamic_params = 1;
+    assert_eq!(
+        verify(&mut stack),
+        Err(TaskError::InvalidPublicInput(
+            "Dynamic params are only allowed for the dynamic layout"
+        ))
+    );
+}
+
+#[test]
+fn test_verify_rejects_insufficient_proof_of_work() {
+    let mut stack = saya_stack();
+    stack.proof.unsent_commitment.proof_of_work.nonce ^= 1;
+    assert_eq!(verify(&mut stack), Err(TaskError::PowInsufficient));
+}
+
+#[test]
+fn test_verify_rejects_oods_mismatch() {
+    let mut stack = saya_stack();
+    let oods_values = stack.proof.unsent_commitment.oods_values.as_slice_mut();
+    let last = oods_values.len() - 1;
+    oods_values[last] += Felt::ONE;
+    assert!(matches!(
+        verify(&mut stack),
+        Err(TaskError::OodsMismatch { claimed, computed }) if claimed != computed
+    ));
+}
+
+#[test]
+fn test_verify_rejects_invalid_verifier_config() {
+    let mut stack = saya_stack();
+    stack.proof.verifier_config.stone_version = 2;
+    assert_eq!(
+        verify(&mut stack),
+        Err(TaskError::InvalidConfig("Invalid stone version"))
+    );
+
+    let mut stack = saya_stack();
+    stack.proof.verifier_config.hasher = 0xff;
+    assert_eq!(
+        verify(&mut stack),
+        Err(TaskError::InvalidConfig("Invalid hasher"))
+    );
+}
+
+#[test]
+fn test_verify_public_input_rejects_invalid_segments() {
+    let run = |tamper: &dyn Fn(&mut BidirectionalStackAccount)| {
+        let mut stack = saya_stack();
+        tamper(&mut stack);
+        stack.push_task(VerifyPublicInput::new());
+        while !stack.is_empty_back() {
+            stack.execute()?;
+        }
+        Ok(())
+    };
+    fn segment(stack: &mut BidirectionalStackAccount, index: usize) -> &mut SegmentInfo {
+        &mut stack.proof.public_input.segments.as_slice_mut()[index]
+    }
+
+    assert_eq!(run(&|_| {}), Ok(()));
+    // The execution segment starts before the end of the program
+    assert_eq!(
+        run(&|stack| segment(stack, segments::EXECUTION).begin_addr = Felt::TWO),
+        Err(TaskError::InvalidPublicInput("Invalid program segment"))
+    );
+    assert_eq!(
+        run(&|stack| segment(stack, segments::EXECUTION).begin_addr = Felt::MAX),
+        Err(TaskError::InvalidPublicInput(
+            "Initial AP exceeds maximum address"
+        ))
+    );
+    assert_eq!(
+        run(&|stack| segment(stack, segments::PROGRAM).begin_addr = Felt::MAX),
+        Err(TaskError::InvalidPublicInput("Wrong initial PC"))
+    );
+    assert_eq!(
+        run(&|stack| stack.proof.public_input.segments.flush()),
+        Err(TaskError::InvalidPublicInput("Missing segment"))
+    );
+}
+
+#[test]
+fn test_public_memory_product_ratio_rejects_invalid_public_input() {
+    let stack = saya_stack();
+    let public_input = &stack.proof.public_input;
+    let alpha = Felt::from(5);
+    let (_, total_length) = public_input.get_public_memory_product(Felt::THREE, alpha);
+    assert!(public_input
+        .get_public_memory_product_ratio(Felt::THREE, alpha, total_length)
+        .is_ok());
+
+    // More public memory cells than the trace has room for
+    assert_eq!(
+        public_input.get_public_memory_product_ratio(Felt::THREE, alpha, total_length - Felt::ONE),
+        Err(TaskError::InvalidPublicInput(
+            "Public memory exceeds the trace"
+        ))
+    );
+
+    // A challenge equal to a cell of the main page zeroes the product
+    let cell = &public_input.main_page.0.as_slice()[0];
+    let z = cell.address + alpha * cell.value;
+    assert_eq!(
+        public_input.get_public_memory_product_ratio(z, alpha, total_length),
+        Err(TaskError::InvalidPublicInput("Zero public memory product"))
+    );
+}
+
+#[test]
+fn test_fri_verify_rejects_invalid_layers() {
+    for n_layers in [Felt::ZERO, Felt::MAX] {
+        let mut stack = BidirectionalStackAccount::default();
+        stack.proof.config.fri.n_layers = n_layers;
+        stack.push_task(FriVerify::new());
+        assert_eq!(
+            stack.execute(),
+            Err(TaskError::InvalidConfig("Invalid number of FRI layers"))
+        );
+    }
+}
+
+#[test]
+fn test_execute_rejects_unknown_task() {
+    let mut stack = BidirectionalStackAccount::default();
+    assert_eq!(stack.execute(), Err(TaskError::StackUnderflow));
+
+    stack.push_back(&[0xff; 2]).unwrap();
+    assert_eq!(stack.execute(), Err(TaskError::StackUnderflow));
+    stack.pop_back();
+
+    stack.push_back(&[0xff; 8]).unwrap();
+    assert_eq!(stack.execute(), Err(TaskError::UnknownTypeTag(0xffffffff)));
+}
+
+#[test]
+fn test_execute_rejects_stack_overflow() {
+    let mut stack = BidirectionalStackAccount::default();
+    stack.push_task(Verify::new());
+    let free = stack.back_index - stack.front_index;
+    stack.push_front(&vec![0; free - 2 - 4]).unwrap();
+
+    // No room for the subtask pushed by Verify
+    assert_eq!(stack.execute(), Err(TaskError::StackOverflow));
+
+    // Nor for the second input of the hash
+    stack.pop_front();
+    stack.push_front(&vec![0; free - 2 - 34]).unwrap();
+    assert_eq!(
+        PoseidonHash::push_input(Felt::ONE, Felt::TWO, &mut stack),
+        Err(TaskError::StackOverflow)
+    );
+}
+
+#[tokio::test]
+async fn test_execute_instruction_returns_error_code() {
+    let account = Pubkey::new_unique();
+    let mut program_test = program_test(PROGRAM_ID);
+    program_test.add_account(
+        account,
+        stack_account(PROGRAM_ID, |stack| {
+            stack.header.state = VerificationState::Uploading;
+            stack.push_back(&[0xff; 8]).unwrap();
+        }),
+    );
+    let mut bank = TestBank::start(program_test).await;
+
+    assert_eq!(
+        bank.process(&[execute(&PROGRAM_ID, &account, 0)], &[])
+            .await,
+        Err(ProgramError::Custom(
+            TaskError::UnknownTypeTag(0xffffffff).code()
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_execute_instruction_records_rejected_proof() {
+    let account = Pubkey::new_unique();
+    let mut program_test = program_test(PROGRAM_ID);
+    program_test.add_account(
+        account,
+        stack_account(PROGRAM_ID, |stack| {
+            *stack = saya_stack();
+            stack.proof.public_input.log_n_steps = Felt::from(0x50);
+            stack.seal(DEFAULT_SECURITY_BITS);
+        }),
+    );
+    let mut bank = TestBank::start(program_test).await;
+
+    // The failing step succeeds and records the failure
+    let error = TaskError::InvalidPublicInput("log_n_steps exceeds maximum");
+    let mut steps: u32 = 0;
+    while !BidirectionalStackAccount::cast(&bank.data(&account).await)
+        .status
+        .is_failed()
+    {
+        let instruction = execute(&PROGRAM_ID, &account, steps);
+        assert_eq!(bank.process(&[instruction], &[]).await, Ok(()));
+        steps += 1;
+    }
+    let data = bank.data(&account).await;
+    let stack = BidirectionalStackAccount::cast(&data);
+    assert_eq!(stack.status.steps, u64::from(steps));
+    assert_eq!(stack.status.error_code, error.code());
+    assert!(stack.status.type_tag != 0);
+    assert_eq!(stack.header.state, VerificationState::Failed);
+    assert_eq!(stack.verified_hashes(), None);
+
+    // Further execution is refused
+    let instruction = execute(&PROGRAM_ID, &account, steps);
+    assert_eq!(
+        bank.process(&[instruction], &[]).await,
+        Err(ProgramError::Custom(error.code()))
+    );
+    assert_eq!(
+        BidirectionalStackAccount::cast(&bank.data(&account).await)
+            .status
+            .steps,
+        u64::from(steps)
+    );
+}
+
+#[test]
+fn test_execute_verification_step_records_check_values() {
+    let mut stack = saya_stack();
+    let oods_values = stack.proof.unsent_commitment.oods_values.as_slice_mut();
+    let last = oods_values.len() - 1;
+    oods_values[last] += Felt::ONE;
+    stack.push_task(Verify::new());
+    while !stack.status.is_failed() {
+        stack.execute_verification_step().unwrap();
+    }
+    assert_eq!(
+        stack.status.error_code,
+        TaskError::OodsMismatch {
+            claimed: Felt::ZERO,
+            computed: Felt::ZERO
+        }
+        .code()
+    );
+    let [claimed, computed] = stack.status.felts;
+    assert!(claimed != computed);
+
+    // Errors of the stack itself are returned without being recorded
+    let mut stack = BidirectionalStackAccount::default();
+    assert_eq!(
+        stack.execute_verification_step(),
+        Err(TaskError::StackUnderflow)
+    );
+    assert!(!stack.status.is_failed());
+}
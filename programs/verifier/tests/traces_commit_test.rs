@@ -28,11 +28,11 @@ fn test_traces_commit_with_reference_values() {
         Felt::from_hex("0x1b9182dce9dc1169fcd00c1f8c0b6acd6baad99ce578370ead5ca230b8fb8c6")
             .unwrap();
 
-    stack.push_task(TracesCommit::new(initial_transcript_digest));
+    stack.push_task(TracesCommit::new(initial_transcript_digest, 6));
 
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
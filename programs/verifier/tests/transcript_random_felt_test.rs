@@ -19,7 +19,7 @@ fn test_transcript_random_felt() {
 
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
@@ -15,13 +15,13 @@ fn test_transcript_read_felt() {
     let expected_digest = read_felt_from_prover_reference(digest, val);
     let expected_counter = Felt::ZERO;
 
-    TranscriptReadFelt::push_input(digest, val, &mut stack);
+    TranscriptReadFelt::push_input(digest, val, &mut stack).unwrap();
 
     stack.push_task(TranscriptReadFelt::new());
 
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
@@ -19,13 +19,13 @@ fn test_transcript_read_felt_vector() {
     let expected_digest = read_felt_vector_from_prover_reference(digest, &values);
     let expected_counter = Felt::ZERO;
 
-    TranscriptReadFeltVector::push_input(digest, &values, &mut stack);
+    TranscriptReadFeltVector::push_input(digest, &values, &mut stack).unwrap();
 
     stack.push_task(TranscriptReadFeltVector::new(values.len()));
 
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
 
@@ -0,0 +1,187 @@
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use solana_sdk::{signature::Keypair, signer::Signer};
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
+use utils::AccountCast;
+use verifier::error::UploadError;
+use verifier::instruction::{initialize, reset, seal};
+use verifier::state::{AccountHeader, BidirectionalStackAccount, VerificationState, PROOF_SIZE};
+
+mod program_test;
+use program_test::{new_stack_account, program_test, set_account_data_chunks, TestBank};
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+const PROOF_OFFSET: usize = std::mem::offset_of!(BidirectionalStackAccount, proof);
+
+struct Uploader {
+    bank: TestBank,
+    account: Keypair,
+    authority: Keypair,
+}
+
+impl Uploader {
+    async fn new() -> Self {
+        let account = Keypair::new();
+        let authority = Keypair::new();
+        let mut program_test = program_test(PROGRAM_ID);
+        program_test.add_account(account.pubkey(), new_stack_account(PROGRAM_ID));
+        let mut bank = TestBank::start(program_test).await;
+        let instruction = initialize(&PROGRAM_ID, &account.pubkey(), &authority.pubkey());
+        bank.process(&[instruction], &[&account, &authority])
+            .await
+            .unwrap();
+        Self {
+            bank,
+            account,
+            authority,
+        }
+    }
+
+    async fn process(&mut self, instructions: &[Instruction]) -> Result<(), ProgramError> {
+        self.bank.process(instructions, &[&self.authority]).await
+    }
+
+    // Writes the data in one transaction
+    async fn write(&mut self, offset: usize, data: Vec<u8>) -> Result<(), ProgramError> {
+        let instructions = set_account_data_chunks(
+            &PROGRAM_ID,
+            &self.account.pubkey(),
+            &self.authority.pubkey(),
+            offset,
+            &data,
+        );
+        self.process(&instructions).await
+    }
+
+    async fn seal(&mut self) -> Result<(), ProgramError> {
+        self.seal_with(DEFAULT_SECURITY_BITS).await
+    }
+
+    async fn seal_with(&mut self, min_security_bits: u64) -> Result<(), ProgramError> {
+        let instruction = seal(
+            &PROGRAM_ID,
+            &self.account.pubkey(),
+            &self.authority.pubkey(),
+            min_security_bits,
+        );
+        self.process(&[instruction]).await
+    }
+
+    async fn data(&mut self) -> Vec<u8> {
+        self.bank.data(&self.account.pubkey()).await
+    }
+
+    async fn header(&mut self) -> AccountHeader {
+        BidirectionalStackAccount::cast(&self.data().await).header
+    }
+}
+
+fn outside(offset: usize, len: usize) -> Result<(), ProgramError> {
+    Err(UploadError::OutsideProofRegion { offset, len }.into())
+}
+
+#[tokio::test]
+async fn test_set_account_data_rejects_writes_outside_proof() {
+    let mut uploader = Uploader::new().await;
+    let snapshot = uploader.data().await;
+
+    // The stack indices, the end of the account and offsets overflowing with the data
+    for (offset, len) in [
+        (0, 16),
+        (PROOF_OFFSET - 1, 2),
+        (PROOF_OFFSET + PROOF_SIZE - 1, 2),
+        (PROOF_OFFSET + PROOF_SIZE, 1),
+        (std::mem::offset_of!(BidirectionalStackAccount, header), 1),
+        (usize::MAX, 1),
+    ] {
+        assert_eq!(
+            uploader.write(offset, vec![0xff; len]).await,
+            outside(offset, len)
+        );
+    }
+    assert!(uploader.data().await == snapshot);
+
+    uploader.write(PROOF_OFFSET, vec![0xff; 2]).await.unwrap();
+    uploader
+        .write(PROOF_OFFSET + PROOF_SIZE - 2, vec![0xff; 2])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_seal_requires_complete_proof() {
+    let mut uploader = Uploader::new().await;
+    let incomplete = |missing| Err(UploadError::IncompleteProof { missing }.into());
+    assert_eq!(uploader.seal().await, incomplete(0));
+
+    // Overlapping chunks, out of order, leaving a gap of one byte
+    let middle = PROOF_SIZE / 2;
+    uploader
+        .write(PROOF_OFFSET + middle, vec![1; PROOF_SIZE - middle])
+        .await
+        .unwrap();
+    uploader.write(PROOF_OFFSET, vec![1; 100]).await.unwrap();
+    uploader
+        .write(PROOF_OFFSET + 50, vec![1; 50])
+        .await
+        .unwrap();
+    uploader
+        .write(PROOF_OFFSET + 101, vec![1; middle - 101])
+        .await
+        .unwrap();
+    assert_eq!(uploader.header().await.upload.first_missing(), Some(100));
+    assert_eq!(uploader.seal().await, incomplete(100));
+    assert_eq!(uploader.header().await.state, VerificationState::Uploading);
+
+    uploader.write(PROOF_OFFSET + 100, vec![1]).await.unwrap();
+    assert_eq!(uploader.header().await.upload.first_missing(), None);
+    uploader.seal().await.unwrap();
+    assert_eq!(uploader.header().await.state, VerificationState::Sealed);
+
+    // The proof has to be uploaded again after a reset, so no range of the previous upload
+    // counts towards the next one
+    let instruction = reset(
+        &PROGRAM_ID,
+        &uploader.account.pubkey(),
+        &uploader.authority.pubkey(),
+    );
+    uploader.process(&[instruction]).await.unwrap();
+    assert_eq!(uploader.header().await.upload.first_missing(), Some(0));
+    uploader
+        .write(PROOF_OFFSET, vec![2; PROOF_SIZE - 1])
+        .await
+        .unwrap();
+    assert_eq!(uploader.seal().await, incomplete(PROOF_SIZE - 1));
+    uploader
+        .write(PROOF_OFFSET + PROOF_SIZE - 1, vec![2])
+        .await
+        .unwrap();
+    uploader.seal().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_seal_requires_default_security() {
+    let mut uploader = Uploader::new().await;
+    uploader
+        .write(PROOF_OFFSET, vec![1; PROOF_SIZE])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        uploader.seal_with(DEFAULT_SECURITY_BITS - 1).await,
+        Err(UploadError::InsufficientSecurityBits {
+            min_security_bits: DEFAULT_SECURITY_BITS - 1
+        }
+        .into())
+    );
+    assert_eq!(uploader.header().await.state, VerificationState::Uploading);
+
+    uploader
+        .seal_with(DEFAULT_SECURITY_BITS + 20)
+        .await
+        .unwrap();
+    assert_eq!(uploader.header().await.state, VerificationState::Sealed);
+    assert_eq!(
+        uploader.header().await.min_security_bits,
+        DEFAULT_SECURITY_BITS + 20
+    );
+}
@@ -1,6 +1,9 @@
+use felt::Felt;
 use stark::stark_proof::validate_public_input::ValidatePublicInput;
+use stark::swiftness::air::layout::LayoutId;
+use swiftness_proof_parser::layout::Layout;
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
-use utils::{BidirectionalStack, Scheduler};
+use utils::{BidirectionalStack, Scheduler, TaskError};
 use verifier::state::BidirectionalStackAccount;
 
 #[test]
@@ -17,6 +20,48 @@ fn validate_public_input() {
 
     stack.push_task(ValidatePublicInput::new());
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
     }
 }
+
+#[test]
+fn validate_public_input_rejects_dynamic_layout() {
+    let mut stack = BidirectionalStackAccount::default();
+
+    let input = include_str!("../../../example_proof/saya.json");
+    let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
+    let proof = StarkProofParser::try_from(proof_json).unwrap();
+
+    stack.proof = proof.transform_to();
+    stack.proof.public_input.layout = LayoutId::Dynamic.code();
+    stack.proof.public_input.has_dynamic_params = 1;
+
+    stack.push_task(ValidatePublicInput::new());
+    assert_eq!(
+        stack.execute(),
+        Err(TaskError::InvalidPublicInput(
+            "Dynamic layout evaluation is not supported"
+        ))
+    );
+}
+
+#[test]
+fn layout_codes() {
+    for layout in LayoutId::ALL {
+        let name = match layout {
+            LayoutId::Dex => Layout::Dex,
+            LayoutId::Plain => Layout::Plain,
+            LayoutId::Recursive => Layout::Recursive,
+            LayoutId::RecursiveWithPoseidon => Layout::RecursiveWithPoseidon,
+            LayoutId::Small => Layout::Small,
+            LayoutId::Starknet => Layout::Starknet,
+            LayoutId::StarknetWithKeccak => Layout::StarknetWithKeccak,
+            LayoutId::Dynamic => Layout::Dynamic,
+        };
+        // The parser encodes the layout name as a big-endian felt
+        let code = Felt::from_bytes_be_slice(&name.bytes_encode());
+        assert_eq!(layout.code(), code);
+        assert_eq!(LayoutId::from_code(code), Some(layout));
+    }
+    assert_eq!(LayoutId::from_code(Felt::from(0x1234)), None);
+}
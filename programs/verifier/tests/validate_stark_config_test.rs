@@ -0,0 +1,210 @@
+use felt::Felt;
+use stark::funvec::{FunVec, FUNVEC_LAYERS, FUNVEC_OODS, FUNVEC_PAGES};
+use stark::stark_proof::validate_stark_config::{
+    security_bits, ValidateStarkConfig, DEFAULT_SECURITY_BITS,
+};
+use stark::stark_proof::verify::Verify;
+use stark::swiftness::stark::types::{cast_struct_to_slice, cast_struct_to_slice_mut};
+use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
+use utils::{BidirectionalStack, Scheduler, TaskError};
+use verifier::state::{BidirectionalStackAccount, VerificationState};
+
+fn load_saya_proof(stack: &mut BidirectionalStackAccount) {
+    let input = include_str!("../../../example_proof/saya.json");
+    let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
+    let proof = StarkProofParser::try_from(proof_json).unwrap();
+    stack.proof = proof.transform_to();
+}
+
+fn run_validate_stark_config(min_security_bits: u64) {
+    let mut stack = BidirectionalStackAccount::default();
+    load_saya_proof(&mut stack);
+
+    stack.push_task(ValidateStarkConfig::new(min_security_bits));
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+    }
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+#[test]
+fn test_validate_stark_config() {
+    run_validate_stark_config(DEFAULT_SECURITY_BITS);
+}
+
+#[test]
+fn test_validate_stark_config_exact_security_bits() {
+    // 16 queries * 4 log cosets + 32 proof of work bits
+    run_validate_stark_config(96);
+}
+
+// Result of validating the saya proof after applying `tamper` to it
+fn validate_tampered(
+    min_security_bits: u64,
+    tamper: impl FnOnce(&mut BidirectionalStackAccount),
+) -> Result<(), TaskError> {
+    let mut stack = BidirectionalStackAccount::default();
+    load_saya_proof(&mut stack);
+    tamper(&mut stack);
+
+    stack.push_task(ValidateStarkConfig::new(min_security_bits));
+    while !stack.is_empty_back() {
+        stack.execute()?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_validate_stark_config_rejects_insufficient_security() {
+    let insufficient = Err(TaskError::InvalidConfig("Insufficient security bits"));
+
+    // A single query: 1 * 4 + 32 bits
+    assert_eq!(
+        validate_tampered(DEFAULT_SECURITY_BITS, |stack| {
+            stack.proof.config.n_queries = Felt::ONE
+        }),
+        insufficient
+    );
+
+    // One bit below the minimum
+    assert_eq!(validate_tampered(97, |_| {}), insufficient);
+    assert_eq!(
+        validate_tampered(96, |stack| stack.proof.config.proof_of_work.n_bits -= 1),
+        insufficient
+    );
+}
+
+#[test]
+fn test_validate_stark_config_rejects_invalid_queries() {
+    let invalid_queries = Err(TaskError::InvalidConfig("Invalid number of queries"));
+    for n_queries in [Felt::ZERO, Felt::from(257), Felt::from(u64::MAX)] {
+        assert_eq!(
+            validate_tampered(DEFAULT_SECURITY_BITS, |stack| {
+                stack.proof.config.n_queries = n_queries
+            }),
+            invalid_queries
+        );
+    }
+
+    // n_queries * log_n_cosets overflows
+    let mut stack = BidirectionalStackAccount::default();
+    load_saya_proof(&mut stack);
+    stack.proof.config.n_queries = Felt::from(u64::MAX);
+    assert_eq!(
+        security_bits(&stack.proof.config),
+        Err(TaskError::InvalidConfig("Security bits out of range"))
+    );
+}
+
+#[test]
+fn test_validate_stark_config_rejects_proof_of_work_bits() {
+    assert_eq!(
+        validate_tampered(0, |stack| stack.proof.config.proof_of_work.n_bits = 29),
+        Err(TaskError::InvalidConfig("Proof of work bits below minimum"))
+    );
+    assert_eq!(
+        validate_tampered(0, |stack| stack.proof.config.proof_of_work.n_bits = 51),
+        Err(TaskError::InvalidConfig(
+            "Proof of work bits exceed maximum"
+        ))
+    );
+}
+
+#[test]
+fn test_validate_stark_config_rejects_domain_size_overflow() {
+    assert_eq!(
+        validate_tampered(DEFAULT_SECURITY_BITS, |stack| {
+            stack.proof.config.log_n_cosets = Felt::from(u64::MAX)
+        }),
+        Err(TaskError::InvalidConfig(
+            "Evaluation domain size out of range"
+        ))
+    );
+}
+
+#[test]
+fn test_verify_min_security_bits() {
+    let mut stack = BidirectionalStackAccount::default();
+    load_saya_proof(&mut stack);
+    stack.push_task(Verify::with_min_security_bits(97));
+    let error = loop {
+        if let Err(error) = stack.execute() {
+            break error;
+        }
+    };
+    assert_eq!(
+        error,
+        TaskError::InvalidConfig("Insufficient security bits")
+    );
+}
+
+// Writes the length of the FunVec in its bytes, as an uploader can. Its offset is found by
+// comparing an empty FunVec with a FunVec of one default element.
+fn set_len<T: Copy + Default, const N: usize>(funvec: &mut FunVec<T, N>, len: usize) {
+    let empty = FunVec::<T, N>::default();
+    let one = FunVec::<T, N>::from_vec(vec![T::default()]);
+    let offset = cast_struct_to_slice(&empty)
+        .iter()
+        .zip(cast_struct_to_slice(&one))
+        .position(|(a, b)| a != b)
+        .unwrap();
+    cast_struct_to_slice_mut(funvec)[offset..offset + size_of::<usize>()]
+        .copy_from_slice(&len.to_le_bytes());
+}
+
+#[test]
+fn test_validate_stark_config_rejects_oversized_lengths() {
+    assert_eq!(
+        validate_tampered(DEFAULT_SECURITY_BITS, |stack| {
+            set_len(&mut stack.proof.public_input.main_page.0, FUNVEC_PAGES + 1)
+        }),
+        Err(TaskError::InvalidPublicInput("Length exceeds capacity"))
+    );
+    assert_eq!(
+        validate_tampered(DEFAULT_SECURITY_BITS, |stack| {
+            set_len(&mut stack.proof.config.fri.fri_step_sizes, usize::MAX)
+        }),
+        Err(TaskError::InvalidConfig("Length exceeds capacity"))
+    );
+    assert_eq!(
+        validate_tampered(DEFAULT_SECURITY_BITS, |stack| {
+            set_len(
+                &mut stack.proof.unsent_commitment.oods_values,
+                FUNVEC_OODS + 1,
+            )
+        }),
+        Err(TaskError::InvalidWitness("Length exceeds capacity"))
+    );
+    // A layer witness past the number of layers of the witness
+    assert_eq!(
+        validate_tampered(DEFAULT_SECURITY_BITS, |stack| {
+            let layer = stack
+                .proof
+                .witness
+                .fri_witness
+                .layers
+                .at_mut(FUNVEC_LAYERS - 1);
+            set_len(&mut layer.table_witness.vector.authentications, usize::MAX)
+        }),
+        Err(TaskError::InvalidWitness("Length exceeds capacity"))
+    );
+}
+
+#[test]
+fn test_verify_rejects_oversized_lengths() {
+    let mut stack = BidirectionalStackAccount::default();
+    load_saya_proof(&mut stack);
+    set_len(
+        &mut stack.proof.unsent_commitment.oods_values,
+        FUNVEC_OODS + 1,
+    );
+    stack.seal(DEFAULT_SECURITY_BITS);
+    stack.execute_verification_step().unwrap();
+    while stack.header.state == VerificationState::Verifying {
+        stack.execute_verification_step().unwrap();
+    }
+    assert_eq!(stack.header.state, VerificationState::Failed);
+}
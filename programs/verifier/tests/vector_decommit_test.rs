@@ -0,0 +1,124 @@
+use blake2::Blake2s256;
+use felt::Felt;
+use sha3::{Digest, Keccak256};
+use stark::funvec::FunVec;
+use stark::stark_proof::stark_verify::{TableDecommitTarget, VectorCommitmentDecommit};
+use stark::swiftness::commitment::vector::types::Query;
+use stark::swiftness::stark::config::Hasher;
+use starknet_crypto::poseidon_hash;
+use starknet_crypto::Felt as StarknetFelt;
+use utils::{BidirectionalStack, Scheduler};
+use verifier::state::BidirectionalStackAccount;
+
+const HEIGHT: usize = 6;
+const N_VERIFIER_FRIENDLY_LAYERS: usize = 3;
+
+#[test]
+fn test_vector_commitment_decommit_mixed_hashers() {
+    run_vector_commitment_decommit(Hasher::Keccak256);
+}
+
+#[test]
+fn test_vector_commitment_decommit_blake2s() {
+    run_vector_commitment_decommit(Hasher::Blake2s256);
+}
+
+fn run_vector_commitment_decommit(hasher: Hasher) {
+    let mut stack = BidirectionalStackAccount::default();
+
+    // Full tree: tree[depth][i], the leaves at depth HEIGHT
+    let mut tree = vec![vec![]; HEIGHT + 1];
+    tree[HEIGHT] = (0..1u64 << HEIGHT)
+        .map(|i| Felt::from(i * 0x1234567 + 0x89abcdef))
+        .collect();
+    for depth in (1..=HEIGHT).rev() {
+        tree[depth - 1] = tree[depth]
+            .chunks(2)
+            .map(|pair| hash_reference(pair[0], pair[1], depth, hasher))
+            .collect();
+    }
+    let root = tree[0][0];
+
+    let rows = [0u64, 1, 5, 17, 18, 40, 63];
+    let authentications = authentications_reference(&tree, &rows);
+
+    stack.proof.verifier_config.hasher = hasher as u8;
+    let vector_config = &mut stack.proof.config.traces.original.vector;
+    vector_config.height = Felt::from(HEIGHT);
+    vector_config.n_verifier_friendly_commitment_layers = Felt::from(N_VERIFIER_FRIENDLY_LAYERS);
+    stack
+        .proof
+        .witness
+        .traces_witness
+        .original
+        .vector
+        .authentications = FunVec::from_vec(authentications);
+    stack
+        .stark_commitment
+        .traces
+        .original
+        .vector_commitment
+        .commitment_hash = root;
+
+    stack.queries.nodes.flush();
+    for row in rows {
+        stack.queries.nodes.push(Query {
+            index: Felt::from(row + (1 << HEIGHT)),
+            value: tree[HEIGHT][row as usize],
+        });
+    }
+
+    stack.push_task(VectorCommitmentDecommit::new(
+        TableDecommitTarget::TracesOriginal,
+    ));
+
+    let mut steps = 0;
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+        steps += 1;
+    }
+
+    println!("VectorCommitmentDecommit completed in {} steps", steps);
+
+    assert_eq!(stack.queries.nodes.len(), 1);
+    assert_eq!(stack.queries.nodes.at(0).value, root);
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+// Reference hash of two children at the given depth
+fn hash_reference(x: Felt, y: Felt, depth: usize, hasher: Hasher) -> Felt {
+    if N_VERIFIER_FRIENDLY_LAYERS >= depth {
+        let hash = poseidon_hash(
+            StarknetFelt::from_bytes_be(&x.to_bytes_be()),
+            StarknetFelt::from_bytes_be(&y.to_bytes_be()),
+        );
+        Felt::from_bytes_be(&hash.to_bytes_be())
+    } else {
+        let bytes = [x.to_bytes_be(), y.to_bytes_be()].concat();
+        let digest = match hasher {
+            Hasher::Keccak256 => Keccak256::digest(&bytes).to_vec(),
+            Hasher::Blake2s256 => Blake2s256::digest(&bytes).to_vec(),
+        };
+        Felt::from_bytes_be_slice(&digest[12..])
+    }
+}
+
+// Siblings of the queried nodes that are not themselves known, bottom layer up, left to right
+fn authentications_reference(tree: &[Vec<Felt>], rows: &[u64]) -> Vec<Felt> {
+    let mut authentications = vec![];
+    let mut known: Vec<u64> = rows.to_vec();
+    for depth in (1..=HEIGHT).rev() {
+        for &index in &known {
+            let sibling = index ^ 1;
+            if !known.contains(&sibling) {
+                authentications.push(tree[depth][sibling as usize]);
+            }
+        }
+        known = known.iter().map(|index| index / 2).collect();
+        known.dedup();
+    }
+    authentications
+}
@@ -1,7 +1,16 @@
+use felt::Felt;
+use stark::funvec::FunVec;
+use stark::stark_proof::segments;
+use stark::stark_proof::stark_commit::StarkCommit;
+use stark::stark_proof::stark_verify::StarkVerify;
+use stark::stark_proof::validate_stark_config::DEFAULT_SECURITY_BITS;
 use stark::stark_proof::verify::Verify;
+use stark::swiftness::air::types::{ContinuousPageHeader, Page};
+use starknet_crypto::Felt as StarknetFelt;
+use starknet_crypto::{pedersen_hash, poseidon_hash_many};
 use swiftness_proof_parser::{json_parser, transform::TransformTo, StarkProof as StarkProofParser};
-use utils::{BidirectionalStack, Scheduler};
-use verifier::state::BidirectionalStackAccount;
+use utils::{BidirectionalStack, Executable, Scheduler};
+use verifier::state::{BidirectionalStackAccount, VerificationState, VerifiedHashes};
 
 #[test]
 fn verify() {
@@ -17,6 +26,181 @@ fn verify() {
 
     stack.push_task(Verify::new());
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
     }
+
+    let (expected_program_hash, expected_output_hash) = public_input_hashes_reference(&stack);
+
+    let program_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    let output_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    let fact_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    let verification_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+
+    assert_eq!(program_hash, expected_program_hash);
+    assert_eq!(output_hash, expected_output_hash);
+    assert_eq!(
+        fact_hash,
+        poseidon_reference(&[expected_program_hash, expected_output_hash])
+    );
+
+    // recursive_with_poseidon, keccak_160_lsb, stone6, strict, as named in Integrity
+    let verifier_config_hash = poseidon_reference(&[
+        Felt::from_hex_unchecked("0x7265637572736976655f776974685f706f736569646f6e"),
+        Felt::from_hex_unchecked("0x6b656363616b5f3136305f6c7362"),
+        Felt::from_hex_unchecked("0x73746f6e6536"),
+        Felt::from_hex_unchecked("0x737472696374"),
+    ]);
+    let config = &stack.proof.config;
+    let security_bits = Felt::from(
+        u64::try_from(config.n_queries).unwrap() * u64::try_from(config.log_n_cosets).unwrap()
+            + config.proof_of_work.n_bits as u64,
+    );
+    assert_eq!(
+        verification_hash,
+        poseidon_reference(&[fact_hash, verifier_config_hash, security_bits])
+    );
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+// The example proof has no continuous pages. Its STARK part is verified as is, then the end of its
+// output is moved from the main page to a continuous page before the public input is hashed. The
+// proof must be verified with the program and output hashes of the original public input, and
+// without Integrity hashes, since Integrity does not verify proofs with continuous pages.
+#[test]
+fn verify_continuous_pages() {
+    let input = include_str!("../../../example_proof/saya.json");
+    let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
+    let proof = StarkProofParser::try_from(proof_json).unwrap();
+
+    let mut stack = BidirectionalStackAccount {
+        proof: proof.transform_to(),
+        ..Default::default()
+    };
+    stack.seal(DEFAULT_SECURITY_BITS);
+    stack.simulate_until(StarkVerify::TYPE_TAG).unwrap();
+    let (program_hash, output_hash) = public_input_hashes_reference(&stack);
+
+    let public_input = &mut stack.proof.public_input;
+    let output_end = public_input.segments.as_slice()[segments::OUTPUT].stop_ptr;
+    let main_page = public_input.main_page.0.to_vec();
+    let (main_page, page) = main_page.split_at(main_page.len() - 2);
+    let page_values: Vec<Felt> = page.iter().map(|cell| cell.value).collect();
+    public_input.main_page = Page(FunVec::from_vec(main_page.to_vec()));
+    public_input.continuous_page_headers = FunVec::from_vec(vec![ContinuousPageHeader {
+        start_address: output_end - Felt::TWO,
+        size: Felt::TWO,
+        hash: hash_on_elements_reference(&page_values),
+        prod: Felt::ZERO,
+    }]);
+    public_input.continuous_page_values = FunVec::from_vec(page_values);
+
+    while matches!(
+        stack.header.state,
+        VerificationState::Sealed | VerificationState::Verifying
+    ) {
+        stack.execute_verification_step().unwrap();
+    }
+    assert_eq!(stack.header.state, VerificationState::Verified);
+    assert_eq!(
+        stack.verified_hashes(),
+        Some(VerifiedHashes {
+            program_hash,
+            output_hash,
+            fact_hash: Felt::ZERO,
+            verification_hash: Felt::ZERO,
+        })
+    );
+}
+
+fn hash_on_elements_reference(values: &[Felt]) -> Felt {
+    let to_starknet = |value: Felt| StarknetFelt::from_bytes_be(&value.to_bytes_be());
+    let hash = values.iter().fold(StarknetFelt::ZERO, |hash, value| {
+        pedersen_hash(&hash, &to_starknet(*value))
+    });
+    let hash = pedersen_hash(&hash, &StarknetFelt::from(values.len() as u64));
+    Felt::from_bytes_be(&hash.to_bytes_be())
+}
+
+fn poseidon_reference(values: &[Felt]) -> Felt {
+    let values: Vec<StarknetFelt> = values
+        .iter()
+        .map(|value| StarknetFelt::from_bytes_be(&value.to_bytes_be()))
+        .collect();
+    Felt::from_bytes_be(&poseidon_hash_many(&values).to_bytes_be())
+}
+
+// Poseidon hashes of the program and of the output, read from the main page of the public input
+fn public_input_hashes_reference(stack: &BidirectionalStackAccount) -> (Felt, Felt) {
+    let public_input = &stack.proof.public_input;
+    let segments = public_input.segments.as_slice();
+    let main_page = public_input.main_page.0.as_slice();
+    let address = |value: Felt| -> usize { value.try_into().unwrap() };
+
+    let initial_pc = address(segments[segments::PROGRAM].begin_addr);
+    let initial_fp = address(segments[segments::EXECUTION].begin_addr);
+    let program_len = initial_fp - 2 - initial_pc;
+    let output_len = address(segments[segments::OUTPUT].stop_ptr)
+        - address(segments[segments::OUTPUT].begin_addr);
+
+    let values: Vec<StarknetFelt> = main_page
+        .iter()
+        .map(|cell| StarknetFelt::from_bytes_be(&cell.value.to_bytes_be()))
+        .collect();
+    let hash =
+        |values: &[StarknetFelt]| Felt::from_bytes_be(&poseidon_hash_many(values).to_bytes_be());
+
+    (
+        hash(&values[..program_len]),
+        hash(&values[values.len() - output_len..]),
+    )
+}
+
+#[test]
+fn verify_until_stark_commit() {
+    let input = include_str!("../../../example_proof/saya.json");
+    let proof_json = serde_json::from_str::<json_parser::StarkProof>(input).unwrap();
+    let proof = StarkProofParser::try_from(proof_json).unwrap();
+
+    let mut stack = BidirectionalStackAccount {
+        proof: proof.transform_to(),
+        ..Default::default()
+    };
+    stack.seal(DEFAULT_SECURITY_BITS);
+    let steps = stack.simulate_until(StarkCommit::TYPE_TAG).unwrap();
+
+    // The commitments of the proof, read back by StarkCommit
+    let stark_commitment = &stack.stark_commitment;
+    assert_eq!(
+        stark_commitment
+            .traces
+            .original
+            .vector_commitment
+            .commitment_hash,
+        Felt::from_hex_unchecked(
+            "0x305f1ee7c0b38a403b2fa7ec86a3d11c8a174891194a2c656147268b59e876d"
+        )
+    );
+    assert_eq!(
+        stark_commitment.interaction_after_composition,
+        Felt::from_hex_unchecked(
+            "0x49185430497be4bd990699e70b3b91b25c0dd22d5cd436dbf23f364136368bc"
+        )
+    );
+    assert_eq!(stack.next_type_tag(), Some(Verify::TYPE_TAG));
+
+    // The rest of the verification runs from there
+    let remaining_steps = stack.simulate().unwrap();
+    let mut sealed = BidirectionalStackAccount {
+        proof: stack.proof,
+        ..Default::default()
+    };
+    sealed.seal(DEFAULT_SECURITY_BITS);
+    assert_eq!(sealed.simulate().unwrap(), steps + remaining_steps);
 }
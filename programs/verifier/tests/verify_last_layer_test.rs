@@ -0,0 +1,110 @@
+use felt::Felt;
+use stark::funvec::FunVec;
+use stark::stark_proof::stark_verify::VerifyLastLayer;
+use stark::swiftness::fri::formula::horner_eval;
+use stark::swiftness::fri::types::FriLayerQuery;
+use utils::{BidirectionalStack, Scheduler, TaskError};
+use verifier::state::BidirectionalStackAccount;
+
+const LOG_LAST_LAYER_DEGREE_BOUND: u32 = 3;
+
+#[test]
+fn test_horner_eval() {
+    // 5 + 3x + 2x^3
+    let coefficients = [Felt::from(5), Felt::from(3), Felt::ZERO, Felt::from(2)];
+    assert_eq!(horner_eval(&coefficients, Felt::ZERO), Felt::from(5));
+    assert_eq!(horner_eval(&coefficients, Felt::from(2)), Felt::from(27));
+    assert_eq!(horner_eval(&coefficients, -Felt::ONE), Felt::ZERO);
+    assert_eq!(horner_eval(&[], Felt::from(7)), Felt::ZERO);
+}
+
+#[test]
+fn test_verify_last_layer() {
+    let mut stack = last_layer_stack(polynomial_reference);
+    stack.push_task(VerifyLastLayer::new());
+
+    let mut steps = 0;
+    while !stack.is_empty_back() {
+        stack.execute().unwrap();
+        steps += 1;
+    }
+
+    println!("VerifyLastLayer completed in {} steps", steps);
+
+    // Check that stack is empty
+    assert_eq!(stack.front_index, 0, "Stack should be empty");
+    assert_eq!(stack.back_index, 65536, "Stack should be empty");
+}
+
+#[test]
+fn test_verify_last_layer_rejects_mismatch() {
+    // A value off the polynomial
+    let mut stack = last_layer_stack(polynomial_reference);
+    let query = stack.queries.fri_queries.at_mut(2);
+    query.y_value += Felt::ONE;
+    let (index, value) = (query.index, query.y_value);
+    stack.push_task(VerifyLastLayer::new());
+    assert_eq!(
+        stack.execute(),
+        Err(TaskError::LastLayerMismatch { index, value })
+    );
+
+    // Values of the polynomial at the inverse points
+    let mut stack = last_layer_stack(|coefficients, x| {
+        polynomial_reference(coefficients, x.inverse().unwrap())
+    });
+    stack.push_task(VerifyLastLayer::new());
+    assert!(matches!(
+        stack.execute(),
+        Err(TaskError::LastLayerMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_verify_last_layer_rejects_coefficients_length() {
+    let mut stack = last_layer_stack(polynomial_reference);
+    let coefficients = stack.stark_commitment.fri.last_layer_coefficients.to_vec();
+    stack
+        .stark_commitment
+        .fri
+        .last_layer_coefficients
+        .overwrite(&coefficients[..coefficients.len() - 1]);
+    stack.push_task(VerifyLastLayer::new());
+    assert_eq!(
+        stack.execute(),
+        Err(TaskError::InvalidWitness(
+            "Invalid last layer coefficients length"
+        ))
+    );
+}
+
+// Account holding the last layer polynomial and queries whose values are y(coefficients, x)
+fn last_layer_stack(y: impl Fn(&[Felt], Felt) -> Felt) -> BidirectionalStackAccount {
+    let mut stack = BidirectionalStackAccount::default();
+
+    let coefficients: Vec<Felt> = (0..1u64 << LOG_LAST_LAYER_DEGREE_BOUND)
+        .map(|i| Felt::from(i * 0x1f2e3d + 0x4c5b6a))
+        .collect();
+    stack.proof.config.fri.log_last_layer_degree_bound = Felt::from(LOG_LAST_LAYER_DEGREE_BOUND);
+    stack.stark_commitment.fri.last_layer_coefficients = FunVec::from_vec(coefficients.clone());
+
+    stack.queries.fri_queries.flush();
+    for index in [2u64, 9, 14, 30] {
+        let x = Felt::from(index * 0x10203 + 1);
+        stack.queries.fri_queries.push(FriLayerQuery {
+            index: Felt::from(index),
+            y_value: y(&coefficients, x),
+            x_inv_value: x.inverse().unwrap(),
+        });
+    }
+    stack
+}
+
+// Sum of coefficients[i] * x^i, term by term
+fn polynomial_reference(coefficients: &[Felt], x: Felt) -> Felt {
+    coefficients
+        .iter()
+        .enumerate()
+        .map(|(i, coefficient)| *coefficient * x.pow(i as u128))
+        .fold(Felt::ZERO, |acc, term| acc + term)
+}
@@ -18,7 +18,8 @@ fn test_verify_oods_with_reference_values() {
     let stark_domains = stark_domains::get();
     let constraint_coefficients = constraint_coefficients::get();
 
-    stack.constraint_coefficients = constraint_coefficients.as_slice().try_into().unwrap();
+    stack.constraint_coefficients[..constraint_coefficients.len()]
+        .copy_from_slice(constraint_coefficients.as_slice());
 
     // Create a StarkProof with reference oods_values
     let mut proof = StarkProof::default();
@@ -26,7 +27,7 @@ fn test_verify_oods_with_reference_values() {
     let oods_values = oods_values::get();
 
     proof.unsent_commitment.oods_values = oods_values;
-    stack.oods_values = oods_values.as_slice().try_into().unwrap();
+    stack.oods_values[..oods_values.len()].copy_from_slice(oods_values.as_slice());
 
     proof.unsent_commitment.fri = unsent_commitment;
     proof.config = config;
@@ -91,7 +92,7 @@ fn test_verify_oods_with_reference_values() {
     // Execute tasks until completion
     let mut steps = 0;
     while !stack.is_empty_back() {
-        stack.execute();
+        stack.execute().unwrap();
         steps += 1;
     }
     assert_eq!(stack.front_index, 0, "Stack should be empty");
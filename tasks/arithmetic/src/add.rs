@@ -1,4 +1,4 @@
-use utils::{impl_type_identifiable, BidirectionalStack};
+use utils::{impl_type_identifiable, BidirectionalStack, TaskError};
 use utils::{Executable, TypeIdentifiable};
 
 #[repr(C)]
@@ -20,13 +20,15 @@ impl Add {
 }
 
 impl Executable for Add {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
         let result = self.compute();
 
         // Convert result to bytes and push to stack
-        stack.push_front(&result.to_be_bytes()).unwrap();
+        stack
+            .push_front(&result.to_be_bytes())
+            .map_err(|_| TaskError::StackOverflow)?;
 
-        Vec::new()
+        Ok(Vec::new())
     }
 
     fn is_finished(&mut self) -> bool {
@@ -1,5 +1,5 @@
 use crate::mul::Mul;
-use utils::{impl_type_identifiable, BidirectionalStack};
+use utils::{impl_type_identifiable, BidirectionalStack, TaskError};
 use utils::{Executable, TypeIdentifiable};
 
 #[repr(C)]
@@ -38,9 +38,14 @@ impl ExpInternal {
 }
 
 impl Executable for ExpInternal {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
         // Get the result of the previous multiplication
-        let mul_result = u128::from_be_bytes(stack.borrow_front().try_into().unwrap());
+        let mul_result = u128::from_be_bytes(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
 
         // Update internal state
         self.counter += 1;
@@ -51,11 +56,13 @@ impl Executable for ExpInternal {
 
         if self.counter < self.exponent {
             // Continue multiplying by creating another Mul task
-            vec![Mul::new(self.result, self.base).to_vec_with_type_tag()]
+            Ok(vec![Mul::new(self.result, self.base).to_vec_with_type_tag()])
         } else {
             // We're done, push the final result
-            stack.push_front(&self.result.to_be_bytes()).unwrap();
-            Vec::new()
+            stack
+                .push_front(&self.result.to_be_bytes())
+                .map_err(|_| TaskError::StackOverflow)?;
+            Ok(Vec::new())
         }
     }
 
@@ -65,17 +72,19 @@ impl Executable for ExpInternal {
 }
 
 impl Executable for Exp {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
         if self.exponent == 0 {
             // Special case: any number raised to 0 is 1
-            stack.push_front(&1u128.to_be_bytes()).unwrap();
-            Vec::new()
+            stack
+                .push_front(&1u128.to_be_bytes())
+                .map_err(|_| TaskError::StackOverflow)?;
+            Ok(Vec::new())
         } else {
             // Create tasks for first multiplication and tracking exponentiation progress
-            vec![
+            Ok(vec![
                 Mul::new(1, self.base).to_vec_with_type_tag(),
                 ExpInternal::new(self.base, self.exponent, self.base, 0).to_vec_with_type_tag(),
-            ]
+            ])
         }
     }
 
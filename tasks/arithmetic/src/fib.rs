@@ -1,4 +1,4 @@
-use utils::{impl_type_identifiable, BidirectionalStack};
+use utils::{impl_type_identifiable, BidirectionalStack, TaskError};
 use utils::{Executable, TypeIdentifiable};
 
 #[repr(C)]
@@ -28,25 +28,29 @@ impl FibonacciCombiner {
 }
 
 impl Executable for Fibonacci {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.n {
             0 => {
                 // Base case: F(0) = 0
-                stack.push_front(&0u128.to_be_bytes()).unwrap();
-                Vec::new()
+                stack
+                    .push_front(&0u128.to_be_bytes())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                Ok(Vec::new())
             }
             1 => {
                 // Base case: F(1) = 1
-                stack.push_front(&1u128.to_be_bytes()).unwrap();
-                Vec::new()
+                stack
+                    .push_front(&1u128.to_be_bytes())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                Ok(Vec::new())
             }
             n => {
                 // Recursive case: F(n) = F(n-1) + F(n-2)
-                vec![
+                Ok(vec![
                     Fibonacci::new(n - 1).to_vec_with_type_tag(),
                     Fibonacci::new(n - 2).to_vec_with_type_tag(),
                     FibonacciCombiner::new(n).to_vec_with_type_tag(),
-                ]
+                ])
             }
         }
     }
@@ -57,21 +61,33 @@ impl Executable for Fibonacci {
 }
 
 impl Executable for FibonacciCombiner {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
         // Pop F(n-2) and F(n-1) from the stack
-        let fib_n_2 = u128::from_be_bytes(stack.borrow_front().try_into().unwrap());
+        let fib_n_2 = u128::from_be_bytes(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
         stack.pop_front();
 
-        let fib_n_1 = u128::from_be_bytes(stack.borrow_front().try_into().unwrap());
+        let fib_n_1 = u128::from_be_bytes(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
         stack.pop_front();
 
         // Compute F(n) = F(n-1) + F(n-2)
         let result = fib_n_1.saturating_add(fib_n_2);
 
         // Push the result back to the stack
-        stack.push_front(&result.to_be_bytes()).unwrap();
+        stack
+            .push_front(&result.to_be_bytes())
+            .map_err(|_| TaskError::StackOverflow)?;
 
-        Vec::new()
+        Ok(Vec::new())
     }
 
     fn is_finished(&mut self) -> bool {
@@ -1,4 +1,4 @@
-use utils::{impl_type_identifiable, BidirectionalStack};
+use utils::{impl_type_identifiable, BidirectionalStack, TaskError};
 use utils::{Executable, TypeIdentifiable};
 
 #[repr(C)]
@@ -19,12 +19,14 @@ impl Default for Increment {
 }
 
 impl Executable for Increment {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
         let result = stack.borrow_front();
-        let result = u128::from_be_bytes(result.try_into().unwrap());
+        let result = u128::from_be_bytes(result.try_into().map_err(|_| TaskError::StackUnderflow)?);
         let result = result.saturating_add(1);
-        stack.push_front(&result.to_be_bytes()).unwrap();
-        Vec::new()
+        stack
+            .push_front(&result.to_be_bytes())
+            .map_err(|_| TaskError::StackOverflow)?;
+        Ok(Vec::new())
     }
 
     fn is_finished(&mut self) -> bool {
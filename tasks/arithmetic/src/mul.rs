@@ -1,5 +1,5 @@
 use crate::add::Add;
-use utils::{impl_type_identifiable, BidirectionalStack};
+use utils::{impl_type_identifiable, BidirectionalStack, TaskError};
 use utils::{Executable, TypeIdentifiable};
 
 #[repr(C)]
@@ -38,9 +38,14 @@ impl MulInternal {
 }
 
 impl Executable for MulInternal {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
         // Get the result of the previous addition
-        let add_result = u128::from_be_bytes(stack.borrow_front().try_into().unwrap());
+        let add_result = u128::from_be_bytes(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
 
         // Update internal state
         self.counter += 1;
@@ -51,11 +56,13 @@ impl Executable for MulInternal {
 
         if self.counter < self.y {
             // Continue adding by creating another Add task
-            vec![Add::new(self.result, self.x).to_vec_with_type_tag()]
+            Ok(vec![Add::new(self.result, self.x).to_vec_with_type_tag()])
         } else {
             // We're done, push the final result
-            stack.push_front(&self.result.to_be_bytes()).unwrap();
-            Vec::new()
+            stack
+                .push_front(&self.result.to_be_bytes())
+                .map_err(|_| TaskError::StackOverflow)?;
+            Ok(Vec::new())
         }
     }
 
@@ -65,17 +72,19 @@ impl Executable for MulInternal {
 }
 
 impl Executable for Mul {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
         if self.y == 0 {
             // Shortcut for multiplication by zero
-            stack.push_front(&0u128.to_be_bytes()).unwrap();
-            Vec::new()
+            stack
+                .push_front(&0u128.to_be_bytes())
+                .map_err(|_| TaskError::StackOverflow)?;
+            Ok(Vec::new())
         } else {
             // Create tasks for initial addition and tracking multiplication progress
-            vec![
+            Ok(vec![
                 Add::new(0, self.x).to_vec_with_type_tag(),
                 MulInternal::new(self.x, self.y, 0, 0).to_vec_with_type_tag(),
-            ]
+            ])
         }
     }
 
@@ -6,14 +6,18 @@ use solana_program::log::sol_log_64;
 use felt::Felt;
 
 pub const FUNVEC_LAYERS: usize = 10;
-pub const FUNVEC_OODS: usize = 256;
+pub const FUNVEC_OODS: usize = 512;
 pub const FUNVEC_LEAVES: usize = 512;
 pub const FUNVEC_AUTHENTICATIONS: usize = 512;
 pub const FUNVEC_LAST_LAYER: usize = 256;
-pub const FUNVEC_DECOMMITMENT_VALUES: usize = 256;
+pub const FUNVEC_DECOMMITMENT_VALUES: usize = 512;
 pub const FUNVEC_PAGES: usize = 1024;
 pub const FUNVEC_SEGMENTS: usize = 12;
+pub const FUNVEC_CONTINUOUS_PAGES: usize = 32;
+pub const FUNVEC_CONTINUOUS_PAGE_VALUES: usize = 1024;
 pub const FUNVEC_QUERIES: usize = 256;
+// Each element of a FRI coset is either a query or a witness leaf.
+pub const FUNVEC_LAYER_VALUES: usize = FUNVEC_QUERIES + FUNVEC_LEAVES;
 pub const FUNVEC_COLUMN_VALUES: usize = 15;
 pub fn print_address<T>(address: &T, label: u64) {
     sol_log_64(
@@ -131,6 +135,12 @@ impl<T: Copy + Default, const N: usize> FunVec<T, N> {
         N
     }
 
+    // Whether the length fits the capacity, which the other methods assume. It does not hold for
+    // a FunVec read from bytes written by someone else until checked.
+    pub fn is_within_capacity(&self) -> bool {
+        self.len <= N
+    }
+
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
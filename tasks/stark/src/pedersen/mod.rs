@@ -3,7 +3,9 @@ use felt::Felt;
 use lambdaworks_math::elliptic_curve::short_weierstrass::{
     curves::stark_curve::StarkCurve, point::ShortWeierstrassProjectivePoint,
 };
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, ProofData, TypeIdentifiable};
+use utils::{
+    impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError, TypeIdentifiable,
+};
 
 pub mod constants;
 
@@ -44,9 +46,17 @@ impl PedersenHash {
         }
     }
 
-    pub fn push_input<T: BidirectionalStack>(x: Felt, y: Felt, stack: &mut T) {
-        stack.push_front(&x.to_bytes_be()).unwrap();
-        stack.push_front(&y.to_bytes_be()).unwrap();
+    pub fn push_input<T: BidirectionalStack>(
+        x: Felt,
+        y: Felt,
+        stack: &mut T,
+    ) -> Result<(), TaskError> {
+        stack
+            .push_front(&x.to_bytes_be())
+            .map_err(|_| TaskError::StackOverflow)?;
+        stack
+            .push_front(&y.to_bytes_be())
+            .map_err(|_| TaskError::StackOverflow)
     }
 }
 
@@ -62,43 +72,82 @@ fn bools_to_usize_le(bools: &[bool]) -> usize {
 }
 
 impl Executable for PedersenHash {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.phase {
             PerdersenPhase::LookupP1 => {
-                let y = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+                let y = Felt::from_bytes_be(
+                    stack
+                        .borrow_front()
+                        .try_into()
+                        .map_err(|_| TaskError::StackUnderflow)?,
+                );
                 stack.pop_front();
-                let x = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+                let x = Felt::from_bytes_be(
+                    stack
+                        .borrow_front()
+                        .try_into()
+                        .map_err(|_| TaskError::StackUnderflow)?,
+                );
                 stack.pop_front();
                 let x = x.to_bits_le();
                 let y = y.to_bits_le();
                 self.x = x;
                 self.y = y;
 
-                stack.push_front(&self.acc.x().to_bytes_be()).unwrap();
-                stack.push_front(&self.acc.y().to_bytes_be()).unwrap();
-                stack.push_front(&self.acc.z().to_bytes_be()).unwrap();
+                stack
+                    .push_front(&self.acc.x().to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&self.acc.y().to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&self.acc.z().to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.phase = PerdersenPhase::LookupP2;
-                vec![LookupAndAccumulate::new(&self.x[..248], 1).to_vec_with_type_tag()]
+                Ok(vec![
+                    LookupAndAccumulate::new(&self.x[..248], 1).to_vec_with_type_tag()
+                ])
             }
             PerdersenPhase::LookupP2 => {
                 self.phase = PerdersenPhase::LookupP3;
-                vec![LookupAndAccumulate::new(&self.x[248..252], 2).to_vec_with_type_tag()]
+                Ok(vec![
+                    LookupAndAccumulate::new(&self.x[248..252], 2).to_vec_with_type_tag()
+                ])
             }
             PerdersenPhase::LookupP3 => {
                 self.phase = PerdersenPhase::LookupP4;
-                vec![LookupAndAccumulate::new(&self.y[..248], 3).to_vec_with_type_tag()]
+                Ok(vec![
+                    LookupAndAccumulate::new(&self.y[..248], 3).to_vec_with_type_tag()
+                ])
             }
             PerdersenPhase::LookupP4 => {
                 self.phase = PerdersenPhase::Results;
-                vec![LookupAndAccumulate::new(&self.y[248..252], 4).to_vec_with_type_tag()]
+                Ok(vec![
+                    LookupAndAccumulate::new(&self.y[248..252], 4).to_vec_with_type_tag()
+                ])
             }
             PerdersenPhase::Results => {
-                let z = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+                let z = Felt::from_bytes_be(
+                    stack
+                        .borrow_front()
+                        .try_into()
+                        .map_err(|_| TaskError::StackUnderflow)?,
+                );
                 stack.pop_front();
-                let y = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+                let y = Felt::from_bytes_be(
+                    stack
+                        .borrow_front()
+                        .try_into()
+                        .map_err(|_| TaskError::StackUnderflow)?,
+                );
                 stack.pop_front();
-                let x = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+                let x = Felt::from_bytes_be(
+                    stack
+                        .borrow_front()
+                        .try_into()
+                        .map_err(|_| TaskError::StackUnderflow)?,
+                );
                 stack.pop_front();
 
                 self.acc = ShortWeierstrassProjectivePoint::<StarkCurve>::new([
@@ -109,14 +158,14 @@ impl Executable for PedersenHash {
                 .unwrap();
 
                 let result = *self.acc.to_affine().x();
-                stack.push_front(&result.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&result.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.phase = PerdersenPhase::Finished;
-                vec![]
-            }
-            PerdersenPhase::Finished => {
-                vec![]
+                Ok(vec![])
             }
+            PerdersenPhase::Finished => Ok(vec![]),
         }
     }
 
@@ -162,14 +211,32 @@ impl LookupAndAccumulate {
 }
 
 impl Executable for LookupAndAccumulate {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.phase {
             LookupAndAccumulatePhase::Lookup => {
-                let z = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+                let z = Felt::from_bytes_be(
+                    stack
+                        .borrow_front()
+                        .try_into()
+                        .map_err(|_| TaskError::StackUnderflow)?,
+                );
                 stack.pop_front();
-                let y = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+                let y = Felt::from_bytes_be(
+                    stack
+                        .borrow_front()
+                        .try_into()
+                        .map_err(|_| TaskError::StackUnderflow)?,
+                );
                 stack.pop_front();
-                let x = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+                let x = Felt::from_bytes_be(
+                    stack
+                        .borrow_front()
+                        .try_into()
+                        .map_err(|_| TaskError::StackUnderflow)?,
+                );
                 stack.pop_front();
 
                 self.acc = ShortWeierstrassProjectivePoint::<StarkCurve>::new([
@@ -179,7 +246,7 @@ impl Executable for LookupAndAccumulate {
                 ])
                 .unwrap();
                 self.phase = LookupAndAccumulatePhase::Accumulate;
-                vec![]
+                Ok(vec![])
             }
             LookupAndAccumulatePhase::Accumulate => {
                 const CHUNK_SIZE: usize = 10;
@@ -217,17 +284,21 @@ impl Executable for LookupAndAccumulate {
 
                 let total_chunks = bits.len().div_ceil(PedersenHash::CURVE_CONST_BITS);
                 if self.chunk_index >= total_chunks {
-                    stack.push_front(&self.acc.x().to_bytes_be()).unwrap();
-                    stack.push_front(&self.acc.y().to_bytes_be()).unwrap();
-                    stack.push_front(&self.acc.z().to_bytes_be()).unwrap();
+                    stack
+                        .push_front(&self.acc.x().to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                    stack
+                        .push_front(&self.acc.y().to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                    stack
+                        .push_front(&self.acc.z().to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
                     self.phase = LookupAndAccumulatePhase::Finished;
                 }
 
-                vec![]
-            }
-            LookupAndAccumulatePhase::Finished => {
-                vec![]
+                Ok(vec![])
             }
+            LookupAndAccumulatePhase::Finished => Ok(vec![]),
         }
     }
 
@@ -1,5 +1,5 @@
 use felt::Felt;
-use utils::{impl_type_identifiable, BidirectionalStack};
+use utils::{impl_type_identifiable, BidirectionalStack, TaskError};
 use utils::{Executable, TypeIdentifiable};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -60,7 +60,7 @@ impl HadesPermutation {
 }
 
 impl Executable for HadesPermutation {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.phase {
             HadesPhase::FirstHalfFullRounds => {
                 // First half of full rounds
@@ -90,15 +90,17 @@ impl Executable for HadesPermutation {
 
                     self.constants_index += Self::N_ROUND_CONSTANTS_COLS;
                 }
-                self.state.iter().rev().for_each(|value| {
-                    stack.push_front(&value.to_bytes_be()).unwrap();
-                });
+                for value in self.state.iter().rev() {
+                    stack
+                        .push_front(&value.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                }
                 self.phase = HadesPhase::Finished;
             }
             HadesPhase::Finished => {}
         }
 
-        vec![]
+        Ok(vec![])
     }
 
     fn is_finished(&mut self) -> bool {
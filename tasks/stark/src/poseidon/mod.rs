@@ -1,7 +1,7 @@
 pub mod constants;
 pub mod hades;
 
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, TypeIdentifiable};
+use utils::{impl_type_identifiable, BidirectionalStack, Executable, TaskError, TypeIdentifiable};
 
 use crate::poseidon::hades::HadesPermutation;
 use felt::Felt;
@@ -24,38 +24,78 @@ impl PoseidonHashMany {
         }
     }
 
-    pub fn push_input<T: BidirectionalStack>(inputs: &[Felt], stack: &mut T) {
+    pub fn push_input<T: BidirectionalStack>(
+        inputs: &[Felt],
+        stack: &mut T,
+    ) -> Result<(), TaskError> {
         let inputs_len = inputs.len() + 1;
         let zero_count = inputs_len.div_ceil(2) * 2 - inputs_len;
         for _ in 0..zero_count {
-            stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
+            stack
+                .push_front(&Felt::ZERO.to_bytes_be())
+                .map_err(|_| TaskError::StackOverflow)?;
         }
-        stack.push_front(&Felt::ONE.to_bytes_be()).unwrap();
-
-        inputs.iter().rev().for_each(|value| {
-            stack.push_front(&value.to_bytes_be()).unwrap();
-        });
-        stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
-        stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
-        stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
+        stack
+            .push_front(&Felt::ONE.to_bytes_be())
+            .map_err(|_| TaskError::StackOverflow)?;
+
+        for value in inputs.iter().rev() {
+            stack
+                .push_front(&value.to_bytes_be())
+                .map_err(|_| TaskError::StackOverflow)?;
+        }
+        stack
+            .push_front(&Felt::ZERO.to_bytes_be())
+            .map_err(|_| TaskError::StackOverflow)?;
+        stack
+            .push_front(&Felt::ZERO.to_bytes_be())
+            .map_err(|_| TaskError::StackOverflow)?;
+        stack
+            .push_front(&Felt::ZERO.to_bytes_be())
+            .map_err(|_| TaskError::StackOverflow)
     }
 }
 
 impl Executable for PoseidonHashMany {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
-        let s1 = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
+        let s1 = Felt::from_bytes_be(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
         stack.pop_front();
 
-        let s2 = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+        let s2 = Felt::from_bytes_be(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
         stack.pop_front();
 
-        let s3 = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+        let s3 = Felt::from_bytes_be(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
         stack.pop_front();
 
-        let v1 = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+        let v1 = Felt::from_bytes_be(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
         stack.pop_front();
 
-        let v2 = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+        let v2 = Felt::from_bytes_be(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
         stack.pop_front();
 
         self.state[0] = s1 + v1;
@@ -64,7 +104,9 @@ impl Executable for PoseidonHashMany {
 
         self.counter += 2;
 
-        vec![HadesPermutation::new(self.state).to_vec_with_type_tag()]
+        Ok(vec![
+            HadesPermutation::new(self.state).to_vec_with_type_tag()
+        ])
     }
 
     fn is_finished(&mut self) -> bool {
@@ -92,9 +134,17 @@ impl PoseidonHash {
         }
     }
 
-    pub fn push_input<T: BidirectionalStack>(x: Felt, y: Felt, stack: &mut T) {
-        stack.push_front(&y.to_bytes_be()).unwrap();
-        stack.push_front(&x.to_bytes_be()).unwrap();
+    pub fn push_input<T: BidirectionalStack>(
+        x: Felt,
+        y: Felt,
+        stack: &mut T,
+    ) -> Result<(), TaskError> {
+        stack
+            .push_front(&y.to_bytes_be())
+            .map_err(|_| TaskError::StackOverflow)?;
+        stack
+            .push_front(&x.to_bytes_be())
+            .map_err(|_| TaskError::StackOverflow)
     }
 }
 
@@ -105,17 +155,27 @@ impl Default for PoseidonHash {
 }
 
 impl Executable for PoseidonHash {
-    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
-        let x = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+    fn execute<T: BidirectionalStack>(&mut self, stack: &mut T) -> Result<Vec<Vec<u8>>, TaskError> {
+        let x = Felt::from_bytes_be(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
         stack.pop_front();
-        let y = Felt::from_bytes_be(stack.borrow_front().try_into().unwrap());
+        let y = Felt::from_bytes_be(
+            stack
+                .borrow_front()
+                .try_into()
+                .map_err(|_| TaskError::StackUnderflow)?,
+        );
         stack.pop_front();
 
         let state = [x, y, Felt::TWO];
 
         self.phase = PoseidonPhase::Done;
 
-        vec![HadesPermutation::new(state).to_vec_with_type_tag()]
+        Ok(vec![HadesPermutation::new(state).to_vec_with_type_tag()])
     }
 
     fn is_finished(&mut self) -> bool {
@@ -0,0 +1,99 @@
+use crate::stark_proof::output::output_memory;
+use crate::swiftness::air::public_memory::PublicInput;
+use felt::Felt;
+
+// Output of a simple_bootloader run:
+//   n_tasks, then for each task: output_size, program_hash, output...
+// where the output_size of a task counts its output_size and program_hash cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BootloaderOutput<'a> {
+    pub tasks: Vec<TaskOutput<'a>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskOutput<'a> {
+    pub program_hash: Felt,
+    pub output: &'a [Felt],
+}
+
+// Size of the output_size and program_hash cells preceding the output of a task.
+pub const TASK_HEADER_SIZE: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootloaderOutputError {
+    // Part of the output segment is in continuous pages, which the proof does not contain.
+    IncompleteOutput,
+    // The output segment is empty or n_tasks does not fit in usize.
+    InvalidTaskCount,
+    // The output_size of the task is smaller than its header or runs past the output segment.
+    InvalidOutputSize { task: usize },
+    // Output left after the last task.
+    TrailingOutput { len: usize },
+}
+
+impl core::fmt::Display for BootloaderOutputError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IncompleteOutput => write!(f, "output segment not in the main page"),
+            Self::InvalidTaskCount => write!(f, "invalid number of tasks"),
+            Self::InvalidOutputSize { task } => write!(f, "invalid output size of task {task}"),
+            Self::TrailingOutput { len } => write!(f, "{len} cells after the last task"),
+        }
+    }
+}
+
+// Parses the output segment of a simple_bootloader run.
+pub fn decode_bootloader_output(
+    output: &[Felt],
+) -> Result<BootloaderOutput<'_>, BootloaderOutputError> {
+    let (n_tasks, mut rest) = output
+        .split_first()
+        .ok_or(BootloaderOutputError::InvalidTaskCount)?;
+    let n_tasks: usize = (*n_tasks)
+        .try_into()
+        .map_err(|_| BootloaderOutputError::InvalidTaskCount)?;
+    // Every task takes at least its header, which also bounds the allocation below.
+    if n_tasks > rest.len() / TASK_HEADER_SIZE {
+        return Err(BootloaderOutputError::InvalidTaskCount);
+    }
+
+    let mut tasks = Vec::with_capacity(n_tasks);
+    for task in 0..n_tasks {
+        let invalid_output_size = BootloaderOutputError::InvalidOutputSize { task };
+        let output_size: usize = rest
+            .first()
+            .copied()
+            .ok_or(invalid_output_size)?
+            .try_into()
+            .map_err(|_| invalid_output_size)?;
+        if output_size < TASK_HEADER_SIZE || output_size > rest.len() {
+            return Err(invalid_output_size);
+        }
+
+        let (task_output, next) = rest.split_at(output_size);
+        tasks.push(TaskOutput {
+            program_hash: task_output[1],
+            output: &task_output[TASK_HEADER_SIZE..],
+        });
+        rest = next;
+    }
+
+    if !rest.is_empty() {
+        return Err(BootloaderOutputError::TrailingOutput { len: rest.len() });
+    }
+    Ok(BootloaderOutput { tasks })
+}
+
+// Values of the output segment of the proof, when it is entirely in the main page.
+pub fn output_values(public_input: &PublicInput) -> Result<Vec<Felt>, BootloaderOutputError> {
+    if !public_input.continuous_page_headers.is_empty() {
+        return Err(BootloaderOutputError::IncompleteOutput);
+    }
+    let output =
+        output_memory(public_input).map_err(|_| BootloaderOutputError::IncompleteOutput)?;
+    let memory = public_input.main_page.0.as_slice();
+    Ok(memory[output.main_page]
+        .iter()
+        .map(|cell| cell.value)
+        .collect())
+}
@@ -1,12 +1,21 @@
 use crate::{
-    pedersen::PedersenHash, poseidon::PoseidonHashMany, swiftness::stark::types::StarkProof,
+    pedersen::PedersenHash,
+    poseidon::PoseidonHashMany,
+    stark_proof::{felt_to_usize, PedersenHashOnMemory},
+    swiftness::stark::{config::StoneVersion, types::StarkProof},
 };
 use felt::Felt;
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, ProofData, TypeIdentifiable};
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError,
+    TypeIdentifiable,
+};
+
+const INVALID_PAGE: &str = "Invalid continuous page";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GetHashStep {
     Init,
+    PageHash,
     WaitForPedersenAddress,
     WaitForPedersenValue,
     MainPageHash,
@@ -22,6 +31,8 @@ pub struct GetHash {
     current_memory_index: usize,
     n_verifier_friendly_commitment_layers: Felt,
     accumulated_hash: Felt,
+    page_index: usize,
+    page_values_offset: usize,
 }
 
 impl_type_identifiable!(GetHash);
@@ -35,6 +46,8 @@ impl GetHash {
             current_memory_index: 0,
             n_verifier_friendly_commitment_layers,
             accumulated_hash: Felt::ZERO,
+            page_index: 0,
+            page_values_offset: 0,
         }
     }
 }
@@ -46,36 +59,65 @@ impl Default for GetHash {
 }
 
 impl Executable for GetHash {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.step {
             GetHashStep::Init => {
+                // Continuous pages are committed to by their headers: they must be non-empty,
+                // sorted by address and disjoint, and their values must add up to the headers.
                 let proof: &StarkProof = stack.get_proof_reference();
-                self.main_page_len = proof.public_input.main_page.0.len();
-                self.current_memory_index = 0;
-                self.accumulated_hash = Felt::ZERO;
-
-                if self.main_page_len == 0 {
-                    self.step = GetHashStep::MainPageHash;
-                    return self.execute_final_pedersen_hash(stack);
+                let public_input = &proof.public_input;
+                let mut values_len: usize = 0;
+                let mut previous_end = None;
+                for header in public_input.continuous_page_headers.as_slice() {
+                    let start = felt_to_usize(header.start_address, INVALID_PAGE)?;
+                    let size = felt_to_usize(header.size, INVALID_PAGE)?;
+                    ensure(
+                        size != 0,
+                        TaskError::InvalidPublicInput("Empty continuous page"),
+                    )?;
+                    ensure(
+                        previous_end.is_none_or(|end| end <= start),
+                        TaskError::InvalidPublicInput("Continuous pages are not sorted or overlap"),
+                    )?;
+                    previous_end = Some(
+                        start
+                            .checked_add(size)
+                            .ok_or(TaskError::InvalidPublicInput(INVALID_PAGE))?,
+                    );
+                    values_len += size;
                 }
+                ensure(
+                    values_len == public_input.continuous_page_values.len(),
+                    TaskError::InvalidPublicInput(
+                        "Continuous page values do not match the headers",
+                    ),
+                )?;
+
+                self.page_index = 0;
+                self.page_values_offset = 0;
+                self.hash_next_page(stack)
+            }
+            GetHashStep::PageHash => {
+                let page_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
 
-                let memory = proof.public_input.main_page.0.as_slice();
-
-                PedersenHash::push_input(
-                    self.accumulated_hash,
-                    memory[self.current_memory_index].address,
-                    stack,
-                );
-
-                self.step = GetHashStep::WaitForPedersenAddress;
-                vec![PedersenHash::new().to_vec_with_type_tag()]
+                let proof: &StarkProof = stack.get_proof_reference();
+                let header = proof.public_input.continuous_page_headers.as_slice()[self.page_index];
+                ensure(
+                    page_hash == header.hash,
+                    TaskError::InvalidPublicInput("Continuous page hash mismatch"),
+                )?;
+                self.page_values_offset += felt_to_usize(header.size, INVALID_PAGE)?;
+                self.page_index += 1;
+                self.hash_next_page(stack)
             }
             GetHashStep::WaitForPedersenAddress => {
                 let bytes = stack.borrow_front();
                 let pedersen_result = Felt::from_bytes_be_slice(bytes);
                 stack.pop_front();
-                stack.pop_front();
-                stack.pop_front();
 
                 self.accumulated_hash = pedersen_result;
 
@@ -86,17 +128,15 @@ impl Executable for GetHash {
                     self.accumulated_hash,
                     memory[self.current_memory_index].value,
                     stack,
-                );
+                )?;
 
                 self.step = GetHashStep::WaitForPedersenValue;
-                vec![PedersenHash::new().to_vec_with_type_tag()]
+                Ok(vec![PedersenHash::new().to_vec_with_type_tag()])
             }
             GetHashStep::WaitForPedersenValue => {
                 let bytes = stack.borrow_front();
                 let pedersen_result = Felt::from_bytes_be_slice(bytes);
                 stack.pop_front();
-                stack.pop_front();
-                stack.pop_front();
 
                 self.accumulated_hash = pedersen_result;
                 self.current_memory_index += 1;
@@ -109,26 +149,25 @@ impl Executable for GetHash {
                         self.accumulated_hash,
                         memory[self.current_memory_index].address,
                         stack,
-                    );
+                    )?;
 
                     self.step = GetHashStep::WaitForPedersenAddress;
-                    vec![PedersenHash::new().to_vec_with_type_tag()]
+                    Ok(vec![PedersenHash::new().to_vec_with_type_tag()])
                 } else {
                     self.step = GetHashStep::MainPageHash;
                     let length_multiplier = Felt::TWO * Felt::from(self.main_page_len);
 
-                    PedersenHash::push_input(self.accumulated_hash, length_multiplier, stack);
-                    vec![PedersenHash::new().to_vec_with_type_tag()]
+                    PedersenHash::push_input(self.accumulated_hash, length_multiplier, stack)?;
+                    Ok(vec![PedersenHash::new().to_vec_with_type_tag()])
                 }
             }
             GetHashStep::MainPageHash => {
                 let bytes = stack.borrow_front();
                 self.main_page_hash = Felt::from_bytes_be_slice(bytes);
                 stack.pop_front();
-                stack.pop_front();
-                stack.pop_front();
 
                 let (
+                    stone_version,
                     n_verifier_friendly_commitment_layers,
                     log_n_steps,
                     range_check_min,
@@ -145,7 +184,8 @@ impl Executable for GetHash {
                     let proof: &StarkProof = stack.get_proof_reference();
                     let public_input = &proof.public_input;
 
-                    let dynamic_params_len = if let Some(ref dp) = public_input.dynamic_params {
+                    let dynamic_params = public_input.dynamic_params()?;
+                    let dynamic_params_len = if let Some(dp) = dynamic_params {
                         let dynamic_params_vec: Vec<u32> = (*dp).into();
                         dynamic_params_vec.len()
                     } else {
@@ -153,12 +193,13 @@ impl Executable for GetHash {
                     };
 
                     (
+                        proof.verifier_config.stone_version()?,
                         self.n_verifier_friendly_commitment_layers,
                         public_input.log_n_steps,
                         public_input.range_check_min,
                         public_input.range_check_max,
                         public_input.layout,
-                        public_input.dynamic_params.is_some(),
+                        dynamic_params.is_some(),
                         dynamic_params_len,
                         public_input.segments.len(),
                         public_input.padding_addr,
@@ -168,7 +209,13 @@ impl Executable for GetHash {
                     )
                 };
 
-                let mut total_elements = 5; // basic fields
+                // Stone 6 mixes the number of verifier friendly layers into the transcript seed.
+                let hash_n_verifier_friendly_layers = stone_version == StoneVersion::Stone6;
+
+                let mut total_elements = 4; // log_n_steps, range_check_min, range_check_max, layout
+                if hash_n_verifier_friendly_layers {
+                    total_elements += 1;
+                }
                 total_elements += dynamic_params_len;
                 total_elements += segments_len * 2;
                 total_elements += 5; // padding_addr, padding_value, headers_len+1, main_page_len, main_page_hash
@@ -177,10 +224,14 @@ impl Executable for GetHash {
                 let inputs_with_one = total_elements + 1;
                 let zero_count = inputs_with_one.div_ceil(2) * 2 - inputs_with_one;
                 for _ in 0..zero_count {
-                    stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
+                    stack
+                        .push_front(&Felt::ZERO.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
                 }
 
-                stack.push_front(&Felt::ONE.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&Felt::ONE.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 for i in (0..headers_len).rev() {
                     let (start_address, size, hash) = {
@@ -190,22 +241,32 @@ impl Executable for GetHash {
                         (header.start_address, header.size, header.hash)
                     };
 
-                    stack.push_front(&hash.to_bytes_be()).unwrap();
-                    stack.push_front(&size.to_bytes_be()).unwrap();
-                    stack.push_front(&start_address.to_bytes_be()).unwrap();
+                    stack
+                        .push_front(&hash.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                    stack
+                        .push_front(&size.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                    stack
+                        .push_front(&start_address.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
                 }
 
                 stack
                     .push_front(&self.main_page_hash.to_bytes_be())
-                    .unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
                 stack
                     .push_front(&Felt::from(main_page_len).to_bytes_be())
-                    .unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
                 stack
                     .push_front(&Felt::from(headers_len + 1).to_bytes_be())
-                    .unwrap();
-                stack.push_front(&padding_value.to_bytes_be()).unwrap();
-                stack.push_front(&padding_addr.to_bytes_be()).unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&padding_value.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&padding_addr.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 for i in (0..segments_len).rev() {
                     let (begin_addr, stop_ptr) = {
@@ -215,48 +276,77 @@ impl Executable for GetHash {
                         (segment.begin_addr, segment.stop_ptr)
                     };
 
-                    stack.push_front(&stop_ptr.to_bytes_be()).unwrap();
-                    stack.push_front(&begin_addr.to_bytes_be()).unwrap();
+                    stack
+                        .push_front(&stop_ptr.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                    stack
+                        .push_front(&begin_addr.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
                 }
 
                 if has_dynamic_params {
                     let proof: &StarkProof = stack.get_proof_reference();
                     let public_input = &proof.public_input;
-                    if let Some(dynamic_params) = &public_input.dynamic_params {
+                    if let Some(dynamic_params) = public_input.dynamic_params()? {
                         let dynamic_params_vec: Vec<u32> = (*dynamic_params).into();
                         for value in dynamic_params_vec.iter().rev() {
                             let felt = Felt::from(*value);
-                            stack.push_front(&felt.to_bytes_be()).unwrap();
+                            stack
+                                .push_front(&felt.to_bytes_be())
+                                .map_err(|_| TaskError::StackOverflow)?;
                         }
                     }
                 }
 
-                stack.push_front(&layout.to_bytes_be()).unwrap();
-                stack.push_front(&range_check_max.to_bytes_be()).unwrap();
-                stack.push_front(&range_check_min.to_bytes_be()).unwrap();
-                stack.push_front(&log_n_steps.to_bytes_be()).unwrap();
                 stack
-                    .push_front(&n_verifier_friendly_commitment_layers.to_bytes_be())
-                    .unwrap();
+                    .push_front(&layout.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&range_check_max.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&range_check_min.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&log_n_steps.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                if hash_n_verifier_friendly_layers {
+                    stack
+                        .push_front(&n_verifier_friendly_commitment_layers.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                }
 
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = GetHashStep::Program;
-                vec![PoseidonHashMany::new(total_elements).to_vec_with_type_tag()]
+                Ok(vec![
+                    PoseidonHashMany::new(total_elements).to_vec_with_type_tag()
+                ])
             }
             GetHashStep::Program => {
                 let bytes = stack.borrow_front();
                 let poseidon_result = Felt::from_bytes_be_slice(bytes);
-                println!("GetHash result: {:?}", poseidon_result);
+                stack.pop_front();
+                stack.pop_front();
+                stack.pop_front();
+
+                // Only the hash of the public input is left on the stack.
+                stack
+                    .push_front(&poseidon_result.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = GetHashStep::Done;
-                vec![]
-            }
-            GetHashStep::Done => {
-                vec![]
+                Ok(vec![])
             }
+            GetHashStep::Done => Ok(vec![]),
         }
     }
 
@@ -266,15 +356,52 @@ impl Executable for GetHash {
 }
 
 impl GetHash {
+    // Checks the hash of the next continuous page, then hashes the main page once all pages have
+    // been checked.
+    //
+    // Page hashes follow the convention of the Cairo verifier on Starknet, which the proof parser
+    // uses to build the headers: a Pedersen hash chain of the page values followed by their count
+    // (compute_hash_on_elements). The keccak page hashes of the L1 memory page fact registry are
+    // 256-bit digests that do not fit in the felt of the header, so they are rejected.
+    fn hash_next_page<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        let proof: &StarkProof = stack.get_proof_reference();
+        let public_input = &proof.public_input;
+        if let Some(header) = public_input.continuous_page_headers.get(self.page_index) {
+            let page_end = self.page_values_offset + felt_to_usize(header.size, INVALID_PAGE)?;
+            self.step = GetHashStep::PageHash;
+            return Ok(vec![PedersenHashOnMemory::new(0, 0)
+                .with_continuous_page_values(self.page_values_offset, page_end)
+                .to_vec_with_type_tag()]);
+        }
+
+        self.main_page_len = public_input.main_page.0.len();
+        self.current_memory_index = 0;
+        self.accumulated_hash = Felt::ZERO;
+
+        if self.main_page_len == 0 {
+            self.step = GetHashStep::MainPageHash;
+            return self.execute_final_pedersen_hash(stack);
+        }
+
+        let address = public_input.main_page.0.as_slice()[self.current_memory_index].address;
+        PedersenHash::push_input(self.accumulated_hash, address, stack)?;
+
+        self.step = GetHashStep::WaitForPedersenAddress;
+        Ok(vec![PedersenHash::new().to_vec_with_type_tag()])
+    }
+
     #[inline(always)]
     fn execute_final_pedersen_hash<T: BidirectionalStack>(
         &mut self,
         stack: &mut T,
-    ) -> Vec<Vec<u8>> {
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         // Final hash with the length multiplier
         let length_multiplier = Felt::TWO * Felt::from(self.main_page_len);
 
-        PedersenHash::push_input(self.accumulated_hash, length_multiplier, stack);
-        vec![PedersenHash::new().to_vec_with_type_tag()]
+        PedersenHash::push_input(self.accumulated_hash, length_multiplier, stack)?;
+        Ok(vec![PedersenHash::new().to_vec_with_type_tag()])
     }
 }
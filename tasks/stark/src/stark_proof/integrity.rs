@@ -0,0 +1,180 @@
+use crate::poseidon::PoseidonHashMany;
+use crate::stark_proof::validate_stark_config::security_bits;
+use crate::swiftness::stark::config::{Hasher, StoneVersion};
+use crate::swiftness::stark::types::StarkProof;
+use felt::Felt;
+use utils::{
+    impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError, TypeIdentifiable,
+};
+
+// Names of the verifier settings in Herodotus Integrity's VerifierConfiguration, encoded as felts.
+pub mod settings {
+    use felt::Felt;
+
+    pub const STONE5: Felt = Felt::from_hex_unchecked("0x73746f6e6535");
+    pub const STONE6: Felt = Felt::from_hex_unchecked("0x73746f6e6536");
+    pub const KECCAK_160_LSB: Felt = Felt::from_hex_unchecked("0x6b656363616b5f3136305f6c7362");
+    pub const BLAKE2S_160_LSB: Felt = Felt::from_hex_unchecked("0x626c616b6532735f3136305f6c7362");
+    // The program and the output are read from the main page of the public memory.
+    pub const MEMORY_VERIFICATION_STRICT: Felt = Felt::from_hex_unchecked("0x737472696374");
+}
+
+// Integrity's VerifierConfiguration of the proof: layout, hasher, stone version and memory
+// verification. Every memory verification mode of Integrity reads the program and the output from
+// the main page, so proofs with continuous pages have no matching configuration.
+pub fn verifier_config(proof: &StarkProof) -> Result<Option<[Felt; 4]>, TaskError> {
+    if !proof.public_input.continuous_page_headers.is_empty() {
+        return Ok(None);
+    }
+    let hasher = match proof.verifier_config.hasher()? {
+        Hasher::Keccak256 => settings::KECCAK_160_LSB,
+        Hasher::Blake2s256 => settings::BLAKE2S_160_LSB,
+    };
+    let stone_version = match proof.verifier_config.stone_version()? {
+        StoneVersion::Stone5 => settings::STONE5,
+        StoneVersion::Stone6 => settings::STONE6,
+    };
+    Ok(Some([
+        proof.public_input.layout,
+        hasher,
+        stone_version,
+        settings::MEMORY_VERIFICATION_STRICT,
+    ]))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityHashesStep {
+    Init,
+    FactHash,
+    VerifierConfigHash,
+    VerificationHash,
+    Done,
+}
+
+// Computes the hashes under which Integrity's FactRegistry records a verified proof, from the
+// program hash and the output hash on the stack, the program hash on top:
+//   fact_hash = poseidon(program_hash, output_hash)
+//   verifier_config_hash = poseidon(layout, hasher, stone_version, memory_verification)
+//   verification_hash = poseidon(fact_hash, verifier_config_hash, security_bits)
+// Leaves the program hash, the output hash, the fact hash and the verification hash on the stack,
+// the program hash on top. Proofs that Integrity cannot verify, those with continuous pages, get
+// zero Integrity hashes.
+#[repr(C)]
+pub struct IntegrityHashes {
+    step: IntegrityHashesStep,
+    program_hash: Felt,
+    output_hash: Felt,
+    fact_hash: Felt,
+    verifier_config: [Felt; 4],
+}
+
+impl_type_identifiable!(IntegrityHashes);
+
+impl IntegrityHashes {
+    pub fn new() -> Self {
+        Self {
+            step: IntegrityHashesStep::Init,
+            program_hash: Felt::ZERO,
+            output_hash: Felt::ZERO,
+            fact_hash: Felt::ZERO,
+            verifier_config: [Felt::ZERO; 4],
+        }
+    }
+
+    fn push_hashes<T: BidirectionalStack>(
+        &self,
+        stack: &mut T,
+        verification_hash: Felt,
+    ) -> Result<(), TaskError> {
+        for hash in [
+            verification_hash,
+            self.fact_hash,
+            self.output_hash,
+            self.program_hash,
+        ] {
+            stack
+                .push_front(&hash.to_bytes_be())
+                .map_err(|_| TaskError::StackOverflow)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for IntegrityHashes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Executable for IntegrityHashes {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            IntegrityHashesStep::Init => {
+                self.program_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+                self.output_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+
+                let proof: &StarkProof = stack.get_proof_reference();
+                let Some(verifier_config) = verifier_config(proof)? else {
+                    self.push_hashes(stack, Felt::ZERO)?;
+                    self.step = IntegrityHashesStep::Done;
+                    return Ok(vec![]);
+                };
+                self.verifier_config = verifier_config;
+
+                PoseidonHashMany::push_input(&[self.program_hash, self.output_hash], stack)?;
+
+                self.step = IntegrityHashesStep::FactHash;
+                Ok(vec![PoseidonHashMany::new(2).to_vec_with_type_tag()])
+            }
+            IntegrityHashesStep::FactHash => {
+                self.fact_hash = pop_poseidon_result(stack);
+
+                PoseidonHashMany::push_input(&self.verifier_config, stack)?;
+
+                self.step = IntegrityHashesStep::VerifierConfigHash;
+                Ok(vec![
+                    PoseidonHashMany::new(self.verifier_config.len()).to_vec_with_type_tag()
+                ])
+            }
+            IntegrityHashesStep::VerifierConfigHash => {
+                let verifier_config_hash = pop_poseidon_result(stack);
+
+                let proof: &StarkProof = stack.get_proof_reference();
+                let security_bits = Felt::from(security_bits(&proof.config)?);
+                PoseidonHashMany::push_input(
+                    &[self.fact_hash, verifier_config_hash, security_bits],
+                    stack,
+                )?;
+
+                self.step = IntegrityHashesStep::VerificationHash;
+                Ok(vec![PoseidonHashMany::new(3).to_vec_with_type_tag()])
+            }
+            IntegrityHashesStep::VerificationHash => {
+                let verification_hash = pop_poseidon_result(stack);
+                self.push_hashes(stack, verification_hash)?;
+
+                self.step = IntegrityHashesStep::Done;
+                Ok(vec![])
+            }
+            IntegrityHashesStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == IntegrityHashesStep::Done
+    }
+}
+
+// Reads the result of a PoseidonHashMany task and drops the rest of its state.
+fn pop_poseidon_result<T: BidirectionalStack>(stack: &mut T) -> Felt {
+    let result = Felt::from_bytes_be_slice(stack.borrow_front());
+    stack.pop_front();
+    stack.pop_front();
+    stack.pop_front();
+    result
+}
@@ -1,12 +1,23 @@
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, ProofData, TypeIdentifiable};
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError,
+    TypeIdentifiable,
+};
 
-use crate::{poseidon::PoseidonHashMany, swiftness::stark::types::StarkProof};
+use crate::{
+    pedersen::PedersenHash,
+    poseidon::PoseidonHashMany,
+    swiftness::stark::{config::HashFunction, types::StarkProof},
+};
 use felt::Felt;
 
+pub mod bootloader;
 pub mod get_hash;
+pub mod integrity;
+pub mod output;
 pub mod stark_commit;
 pub mod stark_verify;
 pub mod validate_public_input;
+pub mod validate_stark_config;
 pub mod verify;
 
 // Constants for validation
@@ -56,11 +67,16 @@ impl HashPublicInputs {
 }
 
 impl Executable for HashPublicInputs {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.step {
             HashPublicInputsStep::Init => {
                 self.step = HashPublicInputsStep::ProgramHash;
-                vec![PoseidonHashMany::new(self.program_input_length).to_vec_with_type_tag()]
+                Ok(vec![
+                    PoseidonHashMany::new(self.program_input_length).to_vec_with_type_tag()
+                ])
             }
             HashPublicInputsStep::ProgramHash => {
                 let bytes = stack.borrow_front();
@@ -70,7 +86,9 @@ impl Executable for HashPublicInputs {
                 stack.pop_front();
                 self.program_hash = program_hash;
                 self.step = HashPublicInputsStep::OutputHash;
-                vec![PoseidonHashMany::new(self.output_input_length).to_vec_with_type_tag()]
+                Ok(vec![
+                    PoseidonHashMany::new(self.output_input_length).to_vec_with_type_tag()
+                ])
             }
             HashPublicInputsStep::OutputHash => {
                 let bytes = stack.borrow_front();
@@ -79,15 +97,17 @@ impl Executable for HashPublicInputs {
                 stack.pop_front();
                 stack.pop_front();
 
-                stack.push_front(&output_hash.to_bytes_be()).unwrap();
-                stack.push_front(&self.program_hash.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&output_hash.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&self.program_hash.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = HashPublicInputsStep::Done;
-                vec![]
-            }
-            HashPublicInputsStep::Done => {
-                vec![]
+                Ok(vec![])
             }
+            HashPublicInputsStep::Done => Ok(vec![]),
         }
     }
 
@@ -95,6 +115,119 @@ impl Executable for HashPublicInputs {
         self.step == HashPublicInputsStep::Done
     }
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PedersenHashOnMemoryStep {
+    Init,
+    Accumulate,
+    Length,
+    Done,
+}
+
+// Pedersen hash chain of the main page values in [begin, end), then of the continuous page values
+// in [continuous_page_begin, continuous_page_end), followed by their count, as in
+// compute_hash_on_elements. Leaves the hash on the stack.
+#[repr(C)]
+pub struct PedersenHashOnMemory {
+    step: PedersenHashOnMemoryStep,
+    begin: usize,
+    end: usize,
+    continuous_page_begin: usize,
+    continuous_page_end: usize,
+    index: usize,
+}
+
+impl_type_identifiable!(PedersenHashOnMemory);
+
+impl PedersenHashOnMemory {
+    pub fn new(begin: usize, end: usize) -> Self {
+        Self {
+            step: PedersenHashOnMemoryStep::Init,
+            begin,
+            end,
+            continuous_page_begin: 0,
+            continuous_page_end: 0,
+            index: 0,
+        }
+    }
+
+    pub fn with_continuous_page_values(mut self, begin: usize, end: usize) -> Self {
+        self.continuous_page_begin = begin;
+        self.continuous_page_end = end;
+        self
+    }
+
+    fn main_page_len(&self) -> usize {
+        self.end - self.begin
+    }
+
+    fn len(&self) -> usize {
+        self.main_page_len() + (self.continuous_page_end - self.continuous_page_begin)
+    }
+
+    fn hash_next<T: BidirectionalStack + ProofData>(
+        &mut self,
+        accumulated_hash: Felt,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        if self.index < self.len() {
+            let proof: &StarkProof = stack.get_proof_reference();
+            let public_input = &proof.public_input;
+            let value = match self.index.checked_sub(self.main_page_len()) {
+                None => public_input.main_page.0.as_slice()[self.begin + self.index].value,
+                Some(i) => {
+                    public_input.continuous_page_values.as_slice()[self.continuous_page_begin + i]
+                }
+            };
+            PedersenHash::push_input(accumulated_hash, value, stack)?;
+            self.index += 1;
+            self.step = PedersenHashOnMemoryStep::Accumulate;
+        } else {
+            let length = Felt::from(self.len());
+            PedersenHash::push_input(accumulated_hash, length, stack)?;
+            self.step = PedersenHashOnMemoryStep::Length;
+        }
+        Ok(vec![PedersenHash::new().to_vec_with_type_tag()])
+    }
+}
+
+impl Default for PedersenHashOnMemory {
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
+
+impl Executable for PedersenHashOnMemory {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            PedersenHashOnMemoryStep::Init => self.hash_next(Felt::ZERO, stack),
+            PedersenHashOnMemoryStep::Accumulate => {
+                let accumulated_hash = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+                self.hash_next(accumulated_hash, stack)
+            }
+            PedersenHashOnMemoryStep::Length => {
+                // The final hash is left on the stack.
+                self.step = PedersenHashOnMemoryStep::Done;
+                Ok(vec![])
+            }
+            PedersenHashOnMemoryStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == PedersenHashOnMemoryStep::Done
+    }
+}
+
+pub(crate) fn felt_to_usize(value: Felt, error: &'static str) -> Result<usize, TaskError> {
+    value
+        .try_into()
+        .map_err(|_| TaskError::InvalidPublicInput(error))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VerifyPublicInputStep {
     Init,
@@ -110,6 +243,7 @@ pub struct VerifyPublicInput {
     program_len: usize,
     output_start: usize,
     output_end: usize,
+    output_pages_end: usize,
     output_len: usize,
 }
 
@@ -123,6 +257,7 @@ impl VerifyPublicInput {
             program_end: 0,
             output_start: 0,
             output_end: 0,
+            output_pages_end: 0,
             program_len: 0,
             output_len: 0,
         }
@@ -136,117 +271,160 @@ impl Default for VerifyPublicInput {
 }
 
 impl Executable for VerifyPublicInput {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.step {
             VerifyPublicInputStep::Init => {
                 let proof: &StarkProof = stack.get_proof_reference();
                 let public_segments = &proof.public_input.segments;
 
-                let initial_pc: usize = public_segments
-                    .get(segments::PROGRAM)
-                    .unwrap()
-                    .begin_addr
-                    .try_into()
-                    .unwrap();
-                let initial_fp: usize = public_segments
-                    .get(segments::EXECUTION)
-                    .unwrap()
-                    .begin_addr
-                    .try_into()
-                    .unwrap();
-                let final_ap: usize = public_segments
-                    .get(segments::EXECUTION)
-                    .unwrap()
-                    .stop_ptr
-                    .try_into()
-                    .unwrap();
-
-                assert!(
+                let segment = |index: usize| {
+                    public_segments
+                        .get(index)
+                        .ok_or(TaskError::InvalidPublicInput("Missing segment"))
+                };
+                let initial_pc =
+                    felt_to_usize(segment(segments::PROGRAM)?.begin_addr, "Wrong initial PC")?;
+                let initial_fp = felt_to_usize(
+                    segment(segments::EXECUTION)?.begin_addr,
+                    "Initial AP exceeds maximum address",
+                )?;
+                let final_ap = felt_to_usize(
+                    segment(segments::EXECUTION)?.stop_ptr,
+                    "Final AP exceeds maximum address",
+                )?;
+
+                ensure(
                     initial_fp < MAX_ADDRESS,
-                    "Initial AP exceeds maximum address"
-                );
-                assert!(final_ap < MAX_ADDRESS, "Final AP exceeds maximum address");
-                assert!(
-                    proof.public_input.continuous_page_headers.is_empty(),
-                    "Continuous page headers are not empty"
-                );
-                assert!(initial_pc == INITIAL_PC, "Wrong initial PC");
+                    TaskError::InvalidPublicInput("Initial AP exceeds maximum address"),
+                )?;
+                ensure(
+                    final_ap < MAX_ADDRESS,
+                    TaskError::InvalidPublicInput("Final AP exceeds maximum address"),
+                )?;
+                ensure(
+                    initial_pc == INITIAL_PC,
+                    TaskError::InvalidPublicInput("Wrong initial PC"),
+                )?;
 
                 //1. Program segment
-                let program_end_pc: usize = initial_fp - 2;
-                let program_len = program_end_pc - initial_pc;
-
-                let output_start: usize = public_segments
-                    .get(segments::OUTPUT)
-                    .unwrap()
-                    .begin_addr
-                    .try_into()
-                    .unwrap();
-                let output_end: usize = public_segments
-                    .get(segments::OUTPUT)
-                    .unwrap()
-                    .stop_ptr
-                    .try_into()
-                    .unwrap();
-                let output_len = output_end - output_start;
-                let output_start = proof.public_input.main_page.0.len() - output_len;
-
-                self.output_start = output_start;
-                self.output_end = proof.public_input.main_page.0.len();
-                self.output_len = output_len;
+                // The execution segment starts after the program and the two cells of the
+                // initial frame.
+                let program_len = initial_fp
+                    .checked_sub(2)
+                    .and_then(|program_end_pc| program_end_pc.checked_sub(initial_pc))
+                    .ok_or(TaskError::InvalidPublicInput("Invalid program segment"))?;
+                ensure(
+                    program_len <= proof.public_input.main_page.0.len(),
+                    TaskError::InvalidPublicInput("Program segment not in the main page"),
+                )?;
+
+                let output = output::output_memory(&proof.public_input)?;
+                self.output_start = output.main_page.start;
+                self.output_end = output.main_page.end;
+                self.output_pages_end = output.continuous_pages.end;
+                self.output_len = output.main_page.len() + output.continuous_pages.len();
 
                 self.program_end = program_len;
                 self.program_len = program_len;
 
                 self.step = VerifyPublicInputStep::Output;
-                vec![]
+                Ok(vec![])
             }
             VerifyPublicInputStep::Output => {
+                let proof: &StarkProof = stack.get_proof_reference();
+                if proof.verifier_config.program_hash_function()? == HashFunction::Pedersen {
+                    // The output hash is computed first so that the program hash ends on top.
+                    self.step = VerifyPublicInputStep::Done;
+                    return Ok(vec![
+                        PedersenHashOnMemory::new(self.output_start, self.output_end)
+                            .with_continuous_page_values(0, self.output_pages_end)
+                            .to_vec_with_type_tag(),
+                        PedersenHashOnMemory::new(self.program_start, self.program_end)
+                            .to_vec_with_type_tag(),
+                    ]);
+                }
+
                 let inputs_len = self.output_len + 1;
                 let zero_count = inputs_len.div_ceil(2) * 2 - inputs_len;
                 for _ in 0..zero_count {
-                    stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
+                    stack
+                        .push_front(&Felt::ZERO.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
                 }
-                stack.push_front(&Felt::ONE.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&Felt::ONE.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
+                for i in (0..self.output_pages_end).rev() {
+                    let proof: &StarkProof = stack.get_proof_reference();
+                    let item = proof.public_input.continuous_page_values.as_slice()[i];
+                    stack
+                        .push_front(&item.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                }
                 for i in (self.output_start..self.output_end).rev() {
                     let proof: &StarkProof = stack.get_proof_reference();
                     let memory = proof.public_input.main_page.0.as_slice();
                     let item = memory[i].value;
-                    stack.push_front(&item.to_bytes_be()).unwrap();
+                    stack
+                        .push_front(&item.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
                 }
 
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
                 self.step = VerifyPublicInputStep::Program;
-                vec![]
+                Ok(vec![])
             }
             VerifyPublicInputStep::Program => {
                 let inputs_len = self.program_len + 1;
                 let zero_count = inputs_len.div_ceil(2) * 2 - inputs_len;
                 for _ in 0..zero_count {
-                    stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
+                    stack
+                        .push_front(&Felt::ZERO.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
                 }
 
-                stack.push_front(&Felt::ONE.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&Felt::ONE.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
                 for i in (self.program_start..self.program_end).rev() {
                     let proof: &StarkProof = stack.get_proof_reference();
                     let memory = proof.public_input.main_page.0.as_slice();
                     let item = memory[i].value;
-                    stack.push_front(&item.to_bytes_be()).unwrap();
+                    stack
+                        .push_front(&item.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
                 }
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = VerifyPublicInputStep::Done;
 
-                vec![HashPublicInputs::new(self.program_len, self.output_len).to_vec_with_type_tag()]
-            }
-            VerifyPublicInputStep::Done => {
-                vec![]
+                Ok(vec![HashPublicInputs::new(
+                    self.program_len,
+                    self.output_len,
+                )
+                .to_vec_with_type_tag()])
             }
+            VerifyPublicInputStep::Done => Ok(vec![]),
         }
     }
 
@@ -0,0 +1,67 @@
+use crate::stark_proof::{felt_to_usize, segments};
+use crate::swiftness::air::public_memory::PublicInput;
+use core::ops::Range;
+use utils::{ensure, TaskError};
+
+// Location of the output segment values in the public input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputMemory {
+    // Main page indices of the head of the output segment.
+    pub main_page: Range<usize>,
+    // Indices in continuous_page_values of the tail of the output segment.
+    pub continuous_pages: Range<usize>,
+}
+
+// The part of the output written to the main page is at its end, and the continuous pages hold
+// the rest of the output segment, one after the other.
+pub fn output_memory(public_input: &PublicInput) -> Result<OutputMemory, TaskError> {
+    const INVALID_OUTPUT: &str = "Invalid output segment";
+    const INVALID_PAGES: &str = "Continuous pages do not cover the end of the output segment";
+
+    let output_segment = public_input
+        .segments
+        .get(segments::OUTPUT)
+        .ok_or(TaskError::InvalidPublicInput(INVALID_OUTPUT))?;
+    let output_start = felt_to_usize(output_segment.begin_addr, INVALID_OUTPUT)?;
+    let output_end = felt_to_usize(output_segment.stop_ptr, INVALID_OUTPUT)?;
+    ensure(
+        output_start <= output_end,
+        TaskError::InvalidPublicInput(INVALID_OUTPUT),
+    )?;
+
+    let headers = public_input.continuous_page_headers.as_slice();
+    let pages_start = match headers.first() {
+        Some(first) => felt_to_usize(first.start_address, INVALID_PAGES)?,
+        None => output_end,
+    };
+    let mut pages_end = pages_start;
+    for header in headers {
+        ensure(
+            felt_to_usize(header.start_address, INVALID_PAGES)? == pages_end,
+            TaskError::InvalidPublicInput(INVALID_PAGES),
+        )?;
+        pages_end = pages_end
+            .checked_add(felt_to_usize(header.size, INVALID_PAGES)?)
+            .ok_or(TaskError::InvalidPublicInput(INVALID_PAGES))?;
+    }
+    ensure(
+        output_start <= pages_start && pages_end == output_end,
+        TaskError::InvalidPublicInput(INVALID_PAGES),
+    )?;
+    let pages_len = pages_end - pages_start;
+    ensure(
+        pages_len == public_input.continuous_page_values.len(),
+        TaskError::InvalidPublicInput("Continuous page values do not match the headers"),
+    )?;
+
+    let main_page_len = public_input.main_page.0.len();
+    let main_page_output_start = main_page_len
+        .checked_sub(pages_start - output_start)
+        .ok_or(TaskError::InvalidPublicInput(
+            "Output segment not in the main page",
+        ))?;
+    Ok(OutputMemory {
+        main_page: main_page_output_start..main_page_len,
+        continuous_pages: 0..pages_len,
+    })
+}
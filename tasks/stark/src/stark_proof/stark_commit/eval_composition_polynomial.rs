@@ -1,18 +1,14 @@
 use crate::stark_proof::stark_commit::eval_composition_polynomial_inner::EvalCompositionPolynomialInner;
-use crate::stark_proof::stark_commit::helpers::{
-    DILUTED_N_BITS, DILUTED_SPACING, FELT_2, PEDERSEN_BUILTIN_RATIO, PEDERSEN_BUILTIN_REPETITIONS,
-    POSEIDON_RATIO,
-};
+use crate::stark_proof::stark_commit::helpers::{DILUTED_N_BITS, DILUTED_SPACING, FELT_2};
 use crate::swiftness::air::consts::*;
+use crate::swiftness::air::layout::{ComputedValues, LayoutId};
 use crate::swiftness::air::periodic_columns::{
-    eval_pedersen_x, eval_pedersen_y, eval_poseidon_poseidon_full_round_key0,
-    eval_poseidon_poseidon_full_round_key1, eval_poseidon_poseidon_full_round_key2,
-    eval_poseidon_poseidon_partial_round_key0, eval_poseidon_poseidon_partial_round_key1,
+    eval_ecdsa_x, eval_ecdsa_y, eval_pedersen_x, eval_pedersen_y,
+    eval_poseidon_poseidon_full_round_key0, eval_poseidon_poseidon_full_round_key1,
+    eval_poseidon_poseidon_full_round_key2, eval_poseidon_poseidon_partial_round_key0,
+    eval_poseidon_poseidon_partial_round_key1,
 };
-use crate::swiftness::air::recursive_with_poseidon::segments;
-use crate::swiftness::air::recursive_with_poseidon::GlobalValues;
-use crate::swiftness::air::recursive_with_poseidon::PUBLIC_MEMORY_STEP;
-use crate::swiftness::air::recursive_with_poseidon::{SHIFT_POINT_X, SHIFT_POINT_Y};
+use crate::swiftness::air::{recursive, recursive_with_poseidon, small, starknet};
 use crate::swiftness::stark::types::StarkCommitment;
 use crate::swiftness::stark::types::StarkProof;
 use felt::Felt;
@@ -20,28 +16,20 @@ use felt::NonZeroFelt;
 use utils::global_values::InteractionElements;
 use utils::{
     impl_type_identifiable, BidirectionalStack, Executable, ProofData, StarkCommitmentTrait,
-    TypeIdentifiable,
+    TaskError, TypeIdentifiable,
 };
 
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct EvalCompositionPolynomial {
     step: EvalCompositionStep,
+    layout: LayoutId,
     // Store intermediate values with defaults
     trace_domain_size: Felt,
     point: Felt,
     trace_generator: Felt,
-    public_memory_prod_ratio: Felt,
-    diluted_prod: Felt,
-    pedersen_points_x: Felt,
-    pedersen_points_y: Felt,
-    poseidon_keys: (Felt, Felt, Felt, Felt, Felt), // (key0, key1, key2, partial_key0, partial_key1)
-    diluted_check_interaction_alpha: Felt,
-    diluted_check_interaction_z: Felt,
-    diluted_check_permutation_interaction_elm: Felt,
-    range_check16_perm_interaction_elm: Felt,
-    memory_multi_column_perm_hash_interaction_elm0: Felt,
-    memory_multi_column_perm_perm_interaction_elm: Felt,
+    interaction_elements: InteractionElements,
+    values: ComputedValues,
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EvalCompositionStep {
@@ -57,20 +45,12 @@ impl EvalCompositionPolynomial {
     pub fn new() -> Self {
         Self {
             step: EvalCompositionStep::CollectMaskValues,
+            layout: LayoutId::RecursiveWithPoseidon,
             trace_domain_size: Felt::ZERO,
             point: Felt::ZERO,
             trace_generator: Felt::ZERO,
-            public_memory_prod_ratio: Felt::ZERO,
-            diluted_prod: Felt::ZERO,
-            pedersen_points_x: Felt::ZERO,
-            pedersen_points_y: Felt::ZERO,
-            poseidon_keys: (Felt::ZERO, Felt::ZERO, Felt::ZERO, Felt::ZERO, Felt::ZERO),
-            diluted_check_interaction_alpha: Felt::ZERO,
-            diluted_check_interaction_z: Felt::ZERO,
-            diluted_check_permutation_interaction_elm: Felt::ZERO,
-            range_check16_perm_interaction_elm: Felt::ZERO,
-            memory_multi_column_perm_hash_interaction_elm0: Felt::ZERO,
-            memory_multi_column_perm_perm_interaction_elm: Felt::ZERO,
+            interaction_elements: InteractionElements::default(),
+            values: ComputedValues::default(),
         }
     }
 }
@@ -85,7 +65,7 @@ impl Executable for EvalCompositionPolynomial {
     fn execute<T: BidirectionalStack + ProofData + StarkCommitmentTrait>(
         &mut self,
         stack: &mut T,
-    ) -> Vec<Vec<u8>> {
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.step {
             EvalCompositionStep::CollectMaskValues => {
                 // Get parameters from stack
@@ -98,209 +78,138 @@ impl Executable for EvalCompositionPolynomial {
 
                 let stark_commitment =
                     stack.get_stark_commitment_mut::<StarkCommitment<InteractionElements>>();
-
-                self.memory_multi_column_perm_perm_interaction_elm = stark_commitment
-                    .traces
-                    .interaction_elements
-                    .memory_multi_column_perm_perm_interaction_elm;
-                self.memory_multi_column_perm_hash_interaction_elm0 = stark_commitment
-                    .traces
-                    .interaction_elements
-                    .memory_multi_column_perm_hash_interaction_elm0;
-                self.range_check16_perm_interaction_elm = stark_commitment
-                    .traces
-                    .interaction_elements
-                    .range_check16_perm_interaction_elm;
-                self.diluted_check_permutation_interaction_elm = stark_commitment
-                    .traces
-                    .interaction_elements
-                    .diluted_check_permutation_interaction_elm;
-                self.diluted_check_interaction_z = stark_commitment
-                    .traces
-                    .interaction_elements
-                    .diluted_check_interaction_z;
-                self.diluted_check_interaction_alpha = stark_commitment
-                    .traces
-                    .interaction_elements
-                    .diluted_check_interaction_alpha;
+                self.interaction_elements = stark_commitment.traces.interaction_elements.clone();
 
                 self.step = EvalCompositionStep::ComputePeriodicColumns;
-                vec![]
+                Ok(vec![])
             }
 
             EvalCompositionStep::ComputePeriodicColumns => {
                 let proof: &StarkProof = stack.get_proof_reference();
                 let public_input = &proof.public_input;
+                let (layout, params) = LayoutId::supported(public_input.layout)?;
+                self.layout = layout;
+                self.values.trace_length = self.trace_domain_size;
 
                 // Calculate public memory column size
-                let public_memory_column_size = self
-                    .trace_domain_size
-                    .field_div(&NonZeroFelt::try_from(Felt::from(PUBLIC_MEMORY_STEP)).unwrap());
-
-                self.public_memory_prod_ratio = public_input.get_public_memory_product_ratio(
-                    self.memory_multi_column_perm_perm_interaction_elm,
-                    self.memory_multi_column_perm_hash_interaction_elm0,
-                    public_memory_column_size,
+                let public_memory_column_size = self.trace_domain_size.field_div(
+                    &NonZeroFelt::try_from(Felt::from(params.public_memory_step))
+                        .map_err(|_| TaskError::InvalidConfig("Invalid public memory step"))?,
                 );
 
-                self.diluted_prod = get_diluted_product(
-                    DILUTED_N_BITS.into(),
-                    DILUTED_SPACING.into(),
-                    self.diluted_check_interaction_z,
-                    self.diluted_check_interaction_alpha,
-                );
+                self.values.public_memory_prod_ratio = public_input
+                    .get_public_memory_product_ratio(
+                        self.interaction_elements
+                            .memory_multi_column_perm_perm_interaction_elm,
+                        self.interaction_elements
+                            .memory_multi_column_perm_hash_interaction_elm0,
+                        public_memory_column_size,
+                    )?;
+
+                if params.has_diluted_pool {
+                    self.values.diluted_prod = get_diluted_product(
+                        DILUTED_N_BITS.into(),
+                        DILUTED_SPACING.into(),
+                        self.interaction_elements.diluted_check_interaction_z,
+                        self.interaction_elements.diluted_check_interaction_alpha,
+                    );
+                }
 
-                // Get proof data to access public input
-                let proof: &StarkProof = stack.get_proof_reference();
-                let public_input = &proof.public_input;
                 // Calculate n_steps
                 let n_steps = FELT_2.pow_felt(&public_input.log_n_steps);
 
                 // Calculate pedersen points
                 let n_pedersen_hash_copies = n_steps.field_div(
-                    &NonZeroFelt::try_from(
-                        Felt::from(PEDERSEN_BUILTIN_RATIO)
-                            * Felt::from(PEDERSEN_BUILTIN_REPETITIONS),
-                    )
-                    .unwrap(),
+                    &NonZeroFelt::try_from(Felt::from(params.pedersen_builtin_ratio))
+                        .map_err(|_| TaskError::InvalidConfig("Invalid builtin row ratio"))?,
                 );
-
                 let pedersen_point = self.point.pow_felt(&n_pedersen_hash_copies);
-                self.pedersen_points_x = eval_pedersen_x(pedersen_point);
-                self.pedersen_points_y = eval_pedersen_y(pedersen_point);
+                self.values.pedersen_points_x = eval_pedersen_x(pedersen_point);
+                self.values.pedersen_points_y = eval_pedersen_y(pedersen_point);
 
-                // Calculate poseidon points
-                let n_poseidon_copies =
-                    n_steps.field_div(&NonZeroFelt::try_from(Felt::from(POSEIDON_RATIO)).unwrap());
-                let poseidon_point = self.point.pow_felt(&n_poseidon_copies);
-                let poseidon_full_round_key0 =
-                    eval_poseidon_poseidon_full_round_key0(poseidon_point);
-                let poseidon_full_round_key1 =
-                    eval_poseidon_poseidon_full_round_key1(poseidon_point);
-                let poseidon_full_round_key2 =
-                    eval_poseidon_poseidon_full_round_key2(poseidon_point);
-                let poseidon_partial_round_key0 =
-                    eval_poseidon_poseidon_partial_round_key0(poseidon_point);
-                let poseidon_partial_round_key1 =
-                    eval_poseidon_poseidon_partial_round_key1(poseidon_point);
+                // Calculate ecdsa points
+                if let Some(ecdsa_builtin_ratio) = params.ecdsa_builtin_ratio {
+                    let n_ecdsa_signature_copies = n_steps.field_div(
+                        &NonZeroFelt::try_from(Felt::from(ecdsa_builtin_ratio))
+                            .map_err(|_| TaskError::InvalidConfig("Invalid builtin row ratio"))?,
+                    );
+                    let ecdsa_point = self.point.pow_felt(&n_ecdsa_signature_copies);
+                    self.values.ecdsa_generator_points_x = eval_ecdsa_x(ecdsa_point);
+                    self.values.ecdsa_generator_points_y = eval_ecdsa_y(ecdsa_point);
+                }
 
-                self.poseidon_keys = (
-                    poseidon_full_round_key0,
-                    poseidon_full_round_key1,
-                    poseidon_full_round_key2,
-                    poseidon_partial_round_key0,
-                    poseidon_partial_round_key1,
-                );
+                // Calculate poseidon points
+                if let Some(poseidon_ratio) = params.poseidon_ratio {
+                    let n_poseidon_copies = n_steps.field_div(
+                        &NonZeroFelt::try_from(Felt::from(poseidon_ratio))
+                            .map_err(|_| TaskError::InvalidConfig("Invalid builtin row ratio"))?,
+                    );
+                    let poseidon_point = self.point.pow_felt(&n_poseidon_copies);
+                    self.values.poseidon_full_round_key0 =
+                        eval_poseidon_poseidon_full_round_key0(poseidon_point);
+                    self.values.poseidon_full_round_key1 =
+                        eval_poseidon_poseidon_full_round_key1(poseidon_point);
+                    self.values.poseidon_full_round_key2 =
+                        eval_poseidon_poseidon_full_round_key2(poseidon_point);
+                    self.values.poseidon_partial_round_key0 =
+                        eval_poseidon_poseidon_partial_round_key0(poseidon_point);
+                    self.values.poseidon_partial_round_key1 =
+                        eval_poseidon_poseidon_partial_round_key1(poseidon_point);
+                }
 
                 self.step = EvalCompositionStep::EvalPolynomial;
-                vec![]
+                Ok(vec![])
             }
 
             EvalCompositionStep::EvalPolynomial => {
-                // Get proof data to access interaction elements and public input
+                // Get proof data to access public input
                 let proof: &StarkProof = stack.get_proof_reference();
                 let public_input = &proof.public_input;
-
-                // Get all the computed values
-                let (
-                    poseidon_key0,
-                    poseidon_key1,
-                    poseidon_key2,
-                    poseidon_partial_key0,
-                    poseidon_partial_key1,
-                ) = self.poseidon_keys;
-
-                // Create GlobalValues structure
-                let global_values = GlobalValues {
-                    trace_length: self.trace_domain_size,
-                    initial_pc: public_input
-                        .segments
-                        .get(segments::PROGRAM)
-                        .unwrap()
-                        .begin_addr,
-                    final_pc: public_input
-                        .segments
-                        .get(segments::PROGRAM)
-                        .unwrap()
-                        .stop_ptr,
-                    initial_ap: public_input
-                        .segments
-                        .get(segments::EXECUTION)
-                        .unwrap()
-                        .begin_addr,
-                    final_ap: public_input
-                        .segments
-                        .get(segments::EXECUTION)
-                        .unwrap()
-                        .stop_ptr,
-                    initial_pedersen_addr: public_input
-                        .segments
-                        .get(segments::PEDERSEN)
-                        .unwrap()
-                        .begin_addr,
-                    initial_range_check_addr: public_input
-                        .segments
-                        .get(segments::RANGE_CHECK)
-                        .unwrap()
-                        .begin_addr,
-                    initial_bitwise_addr: public_input
-                        .segments
-                        .get(segments::BITWISE)
-                        .unwrap()
-                        .begin_addr,
-                    initial_poseidon_addr: public_input
-                        .segments
-                        .get(segments::POSEIDON)
-                        .unwrap()
-                        .begin_addr,
-                    range_check_min: public_input.range_check_min,
-                    range_check_max: public_input.range_check_max,
-                    offset_size: FELT_65536,
-                    half_offset_size: FELT_32768,
-                    pedersen_shift_point: utils::global_values::EcPoint {
-                        x: SHIFT_POINT_X,
-                        y: SHIFT_POINT_Y,
-                    },
-                    pedersen_points_x: self.pedersen_points_x,
-                    pedersen_points_y: self.pedersen_points_y,
-                    poseidon_poseidon_full_round_key0: poseidon_key0,
-                    poseidon_poseidon_full_round_key1: poseidon_key1,
-                    poseidon_poseidon_full_round_key2: poseidon_key2,
-                    poseidon_poseidon_partial_round_key0: poseidon_partial_key0,
-                    poseidon_poseidon_partial_round_key1: poseidon_partial_key1,
-                    memory_multi_column_perm_perm_interaction_elm: self
-                        .memory_multi_column_perm_perm_interaction_elm,
-                    memory_multi_column_perm_hash_interaction_elm0: self
-                        .memory_multi_column_perm_hash_interaction_elm0,
-                    range_check16_perm_interaction_elm: self.range_check16_perm_interaction_elm,
-                    diluted_check_permutation_interaction_elm: self
-                        .diluted_check_permutation_interaction_elm,
-                    diluted_check_interaction_z: self.diluted_check_interaction_z,
-                    diluted_check_interaction_alpha: self.diluted_check_interaction_alpha,
-                    memory_multi_column_perm_perm_public_memory_prod: self.public_memory_prod_ratio,
-                    range_check16_perm_public_memory_prod: FELT_1,
-                    diluted_check_first_elm: FELT_0,
-                    diluted_check_permutation_public_memory_prod: FELT_1,
-                    diluted_check_final_cum_val: self.diluted_prod,
-                };
+                let interaction_elements = &self.interaction_elements;
 
                 // Set global values in the preallocated location in BidirectionalStackAccount
-                stack.set_global_values(global_values);
+                match self.layout {
+                    LayoutId::Recursive => stack.set_global_values(recursive::global_values(
+                        public_input,
+                        interaction_elements,
+                        &self.values,
+                    )),
+                    LayoutId::RecursiveWithPoseidon => {
+                        stack.set_global_values(recursive_with_poseidon::global_values(
+                            public_input,
+                            interaction_elements,
+                            &self.values,
+                        ))
+                    }
+                    LayoutId::Small => stack.set_global_values(small::global_values(
+                        public_input,
+                        interaction_elements,
+                        &self.values,
+                    )),
+                    LayoutId::Starknet => stack.set_global_values(starknet::global_values(
+                        public_input,
+                        interaction_elements,
+                        &self.values,
+                    )),
+                    _ => return Err(TaskError::InvalidPublicInput("Unsupported layout")),
+                }
 
                 // Push parameters for EvalCompositionPolynomialInner
                 stack
                     .push_front(&self.trace_generator.to_bytes_be())
-                    .unwrap();
-                stack.push_front(&self.point.to_bytes_be()).unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&self.point.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = EvalCompositionStep::Done;
 
-                vec![EvalCompositionPolynomialInner::new().to_vec_with_type_tag()]
+                Ok(vec![
+                    EvalCompositionPolynomialInner::new().to_vec_with_type_tag()
+                ])
             }
 
-            EvalCompositionStep::Done => {
-                vec![]
-            }
+            EvalCompositionStep::Done => Ok(vec![]),
         }
     }
 
@@ -6,8 +6,8 @@ use crate::swiftness::transcript::TranscriptRandomFelt;
 use felt::Felt;
 use utils::global_values::InteractionElements;
 use utils::{
-    impl_type_identifiable, BidirectionalStack, Executable, ProofData, StarkCommitmentTrait,
-    TypeIdentifiable,
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData,
+    StarkCommitmentTrait, TaskError, TypeIdentifiable,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,14 +50,21 @@ impl Executable for FriCommit {
     fn execute<T: BidirectionalStack + ProofData + StarkCommitmentTrait>(
         &mut self,
         stack: &mut T,
-    ) -> Vec<Vec<u8>> {
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match &self.step {
             FriCommitStep::Init => {
                 let proof: &StarkProof = stack.get_proof_reference();
                 let fri_config = &proof.config.fri;
 
-                self.n_layers = fri_config.n_layers.to_biguint().try_into().unwrap();
-                assert!(self.n_layers > 0, "Invalid n_layers value");
+                self.n_layers = fri_config
+                    .n_layers
+                    .to_biguint()
+                    .try_into()
+                    .map_err(|_| TaskError::InvalidConfig("Invalid n_layers value"))?;
+                ensure(
+                    self.n_layers > 0,
+                    TaskError::InvalidConfig("Invalid n_layers value"),
+                )?;
 
                 let transcript_counter = Felt::from_bytes_be_slice(stack.borrow_front());
                 stack.pop_front();
@@ -74,7 +81,7 @@ impl Executable for FriCommit {
                     self.step = FriCommitStep::ProcessInnerLayer(0);
                 }
 
-                vec![]
+                Ok(vec![])
             }
 
             FriCommitStep::ProcessInnerLayer(layer_idx) => {
@@ -82,23 +89,23 @@ impl Executable for FriCommit {
                 // Check if we've processed all inner layers (n_layers - 1 total)
                 if layer_idx >= self.n_layers as usize - 1 {
                     self.step = FriCommitStep::ReadLastLayerCoefficients;
-                    vec![]
+                    Ok(vec![])
                 } else {
                     let (stark_commitment, proof) = stack.get_stark_commitment_and_proof_mut::<StarkCommitment<InteractionElements>, StarkProof>();
 
-                    let inner_layer = proof
+                    let inner_layer = *proof
                         .unsent_commitment
                         .fri
                         .inner_layers
                         .get(layer_idx)
-                        .unwrap();
+                        .ok_or(TaskError::InvalidWitness("Missing FRI layer commitments"))?;
                     let inner_layer_commitment = CommitmentTable {
                         config: ConfigTable::default(),
                         vector_commitment:
                             crate::swiftness::commitment::vector::types::Commitment {
                                 config:
                                     crate::swiftness::commitment::vector::config::Config::default(),
-                                commitment_hash: *inner_layer,
+                                commitment_hash: inner_layer,
                             },
                     };
                     stark_commitment
@@ -106,26 +113,17 @@ impl Executable for FriCommit {
                         .inner_layers
                         .push(inner_layer_commitment);
 
-                    let proof: &StarkProof = stack.get_proof_reference();
                     stack
-                        .push_front(
-                            &proof
-                                .unsent_commitment
-                                .fri
-                                .inner_layers
-                                .get(layer_idx)
-                                .unwrap()
-                                .to_bytes_be(),
-                        )
-                        .unwrap();
+                        .push_front(&inner_layer.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
 
                     stack
                         .push_front(&self.current_transcript_digest.to_bytes_be())
-                        .unwrap();
+                        .map_err(|_| TaskError::StackOverflow)?;
 
                     self.step = FriCommitStep::GenerateEvalPoint(layer_idx);
 
-                    vec![TableCommit::new().to_vec_with_type_tag()]
+                    Ok(vec![TableCommit::new().to_vec_with_type_tag()])
                 }
             }
 
@@ -142,7 +140,9 @@ impl Executable for FriCommit {
 
                 self.step = FriCommitStep::CollectEvalPoint(layer_idx);
 
-                vec![TranscriptRandomFelt::new(table_digest, table_counter).to_vec_with_type_tag()]
+                Ok(vec![
+                    TranscriptRandomFelt::new(table_digest, table_counter).to_vec_with_type_tag()
+                ])
             }
 
             FriCommitStep::CollectEvalPoint(layer_idx) => {
@@ -158,10 +158,10 @@ impl Executable for FriCommit {
                 stark_commitment.fri.eval_points.push(eval_point);
 
                 self.current_transcript_counter = updated_counter;
-                // stack.push_front(&eval_point.to_bytes_be()).unwrap();
+                // stack.push_front(&eval_point.to_bytes_be()).map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = FriCommitStep::ProcessInnerLayer(layer_idx + 1);
-                vec![]
+                Ok(vec![])
             }
 
             FriCommitStep::ReadLastLayerCoefficients => {
@@ -170,35 +170,31 @@ impl Executable for FriCommit {
 
                 let expected_len =
                     Felt::TWO.pow_felt(&proof.config.fri.log_last_layer_degree_bound);
-                assert!(
+                ensure(
                     expected_len == last_layer_coefficients.len().into(),
-                    "Invalid last layer coefficients length"
-                );
+                    TaskError::InvalidWitness("Invalid last layer coefficients length"),
+                )?;
 
                 let stark_commitment =
                     stack.get_stark_commitment_mut::<StarkCommitment<InteractionElements>>();
 
-                //this might overflow solana stack because to_vec() creates a new vector
-                stark_commitment.fri.last_layer_coefficients =
-                    last_layer_coefficients.as_slice().to_vec();
+                stark_commitment.fri.last_layer_coefficients = last_layer_coefficients;
 
                 TranscriptReadFeltVector::push_input(
                     self.current_transcript_digest,
                     last_layer_coefficients.as_slice(),
                     stack,
-                );
+                )?;
 
                 self.step = FriCommitStep::Done;
 
-                vec![
-                    TranscriptReadFeltVector::new(last_layer_coefficients.as_slice().len())
-                        .to_vec_with_type_tag(),
-                ]
+                Ok(vec![TranscriptReadFeltVector::new(
+                    last_layer_coefficients.as_slice().len(),
+                )
+                .to_vec_with_type_tag()])
             }
 
-            FriCommitStep::Done => {
-                vec![]
-            }
+            FriCommitStep::Done => Ok(vec![]),
         }
     }
 
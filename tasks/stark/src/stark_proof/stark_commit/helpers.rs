@@ -1,12 +1,11 @@
 use felt::Felt;
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, ProofData, TypeIdentifiable};
+use utils::{
+    impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError, TypeIdentifiable,
+};
 
 pub const DILUTED_N_BITS: u32 = 16;
 pub const DILUTED_SPACING: u32 = 4;
 pub const FELT_2: Felt = Felt::from_hex_unchecked("0x2");
-pub const PEDERSEN_BUILTIN_RATIO: u32 = 256;
-pub const PEDERSEN_BUILTIN_REPETITIONS: u32 = 1;
-pub const POSEIDON_RATIO: u32 = 64;
 
 // PowersArray task - generates array of powers [1, alpha, alpha^2, ..., alpha^(n-1)]
 #[repr(C)]
@@ -24,7 +23,10 @@ impl PowersArray {
 }
 
 impl Executable for PowersArray {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         if self.current == 0 {
             // First iteration - get initial value and alpha
             let initial = Felt::from_bytes_be_slice(stack.borrow_front());
@@ -32,13 +34,19 @@ impl Executable for PowersArray {
             let alpha = Felt::from_bytes_be_slice(stack.borrow_front());
             stack.pop_front();
 
-            stack.push_front(&initial.to_bytes_be()).unwrap();
+            stack
+                .push_front(&initial.to_bytes_be())
+                .map_err(|_| TaskError::StackOverflow)?;
 
             let next_value = initial * alpha;
 
             // Keep alpha on stack for next iteration
-            stack.push_front(&alpha.to_bytes_be()).unwrap();
-            stack.push_front(&next_value.to_bytes_be()).unwrap();
+            stack
+                .push_front(&alpha.to_bytes_be())
+                .map_err(|_| TaskError::StackOverflow)?;
+            stack
+                .push_front(&next_value.to_bytes_be())
+                .map_err(|_| TaskError::StackOverflow)?;
 
             self.current = 1;
         } else if self.current < self.count {
@@ -48,19 +56,25 @@ impl Executable for PowersArray {
             let alpha = Felt::from_bytes_be_slice(stack.borrow_front());
             stack.pop_front();
             // Push current value to results
-            stack.push_front(&current_value.to_bytes_be()).unwrap();
+            stack
+                .push_front(&current_value.to_bytes_be())
+                .map_err(|_| TaskError::StackOverflow)?;
 
             // Calculate next value for next iteration
             let next_value = current_value * alpha;
             if self.current + 1 < self.count {
-                stack.push_front(&alpha.to_bytes_be()).unwrap();
-                stack.push_front(&next_value.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&alpha.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&next_value.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
             }
 
             self.current += 1;
         }
 
-        vec![]
+        Ok(vec![])
     }
 
     fn is_finished(&mut self) -> bool {
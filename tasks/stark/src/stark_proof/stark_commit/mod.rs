@@ -8,8 +8,7 @@ pub mod table_commit;
 pub mod traces_commit;
 pub mod verify_oods;
 
-use crate::swiftness::air::recursive_with_poseidon::Layout;
-use crate::swiftness::air::recursive_with_poseidon::LayoutTrait;
+use crate::swiftness::air::layout::LayoutId;
 use crate::swiftness::commitment::table::config::Config as ConfigTable;
 use crate::swiftness::commitment::table::types::Commitment as CommitmentTable;
 use crate::swiftness::stark::types::StarkCommitment;
@@ -19,12 +18,15 @@ use felt::Felt;
 use utils::global_values::InteractionElements;
 use utils::ProofData;
 use utils::StarkCommitmentTrait;
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, TypeIdentifiable};
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, LayoutGlobalValues, TaskError,
+    TypeIdentifiable,
+};
 
 pub use self::eval_composition_polynomial::EvalCompositionPolynomial;
 pub use self::fri_commit::FriCommit;
 pub use self::helpers::PowersArray;
-pub use self::proof_of_work::{ComputeHash, ProofOfWork, UpdateTranscriptU64};
+pub use self::proof_of_work::{Blake2sHash, ComputeHash, ProofOfWork, UpdateTranscriptU64};
 pub use self::table_commit::TableCommit;
 pub use self::traces_commit::{GenerateInteractionElements, TracesCommit, VectorCommit};
 pub use self::verify_oods::VerifyOods;
@@ -52,6 +54,7 @@ pub struct StarkCommit {
     step: StarkCommitStep,
     traces_coefficients_count: u32,
     oods_coefficients_count: u32,
+    interaction_elements_count: u32,
     current_transcript_digest: Felt,
     current_transcript_counter: Felt,
     oods_point: Felt,
@@ -73,6 +76,7 @@ impl StarkCommit {
             step: StarkCommitStep::Init,
             traces_coefficients_count: 0,
             oods_coefficients_count: 0,
+            interaction_elements_count: 0,
             current_transcript_digest: Felt::ZERO,
             current_transcript_counter: Felt::ZERO,
             oods_point: Felt::ZERO,
@@ -98,17 +102,18 @@ impl Executable for StarkCommit {
     fn execute<T: BidirectionalStack + ProofData + StarkCommitmentTrait>(
         &mut self,
         stack: &mut T,
-    ) -> Vec<Vec<u8>> {
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.step {
             StarkCommitStep::Init => {
-                // Get Layout::N_CONSTRAINTS and calculate counts
-                // Assuming these are available through the proof or as constants
-                self.traces_coefficients_count = Layout::N_CONSTRAINTS as u32;
-                self.oods_coefficients_count =
-                    (Layout::MASK_SIZE + Layout::CONSTRAINT_DEGREE) as u32;
+                // The coefficient counts are the ones of the layout in the public input
+                let proof: &StarkProof = stack.get_proof_reference();
+                let (_, params) = LayoutId::supported(proof.public_input.layout)?;
+                self.traces_coefficients_count = params.n_constraints as u32;
+                self.oods_coefficients_count = params.oods_values_size() as u32;
+                self.interaction_elements_count = params.n_interaction_elements;
 
                 self.step = StarkCommitStep::TracesCommit;
-                vec![]
+                Ok(vec![])
             }
 
             StarkCommitStep::TracesCommit => {
@@ -144,12 +149,25 @@ impl Executable for StarkCommit {
                     .vector_commitment
                     .commitment_hash = unsent_commitment.composition;
 
-                stark_commitment.oods_values =
-                    proof.unsent_commitment.oods_values.as_slice().to_vec();
+                stark_commitment.oods_values = proof.unsent_commitment.oods_values;
+
+                // The OODS evaluations read the mask values from the account.
+                let (proof, _, _, account_oods_values, _, _, _) =
+                    stack.get_proof_data_references::<StarkProof, LayoutGlobalValues<T>>();
+                let oods_values = proof.unsent_commitment.oods_values.as_slice();
+                ensure(
+                    oods_values.len() == self.oods_coefficients_count as usize,
+                    TaskError::InvalidWitness("Invalid number of OODS values"),
+                )?;
+                account_oods_values[..oods_values.len()].copy_from_slice(oods_values);
 
                 self.step = StarkCommitStep::GenerateCompositionAlpha;
 
-                vec![TracesCommit::new(initial_transcript_digest).to_vec_with_type_tag()]
+                Ok(vec![TracesCommit::new(
+                    initial_transcript_digest,
+                    self.interaction_elements_count,
+                )
+                .to_vec_with_type_tag()])
             }
 
             StarkCommitStep::GenerateCompositionAlpha => {
@@ -163,10 +181,11 @@ impl Executable for StarkCommit {
 
                 self.step = StarkCommitStep::GenerateTracesCoefficients;
 
-                vec![
-                    TranscriptRandomFelt::new(transcript_digest, transcript_counter)
-                        .to_vec_with_type_tag(),
-                ]
+                Ok(vec![TranscriptRandomFelt::new(
+                    transcript_digest,
+                    transcript_counter,
+                )
+                .to_vec_with_type_tag()])
             }
 
             StarkCommitStep::GenerateTracesCoefficients => {
@@ -180,13 +199,19 @@ impl Executable for StarkCommit {
                 self.current_transcript_counter = updated_counter;
 
                 // Store values for PowersArray: (initial=ONE, alpha=composition_alpha)
-                stack.push_front(&composition_alpha.to_bytes_be()).unwrap();
-                stack.push_front(&Felt::ONE.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&composition_alpha.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&Felt::ONE.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = StarkCommitStep::CompositionCommit;
 
                 // Return PowersArray task to generate coefficients
-                vec![PowersArray::new(self.traces_coefficients_count).to_vec_with_type_tag()]
+                Ok(vec![
+                    PowersArray::new(self.traces_coefficients_count).to_vec_with_type_tag()
+                ])
             }
 
             StarkCommitStep::CompositionCommit => {
@@ -200,13 +225,13 @@ impl Executable for StarkCommit {
                 let proof: &StarkProof = stack.get_proof_reference();
                 stack
                     .push_front(&proof.unsent_commitment.composition.to_bytes_be())
-                    .unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
                 stack
                     .push_front(&self.current_transcript_digest.to_bytes_be())
-                    .unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = StarkCommitStep::GenerateInteractionAfterComposition;
-                vec![TableCommit::new().to_vec_with_type_tag()]
+                Ok(vec![TableCommit::new().to_vec_with_type_tag()])
             }
 
             StarkCommitStep::GenerateInteractionAfterComposition => {
@@ -223,10 +248,11 @@ impl Executable for StarkCommit {
                 self.step = StarkCommitStep::ReadOodsValues;
 
                 // Use TranscriptRandomFelt to generate interaction_after_composition
-                vec![
-                    TranscriptRandomFelt::new(transcript_digest, transcript_counter)
-                        .to_vec_with_type_tag(),
-                ]
+                Ok(vec![TranscriptRandomFelt::new(
+                    transcript_digest,
+                    transcript_counter,
+                )
+                .to_vec_with_type_tag()])
             }
 
             StarkCommitStep::ReadOodsValues => {
@@ -252,10 +278,12 @@ impl Executable for StarkCommit {
                     self.current_transcript_digest,
                     &oods_values,
                     stack,
-                );
+                )?;
 
                 self.step = StarkCommitStep::VerifyOods;
-                vec![TranscriptReadFeltVector::new(oods_values.len()).to_vec_with_type_tag()]
+                Ok(vec![
+                    TranscriptReadFeltVector::new(oods_values.len()).to_vec_with_type_tag()
+                ])
             }
 
             StarkCommitStep::VerifyOods => {
@@ -269,25 +297,27 @@ impl Executable for StarkCommit {
 
                 stack
                     .push_front(&self.trace_domain_size.to_bytes_be())
-                    .unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
                 stack
                     .push_front(&self.trace_generator.to_bytes_be())
-                    .unwrap();
-                stack.push_front(&self.oods_point.to_bytes_be()).unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&self.oods_point.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = StarkCommitStep::GenerateOodsAlpha;
 
-                vec![VerifyOods::new().to_vec_with_type_tag()]
+                Ok(vec![VerifyOods::new().to_vec_with_type_tag()])
             }
 
             StarkCommitStep::GenerateOodsAlpha => {
                 self.step = StarkCommitStep::GenerateOodsCoefficients;
 
-                vec![TranscriptRandomFelt::new(
+                Ok(vec![TranscriptRandomFelt::new(
                     self.current_transcript_digest,
                     self.current_transcript_counter,
                 )
-                .to_vec_with_type_tag()]
+                .to_vec_with_type_tag()])
             }
 
             StarkCommitStep::GenerateOodsCoefficients => {
@@ -299,12 +329,18 @@ impl Executable for StarkCommit {
                 self.current_transcript_counter = updated_counter;
 
                 // Store values for PowersArray: (initial=ONE, alpha=oods_alpha)
-                stack.push_front(&oods_alpha.to_bytes_be()).unwrap();
-                stack.push_front(&Felt::ONE.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&oods_alpha.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&Felt::ONE.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = StarkCommitStep::FriCommit;
 
-                vec![PowersArray::new(self.oods_coefficients_count).to_vec_with_type_tag()]
+                Ok(vec![
+                    PowersArray::new(self.oods_coefficients_count).to_vec_with_type_tag()
+                ])
             }
 
             StarkCommitStep::FriCommit => {
@@ -320,17 +356,20 @@ impl Executable for StarkCommit {
 
                 let stark_commitment =
                     stack.get_stark_commitment_mut::<StarkCommitment<InteractionElements>>();
-                stark_commitment.interaction_after_oods.reverse();
+                stark_commitment
+                    .interaction_after_oods
+                    .as_slice_mut()
+                    .reverse();
 
                 stack
                     .push_front(&self.current_transcript_digest.to_bytes_be())
-                    .unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
                 stack
                     .push_front(&self.current_transcript_counter.to_bytes_be())
-                    .unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = StarkCommitStep::ProofOfWork;
-                vec![FriCommit::new().to_vec_with_type_tag()]
+                Ok(vec![FriCommit::new().to_vec_with_type_tag()])
             }
 
             StarkCommitStep::ProofOfWork => {
@@ -341,23 +380,18 @@ impl Executable for StarkCommit {
 
                 stack
                     .push_front(&self.current_transcript_digest.to_bytes_be())
-                    .unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = StarkCommitStep::Output;
-                vec![ProofOfWork::new().to_vec_with_type_tag()]
+                Ok(vec![ProofOfWork::new().to_vec_with_type_tag()])
             }
             StarkCommitStep::Output => {
-                let _reseted_counter = Felt::from_bytes_be_slice(stack.borrow_front());
-                stack.pop_front();
-                let _digest = Felt::from_bytes_be_slice(stack.borrow_front());
-                stack.pop_front();
-
+                // The transcript state after the proof of work, [counter, digest], is left on
+                // the stack: the query phase samples the queries from it.
                 self.step = StarkCommitStep::Done;
-                vec![]
-            }
-            StarkCommitStep::Done => {
-                vec![]
+                Ok(vec![])
             }
+            StarkCommitStep::Done => Ok(vec![]),
         }
     }
 
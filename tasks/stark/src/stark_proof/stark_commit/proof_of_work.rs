@@ -1,7 +1,12 @@
+use crate::swiftness::commitment::hash::digest_parts;
+use crate::swiftness::stark::config::Hasher;
 use crate::swiftness::stark::types::StarkProof;
 use crate::swiftness::transcript::TranscriptReadFelt;
 use felt::Felt;
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, ProofData, TypeIdentifiable};
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError,
+    TypeIdentifiable,
+};
 
 // Constants
 pub const MAGIC: u64 = 0x0123456789abcded;
@@ -21,6 +26,7 @@ pub enum ProofOfWorkStep {
 pub struct ProofOfWork {
     step: ProofOfWorkStep,
     n_bits: u8,
+    hasher: Hasher,
     nonce: u64,
     digest: Felt,
 }
@@ -32,10 +38,19 @@ impl ProofOfWork {
         Self {
             step: ProofOfWorkStep::PrepareInitialHash,
             n_bits: 0,
+            hasher: Hasher::Keccak256,
             nonce: 0,
             digest: Felt::ZERO,
         }
     }
+
+    // Hash task of the configured hasher over the input_length bytes on the front of the stack.
+    fn hash_task(&self, input_length: usize) -> Vec<u8> {
+        match self.hasher {
+            Hasher::Keccak256 => ComputeHash::new(input_length).to_vec_with_type_tag(),
+            Hasher::Blake2s256 => Blake2sHash::new(input_length).to_vec_with_type_tag(),
+        }
+    }
 }
 
 impl Default for ProofOfWork {
@@ -45,83 +60,103 @@ impl Default for ProofOfWork {
 }
 
 impl Executable for ProofOfWork {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.step {
             ProofOfWorkStep::PrepareInitialHash => {
                 let proof: &StarkProof = stack.get_proof_reference();
                 let config = &proof.config.proof_of_work;
 
                 self.n_bits = config.n_bits;
+                self.hasher = proof.verifier_config.hasher()?;
                 self.nonce = proof.unsent_commitment.proof_of_work.nonce;
 
                 // Get transcript digest
-                let digest_bytes: [u8; 32] = stack.borrow_front().try_into().unwrap();
+                let digest_bytes: [u8; 32] = stack
+                    .borrow_front()
+                    .try_into()
+                    .map_err(|_| TaskError::StackUnderflow)?;
                 stack.pop_front();
                 self.digest = Felt::from_bytes_be_slice(&digest_bytes);
 
-                stack.push_front(&[self.n_bits]).unwrap();
-                stack.push_front(&digest_bytes).unwrap();
-                stack.push_front(&MAGIC.to_be_bytes()).unwrap();
+                stack
+                    .push_front(&[self.n_bits])
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&digest_bytes)
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&MAGIC.to_be_bytes())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = ProofOfWorkStep::ComputeInitialHash;
 
-                vec![ComputeHash::new(41).to_vec_with_type_tag()]
+                Ok(vec![self.hash_task(41)])
             }
 
             ProofOfWorkStep::ComputeInitialHash => {
                 // Prepare data for final hash: init_hash || nonce
-                let init_hash: [u8; 32] = stack.borrow_front().try_into().unwrap();
+                let init_hash: [u8; 32] = stack
+                    .borrow_front()
+                    .try_into()
+                    .map_err(|_| TaskError::StackUnderflow)?;
                 stack.pop_front();
 
-                stack.push_front(&self.nonce.to_be_bytes()).unwrap();
-                stack.push_front(&init_hash).unwrap();
+                stack
+                    .push_front(&self.nonce.to_be_bytes())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&init_hash)
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = ProofOfWorkStep::ComputeFinalHash;
 
-                vec![ComputeHash::new(40).to_vec_with_type_tag()]
+                Ok(vec![self.hash_task(40)])
             }
 
             ProofOfWorkStep::ComputeFinalHash => {
                 // Final hash is now on stack
                 self.step = ProofOfWorkStep::VerifyWork;
-                vec![]
+                Ok(vec![])
             }
 
             ProofOfWorkStep::VerifyWork => {
-                let final_hash: [u8; 32] = stack.borrow_front().try_into().unwrap();
+                let final_hash: [u8; 32] = stack
+                    .borrow_front()
+                    .try_into()
+                    .map_err(|_| TaskError::StackUnderflow)?;
                 stack.pop_front();
-                println!("final_hash: {:?}", final_hash);
-                println!(
-                    "final_hash: {:?}",
-                    Felt::from_bytes_be_slice(final_hash.as_slice())
-                );
 
                 // Check first 16 bytes (128 bits)
                 let work_value = Felt::from_bytes_be_slice(&final_hash[0..16]);
                 let threshold = Felt::TWO.pow(128 - self.n_bits);
 
-                assert!(work_value < threshold, "Proof of work verification failed");
+                ensure(work_value < threshold, TaskError::PowInsufficient)?;
                 self.step = ProofOfWorkStep::UpdateTranscript;
-                vec![]
+                Ok(vec![])
             }
 
             ProofOfWorkStep::UpdateTranscript => {
-                stack.push_front(&self.digest.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&self.digest.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
                 // Update transcript with nonce
-                stack.push_front(&self.nonce.to_be_bytes()).unwrap();
+                stack
+                    .push_front(&self.nonce.to_be_bytes())
+                    .map_err(|_| TaskError::StackOverflow)?;
                 self.step = ProofOfWorkStep::CollectResults;
 
-                vec![UpdateTranscriptU64::new().to_vec_with_type_tag()]
+                Ok(vec![UpdateTranscriptU64::new().to_vec_with_type_tag()])
             }
             ProofOfWorkStep::CollectResults => {
                 self.step = ProofOfWorkStep::Done;
 
-                vec![]
+                Ok(vec![])
             }
 
-            ProofOfWorkStep::Done => {
-                vec![]
-            }
+            ProofOfWorkStep::Done => Ok(vec![]),
         }
     }
 
@@ -130,7 +165,7 @@ impl Executable for ProofOfWork {
     }
 }
 
-// Task for computing hash (Blake2s or Keccak)
+// Task for computing Keccak256 hash
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct ComputeHash {
@@ -150,31 +185,59 @@ impl ComputeHash {
 }
 
 impl Executable for ComputeHash {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
-        // Collect all input bytes directly from stack
-        let mut input_data = Vec::new();
-
-        // Read all data from stack until we have the required length
-        while input_data.len() < self.input_length {
-            let chunk = stack.borrow_front();
-            let remaining = self.input_length - input_data.len();
-            let to_read = remaining.min(chunk.len());
-            input_data.extend_from_slice(&chunk[0..to_read]);
-            stack.pop_front();
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        let hash = pop_hash_input(stack, Hasher::Keccak256, self.input_length)?;
+
+        // Push hash result (32 bytes)
+        stack
+            .push_front(&hash)
+            .map_err(|_| TaskError::StackOverflow)?;
+
+        self.processed = true;
+        Ok(vec![])
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.processed
+    }
+}
+
+// Task for computing Blake2s-256 hash
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct Blake2sHash {
+    input_length: usize,
+    processed: bool,
+}
+
+impl_type_identifiable!(Blake2sHash);
+
+impl Blake2sHash {
+    pub fn new(input_length: usize) -> Self {
+        Self {
+            input_length,
+            processed: false,
         }
+    }
+}
 
-        let hash_result = {
-            use sha3::{Digest, Keccak256};
-            let mut hasher = Keccak256::new();
-            hasher.update(&input_data);
-            hasher.finalize().to_vec()
-        };
+impl Executable for Blake2sHash {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        let hash = pop_hash_input(stack, Hasher::Blake2s256, self.input_length)?;
 
         // Push hash result (32 bytes)
-        stack.push_front(&hash_result).unwrap();
+        stack
+            .push_front(&hash)
+            .map_err(|_| TaskError::StackOverflow)?;
 
         self.processed = true;
-        vec![]
+        Ok(vec![])
     }
 
     fn is_finished(&mut self) -> bool {
@@ -182,6 +245,36 @@ impl Executable for ComputeHash {
     }
 }
 
+// Hashes input_length bytes directly from the frames on the front of the stack and pops exactly
+// those bytes. When the input ends inside a frame, the unread rest of that frame is pushed back.
+fn pop_hash_input<T: BidirectionalStack>(
+    stack: &mut T,
+    hasher: Hasher,
+    input_length: usize,
+) -> Result<[u8; 32], TaskError> {
+    let frames = stack.borrow_front_frames(input_length);
+    let hashed_length: usize = frames.iter().map(|frame| frame.len()).sum();
+    ensure(hashed_length == input_length, TaskError::StackUnderflow)?;
+    let hash = digest_parts(hasher, &frames);
+
+    let mut remaining = input_length;
+    while remaining > 0 {
+        let frame = stack.borrow_front();
+        if frame.len() <= remaining {
+            remaining -= frame.len();
+            stack.pop_front();
+        } else {
+            let rest = frame[remaining..].to_vec();
+            stack.pop_front();
+            stack
+                .push_front(&rest)
+                .map_err(|_| TaskError::StackOverflow)?;
+            remaining = 0;
+        }
+    }
+    Ok(hash)
+}
+
 // Task for updating transcript with u64
 #[derive(Debug, Clone, Copy)]
 #[repr(C)]
@@ -204,9 +297,15 @@ impl Default for UpdateTranscriptU64 {
 }
 
 impl Executable for UpdateTranscriptU64 {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         // Get nonce bytes from stack
-        let nonce_bytes: [u8; 8] = stack.borrow_front().try_into().unwrap();
+        let nonce_bytes: [u8; 8] = stack
+            .borrow_front()
+            .try_into()
+            .map_err(|_| TaskError::StackUnderflow)?;
         let nonce = u64::from_be_bytes(nonce_bytes);
         stack.pop_front();
 
@@ -217,10 +316,10 @@ impl Executable for UpdateTranscriptU64 {
         // Convert u64 to Felt and update transcript
         let nonce_felt = Felt::from(nonce);
 
-        TranscriptReadFelt::push_input(digest, nonce_felt, stack);
+        TranscriptReadFelt::push_input(digest, nonce_felt, stack)?;
 
         self.processed = true;
-        vec![TranscriptReadFelt::new().to_vec_with_type_tag()]
+        Ok(vec![TranscriptReadFelt::new().to_vec_with_type_tag()])
     }
 
     fn is_finished(&mut self) -> bool {
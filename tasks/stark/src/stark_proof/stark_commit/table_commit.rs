@@ -1,5 +1,7 @@
 use crate::stark_proof::stark_commit::traces_commit::VectorCommit;
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, ProofData, TypeIdentifiable};
+use utils::{
+    impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError, TypeIdentifiable,
+};
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct TableCommit {
@@ -21,9 +23,12 @@ impl Default for TableCommit {
 }
 
 impl Executable for TableCommit {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, _stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        _stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         self.processed = true;
-        vec![VectorCommit::new().to_vec_with_type_tag()]
+        Ok(vec![VectorCommit::new().to_vec_with_type_tag()])
     }
 
     fn is_finished(&mut self) -> bool {
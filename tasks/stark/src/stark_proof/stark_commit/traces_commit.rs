@@ -6,7 +6,9 @@ use crate::swiftness::stark::types::StarkCommitment;
 use felt::Felt;
 use utils::global_values::InteractionElements;
 use utils::StarkCommitmentTrait;
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, ProofData, TypeIdentifiable};
+use utils::{
+    impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError, TypeIdentifiable,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TracesCommitStep {
@@ -26,10 +28,10 @@ pub struct TracesCommit {
 impl_type_identifiable!(TracesCommit);
 
 impl TracesCommit {
-    pub fn new(digest: Felt) -> Self {
+    pub fn new(digest: Felt, interaction_elements_count: u32) -> Self {
         Self {
             step: TracesCommitStep::ReadOriginalCommitment,
-            interaction_elements_count: 6, // recursive_with_poseidon has 6 interaction elements
+            interaction_elements_count,
             current_element: 0,
             digest,
         }
@@ -38,7 +40,7 @@ impl TracesCommit {
 
 impl Default for TracesCommit {
     fn default() -> Self {
-        Self::new(Felt::ZERO)
+        Self::new(Felt::ZERO, 0)
     }
 }
 
@@ -46,7 +48,7 @@ impl Executable for TracesCommit {
     fn execute<T: BidirectionalStack + ProofData + StarkCommitmentTrait>(
         &mut self,
         stack: &mut T,
-    ) -> Vec<Vec<u8>> {
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.step {
             TracesCommitStep::ReadOriginalCommitment => {
                 let proof: &StarkProof = stack.get_proof_reference();
@@ -55,12 +57,14 @@ impl Executable for TracesCommit {
 
                 stack
                     .push_front(&unsent_commitment.original.to_bytes_be())
-                    .unwrap();
-                stack.push_front(&self.digest.to_bytes_be()).unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&self.digest.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = TracesCommitStep::GenerateInteractionElements;
 
-                vec![VectorCommit::new().to_vec_with_type_tag()]
+                Ok(vec![VectorCommit::new().to_vec_with_type_tag()])
             }
 
             TracesCommitStep::GenerateInteractionElements => {
@@ -69,27 +73,46 @@ impl Executable for TracesCommit {
                 let transcript_digest = Felt::from_bytes_be_slice(stack.borrow_front());
                 stack.pop_front();
 
-                stack.push_front(&transcript_counter.to_bytes_be()).unwrap();
-                stack.push_front(&transcript_digest.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&transcript_counter.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&transcript_digest.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.digest = transcript_digest;
 
                 self.step = TracesCommitStep::ReadInteractionCommitment;
-                vec![
-                    GenerateInteractionElements::new(self.interaction_elements_count)
-                        .to_vec_with_type_tag(),
-                ]
+                Ok(vec![GenerateInteractionElements::new(
+                    self.interaction_elements_count,
+                )
+                .to_vec_with_type_tag()])
             }
 
             TracesCommitStep::ReadInteractionCommitment => {
-                let diluted_check_interaction_alpha =
-                    Felt::from_bytes_be_slice(stack.borrow_front());
-                stack.pop_front();
-                let diluted_check_interaction_z = Felt::from_bytes_be_slice(stack.borrow_front());
-                stack.pop_front();
-                let diluted_check_permutation_interaction_elm =
-                    Felt::from_bytes_be_slice(stack.borrow_front());
-                stack.pop_front();
+                // Layouts without a diluted pool draw only the memory and range check elements
+                let (
+                    diluted_check_interaction_alpha,
+                    diluted_check_interaction_z,
+                    diluted_check_permutation_interaction_elm,
+                ) = if self.interaction_elements_count == 6 {
+                    let diluted_check_interaction_alpha =
+                        Felt::from_bytes_be_slice(stack.borrow_front());
+                    stack.pop_front();
+                    let diluted_check_interaction_z =
+                        Felt::from_bytes_be_slice(stack.borrow_front());
+                    stack.pop_front();
+                    let diluted_check_permutation_interaction_elm =
+                        Felt::from_bytes_be_slice(stack.borrow_front());
+                    stack.pop_front();
+                    (
+                        diluted_check_interaction_alpha,
+                        diluted_check_interaction_z,
+                        diluted_check_permutation_interaction_elm,
+                    )
+                } else {
+                    (Felt::ZERO, Felt::ZERO, Felt::ZERO)
+                };
                 let range_check16_perm_interaction_elm =
                     Felt::from_bytes_be_slice(stack.borrow_front());
                 stack.pop_front();
@@ -118,16 +141,16 @@ impl Executable for TracesCommit {
                 //for vector commit
                 stack
                     .push_front(&interaction_commitment.to_bytes_be())
-                    .unwrap();
-                stack.push_front(&self.digest.to_bytes_be()).unwrap();
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&self.digest.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = TracesCommitStep::Done;
-                vec![VectorCommit::new().to_vec_with_type_tag()]
+                Ok(vec![VectorCommit::new().to_vec_with_type_tag()])
             }
 
-            TracesCommitStep::Done => {
-                vec![]
-            }
+            TracesCommitStep::Done => Ok(vec![]),
         }
     }
 
@@ -164,7 +187,10 @@ impl GenerateInteractionElements {
 }
 
 impl Executable for GenerateInteractionElements {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.step {
             GenerateInteractionStep::GenerateHash => {
                 // Get transcript digest and counter from stack
@@ -174,14 +200,18 @@ impl Executable for GenerateInteractionElements {
                 stack.pop_front();
 
                 // Store transcript state for later restoration
-                stack.push_front(&transcript_counter.to_bytes_be()).unwrap();
-                stack.push_front(&transcript_digest.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&transcript_counter.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&transcript_digest.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.step = GenerateInteractionStep::ReadResult;
 
                 // Call PoseidonHash to generate random element
-                PoseidonHash::push_input(transcript_digest, transcript_counter, stack);
-                vec![PoseidonHash::new().to_vec_with_type_tag()]
+                PoseidonHash::push_input(transcript_digest, transcript_counter, stack)?;
+                Ok(vec![PoseidonHash::new().to_vec_with_type_tag()])
             }
 
             GenerateInteractionStep::ReadResult => {
@@ -196,7 +226,9 @@ impl Executable for GenerateInteractionElements {
                 stack.pop_front();
 
                 // Push generated element to result stack
-                stack.push_front(&hash_result.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&hash_result.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 // Update transcript counter for next iteration
                 let new_counter = transcript_counter + Felt::ONE;
@@ -204,12 +236,16 @@ impl Executable for GenerateInteractionElements {
                 self.current_element += 1;
 
                 if self.current_element < self.total_elements {
-                    stack.push_front(&new_counter.to_bytes_be()).unwrap();
-                    stack.push_front(&transcript_digest.to_bytes_be()).unwrap();
+                    stack
+                        .push_front(&new_counter.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                    stack
+                        .push_front(&transcript_digest.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
                     self.step = GenerateInteractionStep::GenerateHash;
                 }
 
-                vec![]
+                Ok(vec![])
             }
         }
     }
@@ -252,7 +288,10 @@ impl Default for VectorCommit {
 }
 
 impl Executable for VectorCommit {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.phase {
             VectorCommitPhase::CallPoseidonHashMany => {
                 let transcript_digest = Felt::from_bytes_be_slice(stack.borrow_front());
@@ -263,11 +302,11 @@ impl Executable for VectorCommit {
                 // Update transcript digest: hash(digest + 1, commitment)
                 let new_digest_input = transcript_digest + Felt::ONE;
 
-                PoseidonHashMany::push_input(&[new_digest_input, commitment], stack);
+                PoseidonHashMany::push_input(&[new_digest_input, commitment], stack)?;
 
                 self.phase = VectorCommitPhase::RestoreTranscriptState;
 
-                vec![PoseidonHashMany::new(2).to_vec_with_type_tag()]
+                Ok(vec![PoseidonHashMany::new(2).to_vec_with_type_tag()])
             }
             VectorCommitPhase::RestoreTranscriptState => {
                 let new_digest = Felt::from_bytes_be_slice(stack.borrow_front());
@@ -277,14 +316,16 @@ impl Executable for VectorCommit {
                 let transcript_counter = Felt::ZERO;
                 stack.pop_front();
 
-                stack.push_front(&new_digest.to_bytes_be()).unwrap();
-                stack.push_front(&transcript_counter.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&new_digest.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&transcript_counter.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
                 self.phase = VectorCommitPhase::Done;
-                vec![]
-            }
-            VectorCommitPhase::Done => {
-                vec![]
+                Ok(vec![])
             }
+            VectorCommitPhase::Done => Ok(vec![]),
         }
     }
 
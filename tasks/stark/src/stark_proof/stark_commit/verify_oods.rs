@@ -1,7 +1,10 @@
 use crate::stark_proof::stark_commit::eval_composition_polynomial::EvalCompositionPolynomial;
 use crate::swiftness::stark::types::StarkProof;
 use felt::Felt;
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, ProofData, TypeIdentifiable};
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError,
+    TypeIdentifiable,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VerifyOodsStep {
@@ -35,16 +38,21 @@ impl Default for VerifyOods {
 }
 
 impl Executable for VerifyOods {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.step {
             VerifyOodsStep::PrepareEvaluation => {
                 self.step = VerifyOodsStep::EvalCompositionPolynomial;
                 self.oods_point = Felt::from_bytes_be_slice(stack.borrow_front());
                 stack.pop_front();
 
-                stack.push_front(&self.oods_point.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&self.oods_point.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
-                vec![EvalCompositionPolynomial::new().to_vec_with_type_tag()]
+                Ok(vec![EvalCompositionPolynomial::new().to_vec_with_type_tag()])
             }
 
             VerifyOodsStep::EvalCompositionPolynomial => {
@@ -65,25 +73,24 @@ impl Executable for VerifyOods {
                 let claimed_composition = comp_value_0 + comp_value_1 * self.oods_point;
 
                 // Verify they match
-                assert!(
+                ensure(
                     composition_from_trace == claimed_composition,
-                    "OODS evaluation invalid: expected {:?}, got {:?}",
-                    claimed_composition,
-                    composition_from_trace
-                );
+                    TaskError::OodsMismatch {
+                        claimed: claimed_composition,
+                        computed: composition_from_trace,
+                    },
+                )?;
 
                 self.step = VerifyOodsStep::Done;
-                vec![]
+                Ok(vec![])
             }
 
             VerifyOodsStep::VerifyComposition => {
                 // This step is merged into EvalCompositionPolynomial for efficiency
-                vec![]
+                Ok(vec![])
             }
 
-            VerifyOodsStep::Done => {
-                vec![]
-            }
+            VerifyOodsStep::Done => Ok(vec![]),
         }
     }
 
@@ -0,0 +1,191 @@
+use crate::stark_proof::stark_commit::eval_oods_polynomial_inner::EvalOodsPolynomialInner;
+use crate::swiftness::air::domains::{bit_reverse_u64, FIELD_GENERATOR};
+use crate::swiftness::air::layout::LayoutId;
+use crate::swiftness::fri::formula::batch_inverse;
+use crate::swiftness::fri::types::FriLayerQuery;
+use crate::swiftness::stark::types::{QueryState, StarkCommitment, StarkProof};
+use felt::Felt;
+use utils::global_values::InteractionElements;
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, LayoutGlobalValues, ProofData,
+    StarkCommitmentTrait, TaskError, TypeIdentifiable,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalOodsBoundaryPolyAtPointsStep {
+    Init,
+    EvalQuery,
+    CollectQuery,
+    InvertPoints,
+    Done,
+}
+
+// Evaluates the DEEP composition polynomial at the point of every query, from the decommitted
+// trace and composition values. The results form the first layer of FRI, stored in
+// QueryState.fri_queries. Each EvalQuery step handles a single query, whose evaluation is done by
+// an EvalOodsPolynomialInner subtask; the trace generator powers are only computed for the first
+// one.
+#[repr(C)]
+pub struct EvalOodsBoundaryPolyAtPoints {
+    step: EvalOodsBoundaryPolyAtPointsStep,
+    log_eval_domain_size: u32,
+    query: u32,
+    eval_generator: Felt,
+    trace_generator: Felt,
+    oods_point: Felt,
+    point: Felt,
+}
+
+impl_type_identifiable!(EvalOodsBoundaryPolyAtPoints);
+
+impl EvalOodsBoundaryPolyAtPoints {
+    pub fn new(log_eval_domain_size: u32, eval_generator: Felt, trace_generator: Felt) -> Self {
+        Self {
+            step: EvalOodsBoundaryPolyAtPointsStep::Init,
+            log_eval_domain_size,
+            query: 0,
+            eval_generator,
+            trace_generator,
+            oods_point: Felt::ZERO,
+            point: Felt::ZERO,
+        }
+    }
+}
+
+impl Executable for EvalOodsBoundaryPolyAtPoints {
+    fn execute<T: BidirectionalStack + ProofData + StarkCommitmentTrait>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            EvalOodsBoundaryPolyAtPointsStep::Init => {
+                let stark_commitment =
+                    stack.get_stark_commitment::<StarkCommitment<InteractionElements>>();
+                self.oods_point = stark_commitment.interaction_after_composition;
+                let n_oods_values = stark_commitment.oods_values.len();
+                let n_queries = stack.get_queries::<QueryState>().queries.len();
+
+                // EvalOodsPolynomialInner reads the mask values and the coefficients from the
+                // account. They are copied one at a time, since both live in the account and a
+                // copy of the whole commitment vectors would not fit in the SBF stack frame.
+                for i in 0..n_oods_values {
+                    let stark_commitment =
+                        stack.get_stark_commitment::<StarkCommitment<InteractionElements>>();
+                    let oods_value = *stark_commitment.oods_values.at(i);
+                    let oods_coefficient = *stark_commitment.interaction_after_oods.at(i);
+                    let (_, _, _, account_oods_values, _, constraint_coefficients, _) =
+                        stack.get_proof_data_references::<StarkProof, LayoutGlobalValues<T>>();
+                    account_oods_values[i] = oods_value;
+                    constraint_coefficients[i] = oods_coefficient;
+                }
+
+                let proof: &StarkProof = stack.get_proof_reference();
+
+                let (_, params) = LayoutId::supported(proof.public_input.layout)?;
+                let witness = &proof.witness;
+                ensure(
+                    witness.traces_decommitment.original.values.len()
+                        == n_queries * params.num_columns_first as usize
+                        && witness.traces_decommitment.interaction.values.len()
+                            == n_queries * params.num_columns_second as usize
+                        && witness.composition_decommitment.values.len()
+                            == n_queries * params.constraint_degree,
+                    TaskError::InvalidWitness("Invalid decommitment length"),
+                )?;
+
+                stack.get_queries_mut::<QueryState>().fri_queries.flush();
+
+                self.step = EvalOodsBoundaryPolyAtPointsStep::EvalQuery;
+                Ok(vec![])
+            }
+            EvalOodsBoundaryPolyAtPointsStep::EvalQuery => {
+                let query = self.query as usize;
+                if query == stack.get_queries::<QueryState>().queries.len() {
+                    self.step = EvalOodsBoundaryPolyAtPointsStep::InvertPoints;
+                    return Ok(vec![]);
+                }
+                let index = *stack.get_queries::<QueryState>().queries.at(query);
+
+                let (proof, _, _, _, _, _, column_values) =
+                    stack.get_proof_data_references::<StarkProof, LayoutGlobalValues<T>>();
+                let (_, params) = LayoutId::supported(proof.public_input.layout)?;
+                let n_original = params.num_columns_first as usize;
+                let n_interaction = params.num_columns_second as usize;
+                let constraint_degree = params.constraint_degree;
+                let witness = &proof.witness;
+                column_values[..n_original].copy_from_slice(
+                    &witness.traces_decommitment.original.values.as_slice()
+                        [query * n_original..(query + 1) * n_original],
+                );
+                column_values[n_original..n_original + n_interaction].copy_from_slice(
+                    &witness.traces_decommitment.interaction.values.as_slice()
+                        [query * n_interaction..(query + 1) * n_interaction],
+                );
+                column_values[n_original + n_interaction..params.column_values_size()]
+                    .copy_from_slice(
+                        &witness.composition_decommitment.values.as_slice()
+                            [query * constraint_degree..(query + 1) * constraint_degree],
+                    );
+
+                // The evaluation domain is a coset of the group generated by eval_generator,
+                // shifted by the field generator, and the queries are in bit-reversed order.
+                let index: u64 = index
+                    .try_into()
+                    .map_err(|_| TaskError::InvalidWitness("Invalid query index"))?;
+                let exponent = bit_reverse_u64(index, self.log_eval_domain_size);
+                self.point = FIELD_GENERATOR * self.eval_generator.pow(exponent);
+
+                for value in [self.trace_generator, self.oods_point, self.point] {
+                    stack
+                        .push_front(&value.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                }
+
+                let inner = if query == 0 {
+                    EvalOodsPolynomialInner::new()
+                } else {
+                    EvalOodsPolynomialInner::with_cached_powers()
+                };
+
+                self.step = EvalOodsBoundaryPolyAtPointsStep::CollectQuery;
+                Ok(vec![inner.to_vec_with_type_tag()])
+            }
+            EvalOodsBoundaryPolyAtPointsStep::CollectQuery => {
+                let y_value = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+
+                let queries = stack.get_queries_mut::<QueryState>();
+                let index = *queries.queries.at(self.query as usize);
+                // The point is kept for now and inverted with the others in InvertPoints.
+                queries.fri_queries.push(FriLayerQuery {
+                    index,
+                    y_value,
+                    x_inv_value: self.point,
+                });
+                self.query += 1;
+
+                self.step = EvalOodsBoundaryPolyAtPointsStep::EvalQuery;
+                Ok(vec![])
+            }
+            EvalOodsBoundaryPolyAtPointsStep::InvertPoints => {
+                let fri_queries = &mut stack.get_queries_mut::<QueryState>().fri_queries;
+                let mut x_inv_values: Vec<Felt> =
+                    fri_queries.iter().map(|query| query.x_inv_value).collect();
+                batch_inverse(&mut x_inv_values)?;
+
+                // FRI works on the homogeneous group, so the coset shift is removed from the point.
+                for (query, x_inv) in fri_queries.as_slice_mut().iter_mut().zip(x_inv_values) {
+                    query.x_inv_value = FIELD_GENERATOR * x_inv;
+                }
+
+                self.step = EvalOodsBoundaryPolyAtPointsStep::Done;
+                Ok(vec![])
+            }
+            EvalOodsBoundaryPolyAtPointsStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == EvalOodsBoundaryPolyAtPointsStep::Done
+    }
+}
@@ -0,0 +1,221 @@
+use crate::stark_proof::stark_verify::table_decommit::{TableDecommit, TableDecommitTarget};
+use crate::stark_proof::stark_verify::verify_last_layer::VerifyLastLayer;
+use crate::swiftness::fri::formula::{fri_formula, FRI_GROUP};
+use crate::swiftness::fri::types::FriLayerQuery;
+use crate::swiftness::stark::types::{QueryState, StarkCommitment, StarkProof};
+use felt::{Felt, NonZeroFelt};
+use utils::global_values::InteractionElements;
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData,
+    StarkCommitmentTrait, TaskError, TypeIdentifiable,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FriVerifyStep {
+    Init,
+    Layer,
+    LastLayer,
+    Done,
+}
+
+// Verifies the FRI decommitment: folds the first layer, left in QueryState.fri_queries by
+// EvalOodsBoundaryPolyAtPoints, through every inner layer and checks the result against the last
+// layer polynomial.
+#[repr(C)]
+pub struct FriVerify {
+    step: FriVerifyStep,
+    n_inner_layers: u32,
+    layer: u32,
+}
+
+impl_type_identifiable!(FriVerify);
+
+impl FriVerify {
+    pub fn new() -> Self {
+        Self {
+            step: FriVerifyStep::Init,
+            n_inner_layers: 0,
+            layer: 0,
+        }
+    }
+}
+
+impl Default for FriVerify {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Executable for FriVerify {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            FriVerifyStep::Init => {
+                let proof: &StarkProof = stack.get_proof_reference();
+                let n_layers: u32 = proof
+                    .config
+                    .fri
+                    .n_layers
+                    .try_into()
+                    .map_err(|_| TaskError::InvalidConfig("Invalid number of FRI layers"))?;
+                self.n_inner_layers = n_layers
+                    .checked_sub(1)
+                    .ok_or(TaskError::InvalidConfig("Invalid number of FRI layers"))?;
+                ensure(
+                    proof.witness.fri_witness.layers.len() == self.n_inner_layers as usize,
+                    TaskError::InvalidWitness("Invalid FRI witness length"),
+                )?;
+
+                self.step = FriVerifyStep::Layer;
+                Ok(vec![])
+            }
+            FriVerifyStep::Layer => {
+                if self.layer == self.n_inner_layers {
+                    self.step = FriVerifyStep::LastLayer;
+                    return Ok(vec![]);
+                }
+                let layer = self.layer as usize;
+                self.layer += 1;
+
+                Ok(vec![
+                    ComputeNextLayer::new(layer).to_vec_with_type_tag(),
+                    TableDecommit::new(TableDecommitTarget::FriLayer(layer)).to_vec_with_type_tag(),
+                ])
+            }
+            FriVerifyStep::LastLayer => {
+                self.step = FriVerifyStep::Done;
+                Ok(vec![VerifyLastLayer::new().to_vec_with_type_tag()])
+            }
+            FriVerifyStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == FriVerifyStep::Done
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComputeNextLayerStep {
+    Init,
+    Done,
+}
+
+// Computes the queries of the next FRI layer. The queries of a layer are grouped by coset, the
+// missing elements of each coset are taken from the witness, and each coset is folded into one
+// query of the next layer. The coset indices and values are left in QueryState for the
+// decommitment of the layer.
+#[repr(C)]
+pub struct ComputeNextLayer {
+    step: ComputeNextLayerStep,
+    layer: usize,
+}
+
+impl_type_identifiable!(ComputeNextLayer);
+
+impl ComputeNextLayer {
+    pub fn new(layer: usize) -> Self {
+        Self {
+            step: ComputeNextLayerStep::Init,
+            layer,
+        }
+    }
+}
+
+impl Executable for ComputeNextLayer {
+    fn execute<T: BidirectionalStack + ProofData + StarkCommitmentTrait>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            ComputeNextLayerStep::Init => {
+                let stark_commitment =
+                    stack.get_stark_commitment::<StarkCommitment<InteractionElements>>();
+                let eval_point = *stark_commitment
+                    .fri
+                    .eval_points
+                    .get(self.layer)
+                    .ok_or(TaskError::InvalidConfig("Invalid number of FRI layers"))?;
+
+                let (queries, proof) = stack.get_queries_and_proof_mut::<QueryState, StarkProof>();
+                let step_size: u32 = (*proof.config.fri.fri_step_sizes.at(self.layer + 1))
+                    .try_into()
+                    .map_err(|_| TaskError::InvalidConfig("Invalid FRI step size"))?;
+                // Cosets of 2, 4, 8 or 16 elements.
+                ensure(
+                    (1..=4).contains(&step_size),
+                    TaskError::InvalidConfig("Invalid FRI step size"),
+                )?;
+                let coset_size = 1usize << step_size;
+                let coset_size_felt = NonZeroFelt::try_from(Felt::from(coset_size))
+                    .map_err(|_| TaskError::InvalidConfig("Invalid FRI step size"))?;
+                let siblings = proof
+                    .witness
+                    .fri_witness
+                    .layers
+                    .at(self.layer)
+                    .leaves
+                    .as_slice();
+
+                queries.layer_indices.flush();
+                queries.layer_values.flush();
+
+                let n_queries = queries.fri_queries.len();
+                let mut read = 0;
+                let mut write = 0;
+                let mut sibling = 0;
+                let mut coset = [Felt::ZERO; 16];
+                while read < n_queries {
+                    let (coset_index, _) =
+                        queries.fri_queries.at(read).index.div_rem(&coset_size_felt);
+                    let coset_start = coset_index * Felt::from(coset_size);
+
+                    let mut coset_x_inv = Felt::ZERO;
+                    for (offset, element) in coset[..coset_size].iter_mut().enumerate() {
+                        let query = queries.fri_queries.get(read).copied();
+                        match query {
+                            Some(query) if query.index == coset_start + Felt::from(offset) => {
+                                *element = query.y_value;
+                                coset_x_inv = query.x_inv_value * FRI_GROUP[offset];
+                                read += 1;
+                            }
+                            _ => {
+                                *element = *siblings.get(sibling).ok_or(
+                                    TaskError::InvalidWitness("Missing FRI witness leaves"),
+                                )?;
+                                sibling += 1;
+                            }
+                        }
+                    }
+
+                    queries.layer_indices.push(coset_index);
+                    queries.layer_values.extend(&coset[..coset_size]);
+
+                    // Writes never overtake reads: every coset holds at least one query.
+                    *queries.fri_queries.at_mut(write) = FriLayerQuery {
+                        index: coset_index,
+                        y_value: fri_formula(&coset[..coset_size], eval_point, coset_x_inv)?,
+                        x_inv_value: coset_x_inv.pow(coset_size as u128),
+                    };
+                    write += 1;
+                }
+                queries.fri_queries.to_size_uninitialized(write);
+
+                ensure(
+                    sibling == siblings.len(),
+                    TaskError::InvalidWitness("Invalid FRI witness leaves"),
+                )?;
+
+                self.step = ComputeNextLayerStep::Done;
+                Ok(vec![])
+            }
+            ComputeNextLayerStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == ComputeNextLayerStep::Done
+    }
+}
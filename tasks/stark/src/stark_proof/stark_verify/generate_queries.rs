@@ -0,0 +1,142 @@
+use crate::funvec::FUNVEC_QUERIES;
+use crate::swiftness::air::domains::StarkDomains;
+use crate::swiftness::stark::types::{QueryState, StarkProof};
+use crate::swiftness::transcript::TranscriptRandomFelt;
+use felt::Felt;
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError,
+    TypeIdentifiable,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerateQueriesStep {
+    Init,
+    Sample,
+    Collect,
+    Sort,
+    Done,
+}
+
+// Samples config.n_queries query indices in the evaluation domain from the transcript state left
+// on the stack by the proof of work, [counter, digest], and stores them, sorted and deduplicated,
+// in the account's QueryState. The transcript state is consumed.
+#[repr(C)]
+pub struct GenerateQueries {
+    step: GenerateQueriesStep,
+    n_samples: u32,
+    sampled: u32,
+    query_upper_bound: u64,
+    digest: Felt,
+    counter: Felt,
+}
+
+impl_type_identifiable!(GenerateQueries);
+
+impl GenerateQueries {
+    pub fn new() -> Self {
+        Self {
+            step: GenerateQueriesStep::Init,
+            n_samples: 0,
+            sampled: 0,
+            query_upper_bound: 0,
+            digest: Felt::ZERO,
+            counter: Felt::ZERO,
+        }
+    }
+}
+
+impl Default for GenerateQueries {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Executable for GenerateQueries {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            GenerateQueriesStep::Init => {
+                self.counter = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+                self.digest = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+
+                let proof: &StarkProof = stack.get_proof_reference();
+                let stark_domains = StarkDomains::new(
+                    proof.config.log_trace_domain_size,
+                    proof.config.log_n_cosets,
+                );
+                self.n_samples = proof
+                    .config
+                    .n_queries
+                    .try_into()
+                    .map_err(|_| TaskError::InvalidConfig("Invalid number of queries"))?;
+                self.query_upper_bound = stark_domains
+                    .eval_domain_size
+                    .try_into()
+                    .map_err(|_| TaskError::InvalidConfig("Invalid evaluation domain size"))?;
+                ensure(
+                    self.n_samples > 0 && self.n_samples as usize <= FUNVEC_QUERIES,
+                    TaskError::InvalidConfig("Invalid number of queries"),
+                )?;
+
+                stack.get_queries_mut::<QueryState>().queries.flush();
+
+                self.step = GenerateQueriesStep::Sample;
+                Ok(vec![])
+            }
+            GenerateQueriesStep::Sample => {
+                self.step = GenerateQueriesStep::Collect;
+                Ok(vec![
+                    TranscriptRandomFelt::new(self.digest, self.counter).to_vec_with_type_tag()
+                ])
+            }
+            GenerateQueriesStep::Collect => {
+                self.counter = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+                let random = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+
+                // Only the low 128 bits of the random felt are used.
+                let low = u128::from_be_bytes(random.to_bytes_be()[16..].try_into().unwrap());
+                let query = low % self.query_upper_bound as u128;
+                stack
+                    .get_queries_mut::<QueryState>()
+                    .queries
+                    .push(Felt::from(query));
+
+                self.sampled += 1;
+                self.step = if self.sampled < self.n_samples {
+                    GenerateQueriesStep::Sample
+                } else {
+                    GenerateQueriesStep::Sort
+                };
+                Ok(vec![])
+            }
+            GenerateQueriesStep::Sort => {
+                let queries = &mut stack.get_queries_mut::<QueryState>().queries;
+                queries.as_slice_mut().sort();
+
+                let mut len = 0;
+                for i in 0..queries.len() {
+                    let query = *queries.at(i);
+                    if len == 0 || *queries.at(len - 1) != query {
+                        *queries.at_mut(len) = query;
+                        len += 1;
+                    }
+                }
+                queries.to_size_uninitialized(len);
+
+                self.step = GenerateQueriesStep::Done;
+                Ok(vec![])
+            }
+            GenerateQueriesStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == GenerateQueriesStep::Done
+    }
+}
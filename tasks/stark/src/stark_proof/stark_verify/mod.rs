@@ -0,0 +1,112 @@
+pub mod eval_oods_boundary;
+pub mod fri_verify;
+pub mod generate_queries;
+pub mod table_decommit;
+pub mod vector_decommit;
+pub mod verify_last_layer;
+
+use crate::swiftness::air::domains::StarkDomains;
+use crate::swiftness::stark::types::StarkProof;
+use felt::Felt;
+use utils::{
+    impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError, TypeIdentifiable,
+};
+
+pub use self::eval_oods_boundary::EvalOodsBoundaryPolyAtPoints;
+pub use self::fri_verify::{ComputeNextLayer, FriVerify};
+pub use self::generate_queries::GenerateQueries;
+pub use self::table_decommit::{TableDecommit, TableDecommitTarget};
+pub use self::vector_decommit::VectorCommitmentDecommit;
+pub use self::verify_last_layer::VerifyLastLayer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StarkVerifyStep {
+    Init,
+    TracesDecommit,
+    EvalFriInputLayer,
+    FriVerify,
+    Done,
+}
+
+// Query phase of the STARK verifier. Expects the transcript state left by StarkCommit,
+// [counter, digest], on the stack, and the commitment it filled in the account.
+#[repr(C)]
+pub struct StarkVerify {
+    step: StarkVerifyStep,
+    log_eval_domain_size: u32,
+    eval_generator: Felt,
+    trace_generator: Felt,
+}
+
+impl_type_identifiable!(StarkVerify);
+
+impl StarkVerify {
+    pub fn new() -> Self {
+        Self {
+            step: StarkVerifyStep::Init,
+            log_eval_domain_size: 0,
+            eval_generator: Felt::ZERO,
+            trace_generator: Felt::ZERO,
+        }
+    }
+}
+
+impl Default for StarkVerify {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Executable for StarkVerify {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            StarkVerifyStep::Init => {
+                let proof: &StarkProof = stack.get_proof_reference();
+                let stark_domains = StarkDomains::new(
+                    proof.config.log_trace_domain_size,
+                    proof.config.log_n_cosets,
+                );
+
+                self.log_eval_domain_size = stark_domains
+                    .log_eval_domain_size
+                    .try_into()
+                    .map_err(|_| TaskError::InvalidConfig("Invalid evaluation domain size"))?;
+                self.eval_generator = stark_domains.eval_generator;
+                self.trace_generator = stark_domains.trace_generator;
+
+                self.step = StarkVerifyStep::TracesDecommit;
+                Ok(vec![GenerateQueries::new().to_vec_with_type_tag()])
+            }
+            StarkVerifyStep::TracesDecommit => {
+                self.step = StarkVerifyStep::EvalFriInputLayer;
+                Ok(vec![
+                    TableDecommit::new(TableDecommitTarget::TracesOriginal).to_vec_with_type_tag(),
+                    TableDecommit::new(TableDecommitTarget::TracesInteraction)
+                        .to_vec_with_type_tag(),
+                    TableDecommit::new(TableDecommitTarget::Composition).to_vec_with_type_tag(),
+                ])
+            }
+            StarkVerifyStep::EvalFriInputLayer => {
+                self.step = StarkVerifyStep::FriVerify;
+                Ok(vec![EvalOodsBoundaryPolyAtPoints::new(
+                    self.log_eval_domain_size,
+                    self.eval_generator,
+                    self.trace_generator,
+                )
+                .to_vec_with_type_tag()])
+            }
+            StarkVerifyStep::FriVerify => {
+                self.step = StarkVerifyStep::Done;
+                Ok(vec![FriVerify::new().to_vec_with_type_tag()])
+            }
+            StarkVerifyStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == StarkVerifyStep::Done
+    }
+}
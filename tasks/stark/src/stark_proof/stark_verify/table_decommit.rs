@@ -0,0 +1,287 @@
+use crate::poseidon::PoseidonHashMany;
+use crate::stark_proof::stark_verify::vector_decommit::VectorCommitmentDecommit;
+use crate::swiftness::commitment::hash::{hash_row, MAX_ROW_LENGTH};
+use crate::swiftness::commitment::table::config::Config as TableConfig;
+use crate::swiftness::commitment::table::types::MONTGOMERY_R;
+use crate::swiftness::commitment::vector::types::Query as VectorQuery;
+use crate::swiftness::stark::config::Hasher;
+use crate::swiftness::stark::types::{QueryState, StarkCommitment, StarkProof};
+use felt::Felt;
+use utils::global_values::InteractionElements;
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError,
+    TypeIdentifiable,
+};
+
+// The table commitments checked by the query phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableDecommitTarget {
+    TracesOriginal,
+    TracesInteraction,
+    Composition,
+    FriLayer(usize),
+}
+
+impl TableDecommitTarget {
+    pub fn config(self, proof: &StarkProof) -> &TableConfig {
+        match self {
+            TableDecommitTarget::TracesOriginal => &proof.config.traces.original,
+            TableDecommitTarget::TracesInteraction => &proof.config.traces.interaction,
+            TableDecommitTarget::Composition => &proof.config.composition,
+            TableDecommitTarget::FriLayer(layer) => proof.config.fri.inner_layers.at(layer),
+        }
+    }
+
+    pub fn commitment_hash(self, commitment: &StarkCommitment<InteractionElements>) -> Felt {
+        match self {
+            TableDecommitTarget::TracesOriginal => {
+                commitment.traces.original.vector_commitment.commitment_hash
+            }
+            TableDecommitTarget::TracesInteraction => {
+                commitment
+                    .traces
+                    .interaction
+                    .vector_commitment
+                    .commitment_hash
+            }
+            TableDecommitTarget::Composition => {
+                commitment.composition.vector_commitment.commitment_hash
+            }
+            TableDecommitTarget::FriLayer(layer) => {
+                commitment
+                    .fri
+                    .inner_layers
+                    .at(layer)
+                    .vector_commitment
+                    .commitment_hash
+            }
+        }
+    }
+
+    // Row indices being decommitted: the queries for the traces and the composition, the coset
+    // indices for a FRI layer.
+    pub fn indices(self, queries: &QueryState) -> &[Felt] {
+        match self {
+            TableDecommitTarget::FriLayer(_) => queries.layer_indices.as_slice(),
+            _ => queries.queries.as_slice(),
+        }
+    }
+
+    // Decommitted values, n_columns per row.
+    pub fn values<'a>(self, queries: &'a QueryState, proof: &'a StarkProof) -> &'a [Felt] {
+        match self {
+            TableDecommitTarget::TracesOriginal => {
+                proof.witness.traces_decommitment.original.values.as_slice()
+            }
+            TableDecommitTarget::TracesInteraction => proof
+                .witness
+                .traces_decommitment
+                .interaction
+                .values
+                .as_slice(),
+            TableDecommitTarget::Composition => {
+                proof.witness.composition_decommitment.values.as_slice()
+            }
+            TableDecommitTarget::FriLayer(_) => queries.layer_values.as_slice(),
+        }
+    }
+
+    // Values of a row in Montgomery form, the form the prover committed to, written to the front of
+    // the buffer. They are always derived from the decommitted values, which are the ones the
+    // verifier goes on to use, so that the leaves tie them to the commitment.
+    pub fn row_montgomery_values<'b>(
+        self,
+        queries: &QueryState,
+        proof: &StarkProof,
+        row: usize,
+        buffer: &'b mut [Felt; MAX_ROW_LENGTH],
+        n_columns: usize,
+    ) -> &'b [Felt] {
+        let values = &self.values(queries, proof)[row * n_columns..(row + 1) * n_columns];
+        for (montgomery_value, value) in buffer.iter_mut().zip(values) {
+            *montgomery_value = *value * MONTGOMERY_R;
+        }
+        &buffer[..n_columns]
+    }
+
+    pub fn authentications(self, proof: &StarkProof) -> &[Felt] {
+        match self {
+            TableDecommitTarget::TracesOriginal => proof
+                .witness
+                .traces_witness
+                .original
+                .vector
+                .authentications
+                .as_slice(),
+            TableDecommitTarget::TracesInteraction => proof
+                .witness
+                .traces_witness
+                .interaction
+                .vector
+                .authentications
+                .as_slice(),
+            TableDecommitTarget::Composition => proof
+                .witness
+                .composition_witness
+                .vector
+                .authentications
+                .as_slice(),
+            TableDecommitTarget::FriLayer(layer) => proof
+                .witness
+                .fri_witness
+                .layers
+                .at(layer)
+                .table_witness
+                .vector
+                .authentications
+                .as_slice(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableDecommitStep {
+    Init,
+    HashRow,
+    CollectRowHash,
+    VectorDecommit,
+    Done,
+}
+
+// Hashes every decommitted row of a table into a leaf of its vector commitment, then checks the
+// leaves against the commitment with VectorCommitmentDecommit.
+#[repr(C)]
+pub struct TableDecommit {
+    step: TableDecommitStep,
+    target: TableDecommitTarget,
+    n_columns: u32,
+    row: u32,
+    is_verifier_friendly: bool,
+    hasher: Hasher,
+    shift: Felt,
+}
+
+impl_type_identifiable!(TableDecommit);
+
+impl TableDecommit {
+    pub fn new(target: TableDecommitTarget) -> Self {
+        Self {
+            step: TableDecommitStep::Init,
+            target,
+            n_columns: 0,
+            row: 0,
+            is_verifier_friendly: false,
+            hasher: Hasher::Keccak256,
+            shift: Felt::ZERO,
+        }
+    }
+
+    fn push_leaf<T: ProofData>(&self, stack: &mut T, row: usize, leaf: Felt) {
+        let queries = stack.get_queries_mut::<QueryState>();
+        let index = self.target.indices(queries)[row] + self.shift;
+        queries.nodes.push(VectorQuery { index, value: leaf });
+    }
+}
+
+impl Executable for TableDecommit {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            TableDecommitStep::Init => {
+                let (queries, proof) = stack.get_queries_and_proof_mut::<QueryState, StarkProof>();
+                let config = self.target.config(proof);
+
+                self.n_columns = config
+                    .n_columns
+                    .try_into()
+                    .map_err(|_| TaskError::InvalidConfig("Invalid number of columns"))?;
+                // Rows are hashed from a fixed buffer
+                ensure(
+                    self.n_columns as usize <= MAX_ROW_LENGTH,
+                    TaskError::InvalidConfig("Table row too long"),
+                )?;
+                // An extra layer is added to the height since the table is considered as a
+                // layer, which is not included in the vector commitment config.
+                self.is_verifier_friendly = config.vector.n_verifier_friendly_commitment_layers
+                    >= config.vector.height + Felt::ONE;
+                self.shift = Felt::TWO.pow_felt(&config.vector.height);
+                self.hasher = proof.verifier_config.hasher()?;
+
+                let n_rows = self.target.indices(queries).len();
+                let n_values = self.target.values(queries, proof).len();
+                ensure(
+                    n_values == n_rows * self.n_columns as usize,
+                    TaskError::InvalidWitness("Invalid decommitment length"),
+                )?;
+
+                queries.nodes.flush();
+
+                self.step = TableDecommitStep::HashRow;
+                Ok(vec![])
+            }
+            TableDecommitStep::HashRow => {
+                // Single column, Keccak and Blake2s rows are hashed inline, several per step.
+                // Poseidon rows are hashed one at a time by a PoseidonHashMany subtask.
+                const INLINE_ROWS_PER_STEP: usize = 8;
+
+                let n_columns = self.n_columns as usize;
+                let mut buffer = [Felt::ZERO; MAX_ROW_LENGTH];
+                for _ in 0..INLINE_ROWS_PER_STEP {
+                    let (queries, proof) =
+                        stack.get_queries_and_proof_mut::<QueryState, StarkProof>();
+                    let row = self.row as usize;
+                    if row == self.target.indices(queries).len() {
+                        self.step = TableDecommitStep::VectorDecommit;
+                        return Ok(vec![]);
+                    }
+
+                    let montgomery_values = self.target.row_montgomery_values(
+                        queries,
+                        proof,
+                        row,
+                        &mut buffer,
+                        n_columns,
+                    );
+
+                    if n_columns == 1 {
+                        self.push_leaf(stack, row, montgomery_values[0]);
+                    } else if self.is_verifier_friendly {
+                        PoseidonHashMany::push_input(montgomery_values, stack)?;
+                        self.step = TableDecommitStep::CollectRowHash;
+                        return Ok(vec![PoseidonHashMany::new(n_columns).to_vec_with_type_tag()]);
+                    } else {
+                        let leaf = hash_row(self.hasher, montgomery_values)?;
+                        self.push_leaf(stack, row, leaf);
+                    }
+                    self.row += 1;
+                }
+                Ok(vec![])
+            }
+            TableDecommitStep::CollectRowHash => {
+                let leaf = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+                stack.pop_front();
+                stack.pop_front();
+
+                self.push_leaf(stack, self.row as usize, leaf);
+                self.row += 1;
+
+                self.step = TableDecommitStep::HashRow;
+                Ok(vec![])
+            }
+            TableDecommitStep::VectorDecommit => {
+                self.step = TableDecommitStep::Done;
+                Ok(vec![
+                    VectorCommitmentDecommit::new(self.target).to_vec_with_type_tag()
+                ])
+            }
+            TableDecommitStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == TableDecommitStep::Done
+    }
+}
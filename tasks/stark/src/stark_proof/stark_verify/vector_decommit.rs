@@ -0,0 +1,191 @@
+use crate::poseidon::PoseidonHash;
+use crate::stark_proof::stark_verify::table_decommit::TableDecommitTarget;
+use crate::swiftness::commitment::hash::hash_node;
+use crate::swiftness::commitment::vector::types::Query as VectorQuery;
+use crate::swiftness::stark::config::Hasher;
+use crate::swiftness::stark::types::{QueryState, StarkCommitment, StarkProof};
+use felt::{Felt, NonZeroFelt};
+use utils::global_values::InteractionElements;
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData,
+    StarkCommitmentTrait, TaskError, TypeIdentifiable,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorCommitmentDecommitStep {
+    Init,
+    HashNode,
+    CollectHash,
+    Done,
+}
+
+// Recomputes the Merkle root of a vector commitment from the leaves in QueryState.nodes and the
+// authentication nodes of the witness, one layer at a time from the bottom up. Nodes at depth
+// at most n_verifier_friendly_layers are hashed with Poseidon, the deeper ones with the hasher of
+// the verifier config.
+#[repr(C)]
+pub struct VectorCommitmentDecommit {
+    step: VectorCommitmentDecommitStep,
+    target: TableDecommitTarget,
+    depth: u32,
+    n_verifier_friendly_layers: u32,
+    hasher: Hasher,
+    read: u32,
+    write: u32,
+    authentication: u32,
+    parent_index: Felt,
+    commitment_hash: Felt,
+}
+
+impl_type_identifiable!(VectorCommitmentDecommit);
+
+impl VectorCommitmentDecommit {
+    pub fn new(target: TableDecommitTarget) -> Self {
+        Self {
+            step: VectorCommitmentDecommitStep::Init,
+            target,
+            depth: 0,
+            n_verifier_friendly_layers: 0,
+            hasher: Hasher::Keccak256,
+            read: 0,
+            write: 0,
+            authentication: 0,
+            parent_index: Felt::ZERO,
+            commitment_hash: Felt::ZERO,
+        }
+    }
+
+    fn next_authentication(&mut self, authentications: &[Felt]) -> Result<Felt, TaskError> {
+        ensure(
+            (self.authentication as usize) < authentications.len(),
+            TaskError::InvalidWitness("Missing authentication nodes"),
+        )?;
+        let authentication = authentications[self.authentication as usize];
+        self.authentication += 1;
+        Ok(authentication)
+    }
+
+    fn write_parent<T: ProofData>(&mut self, stack: &mut T, value: Felt) {
+        let nodes = &mut stack.get_queries_mut::<QueryState>().nodes;
+        *nodes.at_mut(self.write as usize) = VectorQuery {
+            index: self.parent_index,
+            value,
+        };
+        self.write += 1;
+    }
+}
+
+impl Executable for VectorCommitmentDecommit {
+    fn execute<T: BidirectionalStack + ProofData + StarkCommitmentTrait>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            VectorCommitmentDecommitStep::Init => {
+                let stark_commitment =
+                    stack.get_stark_commitment::<StarkCommitment<InteractionElements>>();
+                self.commitment_hash = self.target.commitment_hash(stark_commitment);
+
+                let proof: &StarkProof = stack.get_proof_reference();
+                let config = self.target.config(proof).vector;
+                self.depth = config
+                    .height
+                    .try_into()
+                    .map_err(|_| TaskError::InvalidConfig("Invalid vector commitment height"))?;
+                self.n_verifier_friendly_layers = config
+                    .n_verifier_friendly_commitment_layers
+                    .try_into()
+                    .map_err(|_| {
+                        TaskError::InvalidConfig("Invalid number of verifier friendly layers")
+                    })?;
+                self.hasher = proof.verifier_config.hasher()?;
+
+                self.step = VectorCommitmentDecommitStep::HashNode;
+                Ok(vec![])
+            }
+            VectorCommitmentDecommitStep::HashNode => {
+                // Keccak and Blake2s nodes are hashed inline, several per step. Poseidon nodes are
+                // hashed one at a time by a PoseidonHash subtask.
+                const INLINE_NODES_PER_STEP: usize = 8;
+
+                for _ in 0..INLINE_NODES_PER_STEP {
+                    let (queries, proof) =
+                        stack.get_queries_and_proof_mut::<QueryState, StarkProof>();
+                    let nodes = &mut queries.nodes;
+                    let authentications = self.target.authentications(proof);
+
+                    if self.depth == 0 {
+                        ensure(
+                            nodes.len() == 1 && nodes.at(0).index == Felt::ONE,
+                            TaskError::InvalidWitness("Invalid Merkle queue"),
+                        )?;
+                        ensure(
+                            nodes.at(0).value == self.commitment_hash,
+                            TaskError::CommitmentMismatch {
+                                expected: self.commitment_hash,
+                                actual: nodes.at(0).value,
+                            },
+                        )?;
+                        ensure(
+                            self.authentication as usize == authentications.len(),
+                            TaskError::InvalidWitness("Unused authentication nodes"),
+                        )?;
+                        self.step = VectorCommitmentDecommitStep::Done;
+                        return Ok(vec![]);
+                    }
+
+                    // The current layer is done, continue with the parents written in place.
+                    if self.read as usize == nodes.len() {
+                        nodes.to_size_uninitialized(self.write as usize);
+                        self.read = 0;
+                        self.write = 0;
+                        self.depth -= 1;
+                        continue;
+                    }
+
+                    let current = *nodes.at(self.read as usize);
+                    let (parent_index, bit) = current.index.div_rem(&NonZeroFelt::TWO);
+
+                    let (left, right) = if bit == Felt::ZERO {
+                        let next = nodes.get(self.read as usize + 1).copied();
+                        match next {
+                            Some(next) if next.index == current.index + Felt::ONE => {
+                                self.read += 1;
+                                (current.value, next.value)
+                            }
+                            _ => (current.value, self.next_authentication(authentications)?),
+                        }
+                    } else {
+                        (self.next_authentication(authentications)?, current.value)
+                    };
+                    self.read += 1;
+                    self.parent_index = parent_index;
+
+                    if self.n_verifier_friendly_layers >= self.depth {
+                        PoseidonHash::push_input(left, right, stack)?;
+                        self.step = VectorCommitmentDecommitStep::CollectHash;
+                        return Ok(vec![PoseidonHash::new().to_vec_with_type_tag()]);
+                    }
+                    self.write_parent(stack, hash_node(self.hasher, left, right));
+                }
+                Ok(vec![])
+            }
+            VectorCommitmentDecommitStep::CollectHash => {
+                let hash = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+                stack.pop_front();
+                stack.pop_front();
+
+                self.write_parent(stack, hash);
+
+                self.step = VectorCommitmentDecommitStep::HashNode;
+                Ok(vec![])
+            }
+            VectorCommitmentDecommitStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == VectorCommitmentDecommitStep::Done
+    }
+}
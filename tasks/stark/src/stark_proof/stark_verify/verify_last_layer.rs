@@ -0,0 +1,91 @@
+use crate::swiftness::fri::formula::{batch_inverse, horner_eval};
+use crate::swiftness::stark::types::{QueryState, StarkCommitment, StarkProof};
+use felt::Felt;
+use utils::global_values::InteractionElements;
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData,
+    StarkCommitmentTrait, TaskError, TypeIdentifiable,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyLastLayerStep {
+    Verify,
+    Done,
+}
+
+// Checks that the queries of the last FRI layer lie on the last layer polynomial sent by the
+// prover, of degree less than 2^log_last_layer_degree_bound.
+#[repr(C)]
+pub struct VerifyLastLayer {
+    step: VerifyLastLayerStep,
+}
+
+impl_type_identifiable!(VerifyLastLayer);
+
+impl VerifyLastLayer {
+    pub fn new() -> Self {
+        Self {
+            step: VerifyLastLayerStep::Verify,
+        }
+    }
+}
+
+impl Default for VerifyLastLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Executable for VerifyLastLayer {
+    fn execute<T: BidirectionalStack + ProofData + StarkCommitmentTrait>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            VerifyLastLayerStep::Verify => {
+                let proof: &StarkProof = stack.get_proof_reference();
+                let expected_len =
+                    Felt::TWO.pow_felt(&proof.config.fri.log_last_layer_degree_bound);
+
+                let coefficients = &stack
+                    .get_stark_commitment::<StarkCommitment<InteractionElements>>()
+                    .fri
+                    .last_layer_coefficients;
+                ensure(
+                    expected_len == coefficients.len().into(),
+                    TaskError::InvalidWitness("Invalid last layer coefficients length"),
+                )?;
+                let queries = stack.get_queries::<QueryState>();
+
+                // x_inv_value is the true inverse of the query's point x in the homogeneous group:
+                // EvalOodsBoundaryPolyAtPoints sets it to FIELD_GENERATOR / point and every fold
+                // raises it to the coset size, as fri_formula expects. As in Cairo0
+                // verify_last_layer, the last layer polynomial is evaluated at 1 / x_inv_value.
+                let mut x_values: Vec<Felt> = queries
+                    .fri_queries
+                    .iter()
+                    .map(|query| query.x_inv_value)
+                    .collect();
+                batch_inverse(&mut x_values)?;
+
+                for (query, x) in queries.fri_queries.iter().zip(x_values) {
+                    ensure(
+                        horner_eval(coefficients.as_slice(), x) == query.y_value,
+                        TaskError::LastLayerMismatch {
+                            index: query.index,
+                            value: query.y_value,
+                        },
+                    )?;
+                }
+
+                self.step = VerifyLastLayerStep::Done;
+                Ok(vec![])
+            }
+            VerifyLastLayerStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == VerifyLastLayerStep::Done
+    }
+}
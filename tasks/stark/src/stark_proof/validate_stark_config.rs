@@ -0,0 +1,221 @@
+use crate::funvec::FUNVEC_QUERIES;
+use crate::swiftness::air::layout::LayoutId;
+use crate::swiftness::commitment::vector::config::Config as VectorConfig;
+use crate::swiftness::fri::config::{
+    MAX_FRI_LAYERS, MAX_FRI_STEP, MAX_LAST_LAYER_LOG_DEGREE_BOUND, MIN_FRI_LAYERS, MIN_FRI_STEP,
+};
+use crate::swiftness::pow::config::{MAX_PROOF_OF_WORK_BITS, MIN_PROOF_OF_WORK_BITS};
+use crate::swiftness::stark::config::StarkConfig;
+use crate::swiftness::stark::types::StarkProof;
+use felt::Felt;
+use utils::{
+    ensure, impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError,
+    TypeIdentifiable,
+};
+
+// Minimum security of an accepted proof, in bits.
+pub const DEFAULT_SECURITY_BITS: u64 = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidateStarkConfigStep {
+    Validate,
+    Done,
+}
+
+// Checks that the FunVecs of the proof fit their capacity, that the StarkConfig of the proof is
+// consistent with the layout and the evaluation domain, and that it provides at least
+// min_security_bits bits of security.
+#[repr(C)]
+pub struct ValidateStarkConfig {
+    step: ValidateStarkConfigStep,
+    min_security_bits: u64,
+}
+
+impl_type_identifiable!(ValidateStarkConfig);
+
+impl ValidateStarkConfig {
+    pub fn new(min_security_bits: u64) -> Self {
+        Self {
+            step: ValidateStarkConfigStep::Validate,
+            min_security_bits,
+        }
+    }
+}
+
+impl Default for ValidateStarkConfig {
+    fn default() -> Self {
+        Self::new(DEFAULT_SECURITY_BITS)
+    }
+}
+
+impl Executable for ValidateStarkConfig {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
+        match self.step {
+            ValidateStarkConfigStep::Validate => {
+                let proof: &StarkProof = stack.get_proof_reference();
+                // First task of Verify, nothing has read the proof yet
+                proof.validate_lengths()?;
+                let config = &proof.config;
+                let (_, params) = LayoutId::supported(proof.public_input.layout)?;
+
+                let log_trace_domain_size = felt_to_u64(config.log_trace_domain_size)?;
+                let log_n_cosets = felt_to_u64(config.log_n_cosets)?;
+                let log_eval_domain_size = log_trace_domain_size.checked_add(log_n_cosets).ok_or(
+                    TaskError::InvalidConfig("Evaluation domain size out of range"),
+                )?;
+                let n_verifier_friendly_layers = config.n_verifier_friendly_commitment_layers;
+
+                // 1. Validate proof of work
+                let pow_bits = config.proof_of_work.n_bits;
+                ensure(
+                    pow_bits >= MIN_PROOF_OF_WORK_BITS,
+                    TaskError::InvalidConfig("Proof of work bits below minimum"),
+                )?;
+                ensure(
+                    pow_bits <= MAX_PROOF_OF_WORK_BITS,
+                    TaskError::InvalidConfig("Proof of work bits exceed maximum"),
+                )?;
+
+                // 2. Validate queries and security bits
+                let n_queries = felt_to_u64(config.n_queries)?;
+                ensure(
+                    (1..=FUNVEC_QUERIES as u64).contains(&n_queries),
+                    TaskError::InvalidConfig("Invalid number of queries"),
+                )?;
+                ensure(
+                    security_bits(config)? >= self.min_security_bits,
+                    TaskError::InvalidConfig("Insufficient security bits"),
+                )?;
+
+                // 3. Validate traces
+                ensure(
+                    config.traces.original.n_columns == params.num_columns_first.into(),
+                    TaskError::InvalidConfig("Invalid number of original trace columns"),
+                )?;
+                ensure(
+                    config.traces.interaction.n_columns == params.num_columns_second.into(),
+                    TaskError::InvalidConfig("Invalid number of interaction trace columns"),
+                )?;
+                validate_vector_config(
+                    &config.traces.original.vector,
+                    log_eval_domain_size,
+                    n_verifier_friendly_layers,
+                )?;
+                validate_vector_config(
+                    &config.traces.interaction.vector,
+                    log_eval_domain_size,
+                    n_verifier_friendly_layers,
+                )?;
+
+                // 4. Validate composition
+                ensure(
+                    config.composition.n_columns == params.constraint_degree.into(),
+                    TaskError::InvalidConfig("Invalid number of composition columns"),
+                )?;
+                validate_vector_config(
+                    &config.composition.vector,
+                    log_eval_domain_size,
+                    n_verifier_friendly_layers,
+                )?;
+
+                // 5. Validate FRI
+                let fri = &config.fri;
+                let n_layers = felt_to_u64(fri.n_layers)?;
+                ensure(
+                    (MIN_FRI_LAYERS..=MAX_FRI_LAYERS).contains(&n_layers),
+                    TaskError::InvalidConfig("Invalid number of FRI layers"),
+                )?;
+                ensure(
+                    fri.fri_step_sizes.len() == n_layers as usize
+                        && fri.inner_layers.len() == n_layers as usize - 1,
+                    TaskError::InvalidConfig("Invalid FRI config length"),
+                )?;
+                let log_last_layer_degree_bound = felt_to_u64(fri.log_last_layer_degree_bound)?;
+                ensure(
+                    log_last_layer_degree_bound <= MAX_LAST_LAYER_LOG_DEGREE_BOUND,
+                    TaskError::InvalidConfig("Last layer degree bound exceeds maximum"),
+                )?;
+                ensure(
+                    felt_to_u64(fri.log_input_size)? == log_eval_domain_size,
+                    TaskError::InvalidConfig("Invalid FRI input size"),
+                )?;
+                ensure(
+                    *fri.fri_step_sizes.at(0) == Felt::ZERO,
+                    TaskError::InvalidConfig("Invalid first FRI step size"),
+                )?;
+
+                let mut log_layer_size = log_eval_domain_size;
+                for layer in 1..n_layers as usize {
+                    let step_size = felt_to_u64(*fri.fri_step_sizes.at(layer))?;
+                    ensure(
+                        (MIN_FRI_STEP..=MAX_FRI_STEP).contains(&step_size),
+                        TaskError::InvalidConfig("Invalid FRI step size"),
+                    )?;
+                    ensure(
+                        step_size < log_layer_size,
+                        TaskError::InvalidConfig("FRI steps exceed the input size"),
+                    )?;
+                    log_layer_size -= step_size;
+
+                    // Each row of a layer table is a coset of 2^step_size elements.
+                    let table = fri.inner_layers.at(layer - 1);
+                    ensure(
+                        table.n_columns == Felt::TWO.pow(step_size as u128),
+                        TaskError::InvalidConfig("Invalid number of FRI layer columns"),
+                    )?;
+                    validate_vector_config(
+                        &table.vector,
+                        log_layer_size,
+                        n_verifier_friendly_layers,
+                    )?;
+                }
+
+                // The folded input must match the degree of the last layer polynomial.
+                ensure(
+                    log_last_layer_degree_bound.checked_add(log_n_cosets) == Some(log_layer_size),
+                    TaskError::InvalidConfig("FRI steps do not match the evaluation domain size"),
+                )?;
+
+                self.step = ValidateStarkConfigStep::Done;
+                Ok(vec![])
+            }
+            ValidateStarkConfigStep::Done => Ok(vec![]),
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        self.step == ValidateStarkConfigStep::Done
+    }
+}
+
+// Security of the proof in bits: the FRI queries plus the proof of work.
+pub fn security_bits(config: &StarkConfig) -> Result<u64, TaskError> {
+    felt_to_u64(config.n_queries)?
+        .checked_mul(felt_to_u64(config.log_n_cosets)?)
+        .and_then(|bits| bits.checked_add(config.proof_of_work.n_bits as u64))
+        .ok_or(TaskError::InvalidConfig("Security bits out of range"))
+}
+
+fn felt_to_u64(value: Felt) -> Result<u64, TaskError> {
+    value
+        .try_into()
+        .map_err(|_| TaskError::InvalidConfig("Config value out of range"))
+}
+
+fn validate_vector_config(
+    config: &VectorConfig,
+    expected_height: u64,
+    n_verifier_friendly_layers: Felt,
+) -> Result<(), TaskError> {
+    ensure(
+        config.height == expected_height.into(),
+        TaskError::InvalidConfig("Invalid commitment height"),
+    )?;
+    ensure(
+        config.n_verifier_friendly_commitment_layers == n_verifier_friendly_layers,
+        TaskError::InvalidConfig("Invalid number of verifier friendly layers"),
+    )
+}
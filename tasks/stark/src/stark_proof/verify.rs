@@ -1,33 +1,52 @@
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, ProofData, TypeIdentifiable};
+use utils::{
+    impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError, TypeIdentifiable,
+};
 
 use crate::stark_proof::get_hash::GetHash;
+use crate::stark_proof::integrity::IntegrityHashes;
 use crate::stark_proof::stark_commit::StarkCommit;
 use crate::stark_proof::stark_verify::StarkVerify;
 use crate::stark_proof::validate_public_input::ValidatePublicInput;
+use crate::stark_proof::validate_stark_config::{ValidateStarkConfig, DEFAULT_SECURITY_BITS};
 use crate::stark_proof::VerifyPublicInput;
+use crate::swiftness::air::domains::StarkDomains;
+use crate::swiftness::stark::types::StarkProof;
 use felt::Felt;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VerifyStep {
+    ValidateStarkConfig,
     ValidatePublicInput,
     GetHash,
     StarkCommit,
     StarkVerify,
     VerifyPublicInput,
+    IntegrityHashes,
     Done,
 }
 
+// Verifies the whole proof stored in the account. The transcript is seeded with the hash of the
+// public input, and its state is passed from StarkCommit to StarkVerify on the stack. Leaves the
+// program hash and the output hash of the public input, followed by the Integrity fact hash and
+// verification hash, on the stack, the program hash on top.
 #[repr(C)]
 pub struct Verify {
     step: VerifyStep,
+    min_security_bits: u64,
 }
 
 impl_type_identifiable!(Verify);
 
 impl Verify {
     pub fn new() -> Self {
+        Self::with_min_security_bits(DEFAULT_SECURITY_BITS)
+    }
+
+    // Rejects proofs with less than min_security_bits bits of security.
+    pub fn with_min_security_bits(min_security_bits: u64) -> Self {
         Self {
-            step: VerifyStep::ValidatePublicInput,
+            step: VerifyStep::ValidateStarkConfig,
+            min_security_bits,
         }
     }
 }
@@ -39,31 +58,69 @@ impl Default for Verify {
 }
 
 impl Executable for Verify {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, _stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.step {
+            VerifyStep::ValidateStarkConfig => {
+                self.step = VerifyStep::ValidatePublicInput;
+                Ok(vec![
+                    ValidateStarkConfig::new(self.min_security_bits).to_vec_with_type_tag()
+                ])
+            }
             VerifyStep::ValidatePublicInput => {
                 self.step = VerifyStep::GetHash;
-                vec![ValidatePublicInput::new().to_vec_with_type_tag()]
+                Ok(vec![ValidatePublicInput::new().to_vec_with_type_tag()])
             }
             VerifyStep::GetHash => {
+                let proof: &StarkProof = stack.get_proof_reference();
+                let n_verifier_friendly_commitment_layers =
+                    proof.config.n_verifier_friendly_commitment_layers;
+
                 self.step = VerifyStep::StarkCommit;
-                vec![GetHash::new(Felt::ZERO).to_vec_with_type_tag()]
+                Ok(vec![
+                    GetHash::new(n_verifier_friendly_commitment_layers).to_vec_with_type_tag()
+                ])
             }
             VerifyStep::StarkCommit => {
+                let digest = Felt::from_bytes_be_slice(stack.borrow_front());
+                stack.pop_front();
+
+                let proof: &StarkProof = stack.get_proof_reference();
+                let stark_domains = StarkDomains::new(
+                    proof.config.log_trace_domain_size,
+                    proof.config.log_n_cosets,
+                );
+
+                // Initial transcript state and trace domain, as expected by StarkCommit.
+                for value in [
+                    stark_domains.trace_generator,
+                    stark_domains.trace_domain_size,
+                    digest,
+                    Felt::ZERO,
+                ] {
+                    stack
+                        .push_front(&value.to_bytes_be())
+                        .map_err(|_| TaskError::StackOverflow)?;
+                }
+
                 self.step = VerifyStep::StarkVerify;
-                vec![StarkCommit::new().to_vec_with_type_tag()]
+                Ok(vec![StarkCommit::new().to_vec_with_type_tag()])
             }
             VerifyStep::StarkVerify => {
                 self.step = VerifyStep::VerifyPublicInput;
-                vec![StarkVerify::new().to_vec_with_type_tag()]
+                Ok(vec![StarkVerify::new().to_vec_with_type_tag()])
             }
             VerifyStep::VerifyPublicInput => {
-                self.step = VerifyStep::Done;
-                vec![VerifyPublicInput::new().to_vec_with_type_tag()]
+                self.step = VerifyStep::IntegrityHashes;
+                Ok(vec![VerifyPublicInput::new().to_vec_with_type_tag()])
             }
-            VerifyStep::Done => {
-                vec![]
+            VerifyStep::IntegrityHashes => {
+                self.step = VerifyStep::Done;
+                Ok(vec![IntegrityHashes::new().to_vec_with_type_tag()])
             }
+            VerifyStep::Done => Ok(vec![]),
         }
     }
 
@@ -1,6 +1,6 @@
 use felt::{Felt, NonZeroFelt};
 
-const FIELD_GENERATOR: Felt = Felt::from_hex_unchecked("0x3");
+pub const FIELD_GENERATOR: Felt = Felt::from_hex_unchecked("0x3");
 
 const STARK_PRIME_MINUS_ONE: Felt =
     Felt::from_hex_unchecked("800000000000011000000000000000000000000000000000000000000000000");
@@ -42,6 +42,15 @@ impl StarkDomains {
     }
 }
 
+// Reverses the lowest n_bits bits of num. The evaluation domain is committed in bit-reversed
+// order, so the point of a query with index i is FIELD_GENERATOR * eval_generator^bit_reverse(i).
+pub fn bit_reverse_u64(num: u64, n_bits: u32) -> u64 {
+    if n_bits == 0 {
+        return 0;
+    }
+    num.reverse_bits() >> (64 - n_bits)
+}
+
 #[cfg(test)]
 mod tests {
     use felt::Felt;
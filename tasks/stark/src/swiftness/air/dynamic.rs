@@ -343,6 +343,350 @@ pub struct DynamicParams {
     pub uses_range_check_builtin: u32,
 }
 
+// Names of the dynamic params as they appear in the public input, in field order.
+pub const DYNAMIC_PARAMS_NAMES: [&str; 340] = [
+    "add_mod_a0_suboffset",
+    "add_mod_a1_suboffset",
+    "add_mod_a2_suboffset",
+    "add_mod_a3_suboffset",
+    "add_mod_a_offset_suboffset",
+    "add_mod_b0_suboffset",
+    "add_mod_b1_suboffset",
+    "add_mod_b2_suboffset",
+    "add_mod_b3_suboffset",
+    "add_mod_b_offset_suboffset",
+    "add_mod_c0_suboffset",
+    "add_mod_c1_suboffset",
+    "add_mod_c2_suboffset",
+    "add_mod_c3_suboffset",
+    "add_mod_c_offset_suboffset",
+    "add_mod_carry1_bit_column",
+    "add_mod_carry1_bit_offset",
+    "add_mod_carry1_sign_column",
+    "add_mod_carry1_sign_offset",
+    "add_mod_carry2_bit_column",
+    "add_mod_carry2_bit_offset",
+    "add_mod_carry2_sign_column",
+    "add_mod_carry2_sign_offset",
+    "add_mod_carry3_bit_column",
+    "add_mod_carry3_bit_offset",
+    "add_mod_carry3_sign_column",
+    "add_mod_carry3_sign_offset",
+    "add_mod_n_suboffset",
+    "add_mod_offsets_ptr_suboffset",
+    "add_mod_p0_suboffset",
+    "add_mod_p1_suboffset",
+    "add_mod_p2_suboffset",
+    "add_mod_p3_suboffset",
+    "add_mod_row_ratio",
+    "add_mod_sub_p_bit_column",
+    "add_mod_sub_p_bit_offset",
+    "add_mod_values_ptr_suboffset",
+    "bitwise_diluted_var_pool_suboffset",
+    "bitwise_row_ratio",
+    "bitwise_trim_unpacking192_suboffset",
+    "bitwise_trim_unpacking193_suboffset",
+    "bitwise_trim_unpacking194_suboffset",
+    "bitwise_trim_unpacking195_suboffset",
+    "bitwise_var_pool_suboffset",
+    "bitwise_x_or_y_suboffset",
+    "cpu_decode_mem_inst_suboffset",
+    "cpu_decode_off0_suboffset",
+    "cpu_decode_off1_suboffset",
+    "cpu_decode_off2_suboffset",
+    "cpu_decode_opcode_range_check_column_column",
+    "cpu_decode_opcode_range_check_column_offset",
+    "cpu_operands_mem_dst_suboffset",
+    "cpu_operands_mem_op0_suboffset",
+    "cpu_operands_mem_op1_suboffset",
+    "cpu_operands_ops_mul_column",
+    "cpu_operands_ops_mul_offset",
+    "cpu_operands_res_column",
+    "cpu_operands_res_offset",
+    "cpu_registers_ap_column",
+    "cpu_registers_ap_offset",
+    "cpu_registers_fp_column",
+    "cpu_registers_fp_offset",
+    "cpu_update_registers_update_pc_tmp0_column",
+    "cpu_update_registers_update_pc_tmp0_offset",
+    "cpu_update_registers_update_pc_tmp1_column",
+    "cpu_update_registers_update_pc_tmp1_offset",
+    "cpu_component_step",
+    "diluted_check_cumulative_value_column",
+    "diluted_check_cumulative_value_offset",
+    "diluted_check_permutation_cum_prod0_column",
+    "diluted_check_permutation_cum_prod0_offset",
+    "diluted_check_permuted_values_column",
+    "diluted_check_permuted_values_offset",
+    "diluted_pool_column",
+    "diluted_pool_offset",
+    "diluted_units_row_ratio",
+    "ec_op_doubled_points_x_column",
+    "ec_op_doubled_points_x_offset",
+    "ec_op_doubled_points_y_column",
+    "ec_op_doubled_points_y_offset",
+    "ec_op_doubling_slope_column",
+    "ec_op_doubling_slope_offset",
+    "ec_op_ec_subset_sum_bit_unpacking_prod_ones192_column",
+    "ec_op_ec_subset_sum_bit_unpacking_prod_ones192_offset",
+    "ec_op_ec_subset_sum_bit_unpacking_prod_ones196_column",
+    "ec_op_ec_subset_sum_bit_unpacking_prod_ones196_offset",
+    "ec_op_ec_subset_sum_partial_sum_x_column",
+    "ec_op_ec_subset_sum_partial_sum_x_offset",
+    "ec_op_ec_subset_sum_partial_sum_y_column",
+    "ec_op_ec_subset_sum_partial_sum_y_offset",
+    "ec_op_ec_subset_sum_selector_column",
+    "ec_op_ec_subset_sum_selector_offset",
+    "ec_op_ec_subset_sum_slope_column",
+    "ec_op_ec_subset_sum_slope_offset",
+    "ec_op_ec_subset_sum_x_diff_inv_column",
+    "ec_op_ec_subset_sum_x_diff_inv_offset",
+    "ec_op_m_suboffset",
+    "ec_op_p_x_suboffset",
+    "ec_op_p_y_suboffset",
+    "ec_op_q_x_suboffset",
+    "ec_op_q_y_suboffset",
+    "ec_op_r_x_suboffset",
+    "ec_op_r_y_suboffset",
+    "ec_op_builtin_row_ratio",
+    "ecdsa_message_suboffset",
+    "ecdsa_pubkey_suboffset",
+    "ecdsa_signature0_add_results_inv_column",
+    "ecdsa_signature0_add_results_inv_offset",
+    "ecdsa_signature0_add_results_slope_column",
+    "ecdsa_signature0_add_results_slope_offset",
+    "ecdsa_signature0_doubling_slope_column",
+    "ecdsa_signature0_doubling_slope_offset",
+    "ecdsa_signature0_exponentiate_generator_partial_sum_x_column",
+    "ecdsa_signature0_exponentiate_generator_partial_sum_x_offset",
+    "ecdsa_signature0_exponentiate_generator_partial_sum_y_column",
+    "ecdsa_signature0_exponentiate_generator_partial_sum_y_offset",
+    "ecdsa_signature0_exponentiate_generator_selector_column",
+    "ecdsa_signature0_exponentiate_generator_selector_offset",
+    "ecdsa_signature0_exponentiate_generator_slope_column",
+    "ecdsa_signature0_exponentiate_generator_slope_offset",
+    "ecdsa_signature0_exponentiate_generator_x_diff_inv_column",
+    "ecdsa_signature0_exponentiate_generator_x_diff_inv_offset",
+    "ecdsa_signature0_exponentiate_key_partial_sum_x_column",
+    "ecdsa_signature0_exponentiate_key_partial_sum_x_offset",
+    "ecdsa_signature0_exponentiate_key_partial_sum_y_column",
+    "ecdsa_signature0_exponentiate_key_partial_sum_y_offset",
+    "ecdsa_signature0_exponentiate_key_selector_column",
+    "ecdsa_signature0_exponentiate_key_selector_offset",
+    "ecdsa_signature0_exponentiate_key_slope_column",
+    "ecdsa_signature0_exponentiate_key_slope_offset",
+    "ecdsa_signature0_exponentiate_key_x_diff_inv_column",
+    "ecdsa_signature0_exponentiate_key_x_diff_inv_offset",
+    "ecdsa_signature0_extract_r_inv_column",
+    "ecdsa_signature0_extract_r_inv_offset",
+    "ecdsa_signature0_extract_r_slope_column",
+    "ecdsa_signature0_extract_r_slope_offset",
+    "ecdsa_signature0_key_points_x_column",
+    "ecdsa_signature0_key_points_x_offset",
+    "ecdsa_signature0_key_points_y_column",
+    "ecdsa_signature0_key_points_y_offset",
+    "ecdsa_signature0_q_x_squared_column",
+    "ecdsa_signature0_q_x_squared_offset",
+    "ecdsa_signature0_r_w_inv_column",
+    "ecdsa_signature0_r_w_inv_offset",
+    "ecdsa_signature0_z_inv_column",
+    "ecdsa_signature0_z_inv_offset",
+    "ecdsa_builtin_row_ratio",
+    "keccak_input_output_suboffset",
+    "keccak_keccak_diluted_column0_suboffset",
+    "keccak_keccak_diluted_column1_suboffset",
+    "keccak_keccak_diluted_column2_suboffset",
+    "keccak_keccak_diluted_column3_suboffset",
+    "keccak_keccak_parse_to_diluted_cumulative_sum_column",
+    "keccak_keccak_parse_to_diluted_cumulative_sum_offset",
+    "keccak_keccak_parse_to_diluted_final_reshaped_input_column",
+    "keccak_keccak_parse_to_diluted_final_reshaped_input_offset",
+    "keccak_keccak_parse_to_diluted_reshaped_intermediate_column",
+    "keccak_keccak_parse_to_diluted_reshaped_intermediate_offset",
+    "keccak_keccak_rotated_parity0_column",
+    "keccak_keccak_rotated_parity0_offset",
+    "keccak_keccak_rotated_parity1_column",
+    "keccak_keccak_rotated_parity1_offset",
+    "keccak_keccak_rotated_parity2_column",
+    "keccak_keccak_rotated_parity2_offset",
+    "keccak_keccak_rotated_parity3_column",
+    "keccak_keccak_rotated_parity3_offset",
+    "keccak_keccak_rotated_parity4_column",
+    "keccak_keccak_rotated_parity4_offset",
+    "keccak_row_ratio",
+    "mem_pool_addr_column",
+    "mem_pool_addr_offset",
+    "mem_pool_value_column",
+    "mem_pool_value_offset",
+    "memory_multi_column_perm_perm_cum_prod0_column",
+    "memory_multi_column_perm_perm_cum_prod0_offset",
+    "memory_sorted_addr_column",
+    "memory_sorted_addr_offset",
+    "memory_sorted_value_column",
+    "memory_sorted_value_offset",
+    "memory_units_row_ratio",
+    "mul_mod_a0_suboffset",
+    "mul_mod_a1_suboffset",
+    "mul_mod_a2_suboffset",
+    "mul_mod_a3_suboffset",
+    "mul_mod_a_offset_suboffset",
+    "mul_mod_b0_suboffset",
+    "mul_mod_b1_suboffset",
+    "mul_mod_b2_suboffset",
+    "mul_mod_b3_suboffset",
+    "mul_mod_b_offset_suboffset",
+    "mul_mod_c0_suboffset",
+    "mul_mod_c1_suboffset",
+    "mul_mod_c2_suboffset",
+    "mul_mod_c3_suboffset",
+    "mul_mod_c_offset_suboffset",
+    "mul_mod_carry0_part0_suboffset",
+    "mul_mod_carry0_part1_suboffset",
+    "mul_mod_carry0_part2_suboffset",
+    "mul_mod_carry0_part3_suboffset",
+    "mul_mod_carry0_part4_suboffset",
+    "mul_mod_carry0_part5_suboffset",
+    "mul_mod_carry0_part6_suboffset",
+    "mul_mod_carry1_part0_suboffset",
+    "mul_mod_carry1_part1_suboffset",
+    "mul_mod_carry1_part2_suboffset",
+    "mul_mod_carry1_part3_suboffset",
+    "mul_mod_carry1_part4_suboffset",
+    "mul_mod_carry1_part5_suboffset",
+    "mul_mod_carry1_part6_suboffset",
+    "mul_mod_carry2_part0_suboffset",
+    "mul_mod_carry2_part1_suboffset",
+    "mul_mod_carry2_part2_suboffset",
+    "mul_mod_carry2_part3_suboffset",
+    "mul_mod_carry2_part4_suboffset",
+    "mul_mod_carry2_part5_suboffset",
+    "mul_mod_carry2_part6_suboffset",
+    "mul_mod_carry3_part0_suboffset",
+    "mul_mod_carry3_part1_suboffset",
+    "mul_mod_carry3_part2_suboffset",
+    "mul_mod_carry3_part3_suboffset",
+    "mul_mod_carry3_part4_suboffset",
+    "mul_mod_carry3_part5_suboffset",
+    "mul_mod_carry3_part6_suboffset",
+    "mul_mod_carry4_part0_suboffset",
+    "mul_mod_carry4_part1_suboffset",
+    "mul_mod_carry4_part2_suboffset",
+    "mul_mod_carry4_part3_suboffset",
+    "mul_mod_carry4_part4_suboffset",
+    "mul_mod_carry4_part5_suboffset",
+    "mul_mod_carry4_part6_suboffset",
+    "mul_mod_carry5_part0_suboffset",
+    "mul_mod_carry5_part1_suboffset",
+    "mul_mod_carry5_part2_suboffset",
+    "mul_mod_carry5_part3_suboffset",
+    "mul_mod_carry5_part4_suboffset",
+    "mul_mod_carry5_part5_suboffset",
+    "mul_mod_carry5_part6_suboffset",
+    "mul_mod_n_suboffset",
+    "mul_mod_offsets_ptr_suboffset",
+    "mul_mod_p0_suboffset",
+    "mul_mod_p1_suboffset",
+    "mul_mod_p2_suboffset",
+    "mul_mod_p3_suboffset",
+    "mul_mod_p_multiplier0_part0_suboffset",
+    "mul_mod_p_multiplier0_part1_suboffset",
+    "mul_mod_p_multiplier0_part2_suboffset",
+    "mul_mod_p_multiplier0_part3_suboffset",
+    "mul_mod_p_multiplier0_part4_suboffset",
+    "mul_mod_p_multiplier0_part5_suboffset",
+    "mul_mod_p_multiplier1_part0_suboffset",
+    "mul_mod_p_multiplier1_part1_suboffset",
+    "mul_mod_p_multiplier1_part2_suboffset",
+    "mul_mod_p_multiplier1_part3_suboffset",
+    "mul_mod_p_multiplier1_part4_suboffset",
+    "mul_mod_p_multiplier1_part5_suboffset",
+    "mul_mod_p_multiplier2_part0_suboffset",
+    "mul_mod_p_multiplier2_part1_suboffset",
+    "mul_mod_p_multiplier2_part2_suboffset",
+    "mul_mod_p_multiplier2_part3_suboffset",
+    "mul_mod_p_multiplier2_part4_suboffset",
+    "mul_mod_p_multiplier2_part5_suboffset",
+    "mul_mod_p_multiplier3_part0_suboffset",
+    "mul_mod_p_multiplier3_part1_suboffset",
+    "mul_mod_p_multiplier3_part2_suboffset",
+    "mul_mod_p_multiplier3_part3_suboffset",
+    "mul_mod_p_multiplier3_part4_suboffset",
+    "mul_mod_p_multiplier3_part5_suboffset",
+    "mul_mod_row_ratio",
+    "mul_mod_values_ptr_suboffset",
+    "num_columns_first",
+    "num_columns_second",
+    "orig_public_memory_suboffset",
+    "pedersen_hash0_ec_subset_sum_bit_unpacking_prod_ones192_column",
+    "pedersen_hash0_ec_subset_sum_bit_unpacking_prod_ones192_offset",
+    "pedersen_hash0_ec_subset_sum_bit_unpacking_prod_ones196_column",
+    "pedersen_hash0_ec_subset_sum_bit_unpacking_prod_ones196_offset",
+    "pedersen_hash0_ec_subset_sum_partial_sum_x_column",
+    "pedersen_hash0_ec_subset_sum_partial_sum_x_offset",
+    "pedersen_hash0_ec_subset_sum_partial_sum_y_column",
+    "pedersen_hash0_ec_subset_sum_partial_sum_y_offset",
+    "pedersen_hash0_ec_subset_sum_selector_column",
+    "pedersen_hash0_ec_subset_sum_selector_offset",
+    "pedersen_hash0_ec_subset_sum_slope_column",
+    "pedersen_hash0_ec_subset_sum_slope_offset",
+    "pedersen_input0_suboffset",
+    "pedersen_input1_suboffset",
+    "pedersen_output_suboffset",
+    "pedersen_builtin_row_ratio",
+    "poseidon_param_0_input_output_suboffset",
+    "poseidon_param_1_input_output_suboffset",
+    "poseidon_param_2_input_output_suboffset",
+    "poseidon_poseidon_full_rounds_state0_column",
+    "poseidon_poseidon_full_rounds_state0_offset",
+    "poseidon_poseidon_full_rounds_state0_squared_column",
+    "poseidon_poseidon_full_rounds_state0_squared_offset",
+    "poseidon_poseidon_full_rounds_state1_column",
+    "poseidon_poseidon_full_rounds_state1_offset",
+    "poseidon_poseidon_full_rounds_state1_squared_column",
+    "poseidon_poseidon_full_rounds_state1_squared_offset",
+    "poseidon_poseidon_full_rounds_state2_column",
+    "poseidon_poseidon_full_rounds_state2_offset",
+    "poseidon_poseidon_full_rounds_state2_squared_column",
+    "poseidon_poseidon_full_rounds_state2_squared_offset",
+    "poseidon_poseidon_partial_rounds_state0_column",
+    "poseidon_poseidon_partial_rounds_state0_offset",
+    "poseidon_poseidon_partial_rounds_state0_squared_column",
+    "poseidon_poseidon_partial_rounds_state0_squared_offset",
+    "poseidon_poseidon_partial_rounds_state1_column",
+    "poseidon_poseidon_partial_rounds_state1_offset",
+    "poseidon_poseidon_partial_rounds_state1_squared_column",
+    "poseidon_poseidon_partial_rounds_state1_squared_offset",
+    "poseidon_row_ratio",
+    "range_check16_perm_cum_prod0_column",
+    "range_check16_perm_cum_prod0_offset",
+    "range_check16_sorted_column",
+    "range_check16_sorted_offset",
+    "range_check16_pool_column",
+    "range_check16_pool_offset",
+    "range_check96_builtin_inner_range_check0_suboffset",
+    "range_check96_builtin_inner_range_check1_suboffset",
+    "range_check96_builtin_inner_range_check2_suboffset",
+    "range_check96_builtin_inner_range_check3_suboffset",
+    "range_check96_builtin_inner_range_check4_suboffset",
+    "range_check96_builtin_inner_range_check5_suboffset",
+    "range_check96_builtin_mem_suboffset",
+    "range_check96_builtin_row_ratio",
+    "range_check_builtin_inner_range_check_suboffset",
+    "range_check_builtin_mem_suboffset",
+    "range_check_builtin_row_ratio",
+    "range_check_units_row_ratio",
+    "uses_add_mod_builtin",
+    "uses_bitwise_builtin",
+    "uses_ec_op_builtin",
+    "uses_ecdsa_builtin",
+    "uses_keccak_builtin",
+    "uses_mul_mod_builtin",
+    "uses_pedersen_builtin",
+    "uses_poseidon_builtin",
+    "uses_range_check96_builtin",
+    "uses_range_check_builtin",
+];
+
 impl From<Vec<u32>> for DynamicParams {
     fn from(vec: Vec<u32>) -> Self {
         // Ensure the vector has the correct length
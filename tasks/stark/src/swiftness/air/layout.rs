@@ -0,0 +1,251 @@
+use super::public_memory::PublicInput;
+use super::{recursive, recursive_with_poseidon, small, starknet};
+use felt::Felt;
+use utils::layout::{LayoutStorage, StoredGlobalValues};
+use utils::{ensure, TaskError};
+
+// Cairo layouts, identified in the public input by their name encoded as a felt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutId {
+    Dex,
+    Plain,
+    Recursive,
+    RecursiveWithPoseidon,
+    Small,
+    Starknet,
+    StarknetWithKeccak,
+    Dynamic,
+}
+
+pub mod codes {
+    use felt::Felt;
+
+    pub const DEX: Felt = Felt::from_hex_unchecked("0x646578");
+    pub const PLAIN: Felt = Felt::from_hex_unchecked("0x706c61696e");
+    pub const RECURSIVE: Felt = Felt::from_hex_unchecked("0x726563757273697665");
+    pub const RECURSIVE_WITH_POSEIDON: Felt =
+        Felt::from_hex_unchecked("0x7265637572736976655f776974685f706f736569646f6e");
+    pub const SMALL: Felt = Felt::from_hex_unchecked("0x736d616c6c");
+    pub const STARKNET: Felt = Felt::from_hex_unchecked("0x737461726b6e6574");
+    pub const STARKNET_WITH_KECCAK: Felt =
+        Felt::from_hex_unchecked("0x737461726b6e65745f776974685f6b656363616b");
+    pub const DYNAMIC: Felt = Felt::from_hex_unchecked("0x64796e616d6963");
+}
+
+impl LayoutId {
+    pub const ALL: [LayoutId; 8] = [
+        LayoutId::Dex,
+        LayoutId::Plain,
+        LayoutId::Recursive,
+        LayoutId::RecursiveWithPoseidon,
+        LayoutId::Small,
+        LayoutId::Starknet,
+        LayoutId::StarknetWithKeccak,
+        LayoutId::Dynamic,
+    ];
+
+    pub fn from_code(code: Felt) -> Option<Self> {
+        Self::ALL.into_iter().find(|layout| layout.code() == code)
+    }
+
+    pub fn code(&self) -> Felt {
+        match self {
+            LayoutId::Dex => codes::DEX,
+            LayoutId::Plain => codes::PLAIN,
+            LayoutId::Recursive => codes::RECURSIVE,
+            LayoutId::RecursiveWithPoseidon => codes::RECURSIVE_WITH_POSEIDON,
+            LayoutId::Small => codes::SMALL,
+            LayoutId::Starknet => codes::STARKNET,
+            LayoutId::StarknetWithKeccak => codes::STARKNET_WITH_KECCAK,
+            LayoutId::Dynamic => codes::DYNAMIC,
+        }
+    }
+
+    // Parameters of the layouts with a constraint and OODS evaluation in this verifier.
+    pub fn params(&self) -> Option<&'static LayoutParams> {
+        match self {
+            LayoutId::Recursive => Some(&recursive::PARAMS),
+            LayoutId::RecursiveWithPoseidon => Some(&recursive_with_poseidon::PARAMS),
+            LayoutId::Small => Some(&small::PARAMS),
+            LayoutId::Starknet => Some(&starknet::PARAMS),
+            _ => None,
+        }
+    }
+
+    pub fn is_supported(&self) -> bool {
+        self.params().is_some()
+    }
+
+    // Reads the layout of a proof from the code in its public input, rejecting the layouts that
+    // this verifier has no AIR for. The dynamic layout only has its params validated: there is no
+    // composition or OODS evaluation reading the column and row offsets from them.
+    pub fn supported(code: Felt) -> Result<(Self, &'static LayoutParams), TaskError> {
+        let layout =
+            Self::from_code(code).ok_or(TaskError::InvalidPublicInput("Invalid layout code"))?;
+        ensure(
+            layout != LayoutId::Dynamic,
+            TaskError::InvalidPublicInput("Dynamic layout evaluation is not supported"),
+        )?;
+        let params = layout
+            .params()
+            .ok_or(TaskError::InvalidPublicInput("Unsupported layout"))?;
+        Ok((layout, params))
+    }
+}
+
+// Layout constants needed by the tasks, which only learn the layout from the public input.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LayoutParams {
+    pub constraint_degree: usize,
+    pub n_constraints: usize,
+    pub mask_size: usize,
+    pub num_columns_first: u32,
+    pub num_columns_second: u32,
+    pub n_interaction_elements: u32,
+    pub n_segments: usize,
+    pub public_memory_step: usize,
+    pub has_diluted_pool: bool,
+    // Steps per periodic column copy, including the builtin repetitions.
+    pub pedersen_builtin_ratio: usize,
+    pub ecdsa_builtin_ratio: Option<usize>,
+    pub poseidon_ratio: Option<usize>,
+    pub builtins: &'static [BuiltinSegment],
+}
+
+impl LayoutParams {
+    // Mask values followed by the composition columns evaluated at the OODS point.
+    pub const fn oods_values_size(&self) -> usize {
+        self.mask_size + self.constraint_degree
+    }
+
+    // Trace and composition column values of a single query.
+    pub const fn column_values_size(&self) -> usize {
+        (self.num_columns_first + self.num_columns_second) as usize + self.constraint_degree
+    }
+}
+
+// Memory segment of a builtin, whose uses are bounded by the instances that fit in the trace.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BuiltinSegment {
+    pub segment: usize,
+    pub row_ratio: u32,
+    pub cells_per_instance: u32,
+    pub error: &'static str,
+}
+
+pub trait LayoutTrait {
+    // type InteractionElements;
+
+    const CONSTRAINT_DEGREE: usize;
+    const N_CONSTRAINTS: usize;
+    const MASK_SIZE: usize;
+    const N_INTERACTION_ELEMENTS: u32;
+}
+
+pub trait StaticLayoutTrait {
+    const NUM_COLUMNS_FIRST: u32;
+    const NUM_COLUMNS_SECOND: u32;
+}
+
+pub trait GenericLayoutTrait {
+    fn get_num_columns_first(public_input: &PublicInput) -> Option<u32>;
+    fn get_num_columns_second(public_input: &PublicInput) -> Option<u32>;
+}
+
+// Member expressions that EvalCompositionPolynomial computes, from which every layout takes
+// the ones of its global values.
+#[repr(C)]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ComputedValues {
+    pub trace_length: Felt,
+    pub public_memory_prod_ratio: Felt,
+    pub diluted_prod: Felt,
+    pub pedersen_points_x: Felt,
+    pub pedersen_points_y: Felt,
+    pub ecdsa_generator_points_x: Felt,
+    pub ecdsa_generator_points_y: Felt,
+    pub poseidon_full_round_key0: Felt,
+    pub poseidon_full_round_key1: Felt,
+    pub poseidon_full_round_key2: Felt,
+    pub poseidon_partial_round_key0: Felt,
+    pub poseidon_partial_round_key1: Felt,
+}
+
+// Sizes the account buffers for the largest supported layout, since the layout of a proof is
+// only known at runtime.
+pub struct AnyLayout {}
+
+const fn max_of(values: &[usize]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < values.len() {
+        if values[i] > max {
+            max = values[i];
+        }
+        i += 1;
+    }
+    max
+}
+
+macro_rules! max_over_layouts {
+    ($item:ident) => {
+        max_of(&[
+            <recursive::Layout as LayoutStorage>::$item as usize,
+            <recursive_with_poseidon::Layout as LayoutStorage>::$item as usize,
+            <small::Layout as LayoutStorage>::$item as usize,
+            <starknet::Layout as LayoutStorage>::$item as usize,
+        ])
+    };
+}
+
+impl LayoutStorage for AnyLayout {
+    type GlobalValues = GlobalValuesStorage;
+
+    const CONSTRAINT_DEGREE: usize = max_over_layouts!(CONSTRAINT_DEGREE);
+    const N_CONSTRAINTS: usize = max_over_layouts!(N_CONSTRAINTS);
+    const MASK_SIZE: usize = max_over_layouts!(MASK_SIZE);
+    const NUM_COLUMNS_FIRST: u32 = max_over_layouts!(NUM_COLUMNS_FIRST) as u32;
+    const NUM_COLUMNS_SECOND: u32 = max_over_layouts!(NUM_COLUMNS_SECOND) as u32;
+    const POWS_SIZE: usize = max_over_layouts!(POWS_SIZE);
+    const DOMAINS_SIZE: usize = max_over_layouts!(DOMAINS_SIZE);
+}
+
+const GLOBAL_VALUES_STORAGE_SIZE: usize = max_of(&[
+    core::mem::size_of::<recursive::GlobalValues>(),
+    core::mem::size_of::<recursive_with_poseidon::GlobalValues>(),
+    core::mem::size_of::<small::GlobalValues>(),
+    core::mem::size_of::<starknet::GlobalValues>(),
+])
+.div_ceil(core::mem::size_of::<Felt>());
+
+// Holds the global values of whichever supported layout the proof uses.
+#[repr(C)]
+#[derive(Debug, PartialEq)]
+pub struct GlobalValuesStorage([Felt; GLOBAL_VALUES_STORAGE_SIZE]);
+
+impl Default for GlobalValuesStorage {
+    fn default() -> Self {
+        Self([Felt::ZERO; GLOBAL_VALUES_STORAGE_SIZE])
+    }
+}
+
+unsafe impl StoredGlobalValues for GlobalValuesStorage {}
+
+// StarkCurve
+pub mod stark_curve {
+    use felt::Felt;
+
+    pub const ALPHA: Felt = Felt::from_hex_unchecked("0x1");
+    pub const BETA: Felt = Felt::from_hex_unchecked(
+        "0x6f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89",
+    );
+    pub const ORDER: Felt = Felt::from_hex_unchecked(
+        "0x800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f",
+    );
+    pub const GEN_X: Felt = Felt::from_hex_unchecked(
+        "0x1ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca",
+    );
+    pub const GEN_Y: Felt = Felt::from_hex_unchecked(
+        "0x5668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f",
+    );
+}
@@ -2,8 +2,12 @@ pub mod consts;
 pub mod diluted;
 pub mod domains;
 pub mod dynamic;
+pub mod layout;
 pub mod periodic_columns;
 pub mod public_memory;
+pub mod recursive;
 pub mod recursive_with_poseidon;
+pub mod small;
+pub mod starknet;
 pub mod trace;
 pub mod types;
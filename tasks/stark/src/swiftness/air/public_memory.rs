@@ -1,4 +1,6 @@
-use crate::funvec::{FunVec, FUNVEC_SEGMENTS};
+use crate::funvec::{
+    FunVec, FUNVEC_CONTINUOUS_PAGES, FUNVEC_CONTINUOUS_PAGE_VALUES, FUNVEC_SEGMENTS,
+};
 use felt::Felt;
 
 use super::{
@@ -6,6 +8,7 @@ use super::{
     types::{ContinuousPageHeader, Page, SegmentInfo},
 };
 use felt::NonZeroFelt;
+use utils::{ensure, TaskError};
 
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -14,15 +17,33 @@ pub struct PublicInput {
     pub range_check_min: Felt,
     pub range_check_max: Felt,
     pub layout: Felt,
-    pub dynamic_params: Option<DynamicParams>,
+    // The public input is uploaded with the proof, so whether the layout has dynamic params is
+    // kept as a raw flag and only read through dynamic_params().
+    pub has_dynamic_params: u32,
+    pub dynamic_params: DynamicParams,
     pub segments: FunVec<SegmentInfo, FUNVEC_SEGMENTS>,
     pub padding_addr: Felt,
     pub padding_value: Felt,
     pub main_page: Page,
-    pub continuous_page_headers: FunVec<ContinuousPageHeader, 0>,
+    pub continuous_page_headers: FunVec<ContinuousPageHeader, FUNVEC_CONTINUOUS_PAGES>,
+    // Values of the continuous pages, one page after the other in the order of the headers.
+    pub continuous_page_values: FunVec<Felt, FUNVEC_CONTINUOUS_PAGE_VALUES>,
 }
 
 impl PublicInput {
+    pub fn set_dynamic_params(&mut self, dynamic_params: Option<DynamicParams>) {
+        self.has_dynamic_params = dynamic_params.is_some() as u32;
+        self.dynamic_params = dynamic_params.unwrap_or_default();
+    }
+
+    pub fn dynamic_params(&self) -> Result<Option<&DynamicParams>, TaskError> {
+        match self.has_dynamic_params {
+            0 => Ok(None),
+            1 => Ok(Some(&self.dynamic_params)),
+            _ => Err(TaskError::InvalidPublicInput("Invalid dynamic params flag")),
+        }
+    }
+
     // Returns the ratio between the product of all public memory cells and z^|public_memory|.
     // This is the value that needs to be at the memory_multi_column_perm_perm_public_memory_prod
     // member expression.
@@ -32,27 +53,38 @@ impl PublicInput {
         z: Felt,
         alpha: Felt,
         public_memory_column_size: Felt,
-    ) -> Felt {
+    ) -> Result<Felt, TaskError> {
         let (pages_product, total_length) = self.get_public_memory_product(z, alpha);
 
         // Pad and divide
         let numerator = z.pow_felt(&public_memory_column_size);
         let padded = z - (self.padding_addr + alpha * self.padding_value);
 
-        assert!(total_length <= public_memory_column_size);
+        ensure(
+            total_length <= public_memory_column_size,
+            TaskError::InvalidPublicInput("Public memory exceeds the trace"),
+        )?;
         let denominator_pad = padded.pow_felt(&(public_memory_column_size - total_length));
 
-        numerator
-            .field_div(&NonZeroFelt::from_felt_unchecked(pages_product))
-            .field_div(&NonZeroFelt::from_felt_unchecked(denominator_pad))
+        let pages_product = NonZeroFelt::try_from(pages_product)
+            .map_err(|_| TaskError::InvalidPublicInput("Zero public memory product"))?;
+        let denominator_pad = NonZeroFelt::try_from(denominator_pad)
+            .map_err(|_| TaskError::InvalidPublicInput("Zero public memory padding product"))?;
+        Ok(numerator
+            .field_div(&pages_product)
+            .field_div(&denominator_pad))
     }
     // Returns the product of all public memory cells.
     #[inline(always)]
     pub fn get_public_memory_product(&self, z: Felt, alpha: Felt) -> (Felt, Felt) {
         let main_page_prod = self.main_page.get_product(z, alpha);
 
-        let (continuous_pages_prod, continuous_pages_total_length) =
-            get_continuous_pages_product(&self.continuous_page_headers);
+        let (continuous_pages_prod, continuous_pages_total_length) = get_continuous_pages_product(
+            &self.continuous_page_headers,
+            &self.continuous_page_values,
+            z,
+            alpha,
+        );
 
         let prod = main_page_prod * continuous_pages_prod;
         let total_length = Felt::from(self.main_page.0.len()) + continuous_pages_total_length;
@@ -60,13 +92,29 @@ impl PublicInput {
         (prod, total_length)
     }
 }
+// The product is computed from the page values rather than taken from the prod of the headers, so
+// that the pages in the trace are the ones whose hashes GetHash checks.
 #[inline(always)]
-fn get_continuous_pages_product(page_headers: &FunVec<ContinuousPageHeader, 0>) -> (Felt, Felt) {
+fn get_continuous_pages_product(
+    page_headers: &FunVec<ContinuousPageHeader, FUNVEC_CONTINUOUS_PAGES>,
+    page_values: &FunVec<Felt, FUNVEC_CONTINUOUS_PAGE_VALUES>,
+    z: Felt,
+    alpha: Felt,
+) -> (Felt, Felt) {
     let mut res = Felt::ONE;
     let mut total_length = Felt::ZERO;
+    let mut values = page_values.as_slice().iter();
 
     for header in page_headers {
-        res *= header.prod;
+        let page_end = header.start_address + header.size;
+        let mut address = header.start_address;
+        while address != page_end {
+            let Some(value) = values.next() else {
+                break;
+            };
+            res *= z - (address + alpha * *value);
+            address += Felt::ONE;
+        }
         total_length += header.size
     }
 
@@ -0,0 +1,1840 @@
+use super::{GlobalValues, Layout, LayoutTrait};
+use crate::swiftness::air::consts::*;
+use felt::felt_nonzero;
+use felt::Felt;
+
+// Maps the column_row notation of the constraints to the flat mask value indices, resolved
+// at expansion time so that an access outside of the mask does not compile.
+macro_rules! column_row {
+    ($mask_values:expr, 0, 0) => {
+        $mask_values[0]
+    };
+    ($mask_values:expr, 0, 1) => {
+        $mask_values[1]
+    };
+    ($mask_values:expr, 0, 2) => {
+        $mask_values[2]
+    };
+    ($mask_values:expr, 0, 3) => {
+        $mask_values[3]
+    };
+    ($mask_values:expr, 0, 4) => {
+        $mask_values[4]
+    };
+    ($mask_values:expr, 0, 5) => {
+        $mask_values[5]
+    };
+    ($mask_values:expr, 0, 6) => {
+        $mask_values[6]
+    };
+    ($mask_values:expr, 0, 7) => {
+        $mask_values[7]
+    };
+    ($mask_values:expr, 0, 8) => {
+        $mask_values[8]
+    };
+    ($mask_values:expr, 0, 9) => {
+        $mask_values[9]
+    };
+    ($mask_values:expr, 0, 10) => {
+        $mask_values[10]
+    };
+    ($mask_values:expr, 0, 11) => {
+        $mask_values[11]
+    };
+    ($mask_values:expr, 0, 12) => {
+        $mask_values[12]
+    };
+    ($mask_values:expr, 0, 13) => {
+        $mask_values[13]
+    };
+    ($mask_values:expr, 0, 14) => {
+        $mask_values[14]
+    };
+    ($mask_values:expr, 0, 15) => {
+        $mask_values[15]
+    };
+    ($mask_values:expr, 1, 0) => {
+        $mask_values[16]
+    };
+    ($mask_values:expr, 1, 1) => {
+        $mask_values[17]
+    };
+    ($mask_values:expr, 1, 2) => {
+        $mask_values[18]
+    };
+    ($mask_values:expr, 1, 4) => {
+        $mask_values[19]
+    };
+    ($mask_values:expr, 1, 6) => {
+        $mask_values[20]
+    };
+    ($mask_values:expr, 1, 8) => {
+        $mask_values[21]
+    };
+    ($mask_values:expr, 1, 10) => {
+        $mask_values[22]
+    };
+    ($mask_values:expr, 1, 12) => {
+        $mask_values[23]
+    };
+    ($mask_values:expr, 1, 14) => {
+        $mask_values[24]
+    };
+    ($mask_values:expr, 1, 16) => {
+        $mask_values[25]
+    };
+    ($mask_values:expr, 1, 18) => {
+        $mask_values[26]
+    };
+    ($mask_values:expr, 1, 20) => {
+        $mask_values[27]
+    };
+    ($mask_values:expr, 1, 22) => {
+        $mask_values[28]
+    };
+    ($mask_values:expr, 1, 24) => {
+        $mask_values[29]
+    };
+    ($mask_values:expr, 1, 26) => {
+        $mask_values[30]
+    };
+    ($mask_values:expr, 1, 28) => {
+        $mask_values[31]
+    };
+    ($mask_values:expr, 1, 30) => {
+        $mask_values[32]
+    };
+    ($mask_values:expr, 1, 32) => {
+        $mask_values[33]
+    };
+    ($mask_values:expr, 1, 33) => {
+        $mask_values[34]
+    };
+    ($mask_values:expr, 1, 64) => {
+        $mask_values[35]
+    };
+    ($mask_values:expr, 1, 65) => {
+        $mask_values[36]
+    };
+    ($mask_values:expr, 1, 88) => {
+        $mask_values[37]
+    };
+    ($mask_values:expr, 1, 90) => {
+        $mask_values[38]
+    };
+    ($mask_values:expr, 1, 92) => {
+        $mask_values[39]
+    };
+    ($mask_values:expr, 1, 94) => {
+        $mask_values[40]
+    };
+    ($mask_values:expr, 1, 96) => {
+        $mask_values[41]
+    };
+    ($mask_values:expr, 1, 97) => {
+        $mask_values[42]
+    };
+    ($mask_values:expr, 1, 120) => {
+        $mask_values[43]
+    };
+    ($mask_values:expr, 1, 122) => {
+        $mask_values[44]
+    };
+    ($mask_values:expr, 1, 124) => {
+        $mask_values[45]
+    };
+    ($mask_values:expr, 1, 126) => {
+        $mask_values[46]
+    };
+    ($mask_values:expr, 2, 0) => {
+        $mask_values[47]
+    };
+    ($mask_values:expr, 2, 1) => {
+        $mask_values[48]
+    };
+    ($mask_values:expr, 3, 0) => {
+        $mask_values[49]
+    };
+    ($mask_values:expr, 3, 1) => {
+        $mask_values[50]
+    };
+    ($mask_values:expr, 3, 2) => {
+        $mask_values[51]
+    };
+    ($mask_values:expr, 3, 3) => {
+        $mask_values[52]
+    };
+    ($mask_values:expr, 3, 4) => {
+        $mask_values[53]
+    };
+    ($mask_values:expr, 3, 5) => {
+        $mask_values[54]
+    };
+    ($mask_values:expr, 3, 8) => {
+        $mask_values[55]
+    };
+    ($mask_values:expr, 3, 9) => {
+        $mask_values[56]
+    };
+    ($mask_values:expr, 3, 10) => {
+        $mask_values[57]
+    };
+    ($mask_values:expr, 3, 11) => {
+        $mask_values[58]
+    };
+    ($mask_values:expr, 3, 12) => {
+        $mask_values[59]
+    };
+    ($mask_values:expr, 3, 13) => {
+        $mask_values[60]
+    };
+    ($mask_values:expr, 3, 16) => {
+        $mask_values[61]
+    };
+    ($mask_values:expr, 3, 26) => {
+        $mask_values[62]
+    };
+    ($mask_values:expr, 3, 27) => {
+        $mask_values[63]
+    };
+    ($mask_values:expr, 3, 42) => {
+        $mask_values[64]
+    };
+    ($mask_values:expr, 3, 43) => {
+        $mask_values[65]
+    };
+    ($mask_values:expr, 3, 58) => {
+        $mask_values[66]
+    };
+    ($mask_values:expr, 3, 74) => {
+        $mask_values[67]
+    };
+    ($mask_values:expr, 3, 75) => {
+        $mask_values[68]
+    };
+    ($mask_values:expr, 3, 91) => {
+        $mask_values[69]
+    };
+    ($mask_values:expr, 3, 122) => {
+        $mask_values[70]
+    };
+    ($mask_values:expr, 3, 123) => {
+        $mask_values[71]
+    };
+    ($mask_values:expr, 3, 154) => {
+        $mask_values[72]
+    };
+    ($mask_values:expr, 3, 202) => {
+        $mask_values[73]
+    };
+    ($mask_values:expr, 3, 522) => {
+        $mask_values[74]
+    };
+    ($mask_values:expr, 3, 523) => {
+        $mask_values[75]
+    };
+    ($mask_values:expr, 3, 1034) => {
+        $mask_values[76]
+    };
+    ($mask_values:expr, 3, 1035) => {
+        $mask_values[77]
+    };
+    ($mask_values:expr, 3, 2058) => {
+        $mask_values[78]
+    };
+    ($mask_values:expr, 4, 0) => {
+        $mask_values[79]
+    };
+    ($mask_values:expr, 4, 1) => {
+        $mask_values[80]
+    };
+    ($mask_values:expr, 4, 2) => {
+        $mask_values[81]
+    };
+    ($mask_values:expr, 4, 3) => {
+        $mask_values[82]
+    };
+    ($mask_values:expr, 5, 0) => {
+        $mask_values[83]
+    };
+    ($mask_values:expr, 5, 1) => {
+        $mask_values[84]
+    };
+    ($mask_values:expr, 5, 2) => {
+        $mask_values[85]
+    };
+    ($mask_values:expr, 5, 3) => {
+        $mask_values[86]
+    };
+    ($mask_values:expr, 5, 4) => {
+        $mask_values[87]
+    };
+    ($mask_values:expr, 5, 5) => {
+        $mask_values[88]
+    };
+    ($mask_values:expr, 5, 6) => {
+        $mask_values[89]
+    };
+    ($mask_values:expr, 5, 7) => {
+        $mask_values[90]
+    };
+    ($mask_values:expr, 5, 8) => {
+        $mask_values[91]
+    };
+    ($mask_values:expr, 5, 12) => {
+        $mask_values[92]
+    };
+    ($mask_values:expr, 5, 28) => {
+        $mask_values[93]
+    };
+    ($mask_values:expr, 5, 44) => {
+        $mask_values[94]
+    };
+    ($mask_values:expr, 5, 60) => {
+        $mask_values[95]
+    };
+    ($mask_values:expr, 5, 76) => {
+        $mask_values[96]
+    };
+    ($mask_values:expr, 5, 92) => {
+        $mask_values[97]
+    };
+    ($mask_values:expr, 5, 108) => {
+        $mask_values[98]
+    };
+    ($mask_values:expr, 5, 124) => {
+        $mask_values[99]
+    };
+    ($mask_values:expr, 5, 1021) => {
+        $mask_values[100]
+    };
+    ($mask_values:expr, 5, 1023) => {
+        $mask_values[101]
+    };
+    ($mask_values:expr, 5, 1025) => {
+        $mask_values[102]
+    };
+    ($mask_values:expr, 5, 1027) => {
+        $mask_values[103]
+    };
+    ($mask_values:expr, 5, 2045) => {
+        $mask_values[104]
+    };
+    ($mask_values:expr, 6, 0) => {
+        $mask_values[105]
+    };
+    ($mask_values:expr, 6, 1) => {
+        $mask_values[106]
+    };
+    ($mask_values:expr, 6, 2) => {
+        $mask_values[107]
+    };
+    ($mask_values:expr, 6, 3) => {
+        $mask_values[108]
+    };
+    ($mask_values:expr, 6, 4) => {
+        $mask_values[109]
+    };
+    ($mask_values:expr, 6, 5) => {
+        $mask_values[110]
+    };
+    ($mask_values:expr, 6, 7) => {
+        $mask_values[111]
+    };
+    ($mask_values:expr, 6, 9) => {
+        $mask_values[112]
+    };
+    ($mask_values:expr, 6, 11) => {
+        $mask_values[113]
+    };
+    ($mask_values:expr, 6, 13) => {
+        $mask_values[114]
+    };
+    ($mask_values:expr, 6, 17) => {
+        $mask_values[115]
+    };
+    ($mask_values:expr, 6, 25) => {
+        $mask_values[116]
+    };
+    ($mask_values:expr, 6, 768) => {
+        $mask_values[117]
+    };
+    ($mask_values:expr, 6, 772) => {
+        $mask_values[118]
+    };
+    ($mask_values:expr, 6, 784) => {
+        $mask_values[119]
+    };
+    ($mask_values:expr, 6, 788) => {
+        $mask_values[120]
+    };
+    ($mask_values:expr, 6, 1004) => {
+        $mask_values[121]
+    };
+    ($mask_values:expr, 6, 1008) => {
+        $mask_values[122]
+    };
+    ($mask_values:expr, 6, 1022) => {
+        $mask_values[123]
+    };
+    ($mask_values:expr, 6, 1024) => {
+        $mask_values[124]
+    };
+    ($mask_values:expr, 7, 0) => {
+        $mask_values[125] // inter1_row0
+    };
+    ($mask_values:expr, 7, 1) => {
+        $mask_values[126] // inter1_row1
+    };
+    ($mask_values:expr, 8, 0) => {
+        $mask_values[127] // inter1_row0
+    };
+    ($mask_values:expr, 8, 1) => {
+        $mask_values[128] // inter1_row1
+    };
+    ($mask_values:expr, 9, 0) => {
+        $mask_values[129] // inter1_row0
+    };
+    ($mask_values:expr, 9, 1) => {
+        $mask_values[130] // inter1_row1
+    };
+    ($mask_values:expr, 9, 2) => {
+        $mask_values[131] // inter1_row2
+    };
+    ($mask_values:expr, 9, 5) => {
+        $mask_values[132] // inter1_row5
+    };
+}
+
+// Powers of the evaluation point and of the trace generator used by the constraint domains.
+pub fn eval_composition_powers(
+    point: Felt,
+    trace_generator: Felt,
+    trace_length: Felt,
+    autogenerated_pows: &mut [Felt],
+) {
+    autogenerated_pows[0] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_2048))));
+    autogenerated_pows[1] = autogenerated_pows[0] * autogenerated_pows[0]; // pow(point, (safe_div(global_values.trace_length, 1024))).
+    autogenerated_pows[2] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_128))));
+    autogenerated_pows[3] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_32))));
+    autogenerated_pows[4] = autogenerated_pows[3] * autogenerated_pows[3]; // pow(point, (safe_div(global_values.trace_length, 16))).
+    autogenerated_pows[5] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_4))));
+    autogenerated_pows[6] = autogenerated_pows[5] * autogenerated_pows[5]; // pow(point, (safe_div(global_values.trace_length, 2))).
+    autogenerated_pows[7] = autogenerated_pows[6] * autogenerated_pows[6]; // pow(point, global_values.trace_length).
+    autogenerated_pows[8] = trace_generator.pow_felt(&(trace_length - FELT_128));
+    autogenerated_pows[9] = trace_generator.pow_felt(&(trace_length - FELT_2048));
+    autogenerated_pows[10] = trace_generator.pow_felt(&(trace_length - FELT_1));
+    autogenerated_pows[11] = trace_generator.pow_felt(&(trace_length - FELT_4));
+    autogenerated_pows[12] = trace_generator.pow_felt(&(trace_length - FELT_2));
+    autogenerated_pows[13] = trace_generator.pow_felt(&(trace_length - FELT_16));
+    autogenerated_pows[14] =
+        trace_generator.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_2))));
+    autogenerated_pows[15] =
+        trace_generator.pow_felt(&((FELT_255 * trace_length).floor_div(&felt_nonzero!(FELT_256))));
+    autogenerated_pows[16] =
+        trace_generator.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_64))));
+    autogenerated_pows[17] = autogenerated_pows[16] * autogenerated_pows[16]; // pow(trace_generator, (safe_div(global_values.trace_length, 32))).
+    autogenerated_pows[18] = autogenerated_pows[16] * autogenerated_pows[17]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 64))).
+    autogenerated_pows[19] = autogenerated_pows[16] * autogenerated_pows[18]; // pow(trace_generator, (safe_div(global_values.trace_length, 16))).
+    autogenerated_pows[20] = autogenerated_pows[16] * autogenerated_pows[19]; // pow(trace_generator, (safe_div((safe_mult(5, global_values.trace_length)), 64))).
+    autogenerated_pows[21] = autogenerated_pows[16] * autogenerated_pows[20]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 32))).
+    autogenerated_pows[22] = autogenerated_pows[16] * autogenerated_pows[21]; // pow(trace_generator, (safe_div((safe_mult(7, global_values.trace_length)), 64))).
+    autogenerated_pows[23] = autogenerated_pows[16] * autogenerated_pows[22]; // pow(trace_generator, (safe_div(global_values.trace_length, 8))).
+    autogenerated_pows[24] = autogenerated_pows[16] * autogenerated_pows[23]; // pow(trace_generator, (safe_div((safe_mult(9, global_values.trace_length)), 64))).
+    autogenerated_pows[25] = autogenerated_pows[16] * autogenerated_pows[24]; // pow(trace_generator, (safe_div((safe_mult(5, global_values.trace_length)), 32))).
+    autogenerated_pows[26] = autogenerated_pows[16] * autogenerated_pows[25]; // pow(trace_generator, (safe_div((safe_mult(11, global_values.trace_length)), 64))).
+    autogenerated_pows[27] = autogenerated_pows[16] * autogenerated_pows[26]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 16))).
+    autogenerated_pows[28] = autogenerated_pows[16] * autogenerated_pows[27]; // pow(trace_generator, (safe_div((safe_mult(13, global_values.trace_length)), 64))).
+    autogenerated_pows[29] = autogenerated_pows[16] * autogenerated_pows[28]; // pow(trace_generator, (safe_div((safe_mult(7, global_values.trace_length)), 32))).
+    autogenerated_pows[30] = autogenerated_pows[16] * autogenerated_pows[29]; // pow(trace_generator, (safe_div((safe_mult(15, global_values.trace_length)), 64))).
+    autogenerated_pows[31] =
+        trace_generator.pow_felt(&((FELT_3 * trace_length).floor_div(&felt_nonzero!(FELT_4))));
+    autogenerated_pows[32] = autogenerated_pows[27] * autogenerated_pows[31]; // pow(trace_generator, (safe_div((safe_mult(15, global_values.trace_length)), 16))).
+    autogenerated_pows[33] = autogenerated_pows[18] * autogenerated_pows[32]; // pow(trace_generator, (safe_div((safe_mult(63, global_values.trace_length)), 64))).
+}
+
+pub fn eval_composition_domains(point: Felt, autogenerated_pows: &[Felt], domains: &mut [Felt]) {
+    domains[0] = autogenerated_pows[7] - FELT_1;
+    domains[1] = autogenerated_pows[6] - FELT_1;
+    domains[2] = autogenerated_pows[5] - FELT_1;
+    domains[3] = autogenerated_pows[4] - autogenerated_pows[32];
+    domains[4] = autogenerated_pows[4] - FELT_1;
+    domains[5] = autogenerated_pows[3] - FELT_1;
+    domains[6] = autogenerated_pows[2] - FELT_1;
+    domains[7] = autogenerated_pows[2] - autogenerated_pows[31];
+    let temp = autogenerated_pows[2] - autogenerated_pows[16];
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[17]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[18]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[19]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[20]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[21]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[22]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[23]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[24]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[25]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[26]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[27]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[28]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[29]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[30]);
+    domains[8] = temp * (domains[6]);
+    domains[9] = autogenerated_pows[1] - FELT_1;
+    domains[10] = autogenerated_pows[1] - autogenerated_pows[15];
+    domains[11] = autogenerated_pows[1] - autogenerated_pows[33];
+    domains[12] = autogenerated_pows[0] - autogenerated_pows[14];
+    domains[13] = autogenerated_pows[0] - FELT_1;
+    domains[14] = point - autogenerated_pows[13];
+    domains[15] = point - FELT_1;
+    domains[16] = point - autogenerated_pows[12];
+    domains[17] = point - autogenerated_pows[11];
+    domains[18] = point - autogenerated_pows[10];
+    domains[19] = point - autogenerated_pows[9];
+    domains[20] = point - autogenerated_pows[8];
+}
+
+pub fn eval_composition_constraints(
+    mask_values: &[Felt],
+    constraint_coefficients: &[Felt],
+    domains: &[Felt],
+    global_values: &GlobalValues,
+) -> Felt {
+    let cpu_decode_opcode_range_check_bit_0 = column_row!(mask_values, 0, 0)
+        - (column_row!(mask_values, 0, 1) + column_row!(mask_values, 0, 1));
+    let cpu_decode_opcode_range_check_bit_2 = column_row!(mask_values, 0, 2)
+        - (column_row!(mask_values, 0, 3) + column_row!(mask_values, 0, 3));
+    let cpu_decode_opcode_range_check_bit_4 = column_row!(mask_values, 0, 4)
+        - (column_row!(mask_values, 0, 5) + column_row!(mask_values, 0, 5));
+    let cpu_decode_opcode_range_check_bit_3 = column_row!(mask_values, 0, 3)
+        - (column_row!(mask_values, 0, 4) + column_row!(mask_values, 0, 4));
+    let cpu_decode_flag_op1_base_op0_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_2
+            + cpu_decode_opcode_range_check_bit_4
+            + cpu_decode_opcode_range_check_bit_3);
+    let cpu_decode_opcode_range_check_bit_5 = column_row!(mask_values, 0, 5)
+        - (column_row!(mask_values, 0, 6) + column_row!(mask_values, 0, 6));
+    let cpu_decode_opcode_range_check_bit_6 = column_row!(mask_values, 0, 6)
+        - (column_row!(mask_values, 0, 7) + column_row!(mask_values, 0, 7));
+    let cpu_decode_opcode_range_check_bit_9 = column_row!(mask_values, 0, 9)
+        - (column_row!(mask_values, 0, 10) + column_row!(mask_values, 0, 10));
+    let cpu_decode_flag_res_op1_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_5
+            + cpu_decode_opcode_range_check_bit_6
+            + cpu_decode_opcode_range_check_bit_9);
+    let cpu_decode_opcode_range_check_bit_7 = column_row!(mask_values, 0, 7)
+        - (column_row!(mask_values, 0, 8) + column_row!(mask_values, 0, 8));
+    let cpu_decode_opcode_range_check_bit_8 = column_row!(mask_values, 0, 8)
+        - (column_row!(mask_values, 0, 9) + column_row!(mask_values, 0, 9));
+    let cpu_decode_flag_pc_update_regular_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_7
+            + cpu_decode_opcode_range_check_bit_8
+            + cpu_decode_opcode_range_check_bit_9);
+    let cpu_decode_opcode_range_check_bit_12 = column_row!(mask_values, 0, 12)
+        - (column_row!(mask_values, 0, 13) + column_row!(mask_values, 0, 13));
+    let cpu_decode_opcode_range_check_bit_13 = column_row!(mask_values, 0, 13)
+        - (column_row!(mask_values, 0, 14) + column_row!(mask_values, 0, 14));
+    let cpu_decode_fp_update_regular_0 =
+        FELT_1 - (cpu_decode_opcode_range_check_bit_12 + cpu_decode_opcode_range_check_bit_13);
+    let cpu_decode_opcode_range_check_bit_1 = column_row!(mask_values, 0, 1)
+        - (column_row!(mask_values, 0, 2) + column_row!(mask_values, 0, 2));
+    let npc_reg_0 = column_row!(mask_values, 3, 0) + cpu_decode_opcode_range_check_bit_2 + FELT_1;
+    let cpu_decode_opcode_range_check_bit_10 = column_row!(mask_values, 0, 10)
+        - (column_row!(mask_values, 0, 11) + column_row!(mask_values, 0, 11));
+    let cpu_decode_opcode_range_check_bit_11 = column_row!(mask_values, 0, 11)
+        - (column_row!(mask_values, 0, 12) + column_row!(mask_values, 0, 12));
+    let cpu_decode_opcode_range_check_bit_14 = column_row!(mask_values, 0, 14)
+        - (column_row!(mask_values, 0, 15) + column_row!(mask_values, 0, 15));
+    let memory_address_diff_0 = column_row!(mask_values, 4, 2) - column_row!(mask_values, 4, 0);
+    let range_check16_diff_0 = column_row!(mask_values, 5, 6) - column_row!(mask_values, 5, 2);
+    let pedersen_hash0_ec_subset_sum_bit_0 = column_row!(mask_values, 6, 0)
+        - (column_row!(mask_values, 6, 4) + column_row!(mask_values, 6, 4));
+    let pedersen_hash0_ec_subset_sum_bit_neg_0 = FELT_1 - pedersen_hash0_ec_subset_sum_bit_0;
+    let range_check_builtin_value0_0 = column_row!(mask_values, 5, 12);
+    let range_check_builtin_value1_0 =
+        range_check_builtin_value0_0 * global_values.offset_size + column_row!(mask_values, 5, 28);
+    let range_check_builtin_value2_0 =
+        range_check_builtin_value1_0 * global_values.offset_size + column_row!(mask_values, 5, 44);
+    let range_check_builtin_value3_0 =
+        range_check_builtin_value2_0 * global_values.offset_size + column_row!(mask_values, 5, 60);
+    let range_check_builtin_value4_0 =
+        range_check_builtin_value3_0 * global_values.offset_size + column_row!(mask_values, 5, 76);
+    let range_check_builtin_value5_0 =
+        range_check_builtin_value4_0 * global_values.offset_size + column_row!(mask_values, 5, 92);
+    let range_check_builtin_value6_0 =
+        range_check_builtin_value5_0 * global_values.offset_size + column_row!(mask_values, 5, 108);
+    let range_check_builtin_value7_0 =
+        range_check_builtin_value6_0 * global_values.offset_size + column_row!(mask_values, 5, 124);
+    let bitwise_sum_var_0_0 = column_row!(mask_values, 1, 0)
+        + column_row!(mask_values, 1, 2) * FELT_2
+        + column_row!(mask_values, 1, 4) * FELT_4
+        + column_row!(mask_values, 1, 6) * FELT_8
+        + column_row!(mask_values, 1, 8) * FELT_18446744073709551616
+        + column_row!(mask_values, 1, 10) * FELT_36893488147419103232
+        + column_row!(mask_values, 1, 12) * FELT_73786976294838206464
+        + column_row!(mask_values, 1, 14) * FELT_147573952589676412928;
+    let bitwise_sum_var_8_0 = column_row!(mask_values, 1, 16)
+        * FELT_340282366920938463463374607431768211456
+        + column_row!(mask_values, 1, 18) * FELT_680564733841876926926749214863536422912
+        + column_row!(mask_values, 1, 20) * FELT_1361129467683753853853498429727072845824
+        + column_row!(mask_values, 1, 22) * FELT_2722258935367507707706996859454145691648
+        + column_row!(mask_values, 1, 24)
+            * FELT_6277101735386680763835789423207666416102355444464034512896
+        + column_row!(mask_values, 1, 26)
+            * FELT_12554203470773361527671578846415332832204710888928069025792
+        + column_row!(mask_values, 1, 28)
+            * FELT_25108406941546723055343157692830665664409421777856138051584
+        + column_row!(mask_values, 1, 30)
+            * FELT_50216813883093446110686315385661331328818843555712276103168;
+
+    let mut total_sum = FELT_0;
+
+    // Constraint: cpu/decode/opcode_range_check/bit.
+    let value = (cpu_decode_opcode_range_check_bit_0 * cpu_decode_opcode_range_check_bit_0
+        - cpu_decode_opcode_range_check_bit_0)
+        * domains[3].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[0] * value;
+
+    // Constraint: cpu/decode/opcode_range_check/zero.
+    let value = (column_row!(mask_values, 0, 0)).field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[1] * value;
+
+    // Constraint: cpu/decode/opcode_range_check_input.
+    let value = (column_row!(mask_values, 3, 1)
+        - (((column_row!(mask_values, 0, 0) * global_values.offset_size
+            + column_row!(mask_values, 5, 4))
+            * global_values.offset_size
+            + column_row!(mask_values, 5, 8))
+            * global_values.offset_size
+            + column_row!(mask_values, 5, 0)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[2] * value;
+
+    // Constraint: cpu/decode/flag_op1_base_op0_bit.
+    let value = (cpu_decode_flag_op1_base_op0_0 * cpu_decode_flag_op1_base_op0_0
+        - cpu_decode_flag_op1_base_op0_0)
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[3] * value;
+
+    // Constraint: cpu/decode/flag_res_op1_bit.
+    let value = (cpu_decode_flag_res_op1_0 * cpu_decode_flag_res_op1_0 - cpu_decode_flag_res_op1_0)
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[4] * value;
+
+    // Constraint: cpu/decode/flag_pc_update_regular_bit.
+    let value = (cpu_decode_flag_pc_update_regular_0 * cpu_decode_flag_pc_update_regular_0
+        - cpu_decode_flag_pc_update_regular_0)
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[5] * value;
+
+    // Constraint: cpu/decode/fp_update_regular_bit.
+    let value = (cpu_decode_fp_update_regular_0 * cpu_decode_fp_update_regular_0
+        - cpu_decode_fp_update_regular_0)
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[6] * value;
+
+    // Constraint: cpu/operands/mem_dst_addr.
+    let value = (column_row!(mask_values, 3, 8) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_0 * column_row!(mask_values, 6, 9)
+            + (FELT_1 - cpu_decode_opcode_range_check_bit_0) * column_row!(mask_values, 6, 1)
+            + column_row!(mask_values, 5, 0)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[7] * value;
+
+    // Constraint: cpu/operands/mem0_addr.
+    let value = (column_row!(mask_values, 3, 4) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_1 * column_row!(mask_values, 6, 9)
+            + (FELT_1 - cpu_decode_opcode_range_check_bit_1) * column_row!(mask_values, 6, 1)
+            + column_row!(mask_values, 5, 8)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[8] * value;
+
+    // Constraint: cpu/operands/mem1_addr.
+    let value = (column_row!(mask_values, 3, 12) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_2 * column_row!(mask_values, 3, 0)
+            + cpu_decode_opcode_range_check_bit_4 * column_row!(mask_values, 6, 1)
+            + cpu_decode_opcode_range_check_bit_3 * column_row!(mask_values, 6, 9)
+            + cpu_decode_flag_op1_base_op0_0 * column_row!(mask_values, 3, 5)
+            + column_row!(mask_values, 5, 4)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[9] * value;
+
+    // Constraint: cpu/operands/ops_mul.
+    let value = (column_row!(mask_values, 6, 5)
+        - column_row!(mask_values, 3, 5) * column_row!(mask_values, 3, 13))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[10] * value;
+
+    // Constraint: cpu/operands/res.
+    let value = ((FELT_1 - cpu_decode_opcode_range_check_bit_9) * column_row!(mask_values, 6, 13)
+        - (cpu_decode_opcode_range_check_bit_5
+            * (column_row!(mask_values, 3, 5) + column_row!(mask_values, 3, 13))
+            + cpu_decode_opcode_range_check_bit_6 * column_row!(mask_values, 6, 5)
+            + cpu_decode_flag_res_op1_0 * column_row!(mask_values, 3, 13)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[11] * value;
+
+    // Constraint: cpu/update_registers/update_pc/tmp0.
+    let value = (column_row!(mask_values, 6, 3)
+        - cpu_decode_opcode_range_check_bit_9 * column_row!(mask_values, 3, 9))
+        * domains[14].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[12] * value;
+
+    // Constraint: cpu/update_registers/update_pc/tmp1.
+    let value = (column_row!(mask_values, 6, 11)
+        - column_row!(mask_values, 6, 3) * column_row!(mask_values, 6, 13))
+        * domains[14].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[13] * value;
+
+    // Constraint: cpu/update_registers/update_pc/pc_cond_negative.
+    let value = ((FELT_1 - cpu_decode_opcode_range_check_bit_9) * column_row!(mask_values, 3, 16)
+        + column_row!(mask_values, 6, 3)
+            * (column_row!(mask_values, 3, 16)
+                - (column_row!(mask_values, 3, 0) + column_row!(mask_values, 3, 13)))
+        - (cpu_decode_flag_pc_update_regular_0 * npc_reg_0
+            + cpu_decode_opcode_range_check_bit_7 * column_row!(mask_values, 6, 13)
+            + cpu_decode_opcode_range_check_bit_8
+                * (column_row!(mask_values, 3, 0) + column_row!(mask_values, 6, 13))))
+        * domains[14].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[14] * value;
+
+    // Constraint: cpu/update_registers/update_pc/pc_cond_positive.
+    let value = ((column_row!(mask_values, 6, 11) - cpu_decode_opcode_range_check_bit_9)
+        * (column_row!(mask_values, 3, 16) - npc_reg_0))
+        * domains[14].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[15] * value;
+
+    // Constraint: cpu/update_registers/update_ap/ap_update.
+    let value = (column_row!(mask_values, 6, 17)
+        - (column_row!(mask_values, 6, 1)
+            + cpu_decode_opcode_range_check_bit_10 * column_row!(mask_values, 6, 13)
+            + cpu_decode_opcode_range_check_bit_11
+            + cpu_decode_opcode_range_check_bit_12 * FELT_2))
+        * domains[14].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[16] * value;
+
+    // Constraint: cpu/update_registers/update_fp/fp_update.
+    let value = (column_row!(mask_values, 6, 25)
+        - (cpu_decode_fp_update_regular_0 * column_row!(mask_values, 6, 9)
+            + cpu_decode_opcode_range_check_bit_13 * column_row!(mask_values, 3, 9)
+            + cpu_decode_opcode_range_check_bit_12 * (column_row!(mask_values, 6, 1) + FELT_2)))
+        * domains[14].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[17] * value;
+
+    // Constraint: cpu/opcodes/call/push_fp.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 3, 9) - column_row!(mask_values, 6, 9)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[18] * value;
+
+    // Constraint: cpu/opcodes/call/push_pc.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 3, 5)
+            - (column_row!(mask_values, 3, 0) + cpu_decode_opcode_range_check_bit_2 + FELT_1)))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[19] * value;
+
+    // Constraint: cpu/opcodes/call/off0.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 5, 0) - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[20] * value;
+
+    // Constraint: cpu/opcodes/call/off1.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 5, 8) - (global_values.half_offset_size + FELT_1)))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[21] * value;
+
+    // Constraint: cpu/opcodes/call/flags.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (cpu_decode_opcode_range_check_bit_12
+            + cpu_decode_opcode_range_check_bit_12
+            + FELT_1
+            + FELT_1
+            - (cpu_decode_opcode_range_check_bit_0
+                + cpu_decode_opcode_range_check_bit_1
+                + FELT_4)))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[22] * value;
+
+    // Constraint: cpu/opcodes/ret/off0.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (column_row!(mask_values, 5, 0) + FELT_2 - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[23] * value;
+
+    // Constraint: cpu/opcodes/ret/off2.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (column_row!(mask_values, 5, 4) + FELT_1 - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[24] * value;
+
+    // Constraint: cpu/opcodes/ret/flags.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (cpu_decode_opcode_range_check_bit_7
+            + cpu_decode_opcode_range_check_bit_0
+            + cpu_decode_opcode_range_check_bit_3
+            + cpu_decode_flag_res_op1_0
+            - FELT_4))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[25] * value;
+
+    // Constraint: cpu/opcodes/assert_eq/assert_eq.
+    let value = (cpu_decode_opcode_range_check_bit_14
+        * (column_row!(mask_values, 3, 9) - column_row!(mask_values, 6, 13)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[26] * value;
+
+    // Constraint: initial_ap.
+    let value = (column_row!(mask_values, 6, 1) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[27] * value;
+
+    // Constraint: initial_fp.
+    let value = (column_row!(mask_values, 6, 9) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[28] * value;
+
+    // Constraint: initial_pc.
+    let value = (column_row!(mask_values, 3, 0) - global_values.initial_pc)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[29] * value;
+
+    // Constraint: final_ap.
+    let value = (column_row!(mask_values, 6, 1) - global_values.final_ap)
+        .field_div(&felt_nonzero!(domains[14]));
+    total_sum += constraint_coefficients[30] * value;
+
+    // Constraint: final_fp.
+    let value = (column_row!(mask_values, 6, 9) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[14]));
+    total_sum += constraint_coefficients[31] * value;
+
+    // Constraint: final_pc.
+    let value = (column_row!(mask_values, 3, 0) - global_values.final_pc)
+        .field_div(&felt_nonzero!(domains[14]));
+    total_sum += constraint_coefficients[32] * value;
+
+    // Constraint: memory/multi_column_perm/perm/init0.
+    let value = ((global_values.memory_multi_column_perm_perm_interaction_elm
+        - (column_row!(mask_values, 4, 0)
+            + global_values.memory_multi_column_perm_hash_interaction_elm0
+                * column_row!(mask_values, 4, 1)))
+        * column_row!(mask_values, 9, 0)
+        + column_row!(mask_values, 3, 0)
+        + global_values.memory_multi_column_perm_hash_interaction_elm0
+            * column_row!(mask_values, 3, 1)
+        - global_values.memory_multi_column_perm_perm_interaction_elm)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[33] * value;
+
+    // Constraint: memory/multi_column_perm/perm/step0.
+    let value = ((global_values.memory_multi_column_perm_perm_interaction_elm
+        - (column_row!(mask_values, 4, 2)
+            + global_values.memory_multi_column_perm_hash_interaction_elm0
+                * column_row!(mask_values, 4, 3)))
+        * column_row!(mask_values, 9, 2)
+        - (global_values.memory_multi_column_perm_perm_interaction_elm
+            - (column_row!(mask_values, 3, 2)
+                + global_values.memory_multi_column_perm_hash_interaction_elm0
+                    * column_row!(mask_values, 3, 3)))
+            * column_row!(mask_values, 9, 0))
+        * domains[16].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[34] * value;
+
+    // Constraint: memory/multi_column_perm/perm/last.
+    let value = (column_row!(mask_values, 9, 0)
+        - global_values.memory_multi_column_perm_perm_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[35] * value;
+
+    // Constraint: memory/diff_is_bit.
+    let value = (memory_address_diff_0 * memory_address_diff_0 - memory_address_diff_0)
+        * domains[16].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[36] * value;
+
+    // Constraint: memory/is_func.
+    let value = ((memory_address_diff_0 - FELT_1)
+        * (column_row!(mask_values, 4, 1) - column_row!(mask_values, 4, 3)))
+        * domains[16].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[37] * value;
+
+    // Constraint: memory/initial_addr.
+    let value = (column_row!(mask_values, 4, 0) - FELT_1).field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[38] * value;
+
+    // Constraint: public_memory_addr_zero.
+    let value = (column_row!(mask_values, 3, 2)).field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[39] * value;
+
+    // Constraint: public_memory_value_zero.
+    let value = (column_row!(mask_values, 3, 3)).field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[40] * value;
+
+    // Constraint: range_check16/perm/init0.
+    let value = ((global_values.range_check16_perm_interaction_elm
+        - column_row!(mask_values, 5, 2))
+        * column_row!(mask_values, 9, 1)
+        + column_row!(mask_values, 5, 0)
+        - global_values.range_check16_perm_interaction_elm)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[41] * value;
+
+    // Constraint: range_check16/perm/step0.
+    let value = ((global_values.range_check16_perm_interaction_elm
+        - column_row!(mask_values, 5, 6))
+        * column_row!(mask_values, 9, 5)
+        - (global_values.range_check16_perm_interaction_elm - column_row!(mask_values, 5, 4))
+            * column_row!(mask_values, 9, 1))
+        * domains[17].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[42] * value;
+
+    // Constraint: range_check16/perm/last.
+    let value = (column_row!(mask_values, 9, 1)
+        - global_values.range_check16_perm_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[43] * value;
+
+    // Constraint: range_check16/diff_is_bit.
+    let value = (range_check16_diff_0 * range_check16_diff_0 - range_check16_diff_0)
+        * domains[17].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[44] * value;
+
+    // Constraint: range_check16/minimum.
+    let value = (column_row!(mask_values, 5, 2) - global_values.range_check_min)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[45] * value;
+
+    // Constraint: range_check16/maximum.
+    let value = (column_row!(mask_values, 5, 2) - global_values.range_check_max)
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[46] * value;
+
+    // Constraint: diluted_check/permutation/init0.
+    let value = ((global_values.diluted_check_permutation_interaction_elm
+        - column_row!(mask_values, 2, 0))
+        * column_row!(mask_values, 8, 0)
+        + column_row!(mask_values, 1, 0)
+        - global_values.diluted_check_permutation_interaction_elm)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[47] * value;
+
+    // Constraint: diluted_check/permutation/step0.
+    let value = ((global_values.diluted_check_permutation_interaction_elm
+        - column_row!(mask_values, 2, 1))
+        * column_row!(mask_values, 8, 1)
+        - (global_values.diluted_check_permutation_interaction_elm
+            - column_row!(mask_values, 1, 1))
+            * column_row!(mask_values, 8, 0))
+        * domains[18].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[48] * value;
+
+    // Constraint: diluted_check/permutation/last.
+    let value = (column_row!(mask_values, 8, 0)
+        - global_values.diluted_check_permutation_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[18]));
+    total_sum += constraint_coefficients[49] * value;
+
+    // Constraint: diluted_check/init.
+    let value = (column_row!(mask_values, 7, 0) - FELT_1).field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[50] * value;
+
+    // Constraint: diluted_check/first_element.
+    let value = (column_row!(mask_values, 2, 0) - global_values.diluted_check_first_elm)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[51] * value;
+
+    // Constraint: diluted_check/step.
+    let value = (column_row!(mask_values, 7, 1)
+        - (column_row!(mask_values, 7, 0)
+            * (FELT_1
+                + global_values.diluted_check_interaction_z
+                    * (column_row!(mask_values, 2, 1) - column_row!(mask_values, 2, 0)))
+            + global_values.diluted_check_interaction_alpha
+                * (column_row!(mask_values, 2, 1) - column_row!(mask_values, 2, 0))
+                * (column_row!(mask_values, 2, 1) - column_row!(mask_values, 2, 0))))
+        * domains[18].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[52] * value;
+
+    // Constraint: diluted_check/last.
+    let value = (column_row!(mask_values, 7, 0) - global_values.diluted_check_final_cum_val)
+        .field_div(&felt_nonzero!(domains[18]));
+    total_sum += constraint_coefficients[53] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/last_one_is_zero.
+    let value = (column_row!(mask_values, 6, 7)
+        * (column_row!(mask_values, 6, 0)
+            - (column_row!(mask_values, 6, 4) + column_row!(mask_values, 6, 4))))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[54] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones0.
+    let value = (column_row!(mask_values, 6, 7)
+        * (column_row!(mask_values, 6, 4)
+            - FELT_3138550867693340381917894711603833208051177722232017256448
+                * column_row!(mask_values, 6, 768)))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[55] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/cumulative_bit192.
+    let value = (column_row!(mask_values, 6, 7)
+        - column_row!(mask_values, 6, 1022)
+            * (column_row!(mask_values, 6, 768)
+                - (column_row!(mask_values, 6, 772) + column_row!(mask_values, 6, 772))))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[56] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones192.
+    let value = (column_row!(mask_values, 6, 1022)
+        * (column_row!(mask_values, 6, 772) - FELT_8 * column_row!(mask_values, 6, 784)))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[57] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/cumulative_bit196.
+    let value = (column_row!(mask_values, 6, 1022)
+        - (column_row!(mask_values, 6, 1004)
+            - (column_row!(mask_values, 6, 1008) + column_row!(mask_values, 6, 1008)))
+            * (column_row!(mask_values, 6, 784)
+                - (column_row!(mask_values, 6, 788) + column_row!(mask_values, 6, 788))))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[58] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones196.
+    let value = ((column_row!(mask_values, 6, 1004)
+        - (column_row!(mask_values, 6, 1008) + column_row!(mask_values, 6, 1008)))
+        * (column_row!(mask_values, 6, 788)
+            - FELT_18014398509481984 * column_row!(mask_values, 6, 1004)))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[59] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/booleanity_test.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (pedersen_hash0_ec_subset_sum_bit_0 - FELT_1))
+        * domains[10].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[60] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_extraction_end.
+    let value = (column_row!(mask_values, 6, 0)).field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[61] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/zeros_tail.
+    let value = (column_row!(mask_values, 6, 0)).field_div(&felt_nonzero!(domains[10]));
+    total_sum += constraint_coefficients[62] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/slope.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 5, 3) - global_values.pedersen_points_y)
+        - column_row!(mask_values, 6, 2)
+            * (column_row!(mask_values, 5, 1) - global_values.pedersen_points_x))
+        * domains[10].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[63] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/x.
+    let value = (column_row!(mask_values, 6, 2) * column_row!(mask_values, 6, 2)
+        - pedersen_hash0_ec_subset_sum_bit_0
+            * (column_row!(mask_values, 5, 1)
+                + global_values.pedersen_points_x
+                + column_row!(mask_values, 5, 5)))
+        * domains[10].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[64] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/y.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 5, 3) + column_row!(mask_values, 5, 7))
+        - column_row!(mask_values, 6, 2)
+            * (column_row!(mask_values, 5, 1) - column_row!(mask_values, 5, 5)))
+        * domains[10].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[65] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/copy_point/x.
+    let value = (pedersen_hash0_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 5, 5) - column_row!(mask_values, 5, 1)))
+        * domains[10].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[66] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/copy_point/y.
+    let value = (pedersen_hash0_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 5, 7) - column_row!(mask_values, 5, 3)))
+        * domains[10].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[67] * value;
+
+    // Constraint: pedersen/hash0/copy_point/x.
+    let value = (column_row!(mask_values, 5, 1025) - column_row!(mask_values, 5, 1021))
+        * domains[12].field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[68] * value;
+
+    // Constraint: pedersen/hash0/copy_point/y.
+    let value = (column_row!(mask_values, 5, 1027) - column_row!(mask_values, 5, 1023))
+        * domains[12].field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[69] * value;
+
+    // Constraint: pedersen/hash0/init/x.
+    let value = (column_row!(mask_values, 5, 1) - global_values.pedersen_shift_point.x)
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[70] * value;
+
+    // Constraint: pedersen/hash0/init/y.
+    let value = (column_row!(mask_values, 5, 3) - global_values.pedersen_shift_point.y)
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[71] * value;
+
+    // Constraint: pedersen/input0_value0.
+    let value = (column_row!(mask_values, 3, 11) - column_row!(mask_values, 6, 0))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[72] * value;
+
+    // Constraint: pedersen/input0_addr.
+    let value = (column_row!(mask_values, 3, 2058) - (column_row!(mask_values, 3, 522) + FELT_1))
+        * domains[19].field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[73] * value;
+
+    // Constraint: pedersen/init_addr.
+    let value = (column_row!(mask_values, 3, 10) - global_values.initial_pedersen_addr)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[74] * value;
+
+    // Constraint: pedersen/input1_value0.
+    let value = (column_row!(mask_values, 3, 1035) - column_row!(mask_values, 6, 1024))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[75] * value;
+
+    // Constraint: pedersen/input1_addr.
+    let value = (column_row!(mask_values, 3, 1034) - (column_row!(mask_values, 3, 10) + FELT_1))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[76] * value;
+
+    // Constraint: pedersen/output_value0.
+    let value = (column_row!(mask_values, 3, 523) - column_row!(mask_values, 5, 2045))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[77] * value;
+
+    // Constraint: pedersen/output_addr.
+    let value = (column_row!(mask_values, 3, 522) - (column_row!(mask_values, 3, 1034) + FELT_1))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[78] * value;
+
+    // Constraint: range_check_builtin/value.
+    let value = (range_check_builtin_value7_0 - column_row!(mask_values, 3, 75))
+        .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[79] * value;
+
+    // Constraint: range_check_builtin/addr_step.
+    let value = (column_row!(mask_values, 3, 202) - (column_row!(mask_values, 3, 74) + FELT_1))
+        * domains[20].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[80] * value;
+
+    // Constraint: range_check_builtin/init_addr.
+    let value = (column_row!(mask_values, 3, 74) - global_values.initial_range_check_addr)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[81] * value;
+
+    // Constraint: bitwise/init_var_pool_addr.
+    let value = (column_row!(mask_values, 3, 26) - global_values.initial_bitwise_addr)
+        .field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[82] * value;
+
+    // Constraint: bitwise/step_var_pool_addr.
+    let value = (column_row!(mask_values, 3, 58) - (column_row!(mask_values, 3, 26) + FELT_1))
+        * domains[7].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[83] * value;
+
+    // Constraint: bitwise/x_or_y_addr.
+    let value = (column_row!(mask_values, 3, 42) - (column_row!(mask_values, 3, 122) + FELT_1))
+        .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[84] * value;
+
+    // Constraint: bitwise/next_var_pool_addr.
+    let value = (column_row!(mask_values, 3, 154) - (column_row!(mask_values, 3, 42) + FELT_1))
+        * domains[20].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[85] * value;
+
+    // Constraint: bitwise/partition.
+    let value = (bitwise_sum_var_0_0 + bitwise_sum_var_8_0 - column_row!(mask_values, 3, 27))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[86] * value;
+
+    // Constraint: bitwise/or_is_and_plus_xor.
+    let value = (column_row!(mask_values, 3, 43)
+        - (column_row!(mask_values, 3, 91) + column_row!(mask_values, 3, 123)))
+    .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[87] * value;
+
+    // Constraint: bitwise/addition_is_xor_with_and.
+    let value = (column_row!(mask_values, 1, 0) + column_row!(mask_values, 1, 32)
+        - (column_row!(mask_values, 1, 96)
+            + column_row!(mask_values, 1, 64)
+            + column_row!(mask_values, 1, 64)))
+    .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[88] * value;
+
+    // Constraint: bitwise/unique_unpacking192.
+    let value = ((column_row!(mask_values, 1, 88) + column_row!(mask_values, 1, 120)) * FELT_16
+        - column_row!(mask_values, 1, 1))
+    .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[89] * value;
+
+    // Constraint: bitwise/unique_unpacking193.
+    let value = ((column_row!(mask_values, 1, 90) + column_row!(mask_values, 1, 122)) * FELT_16
+        - column_row!(mask_values, 1, 65))
+    .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[90] * value;
+
+    // Constraint: bitwise/unique_unpacking194.
+    let value = ((column_row!(mask_values, 1, 92) + column_row!(mask_values, 1, 124)) * FELT_16
+        - column_row!(mask_values, 1, 33))
+    .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[91] * value;
+
+    // Constraint: bitwise/unique_unpacking195.
+    let value = ((column_row!(mask_values, 1, 94) + column_row!(mask_values, 1, 126)) * FELT_256
+        - column_row!(mask_values, 1, 97))
+    .field_div(&felt_nonzero!(domains[6]));
+
+    total_sum += constraint_coefficients[92] * value;
+
+    total_sum
+}
+
+// Powers of the trace generator at the row offsets of the mask.
+pub fn eval_oods_powers(trace_generator: Felt, autogenerated_pows: &mut [Felt]) {
+    autogenerated_pows[0] = trace_generator.pow_felt(&(FELT_0));
+    autogenerated_pows[1] = trace_generator.pow_felt(&(FELT_1004));
+    autogenerated_pows[2] = trace_generator.pow_felt(&(FELT_768));
+    autogenerated_pows[3] = trace_generator.pow_felt(&(FELT_522));
+    autogenerated_pows[4] = trace_generator.pow_felt(&(FELT_1));
+    autogenerated_pows[5] = autogenerated_pows[3] * autogenerated_pows[4]; // pow(trace_generator, 523).
+    autogenerated_pows[6] = autogenerated_pows[4] * autogenerated_pows[4]; // pow(trace_generator, 2).
+    autogenerated_pows[7] = autogenerated_pows[4] * autogenerated_pows[6]; // pow(trace_generator, 3).
+    autogenerated_pows[8] = autogenerated_pows[4] * autogenerated_pows[7]; // pow(trace_generator, 4).
+    autogenerated_pows[9] = autogenerated_pows[1] * autogenerated_pows[8]; // pow(trace_generator, 1008).
+    autogenerated_pows[10] = autogenerated_pows[2] * autogenerated_pows[8]; // pow(trace_generator, 772).
+    autogenerated_pows[11] = autogenerated_pows[4] * autogenerated_pows[8]; // pow(trace_generator, 5).
+    autogenerated_pows[12] = autogenerated_pows[4] * autogenerated_pows[11]; // pow(trace_generator, 6).
+    autogenerated_pows[13] = autogenerated_pows[4] * autogenerated_pows[12]; // pow(trace_generator, 7).
+    autogenerated_pows[14] = autogenerated_pows[4] * autogenerated_pows[13]; // pow(trace_generator, 8).
+    autogenerated_pows[15] = autogenerated_pows[4] * autogenerated_pows[14]; // pow(trace_generator, 9).
+    autogenerated_pows[16] = autogenerated_pows[4] * autogenerated_pows[15]; // pow(trace_generator, 10).
+    autogenerated_pows[17] = autogenerated_pows[4] * autogenerated_pows[16]; // pow(trace_generator, 11).
+    autogenerated_pows[18] = autogenerated_pows[4] * autogenerated_pows[17]; // pow(trace_generator, 12).
+    autogenerated_pows[19] = autogenerated_pows[4] * autogenerated_pows[18]; // pow(trace_generator, 13).
+    autogenerated_pows[20] = autogenerated_pows[4] * autogenerated_pows[19]; // pow(trace_generator, 14).
+    autogenerated_pows[21] = autogenerated_pows[4] * autogenerated_pows[20]; // pow(trace_generator, 15).
+    autogenerated_pows[22] = autogenerated_pows[4] * autogenerated_pows[21]; // pow(trace_generator, 16).
+    autogenerated_pows[23] = autogenerated_pows[2] * autogenerated_pows[22]; // pow(trace_generator, 784).
+    autogenerated_pows[24] = autogenerated_pows[4] * autogenerated_pows[22]; // pow(trace_generator, 17).
+    autogenerated_pows[25] = autogenerated_pows[1] * autogenerated_pows[24]; // pow(trace_generator, 1021).
+    autogenerated_pows[26] = autogenerated_pows[4] * autogenerated_pows[24]; // pow(trace_generator, 18).
+    autogenerated_pows[27] = autogenerated_pows[1] * autogenerated_pows[26]; // pow(trace_generator, 1022).
+    autogenerated_pows[28] = autogenerated_pows[4] * autogenerated_pows[27]; // pow(trace_generator, 1023).
+    autogenerated_pows[29] = autogenerated_pows[6] * autogenerated_pows[26]; // pow(trace_generator, 20).
+    autogenerated_pows[30] = autogenerated_pows[6] * autogenerated_pows[29]; // pow(trace_generator, 22).
+    autogenerated_pows[31] = autogenerated_pows[6] * autogenerated_pows[30]; // pow(trace_generator, 24).
+    autogenerated_pows[32] = autogenerated_pows[4] * autogenerated_pows[31]; // pow(trace_generator, 25).
+    autogenerated_pows[33] = autogenerated_pows[4] * autogenerated_pows[32]; // pow(trace_generator, 26).
+    autogenerated_pows[34] = autogenerated_pows[1] * autogenerated_pows[29]; // pow(trace_generator, 1024).
+    autogenerated_pows[35] = autogenerated_pows[25] * autogenerated_pows[34]; // pow(trace_generator, 2045).
+    autogenerated_pows[36] = autogenerated_pows[4] * autogenerated_pows[34]; // pow(trace_generator, 1025).
+    autogenerated_pows[37] = autogenerated_pows[6] * autogenerated_pows[36]; // pow(trace_generator, 1027).
+    autogenerated_pows[38] = autogenerated_pows[4] * autogenerated_pows[33]; // pow(trace_generator, 27).
+    autogenerated_pows[39] = autogenerated_pows[4] * autogenerated_pows[38]; // pow(trace_generator, 28).
+    autogenerated_pows[40] = autogenerated_pows[6] * autogenerated_pows[39]; // pow(trace_generator, 30).
+    autogenerated_pows[41] = autogenerated_pows[6] * autogenerated_pows[40]; // pow(trace_generator, 32).
+    autogenerated_pows[42] = autogenerated_pows[4] * autogenerated_pows[41]; // pow(trace_generator, 33).
+    autogenerated_pows[43] = autogenerated_pows[1] * autogenerated_pows[40]; // pow(trace_generator, 1034).
+    autogenerated_pows[44] = autogenerated_pows[4] * autogenerated_pows[43]; // pow(trace_generator, 1035).
+    autogenerated_pows[45] = autogenerated_pows[19] * autogenerated_pows[35]; // pow(trace_generator, 2058).
+    autogenerated_pows[46] = autogenerated_pows[15] * autogenerated_pows[42]; // pow(trace_generator, 42).
+    autogenerated_pows[47] = autogenerated_pows[4] * autogenerated_pows[46]; // pow(trace_generator, 43).
+    autogenerated_pows[48] = autogenerated_pows[4] * autogenerated_pows[47]; // pow(trace_generator, 44).
+    autogenerated_pows[49] = autogenerated_pows[20] * autogenerated_pows[48]; // pow(trace_generator, 58).
+    autogenerated_pows[50] = autogenerated_pows[6] * autogenerated_pows[49]; // pow(trace_generator, 60).
+    autogenerated_pows[51] = autogenerated_pows[2] * autogenerated_pows[29]; // pow(trace_generator, 788).
+    autogenerated_pows[52] = autogenerated_pows[8] * autogenerated_pows[50]; // pow(trace_generator, 64).
+    autogenerated_pows[53] = autogenerated_pows[4] * autogenerated_pows[52]; // pow(trace_generator, 65).
+    autogenerated_pows[54] = autogenerated_pows[15] * autogenerated_pows[53]; // pow(trace_generator, 74).
+    autogenerated_pows[55] = autogenerated_pows[4] * autogenerated_pows[54]; // pow(trace_generator, 75).
+    autogenerated_pows[56] = autogenerated_pows[4] * autogenerated_pows[55]; // pow(trace_generator, 76).
+    autogenerated_pows[57] = autogenerated_pows[18] * autogenerated_pows[56]; // pow(trace_generator, 88).
+    autogenerated_pows[58] = autogenerated_pows[6] * autogenerated_pows[57]; // pow(trace_generator, 90).
+    autogenerated_pows[59] = autogenerated_pows[4] * autogenerated_pows[58]; // pow(trace_generator, 91).
+    autogenerated_pows[60] = autogenerated_pows[4] * autogenerated_pows[59]; // pow(trace_generator, 92).
+    autogenerated_pows[61] = autogenerated_pows[6] * autogenerated_pows[60]; // pow(trace_generator, 94).
+    autogenerated_pows[62] = autogenerated_pows[6] * autogenerated_pows[61]; // pow(trace_generator, 96).
+    autogenerated_pows[63] = autogenerated_pows[4] * autogenerated_pows[62]; // pow(trace_generator, 97).
+    autogenerated_pows[64] = autogenerated_pows[17] * autogenerated_pows[63]; // pow(trace_generator, 108).
+    autogenerated_pows[65] = autogenerated_pows[18] * autogenerated_pows[64]; // pow(trace_generator, 120).
+    autogenerated_pows[66] = autogenerated_pows[6] * autogenerated_pows[65]; // pow(trace_generator, 122).
+    autogenerated_pows[67] = autogenerated_pows[4] * autogenerated_pows[66]; // pow(trace_generator, 123).
+    autogenerated_pows[68] = autogenerated_pows[4] * autogenerated_pows[67]; // pow(trace_generator, 124).
+    autogenerated_pows[69] = autogenerated_pows[6] * autogenerated_pows[68]; // pow(trace_generator, 126).
+    autogenerated_pows[70] = autogenerated_pows[56] * autogenerated_pows[69]; // pow(trace_generator, 202).
+    autogenerated_pows[71] = autogenerated_pows[39] * autogenerated_pows[69]; // pow(trace_generator, 154).
+}
+
+pub fn eval_oods_constraints(
+    column_values: &[Felt],
+    oods_values: &[Felt],
+    constraint_coefficients: &[Felt],
+    autogenerated_pows: &[Felt],
+    point: Felt,
+    oods_point: Felt,
+) -> Felt {
+    let column0 = column_values[0];
+    let column1 = column_values[1];
+    let column2 = column_values[2];
+    let column3 = column_values[3];
+    let column4 = column_values[4];
+    let column5 = column_values[5];
+    let column6 = column_values[6];
+    let column7 = column_values[7];
+    let column8 = column_values[8];
+    let column9 = column_values[9];
+
+    // Sum the OODS constraints on the trace polynomials.
+    let mut total_sum = FELT_0;
+
+    let value = (column0 - oods_values[0])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[0] * value;
+
+    let value = (column0 - oods_values[1])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[1] * value;
+
+    let value = (column0 - oods_values[2])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[2] * value;
+
+    let value = (column0 - oods_values[3])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[3] * value;
+
+    let value = (column0 - oods_values[4])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[4] * value;
+
+    let value = (column0 - oods_values[5])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[5] * value;
+
+    let value = (column0 - oods_values[6])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[12] * oods_point));
+    total_sum += constraint_coefficients[6] * value;
+
+    let value = (column0 - oods_values[7])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[13] * oods_point));
+    total_sum += constraint_coefficients[7] * value;
+
+    let value = (column0 - oods_values[8])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[8] * value;
+
+    let value = (column0 - oods_values[9])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[15] * oods_point));
+    total_sum += constraint_coefficients[9] * value;
+
+    let value = (column0 - oods_values[10])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[16] * oods_point));
+    total_sum += constraint_coefficients[10] * value;
+
+    let value = (column0 - oods_values[11])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[11] * value;
+
+    let value = (column0 - oods_values[12])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[12] * value;
+
+    let value = (column0 - oods_values[13])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[19] * oods_point));
+    total_sum += constraint_coefficients[13] * value;
+
+    let value = (column0 - oods_values[14])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[20] * oods_point));
+    total_sum += constraint_coefficients[14] * value;
+
+    let value = (column0 - oods_values[15])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[21] * oods_point));
+    total_sum += constraint_coefficients[15] * value;
+
+    let value = (column1 - oods_values[16])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[16] * value;
+
+    let value = (column1 - oods_values[17])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[17] * value;
+
+    let value = (column1 - oods_values[18])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[18] * value;
+
+    let value = (column1 - oods_values[19])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[19] * value;
+
+    let value = (column1 - oods_values[20])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[12] * oods_point));
+    total_sum += constraint_coefficients[20] * value;
+
+    let value = (column1 - oods_values[21])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[21] * value;
+
+    let value = (column1 - oods_values[22])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[16] * oods_point));
+    total_sum += constraint_coefficients[22] * value;
+
+    let value = (column1 - oods_values[23])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[23] * value;
+
+    let value = (column1 - oods_values[24])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[20] * oods_point));
+    total_sum += constraint_coefficients[24] * value;
+
+    let value = (column1 - oods_values[25])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[22] * oods_point));
+    total_sum += constraint_coefficients[25] * value;
+
+    let value = (column1 - oods_values[26])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[26] * oods_point));
+    total_sum += constraint_coefficients[26] * value;
+
+    let value = (column1 - oods_values[27])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[29] * oods_point));
+    total_sum += constraint_coefficients[27] * value;
+
+    let value = (column1 - oods_values[28])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[30] * oods_point));
+    total_sum += constraint_coefficients[28] * value;
+
+    let value = (column1 - oods_values[29])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[31] * oods_point));
+    total_sum += constraint_coefficients[29] * value;
+
+    let value = (column1 - oods_values[30])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[33] * oods_point));
+    total_sum += constraint_coefficients[30] * value;
+
+    let value = (column1 - oods_values[31])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[39] * oods_point));
+    total_sum += constraint_coefficients[31] * value;
+
+    let value = (column1 - oods_values[32])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[40] * oods_point));
+    total_sum += constraint_coefficients[32] * value;
+
+    let value = (column1 - oods_values[33])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[41] * oods_point));
+    total_sum += constraint_coefficients[33] * value;
+
+    let value = (column1 - oods_values[34])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[42] * oods_point));
+    total_sum += constraint_coefficients[34] * value;
+
+    let value = (column1 - oods_values[35])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[52] * oods_point));
+    total_sum += constraint_coefficients[35] * value;
+
+    let value = (column1 - oods_values[36])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[53] * oods_point));
+    total_sum += constraint_coefficients[36] * value;
+
+    let value = (column1 - oods_values[37])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[57] * oods_point));
+    total_sum += constraint_coefficients[37] * value;
+
+    let value = (column1 - oods_values[38])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[58] * oods_point));
+    total_sum += constraint_coefficients[38] * value;
+
+    let value = (column1 - oods_values[39])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[60] * oods_point));
+    total_sum += constraint_coefficients[39] * value;
+
+    let value = (column1 - oods_values[40])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[61] * oods_point));
+    total_sum += constraint_coefficients[40] * value;
+
+    let value = (column1 - oods_values[41])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[62] * oods_point));
+    total_sum += constraint_coefficients[41] * value;
+
+    let value = (column1 - oods_values[42])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[63] * oods_point));
+    total_sum += constraint_coefficients[42] * value;
+
+    let value = (column1 - oods_values[43])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[65] * oods_point));
+    total_sum += constraint_coefficients[43] * value;
+
+    let value = (column1 - oods_values[44])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[66] * oods_point));
+    total_sum += constraint_coefficients[44] * value;
+
+    let value = (column1 - oods_values[45])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[68] * oods_point));
+    total_sum += constraint_coefficients[45] * value;
+
+    let value = (column1 - oods_values[46])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[69] * oods_point));
+    total_sum += constraint_coefficients[46] * value;
+
+    let value = (column2 - oods_values[47])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[47] * value;
+
+    let value = (column2 - oods_values[48])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[48] * value;
+
+    let value = (column3 - oods_values[49])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[49] * value;
+
+    let value = (column3 - oods_values[50])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[50] * value;
+
+    let value = (column3 - oods_values[51])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[51] * value;
+
+    let value = (column3 - oods_values[52])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[52] * value;
+
+    let value = (column3 - oods_values[53])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[53] * value;
+
+    let value = (column3 - oods_values[54])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[54] * value;
+
+    let value = (column3 - oods_values[55])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[55] * value;
+
+    let value = (column3 - oods_values[56])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[15] * oods_point));
+    total_sum += constraint_coefficients[56] * value;
+
+    let value = (column3 - oods_values[57])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[16] * oods_point));
+    total_sum += constraint_coefficients[57] * value;
+
+    let value = (column3 - oods_values[58])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[58] * value;
+
+    let value = (column3 - oods_values[59])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[59] * value;
+
+    let value = (column3 - oods_values[60])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[19] * oods_point));
+    total_sum += constraint_coefficients[60] * value;
+
+    let value = (column3 - oods_values[61])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[22] * oods_point));
+    total_sum += constraint_coefficients[61] * value;
+
+    let value = (column3 - oods_values[62])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[33] * oods_point));
+    total_sum += constraint_coefficients[62] * value;
+
+    let value = (column3 - oods_values[63])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[38] * oods_point));
+    total_sum += constraint_coefficients[63] * value;
+
+    let value = (column3 - oods_values[64])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[46] * oods_point));
+    total_sum += constraint_coefficients[64] * value;
+
+    let value = (column3 - oods_values[65])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[47] * oods_point));
+    total_sum += constraint_coefficients[65] * value;
+
+    let value = (column3 - oods_values[66])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[49] * oods_point));
+    total_sum += constraint_coefficients[66] * value;
+
+    let value = (column3 - oods_values[67])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[54] * oods_point));
+    total_sum += constraint_coefficients[67] * value;
+
+    let value = (column3 - oods_values[68])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[55] * oods_point));
+    total_sum += constraint_coefficients[68] * value;
+
+    let value = (column3 - oods_values[69])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[59] * oods_point));
+    total_sum += constraint_coefficients[69] * value;
+
+    let value = (column3 - oods_values[70])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[66] * oods_point));
+    total_sum += constraint_coefficients[70] * value;
+
+    let value = (column3 - oods_values[71])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[67] * oods_point));
+    total_sum += constraint_coefficients[71] * value;
+
+    let value = (column3 - oods_values[72])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[71] * oods_point));
+    total_sum += constraint_coefficients[72] * value;
+
+    let value = (column3 - oods_values[73])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[70] * oods_point));
+    total_sum += constraint_coefficients[73] * value;
+
+    let value = (column3 - oods_values[74])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[74] * value;
+
+    let value = (column3 - oods_values[75])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[75] * value;
+
+    let value = (column3 - oods_values[76])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[43] * oods_point));
+    total_sum += constraint_coefficients[76] * value;
+
+    let value = (column3 - oods_values[77])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[44] * oods_point));
+    total_sum += constraint_coefficients[77] * value;
+
+    let value = (column3 - oods_values[78])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[45] * oods_point));
+    total_sum += constraint_coefficients[78] * value;
+
+    let value = (column4 - oods_values[79])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[79] * value;
+
+    let value = (column4 - oods_values[80])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[80] * value;
+
+    let value = (column4 - oods_values[81])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[81] * value;
+
+    let value = (column4 - oods_values[82])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[82] * value;
+
+    let value = (column5 - oods_values[83])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[83] * value;
+
+    let value = (column5 - oods_values[84])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[84] * value;
+
+    let value = (column5 - oods_values[85])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[85] * value;
+
+    let value = (column5 - oods_values[86])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[86] * value;
+
+    let value = (column5 - oods_values[87])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[87] * value;
+
+    let value = (column5 - oods_values[88])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[88] * value;
+
+    let value = (column5 - oods_values[89])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[12] * oods_point));
+    total_sum += constraint_coefficients[89] * value;
+
+    let value = (column5 - oods_values[90])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[13] * oods_point));
+    total_sum += constraint_coefficients[90] * value;
+
+    let value = (column5 - oods_values[91])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[91] * value;
+
+    let value = (column5 - oods_values[92])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[92] * value;
+
+    let value = (column5 - oods_values[93])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[39] * oods_point));
+    total_sum += constraint_coefficients[93] * value;
+
+    let value = (column5 - oods_values[94])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[48] * oods_point));
+    total_sum += constraint_coefficients[94] * value;
+
+    let value = (column5 - oods_values[95])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[50] * oods_point));
+    total_sum += constraint_coefficients[95] * value;
+
+    let value = (column5 - oods_values[96])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[56] * oods_point));
+    total_sum += constraint_coefficients[96] * value;
+
+    let value = (column5 - oods_values[97])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[60] * oods_point));
+    total_sum += constraint_coefficients[97] * value;
+
+    let value = (column5 - oods_values[98])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[64] * oods_point));
+    total_sum += constraint_coefficients[98] * value;
+
+    let value = (column5 - oods_values[99])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[68] * oods_point));
+    total_sum += constraint_coefficients[99] * value;
+
+    let value = (column5 - oods_values[100])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[25] * oods_point));
+    total_sum += constraint_coefficients[100] * value;
+
+    let value = (column5 - oods_values[101])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[28] * oods_point));
+    total_sum += constraint_coefficients[101] * value;
+
+    let value = (column5 - oods_values[102])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[36] * oods_point));
+    total_sum += constraint_coefficients[102] * value;
+
+    let value = (column5 - oods_values[103])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[37] * oods_point));
+    total_sum += constraint_coefficients[103] * value;
+
+    let value = (column5 - oods_values[104])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[35] * oods_point));
+    total_sum += constraint_coefficients[104] * value;
+
+    let value = (column6 - oods_values[105])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[105] * value;
+
+    let value = (column6 - oods_values[106])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[106] * value;
+
+    let value = (column6 - oods_values[107])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[107] * value;
+
+    let value = (column6 - oods_values[108])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[108] * value;
+
+    let value = (column6 - oods_values[109])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[109] * value;
+
+    let value = (column6 - oods_values[110])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[110] * value;
+
+    let value = (column6 - oods_values[111])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[13] * oods_point));
+    total_sum += constraint_coefficients[111] * value;
+
+    let value = (column6 - oods_values[112])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[15] * oods_point));
+    total_sum += constraint_coefficients[112] * value;
+
+    let value = (column6 - oods_values[113])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[113] * value;
+
+    let value = (column6 - oods_values[114])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[19] * oods_point));
+    total_sum += constraint_coefficients[114] * value;
+
+    let value = (column6 - oods_values[115])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[24] * oods_point));
+    total_sum += constraint_coefficients[115] * value;
+
+    let value = (column6 - oods_values[116])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[32] * oods_point));
+    total_sum += constraint_coefficients[116] * value;
+
+    let value = (column6 - oods_values[117])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[2] * oods_point));
+    total_sum += constraint_coefficients[117] * value;
+
+    let value = (column6 - oods_values[118])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[10] * oods_point));
+    total_sum += constraint_coefficients[118] * value;
+
+    let value = (column6 - oods_values[119])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[23] * oods_point));
+    total_sum += constraint_coefficients[119] * value;
+
+    let value = (column6 - oods_values[120])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[51] * oods_point));
+    total_sum += constraint_coefficients[120] * value;
+
+    let value = (column6 - oods_values[121])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[1] * oods_point));
+    total_sum += constraint_coefficients[121] * value;
+
+    let value = (column6 - oods_values[122])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[9] * oods_point));
+    total_sum += constraint_coefficients[122] * value;
+
+    let value = (column6 - oods_values[123])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[27] * oods_point));
+    total_sum += constraint_coefficients[123] * value;
+
+    let value = (column6 - oods_values[124])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[34] * oods_point));
+    total_sum += constraint_coefficients[124] * value;
+
+    let value = (column7 - oods_values[125])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[125] * value;
+
+    let value = (column7 - oods_values[126])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[126] * value;
+
+    let value = (column8 - oods_values[127])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[127] * value;
+
+    let value = (column8 - oods_values[128])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[128] * value;
+
+    let value = (column9 - oods_values[129])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[129] * value;
+
+    let value = (column9 - oods_values[130])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[130] * value;
+
+    let value = (column9 - oods_values[131])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[131] * value;
+
+    let value = (column9 - oods_values[132])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[132] * value;
+
+    // Sum the OODS boundary constraints on the composition polynomials.
+    let oods_point_to_deg = oods_point.pow_felt(&Felt::from(Layout::CONSTRAINT_DEGREE));
+
+    let value =
+        (column_values[10] - oods_values[133]).field_div(&felt_nonzero!(point - oods_point_to_deg));
+    total_sum += constraint_coefficients[133] * value;
+
+    let value =
+        (column_values[11] - oods_values[134]).field_div(&felt_nonzero!(point - oods_point_to_deg));
+    total_sum += constraint_coefficients[134] * value;
+
+    total_sum
+}
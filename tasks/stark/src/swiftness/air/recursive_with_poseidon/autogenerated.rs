@@ -0,0 +1,2635 @@
+use super::{GlobalValues, Layout, LayoutTrait};
+use crate::swiftness::air::consts::*;
+use felt::felt_nonzero;
+use felt::Felt;
+
+// Maps the column_row notation of the constraints to the flat mask value indices, resolved
+// at expansion time so that an access outside of the mask does not compile.
+macro_rules! column_row {
+    ($mask_values:expr, 0, 0) => {
+        $mask_values[0]
+    };
+    ($mask_values:expr, 0, 1) => {
+        $mask_values[1]
+    };
+    ($mask_values:expr, 0, 2) => {
+        $mask_values[2]
+    };
+    ($mask_values:expr, 0, 3) => {
+        $mask_values[3]
+    };
+    ($mask_values:expr, 0, 4) => {
+        $mask_values[4]
+    };
+    ($mask_values:expr, 0, 5) => {
+        $mask_values[5]
+    };
+    ($mask_values:expr, 0, 6) => {
+        $mask_values[6]
+    };
+    ($mask_values:expr, 0, 7) => {
+        $mask_values[7]
+    };
+    ($mask_values:expr, 0, 8) => {
+        $mask_values[8]
+    };
+    ($mask_values:expr, 0, 9) => {
+        $mask_values[9]
+    };
+    ($mask_values:expr, 0, 10) => {
+        $mask_values[10]
+    };
+    ($mask_values:expr, 0, 11) => {
+        $mask_values[11]
+    };
+    ($mask_values:expr, 0, 12) => {
+        $mask_values[12]
+    };
+    ($mask_values:expr, 0, 13) => {
+        $mask_values[13]
+    };
+    ($mask_values:expr, 0, 14) => {
+        $mask_values[14]
+    };
+    ($mask_values:expr, 0, 15) => {
+        $mask_values[15]
+    };
+    ($mask_values:expr, 1, 0) => {
+        $mask_values[16]
+    };
+    ($mask_values:expr, 1, 1) => {
+        $mask_values[17]
+    };
+    ($mask_values:expr, 1, 2) => {
+        $mask_values[18]
+    };
+    ($mask_values:expr, 1, 3) => {
+        $mask_values[19]
+    };
+    ($mask_values:expr, 1, 4) => {
+        $mask_values[20]
+    };
+    ($mask_values:expr, 1, 5) => {
+        $mask_values[21]
+    };
+    ($mask_values:expr, 1, 8) => {
+        $mask_values[22]
+    };
+    ($mask_values:expr, 1, 9) => {
+        $mask_values[23]
+    };
+    ($mask_values:expr, 1, 10) => {
+        $mask_values[24]
+    };
+    ($mask_values:expr, 1, 11) => {
+        $mask_values[25]
+    };
+    ($mask_values:expr, 1, 12) => {
+        $mask_values[26]
+    };
+    ($mask_values:expr, 1, 13) => {
+        $mask_values[27]
+    };
+    ($mask_values:expr, 1, 16) => {
+        $mask_values[28]
+    };
+    ($mask_values:expr, 1, 42) => {
+        $mask_values[29]
+    };
+    ($mask_values:expr, 1, 43) => {
+        $mask_values[30]
+    };
+    ($mask_values:expr, 1, 74) => {
+        $mask_values[31]
+    };
+    ($mask_values:expr, 1, 75) => {
+        $mask_values[32]
+    };
+    ($mask_values:expr, 1, 106) => {
+        $mask_values[33]
+    };
+    ($mask_values:expr, 1, 138) => {
+        $mask_values[34]
+    };
+    ($mask_values:expr, 1, 139) => {
+        $mask_values[35]
+    };
+    ($mask_values:expr, 1, 171) => {
+        $mask_values[36]
+    };
+    ($mask_values:expr, 1, 202) => {
+        $mask_values[37]
+    };
+    ($mask_values:expr, 1, 203) => {
+        $mask_values[38]
+    };
+    ($mask_values:expr, 1, 234) => {
+        $mask_values[39]
+    };
+    ($mask_values:expr, 1, 235) => {
+        $mask_values[40]
+    };
+    ($mask_values:expr, 1, 266) => {
+        $mask_values[41]
+    };
+    ($mask_values:expr, 1, 267) => {
+        $mask_values[42]
+    };
+    ($mask_values:expr, 1, 298) => {
+        $mask_values[43]
+    };
+    ($mask_values:expr, 1, 394) => {
+        $mask_values[44]
+    };
+    ($mask_values:expr, 1, 458) => {
+        $mask_values[45]
+    };
+    ($mask_values:expr, 1, 459) => {
+        $mask_values[46]
+    };
+    ($mask_values:expr, 1, 714) => {
+        $mask_values[47]
+    };
+    ($mask_values:expr, 1, 715) => {
+        $mask_values[48]
+    };
+    ($mask_values:expr, 1, 778) => {
+        $mask_values[49]
+    };
+    ($mask_values:expr, 1, 779) => {
+        $mask_values[50]
+    };
+    ($mask_values:expr, 1, 970) => {
+        $mask_values[51]
+    };
+    ($mask_values:expr, 1, 971) => {
+        $mask_values[52]
+    };
+    ($mask_values:expr, 1, 1034) => {
+        $mask_values[53]
+    };
+    ($mask_values:expr, 1, 1035) => {
+        $mask_values[54]
+    };
+    ($mask_values:expr, 1, 2058) => {
+        $mask_values[55]
+    };
+    ($mask_values:expr, 1, 2059) => {
+        $mask_values[56]
+    };
+    ($mask_values:expr, 1, 4106) => {
+        $mask_values[57]
+    };
+    ($mask_values:expr, 2, 0) => {
+        $mask_values[58]
+    };
+    ($mask_values:expr, 2, 1) => {
+        $mask_values[59]
+    };
+    ($mask_values:expr, 2, 2) => {
+        $mask_values[60]
+    };
+    ($mask_values:expr, 2, 3) => {
+        $mask_values[61]
+    };
+    ($mask_values:expr, 3, 0) => {
+        $mask_values[62]
+    };
+    ($mask_values:expr, 3, 1) => {
+        $mask_values[63]
+    };
+    ($mask_values:expr, 3, 2) => {
+        $mask_values[64]
+    };
+    ($mask_values:expr, 3, 3) => {
+        $mask_values[65]
+    };
+    ($mask_values:expr, 3, 4) => {
+        $mask_values[66]
+    };
+    ($mask_values:expr, 3, 8) => {
+        $mask_values[67]
+    };
+    ($mask_values:expr, 3, 12) => {
+        $mask_values[68]
+    };
+    ($mask_values:expr, 3, 16) => {
+        $mask_values[69]
+    };
+    ($mask_values:expr, 3, 20) => {
+        $mask_values[70]
+    };
+    ($mask_values:expr, 3, 24) => {
+        $mask_values[71]
+    };
+    ($mask_values:expr, 3, 28) => {
+        $mask_values[72]
+    };
+    ($mask_values:expr, 3, 32) => {
+        $mask_values[73]
+    };
+    ($mask_values:expr, 3, 36) => {
+        $mask_values[74]
+    };
+    ($mask_values:expr, 3, 40) => {
+        $mask_values[75]
+    };
+    ($mask_values:expr, 3, 44) => {
+        $mask_values[76]
+    };
+    ($mask_values:expr, 3, 48) => {
+        $mask_values[77]
+    };
+    ($mask_values:expr, 3, 52) => {
+        $mask_values[78]
+    };
+    ($mask_values:expr, 3, 56) => {
+        $mask_values[79]
+    };
+    ($mask_values:expr, 3, 60) => {
+        $mask_values[80]
+    };
+    ($mask_values:expr, 3, 64) => {
+        $mask_values[81]
+    };
+    ($mask_values:expr, 3, 66) => {
+        $mask_values[82]
+    };
+    ($mask_values:expr, 3, 128) => {
+        $mask_values[83]
+    };
+    ($mask_values:expr, 3, 130) => {
+        $mask_values[84]
+    };
+    ($mask_values:expr, 3, 176) => {
+        $mask_values[85]
+    };
+    ($mask_values:expr, 3, 180) => {
+        $mask_values[86]
+    };
+    ($mask_values:expr, 3, 184) => {
+        $mask_values[87]
+    };
+    ($mask_values:expr, 3, 188) => {
+        $mask_values[88]
+    };
+    ($mask_values:expr, 3, 192) => {
+        $mask_values[89]
+    };
+    ($mask_values:expr, 3, 194) => {
+        $mask_values[90]
+    };
+    ($mask_values:expr, 3, 240) => {
+        $mask_values[91]
+    };
+    ($mask_values:expr, 3, 244) => {
+        $mask_values[92]
+    };
+    ($mask_values:expr, 3, 248) => {
+        $mask_values[93]
+    };
+    ($mask_values:expr, 3, 252) => {
+        $mask_values[94]
+    };
+    ($mask_values:expr, 4, 0) => {
+        $mask_values[95]
+    };
+    ($mask_values:expr, 4, 1) => {
+        $mask_values[96]
+    };
+    ($mask_values:expr, 4, 2) => {
+        $mask_values[97]
+    };
+    ($mask_values:expr, 4, 3) => {
+        $mask_values[98]
+    };
+    ($mask_values:expr, 4, 4) => {
+        $mask_values[99]
+    };
+    ($mask_values:expr, 4, 5) => {
+        $mask_values[100]
+    };
+    ($mask_values:expr, 4, 6) => {
+        $mask_values[101]
+    };
+    ($mask_values:expr, 4, 7) => {
+        $mask_values[102]
+    };
+    ($mask_values:expr, 4, 8) => {
+        $mask_values[103]
+    };
+    ($mask_values:expr, 4, 9) => {
+        $mask_values[104]
+    };
+    ($mask_values:expr, 4, 11) => {
+        $mask_values[105]
+    };
+    ($mask_values:expr, 4, 12) => {
+        $mask_values[106]
+    };
+    ($mask_values:expr, 4, 13) => {
+        $mask_values[107]
+    };
+    ($mask_values:expr, 4, 44) => {
+        $mask_values[108]
+    };
+    ($mask_values:expr, 4, 76) => {
+        $mask_values[109]
+    };
+    ($mask_values:expr, 4, 108) => {
+        $mask_values[110]
+    };
+    ($mask_values:expr, 4, 140) => {
+        $mask_values[111]
+    };
+    ($mask_values:expr, 4, 172) => {
+        $mask_values[112]
+    };
+    ($mask_values:expr, 4, 204) => {
+        $mask_values[113]
+    };
+    ($mask_values:expr, 4, 236) => {
+        $mask_values[114]
+    };
+    ($mask_values:expr, 4, 1539) => {
+        $mask_values[115]
+    };
+    ($mask_values:expr, 4, 1547) => {
+        $mask_values[116]
+    };
+    ($mask_values:expr, 4, 1571) => {
+        $mask_values[117]
+    };
+    ($mask_values:expr, 4, 1579) => {
+        $mask_values[118]
+    };
+    ($mask_values:expr, 4, 2011) => {
+        $mask_values[119]
+    };
+    ($mask_values:expr, 4, 2019) => {
+        $mask_values[120]
+    };
+    ($mask_values:expr, 4, 2041) => {
+        $mask_values[121]
+    };
+    ($mask_values:expr, 4, 2045) => {
+        $mask_values[122]
+    };
+    ($mask_values:expr, 4, 2047) => {
+        $mask_values[123]
+    };
+    ($mask_values:expr, 4, 2049) => {
+        $mask_values[124]
+    };
+    ($mask_values:expr, 4, 2051) => {
+        $mask_values[125]
+    };
+    ($mask_values:expr, 4, 2053) => {
+        $mask_values[126]
+    };
+    ($mask_values:expr, 4, 4089) => {
+        $mask_values[127]
+    };
+    ($mask_values:expr, 5, 0) => {
+        $mask_values[128]
+    };
+    ($mask_values:expr, 5, 1) => {
+        $mask_values[129]
+    };
+    ($mask_values:expr, 5, 2) => {
+        $mask_values[130]
+    };
+    ($mask_values:expr, 5, 4) => {
+        $mask_values[131]
+    };
+    ($mask_values:expr, 5, 6) => {
+        $mask_values[132]
+    };
+    ($mask_values:expr, 5, 8) => {
+        $mask_values[133]
+    };
+    ($mask_values:expr, 5, 9) => {
+        $mask_values[134]
+    };
+    ($mask_values:expr, 5, 10) => {
+        $mask_values[135]
+    };
+    ($mask_values:expr, 5, 12) => {
+        $mask_values[136]
+    };
+    ($mask_values:expr, 5, 14) => {
+        $mask_values[137]
+    };
+    ($mask_values:expr, 5, 16) => {
+        $mask_values[138]
+    };
+    ($mask_values:expr, 5, 17) => {
+        $mask_values[139]
+    };
+    ($mask_values:expr, 5, 22) => {
+        $mask_values[140]
+    };
+    ($mask_values:expr, 5, 24) => {
+        $mask_values[141]
+    };
+    ($mask_values:expr, 5, 25) => {
+        $mask_values[142]
+    };
+    ($mask_values:expr, 5, 30) => {
+        $mask_values[143]
+    };
+    ($mask_values:expr, 5, 33) => {
+        $mask_values[144]
+    };
+    ($mask_values:expr, 5, 38) => {
+        $mask_values[145]
+    };
+    ($mask_values:expr, 5, 41) => {
+        $mask_values[146]
+    };
+    ($mask_values:expr, 5, 46) => {
+        $mask_values[147]
+    };
+    ($mask_values:expr, 5, 49) => {
+        $mask_values[148]
+    };
+    ($mask_values:expr, 5, 54) => {
+        $mask_values[149]
+    };
+    ($mask_values:expr, 5, 57) => {
+        $mask_values[150]
+    };
+    ($mask_values:expr, 5, 65) => {
+        $mask_values[151]
+    };
+    ($mask_values:expr, 5, 73) => {
+        $mask_values[152]
+    };
+    ($mask_values:expr, 5, 81) => {
+        $mask_values[153]
+    };
+    ($mask_values:expr, 5, 89) => {
+        $mask_values[154]
+    };
+    ($mask_values:expr, 5, 97) => {
+        $mask_values[155]
+    };
+    ($mask_values:expr, 5, 105) => {
+        $mask_values[156]
+    };
+    ($mask_values:expr, 5, 137) => {
+        $mask_values[157]
+    };
+    ($mask_values:expr, 5, 169) => {
+        $mask_values[158]
+    };
+    ($mask_values:expr, 5, 201) => {
+        $mask_values[159]
+    };
+    ($mask_values:expr, 5, 393) => {
+        $mask_values[160]
+    };
+    ($mask_values:expr, 5, 409) => {
+        $mask_values[161]
+    };
+    ($mask_values:expr, 5, 425) => {
+        $mask_values[162]
+    };
+    ($mask_values:expr, 5, 457) => {
+        $mask_values[163]
+    };
+    ($mask_values:expr, 5, 473) => {
+        $mask_values[164]
+    };
+    ($mask_values:expr, 5, 489) => {
+        $mask_values[165]
+    };
+    ($mask_values:expr, 5, 521) => {
+        $mask_values[166]
+    };
+    ($mask_values:expr, 5, 553) => {
+        $mask_values[167]
+    };
+    ($mask_values:expr, 5, 585) => {
+        $mask_values[168]
+    };
+    ($mask_values:expr, 5, 609) => {
+        $mask_values[169]
+    };
+    ($mask_values:expr, 5, 625) => {
+        $mask_values[170]
+    };
+    ($mask_values:expr, 5, 641) => {
+        $mask_values[171]
+    };
+    ($mask_values:expr, 5, 657) => {
+        $mask_values[172]
+    };
+    ($mask_values:expr, 5, 673) => {
+        $mask_values[173]
+    };
+    ($mask_values:expr, 5, 689) => {
+        $mask_values[174]
+    };
+    ($mask_values:expr, 5, 905) => {
+        $mask_values[175]
+    };
+    ($mask_values:expr, 5, 921) => {
+        $mask_values[176]
+    };
+    ($mask_values:expr, 5, 937) => {
+        $mask_values[177]
+    };
+    ($mask_values:expr, 5, 969) => {
+        $mask_values[178]
+    };
+    ($mask_values:expr, 5, 982) => {
+        $mask_values[179]
+    };
+    ($mask_values:expr, 5, 985) => {
+        $mask_values[180]
+    };
+    ($mask_values:expr, 5, 998) => {
+        $mask_values[181]
+    };
+    ($mask_values:expr, 5, 1001) => {
+        $mask_values[182]
+    };
+    ($mask_values:expr, 5, 1014) => {
+        $mask_values[183]
+    };
+    ($mask_values:expr, 6, 0) => {
+        $mask_values[184] // inter1_row0
+    };
+    ($mask_values:expr, 6, 1) => {
+        $mask_values[185] // inter1_row1
+    };
+    ($mask_values:expr, 6, 2) => {
+        $mask_values[186] // inter1_row2
+    };
+    ($mask_values:expr, 6, 3) => {
+        $mask_values[187] // inter1_row3
+    };
+    ($mask_values:expr, 7, 0) => {
+        $mask_values[188] // inter1_row0
+    };
+    ($mask_values:expr, 7, 1) => {
+        $mask_values[189] // inter1_row1
+    };
+    ($mask_values:expr, 7, 2) => {
+        $mask_values[190] // inter1_row2
+    };
+    ($mask_values:expr, 7, 5) => {
+        $mask_values[191] // inter1_row5
+    };
+}
+
+// Powers of the evaluation point and of the trace generator used by the constraint domains.
+pub fn eval_composition_powers(
+    point: Felt,
+    trace_generator: Felt,
+    trace_length: Felt,
+    autogenerated_pows: &mut [Felt],
+) {
+    autogenerated_pows[0] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_4096))));
+    autogenerated_pows[1] = autogenerated_pows[0] * autogenerated_pows[0]; // pow(point, (safe_div(global_values.trace_length, 2048))).
+    autogenerated_pows[2] = autogenerated_pows[1] * autogenerated_pows[1]; // pow(point, (safe_div(global_values.trace_length, 1024))).
+    autogenerated_pows[3] = autogenerated_pows[2] * autogenerated_pows[2]; // pow(point, (safe_div(global_values.trace_length, 512))).
+    autogenerated_pows[4] = autogenerated_pows[3] * autogenerated_pows[3]; // pow(point, (safe_div(global_values.trace_length, 256))).
+    autogenerated_pows[5] = autogenerated_pows[4] * autogenerated_pows[4]; // pow(point, (safe_div(global_values.trace_length, 128))).
+    autogenerated_pows[6] = autogenerated_pows[5] * autogenerated_pows[5]; // pow(point, (safe_div(global_values.trace_length, 64))).
+    autogenerated_pows[7] = autogenerated_pows[6] * autogenerated_pows[6]; // pow(point, (safe_div(global_values.trace_length, 32))).
+    autogenerated_pows[8] = autogenerated_pows[7] * autogenerated_pows[7]; // pow(point, (safe_div(global_values.trace_length, 16))).
+    autogenerated_pows[9] = autogenerated_pows[8] * autogenerated_pows[8]; // pow(point, (safe_div(global_values.trace_length, 8))).
+    autogenerated_pows[10] = autogenerated_pows[9] * autogenerated_pows[9]; // pow(point, (safe_div(global_values.trace_length, 4))).
+    autogenerated_pows[11] = autogenerated_pows[10] * autogenerated_pows[10]; // pow(point, (safe_div(global_values.trace_length, 2))).
+    autogenerated_pows[12] = autogenerated_pows[11] * autogenerated_pows[11]; // pow(point, global_values.trace_length).
+    autogenerated_pows[13] = trace_generator.pow_felt(&(trace_length - FELT_512));
+    autogenerated_pows[14] = trace_generator.pow_felt(&(trace_length - FELT_256));
+    autogenerated_pows[15] = trace_generator.pow_felt(&(trace_length - FELT_4096));
+    autogenerated_pows[16] = trace_generator.pow_felt(&(trace_length - FELT_4));
+    autogenerated_pows[17] = trace_generator.pow_felt(&(trace_length - FELT_2));
+    autogenerated_pows[18] = trace_generator.pow_felt(&(trace_length - FELT_16));
+    autogenerated_pows[19] =
+        trace_generator.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_2))));
+    autogenerated_pows[20] =
+        trace_generator.pow_felt(&((FELT_255 * trace_length).floor_div(&felt_nonzero!(FELT_256))));
+    autogenerated_pows[21] =
+        trace_generator.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_64))));
+    autogenerated_pows[22] = autogenerated_pows[21] * autogenerated_pows[21]; // pow(trace_generator, (safe_div(global_values.trace_length, 32))).
+    autogenerated_pows[23] = autogenerated_pows[21] * autogenerated_pows[22]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 64))).
+    autogenerated_pows[24] = autogenerated_pows[21] * autogenerated_pows[23]; // pow(trace_generator, (safe_div(global_values.trace_length, 16))).
+    autogenerated_pows[25] = autogenerated_pows[21] * autogenerated_pows[24]; // pow(trace_generator, (safe_div((safe_mult(5, global_values.trace_length)), 64))).
+    autogenerated_pows[26] = autogenerated_pows[21] * autogenerated_pows[25]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 32))).
+    autogenerated_pows[27] = autogenerated_pows[19] * autogenerated_pows[26]; // pow(trace_generator, (safe_div((safe_mult(19, global_values.trace_length)), 32))).
+    autogenerated_pows[28] = autogenerated_pows[21] * autogenerated_pows[26]; // pow(trace_generator, (safe_div((safe_mult(7, global_values.trace_length)), 64))).
+    autogenerated_pows[29] = autogenerated_pows[21] * autogenerated_pows[28]; // pow(trace_generator, (safe_div(global_values.trace_length, 8))).
+    autogenerated_pows[30] = autogenerated_pows[19] * autogenerated_pows[29]; // pow(trace_generator, (safe_div((safe_mult(5, global_values.trace_length)), 8))).
+    autogenerated_pows[31] = autogenerated_pows[21] * autogenerated_pows[29]; // pow(trace_generator, (safe_div((safe_mult(9, global_values.trace_length)), 64))).
+    autogenerated_pows[32] = autogenerated_pows[21] * autogenerated_pows[31]; // pow(trace_generator, (safe_div((safe_mult(5, global_values.trace_length)), 32))).
+    autogenerated_pows[33] = autogenerated_pows[19] * autogenerated_pows[32]; // pow(trace_generator, (safe_div((safe_mult(21, global_values.trace_length)), 32))).
+    autogenerated_pows[34] = autogenerated_pows[21] * autogenerated_pows[32]; // pow(trace_generator, (safe_div((safe_mult(11, global_values.trace_length)), 64))).
+    autogenerated_pows[35] = autogenerated_pows[21] * autogenerated_pows[34]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 16))).
+    autogenerated_pows[36] = autogenerated_pows[19] * autogenerated_pows[35]; // pow(trace_generator, (safe_div((safe_mult(11, global_values.trace_length)), 16))).
+    autogenerated_pows[37] = autogenerated_pows[21] * autogenerated_pows[35]; // pow(trace_generator, (safe_div((safe_mult(13, global_values.trace_length)), 64))).
+    autogenerated_pows[38] = autogenerated_pows[21] * autogenerated_pows[37]; // pow(trace_generator, (safe_div((safe_mult(7, global_values.trace_length)), 32))).
+    autogenerated_pows[39] = autogenerated_pows[19] * autogenerated_pows[38]; // pow(trace_generator, (safe_div((safe_mult(23, global_values.trace_length)), 32))).
+    autogenerated_pows[40] = autogenerated_pows[21] * autogenerated_pows[38]; // pow(trace_generator, (safe_div((safe_mult(15, global_values.trace_length)), 64))).
+    autogenerated_pows[41] = autogenerated_pows[22] * autogenerated_pows[39]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 4))).
+    autogenerated_pows[42] = autogenerated_pows[22] * autogenerated_pows[41]; // pow(trace_generator, (safe_div((safe_mult(25, global_values.trace_length)), 32))).
+    autogenerated_pows[43] = autogenerated_pows[22] * autogenerated_pows[42]; // pow(trace_generator, (safe_div((safe_mult(13, global_values.trace_length)), 16))).
+    autogenerated_pows[44] = autogenerated_pows[22] * autogenerated_pows[43]; // pow(trace_generator, (safe_div((safe_mult(27, global_values.trace_length)), 32))).
+    autogenerated_pows[45] = autogenerated_pows[22] * autogenerated_pows[44]; // pow(trace_generator, (safe_div((safe_mult(7, global_values.trace_length)), 8))).
+    autogenerated_pows[46] = autogenerated_pows[22] * autogenerated_pows[45]; // pow(trace_generator, (safe_div((safe_mult(29, global_values.trace_length)), 32))).
+    autogenerated_pows[47] = autogenerated_pows[22] * autogenerated_pows[46]; // pow(trace_generator, (safe_div((safe_mult(15, global_values.trace_length)), 16))).
+    autogenerated_pows[48] = autogenerated_pows[21] * autogenerated_pows[47]; // pow(trace_generator, (safe_div((safe_mult(61, global_values.trace_length)), 64))).
+    autogenerated_pows[49] = autogenerated_pows[21] * autogenerated_pows[48]; // pow(trace_generator, (safe_div((safe_mult(31, global_values.trace_length)), 32))).
+    autogenerated_pows[50] = autogenerated_pows[21] * autogenerated_pows[49]; // pow(trace_generator, (safe_div((safe_mult(63, global_values.trace_length)), 64))).
+}
+
+pub fn eval_composition_domains(point: Felt, autogenerated_pows: &[Felt], domains: &mut [Felt]) {
+    domains[0] = autogenerated_pows[12] - FELT_1;
+    domains[1] = autogenerated_pows[11] - FELT_1;
+    domains[2] = autogenerated_pows[10] - FELT_1;
+    domains[3] = autogenerated_pows[9] - FELT_1;
+    domains[4] = autogenerated_pows[8] - autogenerated_pows[47];
+    domains[5] = autogenerated_pows[8] - FELT_1;
+    domains[6] = autogenerated_pows[7] - FELT_1;
+    domains[7] = autogenerated_pows[6] - FELT_1;
+    domains[8] = autogenerated_pows[5] - FELT_1;
+    domains[9] = autogenerated_pows[4] - FELT_1;
+    domains[10] = autogenerated_pows[4] - autogenerated_pows[41];
+    let temp = autogenerated_pows[4] - autogenerated_pows[21];
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[22]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[23]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[24]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[25]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[26]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[28]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[29]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[31]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[32]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[34]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[35]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[37]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[38]);
+    let temp = temp * (autogenerated_pows[4] - autogenerated_pows[40]);
+    domains[11] = temp * (domains[9]);
+    domains[12] = autogenerated_pows[3] - FELT_1;
+    domains[13] = autogenerated_pows[3] - autogenerated_pows[41];
+    domains[14] = autogenerated_pows[2] - autogenerated_pows[49];
+    let temp = autogenerated_pows[2] - autogenerated_pows[36];
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[39]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[41]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[42]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[43]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[44]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[45]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[46]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[47]);
+    domains[15] = temp * (domains[14]);
+    domains[16] = autogenerated_pows[2] - FELT_1;
+    let temp = autogenerated_pows[2] - autogenerated_pows[48];
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[50]);
+    domains[17] = temp * (domains[14]);
+    let temp = autogenerated_pows[2] - autogenerated_pows[27];
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[30]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[33]);
+    domains[18] = temp * (domains[15]);
+    domains[19] = autogenerated_pows[1] - FELT_1;
+    domains[20] = autogenerated_pows[1] - autogenerated_pows[20];
+    domains[21] = autogenerated_pows[1] - autogenerated_pows[50];
+    domains[22] = autogenerated_pows[0] - autogenerated_pows[19];
+    domains[23] = autogenerated_pows[0] - FELT_1;
+    domains[24] = point - autogenerated_pows[18];
+    domains[25] = point - FELT_1;
+    domains[26] = point - autogenerated_pows[17];
+    domains[27] = point - autogenerated_pows[16];
+    domains[28] = point - autogenerated_pows[15];
+    domains[29] = point - autogenerated_pows[14];
+    domains[30] = point - autogenerated_pows[13];
+}
+
+pub fn eval_composition_constraints(
+    mask_values: &[Felt],
+    constraint_coefficients: &[Felt],
+    domains: &[Felt],
+    global_values: &GlobalValues,
+) -> Felt {
+    let cpu_decode_opcode_range_check_bit_0 = column_row!(mask_values, 0, 0)
+        - (column_row!(mask_values, 0, 1) + column_row!(mask_values, 0, 1));
+    let cpu_decode_opcode_range_check_bit_2 = column_row!(mask_values, 0, 2)
+        - (column_row!(mask_values, 0, 3) + column_row!(mask_values, 0, 3));
+    let cpu_decode_opcode_range_check_bit_4 = column_row!(mask_values, 0, 4)
+        - (column_row!(mask_values, 0, 5) + column_row!(mask_values, 0, 5));
+    let cpu_decode_opcode_range_check_bit_3 = column_row!(mask_values, 0, 3)
+        - (column_row!(mask_values, 0, 4) + column_row!(mask_values, 0, 4));
+    let cpu_decode_flag_op1_base_op0_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_2
+            + cpu_decode_opcode_range_check_bit_4
+            + cpu_decode_opcode_range_check_bit_3);
+    let cpu_decode_opcode_range_check_bit_5 = column_row!(mask_values, 0, 5)
+        - (column_row!(mask_values, 0, 6) + column_row!(mask_values, 0, 6));
+    let cpu_decode_opcode_range_check_bit_6 = column_row!(mask_values, 0, 6)
+        - (column_row!(mask_values, 0, 7) + column_row!(mask_values, 0, 7));
+    let cpu_decode_opcode_range_check_bit_9 = column_row!(mask_values, 0, 9)
+        - (column_row!(mask_values, 0, 10) + column_row!(mask_values, 0, 10));
+    let cpu_decode_flag_res_op1_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_5
+            + cpu_decode_opcode_range_check_bit_6
+            + cpu_decode_opcode_range_check_bit_9);
+    let cpu_decode_opcode_range_check_bit_7 = column_row!(mask_values, 0, 7)
+        - (column_row!(mask_values, 0, 8) + column_row!(mask_values, 0, 8));
+    let cpu_decode_opcode_range_check_bit_8 = column_row!(mask_values, 0, 8)
+        - (column_row!(mask_values, 0, 9) + column_row!(mask_values, 0, 9));
+    let cpu_decode_flag_pc_update_regular_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_7
+            + cpu_decode_opcode_range_check_bit_8
+            + cpu_decode_opcode_range_check_bit_9);
+    let cpu_decode_opcode_range_check_bit_12 = column_row!(mask_values, 0, 12)
+        - (column_row!(mask_values, 0, 13) + column_row!(mask_values, 0, 13));
+    let cpu_decode_opcode_range_check_bit_13 = column_row!(mask_values, 0, 13)
+        - (column_row!(mask_values, 0, 14) + column_row!(mask_values, 0, 14));
+    let cpu_decode_fp_update_regular_0 =
+        FELT_1 - (cpu_decode_opcode_range_check_bit_12 + cpu_decode_opcode_range_check_bit_13);
+    let cpu_decode_opcode_range_check_bit_1 = column_row!(mask_values, 0, 1)
+        - (column_row!(mask_values, 0, 2) + column_row!(mask_values, 0, 2));
+    let npc_reg_0 = column_row!(mask_values, 1, 0) + cpu_decode_opcode_range_check_bit_2 + FELT_1;
+    let cpu_decode_opcode_range_check_bit_10 = column_row!(mask_values, 0, 10)
+        - (column_row!(mask_values, 0, 11) + column_row!(mask_values, 0, 11));
+    let cpu_decode_opcode_range_check_bit_11 = column_row!(mask_values, 0, 11)
+        - (column_row!(mask_values, 0, 12) + column_row!(mask_values, 0, 12));
+    let cpu_decode_opcode_range_check_bit_14 = column_row!(mask_values, 0, 14)
+        - (column_row!(mask_values, 0, 15) + column_row!(mask_values, 0, 15));
+    let memory_address_diff_0 = column_row!(mask_values, 2, 2) - column_row!(mask_values, 2, 0);
+    let range_check16_diff_0 = column_row!(mask_values, 4, 6) - column_row!(mask_values, 4, 2);
+    let pedersen_hash0_ec_subset_sum_bit_0 = column_row!(mask_values, 4, 3)
+        - (column_row!(mask_values, 4, 11) + column_row!(mask_values, 4, 11));
+    let pedersen_hash0_ec_subset_sum_bit_neg_0 = FELT_1 - pedersen_hash0_ec_subset_sum_bit_0;
+    let range_check_builtin_value0_0 = column_row!(mask_values, 4, 12);
+    let range_check_builtin_value1_0 =
+        range_check_builtin_value0_0 * global_values.offset_size + column_row!(mask_values, 4, 44);
+    let range_check_builtin_value2_0 =
+        range_check_builtin_value1_0 * global_values.offset_size + column_row!(mask_values, 4, 76);
+    let range_check_builtin_value3_0 =
+        range_check_builtin_value2_0 * global_values.offset_size + column_row!(mask_values, 4, 108);
+    let range_check_builtin_value4_0 =
+        range_check_builtin_value3_0 * global_values.offset_size + column_row!(mask_values, 4, 140);
+    let range_check_builtin_value5_0 =
+        range_check_builtin_value4_0 * global_values.offset_size + column_row!(mask_values, 4, 172);
+    let range_check_builtin_value6_0 =
+        range_check_builtin_value5_0 * global_values.offset_size + column_row!(mask_values, 4, 204);
+    let range_check_builtin_value7_0 =
+        range_check_builtin_value6_0 * global_values.offset_size + column_row!(mask_values, 4, 236);
+    let bitwise_sum_var_0_0 = column_row!(mask_values, 3, 0)
+        + column_row!(mask_values, 3, 4) * FELT_2
+        + column_row!(mask_values, 3, 8) * FELT_4
+        + column_row!(mask_values, 3, 12) * FELT_8
+        + column_row!(mask_values, 3, 16) * FELT_18446744073709551616
+        + column_row!(mask_values, 3, 20) * FELT_36893488147419103232
+        + column_row!(mask_values, 3, 24) * FELT_73786976294838206464
+        + column_row!(mask_values, 3, 28) * FELT_147573952589676412928;
+    let bitwise_sum_var_8_0 = column_row!(mask_values, 3, 32)
+        * FELT_340282366920938463463374607431768211456
+        + column_row!(mask_values, 3, 36) * FELT_680564733841876926926749214863536422912
+        + column_row!(mask_values, 3, 40) * FELT_1361129467683753853853498429727072845824
+        + column_row!(mask_values, 3, 44) * FELT_2722258935367507707706996859454145691648
+        + column_row!(mask_values, 3, 48)
+            * FELT_6277101735386680763835789423207666416102355444464034512896
+        + column_row!(mask_values, 3, 52)
+            * FELT_12554203470773361527671578846415332832204710888928069025792
+        + column_row!(mask_values, 3, 56)
+            * FELT_25108406941546723055343157692830665664409421777856138051584
+        + column_row!(mask_values, 3, 60)
+            * FELT_50216813883093446110686315385661331328818843555712276103168;
+    let poseidon_poseidon_full_rounds_state0_cubed_0 =
+        column_row!(mask_values, 5, 9) * column_row!(mask_values, 5, 105);
+    let poseidon_poseidon_full_rounds_state1_cubed_0 =
+        column_row!(mask_values, 5, 73) * column_row!(mask_values, 5, 25);
+    let poseidon_poseidon_full_rounds_state2_cubed_0 =
+        column_row!(mask_values, 5, 41) * column_row!(mask_values, 5, 89);
+    let poseidon_poseidon_full_rounds_state0_cubed_7 =
+        column_row!(mask_values, 5, 905) * column_row!(mask_values, 5, 1001);
+    let poseidon_poseidon_full_rounds_state1_cubed_7 =
+        column_row!(mask_values, 5, 969) * column_row!(mask_values, 5, 921);
+    let poseidon_poseidon_full_rounds_state2_cubed_7 =
+        column_row!(mask_values, 5, 937) * column_row!(mask_values, 5, 985);
+    let poseidon_poseidon_full_rounds_state0_cubed_3 =
+        column_row!(mask_values, 5, 393) * column_row!(mask_values, 5, 489);
+    let poseidon_poseidon_full_rounds_state1_cubed_3 =
+        column_row!(mask_values, 5, 457) * column_row!(mask_values, 5, 409);
+    let poseidon_poseidon_full_rounds_state2_cubed_3 =
+        column_row!(mask_values, 5, 425) * column_row!(mask_values, 5, 473);
+    let poseidon_poseidon_partial_rounds_state0_cubed_0 =
+        column_row!(mask_values, 5, 6) * column_row!(mask_values, 5, 14);
+    let poseidon_poseidon_partial_rounds_state0_cubed_1 =
+        column_row!(mask_values, 5, 22) * column_row!(mask_values, 5, 30);
+    let poseidon_poseidon_partial_rounds_state0_cubed_2 =
+        column_row!(mask_values, 5, 38) * column_row!(mask_values, 5, 46);
+    let poseidon_poseidon_partial_rounds_state1_cubed_0 =
+        column_row!(mask_values, 5, 1) * column_row!(mask_values, 5, 17);
+    let poseidon_poseidon_partial_rounds_state1_cubed_1 =
+        column_row!(mask_values, 5, 33) * column_row!(mask_values, 5, 49);
+    let poseidon_poseidon_partial_rounds_state1_cubed_2 =
+        column_row!(mask_values, 5, 65) * column_row!(mask_values, 5, 81);
+    let poseidon_poseidon_partial_rounds_state1_cubed_19 =
+        column_row!(mask_values, 5, 609) * column_row!(mask_values, 5, 625);
+    let poseidon_poseidon_partial_rounds_state1_cubed_20 =
+        column_row!(mask_values, 5, 641) * column_row!(mask_values, 5, 657);
+    let poseidon_poseidon_partial_rounds_state1_cubed_21 =
+        column_row!(mask_values, 5, 673) * column_row!(mask_values, 5, 689);
+
+    let mut total_sum = FELT_0;
+
+    // Constraint: cpu/decode/opcode_range_check/bit.
+    let value = (cpu_decode_opcode_range_check_bit_0 * cpu_decode_opcode_range_check_bit_0
+        - cpu_decode_opcode_range_check_bit_0)
+        * domains[4].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[0] * value;
+
+    // Constraint: cpu/decode/opcode_range_check/zero.
+    let value = (column_row!(mask_values, 0, 0)).field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[1] * value;
+
+    // Constraint: cpu/decode/opcode_range_check_input.
+    let value = (column_row!(mask_values, 1, 1)
+        - (((column_row!(mask_values, 0, 0) * global_values.offset_size
+            + column_row!(mask_values, 4, 4))
+            * global_values.offset_size
+            + column_row!(mask_values, 4, 8))
+            * global_values.offset_size
+            + column_row!(mask_values, 4, 0)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[2] * value;
+
+    // Constraint: cpu/decode/flag_op1_base_op0_bit.
+    let value = (cpu_decode_flag_op1_base_op0_0 * cpu_decode_flag_op1_base_op0_0
+        - cpu_decode_flag_op1_base_op0_0)
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[3] * value;
+
+    // Constraint: cpu/decode/flag_res_op1_bit.
+    let value = (cpu_decode_flag_res_op1_0 * cpu_decode_flag_res_op1_0 - cpu_decode_flag_res_op1_0)
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[4] * value;
+
+    // Constraint: cpu/decode/flag_pc_update_regular_bit.
+    let value = (cpu_decode_flag_pc_update_regular_0 * cpu_decode_flag_pc_update_regular_0
+        - cpu_decode_flag_pc_update_regular_0)
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[5] * value;
+
+    // Constraint: cpu/decode/fp_update_regular_bit.
+    let value = (cpu_decode_fp_update_regular_0 * cpu_decode_fp_update_regular_0
+        - cpu_decode_fp_update_regular_0)
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[6] * value;
+
+    // Constraint: cpu/operands/mem_dst_addr.
+    let value = (column_row!(mask_values, 1, 8) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_0 * column_row!(mask_values, 5, 8)
+            + (FELT_1 - cpu_decode_opcode_range_check_bit_0) * column_row!(mask_values, 5, 0)
+            + column_row!(mask_values, 4, 0)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[7] * value;
+
+    // Constraint: cpu/operands/mem0_addr.
+    let value = (column_row!(mask_values, 1, 4) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_1 * column_row!(mask_values, 5, 8)
+            + (FELT_1 - cpu_decode_opcode_range_check_bit_1) * column_row!(mask_values, 5, 0)
+            + column_row!(mask_values, 4, 8)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[8] * value;
+
+    // Constraint: cpu/operands/mem1_addr.
+    let value = (column_row!(mask_values, 1, 12) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_2 * column_row!(mask_values, 1, 0)
+            + cpu_decode_opcode_range_check_bit_4 * column_row!(mask_values, 5, 0)
+            + cpu_decode_opcode_range_check_bit_3 * column_row!(mask_values, 5, 8)
+            + cpu_decode_flag_op1_base_op0_0 * column_row!(mask_values, 1, 5)
+            + column_row!(mask_values, 4, 4)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[9] * value;
+
+    // Constraint: cpu/operands/ops_mul.
+    let value = (column_row!(mask_values, 5, 4)
+        - column_row!(mask_values, 1, 5) * column_row!(mask_values, 1, 13))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[10] * value;
+
+    // Constraint: cpu/operands/res.
+    let value = ((FELT_1 - cpu_decode_opcode_range_check_bit_9) * column_row!(mask_values, 5, 12)
+        - (cpu_decode_opcode_range_check_bit_5
+            * (column_row!(mask_values, 1, 5) + column_row!(mask_values, 1, 13))
+            + cpu_decode_opcode_range_check_bit_6 * column_row!(mask_values, 5, 4)
+            + cpu_decode_flag_res_op1_0 * column_row!(mask_values, 1, 13)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[11] * value;
+
+    // Constraint: cpu/update_registers/update_pc/tmp0.
+    let value = (column_row!(mask_values, 5, 2)
+        - cpu_decode_opcode_range_check_bit_9 * column_row!(mask_values, 1, 9))
+        * domains[24].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[12] * value;
+
+    // Constraint: cpu/update_registers/update_pc/tmp1.
+    let value = (column_row!(mask_values, 5, 10)
+        - column_row!(mask_values, 5, 2) * column_row!(mask_values, 5, 12))
+        * domains[24].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[13] * value;
+
+    // Constraint: cpu/update_registers/update_pc/pc_cond_negative.
+    let value = ((FELT_1 - cpu_decode_opcode_range_check_bit_9) * column_row!(mask_values, 1, 16)
+        + column_row!(mask_values, 5, 2)
+            * (column_row!(mask_values, 1, 16)
+                - (column_row!(mask_values, 1, 0) + column_row!(mask_values, 1, 13)))
+        - (cpu_decode_flag_pc_update_regular_0 * npc_reg_0
+            + cpu_decode_opcode_range_check_bit_7 * column_row!(mask_values, 5, 12)
+            + cpu_decode_opcode_range_check_bit_8
+                * (column_row!(mask_values, 1, 0) + column_row!(mask_values, 5, 12))))
+        * domains[24].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[14] * value;
+
+    // Constraint: cpu/update_registers/update_pc/pc_cond_positive.
+    let value = ((column_row!(mask_values, 5, 10) - cpu_decode_opcode_range_check_bit_9)
+        * (column_row!(mask_values, 1, 16) - npc_reg_0))
+        * domains[24].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[15] * value;
+
+    // Constraint: cpu/update_registers/update_ap/ap_update.
+    let value = (column_row!(mask_values, 5, 16)
+        - (column_row!(mask_values, 5, 0)
+            + cpu_decode_opcode_range_check_bit_10 * column_row!(mask_values, 5, 12)
+            + cpu_decode_opcode_range_check_bit_11
+            + cpu_decode_opcode_range_check_bit_12 * FELT_2))
+        * domains[24].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[16] * value;
+
+    // Constraint: cpu/update_registers/update_fp/fp_update.
+    let value = (column_row!(mask_values, 5, 24)
+        - (cpu_decode_fp_update_regular_0 * column_row!(mask_values, 5, 8)
+            + cpu_decode_opcode_range_check_bit_13 * column_row!(mask_values, 1, 9)
+            + cpu_decode_opcode_range_check_bit_12 * (column_row!(mask_values, 5, 0) + FELT_2)))
+        * domains[24].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[17] * value;
+
+    // Constraint: cpu/opcodes/call/push_fp.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 1, 9) - column_row!(mask_values, 5, 8)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[18] * value;
+
+    // Constraint: cpu/opcodes/call/push_pc.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 1, 5)
+            - (column_row!(mask_values, 1, 0) + cpu_decode_opcode_range_check_bit_2 + FELT_1)))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[19] * value;
+
+    // Constraint: cpu/opcodes/call/off0.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 4, 0) - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[20] * value;
+
+    // Constraint: cpu/opcodes/call/off1.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 4, 8) - (global_values.half_offset_size + FELT_1)))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[21] * value;
+
+    // Constraint: cpu/opcodes/call/flags.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (cpu_decode_opcode_range_check_bit_12
+            + cpu_decode_opcode_range_check_bit_12
+            + FELT_1
+            + FELT_1
+            - (cpu_decode_opcode_range_check_bit_0
+                + cpu_decode_opcode_range_check_bit_1
+                + FELT_4)))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[22] * value;
+
+    // Constraint: cpu/opcodes/ret/off0.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (column_row!(mask_values, 4, 0) + FELT_2 - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[23] * value;
+
+    // Constraint: cpu/opcodes/ret/off2.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (column_row!(mask_values, 4, 4) + FELT_1 - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[24] * value;
+
+    // Constraint: cpu/opcodes/ret/flags.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (cpu_decode_opcode_range_check_bit_7
+            + cpu_decode_opcode_range_check_bit_0
+            + cpu_decode_opcode_range_check_bit_3
+            + cpu_decode_flag_res_op1_0
+            - FELT_4))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[25] * value;
+
+    // Constraint: cpu/opcodes/assert_eq/assert_eq.
+    let value = (cpu_decode_opcode_range_check_bit_14
+        * (column_row!(mask_values, 1, 9) - column_row!(mask_values, 5, 12)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[26] * value;
+
+    // Constraint: initial_ap.
+    let value = (column_row!(mask_values, 5, 0) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[27] * value;
+
+    // Constraint: initial_fp.
+    let value = (column_row!(mask_values, 5, 8) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[28] * value;
+
+    // Constraint: initial_pc.
+    let value = (column_row!(mask_values, 1, 0) - global_values.initial_pc)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[29] * value;
+
+    // Constraint: final_ap.
+    let value = (column_row!(mask_values, 5, 0) - global_values.final_ap)
+        .field_div(&felt_nonzero!(domains[24]));
+    total_sum += constraint_coefficients[30] * value;
+
+    // Constraint: final_fp.
+    let value = (column_row!(mask_values, 5, 8) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[24]));
+    total_sum += constraint_coefficients[31] * value;
+
+    // Constraint: final_pc.
+    let value = (column_row!(mask_values, 1, 0) - global_values.final_pc)
+        .field_div(&felt_nonzero!(domains[24]));
+    total_sum += constraint_coefficients[32] * value;
+
+    // Constraint: memory/multi_column_perm/perm/init0.
+    let value = ((global_values.memory_multi_column_perm_perm_interaction_elm
+        - (column_row!(mask_values, 2, 0)
+            + global_values.memory_multi_column_perm_hash_interaction_elm0
+                * column_row!(mask_values, 2, 1)))
+        * column_row!(mask_values, 6, 0)
+        + column_row!(mask_values, 1, 0)
+        + global_values.memory_multi_column_perm_hash_interaction_elm0
+            * column_row!(mask_values, 1, 1)
+        - global_values.memory_multi_column_perm_perm_interaction_elm)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[33] * value;
+
+    // Constraint: memory/multi_column_perm/perm/step0.
+    let value = ((global_values.memory_multi_column_perm_perm_interaction_elm
+        - (column_row!(mask_values, 2, 2)
+            + global_values.memory_multi_column_perm_hash_interaction_elm0
+                * column_row!(mask_values, 2, 3)))
+        * column_row!(mask_values, 6, 2)
+        - (global_values.memory_multi_column_perm_perm_interaction_elm
+            - (column_row!(mask_values, 1, 2)
+                + global_values.memory_multi_column_perm_hash_interaction_elm0
+                    * column_row!(mask_values, 1, 3)))
+            * column_row!(mask_values, 6, 0))
+        * domains[26].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[34] * value;
+
+    // Constraint: memory/multi_column_perm/perm/last.
+    let value = (column_row!(mask_values, 6, 0)
+        - global_values.memory_multi_column_perm_perm_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[26]));
+    total_sum += constraint_coefficients[35] * value;
+
+    // Constraint: memory/diff_is_bit.
+    let value = (memory_address_diff_0 * memory_address_diff_0 - memory_address_diff_0)
+        * domains[26].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[36] * value;
+
+    // Constraint: memory/is_func.
+    let value = ((memory_address_diff_0 - FELT_1)
+        * (column_row!(mask_values, 2, 1) - column_row!(mask_values, 2, 3)))
+        * domains[26].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[37] * value;
+
+    // Constraint: memory/initial_addr.
+    let value = (column_row!(mask_values, 2, 0) - FELT_1).field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[38] * value;
+
+    // Constraint: public_memory_addr_zero.
+    let value = (column_row!(mask_values, 1, 2)).field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[39] * value;
+
+    // Constraint: public_memory_value_zero.
+    let value = (column_row!(mask_values, 1, 3)).field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[40] * value;
+
+    // Constraint: range_check16/perm/init0.
+    let value = ((global_values.range_check16_perm_interaction_elm
+        - column_row!(mask_values, 4, 2))
+        * column_row!(mask_values, 7, 1)
+        + column_row!(mask_values, 4, 0)
+        - global_values.range_check16_perm_interaction_elm)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[41] * value;
+
+    // Constraint: range_check16/perm/step0.
+    let value = ((global_values.range_check16_perm_interaction_elm
+        - column_row!(mask_values, 4, 6))
+        * column_row!(mask_values, 7, 5)
+        - (global_values.range_check16_perm_interaction_elm - column_row!(mask_values, 4, 4))
+            * column_row!(mask_values, 7, 1))
+        * domains[27].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[42] * value;
+
+    // Constraint: range_check16/perm/last.
+    let value = (column_row!(mask_values, 7, 1)
+        - global_values.range_check16_perm_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[43] * value;
+
+    // Constraint: range_check16/diff_is_bit.
+    let value = (range_check16_diff_0 * range_check16_diff_0 - range_check16_diff_0)
+        * domains[27].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[44] * value;
+
+    // Constraint: range_check16/minimum.
+    let value = (column_row!(mask_values, 4, 2) - global_values.range_check_min)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[45] * value;
+
+    // Constraint: range_check16/maximum.
+    let value = (column_row!(mask_values, 4, 2) - global_values.range_check_max)
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[46] * value;
+
+    // Constraint: diluted_check/permutation/init0.
+    let value = ((global_values.diluted_check_permutation_interaction_elm
+        - column_row!(mask_values, 3, 1))
+        * column_row!(mask_values, 7, 0)
+        + column_row!(mask_values, 3, 0)
+        - global_values.diluted_check_permutation_interaction_elm)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[47] * value;
+
+    // Constraint: diluted_check/permutation/step0.
+    let value = ((global_values.diluted_check_permutation_interaction_elm
+        - column_row!(mask_values, 3, 3))
+        * column_row!(mask_values, 7, 2)
+        - (global_values.diluted_check_permutation_interaction_elm
+            - column_row!(mask_values, 3, 2))
+            * column_row!(mask_values, 7, 0))
+        * domains[26].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[48] * value;
+
+    // Constraint: diluted_check/permutation/last.
+    let value = (column_row!(mask_values, 7, 0)
+        - global_values.diluted_check_permutation_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[26]));
+    total_sum += constraint_coefficients[49] * value;
+
+    // Constraint: diluted_check/init.
+    let value = (column_row!(mask_values, 6, 1) - FELT_1).field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[50] * value;
+
+    // Constraint: diluted_check/first_element.
+    let value = (column_row!(mask_values, 3, 1) - global_values.diluted_check_first_elm)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[51] * value;
+
+    // Constraint: diluted_check/step.
+    let value = (column_row!(mask_values, 6, 3)
+        - (column_row!(mask_values, 6, 1)
+            * (FELT_1
+                + global_values.diluted_check_interaction_z
+                    * (column_row!(mask_values, 3, 3) - column_row!(mask_values, 3, 1)))
+            + global_values.diluted_check_interaction_alpha
+                * (column_row!(mask_values, 3, 3) - column_row!(mask_values, 3, 1))
+                * (column_row!(mask_values, 3, 3) - column_row!(mask_values, 3, 1))))
+        * domains[26].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[52] * value;
+
+    // Constraint: diluted_check/last.
+    let value = (column_row!(mask_values, 6, 1) - global_values.diluted_check_final_cum_val)
+        .field_div(&felt_nonzero!(domains[26]));
+    total_sum += constraint_coefficients[53] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/last_one_is_zero.
+    let value = (column_row!(mask_values, 5, 57)
+        * (column_row!(mask_values, 4, 3)
+            - (column_row!(mask_values, 4, 11) + column_row!(mask_values, 4, 11))))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[54] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones0.
+    let value = (column_row!(mask_values, 5, 57)
+        * (column_row!(mask_values, 4, 11)
+            - FELT_3138550867693340381917894711603833208051177722232017256448
+                * column_row!(mask_values, 4, 1539)))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[55] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/cumulative_bit192.
+    let value = (column_row!(mask_values, 5, 57)
+        - column_row!(mask_values, 4, 2047)
+            * (column_row!(mask_values, 4, 1539)
+                - (column_row!(mask_values, 4, 1547) + column_row!(mask_values, 4, 1547))))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[56] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones192.
+    let value = (column_row!(mask_values, 4, 2047)
+        * (column_row!(mask_values, 4, 1547) - FELT_8 * column_row!(mask_values, 4, 1571)))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[57] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/cumulative_bit196.
+    let value = (column_row!(mask_values, 4, 2047)
+        - (column_row!(mask_values, 4, 2011)
+            - (column_row!(mask_values, 4, 2019) + column_row!(mask_values, 4, 2019)))
+            * (column_row!(mask_values, 4, 1571)
+                - (column_row!(mask_values, 4, 1579) + column_row!(mask_values, 4, 1579))))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[58] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones196.
+    let value = ((column_row!(mask_values, 4, 2011)
+        - (column_row!(mask_values, 4, 2019) + column_row!(mask_values, 4, 2019)))
+        * (column_row!(mask_values, 4, 1579)
+            - FELT_18014398509481984 * column_row!(mask_values, 4, 2011)))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[59] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/booleanity_test.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (pedersen_hash0_ec_subset_sum_bit_0 - FELT_1))
+        * domains[20].field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[60] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_extraction_end.
+    let value = (column_row!(mask_values, 4, 3)).field_div(&felt_nonzero!(domains[21]));
+    total_sum += constraint_coefficients[61] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/zeros_tail.
+    let value = (column_row!(mask_values, 4, 3)).field_div(&felt_nonzero!(domains[20]));
+    total_sum += constraint_coefficients[62] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/slope.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 4, 5) - global_values.pedersen_points_y)
+        - column_row!(mask_values, 4, 7)
+            * (column_row!(mask_values, 4, 1) - global_values.pedersen_points_x))
+        * domains[20].field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[63] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/x.
+    let value = (column_row!(mask_values, 4, 7) * column_row!(mask_values, 4, 7)
+        - pedersen_hash0_ec_subset_sum_bit_0
+            * (column_row!(mask_values, 4, 1)
+                + global_values.pedersen_points_x
+                + column_row!(mask_values, 4, 9)))
+        * domains[20].field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[64] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/y.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 4, 5) + column_row!(mask_values, 4, 13))
+        - column_row!(mask_values, 4, 7)
+            * (column_row!(mask_values, 4, 1) - column_row!(mask_values, 4, 9)))
+        * domains[20].field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[65] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/copy_point/x.
+    let value = (pedersen_hash0_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 4, 9) - column_row!(mask_values, 4, 1)))
+        * domains[20].field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[66] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/copy_point/y.
+    let value = (pedersen_hash0_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 4, 13) - column_row!(mask_values, 4, 5)))
+        * domains[20].field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[67] * value;
+
+    // Constraint: pedersen/hash0/copy_point/x.
+    let value = (column_row!(mask_values, 4, 2049) - column_row!(mask_values, 4, 2041))
+        * domains[22].field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[68] * value;
+
+    // Constraint: pedersen/hash0/copy_point/y.
+    let value = (column_row!(mask_values, 4, 2053) - column_row!(mask_values, 4, 2045))
+        * domains[22].field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[69] * value;
+
+    // Constraint: pedersen/hash0/init/x.
+    let value = (column_row!(mask_values, 4, 1) - global_values.pedersen_shift_point.x)
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[70] * value;
+
+    // Constraint: pedersen/hash0/init/y.
+    let value = (column_row!(mask_values, 4, 5) - global_values.pedersen_shift_point.y)
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[71] * value;
+
+    // Constraint: pedersen/input0_value0.
+    let value = (column_row!(mask_values, 1, 11) - column_row!(mask_values, 4, 3))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[72] * value;
+
+    // Constraint: pedersen/input0_addr.
+    let value = (column_row!(mask_values, 1, 4106) - (column_row!(mask_values, 1, 1034) + FELT_1))
+        * domains[28].field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[73] * value;
+
+    // Constraint: pedersen/init_addr.
+    let value = (column_row!(mask_values, 1, 10) - global_values.initial_pedersen_addr)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[74] * value;
+
+    // Constraint: pedersen/input1_value0.
+    let value = (column_row!(mask_values, 1, 2059) - column_row!(mask_values, 4, 2051))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[75] * value;
+
+    // Constraint: pedersen/input1_addr.
+    let value = (column_row!(mask_values, 1, 2058) - (column_row!(mask_values, 1, 10) + FELT_1))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[76] * value;
+
+    // Constraint: pedersen/output_value0.
+    let value = (column_row!(mask_values, 1, 1035) - column_row!(mask_values, 4, 4089))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[77] * value;
+
+    // Constraint: pedersen/output_addr.
+    let value = (column_row!(mask_values, 1, 1034) - (column_row!(mask_values, 1, 2058) + FELT_1))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[78] * value;
+
+    // Constraint: range_check_builtin/value.
+    let value = (range_check_builtin_value7_0 - column_row!(mask_values, 1, 139))
+        .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[79] * value;
+
+    // Constraint: range_check_builtin/addr_step.
+    let value = (column_row!(mask_values, 1, 394) - (column_row!(mask_values, 1, 138) + FELT_1))
+        * domains[29].field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[80] * value;
+
+    // Constraint: range_check_builtin/init_addr.
+    let value = (column_row!(mask_values, 1, 138) - global_values.initial_range_check_addr)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[81] * value;
+
+    // Constraint: bitwise/init_var_pool_addr.
+    let value = (column_row!(mask_values, 1, 42) - global_values.initial_bitwise_addr)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[82] * value;
+
+    // Constraint: bitwise/step_var_pool_addr.
+    let value = (column_row!(mask_values, 1, 106) - (column_row!(mask_values, 1, 42) + FELT_1))
+        * domains[10].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[83] * value;
+
+    // Constraint: bitwise/x_or_y_addr.
+    let value = (column_row!(mask_values, 1, 74) - (column_row!(mask_values, 1, 234) + FELT_1))
+        .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[84] * value;
+
+    // Constraint: bitwise/next_var_pool_addr.
+    let value = (column_row!(mask_values, 1, 298) - (column_row!(mask_values, 1, 74) + FELT_1))
+        * domains[29].field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[85] * value;
+
+    // Constraint: bitwise/partition.
+    let value = (bitwise_sum_var_0_0 + bitwise_sum_var_8_0 - column_row!(mask_values, 1, 43))
+        .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[86] * value;
+
+    // Constraint: bitwise/or_is_and_plus_xor.
+    let value = (column_row!(mask_values, 1, 75)
+        - (column_row!(mask_values, 1, 171) + column_row!(mask_values, 1, 235)))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[87] * value;
+
+    // Constraint: bitwise/addition_is_xor_with_and.
+    let value = (column_row!(mask_values, 3, 0) + column_row!(mask_values, 3, 64)
+        - (column_row!(mask_values, 3, 192)
+            + column_row!(mask_values, 3, 128)
+            + column_row!(mask_values, 3, 128)))
+    .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[88] * value;
+
+    // Constraint: bitwise/unique_unpacking192.
+    let value = ((column_row!(mask_values, 3, 176) + column_row!(mask_values, 3, 240)) * FELT_16
+        - column_row!(mask_values, 3, 2))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[89] * value;
+
+    // Constraint: bitwise/unique_unpacking193.
+    let value = ((column_row!(mask_values, 3, 180) + column_row!(mask_values, 3, 244)) * FELT_16
+        - column_row!(mask_values, 3, 130))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[90] * value;
+
+    // Constraint: bitwise/unique_unpacking194.
+    let value = ((column_row!(mask_values, 3, 184) + column_row!(mask_values, 3, 248)) * FELT_16
+        - column_row!(mask_values, 3, 66))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[91] * value;
+
+    // Constraint: bitwise/unique_unpacking195.
+    let value = ((column_row!(mask_values, 3, 188) + column_row!(mask_values, 3, 252)) * FELT_256
+        - column_row!(mask_values, 3, 194))
+    .field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[92] * value;
+
+    // Constraint: poseidon/param_0/init_input_output_addr.
+    let value = (column_row!(mask_values, 1, 266) - global_values.initial_poseidon_addr)
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[93] * value;
+
+    // Constraint: poseidon/param_0/addr_input_output_step.
+    let value = (column_row!(mask_values, 1, 778) - (column_row!(mask_values, 1, 266) + FELT_3))
+        * domains[30].field_div(&felt_nonzero!(domains[12]));
+    total_sum += constraint_coefficients[94] * value;
+
+    // Constraint: poseidon/param_1/init_input_output_addr.
+    let value = (column_row!(mask_values, 1, 202) - (global_values.initial_poseidon_addr + FELT_1))
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[95] * value;
+
+    // Constraint: poseidon/param_1/addr_input_output_step.
+    let value = (column_row!(mask_values, 1, 714) - (column_row!(mask_values, 1, 202) + FELT_3))
+        * domains[30].field_div(&felt_nonzero!(domains[12]));
+    total_sum += constraint_coefficients[96] * value;
+
+    // Constraint: poseidon/param_2/init_input_output_addr.
+    let value = (column_row!(mask_values, 1, 458) - (global_values.initial_poseidon_addr + FELT_2))
+        .field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[97] * value;
+
+    // Constraint: poseidon/param_2/addr_input_output_step.
+    let value = (column_row!(mask_values, 1, 970) - (column_row!(mask_values, 1, 458) + FELT_3))
+        * domains[30].field_div(&felt_nonzero!(domains[12]));
+    total_sum += constraint_coefficients[98] * value;
+
+    // Constraint: poseidon/poseidon/full_rounds_state0_squaring.
+    let value = (column_row!(mask_values, 5, 9) * column_row!(mask_values, 5, 9)
+        - column_row!(mask_values, 5, 105))
+    .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[99] * value;
+
+    // Constraint: poseidon/poseidon/full_rounds_state1_squaring.
+    let value = (column_row!(mask_values, 5, 73) * column_row!(mask_values, 5, 73)
+        - column_row!(mask_values, 5, 25))
+    .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[100] * value;
+
+    // Constraint: poseidon/poseidon/full_rounds_state2_squaring.
+    let value = (column_row!(mask_values, 5, 41) * column_row!(mask_values, 5, 41)
+        - column_row!(mask_values, 5, 89))
+    .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[101] * value;
+
+    // Constraint: poseidon/poseidon/partial_rounds_state0_squaring.
+    let value = (column_row!(mask_values, 5, 6) * column_row!(mask_values, 5, 6)
+        - column_row!(mask_values, 5, 14))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[102] * value;
+
+    // Constraint: poseidon/poseidon/partial_rounds_state1_squaring.
+    let value = (column_row!(mask_values, 5, 1) * column_row!(mask_values, 5, 1)
+        - column_row!(mask_values, 5, 17))
+        * domains[15].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[103] * value;
+
+    // Constraint: poseidon/poseidon/add_first_round_key0.
+    let value = (column_row!(mask_values, 1, 267)
+        + FELT_2950795762459345168613727575620414179244544320470208355568817838579231751791
+        - column_row!(mask_values, 5, 9))
+    .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[104] * value;
+
+    // Constraint: poseidon/poseidon/add_first_round_key1.
+    let value = (column_row!(mask_values, 1, 203)
+        + FELT_1587446564224215276866294500450702039420286416111469274423465069420553242820
+        - column_row!(mask_values, 5, 73))
+    .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[105] * value;
+
+    // Constraint: poseidon/poseidon/add_first_round_key2.
+    let value = (column_row!(mask_values, 1, 459)
+        + FELT_1645965921169490687904413452218868659025437693527479459426157555728339600137
+        - column_row!(mask_values, 5, 41))
+    .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[106] * value;
+
+    // Constraint: poseidon/poseidon/full_round0.
+    let value = (column_row!(mask_values, 5, 137)
+        - (poseidon_poseidon_full_rounds_state0_cubed_0
+            + poseidon_poseidon_full_rounds_state0_cubed_0
+            + poseidon_poseidon_full_rounds_state0_cubed_0
+            + poseidon_poseidon_full_rounds_state1_cubed_0
+            + poseidon_poseidon_full_rounds_state2_cubed_0
+            + global_values.poseidon_poseidon_full_round_key0))
+        * domains[13].field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[107] * value;
+
+    // Constraint: poseidon/poseidon/full_round1.
+    let value = (column_row!(mask_values, 5, 201) + poseidon_poseidon_full_rounds_state1_cubed_0
+        - (poseidon_poseidon_full_rounds_state0_cubed_0
+            + poseidon_poseidon_full_rounds_state2_cubed_0
+            + global_values.poseidon_poseidon_full_round_key1))
+        * domains[13].field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[108] * value;
+
+    // Constraint: poseidon/poseidon/full_round2.
+    let value = (column_row!(mask_values, 5, 169)
+        + poseidon_poseidon_full_rounds_state2_cubed_0
+        + poseidon_poseidon_full_rounds_state2_cubed_0
+        - (poseidon_poseidon_full_rounds_state0_cubed_0
+            + poseidon_poseidon_full_rounds_state1_cubed_0
+            + global_values.poseidon_poseidon_full_round_key2))
+        * domains[13].field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[109] * value;
+
+    // Constraint: poseidon/poseidon/last_full_round0.
+    let value = (column_row!(mask_values, 1, 779)
+        - (poseidon_poseidon_full_rounds_state0_cubed_7
+            + poseidon_poseidon_full_rounds_state0_cubed_7
+            + poseidon_poseidon_full_rounds_state0_cubed_7
+            + poseidon_poseidon_full_rounds_state1_cubed_7
+            + poseidon_poseidon_full_rounds_state2_cubed_7))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[110] * value;
+
+    // Constraint: poseidon/poseidon/last_full_round1.
+    let value = (column_row!(mask_values, 1, 715) + poseidon_poseidon_full_rounds_state1_cubed_7
+        - (poseidon_poseidon_full_rounds_state0_cubed_7
+            + poseidon_poseidon_full_rounds_state2_cubed_7))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[111] * value;
+
+    // Constraint: poseidon/poseidon/last_full_round2.
+    let value = (column_row!(mask_values, 1, 971)
+        + poseidon_poseidon_full_rounds_state2_cubed_7
+        + poseidon_poseidon_full_rounds_state2_cubed_7
+        - (poseidon_poseidon_full_rounds_state0_cubed_7
+            + poseidon_poseidon_full_rounds_state1_cubed_7))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[112] * value;
+
+    // Constraint: poseidon/poseidon/copy_partial_rounds0_i0.
+    let value = (column_row!(mask_values, 5, 982) - column_row!(mask_values, 5, 1))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[113] * value;
+
+    // Constraint: poseidon/poseidon/copy_partial_rounds0_i1.
+    let value = (column_row!(mask_values, 5, 998) - column_row!(mask_values, 5, 33))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[114] * value;
+
+    // Constraint: poseidon/poseidon/copy_partial_rounds0_i2.
+    let value = (column_row!(mask_values, 5, 1014) - column_row!(mask_values, 5, 65))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[115] * value;
+
+    // Constraint: poseidon/poseidon/margin_full_to_partial0.
+    let value = (column_row!(mask_values, 5, 6)
+        + poseidon_poseidon_full_rounds_state2_cubed_3
+        + poseidon_poseidon_full_rounds_state2_cubed_3
+        - (poseidon_poseidon_full_rounds_state0_cubed_3
+            + poseidon_poseidon_full_rounds_state1_cubed_3
+            + FELT_2121140748740143694053732746913428481442990369183417228688865837805149503386))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[116] * value;
+
+    // Constraint: poseidon/poseidon/margin_full_to_partial1.
+    let value = (column_row!(mask_values, 5, 22)
+        - (FELT_3618502788666131213697322783095070105623107215331596699973092056135872020477
+            * poseidon_poseidon_full_rounds_state1_cubed_3
+            + FELT_10 * poseidon_poseidon_full_rounds_state2_cubed_3
+            + FELT_4 * column_row!(mask_values, 5, 6)
+            + FELT_3618502788666131213697322783095070105623107215331596699973092056135872020479
+                * poseidon_poseidon_partial_rounds_state0_cubed_0
+            + FELT_2006642341318481906727563724340978325665491359415674592697055778067937914672))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[117] * value;
+
+    // Constraint: poseidon/poseidon/margin_full_to_partial2.
+    let value = (column_row!(mask_values, 5, 38)
+        - (FELT_8 * poseidon_poseidon_full_rounds_state2_cubed_3
+            + FELT_4 * column_row!(mask_values, 5, 6)
+            + FELT_6 * poseidon_poseidon_partial_rounds_state0_cubed_0
+            + column_row!(mask_values, 5, 22)
+            + column_row!(mask_values, 5, 22)
+            + FELT_3618502788666131213697322783095070105623107215331596699973092056135872020479
+                * poseidon_poseidon_partial_rounds_state0_cubed_1
+            + FELT_427751140904099001132521606468025610873158555767197326325930641757709538586))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[118] * value;
+
+    // Constraint: poseidon/poseidon/partial_round0.
+    let value = (column_row!(mask_values, 5, 54)
+        - (FELT_8 * poseidon_poseidon_partial_rounds_state0_cubed_0
+            + FELT_4 * column_row!(mask_values, 5, 22)
+            + FELT_6 * poseidon_poseidon_partial_rounds_state0_cubed_1
+            + column_row!(mask_values, 5, 38)
+            + column_row!(mask_values, 5, 38)
+            + FELT_3618502788666131213697322783095070105623107215331596699973092056135872020479
+                * poseidon_poseidon_partial_rounds_state0_cubed_2
+            + global_values.poseidon_poseidon_partial_round_key0))
+        * domains[17].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[119] * value;
+
+    // Constraint: poseidon/poseidon/partial_round1.
+    let value = (column_row!(mask_values, 5, 97)
+        - (FELT_8 * poseidon_poseidon_partial_rounds_state1_cubed_0
+            + FELT_4 * column_row!(mask_values, 5, 33)
+            + FELT_6 * poseidon_poseidon_partial_rounds_state1_cubed_1
+            + column_row!(mask_values, 5, 65)
+            + column_row!(mask_values, 5, 65)
+            + FELT_3618502788666131213697322783095070105623107215331596699973092056135872020479
+                * poseidon_poseidon_partial_rounds_state1_cubed_2
+            + global_values.poseidon_poseidon_partial_round_key1))
+        * domains[18].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[120] * value;
+
+    // Constraint: poseidon/poseidon/margin_partial_to_full0.
+    let value = (column_row!(mask_values, 5, 521)
+        - (FELT_16 * poseidon_poseidon_partial_rounds_state1_cubed_19
+            + FELT_8 * column_row!(mask_values, 5, 641)
+            + FELT_16 * poseidon_poseidon_partial_rounds_state1_cubed_20
+            + FELT_6 * column_row!(mask_values, 5, 673)
+            + poseidon_poseidon_partial_rounds_state1_cubed_21
+            + FELT_560279373700919169769089400651532183647886248799764942664266404650165812023))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[121] * value;
+
+    // Constraint: poseidon/poseidon/margin_partial_to_full1.
+    let value = (column_row!(mask_values, 5, 585)
+        - (FELT_4 * poseidon_poseidon_partial_rounds_state1_cubed_20
+            + column_row!(mask_values, 5, 673)
+            + column_row!(mask_values, 5, 673)
+            + poseidon_poseidon_partial_rounds_state1_cubed_21
+            + FELT_1401754474293352309994371631695783042590401941592571735921592823982231996415))
+        .field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[122] * value;
+
+    // Constraint: poseidon/poseidon/margin_partial_to_full2.
+    let value = (column_row!(mask_values, 5, 553)
+        - (FELT_8 * poseidon_poseidon_partial_rounds_state1_cubed_19
+            + FELT_4 * column_row!(mask_values, 5, 641)
+            + FELT_6 * poseidon_poseidon_partial_rounds_state1_cubed_20
+            + column_row!(mask_values, 5, 673)
+            + column_row!(mask_values, 5, 673)
+            + FELT_3618502788666131213697322783095070105623107215331596699973092056135872020479
+                * poseidon_poseidon_partial_rounds_state1_cubed_21
+            + FELT_1246177936547655338400308396717835700699368047388302793172818304164989556526))
+        .field_div(&felt_nonzero!(domains[16]));
+
+    total_sum += constraint_coefficients[123] * value;
+
+    total_sum
+}
+
+// Powers of the trace generator at the row offsets of the mask.
+pub fn eval_oods_powers(trace_generator: Felt, autogenerated_pows: &mut [Felt]) {
+    autogenerated_pows[0] = trace_generator.pow_felt(&(FELT_0));
+    autogenerated_pows[1] = trace_generator.pow_felt(&(FELT_4089));
+    autogenerated_pows[2] = trace_generator.pow_felt(&(FELT_2011));
+    autogenerated_pows[3] = trace_generator.pow_felt(&(FELT_1539));
+    autogenerated_pows[4] = trace_generator.pow_felt(&(FELT_1));
+    autogenerated_pows[5] = autogenerated_pows[4] * autogenerated_pows[4]; // pow(trace_generator, 2).
+    autogenerated_pows[6] = autogenerated_pows[4] * autogenerated_pows[5]; // pow(trace_generator, 3).
+    autogenerated_pows[7] = autogenerated_pows[4] * autogenerated_pows[6]; // pow(trace_generator, 4).
+    autogenerated_pows[8] = autogenerated_pows[4] * autogenerated_pows[7]; // pow(trace_generator, 5).
+    autogenerated_pows[9] = autogenerated_pows[4] * autogenerated_pows[8]; // pow(trace_generator, 6).
+    autogenerated_pows[10] = autogenerated_pows[4] * autogenerated_pows[9]; // pow(trace_generator, 7).
+    autogenerated_pows[11] = autogenerated_pows[4] * autogenerated_pows[10]; // pow(trace_generator, 8).
+    autogenerated_pows[12] = autogenerated_pows[3] * autogenerated_pows[11]; // pow(trace_generator, 1547).
+    autogenerated_pows[13] = autogenerated_pows[4] * autogenerated_pows[11]; // pow(trace_generator, 9).
+    autogenerated_pows[14] = autogenerated_pows[4] * autogenerated_pows[13]; // pow(trace_generator, 10).
+    autogenerated_pows[15] = autogenerated_pows[4] * autogenerated_pows[14]; // pow(trace_generator, 11).
+    autogenerated_pows[16] = autogenerated_pows[4] * autogenerated_pows[15]; // pow(trace_generator, 12).
+    autogenerated_pows[17] = autogenerated_pows[4] * autogenerated_pows[16]; // pow(trace_generator, 13).
+    autogenerated_pows[18] = autogenerated_pows[4] * autogenerated_pows[17]; // pow(trace_generator, 14).
+    autogenerated_pows[19] = autogenerated_pows[4] * autogenerated_pows[18]; // pow(trace_generator, 15).
+    autogenerated_pows[20] = autogenerated_pows[4] * autogenerated_pows[19]; // pow(trace_generator, 16).
+    autogenerated_pows[21] = autogenerated_pows[4] * autogenerated_pows[20]; // pow(trace_generator, 17).
+    autogenerated_pows[22] = autogenerated_pows[6] * autogenerated_pows[21]; // pow(trace_generator, 20).
+    autogenerated_pows[23] = autogenerated_pows[5] * autogenerated_pows[22]; // pow(trace_generator, 22).
+    autogenerated_pows[24] = autogenerated_pows[5] * autogenerated_pows[23]; // pow(trace_generator, 24).
+    autogenerated_pows[25] = autogenerated_pows[4] * autogenerated_pows[24]; // pow(trace_generator, 25).
+    autogenerated_pows[26] = autogenerated_pows[6] * autogenerated_pows[25]; // pow(trace_generator, 28).
+    autogenerated_pows[27] = autogenerated_pows[5] * autogenerated_pows[26]; // pow(trace_generator, 30).
+    autogenerated_pows[28] = autogenerated_pows[5] * autogenerated_pows[27]; // pow(trace_generator, 32).
+    autogenerated_pows[29] = autogenerated_pows[4] * autogenerated_pows[28]; // pow(trace_generator, 33).
+    autogenerated_pows[30] = autogenerated_pows[3] * autogenerated_pows[28]; // pow(trace_generator, 1571).
+    autogenerated_pows[31] = autogenerated_pows[6] * autogenerated_pows[29]; // pow(trace_generator, 36).
+    autogenerated_pows[32] = autogenerated_pows[5] * autogenerated_pows[31]; // pow(trace_generator, 38).
+    autogenerated_pows[33] = autogenerated_pows[5] * autogenerated_pows[32]; // pow(trace_generator, 40).
+    autogenerated_pows[34] = autogenerated_pows[4] * autogenerated_pows[33]; // pow(trace_generator, 41).
+    autogenerated_pows[35] = autogenerated_pows[4] * autogenerated_pows[34]; // pow(trace_generator, 42).
+    autogenerated_pows[36] = autogenerated_pows[4] * autogenerated_pows[35]; // pow(trace_generator, 43).
+    autogenerated_pows[37] = autogenerated_pows[4] * autogenerated_pows[36]; // pow(trace_generator, 44).
+    autogenerated_pows[38] = autogenerated_pows[5] * autogenerated_pows[37]; // pow(trace_generator, 46).
+    autogenerated_pows[39] = autogenerated_pows[5] * autogenerated_pows[38]; // pow(trace_generator, 48).
+    autogenerated_pows[40] = autogenerated_pows[4] * autogenerated_pows[39]; // pow(trace_generator, 49).
+    autogenerated_pows[41] = autogenerated_pows[6] * autogenerated_pows[40]; // pow(trace_generator, 52).
+    autogenerated_pows[42] = autogenerated_pows[5] * autogenerated_pows[41]; // pow(trace_generator, 54).
+    autogenerated_pows[43] = autogenerated_pows[5] * autogenerated_pows[42]; // pow(trace_generator, 56).
+    autogenerated_pows[44] = autogenerated_pows[4] * autogenerated_pows[43]; // pow(trace_generator, 57).
+    autogenerated_pows[45] = autogenerated_pows[6] * autogenerated_pows[44]; // pow(trace_generator, 60).
+    autogenerated_pows[46] = autogenerated_pows[7] * autogenerated_pows[45]; // pow(trace_generator, 64).
+    autogenerated_pows[47] = autogenerated_pows[4] * autogenerated_pows[46]; // pow(trace_generator, 65).
+    autogenerated_pows[48] = autogenerated_pows[4] * autogenerated_pows[47]; // pow(trace_generator, 66).
+    autogenerated_pows[49] = autogenerated_pows[10] * autogenerated_pows[48]; // pow(trace_generator, 73).
+    autogenerated_pows[50] = autogenerated_pows[4] * autogenerated_pows[49]; // pow(trace_generator, 74).
+    autogenerated_pows[51] = autogenerated_pows[4] * autogenerated_pows[50]; // pow(trace_generator, 75).
+    autogenerated_pows[52] = autogenerated_pows[4] * autogenerated_pows[51]; // pow(trace_generator, 76).
+    autogenerated_pows[53] = autogenerated_pows[8] * autogenerated_pows[52]; // pow(trace_generator, 81).
+    autogenerated_pows[54] = autogenerated_pows[11] * autogenerated_pows[53]; // pow(trace_generator, 89).
+    autogenerated_pows[55] = autogenerated_pows[11] * autogenerated_pows[54]; // pow(trace_generator, 97).
+    autogenerated_pows[56] = autogenerated_pows[11] * autogenerated_pows[55]; // pow(trace_generator, 105).
+    autogenerated_pows[57] = autogenerated_pows[4] * autogenerated_pows[56]; // pow(trace_generator, 106).
+    autogenerated_pows[58] = autogenerated_pows[5] * autogenerated_pows[57]; // pow(trace_generator, 108).
+    autogenerated_pows[59] = autogenerated_pows[22] * autogenerated_pows[58]; // pow(trace_generator, 128).
+    autogenerated_pows[60] = autogenerated_pows[5] * autogenerated_pows[59]; // pow(trace_generator, 130).
+    autogenerated_pows[61] = autogenerated_pows[10] * autogenerated_pows[60]; // pow(trace_generator, 137).
+    autogenerated_pows[62] = autogenerated_pows[4] * autogenerated_pows[61]; // pow(trace_generator, 138).
+    autogenerated_pows[63] = autogenerated_pows[4] * autogenerated_pows[62]; // pow(trace_generator, 139).
+    autogenerated_pows[64] = autogenerated_pows[27] * autogenerated_pows[63]; // pow(trace_generator, 169).
+    autogenerated_pows[65] = autogenerated_pows[5] * autogenerated_pows[64]; // pow(trace_generator, 171).
+    autogenerated_pows[66] = autogenerated_pows[4] * autogenerated_pows[63]; // pow(trace_generator, 140).
+    autogenerated_pows[67] = autogenerated_pows[4] * autogenerated_pows[65]; // pow(trace_generator, 172).
+    autogenerated_pows[68] = autogenerated_pows[7] * autogenerated_pows[67]; // pow(trace_generator, 176).
+    autogenerated_pows[69] = autogenerated_pows[7] * autogenerated_pows[68]; // pow(trace_generator, 180).
+    autogenerated_pows[70] = autogenerated_pows[7] * autogenerated_pows[69]; // pow(trace_generator, 184).
+    autogenerated_pows[71] = autogenerated_pows[7] * autogenerated_pows[70]; // pow(trace_generator, 188).
+    autogenerated_pows[72] = autogenerated_pows[7] * autogenerated_pows[71]; // pow(trace_generator, 192).
+    autogenerated_pows[73] = autogenerated_pows[5] * autogenerated_pows[72]; // pow(trace_generator, 194).
+    autogenerated_pows[74] = autogenerated_pows[10] * autogenerated_pows[73]; // pow(trace_generator, 201).
+    autogenerated_pows[75] = autogenerated_pows[4] * autogenerated_pows[74]; // pow(trace_generator, 202).
+    autogenerated_pows[76] = autogenerated_pows[4] * autogenerated_pows[75]; // pow(trace_generator, 203).
+    autogenerated_pows[77] = autogenerated_pows[72] * autogenerated_pows[74]; // pow(trace_generator, 393).
+    autogenerated_pows[78] = autogenerated_pows[4] * autogenerated_pows[76]; // pow(trace_generator, 204).
+    autogenerated_pows[79] = autogenerated_pows[27] * autogenerated_pows[78]; // pow(trace_generator, 234).
+    autogenerated_pows[80] = autogenerated_pows[4] * autogenerated_pows[79]; // pow(trace_generator, 235).
+    autogenerated_pows[81] = autogenerated_pows[4] * autogenerated_pows[80]; // pow(trace_generator, 236).
+    autogenerated_pows[82] = autogenerated_pows[7] * autogenerated_pows[81]; // pow(trace_generator, 240).
+    autogenerated_pows[83] = autogenerated_pows[7] * autogenerated_pows[82]; // pow(trace_generator, 244).
+    autogenerated_pows[84] = autogenerated_pows[7] * autogenerated_pows[83]; // pow(trace_generator, 248).
+    autogenerated_pows[85] = autogenerated_pows[7] * autogenerated_pows[84]; // pow(trace_generator, 252).
+    autogenerated_pows[86] = autogenerated_pows[18] * autogenerated_pows[85]; // pow(trace_generator, 266).
+    autogenerated_pows[87] = autogenerated_pows[4] * autogenerated_pows[86]; // pow(trace_generator, 267).
+    autogenerated_pows[88] = autogenerated_pows[4] * autogenerated_pows[77]; // pow(trace_generator, 394).
+    autogenerated_pows[89] = autogenerated_pows[19] * autogenerated_pows[88]; // pow(trace_generator, 409).
+    autogenerated_pows[90] = autogenerated_pows[20] * autogenerated_pows[89]; // pow(trace_generator, 425).
+    autogenerated_pows[91] = autogenerated_pows[28] * autogenerated_pows[90]; // pow(trace_generator, 457).
+    autogenerated_pows[92] = autogenerated_pows[4] * autogenerated_pows[91]; // pow(trace_generator, 458).
+    autogenerated_pows[93] = autogenerated_pows[4] * autogenerated_pows[92]; // pow(trace_generator, 459).
+    autogenerated_pows[94] = autogenerated_pows[18] * autogenerated_pows[93]; // pow(trace_generator, 473).
+    autogenerated_pows[95] = autogenerated_pows[20] * autogenerated_pows[94]; // pow(trace_generator, 489).
+    autogenerated_pows[96] = autogenerated_pows[28] * autogenerated_pows[95]; // pow(trace_generator, 521).
+    autogenerated_pows[97] = autogenerated_pows[28] * autogenerated_pows[96]; // pow(trace_generator, 553).
+    autogenerated_pows[98] = autogenerated_pows[28] * autogenerated_pows[97]; // pow(trace_generator, 585).
+    autogenerated_pows[99] = autogenerated_pows[24] * autogenerated_pows[98]; // pow(trace_generator, 609).
+    autogenerated_pows[100] = autogenerated_pows[20] * autogenerated_pows[99]; // pow(trace_generator, 625).
+    autogenerated_pows[101] = autogenerated_pows[20] * autogenerated_pows[100]; // pow(trace_generator, 641).
+    autogenerated_pows[102] = autogenerated_pows[20] * autogenerated_pows[101]; // pow(trace_generator, 657).
+    autogenerated_pows[103] = autogenerated_pows[84] * autogenerated_pows[102]; // pow(trace_generator, 905).
+    autogenerated_pows[104] = autogenerated_pows[20] * autogenerated_pows[102]; // pow(trace_generator, 673).
+    autogenerated_pows[105] = autogenerated_pows[20] * autogenerated_pows[103]; // pow(trace_generator, 921).
+    autogenerated_pows[106] = autogenerated_pows[20] * autogenerated_pows[104]; // pow(trace_generator, 689).
+    autogenerated_pows[107] = autogenerated_pows[20] * autogenerated_pows[105]; // pow(trace_generator, 937).
+    autogenerated_pows[108] = autogenerated_pows[28] * autogenerated_pows[107]; // pow(trace_generator, 969).
+    autogenerated_pows[109] = autogenerated_pows[25] * autogenerated_pows[106]; // pow(trace_generator, 714).
+    autogenerated_pows[110] = autogenerated_pows[46] * autogenerated_pows[109]; // pow(trace_generator, 778).
+    autogenerated_pows[111] = autogenerated_pows[4] * autogenerated_pows[108]; // pow(trace_generator, 970).
+    autogenerated_pows[112] = autogenerated_pows[3] * autogenerated_pows[33]; // pow(trace_generator, 1579).
+    autogenerated_pows[113] = autogenerated_pows[4] * autogenerated_pows[109]; // pow(trace_generator, 715).
+    autogenerated_pows[114] = autogenerated_pows[4] * autogenerated_pows[110]; // pow(trace_generator, 779).
+    autogenerated_pows[115] = autogenerated_pows[28] * autogenerated_pows[86]; // pow(trace_generator, 298).
+    autogenerated_pows[116] = autogenerated_pows[4] * autogenerated_pows[111]; // pow(trace_generator, 971).
+    autogenerated_pows[117] = autogenerated_pows[15] * autogenerated_pows[116]; // pow(trace_generator, 982).
+    autogenerated_pows[118] = autogenerated_pows[6] * autogenerated_pows[117]; // pow(trace_generator, 985).
+    autogenerated_pows[119] = autogenerated_pows[17] * autogenerated_pows[118]; // pow(trace_generator, 998).
+    autogenerated_pows[120] = autogenerated_pows[6] * autogenerated_pows[119]; // pow(trace_generator, 1001).
+    autogenerated_pows[121] = autogenerated_pows[17] * autogenerated_pows[120]; // pow(trace_generator, 1014).
+    autogenerated_pows[122] = autogenerated_pows[22] * autogenerated_pows[121]; // pow(trace_generator, 1034).
+    autogenerated_pows[123] = autogenerated_pows[2] * autogenerated_pows[11]; // pow(trace_generator, 2019).
+    autogenerated_pows[124] = autogenerated_pows[2] * autogenerated_pows[27]; // pow(trace_generator, 2041).
+    autogenerated_pows[125] = autogenerated_pows[7] * autogenerated_pows[124]; // pow(trace_generator, 2045).
+    autogenerated_pows[126] = autogenerated_pows[2] * autogenerated_pows[31]; // pow(trace_generator, 2047).
+    autogenerated_pows[127] = autogenerated_pows[4] * autogenerated_pows[122]; // pow(trace_generator, 1035).
+    autogenerated_pows[128] = autogenerated_pows[2] * autogenerated_pows[32]; // pow(trace_generator, 2049).
+    autogenerated_pows[129] = autogenerated_pows[2] * autogenerated_pows[33]; // pow(trace_generator, 2051).
+    autogenerated_pows[130] = autogenerated_pows[2] * autogenerated_pows[35]; // pow(trace_generator, 2053).
+    autogenerated_pows[131] = autogenerated_pows[8] * autogenerated_pows[130]; // pow(trace_generator, 2058).
+    autogenerated_pows[132] = autogenerated_pows[2] * autogenerated_pows[39]; // pow(trace_generator, 2059).
+    autogenerated_pows[133] = autogenerated_pows[1] * autogenerated_pows[21]; // pow(trace_generator, 4106).
+}
+
+pub fn eval_oods_constraints(
+    column_values: &[Felt],
+    oods_values: &[Felt],
+    constraint_coefficients: &[Felt],
+    autogenerated_pows: &[Felt],
+    point: Felt,
+    oods_point: Felt,
+) -> Felt {
+    let column0 = column_values[0];
+    let column1 = column_values[1];
+    let column2 = column_values[2];
+    let column3 = column_values[3];
+    let column4 = column_values[4];
+    let column5 = column_values[5];
+    let column6 = column_values[6];
+    let column7 = column_values[7];
+
+    // Sum the OODS constraints on the trace polynomials.
+    let mut total_sum = FELT_0;
+
+    let value = (column0 - oods_values[0])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[0] * value;
+
+    let value = (column0 - oods_values[1])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[1] * value;
+
+    let value = (column0 - oods_values[2])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[2] * value;
+
+    let value = (column0 - oods_values[3])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[3] * value;
+
+    let value = (column0 - oods_values[4])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[4] * value;
+
+    let value = (column0 - oods_values[5])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[5] * value;
+
+    let value = (column0 - oods_values[6])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[9] * oods_point));
+    total_sum += constraint_coefficients[6] * value;
+
+    let value = (column0 - oods_values[7])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[10] * oods_point));
+    total_sum += constraint_coefficients[7] * value;
+
+    let value = (column0 - oods_values[8])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[8] * value;
+
+    let value = (column0 - oods_values[9])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[13] * oods_point));
+    total_sum += constraint_coefficients[9] * value;
+
+    let value = (column0 - oods_values[10])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[10] * value;
+
+    let value = (column0 - oods_values[11])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[15] * oods_point));
+    total_sum += constraint_coefficients[11] * value;
+
+    let value = (column0 - oods_values[12])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[16] * oods_point));
+    total_sum += constraint_coefficients[12] * value;
+
+    let value = (column0 - oods_values[13])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[13] * value;
+
+    let value = (column0 - oods_values[14])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[14] * value;
+
+    let value = (column0 - oods_values[15])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[19] * oods_point));
+    total_sum += constraint_coefficients[15] * value;
+
+    let value = (column1 - oods_values[16])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[16] * value;
+
+    let value = (column1 - oods_values[17])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[17] * value;
+
+    let value = (column1 - oods_values[18])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[18] * value;
+
+    let value = (column1 - oods_values[19])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[19] * value;
+
+    let value = (column1 - oods_values[20])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[20] * value;
+
+    let value = (column1 - oods_values[21])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[21] * value;
+
+    let value = (column1 - oods_values[22])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[22] * value;
+
+    let value = (column1 - oods_values[23])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[13] * oods_point));
+    total_sum += constraint_coefficients[23] * value;
+
+    let value = (column1 - oods_values[24])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[24] * value;
+
+    let value = (column1 - oods_values[25])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[15] * oods_point));
+    total_sum += constraint_coefficients[25] * value;
+
+    let value = (column1 - oods_values[26])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[16] * oods_point));
+    total_sum += constraint_coefficients[26] * value;
+
+    let value = (column1 - oods_values[27])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[27] * value;
+
+    let value = (column1 - oods_values[28])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[20] * oods_point));
+    total_sum += constraint_coefficients[28] * value;
+
+    let value = (column1 - oods_values[29])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[35] * oods_point));
+    total_sum += constraint_coefficients[29] * value;
+
+    let value = (column1 - oods_values[30])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[36] * oods_point));
+    total_sum += constraint_coefficients[30] * value;
+
+    let value = (column1 - oods_values[31])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[50] * oods_point));
+    total_sum += constraint_coefficients[31] * value;
+
+    let value = (column1 - oods_values[32])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[51] * oods_point));
+    total_sum += constraint_coefficients[32] * value;
+
+    let value = (column1 - oods_values[33])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[57] * oods_point));
+    total_sum += constraint_coefficients[33] * value;
+
+    let value = (column1 - oods_values[34])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[62] * oods_point));
+    total_sum += constraint_coefficients[34] * value;
+
+    let value = (column1 - oods_values[35])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[63] * oods_point));
+    total_sum += constraint_coefficients[35] * value;
+
+    let value = (column1 - oods_values[36])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[65] * oods_point));
+    total_sum += constraint_coefficients[36] * value;
+
+    let value = (column1 - oods_values[37])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[75] * oods_point));
+    total_sum += constraint_coefficients[37] * value;
+
+    let value = (column1 - oods_values[38])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[76] * oods_point));
+    total_sum += constraint_coefficients[38] * value;
+
+    let value = (column1 - oods_values[39])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[79] * oods_point));
+    total_sum += constraint_coefficients[39] * value;
+
+    let value = (column1 - oods_values[40])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[80] * oods_point));
+    total_sum += constraint_coefficients[40] * value;
+
+    let value = (column1 - oods_values[41])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[86] * oods_point));
+    total_sum += constraint_coefficients[41] * value;
+
+    let value = (column1 - oods_values[42])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[87] * oods_point));
+    total_sum += constraint_coefficients[42] * value;
+
+    let value = (column1 - oods_values[43])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[115] * oods_point));
+    total_sum += constraint_coefficients[43] * value;
+
+    let value = (column1 - oods_values[44])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[88] * oods_point));
+    total_sum += constraint_coefficients[44] * value;
+
+    let value = (column1 - oods_values[45])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[92] * oods_point));
+    total_sum += constraint_coefficients[45] * value;
+
+    let value = (column1 - oods_values[46])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[93] * oods_point));
+    total_sum += constraint_coefficients[46] * value;
+
+    let value = (column1 - oods_values[47])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[109] * oods_point));
+    total_sum += constraint_coefficients[47] * value;
+
+    let value = (column1 - oods_values[48])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[113] * oods_point));
+    total_sum += constraint_coefficients[48] * value;
+
+    let value = (column1 - oods_values[49])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[110] * oods_point));
+    total_sum += constraint_coefficients[49] * value;
+
+    let value = (column1 - oods_values[50])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[114] * oods_point));
+    total_sum += constraint_coefficients[50] * value;
+
+    let value = (column1 - oods_values[51])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[111] * oods_point));
+    total_sum += constraint_coefficients[51] * value;
+
+    let value = (column1 - oods_values[52])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[116] * oods_point));
+    total_sum += constraint_coefficients[52] * value;
+
+    let value = (column1 - oods_values[53])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[122] * oods_point));
+    total_sum += constraint_coefficients[53] * value;
+
+    let value = (column1 - oods_values[54])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[127] * oods_point));
+    total_sum += constraint_coefficients[54] * value;
+
+    let value = (column1 - oods_values[55])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[131] * oods_point));
+    total_sum += constraint_coefficients[55] * value;
+
+    let value = (column1 - oods_values[56])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[132] * oods_point));
+    total_sum += constraint_coefficients[56] * value;
+
+    let value = (column1 - oods_values[57])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[133] * oods_point));
+    total_sum += constraint_coefficients[57] * value;
+
+    let value = (column2 - oods_values[58])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[58] * value;
+
+    let value = (column2 - oods_values[59])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[59] * value;
+
+    let value = (column2 - oods_values[60])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[60] * value;
+
+    let value = (column2 - oods_values[61])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[61] * value;
+
+    let value = (column3 - oods_values[62])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[62] * value;
+
+    let value = (column3 - oods_values[63])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[63] * value;
+
+    let value = (column3 - oods_values[64])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[64] * value;
+
+    let value = (column3 - oods_values[65])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[65] * value;
+
+    let value = (column3 - oods_values[66])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[66] * value;
+
+    let value = (column3 - oods_values[67])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[67] * value;
+
+    let value = (column3 - oods_values[68])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[16] * oods_point));
+    total_sum += constraint_coefficients[68] * value;
+
+    let value = (column3 - oods_values[69])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[20] * oods_point));
+    total_sum += constraint_coefficients[69] * value;
+
+    let value = (column3 - oods_values[70])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[22] * oods_point));
+    total_sum += constraint_coefficients[70] * value;
+
+    let value = (column3 - oods_values[71])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[24] * oods_point));
+    total_sum += constraint_coefficients[71] * value;
+
+    let value = (column3 - oods_values[72])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[26] * oods_point));
+    total_sum += constraint_coefficients[72] * value;
+
+    let value = (column3 - oods_values[73])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[28] * oods_point));
+    total_sum += constraint_coefficients[73] * value;
+
+    let value = (column3 - oods_values[74])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[31] * oods_point));
+    total_sum += constraint_coefficients[74] * value;
+
+    let value = (column3 - oods_values[75])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[33] * oods_point));
+    total_sum += constraint_coefficients[75] * value;
+
+    let value = (column3 - oods_values[76])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[37] * oods_point));
+    total_sum += constraint_coefficients[76] * value;
+
+    let value = (column3 - oods_values[77])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[39] * oods_point));
+    total_sum += constraint_coefficients[77] * value;
+
+    let value = (column3 - oods_values[78])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[41] * oods_point));
+    total_sum += constraint_coefficients[78] * value;
+
+    let value = (column3 - oods_values[79])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[43] * oods_point));
+    total_sum += constraint_coefficients[79] * value;
+
+    let value = (column3 - oods_values[80])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[45] * oods_point));
+    total_sum += constraint_coefficients[80] * value;
+
+    let value = (column3 - oods_values[81])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[46] * oods_point));
+    total_sum += constraint_coefficients[81] * value;
+
+    let value = (column3 - oods_values[82])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[48] * oods_point));
+    total_sum += constraint_coefficients[82] * value;
+
+    let value = (column3 - oods_values[83])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[59] * oods_point));
+    total_sum += constraint_coefficients[83] * value;
+
+    let value = (column3 - oods_values[84])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[60] * oods_point));
+    total_sum += constraint_coefficients[84] * value;
+
+    let value = (column3 - oods_values[85])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[68] * oods_point));
+    total_sum += constraint_coefficients[85] * value;
+
+    let value = (column3 - oods_values[86])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[69] * oods_point));
+    total_sum += constraint_coefficients[86] * value;
+
+    let value = (column3 - oods_values[87])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[70] * oods_point));
+    total_sum += constraint_coefficients[87] * value;
+
+    let value = (column3 - oods_values[88])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[71] * oods_point));
+    total_sum += constraint_coefficients[88] * value;
+
+    let value = (column3 - oods_values[89])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[89] * value;
+
+    let value = (column3 - oods_values[90])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[90] * value;
+
+    let value = (column3 - oods_values[91])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[82] * oods_point));
+    total_sum += constraint_coefficients[91] * value;
+
+    let value = (column3 - oods_values[92])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[83] * oods_point));
+    total_sum += constraint_coefficients[92] * value;
+
+    let value = (column3 - oods_values[93])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[84] * oods_point));
+    total_sum += constraint_coefficients[93] * value;
+
+    let value = (column3 - oods_values[94])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[85] * oods_point));
+    total_sum += constraint_coefficients[94] * value;
+
+    let value = (column4 - oods_values[95])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[95] * value;
+
+    let value = (column4 - oods_values[96])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[96] * value;
+
+    let value = (column4 - oods_values[97])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[97] * value;
+
+    let value = (column4 - oods_values[98])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[98] * value;
+
+    let value = (column4 - oods_values[99])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[99] * value;
+
+    let value = (column4 - oods_values[100])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[100] * value;
+
+    let value = (column4 - oods_values[101])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[9] * oods_point));
+    total_sum += constraint_coefficients[101] * value;
+
+    let value = (column4 - oods_values[102])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[10] * oods_point));
+    total_sum += constraint_coefficients[102] * value;
+
+    let value = (column4 - oods_values[103])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[103] * value;
+
+    let value = (column4 - oods_values[104])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[13] * oods_point));
+    total_sum += constraint_coefficients[104] * value;
+
+    let value = (column4 - oods_values[105])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[15] * oods_point));
+    total_sum += constraint_coefficients[105] * value;
+
+    let value = (column4 - oods_values[106])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[16] * oods_point));
+    total_sum += constraint_coefficients[106] * value;
+
+    let value = (column4 - oods_values[107])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[107] * value;
+
+    let value = (column4 - oods_values[108])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[37] * oods_point));
+    total_sum += constraint_coefficients[108] * value;
+
+    let value = (column4 - oods_values[109])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[52] * oods_point));
+    total_sum += constraint_coefficients[109] * value;
+
+    let value = (column4 - oods_values[110])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[58] * oods_point));
+    total_sum += constraint_coefficients[110] * value;
+
+    let value = (column4 - oods_values[111])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[66] * oods_point));
+    total_sum += constraint_coefficients[111] * value;
+
+    let value = (column4 - oods_values[112])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[67] * oods_point));
+    total_sum += constraint_coefficients[112] * value;
+
+    let value = (column4 - oods_values[113])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[78] * oods_point));
+    total_sum += constraint_coefficients[113] * value;
+
+    let value = (column4 - oods_values[114])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[81] * oods_point));
+    total_sum += constraint_coefficients[114] * value;
+
+    let value = (column4 - oods_values[115])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[115] * value;
+
+    let value = (column4 - oods_values[116])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[12] * oods_point));
+    total_sum += constraint_coefficients[116] * value;
+
+    let value = (column4 - oods_values[117])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[30] * oods_point));
+    total_sum += constraint_coefficients[117] * value;
+
+    let value = (column4 - oods_values[118])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[112] * oods_point));
+    total_sum += constraint_coefficients[118] * value;
+
+    let value = (column4 - oods_values[119])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[2] * oods_point));
+    total_sum += constraint_coefficients[119] * value;
+
+    let value = (column4 - oods_values[120])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[123] * oods_point));
+    total_sum += constraint_coefficients[120] * value;
+
+    let value = (column4 - oods_values[121])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[124] * oods_point));
+    total_sum += constraint_coefficients[121] * value;
+
+    let value = (column4 - oods_values[122])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[125] * oods_point));
+    total_sum += constraint_coefficients[122] * value;
+
+    let value = (column4 - oods_values[123])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[126] * oods_point));
+    total_sum += constraint_coefficients[123] * value;
+
+    let value = (column4 - oods_values[124])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[128] * oods_point));
+    total_sum += constraint_coefficients[124] * value;
+
+    let value = (column4 - oods_values[125])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[129] * oods_point));
+    total_sum += constraint_coefficients[125] * value;
+
+    let value = (column4 - oods_values[126])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[130] * oods_point));
+    total_sum += constraint_coefficients[126] * value;
+
+    let value = (column4 - oods_values[127])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[1] * oods_point));
+    total_sum += constraint_coefficients[127] * value;
+
+    let value = (column5 - oods_values[128])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[128] * value;
+
+    let value = (column5 - oods_values[129])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[129] * value;
+
+    let value = (column5 - oods_values[130])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[130] * value;
+
+    let value = (column5 - oods_values[131])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[131] * value;
+
+    let value = (column5 - oods_values[132])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[9] * oods_point));
+    total_sum += constraint_coefficients[132] * value;
+
+    let value = (column5 - oods_values[133])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[133] * value;
+
+    let value = (column5 - oods_values[134])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[13] * oods_point));
+    total_sum += constraint_coefficients[134] * value;
+
+    let value = (column5 - oods_values[135])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[135] * value;
+
+    let value = (column5 - oods_values[136])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[16] * oods_point));
+    total_sum += constraint_coefficients[136] * value;
+
+    let value = (column5 - oods_values[137])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[137] * value;
+
+    let value = (column5 - oods_values[138])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[20] * oods_point));
+    total_sum += constraint_coefficients[138] * value;
+
+    let value = (column5 - oods_values[139])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[21] * oods_point));
+    total_sum += constraint_coefficients[139] * value;
+
+    let value = (column5 - oods_values[140])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[23] * oods_point));
+    total_sum += constraint_coefficients[140] * value;
+
+    let value = (column5 - oods_values[141])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[24] * oods_point));
+    total_sum += constraint_coefficients[141] * value;
+
+    let value = (column5 - oods_values[142])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[25] * oods_point));
+    total_sum += constraint_coefficients[142] * value;
+
+    let value = (column5 - oods_values[143])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[27] * oods_point));
+    total_sum += constraint_coefficients[143] * value;
+
+    let value = (column5 - oods_values[144])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[29] * oods_point));
+    total_sum += constraint_coefficients[144] * value;
+
+    let value = (column5 - oods_values[145])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[32] * oods_point));
+    total_sum += constraint_coefficients[145] * value;
+
+    let value = (column5 - oods_values[146])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[34] * oods_point));
+    total_sum += constraint_coefficients[146] * value;
+
+    let value = (column5 - oods_values[147])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[38] * oods_point));
+    total_sum += constraint_coefficients[147] * value;
+
+    let value = (column5 - oods_values[148])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[40] * oods_point));
+    total_sum += constraint_coefficients[148] * value;
+
+    let value = (column5 - oods_values[149])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[42] * oods_point));
+    total_sum += constraint_coefficients[149] * value;
+
+    let value = (column5 - oods_values[150])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[44] * oods_point));
+    total_sum += constraint_coefficients[150] * value;
+
+    let value = (column5 - oods_values[151])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[47] * oods_point));
+    total_sum += constraint_coefficients[151] * value;
+
+    let value = (column5 - oods_values[152])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[49] * oods_point));
+    total_sum += constraint_coefficients[152] * value;
+
+    let value = (column5 - oods_values[153])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[53] * oods_point));
+    total_sum += constraint_coefficients[153] * value;
+
+    let value = (column5 - oods_values[154])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[54] * oods_point));
+    total_sum += constraint_coefficients[154] * value;
+
+    let value = (column5 - oods_values[155])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[55] * oods_point));
+    total_sum += constraint_coefficients[155] * value;
+
+    let value = (column5 - oods_values[156])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[56] * oods_point));
+    total_sum += constraint_coefficients[156] * value;
+
+    let value = (column5 - oods_values[157])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[61] * oods_point));
+    total_sum += constraint_coefficients[157] * value;
+
+    let value = (column5 - oods_values[158])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[64] * oods_point));
+    total_sum += constraint_coefficients[158] * value;
+
+    let value = (column5 - oods_values[159])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[74] * oods_point));
+    total_sum += constraint_coefficients[159] * value;
+
+    let value = (column5 - oods_values[160])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[77] * oods_point));
+    total_sum += constraint_coefficients[160] * value;
+
+    let value = (column5 - oods_values[161])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[89] * oods_point));
+    total_sum += constraint_coefficients[161] * value;
+
+    let value = (column5 - oods_values[162])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[90] * oods_point));
+    total_sum += constraint_coefficients[162] * value;
+
+    let value = (column5 - oods_values[163])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[91] * oods_point));
+    total_sum += constraint_coefficients[163] * value;
+
+    let value = (column5 - oods_values[164])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[94] * oods_point));
+    total_sum += constraint_coefficients[164] * value;
+
+    let value = (column5 - oods_values[165])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[95] * oods_point));
+    total_sum += constraint_coefficients[165] * value;
+
+    let value = (column5 - oods_values[166])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[96] * oods_point));
+    total_sum += constraint_coefficients[166] * value;
+
+    let value = (column5 - oods_values[167])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[97] * oods_point));
+    total_sum += constraint_coefficients[167] * value;
+
+    let value = (column5 - oods_values[168])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[98] * oods_point));
+    total_sum += constraint_coefficients[168] * value;
+
+    let value = (column5 - oods_values[169])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[99] * oods_point));
+    total_sum += constraint_coefficients[169] * value;
+
+    let value = (column5 - oods_values[170])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[100] * oods_point));
+    total_sum += constraint_coefficients[170] * value;
+
+    let value = (column5 - oods_values[171])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[101] * oods_point));
+    total_sum += constraint_coefficients[171] * value;
+
+    let value = (column5 - oods_values[172])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[102] * oods_point));
+    total_sum += constraint_coefficients[172] * value;
+
+    let value = (column5 - oods_values[173])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[104] * oods_point));
+    total_sum += constraint_coefficients[173] * value;
+
+    let value = (column5 - oods_values[174])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[106] * oods_point));
+    total_sum += constraint_coefficients[174] * value;
+
+    let value = (column5 - oods_values[175])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[103] * oods_point));
+    total_sum += constraint_coefficients[175] * value;
+
+    let value = (column5 - oods_values[176])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[105] * oods_point));
+    total_sum += constraint_coefficients[176] * value;
+
+    let value = (column5 - oods_values[177])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[107] * oods_point));
+    total_sum += constraint_coefficients[177] * value;
+
+    let value = (column5 - oods_values[178])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[108] * oods_point));
+    total_sum += constraint_coefficients[178] * value;
+
+    let value = (column5 - oods_values[179])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[117] * oods_point));
+    total_sum += constraint_coefficients[179] * value;
+
+    let value = (column5 - oods_values[180])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[118] * oods_point));
+    total_sum += constraint_coefficients[180] * value;
+
+    let value = (column5 - oods_values[181])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[119] * oods_point));
+    total_sum += constraint_coefficients[181] * value;
+
+    let value = (column5 - oods_values[182])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[120] * oods_point));
+    total_sum += constraint_coefficients[182] * value;
+
+    let value = (column5 - oods_values[183])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[121] * oods_point));
+    total_sum += constraint_coefficients[183] * value;
+
+    let value = (column6 - oods_values[184])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[184] * value;
+
+    let value = (column6 - oods_values[185])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[185] * value;
+
+    let value = (column6 - oods_values[186])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[186] * value;
+
+    let value = (column6 - oods_values[187])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[187] * value;
+
+    let value = (column7 - oods_values[188])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[188] * value;
+
+    let value = (column7 - oods_values[189])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[189] * value;
+
+    let value = (column7 - oods_values[190])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[190] * value;
+
+    let value = (column7 - oods_values[191])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[191] * value;
+
+    // Sum the OODS boundary constraints on the composition polynomials.
+    let oods_point_to_deg = oods_point.pow_felt(&Felt::from(Layout::CONSTRAINT_DEGREE));
+
+    let value =
+        (column_values[8] - oods_values[192]).field_div(&felt_nonzero!(point - oods_point_to_deg));
+    total_sum += constraint_coefficients[192] * value;
+
+    let value =
+        (column_values[9] - oods_values[193]).field_div(&felt_nonzero!(point - oods_point_to_deg));
+    total_sum += constraint_coefficients[193] * value;
+
+    total_sum
+}
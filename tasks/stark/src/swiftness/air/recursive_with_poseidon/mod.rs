@@ -1,10 +1,16 @@
+pub mod autogenerated;
 pub mod consts;
 
+use crate::swiftness::air::consts::{FELT_0, FELT_1, FELT_32768, FELT_65536};
+use crate::swiftness::air::layout::{BuiltinSegment, ComputedValues, LayoutParams};
 use crate::swiftness::air::public_memory::PublicInput;
 use felt::Felt;
+use utils::global_values::{EcPoint, InteractionElements};
 
 // Re-export items needed by autogenerated files
+pub use crate::swiftness::air::layout::{GenericLayoutTrait, LayoutTrait, StaticLayoutTrait};
 pub use utils::global_values::GlobalValues;
+use utils::layout::LayoutStorage;
 
 pub const BITWISE_RATIO: usize = 16;
 pub const BITWISE_ROW_RATIO: usize = 256;
@@ -85,10 +91,10 @@ impl StaticLayoutTrait for Layout {
 
 impl GenericLayoutTrait for Layout {
     fn get_num_columns_first(_public_input: &PublicInput) -> Option<u32> {
-        Some(Self::NUM_COLUMNS_FIRST)
+        Some(<Self as StaticLayoutTrait>::NUM_COLUMNS_FIRST)
     }
     fn get_num_columns_second(_public_input: &PublicInput) -> Option<u32> {
-        Some(Self::NUM_COLUMNS_SECOND)
+        Some(<Self as StaticLayoutTrait>::NUM_COLUMNS_SECOND)
     }
 }
 
@@ -96,42 +102,108 @@ impl LayoutTrait for Layout {
     const CONSTRAINT_DEGREE: usize = 2;
     const MASK_SIZE: usize = 192;
     const N_CONSTRAINTS: usize = 124;
+    const N_INTERACTION_ELEMENTS: u32 = 6;
     // type InteractionElements = InteractionElements;
 }
 
-// StarkCurve
-pub mod stark_curve {
-    use felt::Felt;
-
-    pub const ALPHA: Felt = Felt::from_hex_unchecked("0x1");
-    pub const BETA: Felt = Felt::from_hex_unchecked(
-        "0x6f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89",
-    );
-    pub const ORDER: Felt = Felt::from_hex_unchecked(
-        "0x800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f",
-    );
-    pub const GEN_X: Felt = Felt::from_hex_unchecked(
-        "0x1ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca",
-    );
-    pub const GEN_Y: Felt = Felt::from_hex_unchecked(
-        "0x5668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f",
-    );
-}
-
-pub trait LayoutTrait {
-    // type InteractionElements;
+impl LayoutStorage for Layout {
+    type GlobalValues = GlobalValues;
 
-    const CONSTRAINT_DEGREE: usize;
-    const N_CONSTRAINTS: usize;
-    const MASK_SIZE: usize;
+    const CONSTRAINT_DEGREE: usize = <Self as LayoutTrait>::CONSTRAINT_DEGREE;
+    const N_CONSTRAINTS: usize = <Self as LayoutTrait>::N_CONSTRAINTS;
+    const MASK_SIZE: usize = <Self as LayoutTrait>::MASK_SIZE;
+    const NUM_COLUMNS_FIRST: u32 = <Self as StaticLayoutTrait>::NUM_COLUMNS_FIRST;
+    const NUM_COLUMNS_SECOND: u32 = <Self as StaticLayoutTrait>::NUM_COLUMNS_SECOND;
+    const POWS_SIZE: usize = 134;
+    const DOMAINS_SIZE: usize = 31;
 }
 
-pub trait StaticLayoutTrait {
-    const NUM_COLUMNS_FIRST: u32;
-    const NUM_COLUMNS_SECOND: u32;
-}
-
-pub trait GenericLayoutTrait {
-    fn get_num_columns_first(public_input: &PublicInput) -> Option<u32>;
-    fn get_num_columns_second(public_input: &PublicInput) -> Option<u32>;
+pub const PARAMS: LayoutParams = LayoutParams {
+    constraint_degree: <Layout as LayoutTrait>::CONSTRAINT_DEGREE,
+    n_constraints: <Layout as LayoutTrait>::N_CONSTRAINTS,
+    mask_size: <Layout as LayoutTrait>::MASK_SIZE,
+    num_columns_first: <Layout as StaticLayoutTrait>::NUM_COLUMNS_FIRST,
+    num_columns_second: <Layout as StaticLayoutTrait>::NUM_COLUMNS_SECOND,
+    n_interaction_elements: <Layout as LayoutTrait>::N_INTERACTION_ELEMENTS,
+    n_segments: segments::N_SEGMENTS,
+    public_memory_step: PUBLIC_MEMORY_STEP,
+    has_diluted_pool: HAS_DILUTED_POOL == 1,
+    pedersen_builtin_ratio: PEDERSEN_BUILTIN_RATIO * PEDERSEN_BUILTIN_REPETITIONS,
+    ecdsa_builtin_ratio: None,
+    poseidon_ratio: Some(POSEIDON_RATIO),
+    builtins: &[
+        BuiltinSegment {
+            segment: segments::PEDERSEN,
+            row_ratio: PEDERSEN_BUILTIN_ROW_RATIO as u32,
+            cells_per_instance: 3,
+            error: "Pedersen uses exceed copies",
+        },
+        BuiltinSegment {
+            segment: segments::RANGE_CHECK,
+            row_ratio: RANGE_CHECK_BUILTIN_ROW_RATIO as u32,
+            cells_per_instance: 1,
+            error: "Range check uses exceed copies",
+        },
+        BuiltinSegment {
+            segment: segments::BITWISE,
+            row_ratio: BITWISE_ROW_RATIO as u32,
+            cells_per_instance: 5,
+            error: "Bitwise uses exceed copies",
+        },
+        BuiltinSegment {
+            segment: segments::POSEIDON,
+            row_ratio: POSEIDON_ROW_RATIO as u32,
+            cells_per_instance: 6,
+            error: "Poseidon uses exceed copies",
+        },
+    ],
+};
+
+pub fn global_values(
+    public_input: &PublicInput,
+    interaction_elements: &InteractionElements,
+    values: &ComputedValues,
+) -> GlobalValues {
+    let memory_segments = public_input.segments.as_slice();
+
+    GlobalValues {
+        trace_length: values.trace_length,
+        initial_pc: memory_segments[segments::PROGRAM].begin_addr,
+        final_pc: memory_segments[segments::PROGRAM].stop_ptr,
+        initial_ap: memory_segments[segments::EXECUTION].begin_addr,
+        final_ap: memory_segments[segments::EXECUTION].stop_ptr,
+        initial_pedersen_addr: memory_segments[segments::PEDERSEN].begin_addr,
+        initial_range_check_addr: memory_segments[segments::RANGE_CHECK].begin_addr,
+        initial_bitwise_addr: memory_segments[segments::BITWISE].begin_addr,
+        initial_poseidon_addr: memory_segments[segments::POSEIDON].begin_addr,
+        range_check_min: public_input.range_check_min,
+        range_check_max: public_input.range_check_max,
+        offset_size: FELT_65536,
+        half_offset_size: FELT_32768,
+        pedersen_shift_point: EcPoint {
+            x: SHIFT_POINT_X,
+            y: SHIFT_POINT_Y,
+        },
+        pedersen_points_x: values.pedersen_points_x,
+        pedersen_points_y: values.pedersen_points_y,
+        poseidon_poseidon_full_round_key0: values.poseidon_full_round_key0,
+        poseidon_poseidon_full_round_key1: values.poseidon_full_round_key1,
+        poseidon_poseidon_full_round_key2: values.poseidon_full_round_key2,
+        poseidon_poseidon_partial_round_key0: values.poseidon_partial_round_key0,
+        poseidon_poseidon_partial_round_key1: values.poseidon_partial_round_key1,
+        memory_multi_column_perm_perm_interaction_elm: interaction_elements
+            .memory_multi_column_perm_perm_interaction_elm,
+        memory_multi_column_perm_hash_interaction_elm0: interaction_elements
+            .memory_multi_column_perm_hash_interaction_elm0,
+        range_check16_perm_interaction_elm: interaction_elements.range_check16_perm_interaction_elm,
+        diluted_check_permutation_interaction_elm: interaction_elements
+            .diluted_check_permutation_interaction_elm,
+        diluted_check_interaction_z: interaction_elements.diluted_check_interaction_z,
+        diluted_check_interaction_alpha: interaction_elements.diluted_check_interaction_alpha,
+        memory_multi_column_perm_perm_public_memory_prod: values.public_memory_prod_ratio,
+        range_check16_perm_public_memory_prod: FELT_1,
+        diluted_check_first_elm: FELT_0,
+        diluted_check_permutation_public_memory_prod: FELT_1,
+        diluted_check_final_cum_val: values.diluted_prod,
+    }
 }
@@ -0,0 +1,2858 @@
+use super::{GlobalValues, Layout, LayoutTrait};
+use crate::swiftness::air::consts::*;
+use felt::felt_nonzero;
+use felt::Felt;
+
+// Maps the column_row notation of the constraints to the flat mask value indices, resolved
+// at expansion time so that an access outside of the mask does not compile.
+macro_rules! column_row {
+    ($mask_values:expr, 0, 0) => {
+        $mask_values[0]
+    };
+    ($mask_values:expr, 0, 1) => {
+        $mask_values[1]
+    };
+    ($mask_values:expr, 0, 4) => {
+        $mask_values[2]
+    };
+    ($mask_values:expr, 0, 8) => {
+        $mask_values[3]
+    };
+    ($mask_values:expr, 0, 12) => {
+        $mask_values[4]
+    };
+    ($mask_values:expr, 0, 28) => {
+        $mask_values[5]
+    };
+    ($mask_values:expr, 0, 44) => {
+        $mask_values[6]
+    };
+    ($mask_values:expr, 0, 60) => {
+        $mask_values[7]
+    };
+    ($mask_values:expr, 0, 76) => {
+        $mask_values[8]
+    };
+    ($mask_values:expr, 0, 92) => {
+        $mask_values[9]
+    };
+    ($mask_values:expr, 0, 108) => {
+        $mask_values[10]
+    };
+    ($mask_values:expr, 0, 124) => {
+        $mask_values[11]
+    };
+    ($mask_values:expr, 1, 0) => {
+        $mask_values[12]
+    };
+    ($mask_values:expr, 1, 1) => {
+        $mask_values[13]
+    };
+    ($mask_values:expr, 1, 2) => {
+        $mask_values[14]
+    };
+    ($mask_values:expr, 1, 3) => {
+        $mask_values[15]
+    };
+    ($mask_values:expr, 1, 4) => {
+        $mask_values[16]
+    };
+    ($mask_values:expr, 1, 5) => {
+        $mask_values[17]
+    };
+    ($mask_values:expr, 1, 6) => {
+        $mask_values[18]
+    };
+    ($mask_values:expr, 1, 7) => {
+        $mask_values[19]
+    };
+    ($mask_values:expr, 1, 8) => {
+        $mask_values[20]
+    };
+    ($mask_values:expr, 1, 9) => {
+        $mask_values[21]
+    };
+    ($mask_values:expr, 1, 10) => {
+        $mask_values[22]
+    };
+    ($mask_values:expr, 1, 11) => {
+        $mask_values[23]
+    };
+    ($mask_values:expr, 1, 12) => {
+        $mask_values[24]
+    };
+    ($mask_values:expr, 1, 13) => {
+        $mask_values[25]
+    };
+    ($mask_values:expr, 1, 14) => {
+        $mask_values[26]
+    };
+    ($mask_values:expr, 1, 15) => {
+        $mask_values[27]
+    };
+    ($mask_values:expr, 2, 0) => {
+        $mask_values[28]
+    };
+    ($mask_values:expr, 2, 1) => {
+        $mask_values[29]
+    };
+    ($mask_values:expr, 3, 0) => {
+        $mask_values[30]
+    };
+    ($mask_values:expr, 3, 1) => {
+        $mask_values[31]
+    };
+    ($mask_values:expr, 3, 255) => {
+        $mask_values[32]
+    };
+    ($mask_values:expr, 3, 256) => {
+        $mask_values[33]
+    };
+    ($mask_values:expr, 3, 511) => {
+        $mask_values[34]
+    };
+    ($mask_values:expr, 4, 0) => {
+        $mask_values[35]
+    };
+    ($mask_values:expr, 4, 1) => {
+        $mask_values[36]
+    };
+    ($mask_values:expr, 4, 255) => {
+        $mask_values[37]
+    };
+    ($mask_values:expr, 4, 256) => {
+        $mask_values[38]
+    };
+    ($mask_values:expr, 5, 0) => {
+        $mask_values[39]
+    };
+    ($mask_values:expr, 5, 1) => {
+        $mask_values[40]
+    };
+    ($mask_values:expr, 5, 192) => {
+        $mask_values[41]
+    };
+    ($mask_values:expr, 5, 193) => {
+        $mask_values[42]
+    };
+    ($mask_values:expr, 5, 196) => {
+        $mask_values[43]
+    };
+    ($mask_values:expr, 5, 197) => {
+        $mask_values[44]
+    };
+    ($mask_values:expr, 5, 251) => {
+        $mask_values[45]
+    };
+    ($mask_values:expr, 5, 252) => {
+        $mask_values[46]
+    };
+    ($mask_values:expr, 5, 256) => {
+        $mask_values[47]
+    };
+    ($mask_values:expr, 6, 0) => {
+        $mask_values[48]
+    };
+    ($mask_values:expr, 6, 1) => {
+        $mask_values[49]
+    };
+    ($mask_values:expr, 6, 255) => {
+        $mask_values[50]
+    };
+    ($mask_values:expr, 6, 256) => {
+        $mask_values[51]
+    };
+    ($mask_values:expr, 6, 511) => {
+        $mask_values[52]
+    };
+    ($mask_values:expr, 7, 0) => {
+        $mask_values[53]
+    };
+    ($mask_values:expr, 7, 1) => {
+        $mask_values[54]
+    };
+    ($mask_values:expr, 7, 255) => {
+        $mask_values[55]
+    };
+    ($mask_values:expr, 7, 256) => {
+        $mask_values[56]
+    };
+    ($mask_values:expr, 8, 0) => {
+        $mask_values[57]
+    };
+    ($mask_values:expr, 8, 1) => {
+        $mask_values[58]
+    };
+    ($mask_values:expr, 8, 192) => {
+        $mask_values[59]
+    };
+    ($mask_values:expr, 8, 193) => {
+        $mask_values[60]
+    };
+    ($mask_values:expr, 8, 196) => {
+        $mask_values[61]
+    };
+    ($mask_values:expr, 8, 197) => {
+        $mask_values[62]
+    };
+    ($mask_values:expr, 8, 251) => {
+        $mask_values[63]
+    };
+    ($mask_values:expr, 8, 252) => {
+        $mask_values[64]
+    };
+    ($mask_values:expr, 8, 256) => {
+        $mask_values[65]
+    };
+    ($mask_values:expr, 9, 0) => {
+        $mask_values[66]
+    };
+    ($mask_values:expr, 9, 1) => {
+        $mask_values[67]
+    };
+    ($mask_values:expr, 9, 255) => {
+        $mask_values[68]
+    };
+    ($mask_values:expr, 9, 256) => {
+        $mask_values[69]
+    };
+    ($mask_values:expr, 9, 511) => {
+        $mask_values[70]
+    };
+    ($mask_values:expr, 10, 0) => {
+        $mask_values[71]
+    };
+    ($mask_values:expr, 10, 1) => {
+        $mask_values[72]
+    };
+    ($mask_values:expr, 10, 255) => {
+        $mask_values[73]
+    };
+    ($mask_values:expr, 10, 256) => {
+        $mask_values[74]
+    };
+    ($mask_values:expr, 11, 0) => {
+        $mask_values[75]
+    };
+    ($mask_values:expr, 11, 1) => {
+        $mask_values[76]
+    };
+    ($mask_values:expr, 11, 192) => {
+        $mask_values[77]
+    };
+    ($mask_values:expr, 11, 193) => {
+        $mask_values[78]
+    };
+    ($mask_values:expr, 11, 196) => {
+        $mask_values[79]
+    };
+    ($mask_values:expr, 11, 197) => {
+        $mask_values[80]
+    };
+    ($mask_values:expr, 11, 251) => {
+        $mask_values[81]
+    };
+    ($mask_values:expr, 11, 252) => {
+        $mask_values[82]
+    };
+    ($mask_values:expr, 11, 256) => {
+        $mask_values[83]
+    };
+    ($mask_values:expr, 12, 0) => {
+        $mask_values[84]
+    };
+    ($mask_values:expr, 12, 1) => {
+        $mask_values[85]
+    };
+    ($mask_values:expr, 12, 255) => {
+        $mask_values[86]
+    };
+    ($mask_values:expr, 12, 256) => {
+        $mask_values[87]
+    };
+    ($mask_values:expr, 12, 511) => {
+        $mask_values[88]
+    };
+    ($mask_values:expr, 13, 0) => {
+        $mask_values[89]
+    };
+    ($mask_values:expr, 13, 1) => {
+        $mask_values[90]
+    };
+    ($mask_values:expr, 13, 255) => {
+        $mask_values[91]
+    };
+    ($mask_values:expr, 13, 256) => {
+        $mask_values[92]
+    };
+    ($mask_values:expr, 14, 0) => {
+        $mask_values[93]
+    };
+    ($mask_values:expr, 14, 1) => {
+        $mask_values[94]
+    };
+    ($mask_values:expr, 14, 192) => {
+        $mask_values[95]
+    };
+    ($mask_values:expr, 14, 193) => {
+        $mask_values[96]
+    };
+    ($mask_values:expr, 14, 196) => {
+        $mask_values[97]
+    };
+    ($mask_values:expr, 14, 197) => {
+        $mask_values[98]
+    };
+    ($mask_values:expr, 14, 251) => {
+        $mask_values[99]
+    };
+    ($mask_values:expr, 14, 252) => {
+        $mask_values[100]
+    };
+    ($mask_values:expr, 14, 256) => {
+        $mask_values[101]
+    };
+    ($mask_values:expr, 15, 0) => {
+        $mask_values[102]
+    };
+    ($mask_values:expr, 15, 255) => {
+        $mask_values[103]
+    };
+    ($mask_values:expr, 16, 0) => {
+        $mask_values[104]
+    };
+    ($mask_values:expr, 16, 255) => {
+        $mask_values[105]
+    };
+    ($mask_values:expr, 17, 0) => {
+        $mask_values[106]
+    };
+    ($mask_values:expr, 17, 255) => {
+        $mask_values[107]
+    };
+    ($mask_values:expr, 18, 0) => {
+        $mask_values[108]
+    };
+    ($mask_values:expr, 18, 255) => {
+        $mask_values[109]
+    };
+    ($mask_values:expr, 19, 0) => {
+        $mask_values[110]
+    };
+    ($mask_values:expr, 19, 1) => {
+        $mask_values[111]
+    };
+    ($mask_values:expr, 19, 2) => {
+        $mask_values[112]
+    };
+    ($mask_values:expr, 19, 3) => {
+        $mask_values[113]
+    };
+    ($mask_values:expr, 19, 4) => {
+        $mask_values[114]
+    };
+    ($mask_values:expr, 19, 5) => {
+        $mask_values[115]
+    };
+    ($mask_values:expr, 19, 6) => {
+        $mask_values[116]
+    };
+    ($mask_values:expr, 19, 7) => {
+        $mask_values[117]
+    };
+    ($mask_values:expr, 19, 8) => {
+        $mask_values[118]
+    };
+    ($mask_values:expr, 19, 9) => {
+        $mask_values[119]
+    };
+    ($mask_values:expr, 19, 12) => {
+        $mask_values[120]
+    };
+    ($mask_values:expr, 19, 13) => {
+        $mask_values[121]
+    };
+    ($mask_values:expr, 19, 16) => {
+        $mask_values[122]
+    };
+    ($mask_values:expr, 19, 22) => {
+        $mask_values[123]
+    };
+    ($mask_values:expr, 19, 23) => {
+        $mask_values[124]
+    };
+    ($mask_values:expr, 19, 38) => {
+        $mask_values[125]
+    };
+    ($mask_values:expr, 19, 39) => {
+        $mask_values[126]
+    };
+    ($mask_values:expr, 19, 70) => {
+        $mask_values[127]
+    };
+    ($mask_values:expr, 19, 71) => {
+        $mask_values[128]
+    };
+    ($mask_values:expr, 19, 102) => {
+        $mask_values[129]
+    };
+    ($mask_values:expr, 19, 103) => {
+        $mask_values[130]
+    };
+    ($mask_values:expr, 19, 134) => {
+        $mask_values[131]
+    };
+    ($mask_values:expr, 19, 135) => {
+        $mask_values[132]
+    };
+    ($mask_values:expr, 19, 167) => {
+        $mask_values[133]
+    };
+    ($mask_values:expr, 19, 199) => {
+        $mask_values[134]
+    };
+    ($mask_values:expr, 19, 230) => {
+        $mask_values[135]
+    };
+    ($mask_values:expr, 19, 263) => {
+        $mask_values[136]
+    };
+    ($mask_values:expr, 19, 295) => {
+        $mask_values[137]
+    };
+    ($mask_values:expr, 19, 327) => {
+        $mask_values[138]
+    };
+    ($mask_values:expr, 19, 391) => {
+        $mask_values[139]
+    };
+    ($mask_values:expr, 19, 423) => {
+        $mask_values[140]
+    };
+    ($mask_values:expr, 19, 455) => {
+        $mask_values[141]
+    };
+    ($mask_values:expr, 19, 4118) => {
+        $mask_values[142]
+    };
+    ($mask_values:expr, 19, 4119) => {
+        $mask_values[143]
+    };
+    ($mask_values:expr, 19, 8214) => {
+        $mask_values[144]
+    };
+    ($mask_values:expr, 20, 0) => {
+        $mask_values[145]
+    };
+    ($mask_values:expr, 20, 1) => {
+        $mask_values[146]
+    };
+    ($mask_values:expr, 20, 2) => {
+        $mask_values[147]
+    };
+    ($mask_values:expr, 20, 3) => {
+        $mask_values[148]
+    };
+    ($mask_values:expr, 21, 0) => {
+        $mask_values[149]
+    };
+    ($mask_values:expr, 21, 1) => {
+        $mask_values[150]
+    };
+    ($mask_values:expr, 21, 2) => {
+        $mask_values[151]
+    };
+    ($mask_values:expr, 21, 3) => {
+        $mask_values[152]
+    };
+    ($mask_values:expr, 21, 4) => {
+        $mask_values[153]
+    };
+    ($mask_values:expr, 21, 5) => {
+        $mask_values[154]
+    };
+    ($mask_values:expr, 21, 6) => {
+        $mask_values[155]
+    };
+    ($mask_values:expr, 21, 7) => {
+        $mask_values[156]
+    };
+    ($mask_values:expr, 21, 8) => {
+        $mask_values[157]
+    };
+    ($mask_values:expr, 21, 9) => {
+        $mask_values[158]
+    };
+    ($mask_values:expr, 21, 10) => {
+        $mask_values[159]
+    };
+    ($mask_values:expr, 21, 11) => {
+        $mask_values[160]
+    };
+    ($mask_values:expr, 21, 12) => {
+        $mask_values[161]
+    };
+    ($mask_values:expr, 21, 13) => {
+        $mask_values[162]
+    };
+    ($mask_values:expr, 21, 14) => {
+        $mask_values[163]
+    };
+    ($mask_values:expr, 21, 15) => {
+        $mask_values[164]
+    };
+    ($mask_values:expr, 21, 16) => {
+        $mask_values[165]
+    };
+    ($mask_values:expr, 21, 17) => {
+        $mask_values[166]
+    };
+    ($mask_values:expr, 21, 21) => {
+        $mask_values[167]
+    };
+    ($mask_values:expr, 21, 22) => {
+        $mask_values[168]
+    };
+    ($mask_values:expr, 21, 23) => {
+        $mask_values[169]
+    };
+    ($mask_values:expr, 21, 24) => {
+        $mask_values[170]
+    };
+    ($mask_values:expr, 21, 25) => {
+        $mask_values[171]
+    };
+    ($mask_values:expr, 21, 30) => {
+        $mask_values[172]
+    };
+    ($mask_values:expr, 21, 31) => {
+        $mask_values[173]
+    };
+    ($mask_values:expr, 21, 39) => {
+        $mask_values[174]
+    };
+    ($mask_values:expr, 21, 47) => {
+        $mask_values[175]
+    };
+    ($mask_values:expr, 21, 55) => {
+        $mask_values[176]
+    };
+    ($mask_values:expr, 21, 4081) => {
+        $mask_values[177]
+    };
+    ($mask_values:expr, 21, 4083) => {
+        $mask_values[178]
+    };
+    ($mask_values:expr, 21, 4089) => {
+        $mask_values[179]
+    };
+    ($mask_values:expr, 21, 4091) => {
+        $mask_values[180]
+    };
+    ($mask_values:expr, 21, 4093) => {
+        $mask_values[181]
+    };
+    ($mask_values:expr, 21, 4102) => {
+        $mask_values[182]
+    };
+    ($mask_values:expr, 21, 4110) => {
+        $mask_values[183]
+    };
+    ($mask_values:expr, 21, 8167) => {
+        $mask_values[184]
+    };
+    ($mask_values:expr, 21, 8177) => {
+        $mask_values[185]
+    };
+    ($mask_values:expr, 21, 8179) => {
+        $mask_values[186]
+    };
+    ($mask_values:expr, 21, 8183) => {
+        $mask_values[187]
+    };
+    ($mask_values:expr, 21, 8185) => {
+        $mask_values[188]
+    };
+    ($mask_values:expr, 21, 8187) => {
+        $mask_values[189]
+    };
+    ($mask_values:expr, 21, 8191) => {
+        $mask_values[190]
+    };
+    ($mask_values:expr, 22, 0) => {
+        $mask_values[191]
+    };
+    ($mask_values:expr, 22, 16) => {
+        $mask_values[192]
+    };
+    ($mask_values:expr, 22, 80) => {
+        $mask_values[193]
+    };
+    ($mask_values:expr, 22, 144) => {
+        $mask_values[194]
+    };
+    ($mask_values:expr, 22, 208) => {
+        $mask_values[195]
+    };
+    ($mask_values:expr, 22, 8160) => {
+        $mask_values[196]
+    };
+    ($mask_values:expr, 23, 0) => {
+        $mask_values[197] // inter1_row0
+    };
+    ($mask_values:expr, 23, 1) => {
+        $mask_values[198] // inter1_row1
+    };
+    ($mask_values:expr, 24, 0) => {
+        $mask_values[199] // inter1_row0
+    };
+    ($mask_values:expr, 24, 2) => {
+        $mask_values[200] // inter1_row2
+    };
+}
+
+// Powers of the evaluation point and of the trace generator used by the constraint domains.
+pub fn eval_composition_powers(
+    point: Felt,
+    trace_generator: Felt,
+    trace_length: Felt,
+    autogenerated_pows: &mut [Felt],
+) {
+    autogenerated_pows[0] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_8192))));
+    autogenerated_pows[1] = autogenerated_pows[0] * autogenerated_pows[0]; // pow(point, (safe_div(global_values.trace_length, 4096))).
+    autogenerated_pows[2] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_512))));
+    autogenerated_pows[3] = autogenerated_pows[2] * autogenerated_pows[2]; // pow(point, (safe_div(global_values.trace_length, 256))).
+    autogenerated_pows[4] = autogenerated_pows[3] * autogenerated_pows[3]; // pow(point, (safe_div(global_values.trace_length, 128))).
+    autogenerated_pows[5] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_32))));
+    autogenerated_pows[6] = autogenerated_pows[5] * autogenerated_pows[5]; // pow(point, (safe_div(global_values.trace_length, 16))).
+    autogenerated_pows[7] = autogenerated_pows[6] * autogenerated_pows[6]; // pow(point, (safe_div(global_values.trace_length, 8))).
+    autogenerated_pows[8] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_2))));
+    autogenerated_pows[9] = autogenerated_pows[8] * autogenerated_pows[8]; // pow(point, global_values.trace_length).
+    autogenerated_pows[10] = trace_generator.pow_felt(&(trace_length - FELT_8192));
+    autogenerated_pows[11] = trace_generator.pow_felt(&(trace_length - FELT_128));
+    autogenerated_pows[12] = trace_generator.pow_felt(&(trace_length - FELT_1));
+    autogenerated_pows[13] = trace_generator.pow_felt(&(trace_length - FELT_2));
+    autogenerated_pows[14] = trace_generator.pow_felt(&(trace_length - FELT_16));
+    autogenerated_pows[15] =
+        trace_generator.pow_felt(&((FELT_251 * trace_length).floor_div(&felt_nonzero!(FELT_256))));
+    autogenerated_pows[16] =
+        trace_generator.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_2))));
+    autogenerated_pows[17] =
+        trace_generator.pow_felt(&((FELT_63 * trace_length).floor_div(&felt_nonzero!(FELT_64))));
+    autogenerated_pows[18] =
+        trace_generator.pow_felt(&((FELT_255 * trace_length).floor_div(&felt_nonzero!(FELT_256))));
+    autogenerated_pows[19] =
+        trace_generator.pow_felt(&((FELT_15 * trace_length).floor_div(&felt_nonzero!(FELT_16))));
+}
+
+pub fn eval_composition_domains(point: Felt, autogenerated_pows: &[Felt], domains: &mut [Felt]) {
+    domains[0] = autogenerated_pows[9] - FELT_1;
+    domains[1] = autogenerated_pows[8] - FELT_1;
+    domains[2] = autogenerated_pows[7] - FELT_1;
+    domains[3] = autogenerated_pows[6] - autogenerated_pows[19];
+    domains[4] = autogenerated_pows[6] - FELT_1;
+    domains[5] = autogenerated_pows[5] - FELT_1;
+    domains[6] = autogenerated_pows[4] - FELT_1;
+    domains[7] = autogenerated_pows[3] - FELT_1;
+    domains[8] = autogenerated_pows[3] - autogenerated_pows[18];
+    domains[9] = autogenerated_pows[3] - autogenerated_pows[17];
+    domains[10] = autogenerated_pows[2] - autogenerated_pows[16];
+    domains[11] = autogenerated_pows[2] - FELT_1;
+    domains[12] = autogenerated_pows[1] - autogenerated_pows[18];
+    domains[13] = autogenerated_pows[1] - autogenerated_pows[15];
+    domains[14] = autogenerated_pows[1] - FELT_1;
+    domains[15] = autogenerated_pows[0] - autogenerated_pows[18];
+    domains[16] = autogenerated_pows[0] - autogenerated_pows[15];
+    domains[17] = autogenerated_pows[0] - FELT_1;
+    domains[18] = point - autogenerated_pows[14];
+    domains[19] = point - FELT_1;
+    domains[20] = point - autogenerated_pows[13];
+    domains[21] = point - autogenerated_pows[12];
+    domains[22] = point - autogenerated_pows[11];
+    domains[23] = point - autogenerated_pows[10];
+}
+
+pub fn eval_composition_constraints(
+    mask_values: &[Felt],
+    constraint_coefficients: &[Felt],
+    domains: &[Felt],
+    global_values: &GlobalValues,
+) -> Felt {
+    let cpu_decode_opcode_range_check_bit_0 = column_row!(mask_values, 1, 0)
+        - (column_row!(mask_values, 1, 1) + column_row!(mask_values, 1, 1));
+    let cpu_decode_opcode_range_check_bit_2 = column_row!(mask_values, 1, 2)
+        - (column_row!(mask_values, 1, 3) + column_row!(mask_values, 1, 3));
+    let cpu_decode_opcode_range_check_bit_4 = column_row!(mask_values, 1, 4)
+        - (column_row!(mask_values, 1, 5) + column_row!(mask_values, 1, 5));
+    let cpu_decode_opcode_range_check_bit_3 = column_row!(mask_values, 1, 3)
+        - (column_row!(mask_values, 1, 4) + column_row!(mask_values, 1, 4));
+    let cpu_decode_flag_op1_base_op0_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_2
+            + cpu_decode_opcode_range_check_bit_4
+            + cpu_decode_opcode_range_check_bit_3);
+    let cpu_decode_opcode_range_check_bit_5 = column_row!(mask_values, 1, 5)
+        - (column_row!(mask_values, 1, 6) + column_row!(mask_values, 1, 6));
+    let cpu_decode_opcode_range_check_bit_6 = column_row!(mask_values, 1, 6)
+        - (column_row!(mask_values, 1, 7) + column_row!(mask_values, 1, 7));
+    let cpu_decode_opcode_range_check_bit_9 = column_row!(mask_values, 1, 9)
+        - (column_row!(mask_values, 1, 10) + column_row!(mask_values, 1, 10));
+    let cpu_decode_flag_res_op1_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_5
+            + cpu_decode_opcode_range_check_bit_6
+            + cpu_decode_opcode_range_check_bit_9);
+    let cpu_decode_opcode_range_check_bit_7 = column_row!(mask_values, 1, 7)
+        - (column_row!(mask_values, 1, 8) + column_row!(mask_values, 1, 8));
+    let cpu_decode_opcode_range_check_bit_8 = column_row!(mask_values, 1, 8)
+        - (column_row!(mask_values, 1, 9) + column_row!(mask_values, 1, 9));
+    let cpu_decode_flag_pc_update_regular_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_7
+            + cpu_decode_opcode_range_check_bit_8
+            + cpu_decode_opcode_range_check_bit_9);
+    let cpu_decode_opcode_range_check_bit_12 = column_row!(mask_values, 1, 12)
+        - (column_row!(mask_values, 1, 13) + column_row!(mask_values, 1, 13));
+    let cpu_decode_opcode_range_check_bit_13 = column_row!(mask_values, 1, 13)
+        - (column_row!(mask_values, 1, 14) + column_row!(mask_values, 1, 14));
+    let cpu_decode_fp_update_regular_0 =
+        FELT_1 - (cpu_decode_opcode_range_check_bit_12 + cpu_decode_opcode_range_check_bit_13);
+    let cpu_decode_opcode_range_check_bit_1 = column_row!(mask_values, 1, 1)
+        - (column_row!(mask_values, 1, 2) + column_row!(mask_values, 1, 2));
+    let npc_reg_0 = column_row!(mask_values, 19, 0) + cpu_decode_opcode_range_check_bit_2 + FELT_1;
+    let cpu_decode_opcode_range_check_bit_10 = column_row!(mask_values, 1, 10)
+        - (column_row!(mask_values, 1, 11) + column_row!(mask_values, 1, 11));
+    let cpu_decode_opcode_range_check_bit_11 = column_row!(mask_values, 1, 11)
+        - (column_row!(mask_values, 1, 12) + column_row!(mask_values, 1, 12));
+    let cpu_decode_opcode_range_check_bit_14 = column_row!(mask_values, 1, 14)
+        - (column_row!(mask_values, 1, 15) + column_row!(mask_values, 1, 15));
+    let memory_address_diff_0 = column_row!(mask_values, 20, 2) - column_row!(mask_values, 20, 0);
+    let range_check16_diff_0 = column_row!(mask_values, 2, 1) - column_row!(mask_values, 2, 0);
+    let pedersen_hash0_ec_subset_sum_bit_0 = column_row!(mask_values, 5, 0)
+        - (column_row!(mask_values, 5, 1) + column_row!(mask_values, 5, 1));
+    let pedersen_hash0_ec_subset_sum_bit_neg_0 = FELT_1 - pedersen_hash0_ec_subset_sum_bit_0;
+    let pedersen_hash1_ec_subset_sum_bit_0 = column_row!(mask_values, 8, 0)
+        - (column_row!(mask_values, 8, 1) + column_row!(mask_values, 8, 1));
+    let pedersen_hash1_ec_subset_sum_bit_neg_0 = FELT_1 - pedersen_hash1_ec_subset_sum_bit_0;
+    let pedersen_hash2_ec_subset_sum_bit_0 = column_row!(mask_values, 11, 0)
+        - (column_row!(mask_values, 11, 1) + column_row!(mask_values, 11, 1));
+    let pedersen_hash2_ec_subset_sum_bit_neg_0 = FELT_1 - pedersen_hash2_ec_subset_sum_bit_0;
+    let pedersen_hash3_ec_subset_sum_bit_0 = column_row!(mask_values, 14, 0)
+        - (column_row!(mask_values, 14, 1) + column_row!(mask_values, 14, 1));
+    let pedersen_hash3_ec_subset_sum_bit_neg_0 = FELT_1 - pedersen_hash3_ec_subset_sum_bit_0;
+    let range_check_builtin_value0_0 = column_row!(mask_values, 0, 12);
+    let range_check_builtin_value1_0 =
+        range_check_builtin_value0_0 * global_values.offset_size + column_row!(mask_values, 0, 28);
+    let range_check_builtin_value2_0 =
+        range_check_builtin_value1_0 * global_values.offset_size + column_row!(mask_values, 0, 44);
+    let range_check_builtin_value3_0 =
+        range_check_builtin_value2_0 * global_values.offset_size + column_row!(mask_values, 0, 60);
+    let range_check_builtin_value4_0 =
+        range_check_builtin_value3_0 * global_values.offset_size + column_row!(mask_values, 0, 76);
+    let range_check_builtin_value5_0 =
+        range_check_builtin_value4_0 * global_values.offset_size + column_row!(mask_values, 0, 92);
+    let range_check_builtin_value6_0 =
+        range_check_builtin_value5_0 * global_values.offset_size + column_row!(mask_values, 0, 108);
+    let range_check_builtin_value7_0 =
+        range_check_builtin_value6_0 * global_values.offset_size + column_row!(mask_values, 0, 124);
+    let ecdsa_signature0_doubling_key_x_squared =
+        column_row!(mask_values, 21, 6) * column_row!(mask_values, 21, 6);
+    let ecdsa_signature0_exponentiate_generator_bit_0 = column_row!(mask_values, 21, 15)
+        - (column_row!(mask_values, 21, 47) + column_row!(mask_values, 21, 47));
+    let ecdsa_signature0_exponentiate_generator_bit_neg_0 =
+        FELT_1 - ecdsa_signature0_exponentiate_generator_bit_0;
+    let ecdsa_signature0_exponentiate_key_bit_0 = column_row!(mask_values, 21, 5)
+        - (column_row!(mask_values, 21, 21) + column_row!(mask_values, 21, 21));
+    let ecdsa_signature0_exponentiate_key_bit_neg_0 =
+        FELT_1 - ecdsa_signature0_exponentiate_key_bit_0;
+
+    let mut total_sum = FELT_0;
+
+    // Constraint: cpu/decode/opcode_range_check/bit.
+    let value = (cpu_decode_opcode_range_check_bit_0 * cpu_decode_opcode_range_check_bit_0
+        - cpu_decode_opcode_range_check_bit_0)
+        * domains[3].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[0] * value;
+
+    // Constraint: cpu/decode/opcode_range_check/zero.
+    let value = (column_row!(mask_values, 1, 0)).field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[1] * value;
+
+    // Constraint: cpu/decode/opcode_range_check_input.
+    let value = (column_row!(mask_values, 19, 1)
+        - (((column_row!(mask_values, 1, 0) * global_values.offset_size
+            + column_row!(mask_values, 0, 4))
+            * global_values.offset_size
+            + column_row!(mask_values, 0, 8))
+            * global_values.offset_size
+            + column_row!(mask_values, 0, 0)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[2] * value;
+
+    // Constraint: cpu/decode/flag_op1_base_op0_bit.
+    let value = (cpu_decode_flag_op1_base_op0_0 * cpu_decode_flag_op1_base_op0_0
+        - cpu_decode_flag_op1_base_op0_0)
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[3] * value;
+
+    // Constraint: cpu/decode/flag_res_op1_bit.
+    let value = (cpu_decode_flag_res_op1_0 * cpu_decode_flag_res_op1_0 - cpu_decode_flag_res_op1_0)
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[4] * value;
+
+    // Constraint: cpu/decode/flag_pc_update_regular_bit.
+    let value = (cpu_decode_flag_pc_update_regular_0 * cpu_decode_flag_pc_update_regular_0
+        - cpu_decode_flag_pc_update_regular_0)
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[5] * value;
+
+    // Constraint: cpu/decode/fp_update_regular_bit.
+    let value = (cpu_decode_fp_update_regular_0 * cpu_decode_fp_update_regular_0
+        - cpu_decode_fp_update_regular_0)
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[6] * value;
+
+    // Constraint: cpu/operands/mem_dst_addr.
+    let value = (column_row!(mask_values, 19, 8) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_0 * column_row!(mask_values, 21, 8)
+            + (FELT_1 - cpu_decode_opcode_range_check_bit_0) * column_row!(mask_values, 21, 0)
+            + column_row!(mask_values, 0, 0)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[7] * value;
+
+    // Constraint: cpu/operands/mem0_addr.
+    let value = (column_row!(mask_values, 19, 4) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_1 * column_row!(mask_values, 21, 8)
+            + (FELT_1 - cpu_decode_opcode_range_check_bit_1) * column_row!(mask_values, 21, 0)
+            + column_row!(mask_values, 0, 8)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[8] * value;
+
+    // Constraint: cpu/operands/mem1_addr.
+    let value = (column_row!(mask_values, 19, 12) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_2 * column_row!(mask_values, 19, 0)
+            + cpu_decode_opcode_range_check_bit_4 * column_row!(mask_values, 21, 0)
+            + cpu_decode_opcode_range_check_bit_3 * column_row!(mask_values, 21, 8)
+            + cpu_decode_flag_op1_base_op0_0 * column_row!(mask_values, 19, 5)
+            + column_row!(mask_values, 0, 4)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[9] * value;
+
+    // Constraint: cpu/operands/ops_mul.
+    let value = (column_row!(mask_values, 21, 4)
+        - column_row!(mask_values, 19, 5) * column_row!(mask_values, 19, 13))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[10] * value;
+
+    // Constraint: cpu/operands/res.
+    let value = ((FELT_1 - cpu_decode_opcode_range_check_bit_9) * column_row!(mask_values, 21, 12)
+        - (cpu_decode_opcode_range_check_bit_5
+            * (column_row!(mask_values, 19, 5) + column_row!(mask_values, 19, 13))
+            + cpu_decode_opcode_range_check_bit_6 * column_row!(mask_values, 21, 4)
+            + cpu_decode_flag_res_op1_0 * column_row!(mask_values, 19, 13)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[11] * value;
+
+    // Constraint: cpu/update_registers/update_pc/tmp0.
+    let value = (column_row!(mask_values, 21, 2)
+        - cpu_decode_opcode_range_check_bit_9 * column_row!(mask_values, 19, 9))
+        * domains[18].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[12] * value;
+
+    // Constraint: cpu/update_registers/update_pc/tmp1.
+    let value = (column_row!(mask_values, 21, 10)
+        - column_row!(mask_values, 21, 2) * column_row!(mask_values, 21, 12))
+        * domains[18].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[13] * value;
+
+    // Constraint: cpu/update_registers/update_pc/pc_cond_negative.
+    let value = ((FELT_1 - cpu_decode_opcode_range_check_bit_9) * column_row!(mask_values, 19, 16)
+        + column_row!(mask_values, 21, 2)
+            * (column_row!(mask_values, 19, 16)
+                - (column_row!(mask_values, 19, 0) + column_row!(mask_values, 19, 13)))
+        - (cpu_decode_flag_pc_update_regular_0 * npc_reg_0
+            + cpu_decode_opcode_range_check_bit_7 * column_row!(mask_values, 21, 12)
+            + cpu_decode_opcode_range_check_bit_8
+                * (column_row!(mask_values, 19, 0) + column_row!(mask_values, 21, 12))))
+        * domains[18].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[14] * value;
+
+    // Constraint: cpu/update_registers/update_pc/pc_cond_positive.
+    let value = ((column_row!(mask_values, 21, 10) - cpu_decode_opcode_range_check_bit_9)
+        * (column_row!(mask_values, 19, 16) - npc_reg_0))
+        * domains[18].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[15] * value;
+
+    // Constraint: cpu/update_registers/update_ap/ap_update.
+    let value = (column_row!(mask_values, 21, 16)
+        - (column_row!(mask_values, 21, 0)
+            + cpu_decode_opcode_range_check_bit_10 * column_row!(mask_values, 21, 12)
+            + cpu_decode_opcode_range_check_bit_11
+            + cpu_decode_opcode_range_check_bit_12 * FELT_2))
+        * domains[18].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[16] * value;
+
+    // Constraint: cpu/update_registers/update_fp/fp_update.
+    let value = (column_row!(mask_values, 21, 24)
+        - (cpu_decode_fp_update_regular_0 * column_row!(mask_values, 21, 8)
+            + cpu_decode_opcode_range_check_bit_13 * column_row!(mask_values, 19, 9)
+            + cpu_decode_opcode_range_check_bit_12 * (column_row!(mask_values, 21, 0) + FELT_2)))
+        * domains[18].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[17] * value;
+
+    // Constraint: cpu/opcodes/call/push_fp.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 19, 9) - column_row!(mask_values, 21, 8)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[18] * value;
+
+    // Constraint: cpu/opcodes/call/push_pc.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 19, 5)
+            - (column_row!(mask_values, 19, 0) + cpu_decode_opcode_range_check_bit_2 + FELT_1)))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[19] * value;
+
+    // Constraint: cpu/opcodes/call/off0.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 0, 0) - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[20] * value;
+
+    // Constraint: cpu/opcodes/call/off1.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 0, 8) - (global_values.half_offset_size + FELT_1)))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[21] * value;
+
+    // Constraint: cpu/opcodes/call/flags.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (cpu_decode_opcode_range_check_bit_12
+            + cpu_decode_opcode_range_check_bit_12
+            + FELT_1
+            + FELT_1
+            - (cpu_decode_opcode_range_check_bit_0
+                + cpu_decode_opcode_range_check_bit_1
+                + FELT_4)))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[22] * value;
+
+    // Constraint: cpu/opcodes/ret/off0.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (column_row!(mask_values, 0, 0) + FELT_2 - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[23] * value;
+
+    // Constraint: cpu/opcodes/ret/off2.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (column_row!(mask_values, 0, 4) + FELT_1 - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[24] * value;
+
+    // Constraint: cpu/opcodes/ret/flags.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (cpu_decode_opcode_range_check_bit_7
+            + cpu_decode_opcode_range_check_bit_0
+            + cpu_decode_opcode_range_check_bit_3
+            + cpu_decode_flag_res_op1_0
+            - FELT_4))
+        .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[25] * value;
+
+    // Constraint: cpu/opcodes/assert_eq/assert_eq.
+    let value = (cpu_decode_opcode_range_check_bit_14
+        * (column_row!(mask_values, 19, 9) - column_row!(mask_values, 21, 12)))
+    .field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[26] * value;
+
+    // Constraint: initial_ap.
+    let value = (column_row!(mask_values, 21, 0) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[27] * value;
+
+    // Constraint: initial_fp.
+    let value = (column_row!(mask_values, 21, 8) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[28] * value;
+
+    // Constraint: initial_pc.
+    let value = (column_row!(mask_values, 19, 0) - global_values.initial_pc)
+        .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[29] * value;
+
+    // Constraint: final_ap.
+    let value = (column_row!(mask_values, 21, 0) - global_values.final_ap)
+        .field_div(&felt_nonzero!(domains[18]));
+    total_sum += constraint_coefficients[30] * value;
+
+    // Constraint: final_fp.
+    let value = (column_row!(mask_values, 21, 8) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[18]));
+    total_sum += constraint_coefficients[31] * value;
+
+    // Constraint: final_pc.
+    let value = (column_row!(mask_values, 19, 0) - global_values.final_pc)
+        .field_div(&felt_nonzero!(domains[18]));
+    total_sum += constraint_coefficients[32] * value;
+
+    // Constraint: memory/multi_column_perm/perm/init0.
+    let value = ((global_values.memory_multi_column_perm_perm_interaction_elm
+        - (column_row!(mask_values, 20, 0)
+            + global_values.memory_multi_column_perm_hash_interaction_elm0
+                * column_row!(mask_values, 20, 1)))
+        * column_row!(mask_values, 24, 0)
+        + column_row!(mask_values, 19, 0)
+        + global_values.memory_multi_column_perm_hash_interaction_elm0
+            * column_row!(mask_values, 19, 1)
+        - global_values.memory_multi_column_perm_perm_interaction_elm)
+        .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[33] * value;
+
+    // Constraint: memory/multi_column_perm/perm/step0.
+    let value = ((global_values.memory_multi_column_perm_perm_interaction_elm
+        - (column_row!(mask_values, 20, 2)
+            + global_values.memory_multi_column_perm_hash_interaction_elm0
+                * column_row!(mask_values, 20, 3)))
+        * column_row!(mask_values, 24, 2)
+        - (global_values.memory_multi_column_perm_perm_interaction_elm
+            - (column_row!(mask_values, 19, 2)
+                + global_values.memory_multi_column_perm_hash_interaction_elm0
+                    * column_row!(mask_values, 19, 3)))
+            * column_row!(mask_values, 24, 0))
+        * domains[20].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[34] * value;
+
+    // Constraint: memory/multi_column_perm/perm/last.
+    let value = (column_row!(mask_values, 24, 0)
+        - global_values.memory_multi_column_perm_perm_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[20]));
+    total_sum += constraint_coefficients[35] * value;
+
+    // Constraint: memory/diff_is_bit.
+    let value = (memory_address_diff_0 * memory_address_diff_0 - memory_address_diff_0)
+        * domains[20].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[36] * value;
+
+    // Constraint: memory/is_func.
+    let value = ((memory_address_diff_0 - FELT_1)
+        * (column_row!(mask_values, 20, 1) - column_row!(mask_values, 20, 3)))
+        * domains[20].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[37] * value;
+
+    // Constraint: memory/initial_addr.
+    let value = (column_row!(mask_values, 20, 0) - FELT_1).field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[38] * value;
+
+    // Constraint: public_memory_addr_zero.
+    let value = (column_row!(mask_values, 19, 2)).field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[39] * value;
+
+    // Constraint: public_memory_value_zero.
+    let value = (column_row!(mask_values, 19, 3)).field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[40] * value;
+
+    // Constraint: range_check16/perm/init0.
+    let value = ((global_values.range_check16_perm_interaction_elm
+        - column_row!(mask_values, 2, 0))
+        * column_row!(mask_values, 23, 0)
+        + column_row!(mask_values, 0, 0)
+        - global_values.range_check16_perm_interaction_elm)
+        .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[41] * value;
+
+    // Constraint: range_check16/perm/step0.
+    let value = ((global_values.range_check16_perm_interaction_elm
+        - column_row!(mask_values, 2, 1))
+        * column_row!(mask_values, 23, 1)
+        - (global_values.range_check16_perm_interaction_elm - column_row!(mask_values, 0, 1))
+            * column_row!(mask_values, 23, 0))
+        * domains[21].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[42] * value;
+
+    // Constraint: range_check16/perm/last.
+    let value = (column_row!(mask_values, 23, 0)
+        - global_values.range_check16_perm_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[21]));
+    total_sum += constraint_coefficients[43] * value;
+
+    // Constraint: range_check16/diff_is_bit.
+    let value = (range_check16_diff_0 * range_check16_diff_0 - range_check16_diff_0)
+        * domains[21].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[44] * value;
+
+    // Constraint: range_check16/minimum.
+    let value = (column_row!(mask_values, 2, 0) - global_values.range_check_min)
+        .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[45] * value;
+
+    // Constraint: range_check16/maximum.
+    let value = (column_row!(mask_values, 2, 0) - global_values.range_check_max)
+        .field_div(&felt_nonzero!(domains[21]));
+    total_sum += constraint_coefficients[46] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/last_one_is_zero.
+    let value = (column_row!(mask_values, 16, 255)
+        * (column_row!(mask_values, 5, 0)
+            - (column_row!(mask_values, 5, 1) + column_row!(mask_values, 5, 1))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[47] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones0.
+    let value = (column_row!(mask_values, 16, 255)
+        * (column_row!(mask_values, 5, 1)
+            - FELT_3138550867693340381917894711603833208051177722232017256448
+                * column_row!(mask_values, 5, 192)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[48] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/cumulative_bit192.
+    let value = (column_row!(mask_values, 16, 255)
+        - column_row!(mask_values, 15, 255)
+            * (column_row!(mask_values, 5, 192)
+                - (column_row!(mask_values, 5, 193) + column_row!(mask_values, 5, 193))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[49] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones192.
+    let value = (column_row!(mask_values, 15, 255)
+        * (column_row!(mask_values, 5, 193) - FELT_8 * column_row!(mask_values, 5, 196)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[50] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/cumulative_bit196.
+    let value = (column_row!(mask_values, 15, 255)
+        - (column_row!(mask_values, 5, 251)
+            - (column_row!(mask_values, 5, 252) + column_row!(mask_values, 5, 252)))
+            * (column_row!(mask_values, 5, 196)
+                - (column_row!(mask_values, 5, 197) + column_row!(mask_values, 5, 197))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[51] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones196.
+    let value = ((column_row!(mask_values, 5, 251)
+        - (column_row!(mask_values, 5, 252) + column_row!(mask_values, 5, 252)))
+        * (column_row!(mask_values, 5, 197)
+            - FELT_18014398509481984 * column_row!(mask_values, 5, 251)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[52] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/booleanity_test.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (pedersen_hash0_ec_subset_sum_bit_0 - FELT_1))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[53] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_extraction_end.
+    let value = (column_row!(mask_values, 5, 0)).field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[54] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/zeros_tail.
+    let value = (column_row!(mask_values, 5, 0)).field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[55] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/slope.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 4, 0) - global_values.pedersen_points_y)
+        - column_row!(mask_values, 15, 0)
+            * (column_row!(mask_values, 3, 0) - global_values.pedersen_points_x))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[56] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/x.
+    let value = (column_row!(mask_values, 15, 0) * column_row!(mask_values, 15, 0)
+        - pedersen_hash0_ec_subset_sum_bit_0
+            * (column_row!(mask_values, 3, 0)
+                + global_values.pedersen_points_x
+                + column_row!(mask_values, 3, 1)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[57] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/y.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 4, 0) + column_row!(mask_values, 4, 1))
+        - column_row!(mask_values, 15, 0)
+            * (column_row!(mask_values, 3, 0) - column_row!(mask_values, 3, 1)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[58] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/copy_point/x.
+    let value = (pedersen_hash0_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 3, 1) - column_row!(mask_values, 3, 0)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[59] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/copy_point/y.
+    let value = (pedersen_hash0_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 4, 1) - column_row!(mask_values, 4, 0)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[60] * value;
+
+    // Constraint: pedersen/hash0/copy_point/x.
+    let value = (column_row!(mask_values, 3, 256) - column_row!(mask_values, 3, 255))
+        * domains[10].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[61] * value;
+
+    // Constraint: pedersen/hash0/copy_point/y.
+    let value = (column_row!(mask_values, 4, 256) - column_row!(mask_values, 4, 255))
+        * domains[10].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[62] * value;
+
+    // Constraint: pedersen/hash0/init/x.
+    let value = (column_row!(mask_values, 3, 0) - global_values.pedersen_shift_point.x)
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[63] * value;
+
+    // Constraint: pedersen/hash0/init/y.
+    let value = (column_row!(mask_values, 4, 0) - global_values.pedersen_shift_point.y)
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[64] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/bit_unpacking/last_one_is_zero.
+    let value = (column_row!(mask_values, 18, 255)
+        * (column_row!(mask_values, 8, 0)
+            - (column_row!(mask_values, 8, 1) + column_row!(mask_values, 8, 1))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[65] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/bit_unpacking/zeroes_between_ones0.
+    let value = (column_row!(mask_values, 18, 255)
+        * (column_row!(mask_values, 8, 1)
+            - FELT_3138550867693340381917894711603833208051177722232017256448
+                * column_row!(mask_values, 8, 192)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[66] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/bit_unpacking/cumulative_bit192.
+    let value = (column_row!(mask_values, 18, 255)
+        - column_row!(mask_values, 17, 255)
+            * (column_row!(mask_values, 8, 192)
+                - (column_row!(mask_values, 8, 193) + column_row!(mask_values, 8, 193))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[67] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/bit_unpacking/zeroes_between_ones192.
+    let value = (column_row!(mask_values, 17, 255)
+        * (column_row!(mask_values, 8, 193) - FELT_8 * column_row!(mask_values, 8, 196)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[68] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/bit_unpacking/cumulative_bit196.
+    let value = (column_row!(mask_values, 17, 255)
+        - (column_row!(mask_values, 8, 251)
+            - (column_row!(mask_values, 8, 252) + column_row!(mask_values, 8, 252)))
+            * (column_row!(mask_values, 8, 196)
+                - (column_row!(mask_values, 8, 197) + column_row!(mask_values, 8, 197))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[69] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/bit_unpacking/zeroes_between_ones196.
+    let value = ((column_row!(mask_values, 8, 251)
+        - (column_row!(mask_values, 8, 252) + column_row!(mask_values, 8, 252)))
+        * (column_row!(mask_values, 8, 197)
+            - FELT_18014398509481984 * column_row!(mask_values, 8, 251)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[70] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/booleanity_test.
+    let value = (pedersen_hash1_ec_subset_sum_bit_0
+        * (pedersen_hash1_ec_subset_sum_bit_0 - FELT_1))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[71] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/bit_extraction_end.
+    let value = (column_row!(mask_values, 8, 0)).field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[72] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/zeros_tail.
+    let value = (column_row!(mask_values, 8, 0)).field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[73] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/add_points/slope.
+    let value = (pedersen_hash1_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 7, 0) - global_values.pedersen_points_y)
+        - column_row!(mask_values, 16, 0)
+            * (column_row!(mask_values, 6, 0) - global_values.pedersen_points_x))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[74] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/add_points/x.
+    let value = (column_row!(mask_values, 16, 0) * column_row!(mask_values, 16, 0)
+        - pedersen_hash1_ec_subset_sum_bit_0
+            * (column_row!(mask_values, 6, 0)
+                + global_values.pedersen_points_x
+                + column_row!(mask_values, 6, 1)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[75] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/add_points/y.
+    let value = (pedersen_hash1_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 7, 0) + column_row!(mask_values, 7, 1))
+        - column_row!(mask_values, 16, 0)
+            * (column_row!(mask_values, 6, 0) - column_row!(mask_values, 6, 1)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[76] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/copy_point/x.
+    let value = (pedersen_hash1_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 6, 1) - column_row!(mask_values, 6, 0)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[77] * value;
+
+    // Constraint: pedersen/hash1/ec_subset_sum/copy_point/y.
+    let value = (pedersen_hash1_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 7, 1) - column_row!(mask_values, 7, 0)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[78] * value;
+
+    // Constraint: pedersen/hash1/copy_point/x.
+    let value = (column_row!(mask_values, 6, 256) - column_row!(mask_values, 6, 255))
+        * domains[10].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[79] * value;
+
+    // Constraint: pedersen/hash1/copy_point/y.
+    let value = (column_row!(mask_values, 7, 256) - column_row!(mask_values, 7, 255))
+        * domains[10].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[80] * value;
+
+    // Constraint: pedersen/hash1/init/x.
+    let value = (column_row!(mask_values, 6, 0) - global_values.pedersen_shift_point.x)
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[81] * value;
+
+    // Constraint: pedersen/hash1/init/y.
+    let value = (column_row!(mask_values, 7, 0) - global_values.pedersen_shift_point.y)
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[82] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/bit_unpacking/last_one_is_zero.
+    let value = (column_row!(mask_values, 22, 144)
+        * (column_row!(mask_values, 11, 0)
+            - (column_row!(mask_values, 11, 1) + column_row!(mask_values, 11, 1))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[83] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/bit_unpacking/zeroes_between_ones0.
+    let value = (column_row!(mask_values, 22, 144)
+        * (column_row!(mask_values, 11, 1)
+            - FELT_3138550867693340381917894711603833208051177722232017256448
+                * column_row!(mask_values, 11, 192)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[84] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/bit_unpacking/cumulative_bit192.
+    let value = (column_row!(mask_values, 22, 144)
+        - column_row!(mask_values, 22, 16)
+            * (column_row!(mask_values, 11, 192)
+                - (column_row!(mask_values, 11, 193) + column_row!(mask_values, 11, 193))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[85] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/bit_unpacking/zeroes_between_ones192.
+    let value = (column_row!(mask_values, 22, 16)
+        * (column_row!(mask_values, 11, 193) - FELT_8 * column_row!(mask_values, 11, 196)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[86] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/bit_unpacking/cumulative_bit196.
+    let value = (column_row!(mask_values, 22, 16)
+        - (column_row!(mask_values, 11, 251)
+            - (column_row!(mask_values, 11, 252) + column_row!(mask_values, 11, 252)))
+            * (column_row!(mask_values, 11, 196)
+                - (column_row!(mask_values, 11, 197) + column_row!(mask_values, 11, 197))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[87] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/bit_unpacking/zeroes_between_ones196.
+    let value = ((column_row!(mask_values, 11, 251)
+        - (column_row!(mask_values, 11, 252) + column_row!(mask_values, 11, 252)))
+        * (column_row!(mask_values, 11, 197)
+            - FELT_18014398509481984 * column_row!(mask_values, 11, 251)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[88] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/booleanity_test.
+    let value = (pedersen_hash2_ec_subset_sum_bit_0
+        * (pedersen_hash2_ec_subset_sum_bit_0 - FELT_1))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[89] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/bit_extraction_end.
+    let value = (column_row!(mask_values, 11, 0)).field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[90] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/zeros_tail.
+    let value = (column_row!(mask_values, 11, 0)).field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[91] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/add_points/slope.
+    let value = (pedersen_hash2_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 10, 0) - global_values.pedersen_points_y)
+        - column_row!(mask_values, 17, 0)
+            * (column_row!(mask_values, 9, 0) - global_values.pedersen_points_x))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[92] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/add_points/x.
+    let value = (column_row!(mask_values, 17, 0) * column_row!(mask_values, 17, 0)
+        - pedersen_hash2_ec_subset_sum_bit_0
+            * (column_row!(mask_values, 9, 0)
+                + global_values.pedersen_points_x
+                + column_row!(mask_values, 9, 1)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[93] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/add_points/y.
+    let value = (pedersen_hash2_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 10, 0) + column_row!(mask_values, 10, 1))
+        - column_row!(mask_values, 17, 0)
+            * (column_row!(mask_values, 9, 0) - column_row!(mask_values, 9, 1)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[94] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/copy_point/x.
+    let value = (pedersen_hash2_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 9, 1) - column_row!(mask_values, 9, 0)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[95] * value;
+
+    // Constraint: pedersen/hash2/ec_subset_sum/copy_point/y.
+    let value = (pedersen_hash2_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 10, 1) - column_row!(mask_values, 10, 0)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[96] * value;
+
+    // Constraint: pedersen/hash2/copy_point/x.
+    let value = (column_row!(mask_values, 9, 256) - column_row!(mask_values, 9, 255))
+        * domains[10].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[97] * value;
+
+    // Constraint: pedersen/hash2/copy_point/y.
+    let value = (column_row!(mask_values, 10, 256) - column_row!(mask_values, 10, 255))
+        * domains[10].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[98] * value;
+
+    // Constraint: pedersen/hash2/init/x.
+    let value = (column_row!(mask_values, 9, 0) - global_values.pedersen_shift_point.x)
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[99] * value;
+
+    // Constraint: pedersen/hash2/init/y.
+    let value = (column_row!(mask_values, 10, 0) - global_values.pedersen_shift_point.y)
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[100] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/bit_unpacking/last_one_is_zero.
+    let value = (column_row!(mask_values, 22, 208)
+        * (column_row!(mask_values, 14, 0)
+            - (column_row!(mask_values, 14, 1) + column_row!(mask_values, 14, 1))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[101] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/bit_unpacking/zeroes_between_ones0.
+    let value = (column_row!(mask_values, 22, 208)
+        * (column_row!(mask_values, 14, 1)
+            - FELT_3138550867693340381917894711603833208051177722232017256448
+                * column_row!(mask_values, 14, 192)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[102] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/bit_unpacking/cumulative_bit192.
+    let value = (column_row!(mask_values, 22, 208)
+        - column_row!(mask_values, 22, 80)
+            * (column_row!(mask_values, 14, 192)
+                - (column_row!(mask_values, 14, 193) + column_row!(mask_values, 14, 193))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[103] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/bit_unpacking/zeroes_between_ones192.
+    let value = (column_row!(mask_values, 22, 80)
+        * (column_row!(mask_values, 14, 193) - FELT_8 * column_row!(mask_values, 14, 196)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[104] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/bit_unpacking/cumulative_bit196.
+    let value = (column_row!(mask_values, 22, 80)
+        - (column_row!(mask_values, 14, 251)
+            - (column_row!(mask_values, 14, 252) + column_row!(mask_values, 14, 252)))
+            * (column_row!(mask_values, 14, 196)
+                - (column_row!(mask_values, 14, 197) + column_row!(mask_values, 14, 197))))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[105] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/bit_unpacking/zeroes_between_ones196.
+    let value = ((column_row!(mask_values, 14, 251)
+        - (column_row!(mask_values, 14, 252) + column_row!(mask_values, 14, 252)))
+        * (column_row!(mask_values, 14, 197)
+            - FELT_18014398509481984 * column_row!(mask_values, 14, 251)))
+    .field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[106] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/booleanity_test.
+    let value = (pedersen_hash3_ec_subset_sum_bit_0
+        * (pedersen_hash3_ec_subset_sum_bit_0 - FELT_1))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[107] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/bit_extraction_end.
+    let value = (column_row!(mask_values, 14, 0)).field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[108] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/zeros_tail.
+    let value = (column_row!(mask_values, 14, 0)).field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[109] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/add_points/slope.
+    let value = (pedersen_hash3_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 13, 0) - global_values.pedersen_points_y)
+        - column_row!(mask_values, 18, 0)
+            * (column_row!(mask_values, 12, 0) - global_values.pedersen_points_x))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[110] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/add_points/x.
+    let value = (column_row!(mask_values, 18, 0) * column_row!(mask_values, 18, 0)
+        - pedersen_hash3_ec_subset_sum_bit_0
+            * (column_row!(mask_values, 12, 0)
+                + global_values.pedersen_points_x
+                + column_row!(mask_values, 12, 1)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[111] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/add_points/y.
+    let value = (pedersen_hash3_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 13, 0) + column_row!(mask_values, 13, 1))
+        - column_row!(mask_values, 18, 0)
+            * (column_row!(mask_values, 12, 0) - column_row!(mask_values, 12, 1)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[112] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/copy_point/x.
+    let value = (pedersen_hash3_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 12, 1) - column_row!(mask_values, 12, 0)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[113] * value;
+
+    // Constraint: pedersen/hash3/ec_subset_sum/copy_point/y.
+    let value = (pedersen_hash3_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 13, 1) - column_row!(mask_values, 13, 0)))
+        * domains[8].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[114] * value;
+
+    // Constraint: pedersen/hash3/copy_point/x.
+    let value = (column_row!(mask_values, 12, 256) - column_row!(mask_values, 12, 255))
+        * domains[10].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[115] * value;
+
+    // Constraint: pedersen/hash3/copy_point/y.
+    let value = (column_row!(mask_values, 13, 256) - column_row!(mask_values, 13, 255))
+        * domains[10].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[116] * value;
+
+    // Constraint: pedersen/hash3/init/x.
+    let value = (column_row!(mask_values, 12, 0) - global_values.pedersen_shift_point.x)
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[117] * value;
+
+    // Constraint: pedersen/hash3/init/y.
+    let value = (column_row!(mask_values, 13, 0) - global_values.pedersen_shift_point.y)
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[118] * value;
+
+    // Constraint: pedersen/input0_value0.
+    let value = (column_row!(mask_values, 19, 7) - column_row!(mask_values, 5, 0))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[119] * value;
+
+    // Constraint: pedersen/input0_value1.
+    let value = (column_row!(mask_values, 19, 135) - column_row!(mask_values, 8, 0))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[120] * value;
+
+    // Constraint: pedersen/input0_value2.
+    let value = (column_row!(mask_values, 19, 263) - column_row!(mask_values, 11, 0))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[121] * value;
+
+    // Constraint: pedersen/input0_value3.
+    let value = (column_row!(mask_values, 19, 391) - column_row!(mask_values, 14, 0))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[122] * value;
+
+    // Constraint: pedersen/input0_addr.
+    let value = (column_row!(mask_values, 19, 134) - (column_row!(mask_values, 19, 38) + FELT_1))
+        * domains[22].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[123] * value;
+
+    // Constraint: pedersen/init_addr.
+    let value = (column_row!(mask_values, 19, 6) - global_values.initial_pedersen_addr)
+        .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[124] * value;
+
+    // Constraint: pedersen/input1_value0.
+    let value = (column_row!(mask_values, 19, 71) - column_row!(mask_values, 5, 256))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[125] * value;
+
+    // Constraint: pedersen/input1_value1.
+    let value = (column_row!(mask_values, 19, 199) - column_row!(mask_values, 8, 256))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[126] * value;
+
+    // Constraint: pedersen/input1_value2.
+    let value = (column_row!(mask_values, 19, 327) - column_row!(mask_values, 11, 256))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[127] * value;
+
+    // Constraint: pedersen/input1_value3.
+    let value = (column_row!(mask_values, 19, 455) - column_row!(mask_values, 14, 256))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[128] * value;
+
+    // Constraint: pedersen/input1_addr.
+    let value = (column_row!(mask_values, 19, 70) - (column_row!(mask_values, 19, 6) + FELT_1))
+        .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[129] * value;
+
+    // Constraint: pedersen/output_value0.
+    let value = (column_row!(mask_values, 19, 39) - column_row!(mask_values, 3, 511))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[130] * value;
+
+    // Constraint: pedersen/output_value1.
+    let value = (column_row!(mask_values, 19, 167) - column_row!(mask_values, 6, 511))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[131] * value;
+
+    // Constraint: pedersen/output_value2.
+    let value = (column_row!(mask_values, 19, 295) - column_row!(mask_values, 9, 511))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[132] * value;
+
+    // Constraint: pedersen/output_value3.
+    let value = (column_row!(mask_values, 19, 423) - column_row!(mask_values, 12, 511))
+        .field_div(&felt_nonzero!(domains[11]));
+    total_sum += constraint_coefficients[133] * value;
+
+    // Constraint: pedersen/output_addr.
+    let value = (column_row!(mask_values, 19, 38) - (column_row!(mask_values, 19, 70) + FELT_1))
+        .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[134] * value;
+
+    // Constraint: range_check_builtin/value.
+    let value = (range_check_builtin_value7_0 - column_row!(mask_values, 19, 103))
+        .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[135] * value;
+
+    // Constraint: range_check_builtin/addr_step.
+    let value = (column_row!(mask_values, 19, 230) - (column_row!(mask_values, 19, 102) + FELT_1))
+        * domains[22].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[136] * value;
+
+    // Constraint: range_check_builtin/init_addr.
+    let value = (column_row!(mask_values, 19, 102) - global_values.initial_range_check_addr)
+        .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[137] * value;
+
+    // Constraint: ecdsa/signature0/doubling_key/slope.
+    let value = (ecdsa_signature0_doubling_key_x_squared
+        + ecdsa_signature0_doubling_key_x_squared
+        + ecdsa_signature0_doubling_key_x_squared
+        + global_values.ecdsa_sig_config.alpha
+        - (column_row!(mask_values, 21, 14) + column_row!(mask_values, 21, 14))
+            * column_row!(mask_values, 21, 13))
+        * domains[12].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[138] * value;
+
+    // Constraint: ecdsa/signature0/doubling_key/x.
+    let value = (column_row!(mask_values, 21, 13) * column_row!(mask_values, 21, 13)
+        - (column_row!(mask_values, 21, 6)
+            + column_row!(mask_values, 21, 6)
+            + column_row!(mask_values, 21, 22)))
+        * domains[12].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[139] * value;
+
+    // Constraint: ecdsa/signature0/doubling_key/y.
+    let value = (column_row!(mask_values, 21, 14) + column_row!(mask_values, 21, 30)
+        - column_row!(mask_values, 21, 13)
+            * (column_row!(mask_values, 21, 6) - column_row!(mask_values, 21, 22)))
+        * domains[12].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[140] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/booleanity_test.
+    let value = (ecdsa_signature0_exponentiate_generator_bit_0
+        * (ecdsa_signature0_exponentiate_generator_bit_0 - FELT_1))
+        * domains[15].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[141] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/bit_extraction_end.
+    let value = (column_row!(mask_values, 21, 15)).field_div(&felt_nonzero!(domains[16]));
+    total_sum += constraint_coefficients[142] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/zeros_tail.
+    let value = (column_row!(mask_values, 21, 15)).field_div(&felt_nonzero!(domains[15]));
+    total_sum += constraint_coefficients[143] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/add_points/slope.
+    let value = (ecdsa_signature0_exponentiate_generator_bit_0
+        * (column_row!(mask_values, 21, 23) - global_values.ecdsa_generator_points_y)
+        - column_row!(mask_values, 21, 31)
+            * (column_row!(mask_values, 21, 7) - global_values.ecdsa_generator_points_x))
+        * domains[15].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[144] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/add_points/x.
+    let value = (column_row!(mask_values, 21, 31) * column_row!(mask_values, 21, 31)
+        - ecdsa_signature0_exponentiate_generator_bit_0
+            * (column_row!(mask_values, 21, 7)
+                + global_values.ecdsa_generator_points_x
+                + column_row!(mask_values, 21, 39)))
+        * domains[15].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[145] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/add_points/y.
+    let value = (ecdsa_signature0_exponentiate_generator_bit_0
+        * (column_row!(mask_values, 21, 23) + column_row!(mask_values, 21, 55))
+        - column_row!(mask_values, 21, 31)
+            * (column_row!(mask_values, 21, 7) - column_row!(mask_values, 21, 39)))
+        * domains[15].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[146] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/add_points/x_diff_inv.
+    let value = (column_row!(mask_values, 22, 0)
+        * (column_row!(mask_values, 21, 7) - global_values.ecdsa_generator_points_x)
+        - FELT_1)
+        * domains[15].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[147] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/copy_point/x.
+    let value = (ecdsa_signature0_exponentiate_generator_bit_neg_0
+        * (column_row!(mask_values, 21, 39) - column_row!(mask_values, 21, 7)))
+        * domains[15].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[148] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/copy_point/y.
+    let value = (ecdsa_signature0_exponentiate_generator_bit_neg_0
+        * (column_row!(mask_values, 21, 55) - column_row!(mask_values, 21, 23)))
+        * domains[15].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[149] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/booleanity_test.
+    let value = (ecdsa_signature0_exponentiate_key_bit_0
+        * (ecdsa_signature0_exponentiate_key_bit_0 - FELT_1))
+        * domains[12].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[150] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/bit_extraction_end.
+    let value = (column_row!(mask_values, 21, 5)).field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[151] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/zeros_tail.
+    let value = (column_row!(mask_values, 21, 5)).field_div(&felt_nonzero!(domains[12]));
+    total_sum += constraint_coefficients[152] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/add_points/slope.
+    let value = (ecdsa_signature0_exponentiate_key_bit_0
+        * (column_row!(mask_values, 21, 9) - column_row!(mask_values, 21, 14))
+        - column_row!(mask_values, 21, 3)
+            * (column_row!(mask_values, 21, 1) - column_row!(mask_values, 21, 6)))
+        * domains[12].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[153] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/add_points/x.
+    let value = (column_row!(mask_values, 21, 3) * column_row!(mask_values, 21, 3)
+        - ecdsa_signature0_exponentiate_key_bit_0
+            * (column_row!(mask_values, 21, 1)
+                + column_row!(mask_values, 21, 6)
+                + column_row!(mask_values, 21, 17)))
+        * domains[12].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[154] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/add_points/y.
+    let value = (ecdsa_signature0_exponentiate_key_bit_0
+        * (column_row!(mask_values, 21, 9) + column_row!(mask_values, 21, 25))
+        - column_row!(mask_values, 21, 3)
+            * (column_row!(mask_values, 21, 1) - column_row!(mask_values, 21, 17)))
+        * domains[12].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[155] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/add_points/x_diff_inv.
+    let value = (column_row!(mask_values, 21, 11)
+        * (column_row!(mask_values, 21, 1) - column_row!(mask_values, 21, 6))
+        - FELT_1)
+        * domains[12].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[156] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/copy_point/x.
+    let value = (ecdsa_signature0_exponentiate_key_bit_neg_0
+        * (column_row!(mask_values, 21, 17) - column_row!(mask_values, 21, 1)))
+        * domains[12].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[157] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/copy_point/y.
+    let value = (ecdsa_signature0_exponentiate_key_bit_neg_0
+        * (column_row!(mask_values, 21, 25) - column_row!(mask_values, 21, 9)))
+        * domains[12].field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[158] * value;
+
+    // Constraint: ecdsa/signature0/init_gen/x.
+    let value = (column_row!(mask_values, 21, 7) - global_values.ecdsa_sig_config.shift_point.x)
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[159] * value;
+
+    // Constraint: ecdsa/signature0/init_gen/y.
+    let value = (column_row!(mask_values, 21, 23) + global_values.ecdsa_sig_config.shift_point.y)
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[160] * value;
+
+    // Constraint: ecdsa/signature0/init_key/x.
+    let value = (column_row!(mask_values, 21, 1) - global_values.ecdsa_sig_config.shift_point.x)
+        .field_div(&felt_nonzero!(domains[14]));
+    total_sum += constraint_coefficients[161] * value;
+
+    // Constraint: ecdsa/signature0/init_key/y.
+    let value = (column_row!(mask_values, 21, 9) - global_values.ecdsa_sig_config.shift_point.y)
+        .field_div(&felt_nonzero!(domains[14]));
+    total_sum += constraint_coefficients[162] * value;
+
+    // Constraint: ecdsa/signature0/add_results/slope.
+    let value = (column_row!(mask_values, 21, 8183)
+        - (column_row!(mask_values, 21, 4089)
+            + column_row!(mask_values, 21, 8191)
+                * (column_row!(mask_values, 21, 8167) - column_row!(mask_values, 21, 4081))))
+    .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[163] * value;
+
+    // Constraint: ecdsa/signature0/add_results/x.
+    let value = (column_row!(mask_values, 21, 8191) * column_row!(mask_values, 21, 8191)
+        - (column_row!(mask_values, 21, 8167)
+            + column_row!(mask_values, 21, 4081)
+            + column_row!(mask_values, 21, 4102)))
+    .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[164] * value;
+
+    // Constraint: ecdsa/signature0/add_results/y.
+    let value = (column_row!(mask_values, 21, 8183) + column_row!(mask_values, 21, 4110)
+        - column_row!(mask_values, 21, 8191)
+            * (column_row!(mask_values, 21, 8167) - column_row!(mask_values, 21, 4102)))
+    .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[165] * value;
+
+    // Constraint: ecdsa/signature0/add_results/x_diff_inv.
+    let value = (column_row!(mask_values, 22, 8160)
+        * (column_row!(mask_values, 21, 8167) - column_row!(mask_values, 21, 4081))
+        - FELT_1)
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[166] * value;
+
+    // Constraint: ecdsa/signature0/extract_r/slope.
+    let value = (column_row!(mask_values, 21, 8185) + global_values.ecdsa_sig_config.shift_point.y
+        - column_row!(mask_values, 21, 4083)
+            * (column_row!(mask_values, 21, 8177) - global_values.ecdsa_sig_config.shift_point.x))
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[167] * value;
+
+    // Constraint: ecdsa/signature0/extract_r/x.
+    let value = (column_row!(mask_values, 21, 4083) * column_row!(mask_values, 21, 4083)
+        - (column_row!(mask_values, 21, 8177)
+            + global_values.ecdsa_sig_config.shift_point.x
+            + column_row!(mask_values, 21, 5)))
+    .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[168] * value;
+
+    // Constraint: ecdsa/signature0/extract_r/x_diff_inv.
+    let value = (column_row!(mask_values, 21, 8179)
+        * (column_row!(mask_values, 21, 8177) - global_values.ecdsa_sig_config.shift_point.x)
+        - FELT_1)
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[169] * value;
+
+    // Constraint: ecdsa/signature0/z_nonzero.
+    let value = (column_row!(mask_values, 21, 15) * column_row!(mask_values, 21, 4091) - FELT_1)
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[170] * value;
+
+    // Constraint: ecdsa/signature0/r_and_w_nonzero.
+    let value = (column_row!(mask_values, 21, 5) * column_row!(mask_values, 21, 4093) - FELT_1)
+        .field_div(&felt_nonzero!(domains[14]));
+    total_sum += constraint_coefficients[171] * value;
+
+    // Constraint: ecdsa/signature0/q_on_curve/x_squared.
+    let value = (column_row!(mask_values, 21, 8187)
+        - column_row!(mask_values, 21, 6) * column_row!(mask_values, 21, 6))
+    .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[172] * value;
+
+    // Constraint: ecdsa/signature0/q_on_curve/on_curve.
+    let value = (column_row!(mask_values, 21, 14) * column_row!(mask_values, 21, 14)
+        - (column_row!(mask_values, 21, 6) * column_row!(mask_values, 21, 8187)
+            + global_values.ecdsa_sig_config.alpha * column_row!(mask_values, 21, 6)
+            + global_values.ecdsa_sig_config.beta))
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[173] * value;
+
+    // Constraint: ecdsa/init_addr.
+    let value = (column_row!(mask_values, 19, 22) - global_values.initial_ecdsa_addr)
+        .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[174] * value;
+
+    // Constraint: ecdsa/message_addr.
+    let value = (column_row!(mask_values, 19, 4118) - (column_row!(mask_values, 19, 22) + FELT_1))
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[175] * value;
+
+    // Constraint: ecdsa/pubkey_addr.
+    let value = (column_row!(mask_values, 19, 8214)
+        - (column_row!(mask_values, 19, 4118) + FELT_1))
+        * domains[23].field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[176] * value;
+
+    // Constraint: ecdsa/message_value0.
+    let value = (column_row!(mask_values, 19, 4119) - column_row!(mask_values, 21, 15))
+        .field_div(&felt_nonzero!(domains[17]));
+    total_sum += constraint_coefficients[177] * value;
+
+    // Constraint: ecdsa/pubkey_value0.
+    let value = (column_row!(mask_values, 19, 23) - column_row!(mask_values, 21, 6))
+        .field_div(&felt_nonzero!(domains[17]));
+
+    total_sum += constraint_coefficients[178] * value;
+
+    total_sum
+}
+
+// Powers of the trace generator at the row offsets of the mask.
+pub fn eval_oods_powers(trace_generator: Felt, autogenerated_pows: &mut [Felt]) {
+    autogenerated_pows[0] = trace_generator.pow_felt(&(FELT_0));
+    autogenerated_pows[1] = trace_generator.pow_felt(&(FELT_8160));
+    autogenerated_pows[2] = trace_generator.pow_felt(&(FELT_4081));
+    autogenerated_pows[3] = trace_generator.pow_felt(&(FELT_1));
+    autogenerated_pows[4] = autogenerated_pows[3] * autogenerated_pows[3]; // pow(trace_generator, 2).
+    autogenerated_pows[5] = autogenerated_pows[2] * autogenerated_pows[4]; // pow(trace_generator, 4083).
+    autogenerated_pows[6] = autogenerated_pows[3] * autogenerated_pows[4]; // pow(trace_generator, 3).
+    autogenerated_pows[7] = autogenerated_pows[3] * autogenerated_pows[6]; // pow(trace_generator, 4).
+    autogenerated_pows[8] = autogenerated_pows[3] * autogenerated_pows[7]; // pow(trace_generator, 5).
+    autogenerated_pows[9] = autogenerated_pows[3] * autogenerated_pows[8]; // pow(trace_generator, 6).
+    autogenerated_pows[10] = autogenerated_pows[3] * autogenerated_pows[9]; // pow(trace_generator, 7).
+    autogenerated_pows[11] = autogenerated_pows[1] * autogenerated_pows[10]; // pow(trace_generator, 8167).
+    autogenerated_pows[12] = autogenerated_pows[3] * autogenerated_pows[10]; // pow(trace_generator, 8).
+    autogenerated_pows[13] = autogenerated_pows[2] * autogenerated_pows[12]; // pow(trace_generator, 4089).
+    autogenerated_pows[14] = autogenerated_pows[3] * autogenerated_pows[12]; // pow(trace_generator, 9).
+    autogenerated_pows[15] = autogenerated_pows[3] * autogenerated_pows[14]; // pow(trace_generator, 10).
+    autogenerated_pows[16] = autogenerated_pows[2] * autogenerated_pows[15]; // pow(trace_generator, 4091).
+    autogenerated_pows[17] = autogenerated_pows[3] * autogenerated_pows[15]; // pow(trace_generator, 11).
+    autogenerated_pows[18] = autogenerated_pows[3] * autogenerated_pows[17]; // pow(trace_generator, 12).
+    autogenerated_pows[19] = autogenerated_pows[3] * autogenerated_pows[18]; // pow(trace_generator, 13).
+    autogenerated_pows[20] = autogenerated_pows[3] * autogenerated_pows[19]; // pow(trace_generator, 14).
+    autogenerated_pows[21] = autogenerated_pows[3] * autogenerated_pows[20]; // pow(trace_generator, 15).
+    autogenerated_pows[22] = autogenerated_pows[2] * autogenerated_pows[18]; // pow(trace_generator, 4093).
+    autogenerated_pows[23] = autogenerated_pows[3] * autogenerated_pows[21]; // pow(trace_generator, 16).
+    autogenerated_pows[24] = autogenerated_pows[3] * autogenerated_pows[23]; // pow(trace_generator, 17).
+    autogenerated_pows[25] = autogenerated_pows[7] * autogenerated_pows[24]; // pow(trace_generator, 21).
+    autogenerated_pows[26] = autogenerated_pows[2] * autogenerated_pows[25]; // pow(trace_generator, 4102).
+    autogenerated_pows[27] = autogenerated_pows[1] * autogenerated_pows[24]; // pow(trace_generator, 8177).
+    autogenerated_pows[28] = autogenerated_pows[4] * autogenerated_pows[27]; // pow(trace_generator, 8179).
+    autogenerated_pows[29] = autogenerated_pows[12] * autogenerated_pows[26]; // pow(trace_generator, 4110).
+    autogenerated_pows[30] = autogenerated_pows[3] * autogenerated_pows[25]; // pow(trace_generator, 22).
+    autogenerated_pows[31] = autogenerated_pows[3] * autogenerated_pows[30]; // pow(trace_generator, 23).
+    autogenerated_pows[32] = autogenerated_pows[3] * autogenerated_pows[31]; // pow(trace_generator, 24).
+    autogenerated_pows[33] = autogenerated_pows[3] * autogenerated_pows[32]; // pow(trace_generator, 25).
+    autogenerated_pows[34] = autogenerated_pows[12] * autogenerated_pows[29]; // pow(trace_generator, 4118).
+    autogenerated_pows[35] = autogenerated_pows[1] * autogenerated_pows[31]; // pow(trace_generator, 8183).
+    autogenerated_pows[36] = autogenerated_pows[1] * autogenerated_pows[33]; // pow(trace_generator, 8185).
+    autogenerated_pows[37] = autogenerated_pows[4] * autogenerated_pows[36]; // pow(trace_generator, 8187).
+    autogenerated_pows[38] = autogenerated_pows[6] * autogenerated_pows[33]; // pow(trace_generator, 28).
+    autogenerated_pows[39] = autogenerated_pows[4] * autogenerated_pows[38]; // pow(trace_generator, 30).
+    autogenerated_pows[40] = autogenerated_pows[3] * autogenerated_pows[39]; // pow(trace_generator, 31).
+    autogenerated_pows[41] = autogenerated_pows[1] * autogenerated_pows[40]; // pow(trace_generator, 8191).
+    autogenerated_pows[42] = autogenerated_pows[10] * autogenerated_pows[40]; // pow(trace_generator, 38).
+    autogenerated_pows[43] = autogenerated_pows[2] * autogenerated_pows[42]; // pow(trace_generator, 4119).
+    autogenerated_pows[44] = autogenerated_pows[3] * autogenerated_pows[42]; // pow(trace_generator, 39).
+    autogenerated_pows[45] = autogenerated_pows[8] * autogenerated_pows[44]; // pow(trace_generator, 44).
+    autogenerated_pows[46] = autogenerated_pows[6] * autogenerated_pows[45]; // pow(trace_generator, 47).
+    autogenerated_pows[47] = autogenerated_pows[12] * autogenerated_pows[46]; // pow(trace_generator, 55).
+    autogenerated_pows[48] = autogenerated_pows[11] * autogenerated_pows[46]; // pow(trace_generator, 8214).
+    autogenerated_pows[49] = autogenerated_pows[8] * autogenerated_pows[47]; // pow(trace_generator, 60).
+    autogenerated_pows[50] = autogenerated_pows[15] * autogenerated_pows[49]; // pow(trace_generator, 70).
+    autogenerated_pows[51] = autogenerated_pows[3] * autogenerated_pows[50]; // pow(trace_generator, 71).
+    autogenerated_pows[52] = autogenerated_pows[8] * autogenerated_pows[51]; // pow(trace_generator, 76).
+    autogenerated_pows[53] = autogenerated_pows[7] * autogenerated_pows[52]; // pow(trace_generator, 80).
+    autogenerated_pows[54] = autogenerated_pows[18] * autogenerated_pows[53]; // pow(trace_generator, 92).
+    autogenerated_pows[55] = autogenerated_pows[15] * autogenerated_pows[54]; // pow(trace_generator, 102).
+    autogenerated_pows[56] = autogenerated_pows[3] * autogenerated_pows[55]; // pow(trace_generator, 103).
+    autogenerated_pows[57] = autogenerated_pows[8] * autogenerated_pows[56]; // pow(trace_generator, 108).
+    autogenerated_pows[58] = autogenerated_pows[23] * autogenerated_pows[57]; // pow(trace_generator, 124).
+    autogenerated_pows[59] = autogenerated_pows[15] * autogenerated_pows[58]; // pow(trace_generator, 134).
+    autogenerated_pows[60] = autogenerated_pows[3] * autogenerated_pows[59]; // pow(trace_generator, 135).
+    autogenerated_pows[61] = autogenerated_pows[14] * autogenerated_pows[60]; // pow(trace_generator, 144).
+    autogenerated_pows[62] = autogenerated_pows[31] * autogenerated_pows[61]; // pow(trace_generator, 167).
+    autogenerated_pows[63] = autogenerated_pows[33] * autogenerated_pows[62]; // pow(trace_generator, 192).
+    autogenerated_pows[64] = autogenerated_pows[3] * autogenerated_pows[63]; // pow(trace_generator, 193).
+    autogenerated_pows[65] = autogenerated_pows[6] * autogenerated_pows[64]; // pow(trace_generator, 196).
+    autogenerated_pows[66] = autogenerated_pows[3] * autogenerated_pows[65]; // pow(trace_generator, 197).
+    autogenerated_pows[67] = autogenerated_pows[4] * autogenerated_pows[66]; // pow(trace_generator, 199).
+    autogenerated_pows[68] = autogenerated_pows[14] * autogenerated_pows[67]; // pow(trace_generator, 208).
+    autogenerated_pows[69] = autogenerated_pows[30] * autogenerated_pows[68]; // pow(trace_generator, 230).
+    autogenerated_pows[70] = autogenerated_pows[25] * autogenerated_pows[69]; // pow(trace_generator, 251).
+    autogenerated_pows[71] = autogenerated_pows[3] * autogenerated_pows[70]; // pow(trace_generator, 252).
+    autogenerated_pows[72] = autogenerated_pows[6] * autogenerated_pows[71]; // pow(trace_generator, 255).
+    autogenerated_pows[73] = autogenerated_pows[3] * autogenerated_pows[72]; // pow(trace_generator, 256).
+    autogenerated_pows[74] = autogenerated_pows[72] * autogenerated_pows[73]; // pow(trace_generator, 511).
+    autogenerated_pows[75] = autogenerated_pows[44] * autogenerated_pows[73]; // pow(trace_generator, 295).
+    autogenerated_pows[76] = autogenerated_pows[10] * autogenerated_pows[73]; // pow(trace_generator, 263).
+    autogenerated_pows[77] = autogenerated_pows[63] * autogenerated_pows[76]; // pow(trace_generator, 455).
+    autogenerated_pows[78] = autogenerated_pows[62] * autogenerated_pows[73]; // pow(trace_generator, 423).
+    autogenerated_pows[79] = autogenerated_pows[60] * autogenerated_pows[73]; // pow(trace_generator, 391).
+    autogenerated_pows[80] = autogenerated_pows[51] * autogenerated_pows[73]; // pow(trace_generator, 327).
+}
+
+pub fn eval_oods_constraints(
+    column_values: &[Felt],
+    oods_values: &[Felt],
+    constraint_coefficients: &[Felt],
+    autogenerated_pows: &[Felt],
+    point: Felt,
+    oods_point: Felt,
+) -> Felt {
+    let column0 = column_values[0];
+    let column1 = column_values[1];
+    let column2 = column_values[2];
+    let column3 = column_values[3];
+    let column4 = column_values[4];
+    let column5 = column_values[5];
+    let column6 = column_values[6];
+    let column7 = column_values[7];
+    let column8 = column_values[8];
+    let column9 = column_values[9];
+    let column10 = column_values[10];
+    let column11 = column_values[11];
+    let column12 = column_values[12];
+    let column13 = column_values[13];
+    let column14 = column_values[14];
+    let column15 = column_values[15];
+    let column16 = column_values[16];
+    let column17 = column_values[17];
+    let column18 = column_values[18];
+    let column19 = column_values[19];
+    let column20 = column_values[20];
+    let column21 = column_values[21];
+    let column22 = column_values[22];
+    let column23 = column_values[23];
+    let column24 = column_values[24];
+
+    // Sum the OODS constraints on the trace polynomials.
+    let mut total_sum = FELT_0;
+
+    let value = (column0 - oods_values[0])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[0] * value;
+
+    let value = (column0 - oods_values[1])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[1] * value;
+
+    let value = (column0 - oods_values[2])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[2] * value;
+
+    let value = (column0 - oods_values[3])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[12] * oods_point));
+    total_sum += constraint_coefficients[3] * value;
+
+    let value = (column0 - oods_values[4])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[4] * value;
+
+    let value = (column0 - oods_values[5])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[38] * oods_point));
+    total_sum += constraint_coefficients[5] * value;
+
+    let value = (column0 - oods_values[6])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[45] * oods_point));
+    total_sum += constraint_coefficients[6] * value;
+
+    let value = (column0 - oods_values[7])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[49] * oods_point));
+    total_sum += constraint_coefficients[7] * value;
+
+    let value = (column0 - oods_values[8])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[52] * oods_point));
+    total_sum += constraint_coefficients[8] * value;
+
+    let value = (column0 - oods_values[9])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[54] * oods_point));
+    total_sum += constraint_coefficients[9] * value;
+
+    let value = (column0 - oods_values[10])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[57] * oods_point));
+    total_sum += constraint_coefficients[10] * value;
+
+    let value = (column0 - oods_values[11])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[58] * oods_point));
+    total_sum += constraint_coefficients[11] * value;
+
+    let value = (column1 - oods_values[12])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[12] * value;
+
+    let value = (column1 - oods_values[13])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[13] * value;
+
+    let value = (column1 - oods_values[14])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[14] * value;
+
+    let value = (column1 - oods_values[15])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[15] * value;
+
+    let value = (column1 - oods_values[16])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[16] * value;
+
+    let value = (column1 - oods_values[17])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[17] * value;
+
+    let value = (column1 - oods_values[18])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[9] * oods_point));
+    total_sum += constraint_coefficients[18] * value;
+
+    let value = (column1 - oods_values[19])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[10] * oods_point));
+    total_sum += constraint_coefficients[19] * value;
+
+    let value = (column1 - oods_values[20])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[12] * oods_point));
+    total_sum += constraint_coefficients[20] * value;
+
+    let value = (column1 - oods_values[21])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[21] * value;
+
+    let value = (column1 - oods_values[22])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[15] * oods_point));
+    total_sum += constraint_coefficients[22] * value;
+
+    let value = (column1 - oods_values[23])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[23] * value;
+
+    let value = (column1 - oods_values[24])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[24] * value;
+
+    let value = (column1 - oods_values[25])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[19] * oods_point));
+    total_sum += constraint_coefficients[25] * value;
+
+    let value = (column1 - oods_values[26])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[20] * oods_point));
+    total_sum += constraint_coefficients[26] * value;
+
+    let value = (column1 - oods_values[27])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[21] * oods_point));
+    total_sum += constraint_coefficients[27] * value;
+
+    let value = (column2 - oods_values[28])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[28] * value;
+
+    let value = (column2 - oods_values[29])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[29] * value;
+
+    let value = (column3 - oods_values[30])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[30] * value;
+
+    let value = (column3 - oods_values[31])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[31] * value;
+
+    let value = (column3 - oods_values[32])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[32] * value;
+
+    let value = (column3 - oods_values[33])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[33] * value;
+
+    let value = (column3 - oods_values[34])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[74] * oods_point));
+    total_sum += constraint_coefficients[34] * value;
+
+    let value = (column4 - oods_values[35])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[35] * value;
+
+    let value = (column4 - oods_values[36])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[36] * value;
+
+    let value = (column4 - oods_values[37])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[37] * value;
+
+    let value = (column4 - oods_values[38])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[38] * value;
+
+    let value = (column5 - oods_values[39])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[39] * value;
+
+    let value = (column5 - oods_values[40])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[40] * value;
+
+    let value = (column5 - oods_values[41])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[63] * oods_point));
+    total_sum += constraint_coefficients[41] * value;
+
+    let value = (column5 - oods_values[42])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[64] * oods_point));
+    total_sum += constraint_coefficients[42] * value;
+
+    let value = (column5 - oods_values[43])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[65] * oods_point));
+    total_sum += constraint_coefficients[43] * value;
+
+    let value = (column5 - oods_values[44])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[66] * oods_point));
+    total_sum += constraint_coefficients[44] * value;
+
+    let value = (column5 - oods_values[45])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[70] * oods_point));
+    total_sum += constraint_coefficients[45] * value;
+
+    let value = (column5 - oods_values[46])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[71] * oods_point));
+    total_sum += constraint_coefficients[46] * value;
+
+    let value = (column5 - oods_values[47])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[47] * value;
+
+    let value = (column6 - oods_values[48])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[48] * value;
+
+    let value = (column6 - oods_values[49])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[49] * value;
+
+    let value = (column6 - oods_values[50])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[50] * value;
+
+    let value = (column6 - oods_values[51])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[51] * value;
+
+    let value = (column6 - oods_values[52])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[74] * oods_point));
+    total_sum += constraint_coefficients[52] * value;
+
+    let value = (column7 - oods_values[53])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[53] * value;
+
+    let value = (column7 - oods_values[54])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[54] * value;
+
+    let value = (column7 - oods_values[55])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[55] * value;
+
+    let value = (column7 - oods_values[56])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[56] * value;
+
+    let value = (column8 - oods_values[57])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[57] * value;
+
+    let value = (column8 - oods_values[58])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[58] * value;
+
+    let value = (column8 - oods_values[59])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[63] * oods_point));
+    total_sum += constraint_coefficients[59] * value;
+
+    let value = (column8 - oods_values[60])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[64] * oods_point));
+    total_sum += constraint_coefficients[60] * value;
+
+    let value = (column8 - oods_values[61])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[65] * oods_point));
+    total_sum += constraint_coefficients[61] * value;
+
+    let value = (column8 - oods_values[62])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[66] * oods_point));
+    total_sum += constraint_coefficients[62] * value;
+
+    let value = (column8 - oods_values[63])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[70] * oods_point));
+    total_sum += constraint_coefficients[63] * value;
+
+    let value = (column8 - oods_values[64])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[71] * oods_point));
+    total_sum += constraint_coefficients[64] * value;
+
+    let value = (column8 - oods_values[65])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[65] * value;
+
+    let value = (column9 - oods_values[66])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[66] * value;
+
+    let value = (column9 - oods_values[67])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[67] * value;
+
+    let value = (column9 - oods_values[68])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[68] * value;
+
+    let value = (column9 - oods_values[69])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[69] * value;
+
+    let value = (column9 - oods_values[70])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[74] * oods_point));
+    total_sum += constraint_coefficients[70] * value;
+
+    let value = (column10 - oods_values[71])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[71] * value;
+
+    let value = (column10 - oods_values[72])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[72] * value;
+
+    let value = (column10 - oods_values[73])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[73] * value;
+
+    let value = (column10 - oods_values[74])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[74] * value;
+
+    let value = (column11 - oods_values[75])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[75] * value;
+
+    let value = (column11 - oods_values[76])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[76] * value;
+
+    let value = (column11 - oods_values[77])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[63] * oods_point));
+    total_sum += constraint_coefficients[77] * value;
+
+    let value = (column11 - oods_values[78])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[64] * oods_point));
+    total_sum += constraint_coefficients[78] * value;
+
+    let value = (column11 - oods_values[79])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[65] * oods_point));
+    total_sum += constraint_coefficients[79] * value;
+
+    let value = (column11 - oods_values[80])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[66] * oods_point));
+    total_sum += constraint_coefficients[80] * value;
+
+    let value = (column11 - oods_values[81])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[70] * oods_point));
+    total_sum += constraint_coefficients[81] * value;
+
+    let value = (column11 - oods_values[82])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[71] * oods_point));
+    total_sum += constraint_coefficients[82] * value;
+
+    let value = (column11 - oods_values[83])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[83] * value;
+
+    let value = (column12 - oods_values[84])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[84] * value;
+
+    let value = (column12 - oods_values[85])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[85] * value;
+
+    let value = (column12 - oods_values[86])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[86] * value;
+
+    let value = (column12 - oods_values[87])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[87] * value;
+
+    let value = (column12 - oods_values[88])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[74] * oods_point));
+    total_sum += constraint_coefficients[88] * value;
+
+    let value = (column13 - oods_values[89])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[89] * value;
+
+    let value = (column13 - oods_values[90])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[90] * value;
+
+    let value = (column13 - oods_values[91])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[91] * value;
+
+    let value = (column13 - oods_values[92])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[92] * value;
+
+    let value = (column14 - oods_values[93])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[93] * value;
+
+    let value = (column14 - oods_values[94])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[94] * value;
+
+    let value = (column14 - oods_values[95])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[63] * oods_point));
+    total_sum += constraint_coefficients[95] * value;
+
+    let value = (column14 - oods_values[96])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[64] * oods_point));
+    total_sum += constraint_coefficients[96] * value;
+
+    let value = (column14 - oods_values[97])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[65] * oods_point));
+    total_sum += constraint_coefficients[97] * value;
+
+    let value = (column14 - oods_values[98])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[66] * oods_point));
+    total_sum += constraint_coefficients[98] * value;
+
+    let value = (column14 - oods_values[99])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[70] * oods_point));
+    total_sum += constraint_coefficients[99] * value;
+
+    let value = (column14 - oods_values[100])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[71] * oods_point));
+    total_sum += constraint_coefficients[100] * value;
+
+    let value = (column14 - oods_values[101])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[101] * value;
+
+    let value = (column15 - oods_values[102])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[102] * value;
+
+    let value = (column15 - oods_values[103])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[103] * value;
+
+    let value = (column16 - oods_values[104])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[104] * value;
+
+    let value = (column16 - oods_values[105])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[105] * value;
+
+    let value = (column17 - oods_values[106])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[106] * value;
+
+    let value = (column17 - oods_values[107])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[107] * value;
+
+    let value = (column18 - oods_values[108])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[108] * value;
+
+    let value = (column18 - oods_values[109])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[109] * value;
+
+    let value = (column19 - oods_values[110])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[110] * value;
+
+    let value = (column19 - oods_values[111])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[111] * value;
+
+    let value = (column19 - oods_values[112])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[112] * value;
+
+    let value = (column19 - oods_values[113])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[113] * value;
+
+    let value = (column19 - oods_values[114])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[114] * value;
+
+    let value = (column19 - oods_values[115])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[115] * value;
+
+    let value = (column19 - oods_values[116])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[9] * oods_point));
+    total_sum += constraint_coefficients[116] * value;
+
+    let value = (column19 - oods_values[117])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[10] * oods_point));
+    total_sum += constraint_coefficients[117] * value;
+
+    let value = (column19 - oods_values[118])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[12] * oods_point));
+    total_sum += constraint_coefficients[118] * value;
+
+    let value = (column19 - oods_values[119])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[119] * value;
+
+    let value = (column19 - oods_values[120])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[120] * value;
+
+    let value = (column19 - oods_values[121])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[19] * oods_point));
+    total_sum += constraint_coefficients[121] * value;
+
+    let value = (column19 - oods_values[122])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[23] * oods_point));
+    total_sum += constraint_coefficients[122] * value;
+
+    let value = (column19 - oods_values[123])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[30] * oods_point));
+    total_sum += constraint_coefficients[123] * value;
+
+    let value = (column19 - oods_values[124])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[31] * oods_point));
+    total_sum += constraint_coefficients[124] * value;
+
+    let value = (column19 - oods_values[125])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[42] * oods_point));
+    total_sum += constraint_coefficients[125] * value;
+
+    let value = (column19 - oods_values[126])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[44] * oods_point));
+    total_sum += constraint_coefficients[126] * value;
+
+    let value = (column19 - oods_values[127])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[50] * oods_point));
+    total_sum += constraint_coefficients[127] * value;
+
+    let value = (column19 - oods_values[128])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[51] * oods_point));
+    total_sum += constraint_coefficients[128] * value;
+
+    let value = (column19 - oods_values[129])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[55] * oods_point));
+    total_sum += constraint_coefficients[129] * value;
+
+    let value = (column19 - oods_values[130])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[56] * oods_point));
+    total_sum += constraint_coefficients[130] * value;
+
+    let value = (column19 - oods_values[131])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[59] * oods_point));
+    total_sum += constraint_coefficients[131] * value;
+
+    let value = (column19 - oods_values[132])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[60] * oods_point));
+    total_sum += constraint_coefficients[132] * value;
+
+    let value = (column19 - oods_values[133])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[62] * oods_point));
+    total_sum += constraint_coefficients[133] * value;
+
+    let value = (column19 - oods_values[134])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[67] * oods_point));
+    total_sum += constraint_coefficients[134] * value;
+
+    let value = (column19 - oods_values[135])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[69] * oods_point));
+    total_sum += constraint_coefficients[135] * value;
+
+    let value = (column19 - oods_values[136])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[76] * oods_point));
+    total_sum += constraint_coefficients[136] * value;
+
+    let value = (column19 - oods_values[137])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[75] * oods_point));
+    total_sum += constraint_coefficients[137] * value;
+
+    let value = (column19 - oods_values[138])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[80] * oods_point));
+    total_sum += constraint_coefficients[138] * value;
+
+    let value = (column19 - oods_values[139])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[79] * oods_point));
+    total_sum += constraint_coefficients[139] * value;
+
+    let value = (column19 - oods_values[140])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[78] * oods_point));
+    total_sum += constraint_coefficients[140] * value;
+
+    let value = (column19 - oods_values[141])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[77] * oods_point));
+    total_sum += constraint_coefficients[141] * value;
+
+    let value = (column19 - oods_values[142])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[34] * oods_point));
+    total_sum += constraint_coefficients[142] * value;
+
+    let value = (column19 - oods_values[143])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[43] * oods_point));
+    total_sum += constraint_coefficients[143] * value;
+
+    let value = (column19 - oods_values[144])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[48] * oods_point));
+    total_sum += constraint_coefficients[144] * value;
+
+    let value = (column20 - oods_values[145])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[145] * value;
+
+    let value = (column20 - oods_values[146])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[146] * value;
+
+    let value = (column20 - oods_values[147])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[147] * value;
+
+    let value = (column20 - oods_values[148])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[148] * value;
+
+    let value = (column21 - oods_values[149])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[149] * value;
+
+    let value = (column21 - oods_values[150])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[150] * value;
+
+    let value = (column21 - oods_values[151])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[151] * value;
+
+    let value = (column21 - oods_values[152])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[152] * value;
+
+    let value = (column21 - oods_values[153])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[153] * value;
+
+    let value = (column21 - oods_values[154])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[154] * value;
+
+    let value = (column21 - oods_values[155])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[9] * oods_point));
+    total_sum += constraint_coefficients[155] * value;
+
+    let value = (column21 - oods_values[156])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[10] * oods_point));
+    total_sum += constraint_coefficients[156] * value;
+
+    let value = (column21 - oods_values[157])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[12] * oods_point));
+    total_sum += constraint_coefficients[157] * value;
+
+    let value = (column21 - oods_values[158])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[158] * value;
+
+    let value = (column21 - oods_values[159])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[15] * oods_point));
+    total_sum += constraint_coefficients[159] * value;
+
+    let value = (column21 - oods_values[160])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[160] * value;
+
+    let value = (column21 - oods_values[161])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[161] * value;
+
+    let value = (column21 - oods_values[162])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[19] * oods_point));
+    total_sum += constraint_coefficients[162] * value;
+
+    let value = (column21 - oods_values[163])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[20] * oods_point));
+    total_sum += constraint_coefficients[163] * value;
+
+    let value = (column21 - oods_values[164])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[21] * oods_point));
+    total_sum += constraint_coefficients[164] * value;
+
+    let value = (column21 - oods_values[165])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[23] * oods_point));
+    total_sum += constraint_coefficients[165] * value;
+
+    let value = (column21 - oods_values[166])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[24] * oods_point));
+    total_sum += constraint_coefficients[166] * value;
+
+    let value = (column21 - oods_values[167])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[25] * oods_point));
+    total_sum += constraint_coefficients[167] * value;
+
+    let value = (column21 - oods_values[168])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[30] * oods_point));
+    total_sum += constraint_coefficients[168] * value;
+
+    let value = (column21 - oods_values[169])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[31] * oods_point));
+    total_sum += constraint_coefficients[169] * value;
+
+    let value = (column21 - oods_values[170])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[32] * oods_point));
+    total_sum += constraint_coefficients[170] * value;
+
+    let value = (column21 - oods_values[171])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[33] * oods_point));
+    total_sum += constraint_coefficients[171] * value;
+
+    let value = (column21 - oods_values[172])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[39] * oods_point));
+    total_sum += constraint_coefficients[172] * value;
+
+    let value = (column21 - oods_values[173])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[40] * oods_point));
+    total_sum += constraint_coefficients[173] * value;
+
+    let value = (column21 - oods_values[174])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[44] * oods_point));
+    total_sum += constraint_coefficients[174] * value;
+
+    let value = (column21 - oods_values[175])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[46] * oods_point));
+    total_sum += constraint_coefficients[175] * value;
+
+    let value = (column21 - oods_values[176])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[47] * oods_point));
+    total_sum += constraint_coefficients[176] * value;
+
+    let value = (column21 - oods_values[177])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[2] * oods_point));
+    total_sum += constraint_coefficients[177] * value;
+
+    let value = (column21 - oods_values[178])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[178] * value;
+
+    let value = (column21 - oods_values[179])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[13] * oods_point));
+    total_sum += constraint_coefficients[179] * value;
+
+    let value = (column21 - oods_values[180])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[16] * oods_point));
+    total_sum += constraint_coefficients[180] * value;
+
+    let value = (column21 - oods_values[181])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[22] * oods_point));
+    total_sum += constraint_coefficients[181] * value;
+
+    let value = (column21 - oods_values[182])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[26] * oods_point));
+    total_sum += constraint_coefficients[182] * value;
+
+    let value = (column21 - oods_values[183])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[29] * oods_point));
+    total_sum += constraint_coefficients[183] * value;
+
+    let value = (column21 - oods_values[184])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[184] * value;
+
+    let value = (column21 - oods_values[185])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[27] * oods_point));
+    total_sum += constraint_coefficients[185] * value;
+
+    let value = (column21 - oods_values[186])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[28] * oods_point));
+    total_sum += constraint_coefficients[186] * value;
+
+    let value = (column21 - oods_values[187])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[35] * oods_point));
+    total_sum += constraint_coefficients[187] * value;
+
+    let value = (column21 - oods_values[188])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[36] * oods_point));
+    total_sum += constraint_coefficients[188] * value;
+
+    let value = (column21 - oods_values[189])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[37] * oods_point));
+    total_sum += constraint_coefficients[189] * value;
+
+    let value = (column21 - oods_values[190])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[41] * oods_point));
+    total_sum += constraint_coefficients[190] * value;
+
+    let value = (column22 - oods_values[191])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[191] * value;
+
+    let value = (column22 - oods_values[192])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[23] * oods_point));
+    total_sum += constraint_coefficients[192] * value;
+
+    let value = (column22 - oods_values[193])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[53] * oods_point));
+    total_sum += constraint_coefficients[193] * value;
+
+    let value = (column22 - oods_values[194])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[61] * oods_point));
+    total_sum += constraint_coefficients[194] * value;
+
+    let value = (column22 - oods_values[195])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[68] * oods_point));
+    total_sum += constraint_coefficients[195] * value;
+
+    let value = (column22 - oods_values[196])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[1] * oods_point));
+    total_sum += constraint_coefficients[196] * value;
+
+    let value = (column23 - oods_values[197])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[197] * value;
+
+    let value = (column23 - oods_values[198])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[198] * value;
+
+    let value = (column24 - oods_values[199])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[199] * value;
+
+    let value = (column24 - oods_values[200])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[200] * value;
+
+    // Sum the OODS boundary constraints on the composition polynomials.
+    let oods_point_to_deg = oods_point.pow_felt(&Felt::from(Layout::CONSTRAINT_DEGREE));
+
+    let value =
+        (column_values[25] - oods_values[201]).field_div(&felt_nonzero!(point - oods_point_to_deg));
+    total_sum += constraint_coefficients[201] * value;
+
+    let value =
+        (column_values[26] - oods_values[202]).field_div(&felt_nonzero!(point - oods_point_to_deg));
+    total_sum += constraint_coefficients[202] * value;
+
+    total_sum
+}
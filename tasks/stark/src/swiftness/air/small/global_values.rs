@@ -0,0 +1,78 @@
+use felt::Felt;
+use utils::global_values::{EcPoint, EcdsaSigConfig};
+use utils::layout::StoredGlobalValues;
+
+// Accumulation of member expressions for auto generated composition polynomial code.
+#[derive(Debug, PartialEq)]
+pub struct GlobalValues {
+    // Public input.
+    pub trace_length: Felt,
+    pub initial_pc: Felt,
+    pub final_pc: Felt,
+    pub initial_ap: Felt,
+    pub final_ap: Felt,
+    pub initial_pedersen_addr: Felt,
+    pub initial_range_check_addr: Felt,
+    pub initial_ecdsa_addr: Felt,
+    pub range_check_min: Felt,
+    pub range_check_max: Felt,
+    // Constants.
+    pub offset_size: Felt,
+    pub half_offset_size: Felt,
+    pub pedersen_shift_point: EcPoint,
+    pub ecdsa_sig_config: EcdsaSigConfig,
+    // Periodic columns.
+    pub pedersen_points_x: Felt,
+    pub pedersen_points_y: Felt,
+    pub ecdsa_generator_points_x: Felt,
+    pub ecdsa_generator_points_y: Felt,
+    // Interaction elements.
+    pub memory_multi_column_perm_perm_interaction_elm: Felt,
+    pub memory_multi_column_perm_hash_interaction_elm0: Felt,
+    pub range_check16_perm_interaction_elm: Felt,
+    // Permutation products.
+    pub memory_multi_column_perm_perm_public_memory_prod: Felt,
+    pub range_check16_perm_public_memory_prod: Felt,
+}
+
+impl Default for GlobalValues {
+    fn default() -> Self {
+        Self {
+            trace_length: Felt::ZERO,
+            initial_pc: Felt::ZERO,
+            final_pc: Felt::ZERO,
+            initial_ap: Felt::ZERO,
+            final_ap: Felt::ZERO,
+            initial_pedersen_addr: Felt::ZERO,
+            initial_range_check_addr: Felt::ZERO,
+            initial_ecdsa_addr: Felt::ZERO,
+            range_check_min: Felt::ZERO,
+            range_check_max: Felt::ZERO,
+            offset_size: Felt::ZERO,
+            half_offset_size: Felt::ZERO,
+            pedersen_shift_point: EcPoint {
+                x: Felt::ZERO,
+                y: Felt::ZERO,
+            },
+            ecdsa_sig_config: EcdsaSigConfig {
+                alpha: Felt::ZERO,
+                beta: Felt::ZERO,
+                shift_point: EcPoint {
+                    x: Felt::ZERO,
+                    y: Felt::ZERO,
+                },
+            },
+            pedersen_points_x: Felt::ZERO,
+            pedersen_points_y: Felt::ZERO,
+            ecdsa_generator_points_x: Felt::ZERO,
+            ecdsa_generator_points_y: Felt::ZERO,
+            memory_multi_column_perm_perm_interaction_elm: Felt::ZERO,
+            memory_multi_column_perm_hash_interaction_elm0: Felt::ZERO,
+            range_check16_perm_interaction_elm: Felt::ZERO,
+            memory_multi_column_perm_perm_public_memory_prod: Felt::ZERO,
+            range_check16_perm_public_memory_prod: Felt::ZERO,
+        }
+    }
+}
+
+unsafe impl StoredGlobalValues for GlobalValues {}
@@ -0,0 +1,3740 @@
+use super::{GlobalValues, Layout, LayoutTrait};
+use crate::swiftness::air::consts::*;
+use felt::felt_nonzero;
+use felt::Felt;
+
+// Maps the column_row notation of the constraints to the flat mask value indices, resolved
+// at expansion time so that an access outside of the mask does not compile.
+macro_rules! column_row {
+    ($mask_values:expr, 0, 0) => {
+        $mask_values[0]
+    };
+    ($mask_values:expr, 0, 1) => {
+        $mask_values[1]
+    };
+    ($mask_values:expr, 0, 2) => {
+        $mask_values[2]
+    };
+    ($mask_values:expr, 0, 3) => {
+        $mask_values[3]
+    };
+    ($mask_values:expr, 0, 4) => {
+        $mask_values[4]
+    };
+    ($mask_values:expr, 0, 5) => {
+        $mask_values[5]
+    };
+    ($mask_values:expr, 0, 6) => {
+        $mask_values[6]
+    };
+    ($mask_values:expr, 0, 7) => {
+        $mask_values[7]
+    };
+    ($mask_values:expr, 0, 8) => {
+        $mask_values[8]
+    };
+    ($mask_values:expr, 0, 9) => {
+        $mask_values[9]
+    };
+    ($mask_values:expr, 0, 10) => {
+        $mask_values[10]
+    };
+    ($mask_values:expr, 0, 11) => {
+        $mask_values[11]
+    };
+    ($mask_values:expr, 0, 12) => {
+        $mask_values[12]
+    };
+    ($mask_values:expr, 0, 13) => {
+        $mask_values[13]
+    };
+    ($mask_values:expr, 0, 14) => {
+        $mask_values[14]
+    };
+    ($mask_values:expr, 0, 15) => {
+        $mask_values[15]
+    };
+    ($mask_values:expr, 1, 0) => {
+        $mask_values[16]
+    };
+    ($mask_values:expr, 1, 1) => {
+        $mask_values[17]
+    };
+    ($mask_values:expr, 1, 255) => {
+        $mask_values[18]
+    };
+    ($mask_values:expr, 1, 256) => {
+        $mask_values[19]
+    };
+    ($mask_values:expr, 1, 511) => {
+        $mask_values[20]
+    };
+    ($mask_values:expr, 2, 0) => {
+        $mask_values[21]
+    };
+    ($mask_values:expr, 2, 1) => {
+        $mask_values[22]
+    };
+    ($mask_values:expr, 2, 255) => {
+        $mask_values[23]
+    };
+    ($mask_values:expr, 2, 256) => {
+        $mask_values[24]
+    };
+    ($mask_values:expr, 3, 0) => {
+        $mask_values[25]
+    };
+    ($mask_values:expr, 3, 1) => {
+        $mask_values[26]
+    };
+    ($mask_values:expr, 3, 192) => {
+        $mask_values[27]
+    };
+    ($mask_values:expr, 3, 193) => {
+        $mask_values[28]
+    };
+    ($mask_values:expr, 3, 196) => {
+        $mask_values[29]
+    };
+    ($mask_values:expr, 3, 197) => {
+        $mask_values[30]
+    };
+    ($mask_values:expr, 3, 251) => {
+        $mask_values[31]
+    };
+    ($mask_values:expr, 3, 252) => {
+        $mask_values[32]
+    };
+    ($mask_values:expr, 3, 256) => {
+        $mask_values[33]
+    };
+    ($mask_values:expr, 4, 0) => {
+        $mask_values[34]
+    };
+    ($mask_values:expr, 4, 255) => {
+        $mask_values[35]
+    };
+    ($mask_values:expr, 5, 0) => {
+        $mask_values[36]
+    };
+    ($mask_values:expr, 5, 1) => {
+        $mask_values[37]
+    };
+    ($mask_values:expr, 5, 2) => {
+        $mask_values[38]
+    };
+    ($mask_values:expr, 5, 3) => {
+        $mask_values[39]
+    };
+    ($mask_values:expr, 5, 4) => {
+        $mask_values[40]
+    };
+    ($mask_values:expr, 5, 5) => {
+        $mask_values[41]
+    };
+    ($mask_values:expr, 5, 6) => {
+        $mask_values[42]
+    };
+    ($mask_values:expr, 5, 7) => {
+        $mask_values[43]
+    };
+    ($mask_values:expr, 5, 8) => {
+        $mask_values[44]
+    };
+    ($mask_values:expr, 5, 9) => {
+        $mask_values[45]
+    };
+    ($mask_values:expr, 5, 12) => {
+        $mask_values[46]
+    };
+    ($mask_values:expr, 5, 13) => {
+        $mask_values[47]
+    };
+    ($mask_values:expr, 5, 16) => {
+        $mask_values[48]
+    };
+    ($mask_values:expr, 5, 38) => {
+        $mask_values[49]
+    };
+    ($mask_values:expr, 5, 39) => {
+        $mask_values[50]
+    };
+    ($mask_values:expr, 5, 70) => {
+        $mask_values[51]
+    };
+    ($mask_values:expr, 5, 71) => {
+        $mask_values[52]
+    };
+    ($mask_values:expr, 5, 102) => {
+        $mask_values[53]
+    };
+    ($mask_values:expr, 5, 103) => {
+        $mask_values[54]
+    };
+    ($mask_values:expr, 5, 134) => {
+        $mask_values[55]
+    };
+    ($mask_values:expr, 5, 135) => {
+        $mask_values[56]
+    };
+    ($mask_values:expr, 5, 166) => {
+        $mask_values[57]
+    };
+    ($mask_values:expr, 5, 167) => {
+        $mask_values[58]
+    };
+    ($mask_values:expr, 5, 198) => {
+        $mask_values[59]
+    };
+    ($mask_values:expr, 5, 199) => {
+        $mask_values[60]
+    };
+    ($mask_values:expr, 5, 262) => {
+        $mask_values[61]
+    };
+    ($mask_values:expr, 5, 263) => {
+        $mask_values[62]
+    };
+    ($mask_values:expr, 5, 294) => {
+        $mask_values[63]
+    };
+    ($mask_values:expr, 5, 295) => {
+        $mask_values[64]
+    };
+    ($mask_values:expr, 5, 326) => {
+        $mask_values[65]
+    };
+    ($mask_values:expr, 5, 358) => {
+        $mask_values[66]
+    };
+    ($mask_values:expr, 5, 359) => {
+        $mask_values[67]
+    };
+    ($mask_values:expr, 5, 390) => {
+        $mask_values[68]
+    };
+    ($mask_values:expr, 5, 391) => {
+        $mask_values[69]
+    };
+    ($mask_values:expr, 5, 422) => {
+        $mask_values[70]
+    };
+    ($mask_values:expr, 5, 423) => {
+        $mask_values[71]
+    };
+    ($mask_values:expr, 5, 454) => {
+        $mask_values[72]
+    };
+    ($mask_values:expr, 5, 518) => {
+        $mask_values[73]
+    };
+    ($mask_values:expr, 5, 711) => {
+        $mask_values[74]
+    };
+    ($mask_values:expr, 5, 902) => {
+        $mask_values[75]
+    };
+    ($mask_values:expr, 5, 903) => {
+        $mask_values[76]
+    };
+    ($mask_values:expr, 5, 966) => {
+        $mask_values[77]
+    };
+    ($mask_values:expr, 5, 967) => {
+        $mask_values[78]
+    };
+    ($mask_values:expr, 5, 1222) => {
+        $mask_values[79]
+    };
+    ($mask_values:expr, 5, 2438) => {
+        $mask_values[80]
+    };
+    ($mask_values:expr, 5, 2439) => {
+        $mask_values[81]
+    };
+    ($mask_values:expr, 5, 4486) => {
+        $mask_values[82]
+    };
+    ($mask_values:expr, 5, 4487) => {
+        $mask_values[83]
+    };
+    ($mask_values:expr, 5, 6534) => {
+        $mask_values[84]
+    };
+    ($mask_values:expr, 5, 6535) => {
+        $mask_values[85]
+    };
+    ($mask_values:expr, 5, 8582) => {
+        $mask_values[86]
+    };
+    ($mask_values:expr, 5, 8583) => {
+        $mask_values[87]
+    };
+    ($mask_values:expr, 5, 10630) => {
+        $mask_values[88]
+    };
+    ($mask_values:expr, 5, 10631) => {
+        $mask_values[89]
+    };
+    ($mask_values:expr, 5, 12678) => {
+        $mask_values[90]
+    };
+    ($mask_values:expr, 5, 12679) => {
+        $mask_values[91]
+    };
+    ($mask_values:expr, 5, 14726) => {
+        $mask_values[92]
+    };
+    ($mask_values:expr, 5, 14727) => {
+        $mask_values[93]
+    };
+    ($mask_values:expr, 5, 16774) => {
+        $mask_values[94]
+    };
+    ($mask_values:expr, 5, 16775) => {
+        $mask_values[95]
+    };
+    ($mask_values:expr, 5, 24966) => {
+        $mask_values[96]
+    };
+    ($mask_values:expr, 5, 33158) => {
+        $mask_values[97]
+    };
+    ($mask_values:expr, 6, 0) => {
+        $mask_values[98]
+    };
+    ($mask_values:expr, 6, 1) => {
+        $mask_values[99]
+    };
+    ($mask_values:expr, 6, 2) => {
+        $mask_values[100]
+    };
+    ($mask_values:expr, 6, 3) => {
+        $mask_values[101]
+    };
+    ($mask_values:expr, 7, 0) => {
+        $mask_values[102]
+    };
+    ($mask_values:expr, 7, 1) => {
+        $mask_values[103]
+    };
+    ($mask_values:expr, 7, 2) => {
+        $mask_values[104]
+    };
+    ($mask_values:expr, 7, 3) => {
+        $mask_values[105]
+    };
+    ($mask_values:expr, 7, 4) => {
+        $mask_values[106]
+    };
+    ($mask_values:expr, 7, 5) => {
+        $mask_values[107]
+    };
+    ($mask_values:expr, 7, 6) => {
+        $mask_values[108]
+    };
+    ($mask_values:expr, 7, 7) => {
+        $mask_values[109]
+    };
+    ($mask_values:expr, 7, 8) => {
+        $mask_values[110]
+    };
+    ($mask_values:expr, 7, 9) => {
+        $mask_values[111]
+    };
+    ($mask_values:expr, 7, 11) => {
+        $mask_values[112]
+    };
+    ($mask_values:expr, 7, 12) => {
+        $mask_values[113]
+    };
+    ($mask_values:expr, 7, 13) => {
+        $mask_values[114]
+    };
+    ($mask_values:expr, 7, 15) => {
+        $mask_values[115]
+    };
+    ($mask_values:expr, 7, 17) => {
+        $mask_values[116]
+    };
+    ($mask_values:expr, 7, 19) => {
+        $mask_values[117]
+    };
+    ($mask_values:expr, 7, 23) => {
+        $mask_values[118]
+    };
+    ($mask_values:expr, 7, 27) => {
+        $mask_values[119]
+    };
+    ($mask_values:expr, 7, 33) => {
+        $mask_values[120]
+    };
+    ($mask_values:expr, 7, 44) => {
+        $mask_values[121]
+    };
+    ($mask_values:expr, 7, 49) => {
+        $mask_values[122]
+    };
+    ($mask_values:expr, 7, 65) => {
+        $mask_values[123]
+    };
+    ($mask_values:expr, 7, 76) => {
+        $mask_values[124]
+    };
+    ($mask_values:expr, 7, 81) => {
+        $mask_values[125]
+    };
+    ($mask_values:expr, 7, 97) => {
+        $mask_values[126]
+    };
+    ($mask_values:expr, 7, 108) => {
+        $mask_values[127]
+    };
+    ($mask_values:expr, 7, 113) => {
+        $mask_values[128]
+    };
+    ($mask_values:expr, 7, 129) => {
+        $mask_values[129]
+    };
+    ($mask_values:expr, 7, 140) => {
+        $mask_values[130]
+    };
+    ($mask_values:expr, 7, 145) => {
+        $mask_values[131]
+    };
+    ($mask_values:expr, 7, 161) => {
+        $mask_values[132]
+    };
+    ($mask_values:expr, 7, 172) => {
+        $mask_values[133]
+    };
+    ($mask_values:expr, 7, 177) => {
+        $mask_values[134]
+    };
+    ($mask_values:expr, 7, 193) => {
+        $mask_values[135]
+    };
+    ($mask_values:expr, 7, 204) => {
+        $mask_values[136]
+    };
+    ($mask_values:expr, 7, 209) => {
+        $mask_values[137]
+    };
+    ($mask_values:expr, 7, 225) => {
+        $mask_values[138]
+    };
+    ($mask_values:expr, 7, 236) => {
+        $mask_values[139]
+    };
+    ($mask_values:expr, 7, 241) => {
+        $mask_values[140]
+    };
+    ($mask_values:expr, 7, 257) => {
+        $mask_values[141]
+    };
+    ($mask_values:expr, 7, 265) => {
+        $mask_values[142]
+    };
+    ($mask_values:expr, 7, 491) => {
+        $mask_values[143]
+    };
+    ($mask_values:expr, 7, 499) => {
+        $mask_values[144]
+    };
+    ($mask_values:expr, 7, 507) => {
+        $mask_values[145]
+    };
+    ($mask_values:expr, 7, 513) => {
+        $mask_values[146]
+    };
+    ($mask_values:expr, 7, 521) => {
+        $mask_values[147]
+    };
+    ($mask_values:expr, 7, 705) => {
+        $mask_values[148]
+    };
+    ($mask_values:expr, 7, 721) => {
+        $mask_values[149]
+    };
+    ($mask_values:expr, 7, 737) => {
+        $mask_values[150]
+    };
+    ($mask_values:expr, 7, 753) => {
+        $mask_values[151]
+    };
+    ($mask_values:expr, 7, 769) => {
+        $mask_values[152]
+    };
+    ($mask_values:expr, 7, 777) => {
+        $mask_values[153]
+    };
+    ($mask_values:expr, 7, 961) => {
+        $mask_values[154]
+    };
+    ($mask_values:expr, 7, 977) => {
+        $mask_values[155]
+    };
+    ($mask_values:expr, 7, 993) => {
+        $mask_values[156]
+    };
+    ($mask_values:expr, 7, 1009) => {
+        $mask_values[157]
+    };
+    ($mask_values:expr, 8, 0) => {
+        $mask_values[158]
+    };
+    ($mask_values:expr, 8, 1) => {
+        $mask_values[159]
+    };
+    ($mask_values:expr, 8, 2) => {
+        $mask_values[160]
+    };
+    ($mask_values:expr, 8, 3) => {
+        $mask_values[161]
+    };
+    ($mask_values:expr, 8, 4) => {
+        $mask_values[162]
+    };
+    ($mask_values:expr, 8, 5) => {
+        $mask_values[163]
+    };
+    ($mask_values:expr, 8, 6) => {
+        $mask_values[164]
+    };
+    ($mask_values:expr, 8, 7) => {
+        $mask_values[165]
+    };
+    ($mask_values:expr, 8, 8) => {
+        $mask_values[166]
+    };
+    ($mask_values:expr, 8, 9) => {
+        $mask_values[167]
+    };
+    ($mask_values:expr, 8, 10) => {
+        $mask_values[168]
+    };
+    ($mask_values:expr, 8, 11) => {
+        $mask_values[169]
+    };
+    ($mask_values:expr, 8, 12) => {
+        $mask_values[170]
+    };
+    ($mask_values:expr, 8, 13) => {
+        $mask_values[171]
+    };
+    ($mask_values:expr, 8, 14) => {
+        $mask_values[172]
+    };
+    ($mask_values:expr, 8, 16) => {
+        $mask_values[173]
+    };
+    ($mask_values:expr, 8, 17) => {
+        $mask_values[174]
+    };
+    ($mask_values:expr, 8, 19) => {
+        $mask_values[175]
+    };
+    ($mask_values:expr, 8, 21) => {
+        $mask_values[176]
+    };
+    ($mask_values:expr, 8, 22) => {
+        $mask_values[177]
+    };
+    ($mask_values:expr, 8, 24) => {
+        $mask_values[178]
+    };
+    ($mask_values:expr, 8, 25) => {
+        $mask_values[179]
+    };
+    ($mask_values:expr, 8, 27) => {
+        $mask_values[180]
+    };
+    ($mask_values:expr, 8, 29) => {
+        $mask_values[181]
+    };
+    ($mask_values:expr, 8, 30) => {
+        $mask_values[182]
+    };
+    ($mask_values:expr, 8, 33) => {
+        $mask_values[183]
+    };
+    ($mask_values:expr, 8, 35) => {
+        $mask_values[184]
+    };
+    ($mask_values:expr, 8, 37) => {
+        $mask_values[185]
+    };
+    ($mask_values:expr, 8, 38) => {
+        $mask_values[186]
+    };
+    ($mask_values:expr, 8, 41) => {
+        $mask_values[187]
+    };
+    ($mask_values:expr, 8, 43) => {
+        $mask_values[188]
+    };
+    ($mask_values:expr, 8, 45) => {
+        $mask_values[189]
+    };
+    ($mask_values:expr, 8, 46) => {
+        $mask_values[190]
+    };
+    ($mask_values:expr, 8, 49) => {
+        $mask_values[191]
+    };
+    ($mask_values:expr, 8, 51) => {
+        $mask_values[192]
+    };
+    ($mask_values:expr, 8, 53) => {
+        $mask_values[193]
+    };
+    ($mask_values:expr, 8, 54) => {
+        $mask_values[194]
+    };
+    ($mask_values:expr, 8, 57) => {
+        $mask_values[195]
+    };
+    ($mask_values:expr, 8, 59) => {
+        $mask_values[196]
+    };
+    ($mask_values:expr, 8, 61) => {
+        $mask_values[197]
+    };
+    ($mask_values:expr, 8, 65) => {
+        $mask_values[198]
+    };
+    ($mask_values:expr, 8, 69) => {
+        $mask_values[199]
+    };
+    ($mask_values:expr, 8, 71) => {
+        $mask_values[200]
+    };
+    ($mask_values:expr, 8, 73) => {
+        $mask_values[201]
+    };
+    ($mask_values:expr, 8, 77) => {
+        $mask_values[202]
+    };
+    ($mask_values:expr, 8, 81) => {
+        $mask_values[203]
+    };
+    ($mask_values:expr, 8, 85) => {
+        $mask_values[204]
+    };
+    ($mask_values:expr, 8, 89) => {
+        $mask_values[205]
+    };
+    ($mask_values:expr, 8, 91) => {
+        $mask_values[206]
+    };
+    ($mask_values:expr, 8, 97) => {
+        $mask_values[207]
+    };
+    ($mask_values:expr, 8, 101) => {
+        $mask_values[208]
+    };
+    ($mask_values:expr, 8, 105) => {
+        $mask_values[209]
+    };
+    ($mask_values:expr, 8, 109) => {
+        $mask_values[210]
+    };
+    ($mask_values:expr, 8, 113) => {
+        $mask_values[211]
+    };
+    ($mask_values:expr, 8, 117) => {
+        $mask_values[212]
+    };
+    ($mask_values:expr, 8, 123) => {
+        $mask_values[213]
+    };
+    ($mask_values:expr, 8, 155) => {
+        $mask_values[214]
+    };
+    ($mask_values:expr, 8, 187) => {
+        $mask_values[215]
+    };
+    ($mask_values:expr, 8, 195) => {
+        $mask_values[216]
+    };
+    ($mask_values:expr, 8, 205) => {
+        $mask_values[217]
+    };
+    ($mask_values:expr, 8, 219) => {
+        $mask_values[218]
+    };
+    ($mask_values:expr, 8, 221) => {
+        $mask_values[219]
+    };
+    ($mask_values:expr, 8, 237) => {
+        $mask_values[220]
+    };
+    ($mask_values:expr, 8, 245) => {
+        $mask_values[221]
+    };
+    ($mask_values:expr, 8, 253) => {
+        $mask_values[222]
+    };
+    ($mask_values:expr, 8, 269) => {
+        $mask_values[223]
+    };
+    ($mask_values:expr, 8, 301) => {
+        $mask_values[224]
+    };
+    ($mask_values:expr, 8, 309) => {
+        $mask_values[225]
+    };
+    ($mask_values:expr, 8, 310) => {
+        $mask_values[226]
+    };
+    ($mask_values:expr, 8, 318) => {
+        $mask_values[227]
+    };
+    ($mask_values:expr, 8, 326) => {
+        $mask_values[228]
+    };
+    ($mask_values:expr, 8, 334) => {
+        $mask_values[229]
+    };
+    ($mask_values:expr, 8, 342) => {
+        $mask_values[230]
+    };
+    ($mask_values:expr, 8, 350) => {
+        $mask_values[231]
+    };
+    ($mask_values:expr, 8, 451) => {
+        $mask_values[232]
+    };
+    ($mask_values:expr, 8, 461) => {
+        $mask_values[233]
+    };
+    ($mask_values:expr, 8, 477) => {
+        $mask_values[234]
+    };
+    ($mask_values:expr, 8, 493) => {
+        $mask_values[235]
+    };
+    ($mask_values:expr, 8, 501) => {
+        $mask_values[236]
+    };
+    ($mask_values:expr, 8, 509) => {
+        $mask_values[237]
+    };
+    ($mask_values:expr, 8, 12309) => {
+        $mask_values[238]
+    };
+    ($mask_values:expr, 8, 12373) => {
+        $mask_values[239]
+    };
+    ($mask_values:expr, 8, 12565) => {
+        $mask_values[240]
+    };
+    ($mask_values:expr, 8, 12629) => {
+        $mask_values[241]
+    };
+    ($mask_values:expr, 8, 16085) => {
+        $mask_values[242]
+    };
+    ($mask_values:expr, 8, 16149) => {
+        $mask_values[243]
+    };
+    ($mask_values:expr, 8, 16325) => {
+        $mask_values[244]
+    };
+    ($mask_values:expr, 8, 16331) => {
+        $mask_values[245]
+    };
+    ($mask_values:expr, 8, 16337) => {
+        $mask_values[246]
+    };
+    ($mask_values:expr, 8, 16339) => {
+        $mask_values[247]
+    };
+    ($mask_values:expr, 8, 16355) => {
+        $mask_values[248]
+    };
+    ($mask_values:expr, 8, 16357) => {
+        $mask_values[249]
+    };
+    ($mask_values:expr, 8, 16363) => {
+        $mask_values[250]
+    };
+    ($mask_values:expr, 8, 16369) => {
+        $mask_values[251]
+    };
+    ($mask_values:expr, 8, 16371) => {
+        $mask_values[252]
+    };
+    ($mask_values:expr, 8, 16385) => {
+        $mask_values[253]
+    };
+    ($mask_values:expr, 8, 16417) => {
+        $mask_values[254]
+    };
+    ($mask_values:expr, 8, 32647) => {
+        $mask_values[255]
+    };
+    ($mask_values:expr, 8, 32667) => {
+        $mask_values[256]
+    };
+    ($mask_values:expr, 8, 32715) => {
+        $mask_values[257]
+    };
+    ($mask_values:expr, 8, 32721) => {
+        $mask_values[258]
+    };
+    ($mask_values:expr, 8, 32731) => {
+        $mask_values[259]
+    };
+    ($mask_values:expr, 8, 32747) => {
+        $mask_values[260]
+    };
+    ($mask_values:expr, 8, 32753) => {
+        $mask_values[261]
+    };
+    ($mask_values:expr, 8, 32763) => {
+        $mask_values[262]
+    };
+    ($mask_values:expr, 9, 0) => {
+        $mask_values[263] // inter1_row0
+    };
+    ($mask_values:expr, 9, 1) => {
+        $mask_values[264] // inter1_row1
+    };
+    ($mask_values:expr, 9, 2) => {
+        $mask_values[265] // inter1_row2
+    };
+    ($mask_values:expr, 9, 3) => {
+        $mask_values[266] // inter1_row3
+    };
+    ($mask_values:expr, 9, 5) => {
+        $mask_values[267] // inter1_row5
+    };
+    ($mask_values:expr, 9, 7) => {
+        $mask_values[268] // inter1_row7
+    };
+    ($mask_values:expr, 9, 11) => {
+        $mask_values[269] // inter1_row11
+    };
+    ($mask_values:expr, 9, 15) => {
+        $mask_values[270] // inter1_row15
+    };
+}
+
+// Powers of the evaluation point and of the trace generator used by the constraint domains.
+pub fn eval_composition_powers(
+    point: Felt,
+    trace_generator: Felt,
+    trace_length: Felt,
+    autogenerated_pows: &mut [Felt],
+) {
+    autogenerated_pows[0] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_32768))));
+    autogenerated_pows[1] = autogenerated_pows[0] * autogenerated_pows[0]; // pow(point, (safe_div(global_values.trace_length, 16384))).
+    autogenerated_pows[2] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_1024))));
+    autogenerated_pows[3] = autogenerated_pows[2] * autogenerated_pows[2]; // pow(point, (safe_div(global_values.trace_length, 512))).
+    autogenerated_pows[4] = autogenerated_pows[3] * autogenerated_pows[3]; // pow(point, (safe_div(global_values.trace_length, 256))).
+    autogenerated_pows[5] = autogenerated_pows[4] * autogenerated_pows[4]; // pow(point, (safe_div(global_values.trace_length, 128))).
+    autogenerated_pows[6] = autogenerated_pows[5] * autogenerated_pows[5]; // pow(point, (safe_div(global_values.trace_length, 64))).
+    autogenerated_pows[7] = point.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_16))));
+    autogenerated_pows[8] = autogenerated_pows[7] * autogenerated_pows[7]; // pow(point, (safe_div(global_values.trace_length, 8))).
+    autogenerated_pows[9] = autogenerated_pows[8] * autogenerated_pows[8]; // pow(point, (safe_div(global_values.trace_length, 4))).
+    autogenerated_pows[10] = autogenerated_pows[9] * autogenerated_pows[9]; // pow(point, (safe_div(global_values.trace_length, 2))).
+    autogenerated_pows[11] = autogenerated_pows[10] * autogenerated_pows[10]; // pow(point, global_values.trace_length).
+    autogenerated_pows[12] = trace_generator.pow_felt(&(trace_length - FELT_16384));
+    autogenerated_pows[13] = trace_generator.pow_felt(&(trace_length - FELT_1024));
+    autogenerated_pows[14] = trace_generator.pow_felt(&(trace_length - FELT_32768));
+    autogenerated_pows[15] = trace_generator.pow_felt(&(trace_length - FELT_256));
+    autogenerated_pows[16] = trace_generator.pow_felt(&(trace_length - FELT_512));
+    autogenerated_pows[17] = trace_generator.pow_felt(&(trace_length - FELT_8));
+    autogenerated_pows[18] = trace_generator.pow_felt(&(trace_length - FELT_4));
+    autogenerated_pows[19] = trace_generator.pow_felt(&(trace_length - FELT_2));
+    autogenerated_pows[20] = trace_generator.pow_felt(&(trace_length - FELT_16));
+    autogenerated_pows[21] =
+        trace_generator.pow_felt(&((FELT_251 * trace_length).floor_div(&felt_nonzero!(FELT_256))));
+    autogenerated_pows[22] =
+        trace_generator.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_64))));
+    autogenerated_pows[23] = autogenerated_pows[22] * autogenerated_pows[22]; // pow(trace_generator, (safe_div(global_values.trace_length, 32))).
+    autogenerated_pows[24] = autogenerated_pows[22] * autogenerated_pows[23]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 64))).
+    autogenerated_pows[25] = autogenerated_pows[22] * autogenerated_pows[24]; // pow(trace_generator, (safe_div(global_values.trace_length, 16))).
+    autogenerated_pows[26] = autogenerated_pows[22] * autogenerated_pows[25]; // pow(trace_generator, (safe_div((safe_mult(5, global_values.trace_length)), 64))).
+    autogenerated_pows[27] = autogenerated_pows[22] * autogenerated_pows[26]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 32))).
+    autogenerated_pows[28] = autogenerated_pows[22] * autogenerated_pows[27]; // pow(trace_generator, (safe_div((safe_mult(7, global_values.trace_length)), 64))).
+    autogenerated_pows[29] = autogenerated_pows[22] * autogenerated_pows[28]; // pow(trace_generator, (safe_div(global_values.trace_length, 8))).
+    autogenerated_pows[30] = autogenerated_pows[22] * autogenerated_pows[29]; // pow(trace_generator, (safe_div((safe_mult(9, global_values.trace_length)), 64))).
+    autogenerated_pows[31] = autogenerated_pows[22] * autogenerated_pows[30]; // pow(trace_generator, (safe_div((safe_mult(5, global_values.trace_length)), 32))).
+    autogenerated_pows[32] = autogenerated_pows[22] * autogenerated_pows[31]; // pow(trace_generator, (safe_div((safe_mult(11, global_values.trace_length)), 64))).
+    autogenerated_pows[33] = autogenerated_pows[22] * autogenerated_pows[32]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 16))).
+    autogenerated_pows[34] = autogenerated_pows[22] * autogenerated_pows[33]; // pow(trace_generator, (safe_div((safe_mult(13, global_values.trace_length)), 64))).
+    autogenerated_pows[35] = autogenerated_pows[22] * autogenerated_pows[34]; // pow(trace_generator, (safe_div((safe_mult(7, global_values.trace_length)), 32))).
+    autogenerated_pows[36] = autogenerated_pows[22] * autogenerated_pows[35]; // pow(trace_generator, (safe_div((safe_mult(15, global_values.trace_length)), 64))).
+    autogenerated_pows[37] =
+        trace_generator.pow_felt(&(trace_length.floor_div(&felt_nonzero!(FELT_2))));
+    autogenerated_pows[38] = autogenerated_pows[27] * autogenerated_pows[37]; // pow(trace_generator, (safe_div((safe_mult(19, global_values.trace_length)), 32))).
+    autogenerated_pows[39] = autogenerated_pows[23] * autogenerated_pows[38]; // pow(trace_generator, (safe_div((safe_mult(5, global_values.trace_length)), 8))).
+    autogenerated_pows[40] = autogenerated_pows[23] * autogenerated_pows[39]; // pow(trace_generator, (safe_div((safe_mult(21, global_values.trace_length)), 32))).
+    autogenerated_pows[41] = autogenerated_pows[23] * autogenerated_pows[40]; // pow(trace_generator, (safe_div((safe_mult(11, global_values.trace_length)), 16))).
+    autogenerated_pows[42] = autogenerated_pows[23] * autogenerated_pows[41]; // pow(trace_generator, (safe_div((safe_mult(23, global_values.trace_length)), 32))).
+    autogenerated_pows[43] = autogenerated_pows[23] * autogenerated_pows[42]; // pow(trace_generator, (safe_div((safe_mult(3, global_values.trace_length)), 4))).
+    autogenerated_pows[44] = autogenerated_pows[23] * autogenerated_pows[43]; // pow(trace_generator, (safe_div((safe_mult(25, global_values.trace_length)), 32))).
+    autogenerated_pows[45] = autogenerated_pows[23] * autogenerated_pows[44]; // pow(trace_generator, (safe_div((safe_mult(13, global_values.trace_length)), 16))).
+    autogenerated_pows[46] = autogenerated_pows[23] * autogenerated_pows[45]; // pow(trace_generator, (safe_div((safe_mult(27, global_values.trace_length)), 32))).
+    autogenerated_pows[47] = autogenerated_pows[23] * autogenerated_pows[46]; // pow(trace_generator, (safe_div((safe_mult(7, global_values.trace_length)), 8))).
+    autogenerated_pows[48] = autogenerated_pows[23] * autogenerated_pows[47]; // pow(trace_generator, (safe_div((safe_mult(29, global_values.trace_length)), 32))).
+    autogenerated_pows[49] = autogenerated_pows[21] * autogenerated_pows[22]; // pow(trace_generator, (safe_div((safe_mult(255, global_values.trace_length)), 256))).
+    autogenerated_pows[50] = autogenerated_pows[23] * autogenerated_pows[48]; // pow(trace_generator, (safe_div((safe_mult(15, global_values.trace_length)), 16))).
+    autogenerated_pows[51] = autogenerated_pows[22] * autogenerated_pows[50]; // pow(trace_generator, (safe_div((safe_mult(61, global_values.trace_length)), 64))).
+    autogenerated_pows[52] = autogenerated_pows[22] * autogenerated_pows[51]; // pow(trace_generator, (safe_div((safe_mult(31, global_values.trace_length)), 32))).
+    autogenerated_pows[53] = autogenerated_pows[22] * autogenerated_pows[52]; // pow(trace_generator, (safe_div((safe_mult(63, global_values.trace_length)), 64))).
+}
+
+pub fn eval_composition_domains(point: Felt, autogenerated_pows: &[Felt], domains: &mut [Felt]) {
+    domains[0] = autogenerated_pows[11] - FELT_1;
+    domains[1] = autogenerated_pows[10] - FELT_1;
+    domains[2] = autogenerated_pows[9] - FELT_1;
+    domains[3] = autogenerated_pows[8] - FELT_1;
+    domains[4] = autogenerated_pows[7] - autogenerated_pows[50];
+    domains[5] = autogenerated_pows[7] - FELT_1;
+    domains[6] = autogenerated_pows[6] - FELT_1;
+    domains[7] = autogenerated_pows[5] - FELT_1;
+    domains[8] = autogenerated_pows[4] - FELT_1;
+    domains[9] = autogenerated_pows[4] - autogenerated_pows[49];
+    domains[10] = autogenerated_pows[4] - autogenerated_pows[53];
+    domains[11] = autogenerated_pows[4] - autogenerated_pows[43];
+    domains[12] = autogenerated_pows[3] - autogenerated_pows[37];
+    domains[13] = autogenerated_pows[3] - FELT_1;
+    domains[14] = autogenerated_pows[3] - autogenerated_pows[52];
+    let temp = autogenerated_pows[3] - autogenerated_pows[41];
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[42]);
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[43]);
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[44]);
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[45]);
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[46]);
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[47]);
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[48]);
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[50]);
+    domains[15] = temp * (domains[14]);
+    let temp = autogenerated_pows[3] - autogenerated_pows[51];
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[53]);
+    domains[16] = temp * (domains[14]);
+    let temp = autogenerated_pows[3] - autogenerated_pows[38];
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[39]);
+    let temp = temp * (autogenerated_pows[3] - autogenerated_pows[40]);
+    domains[17] = temp * (domains[15]);
+    domains[18] = autogenerated_pows[2] - autogenerated_pows[43];
+    domains[19] = autogenerated_pows[2] - FELT_1;
+    let temp = autogenerated_pows[2] - autogenerated_pows[22];
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[23]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[24]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[25]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[26]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[27]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[28]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[29]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[30]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[31]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[32]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[33]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[34]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[35]);
+    let temp = temp * (autogenerated_pows[2] - autogenerated_pows[36]);
+    domains[20] = temp * (domains[19]);
+    domains[21] = autogenerated_pows[1] - autogenerated_pows[49];
+    domains[22] = autogenerated_pows[1] - autogenerated_pows[21];
+    domains[23] = autogenerated_pows[1] - FELT_1;
+    domains[24] = autogenerated_pows[1] - autogenerated_pows[53];
+    domains[25] = autogenerated_pows[0] - autogenerated_pows[49];
+    domains[26] = autogenerated_pows[0] - autogenerated_pows[21];
+    domains[27] = autogenerated_pows[0] - FELT_1;
+    domains[28] = point - autogenerated_pows[20];
+    domains[29] = point - FELT_1;
+    domains[30] = point - autogenerated_pows[19];
+    domains[31] = point - autogenerated_pows[18];
+    domains[32] = point - autogenerated_pows[17];
+    domains[33] = point - autogenerated_pows[16];
+    domains[34] = point - autogenerated_pows[15];
+    domains[35] = point - autogenerated_pows[14];
+    domains[36] = point - autogenerated_pows[13];
+    domains[37] = point - autogenerated_pows[12];
+}
+
+pub fn eval_composition_constraints(
+    mask_values: &[Felt],
+    constraint_coefficients: &[Felt],
+    domains: &[Felt],
+    global_values: &GlobalValues,
+) -> Felt {
+    let cpu_decode_opcode_range_check_bit_0 = column_row!(mask_values, 0, 0)
+        - (column_row!(mask_values, 0, 1) + column_row!(mask_values, 0, 1));
+    let cpu_decode_opcode_range_check_bit_2 = column_row!(mask_values, 0, 2)
+        - (column_row!(mask_values, 0, 3) + column_row!(mask_values, 0, 3));
+    let cpu_decode_opcode_range_check_bit_4 = column_row!(mask_values, 0, 4)
+        - (column_row!(mask_values, 0, 5) + column_row!(mask_values, 0, 5));
+    let cpu_decode_opcode_range_check_bit_3 = column_row!(mask_values, 0, 3)
+        - (column_row!(mask_values, 0, 4) + column_row!(mask_values, 0, 4));
+    let cpu_decode_flag_op1_base_op0_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_2
+            + cpu_decode_opcode_range_check_bit_4
+            + cpu_decode_opcode_range_check_bit_3);
+    let cpu_decode_opcode_range_check_bit_5 = column_row!(mask_values, 0, 5)
+        - (column_row!(mask_values, 0, 6) + column_row!(mask_values, 0, 6));
+    let cpu_decode_opcode_range_check_bit_6 = column_row!(mask_values, 0, 6)
+        - (column_row!(mask_values, 0, 7) + column_row!(mask_values, 0, 7));
+    let cpu_decode_opcode_range_check_bit_9 = column_row!(mask_values, 0, 9)
+        - (column_row!(mask_values, 0, 10) + column_row!(mask_values, 0, 10));
+    let cpu_decode_flag_res_op1_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_5
+            + cpu_decode_opcode_range_check_bit_6
+            + cpu_decode_opcode_range_check_bit_9);
+    let cpu_decode_opcode_range_check_bit_7 = column_row!(mask_values, 0, 7)
+        - (column_row!(mask_values, 0, 8) + column_row!(mask_values, 0, 8));
+    let cpu_decode_opcode_range_check_bit_8 = column_row!(mask_values, 0, 8)
+        - (column_row!(mask_values, 0, 9) + column_row!(mask_values, 0, 9));
+    let cpu_decode_flag_pc_update_regular_0 = FELT_1
+        - (cpu_decode_opcode_range_check_bit_7
+            + cpu_decode_opcode_range_check_bit_8
+            + cpu_decode_opcode_range_check_bit_9);
+    let cpu_decode_opcode_range_check_bit_12 = column_row!(mask_values, 0, 12)
+        - (column_row!(mask_values, 0, 13) + column_row!(mask_values, 0, 13));
+    let cpu_decode_opcode_range_check_bit_13 = column_row!(mask_values, 0, 13)
+        - (column_row!(mask_values, 0, 14) + column_row!(mask_values, 0, 14));
+    let cpu_decode_fp_update_regular_0 =
+        FELT_1 - (cpu_decode_opcode_range_check_bit_12 + cpu_decode_opcode_range_check_bit_13);
+    let cpu_decode_opcode_range_check_bit_1 = column_row!(mask_values, 0, 1)
+        - (column_row!(mask_values, 0, 2) + column_row!(mask_values, 0, 2));
+    let npc_reg_0 = column_row!(mask_values, 5, 0) + cpu_decode_opcode_range_check_bit_2 + FELT_1;
+    let cpu_decode_opcode_range_check_bit_10 = column_row!(mask_values, 0, 10)
+        - (column_row!(mask_values, 0, 11) + column_row!(mask_values, 0, 11));
+    let cpu_decode_opcode_range_check_bit_11 = column_row!(mask_values, 0, 11)
+        - (column_row!(mask_values, 0, 12) + column_row!(mask_values, 0, 12));
+    let cpu_decode_opcode_range_check_bit_14 = column_row!(mask_values, 0, 14)
+        - (column_row!(mask_values, 0, 15) + column_row!(mask_values, 0, 15));
+    let memory_address_diff_0 = column_row!(mask_values, 6, 2) - column_row!(mask_values, 6, 0);
+    let range_check16_diff_0 = column_row!(mask_values, 7, 6) - column_row!(mask_values, 7, 2);
+    let pedersen_hash0_ec_subset_sum_bit_0 = column_row!(mask_values, 3, 0)
+        - (column_row!(mask_values, 3, 1) + column_row!(mask_values, 3, 1));
+    let pedersen_hash0_ec_subset_sum_bit_neg_0 = FELT_1 - pedersen_hash0_ec_subset_sum_bit_0;
+    let range_check_builtin_value0_0 = column_row!(mask_values, 7, 12);
+    let range_check_builtin_value1_0 =
+        range_check_builtin_value0_0 * global_values.offset_size + column_row!(mask_values, 7, 44);
+    let range_check_builtin_value2_0 =
+        range_check_builtin_value1_0 * global_values.offset_size + column_row!(mask_values, 7, 76);
+    let range_check_builtin_value3_0 =
+        range_check_builtin_value2_0 * global_values.offset_size + column_row!(mask_values, 7, 108);
+    let range_check_builtin_value4_0 =
+        range_check_builtin_value3_0 * global_values.offset_size + column_row!(mask_values, 7, 140);
+    let range_check_builtin_value5_0 =
+        range_check_builtin_value4_0 * global_values.offset_size + column_row!(mask_values, 7, 172);
+    let range_check_builtin_value6_0 =
+        range_check_builtin_value5_0 * global_values.offset_size + column_row!(mask_values, 7, 204);
+    let range_check_builtin_value7_0 =
+        range_check_builtin_value6_0 * global_values.offset_size + column_row!(mask_values, 7, 236);
+    let ecdsa_signature0_doubling_key_x_squared =
+        column_row!(mask_values, 8, 1) * column_row!(mask_values, 8, 1);
+    let ecdsa_signature0_exponentiate_generator_bit_0 = column_row!(mask_values, 8, 59)
+        - (column_row!(mask_values, 8, 187) + column_row!(mask_values, 8, 187));
+    let ecdsa_signature0_exponentiate_generator_bit_neg_0 =
+        FELT_1 - ecdsa_signature0_exponentiate_generator_bit_0;
+    let ecdsa_signature0_exponentiate_key_bit_0 = column_row!(mask_values, 8, 9)
+        - (column_row!(mask_values, 8, 73) + column_row!(mask_values, 8, 73));
+    let ecdsa_signature0_exponentiate_key_bit_neg_0 =
+        FELT_1 - ecdsa_signature0_exponentiate_key_bit_0;
+    let bitwise_sum_var_0_0 = column_row!(mask_values, 7, 1)
+        + column_row!(mask_values, 7, 17) * FELT_2
+        + column_row!(mask_values, 7, 33) * FELT_4
+        + column_row!(mask_values, 7, 49) * FELT_8
+        + column_row!(mask_values, 7, 65) * FELT_18446744073709551616
+        + column_row!(mask_values, 7, 81) * FELT_36893488147419103232
+        + column_row!(mask_values, 7, 97) * FELT_73786976294838206464
+        + column_row!(mask_values, 7, 113) * FELT_147573952589676412928;
+    let bitwise_sum_var_8_0 = column_row!(mask_values, 7, 129)
+        * FELT_340282366920938463463374607431768211456
+        + column_row!(mask_values, 7, 145) * FELT_680564733841876926926749214863536422912
+        + column_row!(mask_values, 7, 161) * FELT_1361129467683753853853498429727072845824
+        + column_row!(mask_values, 7, 177) * FELT_2722258935367507707706996859454145691648
+        + column_row!(mask_values, 7, 193)
+            * FELT_6277101735386680763835789423207666416102355444464034512896
+        + column_row!(mask_values, 7, 209)
+            * FELT_12554203470773361527671578846415332832204710888928069025792
+        + column_row!(mask_values, 7, 225)
+            * FELT_25108406941546723055343157692830665664409421777856138051584
+        + column_row!(mask_values, 7, 241)
+            * FELT_50216813883093446110686315385661331328818843555712276103168;
+    let ec_op_doubling_q_x_squared_0 =
+        column_row!(mask_values, 8, 41) * column_row!(mask_values, 8, 41);
+    let ec_op_ec_subset_sum_bit_0 = column_row!(mask_values, 8, 21)
+        - (column_row!(mask_values, 8, 85) + column_row!(mask_values, 8, 85));
+    let ec_op_ec_subset_sum_bit_neg_0 = FELT_1 - ec_op_ec_subset_sum_bit_0;
+    let poseidon_poseidon_full_rounds_state0_cubed_0 =
+        column_row!(mask_values, 8, 53) * column_row!(mask_values, 8, 29);
+    let poseidon_poseidon_full_rounds_state1_cubed_0 =
+        column_row!(mask_values, 8, 13) * column_row!(mask_values, 8, 61);
+    let poseidon_poseidon_full_rounds_state2_cubed_0 =
+        column_row!(mask_values, 8, 45) * column_row!(mask_values, 8, 3);
+    let poseidon_poseidon_full_rounds_state0_cubed_7 =
+        column_row!(mask_values, 8, 501) * column_row!(mask_values, 8, 477);
+    let poseidon_poseidon_full_rounds_state1_cubed_7 =
+        column_row!(mask_values, 8, 461) * column_row!(mask_values, 8, 509);
+    let poseidon_poseidon_full_rounds_state2_cubed_7 =
+        column_row!(mask_values, 8, 493) * column_row!(mask_values, 8, 451);
+    let poseidon_poseidon_full_rounds_state0_cubed_3 =
+        column_row!(mask_values, 8, 245) * column_row!(mask_values, 8, 221);
+    let poseidon_poseidon_full_rounds_state1_cubed_3 =
+        column_row!(mask_values, 8, 205) * column_row!(mask_values, 8, 253);
+    let poseidon_poseidon_full_rounds_state2_cubed_3 =
+        column_row!(mask_values, 8, 237) * column_row!(mask_values, 8, 195);
+    let poseidon_poseidon_partial_rounds_state0_cubed_0 =
+        column_row!(mask_values, 7, 3) * column_row!(mask_values, 7, 7);
+    let poseidon_poseidon_partial_rounds_state0_cubed_1 =
+        column_row!(mask_values, 7, 11) * column_row!(mask_values, 7, 15);
+    let poseidon_poseidon_partial_rounds_state0_cubed_2 =
+        column_row!(mask_values, 7, 19) * column_row!(mask_values, 7, 23);
+    let poseidon_poseidon_partial_rounds_state1_cubed_0 =
+        column_row!(mask_values, 8, 6) * column_row!(mask_values, 8, 14);
+    let poseidon_poseidon_partial_rounds_state1_cubed_1 =
+        column_row!(mask_values, 8, 22) * column_row!(mask_values, 8, 30);
+    let poseidon_poseidon_partial_rounds_state1_cubed_2 =
+        column_row!(mask_values, 8, 38) * column_row!(mask_values, 8, 46);
+    let poseidon_poseidon_partial_rounds_state1_cubed_19 =
+        column_row!(mask_values, 8, 310) * column_row!(mask_values, 8, 318);
+    let poseidon_poseidon_partial_rounds_state1_cubed_20 =
+        column_row!(mask_values, 8, 326) * column_row!(mask_values, 8, 334);
+    let poseidon_poseidon_partial_rounds_state1_cubed_21 =
+        column_row!(mask_values, 8, 342) * column_row!(mask_values, 8, 350);
+
+    let mut total_sum = FELT_0;
+
+    // Constraint: cpu/decode/opcode_range_check/bit.
+    let value = (cpu_decode_opcode_range_check_bit_0 * cpu_decode_opcode_range_check_bit_0
+        - cpu_decode_opcode_range_check_bit_0)
+        * domains[4].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[0] * value;
+
+    // Constraint: cpu/decode/opcode_range_check/zero.
+    let value = (column_row!(mask_values, 0, 0)).field_div(&felt_nonzero!(domains[4]));
+    total_sum += constraint_coefficients[1] * value;
+
+    // Constraint: cpu/decode/opcode_range_check_input.
+    let value = (column_row!(mask_values, 5, 1)
+        - (((column_row!(mask_values, 0, 0) * global_values.offset_size
+            + column_row!(mask_values, 7, 4))
+            * global_values.offset_size
+            + column_row!(mask_values, 7, 8))
+            * global_values.offset_size
+            + column_row!(mask_values, 7, 0)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[2] * value;
+
+    // Constraint: cpu/decode/flag_op1_base_op0_bit.
+    let value = (cpu_decode_flag_op1_base_op0_0 * cpu_decode_flag_op1_base_op0_0
+        - cpu_decode_flag_op1_base_op0_0)
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[3] * value;
+
+    // Constraint: cpu/decode/flag_res_op1_bit.
+    let value = (cpu_decode_flag_res_op1_0 * cpu_decode_flag_res_op1_0 - cpu_decode_flag_res_op1_0)
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[4] * value;
+
+    // Constraint: cpu/decode/flag_pc_update_regular_bit.
+    let value = (cpu_decode_flag_pc_update_regular_0 * cpu_decode_flag_pc_update_regular_0
+        - cpu_decode_flag_pc_update_regular_0)
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[5] * value;
+
+    // Constraint: cpu/decode/fp_update_regular_bit.
+    let value = (cpu_decode_fp_update_regular_0 * cpu_decode_fp_update_regular_0
+        - cpu_decode_fp_update_regular_0)
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[6] * value;
+
+    // Constraint: cpu/operands/mem_dst_addr.
+    let value = (column_row!(mask_values, 5, 8) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_0 * column_row!(mask_values, 8, 8)
+            + (FELT_1 - cpu_decode_opcode_range_check_bit_0) * column_row!(mask_values, 8, 0)
+            + column_row!(mask_values, 7, 0)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[7] * value;
+
+    // Constraint: cpu/operands/mem0_addr.
+    let value = (column_row!(mask_values, 5, 4) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_1 * column_row!(mask_values, 8, 8)
+            + (FELT_1 - cpu_decode_opcode_range_check_bit_1) * column_row!(mask_values, 8, 0)
+            + column_row!(mask_values, 7, 8)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[8] * value;
+
+    // Constraint: cpu/operands/mem1_addr.
+    let value = (column_row!(mask_values, 5, 12) + global_values.half_offset_size
+        - (cpu_decode_opcode_range_check_bit_2 * column_row!(mask_values, 5, 0)
+            + cpu_decode_opcode_range_check_bit_4 * column_row!(mask_values, 8, 0)
+            + cpu_decode_opcode_range_check_bit_3 * column_row!(mask_values, 8, 8)
+            + cpu_decode_flag_op1_base_op0_0 * column_row!(mask_values, 5, 5)
+            + column_row!(mask_values, 7, 4)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[9] * value;
+
+    // Constraint: cpu/operands/ops_mul.
+    let value = (column_row!(mask_values, 8, 4)
+        - column_row!(mask_values, 5, 5) * column_row!(mask_values, 5, 13))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[10] * value;
+
+    // Constraint: cpu/operands/res.
+    let value = ((FELT_1 - cpu_decode_opcode_range_check_bit_9) * column_row!(mask_values, 8, 12)
+        - (cpu_decode_opcode_range_check_bit_5
+            * (column_row!(mask_values, 5, 5) + column_row!(mask_values, 5, 13))
+            + cpu_decode_opcode_range_check_bit_6 * column_row!(mask_values, 8, 4)
+            + cpu_decode_flag_res_op1_0 * column_row!(mask_values, 5, 13)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[11] * value;
+
+    // Constraint: cpu/update_registers/update_pc/tmp0.
+    let value = (column_row!(mask_values, 8, 2)
+        - cpu_decode_opcode_range_check_bit_9 * column_row!(mask_values, 5, 9))
+        * domains[28].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[12] * value;
+
+    // Constraint: cpu/update_registers/update_pc/tmp1.
+    let value = (column_row!(mask_values, 8, 10)
+        - column_row!(mask_values, 8, 2) * column_row!(mask_values, 8, 12))
+        * domains[28].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[13] * value;
+
+    // Constraint: cpu/update_registers/update_pc/pc_cond_negative.
+    let value = ((FELT_1 - cpu_decode_opcode_range_check_bit_9) * column_row!(mask_values, 5, 16)
+        + column_row!(mask_values, 8, 2)
+            * (column_row!(mask_values, 5, 16)
+                - (column_row!(mask_values, 5, 0) + column_row!(mask_values, 5, 13)))
+        - (cpu_decode_flag_pc_update_regular_0 * npc_reg_0
+            + cpu_decode_opcode_range_check_bit_7 * column_row!(mask_values, 8, 12)
+            + cpu_decode_opcode_range_check_bit_8
+                * (column_row!(mask_values, 5, 0) + column_row!(mask_values, 8, 12))))
+        * domains[28].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[14] * value;
+
+    // Constraint: cpu/update_registers/update_pc/pc_cond_positive.
+    let value = ((column_row!(mask_values, 8, 10) - cpu_decode_opcode_range_check_bit_9)
+        * (column_row!(mask_values, 5, 16) - npc_reg_0))
+        * domains[28].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[15] * value;
+
+    // Constraint: cpu/update_registers/update_ap/ap_update.
+    let value = (column_row!(mask_values, 8, 16)
+        - (column_row!(mask_values, 8, 0)
+            + cpu_decode_opcode_range_check_bit_10 * column_row!(mask_values, 8, 12)
+            + cpu_decode_opcode_range_check_bit_11
+            + cpu_decode_opcode_range_check_bit_12 * FELT_2))
+        * domains[28].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[16] * value;
+
+    // Constraint: cpu/update_registers/update_fp/fp_update.
+    let value = (column_row!(mask_values, 8, 24)
+        - (cpu_decode_fp_update_regular_0 * column_row!(mask_values, 8, 8)
+            + cpu_decode_opcode_range_check_bit_13 * column_row!(mask_values, 5, 9)
+            + cpu_decode_opcode_range_check_bit_12 * (column_row!(mask_values, 8, 0) + FELT_2)))
+        * domains[28].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[17] * value;
+
+    // Constraint: cpu/opcodes/call/push_fp.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 5, 9) - column_row!(mask_values, 8, 8)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[18] * value;
+
+    // Constraint: cpu/opcodes/call/push_pc.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 5, 5)
+            - (column_row!(mask_values, 5, 0) + cpu_decode_opcode_range_check_bit_2 + FELT_1)))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[19] * value;
+
+    // Constraint: cpu/opcodes/call/off0.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 7, 0) - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[20] * value;
+
+    // Constraint: cpu/opcodes/call/off1.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (column_row!(mask_values, 7, 8) - (global_values.half_offset_size + FELT_1)))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[21] * value;
+
+    // Constraint: cpu/opcodes/call/flags.
+    let value = (cpu_decode_opcode_range_check_bit_12
+        * (cpu_decode_opcode_range_check_bit_12
+            + cpu_decode_opcode_range_check_bit_12
+            + FELT_1
+            + FELT_1
+            - (cpu_decode_opcode_range_check_bit_0
+                + cpu_decode_opcode_range_check_bit_1
+                + FELT_4)))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[22] * value;
+
+    // Constraint: cpu/opcodes/ret/off0.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (column_row!(mask_values, 7, 0) + FELT_2 - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[23] * value;
+
+    // Constraint: cpu/opcodes/ret/off2.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (column_row!(mask_values, 7, 4) + FELT_1 - global_values.half_offset_size))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[24] * value;
+
+    // Constraint: cpu/opcodes/ret/flags.
+    let value = (cpu_decode_opcode_range_check_bit_13
+        * (cpu_decode_opcode_range_check_bit_7
+            + cpu_decode_opcode_range_check_bit_0
+            + cpu_decode_opcode_range_check_bit_3
+            + cpu_decode_flag_res_op1_0
+            - FELT_4))
+        .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[25] * value;
+
+    // Constraint: cpu/opcodes/assert_eq/assert_eq.
+    let value = (cpu_decode_opcode_range_check_bit_14
+        * (column_row!(mask_values, 5, 9) - column_row!(mask_values, 8, 12)))
+    .field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[26] * value;
+
+    // Constraint: initial_ap.
+    let value = (column_row!(mask_values, 8, 0) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[27] * value;
+
+    // Constraint: initial_fp.
+    let value = (column_row!(mask_values, 8, 8) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[28] * value;
+
+    // Constraint: initial_pc.
+    let value = (column_row!(mask_values, 5, 0) - global_values.initial_pc)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[29] * value;
+
+    // Constraint: final_ap.
+    let value = (column_row!(mask_values, 8, 0) - global_values.final_ap)
+        .field_div(&felt_nonzero!(domains[28]));
+    total_sum += constraint_coefficients[30] * value;
+
+    // Constraint: final_fp.
+    let value = (column_row!(mask_values, 8, 8) - global_values.initial_ap)
+        .field_div(&felt_nonzero!(domains[28]));
+    total_sum += constraint_coefficients[31] * value;
+
+    // Constraint: final_pc.
+    let value = (column_row!(mask_values, 5, 0) - global_values.final_pc)
+        .field_div(&felt_nonzero!(domains[28]));
+    total_sum += constraint_coefficients[32] * value;
+
+    // Constraint: memory/multi_column_perm/perm/init0.
+    let value = ((global_values.memory_multi_column_perm_perm_interaction_elm
+        - (column_row!(mask_values, 6, 0)
+            + global_values.memory_multi_column_perm_hash_interaction_elm0
+                * column_row!(mask_values, 6, 1)))
+        * column_row!(mask_values, 9, 0)
+        + column_row!(mask_values, 5, 0)
+        + global_values.memory_multi_column_perm_hash_interaction_elm0
+            * column_row!(mask_values, 5, 1)
+        - global_values.memory_multi_column_perm_perm_interaction_elm)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[33] * value;
+
+    // Constraint: memory/multi_column_perm/perm/step0.
+    let value = ((global_values.memory_multi_column_perm_perm_interaction_elm
+        - (column_row!(mask_values, 6, 2)
+            + global_values.memory_multi_column_perm_hash_interaction_elm0
+                * column_row!(mask_values, 6, 3)))
+        * column_row!(mask_values, 9, 2)
+        - (global_values.memory_multi_column_perm_perm_interaction_elm
+            - (column_row!(mask_values, 5, 2)
+                + global_values.memory_multi_column_perm_hash_interaction_elm0
+                    * column_row!(mask_values, 5, 3)))
+            * column_row!(mask_values, 9, 0))
+        * domains[30].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[34] * value;
+
+    // Constraint: memory/multi_column_perm/perm/last.
+    let value = (column_row!(mask_values, 9, 0)
+        - global_values.memory_multi_column_perm_perm_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[30]));
+    total_sum += constraint_coefficients[35] * value;
+
+    // Constraint: memory/diff_is_bit.
+    let value = (memory_address_diff_0 * memory_address_diff_0 - memory_address_diff_0)
+        * domains[30].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[36] * value;
+
+    // Constraint: memory/is_func.
+    let value = ((memory_address_diff_0 - FELT_1)
+        * (column_row!(mask_values, 6, 1) - column_row!(mask_values, 6, 3)))
+        * domains[30].field_div(&felt_nonzero!(domains[1]));
+    total_sum += constraint_coefficients[37] * value;
+
+    // Constraint: memory/initial_addr.
+    let value = (column_row!(mask_values, 6, 0) - FELT_1).field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[38] * value;
+
+    // Constraint: public_memory_addr_zero.
+    let value = (column_row!(mask_values, 5, 2)).field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[39] * value;
+
+    // Constraint: public_memory_value_zero.
+    let value = (column_row!(mask_values, 5, 3)).field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[40] * value;
+
+    // Constraint: range_check16/perm/init0.
+    let value = ((global_values.range_check16_perm_interaction_elm
+        - column_row!(mask_values, 7, 2))
+        * column_row!(mask_values, 9, 1)
+        + column_row!(mask_values, 7, 0)
+        - global_values.range_check16_perm_interaction_elm)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[41] * value;
+
+    // Constraint: range_check16/perm/step0.
+    let value = ((global_values.range_check16_perm_interaction_elm
+        - column_row!(mask_values, 7, 6))
+        * column_row!(mask_values, 9, 5)
+        - (global_values.range_check16_perm_interaction_elm - column_row!(mask_values, 7, 4))
+            * column_row!(mask_values, 9, 1))
+        * domains[31].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[42] * value;
+
+    // Constraint: range_check16/perm/last.
+    let value = (column_row!(mask_values, 9, 1)
+        - global_values.range_check16_perm_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[31]));
+    total_sum += constraint_coefficients[43] * value;
+
+    // Constraint: range_check16/diff_is_bit.
+    let value = (range_check16_diff_0 * range_check16_diff_0 - range_check16_diff_0)
+        * domains[31].field_div(&felt_nonzero!(domains[2]));
+    total_sum += constraint_coefficients[44] * value;
+
+    // Constraint: range_check16/minimum.
+    let value = (column_row!(mask_values, 7, 2) - global_values.range_check_min)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[45] * value;
+
+    // Constraint: range_check16/maximum.
+    let value = (column_row!(mask_values, 7, 2) - global_values.range_check_max)
+        .field_div(&felt_nonzero!(domains[31]));
+    total_sum += constraint_coefficients[46] * value;
+
+    // Constraint: diluted_check/permutation/init0.
+    let value = ((global_values.diluted_check_permutation_interaction_elm
+        - column_row!(mask_values, 7, 5))
+        * column_row!(mask_values, 9, 7)
+        + column_row!(mask_values, 7, 1)
+        - global_values.diluted_check_permutation_interaction_elm)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[47] * value;
+
+    // Constraint: diluted_check/permutation/step0.
+    let value = ((global_values.diluted_check_permutation_interaction_elm
+        - column_row!(mask_values, 7, 13))
+        * column_row!(mask_values, 9, 15)
+        - (global_values.diluted_check_permutation_interaction_elm
+            - column_row!(mask_values, 7, 9))
+            * column_row!(mask_values, 9, 7))
+        * domains[32].field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[48] * value;
+
+    // Constraint: diluted_check/permutation/last.
+    let value = (column_row!(mask_values, 9, 7)
+        - global_values.diluted_check_permutation_public_memory_prod)
+        .field_div(&felt_nonzero!(domains[32]));
+    total_sum += constraint_coefficients[49] * value;
+
+    // Constraint: diluted_check/init.
+    let value = (column_row!(mask_values, 9, 3) - FELT_1).field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[50] * value;
+
+    // Constraint: diluted_check/first_element.
+    let value = (column_row!(mask_values, 7, 5) - global_values.diluted_check_first_elm)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[51] * value;
+
+    // Constraint: diluted_check/step.
+    let value = (column_row!(mask_values, 9, 11)
+        - (column_row!(mask_values, 9, 3)
+            * (FELT_1
+                + global_values.diluted_check_interaction_z
+                    * (column_row!(mask_values, 7, 13) - column_row!(mask_values, 7, 5)))
+            + global_values.diluted_check_interaction_alpha
+                * (column_row!(mask_values, 7, 13) - column_row!(mask_values, 7, 5))
+                * (column_row!(mask_values, 7, 13) - column_row!(mask_values, 7, 5))))
+        * domains[32].field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[52] * value;
+
+    // Constraint: diluted_check/last.
+    let value = (column_row!(mask_values, 9, 3) - global_values.diluted_check_final_cum_val)
+        .field_div(&felt_nonzero!(domains[32]));
+    total_sum += constraint_coefficients[53] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/last_one_is_zero.
+    let value = (column_row!(mask_values, 8, 71)
+        * (column_row!(mask_values, 3, 0)
+            - (column_row!(mask_values, 3, 1) + column_row!(mask_values, 3, 1))))
+    .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[54] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones0.
+    let value = (column_row!(mask_values, 8, 71)
+        * (column_row!(mask_values, 3, 1)
+            - FELT_3138550867693340381917894711603833208051177722232017256448
+                * column_row!(mask_values, 3, 192)))
+    .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[55] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/cumulative_bit192.
+    let value = (column_row!(mask_values, 8, 71)
+        - column_row!(mask_values, 4, 255)
+            * (column_row!(mask_values, 3, 192)
+                - (column_row!(mask_values, 3, 193) + column_row!(mask_values, 3, 193))))
+    .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[56] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones192.
+    let value = (column_row!(mask_values, 4, 255)
+        * (column_row!(mask_values, 3, 193) - FELT_8 * column_row!(mask_values, 3, 196)))
+    .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[57] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/cumulative_bit196.
+    let value = (column_row!(mask_values, 4, 255)
+        - (column_row!(mask_values, 3, 251)
+            - (column_row!(mask_values, 3, 252) + column_row!(mask_values, 3, 252)))
+            * (column_row!(mask_values, 3, 196)
+                - (column_row!(mask_values, 3, 197) + column_row!(mask_values, 3, 197))))
+    .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[58] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_unpacking/zeroes_between_ones196.
+    let value = ((column_row!(mask_values, 3, 251)
+        - (column_row!(mask_values, 3, 252) + column_row!(mask_values, 3, 252)))
+        * (column_row!(mask_values, 3, 197)
+            - FELT_18014398509481984 * column_row!(mask_values, 3, 251)))
+    .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[59] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/booleanity_test.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (pedersen_hash0_ec_subset_sum_bit_0 - FELT_1))
+        * domains[9].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[60] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/bit_extraction_end.
+    let value = (column_row!(mask_values, 3, 0)).field_div(&felt_nonzero!(domains[10]));
+    total_sum += constraint_coefficients[61] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/zeros_tail.
+    let value = (column_row!(mask_values, 3, 0)).field_div(&felt_nonzero!(domains[9]));
+    total_sum += constraint_coefficients[62] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/slope.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 2, 0) - global_values.pedersen_points_y)
+        - column_row!(mask_values, 4, 0)
+            * (column_row!(mask_values, 1, 0) - global_values.pedersen_points_x))
+        * domains[9].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[63] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/x.
+    let value = (column_row!(mask_values, 4, 0) * column_row!(mask_values, 4, 0)
+        - pedersen_hash0_ec_subset_sum_bit_0
+            * (column_row!(mask_values, 1, 0)
+                + global_values.pedersen_points_x
+                + column_row!(mask_values, 1, 1)))
+        * domains[9].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[64] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/add_points/y.
+    let value = (pedersen_hash0_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 2, 0) + column_row!(mask_values, 2, 1))
+        - column_row!(mask_values, 4, 0)
+            * (column_row!(mask_values, 1, 0) - column_row!(mask_values, 1, 1)))
+        * domains[9].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[65] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/copy_point/x.
+    let value = (pedersen_hash0_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 1, 1) - column_row!(mask_values, 1, 0)))
+        * domains[9].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[66] * value;
+
+    // Constraint: pedersen/hash0/ec_subset_sum/copy_point/y.
+    let value = (pedersen_hash0_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 2, 1) - column_row!(mask_values, 2, 0)))
+        * domains[9].field_div(&felt_nonzero!(domains[0]));
+    total_sum += constraint_coefficients[67] * value;
+
+    // Constraint: pedersen/hash0/copy_point/x.
+    let value = (column_row!(mask_values, 1, 256) - column_row!(mask_values, 1, 255))
+        * domains[12].field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[68] * value;
+
+    // Constraint: pedersen/hash0/copy_point/y.
+    let value = (column_row!(mask_values, 2, 256) - column_row!(mask_values, 2, 255))
+        * domains[12].field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[69] * value;
+
+    // Constraint: pedersen/hash0/init/x.
+    let value = (column_row!(mask_values, 1, 0) - global_values.pedersen_shift_point.x)
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[70] * value;
+
+    // Constraint: pedersen/hash0/init/y.
+    let value = (column_row!(mask_values, 2, 0) - global_values.pedersen_shift_point.y)
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[71] * value;
+
+    // Constraint: pedersen/input0_value0.
+    let value = (column_row!(mask_values, 5, 7) - column_row!(mask_values, 3, 0))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[72] * value;
+
+    // Constraint: pedersen/input0_addr.
+    let value = (column_row!(mask_values, 5, 518) - (column_row!(mask_values, 5, 134) + FELT_1))
+        * domains[33].field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[73] * value;
+
+    // Constraint: pedersen/init_addr.
+    let value = (column_row!(mask_values, 5, 6) - global_values.initial_pedersen_addr)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[74] * value;
+
+    // Constraint: pedersen/input1_value0.
+    let value = (column_row!(mask_values, 5, 263) - column_row!(mask_values, 3, 256))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[75] * value;
+
+    // Constraint: pedersen/input1_addr.
+    let value = (column_row!(mask_values, 5, 262) - (column_row!(mask_values, 5, 6) + FELT_1))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[76] * value;
+
+    // Constraint: pedersen/output_value0.
+    let value = (column_row!(mask_values, 5, 135) - column_row!(mask_values, 1, 511))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[77] * value;
+
+    // Constraint: pedersen/output_addr.
+    let value = (column_row!(mask_values, 5, 134) - (column_row!(mask_values, 5, 262) + FELT_1))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[78] * value;
+
+    // Constraint: range_check_builtin/value.
+    let value = (range_check_builtin_value7_0 - column_row!(mask_values, 5, 71))
+        .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[79] * value;
+
+    // Constraint: range_check_builtin/addr_step.
+    let value = (column_row!(mask_values, 5, 326) - (column_row!(mask_values, 5, 70) + FELT_1))
+        * domains[34].field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[80] * value;
+
+    // Constraint: range_check_builtin/init_addr.
+    let value = (column_row!(mask_values, 5, 70) - global_values.initial_range_check_addr)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[81] * value;
+
+    // Constraint: ecdsa/signature0/doubling_key/slope.
+    let value = (ecdsa_signature0_doubling_key_x_squared
+        + ecdsa_signature0_doubling_key_x_squared
+        + ecdsa_signature0_doubling_key_x_squared
+        + global_values.ecdsa_sig_config.alpha
+        - (column_row!(mask_values, 8, 33) + column_row!(mask_values, 8, 33))
+            * column_row!(mask_values, 8, 35))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[82] * value;
+
+    // Constraint: ecdsa/signature0/doubling_key/x.
+    let value = (column_row!(mask_values, 8, 35) * column_row!(mask_values, 8, 35)
+        - (column_row!(mask_values, 8, 1)
+            + column_row!(mask_values, 8, 1)
+            + column_row!(mask_values, 8, 65)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[83] * value;
+
+    // Constraint: ecdsa/signature0/doubling_key/y.
+    let value = (column_row!(mask_values, 8, 33) + column_row!(mask_values, 8, 97)
+        - column_row!(mask_values, 8, 35)
+            * (column_row!(mask_values, 8, 1) - column_row!(mask_values, 8, 65)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[84] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/booleanity_test.
+    let value = (ecdsa_signature0_exponentiate_generator_bit_0
+        * (ecdsa_signature0_exponentiate_generator_bit_0 - FELT_1))
+        * domains[25].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[85] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/bit_extraction_end.
+    let value = (column_row!(mask_values, 8, 59)).field_div(&felt_nonzero!(domains[26]));
+    total_sum += constraint_coefficients[86] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/zeros_tail.
+    let value = (column_row!(mask_values, 8, 59)).field_div(&felt_nonzero!(domains[25]));
+    total_sum += constraint_coefficients[87] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/add_points/slope.
+    let value = (ecdsa_signature0_exponentiate_generator_bit_0
+        * (column_row!(mask_values, 8, 91) - global_values.ecdsa_generator_points_y)
+        - column_row!(mask_values, 8, 123)
+            * (column_row!(mask_values, 8, 27) - global_values.ecdsa_generator_points_x))
+        * domains[25].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[88] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/add_points/x.
+    let value = (column_row!(mask_values, 8, 123) * column_row!(mask_values, 8, 123)
+        - ecdsa_signature0_exponentiate_generator_bit_0
+            * (column_row!(mask_values, 8, 27)
+                + global_values.ecdsa_generator_points_x
+                + column_row!(mask_values, 8, 155)))
+        * domains[25].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[89] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/add_points/y.
+    let value = (ecdsa_signature0_exponentiate_generator_bit_0
+        * (column_row!(mask_values, 8, 91) + column_row!(mask_values, 8, 219))
+        - column_row!(mask_values, 8, 123)
+            * (column_row!(mask_values, 8, 27) - column_row!(mask_values, 8, 155)))
+        * domains[25].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[90] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/add_points/x_diff_inv.
+    let value = (column_row!(mask_values, 8, 7)
+        * (column_row!(mask_values, 8, 27) - global_values.ecdsa_generator_points_x)
+        - FELT_1)
+        * domains[25].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[91] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/copy_point/x.
+    let value = (ecdsa_signature0_exponentiate_generator_bit_neg_0
+        * (column_row!(mask_values, 8, 155) - column_row!(mask_values, 8, 27)))
+        * domains[25].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[92] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_generator/copy_point/y.
+    let value = (ecdsa_signature0_exponentiate_generator_bit_neg_0
+        * (column_row!(mask_values, 8, 219) - column_row!(mask_values, 8, 91)))
+        * domains[25].field_div(&felt_nonzero!(domains[7]));
+    total_sum += constraint_coefficients[93] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/booleanity_test.
+    let value = (ecdsa_signature0_exponentiate_key_bit_0
+        * (ecdsa_signature0_exponentiate_key_bit_0 - FELT_1))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[94] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/bit_extraction_end.
+    let value = (column_row!(mask_values, 8, 9)).field_div(&felt_nonzero!(domains[22]));
+    total_sum += constraint_coefficients[95] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/zeros_tail.
+    let value = (column_row!(mask_values, 8, 9)).field_div(&felt_nonzero!(domains[21]));
+    total_sum += constraint_coefficients[96] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/add_points/slope.
+    let value = (ecdsa_signature0_exponentiate_key_bit_0
+        * (column_row!(mask_values, 8, 49) - column_row!(mask_values, 8, 33))
+        - column_row!(mask_values, 8, 19)
+            * (column_row!(mask_values, 8, 17) - column_row!(mask_values, 8, 1)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[97] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/add_points/x.
+    let value = (column_row!(mask_values, 8, 19) * column_row!(mask_values, 8, 19)
+        - ecdsa_signature0_exponentiate_key_bit_0
+            * (column_row!(mask_values, 8, 17)
+                + column_row!(mask_values, 8, 1)
+                + column_row!(mask_values, 8, 81)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[98] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/add_points/y.
+    let value = (ecdsa_signature0_exponentiate_key_bit_0
+        * (column_row!(mask_values, 8, 49) + column_row!(mask_values, 8, 113))
+        - column_row!(mask_values, 8, 19)
+            * (column_row!(mask_values, 8, 17) - column_row!(mask_values, 8, 81)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[99] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/add_points/x_diff_inv.
+    let value = (column_row!(mask_values, 8, 51)
+        * (column_row!(mask_values, 8, 17) - column_row!(mask_values, 8, 1))
+        - FELT_1)
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[100] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/copy_point/x.
+    let value = (ecdsa_signature0_exponentiate_key_bit_neg_0
+        * (column_row!(mask_values, 8, 81) - column_row!(mask_values, 8, 17)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[101] * value;
+
+    // Constraint: ecdsa/signature0/exponentiate_key/copy_point/y.
+    let value = (ecdsa_signature0_exponentiate_key_bit_neg_0
+        * (column_row!(mask_values, 8, 113) - column_row!(mask_values, 8, 49)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[102] * value;
+
+    // Constraint: ecdsa/signature0/init_gen/x.
+    let value = (column_row!(mask_values, 8, 27) - global_values.ecdsa_sig_config.shift_point.x)
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[103] * value;
+
+    // Constraint: ecdsa/signature0/init_gen/y.
+    let value = (column_row!(mask_values, 8, 91) + global_values.ecdsa_sig_config.shift_point.y)
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[104] * value;
+
+    // Constraint: ecdsa/signature0/init_key/x.
+    let value = (column_row!(mask_values, 8, 17) - global_values.ecdsa_sig_config.shift_point.x)
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[105] * value;
+
+    // Constraint: ecdsa/signature0/init_key/y.
+    let value = (column_row!(mask_values, 8, 49) - global_values.ecdsa_sig_config.shift_point.y)
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[106] * value;
+
+    // Constraint: ecdsa/signature0/add_results/slope.
+    let value = (column_row!(mask_values, 8, 32731)
+        - (column_row!(mask_values, 8, 16369)
+            + column_row!(mask_values, 8, 32763)
+                * (column_row!(mask_values, 8, 32667) - column_row!(mask_values, 8, 16337))))
+    .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[107] * value;
+
+    // Constraint: ecdsa/signature0/add_results/x.
+    let value = (column_row!(mask_values, 8, 32763) * column_row!(mask_values, 8, 32763)
+        - (column_row!(mask_values, 8, 32667)
+            + column_row!(mask_values, 8, 16337)
+            + column_row!(mask_values, 8, 16385)))
+    .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[108] * value;
+
+    // Constraint: ecdsa/signature0/add_results/y.
+    let value = (column_row!(mask_values, 8, 32731) + column_row!(mask_values, 8, 16417)
+        - column_row!(mask_values, 8, 32763)
+            * (column_row!(mask_values, 8, 32667) - column_row!(mask_values, 8, 16385)))
+    .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[109] * value;
+
+    // Constraint: ecdsa/signature0/add_results/x_diff_inv.
+    let value = (column_row!(mask_values, 8, 32647)
+        * (column_row!(mask_values, 8, 32667) - column_row!(mask_values, 8, 16337))
+        - FELT_1)
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[110] * value;
+
+    // Constraint: ecdsa/signature0/extract_r/slope.
+    let value = (column_row!(mask_values, 8, 32753) + global_values.ecdsa_sig_config.shift_point.y
+        - column_row!(mask_values, 8, 16331)
+            * (column_row!(mask_values, 8, 32721) - global_values.ecdsa_sig_config.shift_point.x))
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[111] * value;
+
+    // Constraint: ecdsa/signature0/extract_r/x.
+    let value = (column_row!(mask_values, 8, 16331) * column_row!(mask_values, 8, 16331)
+        - (column_row!(mask_values, 8, 32721)
+            + global_values.ecdsa_sig_config.shift_point.x
+            + column_row!(mask_values, 8, 9)))
+    .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[112] * value;
+
+    // Constraint: ecdsa/signature0/extract_r/x_diff_inv.
+    let value = (column_row!(mask_values, 8, 32715)
+        * (column_row!(mask_values, 8, 32721) - global_values.ecdsa_sig_config.shift_point.x)
+        - FELT_1)
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[113] * value;
+
+    // Constraint: ecdsa/signature0/z_nonzero.
+    let value = (column_row!(mask_values, 8, 59) * column_row!(mask_values, 8, 16363) - FELT_1)
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[114] * value;
+
+    // Constraint: ecdsa/signature0/r_and_w_nonzero.
+    let value = (column_row!(mask_values, 8, 9) * column_row!(mask_values, 8, 16355) - FELT_1)
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[115] * value;
+
+    // Constraint: ecdsa/signature0/q_on_curve/x_squared.
+    let value = (column_row!(mask_values, 8, 32747)
+        - column_row!(mask_values, 8, 1) * column_row!(mask_values, 8, 1))
+    .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[116] * value;
+
+    // Constraint: ecdsa/signature0/q_on_curve/on_curve.
+    let value = (column_row!(mask_values, 8, 33) * column_row!(mask_values, 8, 33)
+        - (column_row!(mask_values, 8, 1) * column_row!(mask_values, 8, 32747)
+            + global_values.ecdsa_sig_config.alpha * column_row!(mask_values, 8, 1)
+            + global_values.ecdsa_sig_config.beta))
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[117] * value;
+
+    // Constraint: ecdsa/init_addr.
+    let value = (column_row!(mask_values, 5, 390) - global_values.initial_ecdsa_addr)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[118] * value;
+
+    // Constraint: ecdsa/message_addr.
+    let value = (column_row!(mask_values, 5, 16774) - (column_row!(mask_values, 5, 390) + FELT_1))
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[119] * value;
+
+    // Constraint: ecdsa/pubkey_addr.
+    let value = (column_row!(mask_values, 5, 33158)
+        - (column_row!(mask_values, 5, 16774) + FELT_1))
+        * domains[35].field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[120] * value;
+
+    // Constraint: ecdsa/message_value0.
+    let value = (column_row!(mask_values, 5, 16775) - column_row!(mask_values, 8, 59))
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[121] * value;
+
+    // Constraint: ecdsa/pubkey_value0.
+    let value = (column_row!(mask_values, 5, 391) - column_row!(mask_values, 8, 1))
+        .field_div(&felt_nonzero!(domains[27]));
+    total_sum += constraint_coefficients[122] * value;
+
+    // Constraint: bitwise/init_var_pool_addr.
+    let value = (column_row!(mask_values, 5, 198) - global_values.initial_bitwise_addr)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[123] * value;
+
+    // Constraint: bitwise/step_var_pool_addr.
+    let value = (column_row!(mask_values, 5, 454) - (column_row!(mask_values, 5, 198) + FELT_1))
+        * domains[18].field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[124] * value;
+
+    // Constraint: bitwise/x_or_y_addr.
+    let value = (column_row!(mask_values, 5, 902) - (column_row!(mask_values, 5, 966) + FELT_1))
+        .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[125] * value;
+
+    // Constraint: bitwise/next_var_pool_addr.
+    let value = (column_row!(mask_values, 5, 1222) - (column_row!(mask_values, 5, 902) + FELT_1))
+        * domains[36].field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[126] * value;
+
+    // Constraint: bitwise/partition.
+    let value = (bitwise_sum_var_0_0 + bitwise_sum_var_8_0 - column_row!(mask_values, 5, 199))
+        .field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[127] * value;
+
+    // Constraint: bitwise/or_is_and_plus_xor.
+    let value = (column_row!(mask_values, 5, 903)
+        - (column_row!(mask_values, 5, 711) + column_row!(mask_values, 5, 967)))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[128] * value;
+
+    // Constraint: bitwise/addition_is_xor_with_and.
+    let value = (column_row!(mask_values, 7, 1) + column_row!(mask_values, 7, 257)
+        - (column_row!(mask_values, 7, 769)
+            + column_row!(mask_values, 7, 513)
+            + column_row!(mask_values, 7, 513)))
+    .field_div(&felt_nonzero!(domains[20]));
+    total_sum += constraint_coefficients[129] * value;
+
+    // Constraint: bitwise/unique_unpacking192.
+    let value = ((column_row!(mask_values, 7, 705) + column_row!(mask_values, 7, 961)) * FELT_16
+        - column_row!(mask_values, 7, 9))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[130] * value;
+
+    // Constraint: bitwise/unique_unpacking193.
+    let value = ((column_row!(mask_values, 7, 721) + column_row!(mask_values, 7, 977)) * FELT_16
+        - column_row!(mask_values, 7, 521))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[131] * value;
+
+    // Constraint: bitwise/unique_unpacking194.
+    let value = ((column_row!(mask_values, 7, 737) + column_row!(mask_values, 7, 993)) * FELT_16
+        - column_row!(mask_values, 7, 265))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[132] * value;
+
+    // Constraint: bitwise/unique_unpacking195.
+    let value = ((column_row!(mask_values, 7, 753) + column_row!(mask_values, 7, 1009)) * FELT_256
+        - column_row!(mask_values, 7, 777))
+    .field_div(&felt_nonzero!(domains[19]));
+    total_sum += constraint_coefficients[133] * value;
+
+    // Constraint: ec_op/init_addr.
+    let value = (column_row!(mask_values, 5, 8582) - global_values.initial_ec_op_addr)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[134] * value;
+
+    // Constraint: ec_op/p_x_addr.
+    let value = (column_row!(mask_values, 5, 24966) - (column_row!(mask_values, 5, 8582) + FELT_7))
+        * domains[37].field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[135] * value;
+
+    // Constraint: ec_op/p_y_addr.
+    let value = (column_row!(mask_values, 5, 4486) - (column_row!(mask_values, 5, 8582) + FELT_1))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[136] * value;
+
+    // Constraint: ec_op/q_x_addr.
+    let value = (column_row!(mask_values, 5, 12678) - (column_row!(mask_values, 5, 4486) + FELT_1))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[137] * value;
+
+    // Constraint: ec_op/q_y_addr.
+    let value = (column_row!(mask_values, 5, 2438) - (column_row!(mask_values, 5, 12678) + FELT_1))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[138] * value;
+
+    // Constraint: ec_op/m_addr.
+    let value = (column_row!(mask_values, 5, 10630) - (column_row!(mask_values, 5, 2438) + FELT_1))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[139] * value;
+
+    // Constraint: ec_op/r_x_addr.
+    let value = (column_row!(mask_values, 5, 6534) - (column_row!(mask_values, 5, 10630) + FELT_1))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[140] * value;
+
+    // Constraint: ec_op/r_y_addr.
+    let value = (column_row!(mask_values, 5, 14726) - (column_row!(mask_values, 5, 6534) + FELT_1))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[141] * value;
+
+    // Constraint: ec_op/doubling_q/slope.
+    let value = (ec_op_doubling_q_x_squared_0
+        + ec_op_doubling_q_x_squared_0
+        + ec_op_doubling_q_x_squared_0
+        + global_values.ec_op_curve_config.alpha
+        - (column_row!(mask_values, 8, 25) + column_row!(mask_values, 8, 25))
+            * column_row!(mask_values, 8, 57))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[142] * value;
+
+    // Constraint: ec_op/doubling_q/x.
+    let value = (column_row!(mask_values, 8, 57) * column_row!(mask_values, 8, 57)
+        - (column_row!(mask_values, 8, 41)
+            + column_row!(mask_values, 8, 41)
+            + column_row!(mask_values, 8, 105)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[143] * value;
+
+    // Constraint: ec_op/doubling_q/y.
+    let value = (column_row!(mask_values, 8, 25) + column_row!(mask_values, 8, 89)
+        - column_row!(mask_values, 8, 57)
+            * (column_row!(mask_values, 8, 41) - column_row!(mask_values, 8, 105)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[144] * value;
+
+    // Constraint: ec_op/get_q_x.
+    let value = (column_row!(mask_values, 5, 12679) - column_row!(mask_values, 8, 41))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[145] * value;
+
+    // Constraint: ec_op/get_q_y.
+    let value = (column_row!(mask_values, 5, 2439) - column_row!(mask_values, 8, 25))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[146] * value;
+
+    // Constraint: ec_op/ec_subset_sum/bit_unpacking/last_one_is_zero.
+    let value = (column_row!(mask_values, 8, 16371)
+        * (column_row!(mask_values, 8, 21)
+            - (column_row!(mask_values, 8, 85) + column_row!(mask_values, 8, 85))))
+    .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[147] * value;
+
+    // Constraint: ec_op/ec_subset_sum/bit_unpacking/zeroes_between_ones0.
+    let value = (column_row!(mask_values, 8, 16371)
+        * (column_row!(mask_values, 8, 85)
+            - FELT_3138550867693340381917894711603833208051177722232017256448
+                * column_row!(mask_values, 8, 12309)))
+    .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[148] * value;
+
+    // Constraint: ec_op/ec_subset_sum/bit_unpacking/cumulative_bit192.
+    let value = (column_row!(mask_values, 8, 16371)
+        - column_row!(mask_values, 8, 16339)
+            * (column_row!(mask_values, 8, 12309)
+                - (column_row!(mask_values, 8, 12373) + column_row!(mask_values, 8, 12373))))
+    .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[149] * value;
+
+    // Constraint: ec_op/ec_subset_sum/bit_unpacking/zeroes_between_ones192.
+    let value = (column_row!(mask_values, 8, 16339)
+        * (column_row!(mask_values, 8, 12373) - FELT_8 * column_row!(mask_values, 8, 12565)))
+    .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[150] * value;
+
+    // Constraint: ec_op/ec_subset_sum/bit_unpacking/cumulative_bit196.
+    let value = (column_row!(mask_values, 8, 16339)
+        - (column_row!(mask_values, 8, 16085)
+            - (column_row!(mask_values, 8, 16149) + column_row!(mask_values, 8, 16149)))
+            * (column_row!(mask_values, 8, 12565)
+                - (column_row!(mask_values, 8, 12629) + column_row!(mask_values, 8, 12629))))
+    .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[151] * value;
+
+    // Constraint: ec_op/ec_subset_sum/bit_unpacking/zeroes_between_ones196.
+    let value = ((column_row!(mask_values, 8, 16085)
+        - (column_row!(mask_values, 8, 16149) + column_row!(mask_values, 8, 16149)))
+        * (column_row!(mask_values, 8, 12629)
+            - FELT_18014398509481984 * column_row!(mask_values, 8, 16085)))
+    .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[152] * value;
+
+    // Constraint: ec_op/ec_subset_sum/booleanity_test.
+    let value = (ec_op_ec_subset_sum_bit_0 * (ec_op_ec_subset_sum_bit_0 - FELT_1))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[153] * value;
+
+    // Constraint: ec_op/ec_subset_sum/bit_extraction_end.
+    let value = (column_row!(mask_values, 8, 21)).field_div(&felt_nonzero!(domains[24]));
+    total_sum += constraint_coefficients[154] * value;
+
+    // Constraint: ec_op/ec_subset_sum/zeros_tail.
+    let value = (column_row!(mask_values, 8, 21)).field_div(&felt_nonzero!(domains[21]));
+    total_sum += constraint_coefficients[155] * value;
+
+    // Constraint: ec_op/ec_subset_sum/add_points/slope.
+    let value = (ec_op_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 8, 37) - column_row!(mask_values, 8, 25))
+        - column_row!(mask_values, 8, 11)
+            * (column_row!(mask_values, 8, 5) - column_row!(mask_values, 8, 41)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[156] * value;
+
+    // Constraint: ec_op/ec_subset_sum/add_points/x.
+    let value = (column_row!(mask_values, 8, 11) * column_row!(mask_values, 8, 11)
+        - ec_op_ec_subset_sum_bit_0
+            * (column_row!(mask_values, 8, 5)
+                + column_row!(mask_values, 8, 41)
+                + column_row!(mask_values, 8, 69)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[157] * value;
+
+    // Constraint: ec_op/ec_subset_sum/add_points/y.
+    let value = (ec_op_ec_subset_sum_bit_0
+        * (column_row!(mask_values, 8, 37) + column_row!(mask_values, 8, 101))
+        - column_row!(mask_values, 8, 11)
+            * (column_row!(mask_values, 8, 5) - column_row!(mask_values, 8, 69)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[158] * value;
+
+    // Constraint: ec_op/ec_subset_sum/add_points/x_diff_inv.
+    let value = (column_row!(mask_values, 8, 43)
+        * (column_row!(mask_values, 8, 5) - column_row!(mask_values, 8, 41))
+        - FELT_1)
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[159] * value;
+
+    // Constraint: ec_op/ec_subset_sum/copy_point/x.
+    let value = (ec_op_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 8, 69) - column_row!(mask_values, 8, 5)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[160] * value;
+
+    // Constraint: ec_op/ec_subset_sum/copy_point/y.
+    let value = (ec_op_ec_subset_sum_bit_neg_0
+        * (column_row!(mask_values, 8, 101) - column_row!(mask_values, 8, 37)))
+        * domains[21].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[161] * value;
+
+    // Constraint: ec_op/get_m.
+    let value = (column_row!(mask_values, 8, 21) - column_row!(mask_values, 5, 10631))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[162] * value;
+
+    // Constraint: ec_op/get_p_x.
+    let value = (column_row!(mask_values, 5, 8583) - column_row!(mask_values, 8, 5))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[163] * value;
+
+    // Constraint: ec_op/get_p_y.
+    let value = (column_row!(mask_values, 5, 4487) - column_row!(mask_values, 8, 37))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[164] * value;
+
+    // Constraint: ec_op/set_r_x.
+    let value = (column_row!(mask_values, 5, 6535) - column_row!(mask_values, 8, 16325))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[165] * value;
+
+    // Constraint: ec_op/set_r_y.
+    let value = (column_row!(mask_values, 5, 14727) - column_row!(mask_values, 8, 16357))
+        .field_div(&felt_nonzero!(domains[23]));
+    total_sum += constraint_coefficients[166] * value;
+
+    // Constraint: poseidon/param_0/init_input_output_addr.
+    let value = (column_row!(mask_values, 5, 38) - global_values.initial_poseidon_addr)
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[167] * value;
+
+    // Constraint: poseidon/param_0/addr_input_output_step.
+    let value = (column_row!(mask_values, 5, 294) - (column_row!(mask_values, 5, 38) + FELT_3))
+        * domains[34].field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[168] * value;
+
+    // Constraint: poseidon/param_1/init_input_output_addr.
+    let value = (column_row!(mask_values, 5, 166) - (global_values.initial_poseidon_addr + FELT_1))
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[169] * value;
+
+    // Constraint: poseidon/param_1/addr_input_output_step.
+    let value = (column_row!(mask_values, 5, 422) - (column_row!(mask_values, 5, 166) + FELT_3))
+        * domains[34].field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[170] * value;
+
+    // Constraint: poseidon/param_2/init_input_output_addr.
+    let value = (column_row!(mask_values, 5, 102) - (global_values.initial_poseidon_addr + FELT_2))
+        .field_div(&felt_nonzero!(domains[29]));
+    total_sum += constraint_coefficients[171] * value;
+
+    // Constraint: poseidon/param_2/addr_input_output_step.
+    let value = (column_row!(mask_values, 5, 358) - (column_row!(mask_values, 5, 102) + FELT_3))
+        * domains[34].field_div(&felt_nonzero!(domains[8]));
+    total_sum += constraint_coefficients[172] * value;
+
+    // Constraint: poseidon/poseidon/full_rounds_state0_squaring.
+    let value = (column_row!(mask_values, 8, 53) * column_row!(mask_values, 8, 53)
+        - column_row!(mask_values, 8, 29))
+    .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[173] * value;
+
+    // Constraint: poseidon/poseidon/full_rounds_state1_squaring.
+    let value = (column_row!(mask_values, 8, 13) * column_row!(mask_values, 8, 13)
+        - column_row!(mask_values, 8, 61))
+    .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[174] * value;
+
+    // Constraint: poseidon/poseidon/full_rounds_state2_squaring.
+    let value = (column_row!(mask_values, 8, 45) * column_row!(mask_values, 8, 45)
+        - column_row!(mask_values, 8, 3))
+    .field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[175] * value;
+
+    // Constraint: poseidon/poseidon/partial_rounds_state0_squaring.
+    let value = (column_row!(mask_values, 7, 3) * column_row!(mask_values, 7, 3)
+        - column_row!(mask_values, 7, 7))
+    .field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[176] * value;
+
+    // Constraint: poseidon/poseidon/partial_rounds_state1_squaring.
+    let value = (column_row!(mask_values, 8, 6) * column_row!(mask_values, 8, 6)
+        - column_row!(mask_values, 8, 14))
+        * domains[15].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[177] * value;
+
+    // Constraint: poseidon/poseidon/add_first_round_key0.
+    let value = (column_row!(mask_values, 5, 39)
+        + FELT_2950795762459345168613727575620414179244544320470208355568817838579231751791
+        - column_row!(mask_values, 8, 53))
+    .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[178] * value;
+
+    // Constraint: poseidon/poseidon/add_first_round_key1.
+    let value = (column_row!(mask_values, 5, 167)
+        + FELT_1587446564224215276866294500450702039420286416111469274423465069420553242820
+        - column_row!(mask_values, 8, 13))
+    .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[179] * value;
+
+    // Constraint: poseidon/poseidon/add_first_round_key2.
+    let value = (column_row!(mask_values, 5, 103)
+        + FELT_1645965921169490687904413452218868659025437693527479459426157555728339600137
+        - column_row!(mask_values, 8, 45))
+    .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[180] * value;
+
+    // Constraint: poseidon/poseidon/full_round0.
+    let value = (column_row!(mask_values, 8, 117)
+        - (poseidon_poseidon_full_rounds_state0_cubed_0
+            + poseidon_poseidon_full_rounds_state0_cubed_0
+            + poseidon_poseidon_full_rounds_state0_cubed_0
+            + poseidon_poseidon_full_rounds_state1_cubed_0
+            + poseidon_poseidon_full_rounds_state2_cubed_0
+            + global_values.poseidon_poseidon_full_round_key0))
+        * domains[11].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[181] * value;
+
+    // Constraint: poseidon/poseidon/full_round1.
+    let value = (column_row!(mask_values, 8, 77) + poseidon_poseidon_full_rounds_state1_cubed_0
+        - (poseidon_poseidon_full_rounds_state0_cubed_0
+            + poseidon_poseidon_full_rounds_state2_cubed_0
+            + global_values.poseidon_poseidon_full_round_key1))
+        * domains[11].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[182] * value;
+
+    // Constraint: poseidon/poseidon/full_round2.
+    let value = (column_row!(mask_values, 8, 109)
+        + poseidon_poseidon_full_rounds_state2_cubed_0
+        + poseidon_poseidon_full_rounds_state2_cubed_0
+        - (poseidon_poseidon_full_rounds_state0_cubed_0
+            + poseidon_poseidon_full_rounds_state1_cubed_0
+            + global_values.poseidon_poseidon_full_round_key2))
+        * domains[11].field_div(&felt_nonzero!(domains[6]));
+    total_sum += constraint_coefficients[183] * value;
+
+    // Constraint: poseidon/poseidon/last_full_round0.
+    let value = (column_row!(mask_values, 5, 295)
+        - (poseidon_poseidon_full_rounds_state0_cubed_7
+            + poseidon_poseidon_full_rounds_state0_cubed_7
+            + poseidon_poseidon_full_rounds_state0_cubed_7
+            + poseidon_poseidon_full_rounds_state1_cubed_7
+            + poseidon_poseidon_full_rounds_state2_cubed_7))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[184] * value;
+
+    // Constraint: poseidon/poseidon/last_full_round1.
+    let value = (column_row!(mask_values, 5, 423) + poseidon_poseidon_full_rounds_state1_cubed_7
+        - (poseidon_poseidon_full_rounds_state0_cubed_7
+            + poseidon_poseidon_full_rounds_state2_cubed_7))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[185] * value;
+
+    // Constraint: poseidon/poseidon/last_full_round2.
+    let value = (column_row!(mask_values, 5, 359)
+        + poseidon_poseidon_full_rounds_state2_cubed_7
+        + poseidon_poseidon_full_rounds_state2_cubed_7
+        - (poseidon_poseidon_full_rounds_state0_cubed_7
+            + poseidon_poseidon_full_rounds_state1_cubed_7))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[186] * value;
+
+    // Constraint: poseidon/poseidon/copy_partial_rounds0_i0.
+    let value = (column_row!(mask_values, 7, 491) - column_row!(mask_values, 8, 6))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[187] * value;
+
+    // Constraint: poseidon/poseidon/copy_partial_rounds0_i1.
+    let value = (column_row!(mask_values, 7, 499) - column_row!(mask_values, 8, 22))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[188] * value;
+
+    // Constraint: poseidon/poseidon/copy_partial_rounds0_i2.
+    let value = (column_row!(mask_values, 7, 507) - column_row!(mask_values, 8, 38))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[189] * value;
+
+    // Constraint: poseidon/poseidon/margin_full_to_partial0.
+    let value = (column_row!(mask_values, 7, 3)
+        + poseidon_poseidon_full_rounds_state2_cubed_3
+        + poseidon_poseidon_full_rounds_state2_cubed_3
+        - (poseidon_poseidon_full_rounds_state0_cubed_3
+            + poseidon_poseidon_full_rounds_state1_cubed_3
+            + FELT_2121140748740143694053732746913428481442990369183417228688865837805149503386))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[190] * value;
+
+    // Constraint: poseidon/poseidon/margin_full_to_partial1.
+    let value = (column_row!(mask_values, 7, 11)
+        - (FELT_3618502788666131213697322783095070105623107215331596699973092056135872020477
+            * poseidon_poseidon_full_rounds_state1_cubed_3
+            + FELT_10 * poseidon_poseidon_full_rounds_state2_cubed_3
+            + FELT_4 * column_row!(mask_values, 7, 3)
+            + FELT_3618502788666131213697322783095070105623107215331596699973092056135872020479
+                * poseidon_poseidon_partial_rounds_state0_cubed_0
+            + FELT_2006642341318481906727563724340978325665491359415674592697055778067937914672))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[191] * value;
+
+    // Constraint: poseidon/poseidon/margin_full_to_partial2.
+    let value = (column_row!(mask_values, 7, 19)
+        - (FELT_8 * poseidon_poseidon_full_rounds_state2_cubed_3
+            + FELT_4 * column_row!(mask_values, 7, 3)
+            + FELT_6 * poseidon_poseidon_partial_rounds_state0_cubed_0
+            + column_row!(mask_values, 7, 11)
+            + column_row!(mask_values, 7, 11)
+            + FELT_3618502788666131213697322783095070105623107215331596699973092056135872020479
+                * poseidon_poseidon_partial_rounds_state0_cubed_1
+            + FELT_427751140904099001132521606468025610873158555767197326325930641757709538586))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[192] * value;
+
+    // Constraint: poseidon/poseidon/partial_round0.
+    let value = (column_row!(mask_values, 7, 27)
+        - (FELT_8 * poseidon_poseidon_partial_rounds_state0_cubed_0
+            + FELT_4 * column_row!(mask_values, 7, 11)
+            + FELT_6 * poseidon_poseidon_partial_rounds_state0_cubed_1
+            + column_row!(mask_values, 7, 19)
+            + column_row!(mask_values, 7, 19)
+            + FELT_3618502788666131213697322783095070105623107215331596699973092056135872020479
+                * poseidon_poseidon_partial_rounds_state0_cubed_2
+            + global_values.poseidon_poseidon_partial_round_key0))
+        * domains[16].field_div(&felt_nonzero!(domains[3]));
+    total_sum += constraint_coefficients[193] * value;
+
+    // Constraint: poseidon/poseidon/partial_round1.
+    let value = (column_row!(mask_values, 8, 54)
+        - (FELT_8 * poseidon_poseidon_partial_rounds_state1_cubed_0
+            + FELT_4 * column_row!(mask_values, 8, 22)
+            + FELT_6 * poseidon_poseidon_partial_rounds_state1_cubed_1
+            + column_row!(mask_values, 8, 38)
+            + column_row!(mask_values, 8, 38)
+            + FELT_3618502788666131213697322783095070105623107215331596699973092056135872020479
+                * poseidon_poseidon_partial_rounds_state1_cubed_2
+            + global_values.poseidon_poseidon_partial_round_key1))
+        * domains[17].field_div(&felt_nonzero!(domains[5]));
+    total_sum += constraint_coefficients[194] * value;
+
+    // Constraint: poseidon/poseidon/margin_partial_to_full0.
+    let value = (column_row!(mask_values, 8, 309)
+        - (FELT_16 * poseidon_poseidon_partial_rounds_state1_cubed_19
+            + FELT_8 * column_row!(mask_values, 8, 326)
+            + FELT_16 * poseidon_poseidon_partial_rounds_state1_cubed_20
+            + FELT_6 * column_row!(mask_values, 8, 342)
+            + poseidon_poseidon_partial_rounds_state1_cubed_21
+            + FELT_560279373700919169769089400651532183647886248799764942664266404650165812023))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[195] * value;
+
+    // Constraint: poseidon/poseidon/margin_partial_to_full1.
+    let value = (column_row!(mask_values, 8, 269)
+        - (FELT_4 * poseidon_poseidon_partial_rounds_state1_cubed_20
+            + column_row!(mask_values, 8, 342)
+            + column_row!(mask_values, 8, 342)
+            + poseidon_poseidon_partial_rounds_state1_cubed_21
+            + FELT_1401754474293352309994371631695783042590401941592571735921592823982231996415))
+        .field_div(&felt_nonzero!(domains[13]));
+    total_sum += constraint_coefficients[196] * value;
+
+    // Constraint: poseidon/poseidon/margin_partial_to_full2.
+    let value = (column_row!(mask_values, 8, 301)
+        - (FELT_8 * poseidon_poseidon_partial_rounds_state1_cubed_19
+            + FELT_4 * column_row!(mask_values, 8, 326)
+            + FELT_6 * poseidon_poseidon_partial_rounds_state1_cubed_20
+            + column_row!(mask_values, 8, 342)
+            + column_row!(mask_values, 8, 342)
+            + FELT_3618502788666131213697322783095070105623107215331596699973092056135872020479
+                * poseidon_poseidon_partial_rounds_state1_cubed_21
+            + FELT_1246177936547655338400308396717835700699368047388302793172818304164989556526))
+        .field_div(&felt_nonzero!(domains[13]));
+
+    total_sum += constraint_coefficients[197] * value;
+
+    total_sum
+}
+
+// Powers of the trace generator at the row offsets of the mask.
+pub fn eval_oods_powers(trace_generator: Felt, autogenerated_pows: &mut [Felt]) {
+    autogenerated_pows[0] = trace_generator.pow_felt(&(FELT_0));
+    autogenerated_pows[1] = trace_generator.pow_felt(&(FELT_32715));
+    autogenerated_pows[2] = trace_generator.pow_felt(&(FELT_32667));
+    autogenerated_pows[3] = trace_generator.pow_felt(&(FELT_32647));
+    autogenerated_pows[4] = trace_generator.pow_felt(&(FELT_16325));
+    autogenerated_pows[5] = trace_generator.pow_felt(&(FELT_16149));
+    autogenerated_pows[6] = trace_generator.pow_felt(&(FELT_16085));
+    autogenerated_pows[7] = trace_generator.pow_felt(&(FELT_12373));
+    autogenerated_pows[8] = trace_generator.pow_felt(&(FELT_12309));
+    autogenerated_pows[9] = trace_generator.pow_felt(&(FELT_24966));
+    autogenerated_pows[10] = trace_generator.pow_felt(&(FELT_16774));
+    autogenerated_pows[11] = trace_generator.pow_felt(&(FELT_14726));
+    autogenerated_pows[12] = trace_generator.pow_felt(&(FELT_10630));
+    autogenerated_pows[13] = trace_generator.pow_felt(&(FELT_8582));
+    autogenerated_pows[14] = trace_generator.pow_felt(&(FELT_6534));
+    autogenerated_pows[15] = trace_generator.pow_felt(&(FELT_4486));
+    autogenerated_pows[16] = trace_generator.pow_felt(&(FELT_2438));
+    autogenerated_pows[17] = trace_generator.pow_felt(&(FELT_1));
+    autogenerated_pows[18] = autogenerated_pows[11] * autogenerated_pows[17]; // pow(trace_generator, 14727).
+    autogenerated_pows[19] = autogenerated_pows[12] * autogenerated_pows[17]; // pow(trace_generator, 10631).
+    autogenerated_pows[20] = autogenerated_pows[13] * autogenerated_pows[17]; // pow(trace_generator, 8583).
+    autogenerated_pows[21] = autogenerated_pows[14] * autogenerated_pows[17]; // pow(trace_generator, 6535).
+    autogenerated_pows[22] = autogenerated_pows[15] * autogenerated_pows[17]; // pow(trace_generator, 4487).
+    autogenerated_pows[23] = autogenerated_pows[16] * autogenerated_pows[17]; // pow(trace_generator, 2439).
+    autogenerated_pows[24] = autogenerated_pows[17] * autogenerated_pows[17]; // pow(trace_generator, 2).
+    autogenerated_pows[25] = autogenerated_pows[17] * autogenerated_pows[24]; // pow(trace_generator, 3).
+    autogenerated_pows[26] = autogenerated_pows[17] * autogenerated_pows[25]; // pow(trace_generator, 4).
+    autogenerated_pows[27] = autogenerated_pows[17] * autogenerated_pows[26]; // pow(trace_generator, 5).
+    autogenerated_pows[28] = autogenerated_pows[17] * autogenerated_pows[27]; // pow(trace_generator, 6).
+    autogenerated_pows[29] = autogenerated_pows[4] * autogenerated_pows[28]; // pow(trace_generator, 16331).
+    autogenerated_pows[30] = autogenerated_pows[17] * autogenerated_pows[28]; // pow(trace_generator, 7).
+    autogenerated_pows[31] = autogenerated_pows[17] * autogenerated_pows[30]; // pow(trace_generator, 8).
+    autogenerated_pows[32] = autogenerated_pows[17] * autogenerated_pows[31]; // pow(trace_generator, 9).
+    autogenerated_pows[33] = autogenerated_pows[17] * autogenerated_pows[32]; // pow(trace_generator, 10).
+    autogenerated_pows[34] = autogenerated_pows[17] * autogenerated_pows[33]; // pow(trace_generator, 11).
+    autogenerated_pows[35] = autogenerated_pows[17] * autogenerated_pows[34]; // pow(trace_generator, 12).
+    autogenerated_pows[36] = autogenerated_pows[17] * autogenerated_pows[35]; // pow(trace_generator, 13).
+    autogenerated_pows[37] = autogenerated_pows[17] * autogenerated_pows[36]; // pow(trace_generator, 14).
+    autogenerated_pows[38] = autogenerated_pows[17] * autogenerated_pows[37]; // pow(trace_generator, 15).
+    autogenerated_pows[39] = autogenerated_pows[17] * autogenerated_pows[38]; // pow(trace_generator, 16).
+    autogenerated_pows[40] = autogenerated_pows[17] * autogenerated_pows[39]; // pow(trace_generator, 17).
+    autogenerated_pows[41] = autogenerated_pows[24] * autogenerated_pows[40]; // pow(trace_generator, 19).
+    autogenerated_pows[42] = autogenerated_pows[24] * autogenerated_pows[41]; // pow(trace_generator, 21).
+    autogenerated_pows[43] = autogenerated_pows[17] * autogenerated_pows[42]; // pow(trace_generator, 22).
+    autogenerated_pows[44] = autogenerated_pows[17] * autogenerated_pows[43]; // pow(trace_generator, 23).
+    autogenerated_pows[45] = autogenerated_pows[17] * autogenerated_pows[44]; // pow(trace_generator, 24).
+    autogenerated_pows[46] = autogenerated_pows[17] * autogenerated_pows[45]; // pow(trace_generator, 25).
+    autogenerated_pows[47] = autogenerated_pows[24] * autogenerated_pows[46]; // pow(trace_generator, 27).
+    autogenerated_pows[48] = autogenerated_pows[24] * autogenerated_pows[47]; // pow(trace_generator, 29).
+    autogenerated_pows[49] = autogenerated_pows[17] * autogenerated_pows[48]; // pow(trace_generator, 30).
+    autogenerated_pows[50] = autogenerated_pows[25] * autogenerated_pows[49]; // pow(trace_generator, 33).
+    autogenerated_pows[51] = autogenerated_pows[24] * autogenerated_pows[50]; // pow(trace_generator, 35).
+    autogenerated_pows[52] = autogenerated_pows[24] * autogenerated_pows[51]; // pow(trace_generator, 37).
+    autogenerated_pows[53] = autogenerated_pows[17] * autogenerated_pows[52]; // pow(trace_generator, 38).
+    autogenerated_pows[54] = autogenerated_pows[17] * autogenerated_pows[53]; // pow(trace_generator, 39).
+    autogenerated_pows[55] = autogenerated_pows[24] * autogenerated_pows[54]; // pow(trace_generator, 41).
+    autogenerated_pows[56] = autogenerated_pows[24] * autogenerated_pows[55]; // pow(trace_generator, 43).
+    autogenerated_pows[57] = autogenerated_pows[17] * autogenerated_pows[56]; // pow(trace_generator, 44).
+    autogenerated_pows[58] = autogenerated_pows[17] * autogenerated_pows[57]; // pow(trace_generator, 45).
+    autogenerated_pows[59] = autogenerated_pows[17] * autogenerated_pows[58]; // pow(trace_generator, 46).
+    autogenerated_pows[60] = autogenerated_pows[25] * autogenerated_pows[59]; // pow(trace_generator, 49).
+    autogenerated_pows[61] = autogenerated_pows[24] * autogenerated_pows[60]; // pow(trace_generator, 51).
+    autogenerated_pows[62] = autogenerated_pows[24] * autogenerated_pows[61]; // pow(trace_generator, 53).
+    autogenerated_pows[63] = autogenerated_pows[17] * autogenerated_pows[62]; // pow(trace_generator, 54).
+    autogenerated_pows[64] = autogenerated_pows[1] * autogenerated_pows[28]; // pow(trace_generator, 32721).
+    autogenerated_pows[65] = autogenerated_pows[1] * autogenerated_pows[39]; // pow(trace_generator, 32731).
+    autogenerated_pows[66] = autogenerated_pows[39] * autogenerated_pows[65]; // pow(trace_generator, 32747).
+    autogenerated_pows[67] = autogenerated_pows[1] * autogenerated_pows[53]; // pow(trace_generator, 32753).
+    autogenerated_pows[68] = autogenerated_pows[33] * autogenerated_pows[67]; // pow(trace_generator, 32763).
+    autogenerated_pows[69] = autogenerated_pows[25] * autogenerated_pows[63]; // pow(trace_generator, 57).
+    autogenerated_pows[70] = autogenerated_pows[24] * autogenerated_pows[69]; // pow(trace_generator, 59).
+    autogenerated_pows[71] = autogenerated_pows[24] * autogenerated_pows[70]; // pow(trace_generator, 61).
+    autogenerated_pows[72] = autogenerated_pows[26] * autogenerated_pows[71]; // pow(trace_generator, 65).
+    autogenerated_pows[73] = autogenerated_pows[26] * autogenerated_pows[72]; // pow(trace_generator, 69).
+    autogenerated_pows[74] = autogenerated_pows[17] * autogenerated_pows[73]; // pow(trace_generator, 70).
+    autogenerated_pows[75] = autogenerated_pows[17] * autogenerated_pows[74]; // pow(trace_generator, 71).
+    autogenerated_pows[76] = autogenerated_pows[24] * autogenerated_pows[75]; // pow(trace_generator, 73).
+    autogenerated_pows[77] = autogenerated_pows[25] * autogenerated_pows[76]; // pow(trace_generator, 76).
+    autogenerated_pows[78] = autogenerated_pows[17] * autogenerated_pows[77]; // pow(trace_generator, 77).
+    autogenerated_pows[79] = autogenerated_pows[26] * autogenerated_pows[78]; // pow(trace_generator, 81).
+    autogenerated_pows[80] = autogenerated_pows[26] * autogenerated_pows[79]; // pow(trace_generator, 85).
+    autogenerated_pows[81] = autogenerated_pows[26] * autogenerated_pows[80]; // pow(trace_generator, 89).
+    autogenerated_pows[82] = autogenerated_pows[24] * autogenerated_pows[81]; // pow(trace_generator, 91).
+    autogenerated_pows[83] = autogenerated_pows[28] * autogenerated_pows[82]; // pow(trace_generator, 97).
+    autogenerated_pows[84] = autogenerated_pows[26] * autogenerated_pows[83]; // pow(trace_generator, 101).
+    autogenerated_pows[85] = autogenerated_pows[17] * autogenerated_pows[84]; // pow(trace_generator, 102).
+    autogenerated_pows[86] = autogenerated_pows[17] * autogenerated_pows[85]; // pow(trace_generator, 103).
+    autogenerated_pows[87] = autogenerated_pows[24] * autogenerated_pows[86]; // pow(trace_generator, 105).
+    autogenerated_pows[88] = autogenerated_pows[25] * autogenerated_pows[87]; // pow(trace_generator, 108).
+    autogenerated_pows[89] = autogenerated_pows[17] * autogenerated_pows[88]; // pow(trace_generator, 109).
+    autogenerated_pows[90] = autogenerated_pows[26] * autogenerated_pows[89]; // pow(trace_generator, 113).
+    autogenerated_pows[91] = autogenerated_pows[26] * autogenerated_pows[90]; // pow(trace_generator, 117).
+    autogenerated_pows[92] = autogenerated_pows[28] * autogenerated_pows[91]; // pow(trace_generator, 123).
+    autogenerated_pows[93] = autogenerated_pows[28] * autogenerated_pows[92]; // pow(trace_generator, 129).
+    autogenerated_pows[94] = autogenerated_pows[27] * autogenerated_pows[93]; // pow(trace_generator, 134).
+    autogenerated_pows[95] = autogenerated_pows[17] * autogenerated_pows[94]; // pow(trace_generator, 135).
+    autogenerated_pows[96] = autogenerated_pows[27] * autogenerated_pows[95]; // pow(trace_generator, 140).
+    autogenerated_pows[97] = autogenerated_pows[27] * autogenerated_pows[96]; // pow(trace_generator, 145).
+    autogenerated_pows[98] = autogenerated_pows[33] * autogenerated_pows[97]; // pow(trace_generator, 155).
+    autogenerated_pows[99] = autogenerated_pows[28] * autogenerated_pows[98]; // pow(trace_generator, 161).
+    autogenerated_pows[100] = autogenerated_pows[27] * autogenerated_pows[99]; // pow(trace_generator, 166).
+    autogenerated_pows[101] = autogenerated_pows[17] * autogenerated_pows[100]; // pow(trace_generator, 167).
+    autogenerated_pows[102] = autogenerated_pows[27] * autogenerated_pows[101]; // pow(trace_generator, 172).
+    autogenerated_pows[103] = autogenerated_pows[27] * autogenerated_pows[102]; // pow(trace_generator, 177).
+    autogenerated_pows[104] = autogenerated_pows[33] * autogenerated_pows[103]; // pow(trace_generator, 187).
+    autogenerated_pows[105] = autogenerated_pows[27] * autogenerated_pows[104]; // pow(trace_generator, 192).
+    autogenerated_pows[106] = autogenerated_pows[17] * autogenerated_pows[105]; // pow(trace_generator, 193).
+    autogenerated_pows[107] = autogenerated_pows[24] * autogenerated_pows[106]; // pow(trace_generator, 195).
+    autogenerated_pows[108] = autogenerated_pows[17] * autogenerated_pows[107]; // pow(trace_generator, 196).
+    autogenerated_pows[109] = autogenerated_pows[17] * autogenerated_pows[108]; // pow(trace_generator, 197).
+    autogenerated_pows[110] = autogenerated_pows[17] * autogenerated_pows[109]; // pow(trace_generator, 198).
+    autogenerated_pows[111] = autogenerated_pows[17] * autogenerated_pows[110]; // pow(trace_generator, 199).
+    autogenerated_pows[112] = autogenerated_pows[27] * autogenerated_pows[111]; // pow(trace_generator, 204).
+    autogenerated_pows[113] = autogenerated_pows[17] * autogenerated_pows[112]; // pow(trace_generator, 205).
+    autogenerated_pows[114] = autogenerated_pows[26] * autogenerated_pows[113]; // pow(trace_generator, 209).
+    autogenerated_pows[115] = autogenerated_pows[33] * autogenerated_pows[114]; // pow(trace_generator, 219).
+    autogenerated_pows[116] = autogenerated_pows[24] * autogenerated_pows[115]; // pow(trace_generator, 221).
+    autogenerated_pows[117] = autogenerated_pows[26] * autogenerated_pows[116]; // pow(trace_generator, 225).
+    autogenerated_pows[118] = autogenerated_pows[34] * autogenerated_pows[117]; // pow(trace_generator, 236).
+    autogenerated_pows[119] = autogenerated_pows[17] * autogenerated_pows[118]; // pow(trace_generator, 237).
+    autogenerated_pows[120] = autogenerated_pows[26] * autogenerated_pows[119]; // pow(trace_generator, 241).
+    autogenerated_pows[121] = autogenerated_pows[26] * autogenerated_pows[120]; // pow(trace_generator, 245).
+    autogenerated_pows[122] = autogenerated_pows[28] * autogenerated_pows[121]; // pow(trace_generator, 251).
+    autogenerated_pows[123] = autogenerated_pows[17] * autogenerated_pows[122]; // pow(trace_generator, 252).
+    autogenerated_pows[124] = autogenerated_pows[4] * autogenerated_pows[35]; // pow(trace_generator, 16337).
+    autogenerated_pows[125] = autogenerated_pows[4] * autogenerated_pows[37]; // pow(trace_generator, 16339).
+    autogenerated_pows[126] = autogenerated_pows[4] * autogenerated_pows[49]; // pow(trace_generator, 16355).
+    autogenerated_pows[127] = autogenerated_pows[24] * autogenerated_pows[126]; // pow(trace_generator, 16357).
+    autogenerated_pows[128] = autogenerated_pows[4] * autogenerated_pows[53]; // pow(trace_generator, 16363).
+    autogenerated_pows[129] = autogenerated_pows[4] * autogenerated_pows[57]; // pow(trace_generator, 16369).
+    autogenerated_pows[130] = autogenerated_pows[4] * autogenerated_pows[59]; // pow(trace_generator, 16371).
+    autogenerated_pows[131] = autogenerated_pows[5] * autogenerated_pows[118]; // pow(trace_generator, 16385).
+    autogenerated_pows[132] = autogenerated_pows[59] * autogenerated_pows[130]; // pow(trace_generator, 16417).
+    autogenerated_pows[133] = autogenerated_pows[17] * autogenerated_pows[123]; // pow(trace_generator, 253).
+    autogenerated_pows[134] = autogenerated_pows[24] * autogenerated_pows[133]; // pow(trace_generator, 255).
+    autogenerated_pows[135] = autogenerated_pows[17] * autogenerated_pows[134]; // pow(trace_generator, 256).
+    autogenerated_pows[136] = autogenerated_pows[17] * autogenerated_pows[135]; // pow(trace_generator, 257).
+    autogenerated_pows[137] = autogenerated_pows[7] * autogenerated_pows[135]; // pow(trace_generator, 12629).
+    autogenerated_pows[138] = autogenerated_pows[7] * autogenerated_pows[105]; // pow(trace_generator, 12565).
+    autogenerated_pows[139] = autogenerated_pows[60] * autogenerated_pows[137]; // pow(trace_generator, 12678).
+    autogenerated_pows[140] = autogenerated_pows[17] * autogenerated_pows[139]; // pow(trace_generator, 12679).
+    autogenerated_pows[141] = autogenerated_pows[27] * autogenerated_pows[136]; // pow(trace_generator, 262).
+    autogenerated_pows[142] = autogenerated_pows[17] * autogenerated_pows[141]; // pow(trace_generator, 263).
+    autogenerated_pows[143] = autogenerated_pows[24] * autogenerated_pows[142]; // pow(trace_generator, 265).
+    autogenerated_pows[144] = autogenerated_pows[26] * autogenerated_pows[143]; // pow(trace_generator, 269).
+    autogenerated_pows[145] = autogenerated_pows[46] * autogenerated_pows[144]; // pow(trace_generator, 294).
+    autogenerated_pows[146] = autogenerated_pows[17] * autogenerated_pows[145]; // pow(trace_generator, 295).
+    autogenerated_pows[147] = autogenerated_pows[28] * autogenerated_pows[146]; // pow(trace_generator, 301).
+    autogenerated_pows[148] = autogenerated_pows[31] * autogenerated_pows[147]; // pow(trace_generator, 309).
+    autogenerated_pows[149] = autogenerated_pows[17] * autogenerated_pows[148]; // pow(trace_generator, 310).
+    autogenerated_pows[150] = autogenerated_pows[31] * autogenerated_pows[149]; // pow(trace_generator, 318).
+    autogenerated_pows[151] = autogenerated_pows[90] * autogenerated_pows[148]; // pow(trace_generator, 422).
+    autogenerated_pows[152] = autogenerated_pows[79] * autogenerated_pows[148]; // pow(trace_generator, 390).
+    autogenerated_pows[153] = autogenerated_pows[31] * autogenerated_pows[150]; // pow(trace_generator, 326).
+    autogenerated_pows[154] = autogenerated_pows[31] * autogenerated_pows[153]; // pow(trace_generator, 334).
+    autogenerated_pows[155] = autogenerated_pows[31] * autogenerated_pows[154]; // pow(trace_generator, 342).
+    autogenerated_pows[156] = autogenerated_pows[31] * autogenerated_pows[155]; // pow(trace_generator, 350).
+    autogenerated_pows[157] = autogenerated_pows[31] * autogenerated_pows[156]; // pow(trace_generator, 358).
+    autogenerated_pows[158] = autogenerated_pows[17] * autogenerated_pows[151]; // pow(trace_generator, 423).
+    autogenerated_pows[159] = autogenerated_pows[17] * autogenerated_pows[152]; // pow(trace_generator, 391).
+    autogenerated_pows[160] = autogenerated_pows[17] * autogenerated_pows[157]; // pow(trace_generator, 359).
+    autogenerated_pows[161] = autogenerated_pows[10] * autogenerated_pows[17]; // pow(trace_generator, 16775).
+    autogenerated_pows[162] = autogenerated_pows[48] * autogenerated_pows[151]; // pow(trace_generator, 451).
+    autogenerated_pows[163] = autogenerated_pows[25] * autogenerated_pows[162]; // pow(trace_generator, 454).
+    autogenerated_pows[164] = autogenerated_pows[30] * autogenerated_pows[163]; // pow(trace_generator, 461).
+    autogenerated_pows[165] = autogenerated_pows[39] * autogenerated_pows[164]; // pow(trace_generator, 477).
+    autogenerated_pows[166] = autogenerated_pows[37] * autogenerated_pows[165]; // pow(trace_generator, 491).
+    autogenerated_pows[167] = autogenerated_pows[24] * autogenerated_pows[166]; // pow(trace_generator, 493).
+    autogenerated_pows[168] = autogenerated_pows[28] * autogenerated_pows[167]; // pow(trace_generator, 499).
+    autogenerated_pows[169] = autogenerated_pows[24] * autogenerated_pows[168]; // pow(trace_generator, 501).
+    autogenerated_pows[170] = autogenerated_pows[28] * autogenerated_pows[169]; // pow(trace_generator, 507).
+    autogenerated_pows[171] = autogenerated_pows[24] * autogenerated_pows[170]; // pow(trace_generator, 509).
+    autogenerated_pows[172] = autogenerated_pows[24] * autogenerated_pows[171]; // pow(trace_generator, 511).
+    autogenerated_pows[173] = autogenerated_pows[2] * autogenerated_pows[166]; // pow(trace_generator, 33158).
+    autogenerated_pows[174] = autogenerated_pows[24] * autogenerated_pows[172]; // pow(trace_generator, 513).
+    autogenerated_pows[175] = autogenerated_pows[27] * autogenerated_pows[174]; // pow(trace_generator, 518).
+    autogenerated_pows[176] = autogenerated_pows[104] * autogenerated_pows[175]; // pow(trace_generator, 705).
+    autogenerated_pows[177] = autogenerated_pows[109] * autogenerated_pows[176]; // pow(trace_generator, 902).
+    autogenerated_pows[178] = autogenerated_pows[28] * autogenerated_pows[176]; // pow(trace_generator, 711).
+    autogenerated_pows[179] = autogenerated_pows[33] * autogenerated_pows[178]; // pow(trace_generator, 721).
+    autogenerated_pows[180] = autogenerated_pows[39] * autogenerated_pows[179]; // pow(trace_generator, 737).
+    autogenerated_pows[181] = autogenerated_pows[39] * autogenerated_pows[180]; // pow(trace_generator, 753).
+    autogenerated_pows[182] = autogenerated_pows[39] * autogenerated_pows[181]; // pow(trace_generator, 769).
+    autogenerated_pows[183] = autogenerated_pows[70] * autogenerated_pows[177]; // pow(trace_generator, 961).
+    autogenerated_pows[184] = autogenerated_pows[27] * autogenerated_pows[183]; // pow(trace_generator, 966).
+    autogenerated_pows[185] = autogenerated_pows[17] * autogenerated_pows[184]; // pow(trace_generator, 967).
+    autogenerated_pows[186] = autogenerated_pows[33] * autogenerated_pows[185]; // pow(trace_generator, 977).
+    autogenerated_pows[187] = autogenerated_pows[121] * autogenerated_pows[186]; // pow(trace_generator, 1222).
+    autogenerated_pows[188] = autogenerated_pows[17] * autogenerated_pows[177]; // pow(trace_generator, 903).
+    autogenerated_pows[189] = autogenerated_pows[39] * autogenerated_pows[186]; // pow(trace_generator, 993).
+    autogenerated_pows[190] = autogenerated_pows[39] * autogenerated_pows[189]; // pow(trace_generator, 1009).
+    autogenerated_pows[191] = autogenerated_pows[25] * autogenerated_pows[175]; // pow(trace_generator, 521).
+    autogenerated_pows[192] = autogenerated_pows[31] * autogenerated_pows[182]; // pow(trace_generator, 777).
+}
+
+pub fn eval_oods_constraints(
+    column_values: &[Felt],
+    oods_values: &[Felt],
+    constraint_coefficients: &[Felt],
+    autogenerated_pows: &[Felt],
+    point: Felt,
+    oods_point: Felt,
+) -> Felt {
+    let column0 = column_values[0];
+    let column1 = column_values[1];
+    let column2 = column_values[2];
+    let column3 = column_values[3];
+    let column4 = column_values[4];
+    let column5 = column_values[5];
+    let column6 = column_values[6];
+    let column7 = column_values[7];
+    let column8 = column_values[8];
+    let column9 = column_values[9];
+
+    // Sum the OODS constraints on the trace polynomials.
+    let mut total_sum = FELT_0;
+
+    let value = (column0 - oods_values[0])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[0] * value;
+
+    let value = (column0 - oods_values[1])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[1] * value;
+
+    let value = (column0 - oods_values[2])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[24] * oods_point));
+    total_sum += constraint_coefficients[2] * value;
+
+    let value = (column0 - oods_values[3])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[25] * oods_point));
+    total_sum += constraint_coefficients[3] * value;
+
+    let value = (column0 - oods_values[4])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[26] * oods_point));
+    total_sum += constraint_coefficients[4] * value;
+
+    let value = (column0 - oods_values[5])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[27] * oods_point));
+    total_sum += constraint_coefficients[5] * value;
+
+    let value = (column0 - oods_values[6])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[28] * oods_point));
+    total_sum += constraint_coefficients[6] * value;
+
+    let value = (column0 - oods_values[7])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[30] * oods_point));
+    total_sum += constraint_coefficients[7] * value;
+
+    let value = (column0 - oods_values[8])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[31] * oods_point));
+    total_sum += constraint_coefficients[8] * value;
+
+    let value = (column0 - oods_values[9])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[32] * oods_point));
+    total_sum += constraint_coefficients[9] * value;
+
+    let value = (column0 - oods_values[10])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[33] * oods_point));
+    total_sum += constraint_coefficients[10] * value;
+
+    let value = (column0 - oods_values[11])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[34] * oods_point));
+    total_sum += constraint_coefficients[11] * value;
+
+    let value = (column0 - oods_values[12])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[35] * oods_point));
+    total_sum += constraint_coefficients[12] * value;
+
+    let value = (column0 - oods_values[13])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[36] * oods_point));
+    total_sum += constraint_coefficients[13] * value;
+
+    let value = (column0 - oods_values[14])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[37] * oods_point));
+    total_sum += constraint_coefficients[14] * value;
+
+    let value = (column0 - oods_values[15])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[38] * oods_point));
+    total_sum += constraint_coefficients[15] * value;
+
+    let value = (column1 - oods_values[16])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[16] * value;
+
+    let value = (column1 - oods_values[17])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[17] * value;
+
+    let value = (column1 - oods_values[18])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[134] * oods_point));
+    total_sum += constraint_coefficients[18] * value;
+
+    let value = (column1 - oods_values[19])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[135] * oods_point));
+    total_sum += constraint_coefficients[19] * value;
+
+    let value = (column1 - oods_values[20])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[172] * oods_point));
+    total_sum += constraint_coefficients[20] * value;
+
+    let value = (column2 - oods_values[21])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[21] * value;
+
+    let value = (column2 - oods_values[22])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[22] * value;
+
+    let value = (column2 - oods_values[23])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[134] * oods_point));
+    total_sum += constraint_coefficients[23] * value;
+
+    let value = (column2 - oods_values[24])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[135] * oods_point));
+    total_sum += constraint_coefficients[24] * value;
+
+    let value = (column3 - oods_values[25])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[25] * value;
+
+    let value = (column3 - oods_values[26])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[26] * value;
+
+    let value = (column3 - oods_values[27])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[105] * oods_point));
+    total_sum += constraint_coefficients[27] * value;
+
+    let value = (column3 - oods_values[28])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[106] * oods_point));
+    total_sum += constraint_coefficients[28] * value;
+
+    let value = (column3 - oods_values[29])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[108] * oods_point));
+    total_sum += constraint_coefficients[29] * value;
+
+    let value = (column3 - oods_values[30])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[109] * oods_point));
+    total_sum += constraint_coefficients[30] * value;
+
+    let value = (column3 - oods_values[31])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[122] * oods_point));
+    total_sum += constraint_coefficients[31] * value;
+
+    let value = (column3 - oods_values[32])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[123] * oods_point));
+    total_sum += constraint_coefficients[32] * value;
+
+    let value = (column3 - oods_values[33])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[135] * oods_point));
+    total_sum += constraint_coefficients[33] * value;
+
+    let value = (column4 - oods_values[34])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[34] * value;
+
+    let value = (column4 - oods_values[35])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[134] * oods_point));
+    total_sum += constraint_coefficients[35] * value;
+
+    let value = (column5 - oods_values[36])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[36] * value;
+
+    let value = (column5 - oods_values[37])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[37] * value;
+
+    let value = (column5 - oods_values[38])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[24] * oods_point));
+    total_sum += constraint_coefficients[38] * value;
+
+    let value = (column5 - oods_values[39])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[25] * oods_point));
+    total_sum += constraint_coefficients[39] * value;
+
+    let value = (column5 - oods_values[40])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[26] * oods_point));
+    total_sum += constraint_coefficients[40] * value;
+
+    let value = (column5 - oods_values[41])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[27] * oods_point));
+    total_sum += constraint_coefficients[41] * value;
+
+    let value = (column5 - oods_values[42])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[28] * oods_point));
+    total_sum += constraint_coefficients[42] * value;
+
+    let value = (column5 - oods_values[43])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[30] * oods_point));
+    total_sum += constraint_coefficients[43] * value;
+
+    let value = (column5 - oods_values[44])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[31] * oods_point));
+    total_sum += constraint_coefficients[44] * value;
+
+    let value = (column5 - oods_values[45])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[32] * oods_point));
+    total_sum += constraint_coefficients[45] * value;
+
+    let value = (column5 - oods_values[46])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[35] * oods_point));
+    total_sum += constraint_coefficients[46] * value;
+
+    let value = (column5 - oods_values[47])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[36] * oods_point));
+    total_sum += constraint_coefficients[47] * value;
+
+    let value = (column5 - oods_values[48])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[39] * oods_point));
+    total_sum += constraint_coefficients[48] * value;
+
+    let value = (column5 - oods_values[49])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[53] * oods_point));
+    total_sum += constraint_coefficients[49] * value;
+
+    let value = (column5 - oods_values[50])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[54] * oods_point));
+    total_sum += constraint_coefficients[50] * value;
+
+    let value = (column5 - oods_values[51])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[74] * oods_point));
+    total_sum += constraint_coefficients[51] * value;
+
+    let value = (column5 - oods_values[52])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[75] * oods_point));
+    total_sum += constraint_coefficients[52] * value;
+
+    let value = (column5 - oods_values[53])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[85] * oods_point));
+    total_sum += constraint_coefficients[53] * value;
+
+    let value = (column5 - oods_values[54])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[86] * oods_point));
+    total_sum += constraint_coefficients[54] * value;
+
+    let value = (column5 - oods_values[55])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[94] * oods_point));
+    total_sum += constraint_coefficients[55] * value;
+
+    let value = (column5 - oods_values[56])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[95] * oods_point));
+    total_sum += constraint_coefficients[56] * value;
+
+    let value = (column5 - oods_values[57])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[100] * oods_point));
+    total_sum += constraint_coefficients[57] * value;
+
+    let value = (column5 - oods_values[58])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[101] * oods_point));
+    total_sum += constraint_coefficients[58] * value;
+
+    let value = (column5 - oods_values[59])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[110] * oods_point));
+    total_sum += constraint_coefficients[59] * value;
+
+    let value = (column5 - oods_values[60])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[111] * oods_point));
+    total_sum += constraint_coefficients[60] * value;
+
+    let value = (column5 - oods_values[61])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[141] * oods_point));
+    total_sum += constraint_coefficients[61] * value;
+
+    let value = (column5 - oods_values[62])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[142] * oods_point));
+    total_sum += constraint_coefficients[62] * value;
+
+    let value = (column5 - oods_values[63])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[145] * oods_point));
+    total_sum += constraint_coefficients[63] * value;
+
+    let value = (column5 - oods_values[64])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[146] * oods_point));
+    total_sum += constraint_coefficients[64] * value;
+
+    let value = (column5 - oods_values[65])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[153] * oods_point));
+    total_sum += constraint_coefficients[65] * value;
+
+    let value = (column5 - oods_values[66])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[157] * oods_point));
+    total_sum += constraint_coefficients[66] * value;
+
+    let value = (column5 - oods_values[67])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[160] * oods_point));
+    total_sum += constraint_coefficients[67] * value;
+
+    let value = (column5 - oods_values[68])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[152] * oods_point));
+    total_sum += constraint_coefficients[68] * value;
+
+    let value = (column5 - oods_values[69])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[159] * oods_point));
+    total_sum += constraint_coefficients[69] * value;
+
+    let value = (column5 - oods_values[70])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[151] * oods_point));
+    total_sum += constraint_coefficients[70] * value;
+
+    let value = (column5 - oods_values[71])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[158] * oods_point));
+    total_sum += constraint_coefficients[71] * value;
+
+    let value = (column5 - oods_values[72])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[163] * oods_point));
+    total_sum += constraint_coefficients[72] * value;
+
+    let value = (column5 - oods_values[73])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[175] * oods_point));
+    total_sum += constraint_coefficients[73] * value;
+
+    let value = (column5 - oods_values[74])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[178] * oods_point));
+    total_sum += constraint_coefficients[74] * value;
+
+    let value = (column5 - oods_values[75])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[177] * oods_point));
+    total_sum += constraint_coefficients[75] * value;
+
+    let value = (column5 - oods_values[76])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[188] * oods_point));
+    total_sum += constraint_coefficients[76] * value;
+
+    let value = (column5 - oods_values[77])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[184] * oods_point));
+    total_sum += constraint_coefficients[77] * value;
+
+    let value = (column5 - oods_values[78])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[185] * oods_point));
+    total_sum += constraint_coefficients[78] * value;
+
+    let value = (column5 - oods_values[79])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[187] * oods_point));
+    total_sum += constraint_coefficients[79] * value;
+
+    let value = (column5 - oods_values[80])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[16] * oods_point));
+    total_sum += constraint_coefficients[80] * value;
+
+    let value = (column5 - oods_values[81])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[23] * oods_point));
+    total_sum += constraint_coefficients[81] * value;
+
+    let value = (column5 - oods_values[82])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[15] * oods_point));
+    total_sum += constraint_coefficients[82] * value;
+
+    let value = (column5 - oods_values[83])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[22] * oods_point));
+    total_sum += constraint_coefficients[83] * value;
+
+    let value = (column5 - oods_values[84])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[14] * oods_point));
+    total_sum += constraint_coefficients[84] * value;
+
+    let value = (column5 - oods_values[85])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[21] * oods_point));
+    total_sum += constraint_coefficients[85] * value;
+
+    let value = (column5 - oods_values[86])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[13] * oods_point));
+    total_sum += constraint_coefficients[86] * value;
+
+    let value = (column5 - oods_values[87])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[20] * oods_point));
+    total_sum += constraint_coefficients[87] * value;
+
+    let value = (column5 - oods_values[88])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[12] * oods_point));
+    total_sum += constraint_coefficients[88] * value;
+
+    let value = (column5 - oods_values[89])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[19] * oods_point));
+    total_sum += constraint_coefficients[89] * value;
+
+    let value = (column5 - oods_values[90])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[139] * oods_point));
+    total_sum += constraint_coefficients[90] * value;
+
+    let value = (column5 - oods_values[91])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[140] * oods_point));
+    total_sum += constraint_coefficients[91] * value;
+
+    let value = (column5 - oods_values[92])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[11] * oods_point));
+    total_sum += constraint_coefficients[92] * value;
+
+    let value = (column5 - oods_values[93])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[18] * oods_point));
+    total_sum += constraint_coefficients[93] * value;
+
+    let value = (column5 - oods_values[94])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[10] * oods_point));
+    total_sum += constraint_coefficients[94] * value;
+
+    let value = (column5 - oods_values[95])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[161] * oods_point));
+    total_sum += constraint_coefficients[95] * value;
+
+    let value = (column5 - oods_values[96])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[9] * oods_point));
+    total_sum += constraint_coefficients[96] * value;
+
+    let value = (column5 - oods_values[97])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[173] * oods_point));
+    total_sum += constraint_coefficients[97] * value;
+
+    let value = (column6 - oods_values[98])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[98] * value;
+
+    let value = (column6 - oods_values[99])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[99] * value;
+
+    let value = (column6 - oods_values[100])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[24] * oods_point));
+    total_sum += constraint_coefficients[100] * value;
+
+    let value = (column6 - oods_values[101])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[25] * oods_point));
+    total_sum += constraint_coefficients[101] * value;
+
+    let value = (column7 - oods_values[102])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[102] * value;
+
+    let value = (column7 - oods_values[103])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[103] * value;
+
+    let value = (column7 - oods_values[104])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[24] * oods_point));
+    total_sum += constraint_coefficients[104] * value;
+
+    let value = (column7 - oods_values[105])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[25] * oods_point));
+    total_sum += constraint_coefficients[105] * value;
+
+    let value = (column7 - oods_values[106])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[26] * oods_point));
+    total_sum += constraint_coefficients[106] * value;
+
+    let value = (column7 - oods_values[107])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[27] * oods_point));
+    total_sum += constraint_coefficients[107] * value;
+
+    let value = (column7 - oods_values[108])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[28] * oods_point));
+    total_sum += constraint_coefficients[108] * value;
+
+    let value = (column7 - oods_values[109])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[30] * oods_point));
+    total_sum += constraint_coefficients[109] * value;
+
+    let value = (column7 - oods_values[110])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[31] * oods_point));
+    total_sum += constraint_coefficients[110] * value;
+
+    let value = (column7 - oods_values[111])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[32] * oods_point));
+    total_sum += constraint_coefficients[111] * value;
+
+    let value = (column7 - oods_values[112])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[34] * oods_point));
+    total_sum += constraint_coefficients[112] * value;
+
+    let value = (column7 - oods_values[113])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[35] * oods_point));
+    total_sum += constraint_coefficients[113] * value;
+
+    let value = (column7 - oods_values[114])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[36] * oods_point));
+    total_sum += constraint_coefficients[114] * value;
+
+    let value = (column7 - oods_values[115])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[38] * oods_point));
+    total_sum += constraint_coefficients[115] * value;
+
+    let value = (column7 - oods_values[116])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[40] * oods_point));
+    total_sum += constraint_coefficients[116] * value;
+
+    let value = (column7 - oods_values[117])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[41] * oods_point));
+    total_sum += constraint_coefficients[117] * value;
+
+    let value = (column7 - oods_values[118])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[44] * oods_point));
+    total_sum += constraint_coefficients[118] * value;
+
+    let value = (column7 - oods_values[119])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[47] * oods_point));
+    total_sum += constraint_coefficients[119] * value;
+
+    let value = (column7 - oods_values[120])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[50] * oods_point));
+    total_sum += constraint_coefficients[120] * value;
+
+    let value = (column7 - oods_values[121])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[57] * oods_point));
+    total_sum += constraint_coefficients[121] * value;
+
+    let value = (column7 - oods_values[122])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[60] * oods_point));
+    total_sum += constraint_coefficients[122] * value;
+
+    let value = (column7 - oods_values[123])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[123] * value;
+
+    let value = (column7 - oods_values[124])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[77] * oods_point));
+    total_sum += constraint_coefficients[124] * value;
+
+    let value = (column7 - oods_values[125])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[79] * oods_point));
+    total_sum += constraint_coefficients[125] * value;
+
+    let value = (column7 - oods_values[126])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[83] * oods_point));
+    total_sum += constraint_coefficients[126] * value;
+
+    let value = (column7 - oods_values[127])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[88] * oods_point));
+    total_sum += constraint_coefficients[127] * value;
+
+    let value = (column7 - oods_values[128])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[90] * oods_point));
+    total_sum += constraint_coefficients[128] * value;
+
+    let value = (column7 - oods_values[129])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[93] * oods_point));
+    total_sum += constraint_coefficients[129] * value;
+
+    let value = (column7 - oods_values[130])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[96] * oods_point));
+    total_sum += constraint_coefficients[130] * value;
+
+    let value = (column7 - oods_values[131])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[97] * oods_point));
+    total_sum += constraint_coefficients[131] * value;
+
+    let value = (column7 - oods_values[132])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[99] * oods_point));
+    total_sum += constraint_coefficients[132] * value;
+
+    let value = (column7 - oods_values[133])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[102] * oods_point));
+    total_sum += constraint_coefficients[133] * value;
+
+    let value = (column7 - oods_values[134])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[103] * oods_point));
+    total_sum += constraint_coefficients[134] * value;
+
+    let value = (column7 - oods_values[135])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[106] * oods_point));
+    total_sum += constraint_coefficients[135] * value;
+
+    let value = (column7 - oods_values[136])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[112] * oods_point));
+    total_sum += constraint_coefficients[136] * value;
+
+    let value = (column7 - oods_values[137])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[114] * oods_point));
+    total_sum += constraint_coefficients[137] * value;
+
+    let value = (column7 - oods_values[138])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[117] * oods_point));
+    total_sum += constraint_coefficients[138] * value;
+
+    let value = (column7 - oods_values[139])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[118] * oods_point));
+    total_sum += constraint_coefficients[139] * value;
+
+    let value = (column7 - oods_values[140])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[120] * oods_point));
+    total_sum += constraint_coefficients[140] * value;
+
+    let value = (column7 - oods_values[141])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[136] * oods_point));
+    total_sum += constraint_coefficients[141] * value;
+
+    let value = (column7 - oods_values[142])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[143] * oods_point));
+    total_sum += constraint_coefficients[142] * value;
+
+    let value = (column7 - oods_values[143])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[166] * oods_point));
+    total_sum += constraint_coefficients[143] * value;
+
+    let value = (column7 - oods_values[144])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[168] * oods_point));
+    total_sum += constraint_coefficients[144] * value;
+
+    let value = (column7 - oods_values[145])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[170] * oods_point));
+    total_sum += constraint_coefficients[145] * value;
+
+    let value = (column7 - oods_values[146])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[174] * oods_point));
+    total_sum += constraint_coefficients[146] * value;
+
+    let value = (column7 - oods_values[147])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[191] * oods_point));
+    total_sum += constraint_coefficients[147] * value;
+
+    let value = (column7 - oods_values[148])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[176] * oods_point));
+    total_sum += constraint_coefficients[148] * value;
+
+    let value = (column7 - oods_values[149])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[179] * oods_point));
+    total_sum += constraint_coefficients[149] * value;
+
+    let value = (column7 - oods_values[150])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[180] * oods_point));
+    total_sum += constraint_coefficients[150] * value;
+
+    let value = (column7 - oods_values[151])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[181] * oods_point));
+    total_sum += constraint_coefficients[151] * value;
+
+    let value = (column7 - oods_values[152])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[182] * oods_point));
+    total_sum += constraint_coefficients[152] * value;
+
+    let value = (column7 - oods_values[153])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[192] * oods_point));
+    total_sum += constraint_coefficients[153] * value;
+
+    let value = (column7 - oods_values[154])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[183] * oods_point));
+    total_sum += constraint_coefficients[154] * value;
+
+    let value = (column7 - oods_values[155])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[186] * oods_point));
+    total_sum += constraint_coefficients[155] * value;
+
+    let value = (column7 - oods_values[156])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[189] * oods_point));
+    total_sum += constraint_coefficients[156] * value;
+
+    let value = (column7 - oods_values[157])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[190] * oods_point));
+    total_sum += constraint_coefficients[157] * value;
+
+    let value = (column8 - oods_values[158])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[158] * value;
+
+    let value = (column8 - oods_values[159])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[159] * value;
+
+    let value = (column8 - oods_values[160])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[24] * oods_point));
+    total_sum += constraint_coefficients[160] * value;
+
+    let value = (column8 - oods_values[161])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[25] * oods_point));
+    total_sum += constraint_coefficients[161] * value;
+
+    let value = (column8 - oods_values[162])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[26] * oods_point));
+    total_sum += constraint_coefficients[162] * value;
+
+    let value = (column8 - oods_values[163])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[27] * oods_point));
+    total_sum += constraint_coefficients[163] * value;
+
+    let value = (column8 - oods_values[164])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[28] * oods_point));
+    total_sum += constraint_coefficients[164] * value;
+
+    let value = (column8 - oods_values[165])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[30] * oods_point));
+    total_sum += constraint_coefficients[165] * value;
+
+    let value = (column8 - oods_values[166])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[31] * oods_point));
+    total_sum += constraint_coefficients[166] * value;
+
+    let value = (column8 - oods_values[167])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[32] * oods_point));
+    total_sum += constraint_coefficients[167] * value;
+
+    let value = (column8 - oods_values[168])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[33] * oods_point));
+    total_sum += constraint_coefficients[168] * value;
+
+    let value = (column8 - oods_values[169])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[34] * oods_point));
+    total_sum += constraint_coefficients[169] * value;
+
+    let value = (column8 - oods_values[170])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[35] * oods_point));
+    total_sum += constraint_coefficients[170] * value;
+
+    let value = (column8 - oods_values[171])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[36] * oods_point));
+    total_sum += constraint_coefficients[171] * value;
+
+    let value = (column8 - oods_values[172])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[37] * oods_point));
+    total_sum += constraint_coefficients[172] * value;
+
+    let value = (column8 - oods_values[173])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[39] * oods_point));
+    total_sum += constraint_coefficients[173] * value;
+
+    let value = (column8 - oods_values[174])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[40] * oods_point));
+    total_sum += constraint_coefficients[174] * value;
+
+    let value = (column8 - oods_values[175])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[41] * oods_point));
+    total_sum += constraint_coefficients[175] * value;
+
+    let value = (column8 - oods_values[176])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[42] * oods_point));
+    total_sum += constraint_coefficients[176] * value;
+
+    let value = (column8 - oods_values[177])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[43] * oods_point));
+    total_sum += constraint_coefficients[177] * value;
+
+    let value = (column8 - oods_values[178])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[45] * oods_point));
+    total_sum += constraint_coefficients[178] * value;
+
+    let value = (column8 - oods_values[179])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[46] * oods_point));
+    total_sum += constraint_coefficients[179] * value;
+
+    let value = (column8 - oods_values[180])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[47] * oods_point));
+    total_sum += constraint_coefficients[180] * value;
+
+    let value = (column8 - oods_values[181])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[48] * oods_point));
+    total_sum += constraint_coefficients[181] * value;
+
+    let value = (column8 - oods_values[182])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[49] * oods_point));
+    total_sum += constraint_coefficients[182] * value;
+
+    let value = (column8 - oods_values[183])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[50] * oods_point));
+    total_sum += constraint_coefficients[183] * value;
+
+    let value = (column8 - oods_values[184])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[51] * oods_point));
+    total_sum += constraint_coefficients[184] * value;
+
+    let value = (column8 - oods_values[185])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[52] * oods_point));
+    total_sum += constraint_coefficients[185] * value;
+
+    let value = (column8 - oods_values[186])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[53] * oods_point));
+    total_sum += constraint_coefficients[186] * value;
+
+    let value = (column8 - oods_values[187])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[55] * oods_point));
+    total_sum += constraint_coefficients[187] * value;
+
+    let value = (column8 - oods_values[188])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[56] * oods_point));
+    total_sum += constraint_coefficients[188] * value;
+
+    let value = (column8 - oods_values[189])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[58] * oods_point));
+    total_sum += constraint_coefficients[189] * value;
+
+    let value = (column8 - oods_values[190])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[59] * oods_point));
+    total_sum += constraint_coefficients[190] * value;
+
+    let value = (column8 - oods_values[191])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[60] * oods_point));
+    total_sum += constraint_coefficients[191] * value;
+
+    let value = (column8 - oods_values[192])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[61] * oods_point));
+    total_sum += constraint_coefficients[192] * value;
+
+    let value = (column8 - oods_values[193])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[62] * oods_point));
+    total_sum += constraint_coefficients[193] * value;
+
+    let value = (column8 - oods_values[194])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[63] * oods_point));
+    total_sum += constraint_coefficients[194] * value;
+
+    let value = (column8 - oods_values[195])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[69] * oods_point));
+    total_sum += constraint_coefficients[195] * value;
+
+    let value = (column8 - oods_values[196])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[70] * oods_point));
+    total_sum += constraint_coefficients[196] * value;
+
+    let value = (column8 - oods_values[197])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[71] * oods_point));
+    total_sum += constraint_coefficients[197] * value;
+
+    let value = (column8 - oods_values[198])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[72] * oods_point));
+    total_sum += constraint_coefficients[198] * value;
+
+    let value = (column8 - oods_values[199])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[73] * oods_point));
+    total_sum += constraint_coefficients[199] * value;
+
+    let value = (column8 - oods_values[200])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[75] * oods_point));
+    total_sum += constraint_coefficients[200] * value;
+
+    let value = (column8 - oods_values[201])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[76] * oods_point));
+    total_sum += constraint_coefficients[201] * value;
+
+    let value = (column8 - oods_values[202])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[78] * oods_point));
+    total_sum += constraint_coefficients[202] * value;
+
+    let value = (column8 - oods_values[203])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[79] * oods_point));
+    total_sum += constraint_coefficients[203] * value;
+
+    let value = (column8 - oods_values[204])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[80] * oods_point));
+    total_sum += constraint_coefficients[204] * value;
+
+    let value = (column8 - oods_values[205])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[81] * oods_point));
+    total_sum += constraint_coefficients[205] * value;
+
+    let value = (column8 - oods_values[206])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[82] * oods_point));
+    total_sum += constraint_coefficients[206] * value;
+
+    let value = (column8 - oods_values[207])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[83] * oods_point));
+    total_sum += constraint_coefficients[207] * value;
+
+    let value = (column8 - oods_values[208])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[84] * oods_point));
+    total_sum += constraint_coefficients[208] * value;
+
+    let value = (column8 - oods_values[209])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[87] * oods_point));
+    total_sum += constraint_coefficients[209] * value;
+
+    let value = (column8 - oods_values[210])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[89] * oods_point));
+    total_sum += constraint_coefficients[210] * value;
+
+    let value = (column8 - oods_values[211])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[90] * oods_point));
+    total_sum += constraint_coefficients[211] * value;
+
+    let value = (column8 - oods_values[212])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[91] * oods_point));
+    total_sum += constraint_coefficients[212] * value;
+
+    let value = (column8 - oods_values[213])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[92] * oods_point));
+    total_sum += constraint_coefficients[213] * value;
+
+    let value = (column8 - oods_values[214])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[98] * oods_point));
+    total_sum += constraint_coefficients[214] * value;
+
+    let value = (column8 - oods_values[215])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[104] * oods_point));
+    total_sum += constraint_coefficients[215] * value;
+
+    let value = (column8 - oods_values[216])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[107] * oods_point));
+    total_sum += constraint_coefficients[216] * value;
+
+    let value = (column8 - oods_values[217])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[113] * oods_point));
+    total_sum += constraint_coefficients[217] * value;
+
+    let value = (column8 - oods_values[218])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[115] * oods_point));
+    total_sum += constraint_coefficients[218] * value;
+
+    let value = (column8 - oods_values[219])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[116] * oods_point));
+    total_sum += constraint_coefficients[219] * value;
+
+    let value = (column8 - oods_values[220])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[119] * oods_point));
+    total_sum += constraint_coefficients[220] * value;
+
+    let value = (column8 - oods_values[221])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[121] * oods_point));
+    total_sum += constraint_coefficients[221] * value;
+
+    let value = (column8 - oods_values[222])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[133] * oods_point));
+    total_sum += constraint_coefficients[222] * value;
+
+    let value = (column8 - oods_values[223])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[144] * oods_point));
+    total_sum += constraint_coefficients[223] * value;
+
+    let value = (column8 - oods_values[224])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[147] * oods_point));
+    total_sum += constraint_coefficients[224] * value;
+
+    let value = (column8 - oods_values[225])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[148] * oods_point));
+    total_sum += constraint_coefficients[225] * value;
+
+    let value = (column8 - oods_values[226])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[149] * oods_point));
+    total_sum += constraint_coefficients[226] * value;
+
+    let value = (column8 - oods_values[227])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[150] * oods_point));
+    total_sum += constraint_coefficients[227] * value;
+
+    let value = (column8 - oods_values[228])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[153] * oods_point));
+    total_sum += constraint_coefficients[228] * value;
+
+    let value = (column8 - oods_values[229])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[154] * oods_point));
+    total_sum += constraint_coefficients[229] * value;
+
+    let value = (column8 - oods_values[230])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[155] * oods_point));
+    total_sum += constraint_coefficients[230] * value;
+
+    let value = (column8 - oods_values[231])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[156] * oods_point));
+    total_sum += constraint_coefficients[231] * value;
+
+    let value = (column8 - oods_values[232])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[162] * oods_point));
+    total_sum += constraint_coefficients[232] * value;
+
+    let value = (column8 - oods_values[233])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[164] * oods_point));
+    total_sum += constraint_coefficients[233] * value;
+
+    let value = (column8 - oods_values[234])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[165] * oods_point));
+    total_sum += constraint_coefficients[234] * value;
+
+    let value = (column8 - oods_values[235])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[167] * oods_point));
+    total_sum += constraint_coefficients[235] * value;
+
+    let value = (column8 - oods_values[236])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[169] * oods_point));
+    total_sum += constraint_coefficients[236] * value;
+
+    let value = (column8 - oods_values[237])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[171] * oods_point));
+    total_sum += constraint_coefficients[237] * value;
+
+    let value = (column8 - oods_values[238])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[8] * oods_point));
+    total_sum += constraint_coefficients[238] * value;
+
+    let value = (column8 - oods_values[239])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[7] * oods_point));
+    total_sum += constraint_coefficients[239] * value;
+
+    let value = (column8 - oods_values[240])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[138] * oods_point));
+    total_sum += constraint_coefficients[240] * value;
+
+    let value = (column8 - oods_values[241])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[137] * oods_point));
+    total_sum += constraint_coefficients[241] * value;
+
+    let value = (column8 - oods_values[242])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[6] * oods_point));
+    total_sum += constraint_coefficients[242] * value;
+
+    let value = (column8 - oods_values[243])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[5] * oods_point));
+    total_sum += constraint_coefficients[243] * value;
+
+    let value = (column8 - oods_values[244])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[4] * oods_point));
+    total_sum += constraint_coefficients[244] * value;
+
+    let value = (column8 - oods_values[245])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[29] * oods_point));
+    total_sum += constraint_coefficients[245] * value;
+
+    let value = (column8 - oods_values[246])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[124] * oods_point));
+    total_sum += constraint_coefficients[246] * value;
+
+    let value = (column8 - oods_values[247])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[125] * oods_point));
+    total_sum += constraint_coefficients[247] * value;
+
+    let value = (column8 - oods_values[248])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[126] * oods_point));
+    total_sum += constraint_coefficients[248] * value;
+
+    let value = (column8 - oods_values[249])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[127] * oods_point));
+    total_sum += constraint_coefficients[249] * value;
+
+    let value = (column8 - oods_values[250])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[128] * oods_point));
+    total_sum += constraint_coefficients[250] * value;
+
+    let value = (column8 - oods_values[251])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[129] * oods_point));
+    total_sum += constraint_coefficients[251] * value;
+
+    let value = (column8 - oods_values[252])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[130] * oods_point));
+    total_sum += constraint_coefficients[252] * value;
+
+    let value = (column8 - oods_values[253])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[131] * oods_point));
+    total_sum += constraint_coefficients[253] * value;
+
+    let value = (column8 - oods_values[254])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[132] * oods_point));
+    total_sum += constraint_coefficients[254] * value;
+
+    let value = (column8 - oods_values[255])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[3] * oods_point));
+    total_sum += constraint_coefficients[255] * value;
+
+    let value = (column8 - oods_values[256])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[2] * oods_point));
+    total_sum += constraint_coefficients[256] * value;
+
+    let value = (column8 - oods_values[257])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[1] * oods_point));
+    total_sum += constraint_coefficients[257] * value;
+
+    let value = (column8 - oods_values[258])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[64] * oods_point));
+    total_sum += constraint_coefficients[258] * value;
+
+    let value = (column8 - oods_values[259])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[65] * oods_point));
+    total_sum += constraint_coefficients[259] * value;
+
+    let value = (column8 - oods_values[260])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[66] * oods_point));
+    total_sum += constraint_coefficients[260] * value;
+
+    let value = (column8 - oods_values[261])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[67] * oods_point));
+    total_sum += constraint_coefficients[261] * value;
+
+    let value = (column8 - oods_values[262])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[68] * oods_point));
+    total_sum += constraint_coefficients[262] * value;
+
+    let value = (column9 - oods_values[263])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[0] * oods_point));
+    total_sum += constraint_coefficients[263] * value;
+
+    let value = (column9 - oods_values[264])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[17] * oods_point));
+    total_sum += constraint_coefficients[264] * value;
+
+    let value = (column9 - oods_values[265])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[24] * oods_point));
+    total_sum += constraint_coefficients[265] * value;
+
+    let value = (column9 - oods_values[266])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[25] * oods_point));
+    total_sum += constraint_coefficients[266] * value;
+
+    let value = (column9 - oods_values[267])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[27] * oods_point));
+    total_sum += constraint_coefficients[267] * value;
+
+    let value = (column9 - oods_values[268])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[30] * oods_point));
+    total_sum += constraint_coefficients[268] * value;
+
+    let value = (column9 - oods_values[269])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[34] * oods_point));
+    total_sum += constraint_coefficients[269] * value;
+
+    let value = (column9 - oods_values[270])
+        .field_div(&felt_nonzero!(point - autogenerated_pows[38] * oods_point));
+    total_sum += constraint_coefficients[270] * value;
+
+    // Sum the OODS boundary constraints on the composition polynomials.
+    let oods_point_to_deg = oods_point.pow_felt(&Felt::from(Layout::CONSTRAINT_DEGREE));
+
+    let value =
+        (column_values[10] - oods_values[271]).field_div(&felt_nonzero!(point - oods_point_to_deg));
+    total_sum += constraint_coefficients[271] * value;
+
+    let value =
+        (column_values[11] - oods_values[272]).field_div(&felt_nonzero!(point - oods_point_to_deg));
+    total_sum += constraint_coefficients[272] * value;
+
+    total_sum
+}
@@ -0,0 +1,251 @@
+pub mod autogenerated;
+pub mod global_values;
+
+use crate::swiftness::air::consts::{FELT_0, FELT_1, FELT_32768, FELT_65536};
+use crate::swiftness::air::layout::{
+    stark_curve, BuiltinSegment, ComputedValues, GenericLayoutTrait, LayoutParams, LayoutTrait,
+    StaticLayoutTrait,
+};
+use crate::swiftness::air::public_memory::PublicInput;
+use felt::Felt;
+use utils::global_values::{CurveConfig, EcPoint, EcdsaSigConfig, InteractionElements};
+use utils::layout::LayoutStorage;
+
+pub use global_values::GlobalValues;
+
+pub const BITWISE_RATIO: usize = 64;
+pub const BITWISE_ROW_RATIO: usize = 1024;
+pub const BITWISE_TOTAL_N_BITS: usize = 251;
+pub const CPU_COMPONENT_HEIGHT: usize = 16;
+pub const CPU_COMPONENT_STEP: usize = 1;
+pub const DILUTED_N_BITS: usize = 16;
+pub const DILUTED_SPACING: usize = 4;
+pub const EC_OP_BUILTIN_RATIO: usize = 1024;
+pub const EC_OP_BUILTIN_ROW_RATIO: usize = 16384;
+pub const EC_OP_N_BITS: usize = 252;
+pub const EC_OP_SCALAR_HEIGHT: usize = 256;
+pub const ECDSA_BUILTIN_RATIO: usize = 2048;
+pub const ECDSA_BUILTIN_REPETITIONS: usize = 1;
+pub const ECDSA_BUILTIN_ROW_RATIO: usize = 32768;
+pub const ECDSA_ELEMENT_BITS: usize = 251;
+pub const ECDSA_ELEMENT_HEIGHT: usize = 256;
+pub const HAS_BITWISE_BUILTIN: usize = 1;
+pub const HAS_DILUTED_POOL: usize = 1;
+pub const HAS_EC_OP_BUILTIN: usize = 1;
+pub const HAS_ECDSA_BUILTIN: usize = 1;
+pub const HAS_KECCAK_BUILTIN: usize = 0;
+pub const HAS_OUTPUT_BUILTIN: usize = 1;
+pub const HAS_PEDERSEN_BUILTIN: usize = 1;
+pub const HAS_POSEIDON_BUILTIN: usize = 1;
+pub const HAS_RANGE_CHECK_BUILTIN: usize = 1;
+pub const HAS_RANGE_CHECK96_BUILTIN: usize = 0;
+pub const IS_DYNAMIC_AIR: usize = 0;
+pub const LAYOUT_CODE: Felt = Felt::from_hex_unchecked("0x737461726b6e6574");
+pub const LOG_CPU_COMPONENT_HEIGHT: usize = 4;
+pub const N_DYNAMIC_PARAMS: usize = 0;
+pub const PEDERSEN_BUILTIN_RATIO: usize = 32;
+pub const PEDERSEN_BUILTIN_REPETITIONS: usize = 1;
+pub const PEDERSEN_BUILTIN_ROW_RATIO: usize = 512;
+pub const POSEIDON_M: usize = 3;
+pub const POSEIDON_RATIO: usize = 32;
+pub const POSEIDON_ROUNDS_FULL: usize = 8;
+pub const POSEIDON_ROUNDS_PARTIAL: usize = 83;
+pub const POSEIDON_ROW_RATIO: usize = 512;
+pub const PUBLIC_MEMORY_STEP: usize = 8;
+pub const RANGE_CHECK_BUILTIN_RATIO: usize = 16;
+pub const RANGE_CHECK_BUILTIN_ROW_RATIO: usize = 256;
+pub const RANGE_CHECK_N_PARTS: usize = 8;
+
+pub mod segments {
+    pub const BITWISE: usize = 6;
+    pub const EC_OP: usize = 7;
+    pub const ECDSA: usize = 5;
+    pub const EXECUTION: usize = 1;
+    pub const N_SEGMENTS: usize = 9;
+    pub const OUTPUT: usize = 2;
+    pub const PEDERSEN: usize = 3;
+    pub const POSEIDON: usize = 8;
+    pub const PROGRAM: usize = 0;
+    pub const RANGE_CHECK: usize = 4;
+}
+
+pub mod builtins {
+    use felt::Felt;
+
+    pub const OUTPUT: Felt = Felt::from_hex_unchecked("0x6F7574707574");
+    pub const PEDERSEN: Felt = Felt::from_hex_unchecked("0x706564657273656E");
+    pub const RANGE_CHECK: Felt = Felt::from_hex_unchecked("0x72616E67655F636865636B");
+    pub const ECDSA: Felt = Felt::from_hex_unchecked("0x6563647361");
+    pub const BITWISE: Felt = Felt::from_hex_unchecked("0x62697477697365");
+    pub const EC_OP: Felt = Felt::from_hex_unchecked("0x65635F6F70");
+    pub const POSEIDON: Felt = Felt::from_hex_unchecked("0x706F736569646F6E");
+}
+
+// Pedersen builtin
+pub const SHIFT_POINT_X: Felt =
+    Felt::from_hex_unchecked("0x49ee3eba8c1600700ee1b87eb599f16716b0b1022947733551fde4050ca6804");
+pub const SHIFT_POINT_Y: Felt =
+    Felt::from_hex_unchecked("0x3ca0cfe4b3bc6ddf346d49d06ea0ed34e621062c0e056c1d0405d266e10268a");
+
+pub const BUILTINS: [Felt; 7] = [
+    builtins::OUTPUT,
+    builtins::PEDERSEN,
+    builtins::RANGE_CHECK,
+    builtins::ECDSA,
+    builtins::BITWISE,
+    builtins::EC_OP,
+    builtins::POSEIDON,
+];
+
+pub struct Layout {}
+
+impl StaticLayoutTrait for Layout {
+    const NUM_COLUMNS_FIRST: u32 = 9;
+    const NUM_COLUMNS_SECOND: u32 = 1;
+}
+
+impl GenericLayoutTrait for Layout {
+    fn get_num_columns_first(_public_input: &PublicInput) -> Option<u32> {
+        Some(<Self as StaticLayoutTrait>::NUM_COLUMNS_FIRST)
+    }
+    fn get_num_columns_second(_public_input: &PublicInput) -> Option<u32> {
+        Some(<Self as StaticLayoutTrait>::NUM_COLUMNS_SECOND)
+    }
+}
+
+impl LayoutTrait for Layout {
+    const CONSTRAINT_DEGREE: usize = 2;
+    const MASK_SIZE: usize = 271;
+    const N_CONSTRAINTS: usize = 198;
+    const N_INTERACTION_ELEMENTS: u32 = 6;
+}
+
+impl LayoutStorage for Layout {
+    type GlobalValues = GlobalValues;
+
+    const CONSTRAINT_DEGREE: usize = <Self as LayoutTrait>::CONSTRAINT_DEGREE;
+    const N_CONSTRAINTS: usize = <Self as LayoutTrait>::N_CONSTRAINTS;
+    const MASK_SIZE: usize = <Self as LayoutTrait>::MASK_SIZE;
+    const NUM_COLUMNS_FIRST: u32 = <Self as StaticLayoutTrait>::NUM_COLUMNS_FIRST;
+    const NUM_COLUMNS_SECOND: u32 = <Self as StaticLayoutTrait>::NUM_COLUMNS_SECOND;
+    const POWS_SIZE: usize = 193;
+    const DOMAINS_SIZE: usize = 38;
+}
+
+pub const PARAMS: LayoutParams = LayoutParams {
+    constraint_degree: <Layout as LayoutTrait>::CONSTRAINT_DEGREE,
+    n_constraints: <Layout as LayoutTrait>::N_CONSTRAINTS,
+    mask_size: <Layout as LayoutTrait>::MASK_SIZE,
+    num_columns_first: <Layout as StaticLayoutTrait>::NUM_COLUMNS_FIRST,
+    num_columns_second: <Layout as StaticLayoutTrait>::NUM_COLUMNS_SECOND,
+    n_interaction_elements: <Layout as LayoutTrait>::N_INTERACTION_ELEMENTS,
+    n_segments: segments::N_SEGMENTS,
+    public_memory_step: PUBLIC_MEMORY_STEP,
+    has_diluted_pool: HAS_DILUTED_POOL == 1,
+    pedersen_builtin_ratio: PEDERSEN_BUILTIN_RATIO * PEDERSEN_BUILTIN_REPETITIONS,
+    ecdsa_builtin_ratio: Some(ECDSA_BUILTIN_RATIO * ECDSA_BUILTIN_REPETITIONS),
+    poseidon_ratio: Some(POSEIDON_RATIO),
+    builtins: &[
+        BuiltinSegment {
+            segment: segments::PEDERSEN,
+            row_ratio: PEDERSEN_BUILTIN_ROW_RATIO as u32,
+            cells_per_instance: 3,
+            error: "Pedersen uses exceed copies",
+        },
+        BuiltinSegment {
+            segment: segments::RANGE_CHECK,
+            row_ratio: RANGE_CHECK_BUILTIN_ROW_RATIO as u32,
+            cells_per_instance: 1,
+            error: "Range check uses exceed copies",
+        },
+        BuiltinSegment {
+            segment: segments::ECDSA,
+            row_ratio: ECDSA_BUILTIN_ROW_RATIO as u32,
+            cells_per_instance: 2,
+            error: "Ecdsa uses exceed copies",
+        },
+        BuiltinSegment {
+            segment: segments::BITWISE,
+            row_ratio: BITWISE_ROW_RATIO as u32,
+            cells_per_instance: 5,
+            error: "Bitwise uses exceed copies",
+        },
+        BuiltinSegment {
+            segment: segments::EC_OP,
+            row_ratio: EC_OP_BUILTIN_ROW_RATIO as u32,
+            cells_per_instance: 7,
+            error: "Ec op uses exceed copies",
+        },
+        BuiltinSegment {
+            segment: segments::POSEIDON,
+            row_ratio: POSEIDON_ROW_RATIO as u32,
+            cells_per_instance: 6,
+            error: "Poseidon uses exceed copies",
+        },
+    ],
+};
+
+pub fn global_values(
+    public_input: &PublicInput,
+    interaction_elements: &InteractionElements,
+    values: &ComputedValues,
+) -> GlobalValues {
+    let memory_segments = public_input.segments.as_slice();
+
+    GlobalValues {
+        trace_length: values.trace_length,
+        initial_pc: memory_segments[segments::PROGRAM].begin_addr,
+        final_pc: memory_segments[segments::PROGRAM].stop_ptr,
+        initial_ap: memory_segments[segments::EXECUTION].begin_addr,
+        final_ap: memory_segments[segments::EXECUTION].stop_ptr,
+        initial_pedersen_addr: memory_segments[segments::PEDERSEN].begin_addr,
+        initial_range_check_addr: memory_segments[segments::RANGE_CHECK].begin_addr,
+        initial_ecdsa_addr: memory_segments[segments::ECDSA].begin_addr,
+        initial_bitwise_addr: memory_segments[segments::BITWISE].begin_addr,
+        initial_ec_op_addr: memory_segments[segments::EC_OP].begin_addr,
+        initial_poseidon_addr: memory_segments[segments::POSEIDON].begin_addr,
+        range_check_min: public_input.range_check_min,
+        range_check_max: public_input.range_check_max,
+        offset_size: FELT_65536,
+        half_offset_size: FELT_32768,
+        pedersen_shift_point: EcPoint {
+            x: SHIFT_POINT_X,
+            y: SHIFT_POINT_Y,
+        },
+        ecdsa_sig_config: EcdsaSigConfig {
+            alpha: stark_curve::ALPHA,
+            beta: stark_curve::BETA,
+            shift_point: EcPoint {
+                x: SHIFT_POINT_X,
+                y: SHIFT_POINT_Y,
+            },
+        },
+        ec_op_curve_config: CurveConfig {
+            alpha: stark_curve::ALPHA,
+            beta: stark_curve::BETA,
+        },
+        pedersen_points_x: values.pedersen_points_x,
+        pedersen_points_y: values.pedersen_points_y,
+        ecdsa_generator_points_x: values.ecdsa_generator_points_x,
+        ecdsa_generator_points_y: values.ecdsa_generator_points_y,
+        poseidon_poseidon_full_round_key0: values.poseidon_full_round_key0,
+        poseidon_poseidon_full_round_key1: values.poseidon_full_round_key1,
+        poseidon_poseidon_full_round_key2: values.poseidon_full_round_key2,
+        poseidon_poseidon_partial_round_key0: values.poseidon_partial_round_key0,
+        poseidon_poseidon_partial_round_key1: values.poseidon_partial_round_key1,
+        memory_multi_column_perm_perm_interaction_elm: interaction_elements
+            .memory_multi_column_perm_perm_interaction_elm,
+        memory_multi_column_perm_hash_interaction_elm0: interaction_elements
+            .memory_multi_column_perm_hash_interaction_elm0,
+        range_check16_perm_interaction_elm: interaction_elements.range_check16_perm_interaction_elm,
+        diluted_check_permutation_interaction_elm: interaction_elements
+            .diluted_check_permutation_interaction_elm,
+        diluted_check_interaction_z: interaction_elements.diluted_check_interaction_z,
+        diluted_check_interaction_alpha: interaction_elements.diluted_check_interaction_alpha,
+        memory_multi_column_perm_perm_public_memory_prod: values.public_memory_prod_ratio,
+        range_check16_perm_public_memory_prod: FELT_1,
+        diluted_check_first_elm: FELT_0,
+        diluted_check_permutation_public_memory_prod: FELT_1,
+        diluted_check_final_cum_val: values.diluted_prod,
+    }
+}
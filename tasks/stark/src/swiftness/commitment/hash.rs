@@ -0,0 +1,86 @@
+#![allow(unexpected_cfgs)]
+
+use crate::swiftness::air::layout::AnyLayout;
+use crate::swiftness::fri::config::MAX_FRI_STEP;
+use crate::swiftness::stark::config::Hasher;
+use blake2::{Blake2s256, Digest};
+use felt::Felt;
+#[cfg(not(target_os = "solana"))]
+use sha3::Keccak256;
+use utils::layout::LayoutStorage;
+use utils::{ensure, TaskError};
+
+// Number of low bytes of the digest kept by the "*_masked160_lsb" commitment hashes.
+const MASKED_160_BYTES: usize = 20;
+
+// Widest table row that is hashed from a fixed buffer: the wider of a FRI layer of the largest step
+// and the first trace table of the widest supported layout. The other tables have fewer columns.
+pub const MAX_ROW_LENGTH: usize = {
+    let fri_row = 1 << MAX_FRI_STEP;
+    let trace_row = <AnyLayout as LayoutStorage>::NUM_COLUMNS_FIRST as usize;
+    if fri_row > trace_row {
+        fri_row
+    } else {
+        trace_row
+    }
+};
+
+// Full digest of the bytes, as used by the proof of work.
+pub fn digest(hasher: Hasher, data: &[u8]) -> [u8; 32] {
+    digest_parts(hasher, &[data])
+}
+
+// Full digest of the concatenation of the parts, without copying them into one buffer.
+pub fn digest_parts(hasher: Hasher, parts: &[&[u8]]) -> [u8; 32] {
+    match hasher {
+        Hasher::Keccak256 => keccak256(parts),
+        Hasher::Blake2s256 => {
+            let mut hasher = Blake2s256::new();
+            for part in parts {
+                hasher.update(part);
+            }
+            hasher.finalize().into()
+        }
+    }
+}
+
+// Keccak-256 of the concatenation of the parts. On-chain it goes through the sol_keccak256
+// syscall, which is far cheaper in compute units than hashing in the program.
+#[cfg(target_os = "solana")]
+#[allow(deprecated)]
+pub fn keccak256(parts: &[&[u8]]) -> [u8; 32] {
+    solana_program::keccak::hashv(parts).to_bytes()
+}
+
+// Keccak-256 of the concatenation of the parts, computed with sha3 on host builds.
+#[cfg(not(target_os = "solana"))]
+pub fn keccak256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+// Non verifier friendly hash of a table row: the digest of the big-endian bytes of the values,
+// keeping its 160 least significant bits. Rows longer than MAX_ROW_LENGTH values are rejected.
+pub fn hash_row(hasher: Hasher, values: &[Felt]) -> Result<Felt, TaskError> {
+    ensure(
+        values.len() <= MAX_ROW_LENGTH,
+        TaskError::InvalidConfig("Table row too long"),
+    )?;
+    let mut bytes = [0u8; MAX_ROW_LENGTH * 32];
+    for (chunk, value) in bytes.chunks_exact_mut(32).zip(values) {
+        chunk.copy_from_slice(&value.to_bytes_be());
+    }
+    let digest = digest(hasher, &bytes[..values.len() * 32]);
+    Ok(Felt::from_bytes_be_slice(
+        &digest[digest.len() - MASKED_160_BYTES..],
+    ))
+}
+
+// Non verifier friendly hash of two Merkle tree siblings.
+pub fn hash_node(hasher: Hasher, x: Felt, y: Felt) -> Felt {
+    let digest = digest_parts(hasher, &[&x.to_bytes_be(), &y.to_bytes_be()]);
+    Felt::from_bytes_be_slice(&digest[digest.len() - MASKED_160_BYTES..])
+}
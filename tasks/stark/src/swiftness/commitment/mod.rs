@@ -1,3 +1,4 @@
+pub mod hash;
 pub mod table;
 pub mod types;
 pub mod vector;
@@ -5,8 +5,13 @@ use crate::{
 };
 use felt::Felt;
 
+// Decommitted values are stored in standard form, while the prover commits to their montgomery
+// form. Multiplying by R = 2^256 mod p converts between the two.
+pub const MONTGOMERY_R: Felt =
+    Felt::from_hex_unchecked("0x7FFFFFFFFFFFDF0FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE1");
+
 // Commitment for a table (n_rows x n_columns) of field elements in montgomery form.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Commitment {
     pub config: Config,
     pub vector_commitment: vector::types::Commitment,
@@ -23,3 +28,15 @@ pub struct Decommitment {
 pub struct Witness {
     pub vector: vector::types::Witness,
 }
+
+impl Decommitment {
+    pub fn is_within_capacity(&self) -> bool {
+        self.values.is_within_capacity() && self.montgomery_values.is_within_capacity()
+    }
+}
+
+impl Witness {
+    pub fn is_within_capacity(&self) -> bool {
+        self.vector.authentications.is_within_capacity()
+    }
+}
@@ -3,7 +3,7 @@ use crate::swiftness::commitment::vector::config::Config;
 use felt::Felt;
 
 // Commitment for a vector of field elements.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Commitment {
     pub config: Config,
     pub commitment_hash: Felt,
@@ -15,3 +15,11 @@ pub struct Witness {
     // bottom layer up, left to right.
     pub authentications: FunVec<Felt, FUNVEC_AUTHENTICATIONS>,
 }
+
+// A query to the vector commitment: the index of a node in the Merkle tree (the root has index 1)
+// and its value.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Query {
+    pub index: Felt,
+    pub value: Felt,
+}
@@ -0,0 +1,93 @@
+use felt::Felt;
+use utils::{ensure, TaskError};
+
+// The 16th roots of unity in bit-reversed order: FRI_GROUP[i] = g^bit_reverse(i), where g is the
+// generator of the multiplicative subgroup of order 16. Elements of a coset are committed in this
+// order, so FRI_GROUP[i] is the ratio between the i-th element of a coset and its first element.
+pub const FRI_GROUP: [Felt; 16] = [
+    Felt::from_hex_unchecked("0x1"),
+    Felt::from_hex_unchecked("0x800000000000011000000000000000000000000000000000000000000000000"),
+    Felt::from_hex_unchecked("0x625023929a2995b533120664329f8c7c5268e56ac8320da2a616626f41337e3"),
+    Felt::from_hex_unchecked("0x1dafdc6d65d66b5accedf99bcd607383ad971a9537cdf25d59e99d90becc81e"),
+    Felt::from_hex_unchecked("0x63365fe0de874d9c90adb1e2f9c676e98c62155e4412e873ada5e1dee6feebb"),
+    Felt::from_hex_unchecked("0x1cc9a01f2178b3736f524e1d06398916739deaa1bbed178c525a1e211901146"),
+    Felt::from_hex_unchecked("0x3b912c31d6a226e4a15988c6b7ec1915474043aac68553537192090b43635cd"),
+    Felt::from_hex_unchecked("0x446ed3ce295dda2b5ea677394813e6eab8bfbc55397aacac8e6df6f4bc9ca34"),
+    Felt::from_hex_unchecked("0x5ec467b88826aba4537602d514425f3b0bdf467bbf302458337c45f6021e539"),
+    Felt::from_hex_unchecked("0x213b984777d9556bac89fd2aebbda0c4f420b98440cfdba7cc83ba09fde1ac8"),
+    Felt::from_hex_unchecked("0x5ce3fa16c35cb4da537753675ca3276ead24059dddea2ca47c36587e5a538d1"),
+    Felt::from_hex_unchecked("0x231c05e93ca34c35ac88ac98a35cd89152dbfa622215d35b83c9a781a5ac730"),
+    Felt::from_hex_unchecked("0xb54759e8c46e1258dc80f091e6f3be387888015452ce5f0ca09ce9e571f52"),
+    Felt::from_hex_unchecked("0x7f4ab8a6173b92fda7237f0f6e190c41c78777feabad31a0f35f63161a8e0af"),
+    Felt::from_hex_unchecked("0x23c12f3909539339b83645c1b8de3e14ebfee15c2e8b3ad2867e3a47eba558c"),
+    Felt::from_hex_unchecked("0x5c3ed0c6f6ac6dd647c9ba3e4721c1eb14011ea3d174c52d7981c5b8145aa75"),
+];
+
+// FRI_GROUP[PAIR_INV_INDEX[j]] is the inverse of FRI_GROUP[2 * j], the offset of the j-th pair of
+// elements (x, -x) inside a coset.
+const PAIR_INV_INDEX: [usize; 8] = [0, 3, 7, 5, 15, 13, 11, 9];
+
+// One folding step on the pair (f(x), f(-x)). The factor 2 of the textbook formula is omitted, as
+// in the Stone prover.
+pub fn fri_formula2(f_x: Felt, f_minus_x: Felt, eval_point: Felt, x_inv: Felt) -> Felt {
+    f_x + f_minus_x + eval_point * x_inv * (f_x - f_minus_x)
+}
+
+// Folds the values of a coset (in bit-reversed order, of size 2, 4, 8 or 16) into a single value
+// of the next layer. `x_inv` is the inverse of the first element of the coset.
+pub fn fri_formula(values: &[Felt], eval_point: Felt, x_inv: Felt) -> Result<Felt, TaskError> {
+    let mut buffer = [Felt::ZERO; 16];
+    let mut len = values.len();
+    ensure(
+        len.is_power_of_two() && (2..=16).contains(&len),
+        TaskError::InvalidConfig("Invalid coset size"),
+    )?;
+    buffer[..len].copy_from_slice(values);
+
+    let mut eval_point = eval_point;
+    let mut x_inv = x_inv;
+    while len > 1 {
+        for j in 0..len / 2 {
+            buffer[j] = fri_formula2(
+                buffer[2 * j],
+                buffer[2 * j + 1],
+                eval_point,
+                x_inv * FRI_GROUP[PAIR_INV_INDEX[j]],
+            );
+        }
+        len /= 2;
+        eval_point = eval_point * eval_point;
+        x_inv = x_inv * x_inv;
+    }
+    Ok(buffer[0])
+}
+
+// Evaluates the polynomial with the given coefficients (lowest degree first) at `point`, using
+// Horner's method.
+pub fn horner_eval(coefficients: &[Felt], point: Felt) -> Felt {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Felt::ZERO, |acc, coefficient| acc * point + *coefficient)
+}
+
+// Inverts every element of `values` in place with a single field inversion (Montgomery's trick).
+// Fails if one of the elements is zero.
+pub fn batch_inverse(values: &mut [Felt]) -> Result<(), TaskError> {
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut product = Felt::ONE;
+    for value in values.iter() {
+        prefix_products.push(product);
+        product *= *value;
+    }
+
+    let mut inverse = product
+        .inverse()
+        .ok_or(TaskError::InvalidWitness("Cannot invert zero"))?;
+    for (value, prefix_product) in values.iter_mut().zip(prefix_products).rev() {
+        let next_inverse = inverse * *value;
+        *value = inverse * prefix_product;
+        inverse = next_inverse;
+    }
+    Ok(())
+}
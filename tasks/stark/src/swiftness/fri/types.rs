@@ -33,10 +33,19 @@ pub struct LayerWitness {
 pub struct Commitment {
     pub config: swiftness::fri::config::Config,
     // Array of size n_layers - 1 containing table commitments for each inner layer.
-    pub inner_layers: Vec<swiftness::commitment::table::types::Commitment>,
+    pub inner_layers: FunVec<swiftness::commitment::table::types::Commitment, FUNVEC_LAYERS>,
     // Array of size n_layers, of one evaluation point for each layer.
-    pub eval_points: Vec<Felt>,
+    pub eval_points: FunVec<Felt, FUNVEC_LAYERS>,
     // Array of size 2**log_last_layer_degree_bound containing coefficients for the last layer
     // polynomial.
-    pub last_layer_coefficients: Vec<Felt>,
+    pub last_layer_coefficients: FunVec<Felt, FUNVEC_LAST_LAYER>,
+}
+
+// A query to a FRI layer: the index of the queried element in the layer, the value of the layer
+// at that element and the inverse of the corresponding evaluation point.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct FriLayerQuery {
+    pub index: Felt,
+    pub y_value: Felt,
+    pub x_inv_value: Felt,
 }
@@ -1,3 +1,7 @@
+// Bounds on the proof of work difficulty, the threshold is computed as 2^(128 - n_bits).
+pub const MIN_PROOF_OF_WORK_BITS: u8 = 30;
+pub const MAX_PROOF_OF_WORK_BITS: u8 = 50;
+
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct Config {
     // Proof of work difficulty (number of bits required to be 0).
@@ -3,6 +3,7 @@ use crate::swiftness::commitment;
 use crate::swiftness::fri::config::Config as FriConfig;
 use crate::swiftness::pow;
 use felt::Felt;
+use utils::TaskError;
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct StarkConfig {
     pub traces: trace::config::Config,
@@ -19,3 +20,117 @@ pub struct StarkConfig {
     // Number of layers that use a verifier friendly hash in each commitment.
     pub n_verifier_friendly_commitment_layers: Felt,
 }
+
+// Version of the Stone prover that generated the proof.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StoneVersion {
+    // The public input hash does not include n_verifier_friendly_commitment_layers.
+    Stone5,
+    // The public input hash starts with n_verifier_friendly_commitment_layers.
+    #[default]
+    Stone6,
+}
+
+// Hash function of the program and output hashes of the public input.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HashFunction {
+    Pedersen,
+    #[default]
+    Poseidon,
+}
+
+// Non verifier friendly hash of the commitments and of the proof of work. Commitments keep the
+// 160 least significant bits of the digest ("*_masked160_lsb" in Stone), the proof of work uses the
+// full digest.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Hasher {
+    #[default]
+    Keccak256,
+    Blake2s256,
+}
+
+impl TryFrom<u8> for StoneVersion {
+    type Error = TaskError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(StoneVersion::Stone5),
+            1 => Ok(StoneVersion::Stone6),
+            _ => Err(TaskError::InvalidConfig("Invalid stone version")),
+        }
+    }
+}
+
+impl TryFrom<u8> for HashFunction {
+    type Error = TaskError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(HashFunction::Pedersen),
+            1 => Ok(HashFunction::Poseidon),
+            _ => Err(TaskError::InvalidConfig("Invalid program hash function")),
+        }
+    }
+}
+
+impl TryFrom<u8> for Hasher {
+    type Error = TaskError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Hasher::Keccak256),
+            1 => Ok(Hasher::Blake2s256),
+            _ => Err(TaskError::InvalidConfig("Invalid hasher")),
+        }
+    }
+}
+
+// How the verifier hashes the public input and the commitments, which depends on the prover and on
+// the program. The settings are uploaded with the proof, so they are kept as raw bytes and only
+// read through the checked conversions to their enums.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifierConfig {
+    pub stone_version: u8,
+    pub program_hash_function: u8,
+    pub hasher: u8,
+}
+
+impl VerifierConfig {
+    pub fn new(
+        stone_version: StoneVersion,
+        program_hash_function: HashFunction,
+        hasher: Hasher,
+    ) -> Self {
+        Self {
+            stone_version: stone_version as u8,
+            program_hash_function: program_hash_function as u8,
+            hasher: hasher as u8,
+        }
+    }
+
+    pub fn stone_version(&self) -> Result<StoneVersion, TaskError> {
+        self.stone_version.try_into()
+    }
+
+    pub fn program_hash_function(&self) -> Result<HashFunction, TaskError> {
+        self.program_hash_function.try_into()
+    }
+
+    pub fn hasher(&self) -> Result<Hasher, TaskError> {
+        self.hasher.try_into()
+    }
+}
+
+impl Default for VerifierConfig {
+    fn default() -> Self {
+        Self::new(
+            StoneVersion::default(),
+            HashFunction::default(),
+            Hasher::default(),
+        )
+    }
+}
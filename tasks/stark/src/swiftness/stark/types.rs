@@ -1,14 +1,17 @@
-use super::config::StarkConfig;
-use crate::funvec::{FunVec, FUNVEC_OODS};
+use super::config::{StarkConfig, VerifierConfig};
+use crate::funvec::{FunVec, FUNVEC_LAYER_VALUES, FUNVEC_OODS, FUNVEC_QUERIES};
 use crate::swiftness;
 use crate::swiftness::air::public_memory::PublicInput;
 use crate::swiftness::air::trace;
 use crate::swiftness::commitment::table;
+use crate::swiftness::commitment::vector;
+use crate::swiftness::fri::types::FriLayerQuery;
 use crate::swiftness::{fri, pow::pow};
 use felt::Felt;
 use swiftness::air::trace::Commitment as TracesCommitment;
 use swiftness::commitment::table::types::Commitment as TableCommitment;
 use swiftness::fri::types::Commitment as FriCommitment;
+use utils::{ensure, TaskError};
 
 pub fn cast_slice_to_struct<T>(slice: &[u8]) -> &T
 where
@@ -37,12 +40,63 @@ where
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct StarkProof {
+    pub verifier_config: VerifierConfig,
     pub config: StarkConfig,
     pub public_input: PublicInput,
     pub unsent_commitment: StarkUnsentCommitment,
     pub witness: StarkWitness,
 }
 
+impl StarkProof {
+    // The proof is uploaded as raw bytes, so the length of each FunVec comes from the uploader.
+    // Checked before any task reads the proof. Every layer witness is checked, not only the first
+    // n_layers - 1, since the layers are indexed by the config.
+    pub fn validate_lengths(&self) -> Result<(), TaskError> {
+        let public_input = &self.public_input;
+        ensure(
+            public_input.segments.is_within_capacity()
+                && public_input.main_page.0.is_within_capacity()
+                && public_input.continuous_page_headers.is_within_capacity()
+                && public_input.continuous_page_values.is_within_capacity(),
+            TaskError::InvalidPublicInput("Length exceeds capacity"),
+        )?;
+
+        let fri_config = &self.config.fri;
+        ensure(
+            fri_config.inner_layers.is_within_capacity()
+                && fri_config.fri_step_sizes.is_within_capacity(),
+            TaskError::InvalidConfig("Length exceeds capacity"),
+        )?;
+
+        let unsent_commitment = &self.unsent_commitment;
+        let witness = &self.witness;
+        let fri_layers = &witness.fri_witness.layers;
+        ensure(
+            unsent_commitment.oods_values.is_within_capacity()
+                && unsent_commitment.fri.inner_layers.is_within_capacity()
+                && unsent_commitment
+                    .fri
+                    .last_layer_coefficients
+                    .is_within_capacity()
+                && witness.traces_decommitment.original.is_within_capacity()
+                && witness.traces_decommitment.interaction.is_within_capacity()
+                && witness.traces_witness.original.is_within_capacity()
+                && witness.traces_witness.interaction.is_within_capacity()
+                && witness.composition_decommitment.is_within_capacity()
+                && witness.composition_witness.is_within_capacity()
+                && fri_layers.is_within_capacity()
+                && fri_layers
+                    .unchecked_slice(fri_layers.capacity())
+                    .iter()
+                    .all(|layer| {
+                        layer.leaves.is_within_capacity()
+                            && layer.table_witness.is_within_capacity()
+                    }),
+            TaskError::InvalidWitness("Length exceeds capacity"),
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct StarkUnsentCommitment {
     pub traces: trace::UnsentCommitment,
@@ -64,15 +118,32 @@ pub struct StarkWitness {
     pub fri_witness: fri::types::Witness,
 }
 
+// Kept in the account between the commit and the query phase, which run in different transactions,
+// so it holds no heap allocated fields.
 #[derive(Debug, PartialEq, Default)]
 pub struct StarkCommitment<InteractionElements> {
     pub traces: TracesCommitment<InteractionElements>,
     pub composition: TableCommitment,
     pub interaction_after_composition: Felt,
-    pub oods_values: Vec<Felt>,
-    pub interaction_after_oods: Vec<Felt>,
+    pub oods_values: FunVec<Felt, FUNVEC_OODS>,
+    pub interaction_after_oods: FunVec<Felt, FUNVEC_OODS>,
     pub fri: FriCommitment,
 }
+// Working memory of the query phase. StarkVerify and its subtasks run over many transactions, so
+// everything derived from the queries is kept in the account rather than in the tasks.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QueryState {
+    // Sorted and deduplicated query indices in the evaluation domain.
+    pub queries: FunVec<Felt, FUNVEC_QUERIES>,
+    // Queries to the FRI layer being verified.
+    pub fri_queries: FunVec<FriLayerQuery, FUNVEC_QUERIES>,
+    // Coset indices of the FRI layer being verified and the n_columns coset elements of each.
+    pub layer_indices: FunVec<Felt, FUNVEC_QUERIES>,
+    pub layer_values: FunVec<Felt, FUNVEC_LAYER_VALUES>,
+    // Nodes of the Merkle tree layer being authenticated, sorted by index.
+    pub nodes: FunVec<vector::types::Query, FUNVEC_QUERIES>,
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -94,25 +165,28 @@ mod test {
     #[test]
     fn test_stark_proof() {
         let proof = StarkProof {
+            verifier_config: Default::default(),
             public_input: PublicInput {
                 log_n_steps: Felt::from(1),
                 range_check_min: Felt::from(2),
                 range_check_max: Felt::from(3),
                 layout: Felt::from(4),
-                dynamic_params: None,
+                has_dynamic_params: 0,
+                dynamic_params: Default::default(),
                 segments: FunVec::default(),
                 padding_addr: Felt::from(5),
                 padding_value: Felt::from(6),
                 main_page: Page::default(),
                 continuous_page_headers: FunVec::default(),
+                continuous_page_values: FunVec::default(),
             },
             config: StarkConfig::default(),
             unsent_commitment: StarkUnsentCommitment::default(),
             witness: StarkWitness::default(),
         };
         println!("proof: {proof:?}");
-        let mut proof_clone = proof.clone();
-        let bytes = cast_struct_to_slice(&mut proof_clone);
+        let proof_clone = proof.clone();
+        let bytes = cast_struct_to_slice(&proof_clone);
 
         let proof_from_bytes = cast_slice_to_struct::<StarkProof>(bytes);
         assert_eq!(proof_from_bytes, &proof);
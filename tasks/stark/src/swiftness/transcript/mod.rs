@@ -4,7 +4,9 @@
 use crate::poseidon::{PoseidonHash, PoseidonHashMany};
 use felt::Felt;
 pub use utils::transcript::Transcript;
-use utils::{impl_type_identifiable, BidirectionalStack, Executable, ProofData, TypeIdentifiable};
+use utils::{
+    impl_type_identifiable, BidirectionalStack, Executable, ProofData, TaskError, TypeIdentifiable,
+};
 
 #[repr(C)]
 pub struct TranscriptRandomFelt {
@@ -33,12 +35,15 @@ impl TranscriptRandomFelt {
 }
 
 impl Executable for TranscriptRandomFelt {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.phase {
             TranscriptRandomFeltPhase::ComputeHash => {
                 self.phase = TranscriptRandomFeltPhase::ReadPosiedonResult;
-                PoseidonHash::push_input(self.digest, self.counter, stack);
-                vec![PoseidonHash::new().to_vec_with_type_tag()]
+                PoseidonHash::push_input(self.digest, self.counter, stack)?;
+                Ok(vec![PoseidonHash::new().to_vec_with_type_tag()])
             }
             TranscriptRandomFeltPhase::ReadPosiedonResult => {
                 let result = Felt::from_bytes_be_slice(stack.borrow_front());
@@ -46,16 +51,18 @@ impl Executable for TranscriptRandomFelt {
                 stack.pop_front();
                 stack.pop_front();
 
-                stack.push_front(&result.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&result.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
                 let counter = self.counter + Felt::ONE;
-                stack.push_front(&counter.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&counter.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.phase = TranscriptRandomFeltPhase::Finished;
-                vec![]
-            }
-            TranscriptRandomFeltPhase::Finished => {
-                vec![]
+                Ok(vec![])
             }
+            TranscriptRandomFeltPhase::Finished => Ok(vec![]),
         }
     }
 
@@ -87,9 +94,13 @@ impl TranscriptReadFelt {
         }
     }
 
-    pub fn push_input<T: BidirectionalStack>(digest: Felt, val: Felt, stack: &mut T) {
+    pub fn push_input<T: BidirectionalStack>(
+        digest: Felt,
+        val: Felt,
+        stack: &mut T,
+    ) -> Result<(), TaskError> {
         let inputs = vec![digest + Felt::ONE, val];
-        PoseidonHashMany::push_input(&inputs, stack);
+        PoseidonHashMany::push_input(&inputs, stack)
     }
 }
 
@@ -100,11 +111,16 @@ impl Default for TranscriptReadFelt {
 }
 
 impl Executable for TranscriptReadFelt {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.phase {
             TranscriptReadFeltPhase::ComputeHash => {
                 self.phase = TranscriptReadFeltPhase::ReadPosiedonResult;
-                vec![PoseidonHashMany::new(self.inputs_len).to_vec_with_type_tag()]
+                Ok(vec![
+                    PoseidonHashMany::new(self.inputs_len).to_vec_with_type_tag()
+                ])
             }
             TranscriptReadFeltPhase::ReadPosiedonResult => {
                 let result = Felt::from_bytes_be_slice(stack.borrow_front());
@@ -112,15 +128,17 @@ impl Executable for TranscriptReadFelt {
                 stack.pop_front();
                 stack.pop_front();
 
-                stack.push_front(&result.to_bytes_be()).unwrap();
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&result.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.phase = TranscriptReadFeltPhase::Finished;
-                vec![]
-            }
-            TranscriptReadFeltPhase::Finished => {
-                vec![]
+                Ok(vec![])
             }
+            TranscriptReadFeltPhase::Finished => Ok(vec![]),
         }
     }
 
@@ -152,19 +170,28 @@ impl TranscriptReadFeltVector {
         }
     }
 
-    pub fn push_input<T: BidirectionalStack>(digest: Felt, values: &[Felt], stack: &mut T) {
+    pub fn push_input<T: BidirectionalStack>(
+        digest: Felt,
+        values: &[Felt],
+        stack: &mut T,
+    ) -> Result<(), TaskError> {
         let mut inputs = vec![digest + Felt::ONE];
         inputs.extend_from_slice(values);
-        PoseidonHashMany::push_input(&inputs, stack);
+        PoseidonHashMany::push_input(&inputs, stack)
     }
 }
 
 impl Executable for TranscriptReadFeltVector {
-    fn execute<T: BidirectionalStack + ProofData>(&mut self, stack: &mut T) -> Vec<Vec<u8>> {
+    fn execute<T: BidirectionalStack + ProofData>(
+        &mut self,
+        stack: &mut T,
+    ) -> Result<Vec<Vec<u8>>, TaskError> {
         match self.phase {
             TranscriptReadFeltVectorPhase::ComputeHash => {
                 self.phase = TranscriptReadFeltVectorPhase::ReadPosiedonResult;
-                vec![PoseidonHashMany::new(self.inputs_len).to_vec_with_type_tag()]
+                Ok(vec![
+                    PoseidonHashMany::new(self.inputs_len).to_vec_with_type_tag()
+                ])
             }
             TranscriptReadFeltVectorPhase::ReadPosiedonResult => {
                 let result = Felt::from_bytes_be_slice(stack.borrow_front());
@@ -172,15 +199,17 @@ impl Executable for TranscriptReadFeltVector {
                 stack.pop_front();
                 stack.pop_front();
 
-                stack.push_front(&result.to_bytes_be()).unwrap();
-                stack.push_front(&Felt::ZERO.to_bytes_be()).unwrap();
+                stack
+                    .push_front(&result.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
+                stack
+                    .push_front(&Felt::ZERO.to_bytes_be())
+                    .map_err(|_| TaskError::StackOverflow)?;
 
                 self.phase = TranscriptReadFeltVectorPhase::Finished;
-                vec![]
-            }
-            TranscriptReadFeltVectorPhase::Finished => {
-                vec![]
+                Ok(vec![])
             }
+            TranscriptReadFeltVectorPhase::Finished => Ok(vec![]),
         }
     }
 